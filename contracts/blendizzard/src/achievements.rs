@@ -0,0 +1,101 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::events::emit_achievement_unlocked;
+use crate::math::{checked_mul_scalar_7, Rounding};
+use crate::storage;
+use crate::types::{Achievement, AchievementSettings, Achievements, EpochInfo, SCALAR_7};
+
+// ============================================================================
+// Achievements
+// ============================================================================
+//
+// Players unlock profile badges for milestones: their first win, a win streak
+// and a number of finished sessions (thresholds in `AchievementSettings`), and
+// being the top contributor of an epoch's winning faction. Each achievement is
+// unlocked once and emits `AchievementUnlocked`.
+//
+// With `AchievementSettings::fp_bonus` set, every unlocked achievement adds
+// that fraction to the player's epoch FP. FP is calculated when a player
+// first plays in an epoch, so a new badge boosts the next epoch.
+
+/// Largest FP bonus per achievement (10%)
+pub(crate) const MAX_ACHIEVEMENT_FP_BONUS: i128 = SCALAR_7 / 10;
+
+/// Update both players' progress after a finished session
+pub(crate) fn record_game(env: &Env, winner: &Address, loser: &Address) {
+    let settings = storage::get_achievement_settings(env);
+    record_result(env, &settings, winner, true);
+    record_result(env, &settings, loser, false);
+}
+
+/// Update one player's progress after a finished session
+fn record_result(env: &Env, settings: &AchievementSettings, player: &Address, won: bool) {
+    let mut achievements = storage::get_achievements(env, player);
+    achievements.games_played = achievements.games_played.saturating_add(1);
+    achievements.win_streak = if won {
+        achievements.win_streak.saturating_add(1)
+    } else {
+        0
+    };
+
+    if won {
+        unlock(env, player, &mut achievements, Achievement::FirstWin);
+    }
+    if achievements.win_streak >= settings.win_streak {
+        unlock(env, player, &mut achievements, Achievement::WinStreak);
+    }
+    if achievements.games_played >= settings.games_played {
+        unlock(env, player, &mut achievements, Achievement::GamesPlayed);
+    }
+
+    storage::set_achievements(env, player, &achievements);
+}
+
+/// Award `EpochMvp` to the winning faction's top contributor of a closed epoch
+pub(crate) fn record_epoch_mvp(env: &Env, epoch_info: &EpochInfo, winning_faction: u32) {
+    let Some(mvp) = epoch_info.faction_leaders.get(winning_faction) else {
+        return;
+    };
+    let mut achievements = storage::get_achievements(env, &mvp);
+    if unlock(env, &mvp, &mut achievements, Achievement::EpochMvp) {
+        storage::set_achievements(env, &mvp, &achievements);
+    }
+}
+
+/// Unlock an achievement if it isn't already (returns true if newly unlocked)
+fn unlock(
+    env: &Env,
+    player: &Address,
+    achievements: &mut Achievements,
+    achievement: Achievement,
+) -> bool {
+    if achievements.unlocked.contains(achievement) {
+        return false;
+    }
+    achievements.unlocked.push_back(achievement);
+    emit_achievement_unlocked(env, player, achievement);
+    true
+}
+
+/// Scale a player's epoch FP by their achievement bonus
+///
+/// # Errors
+/// * `OverflowError` - If the boosted FP overflows
+pub(crate) fn apply_fp_bonus(env: &Env, player: &Address, fp: i128) -> Result<i128, Error> {
+    let settings = storage::get_achievement_settings(env);
+    if settings.fp_bonus == 0 {
+        return Ok(fp);
+    }
+    let unlocked = storage::get_achievements(env, player).unlocked.len() as i128;
+    if unlocked == 0 {
+        return Ok(fp);
+    }
+
+    let multiplier = settings
+        .fp_bonus
+        .checked_mul(unlocked)
+        .and_then(|bonus| bonus.checked_add(SCALAR_7))
+        .ok_or(Error::OverflowError)?;
+    checked_mul_scalar_7(fp, multiplier, Rounding::Floor).ok_or(Error::OverflowError)
+}
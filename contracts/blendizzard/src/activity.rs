@@ -0,0 +1,111 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::math::checked_fixed_sqrt;
+use crate::storage;
+use crate::streak::reward_weight;
+use crate::types::{ActivityRequirement, EpochPlayer, SCALAR_7};
+
+// ============================================================================
+// Activity Requirement
+// ============================================================================
+//
+// With an `ActivityRequirement` set, a winner only gets a share of the epoch's
+// reward pool after finishing `min_games` sessions and contributing `min_fp`
+// FP in that epoch. Until then the player's wins still count towards faction
+// standings (and so towards which faction wins), but not towards the reward
+// denominators (`faction_weighted_standings` / `faction_sqrt_standings`), so
+// players who never qualify don't dilute the ones who do.
+//
+// Qualifying is checked as sessions finish. A player who qualifies has their
+// whole contribution so far added to the denominators and stays eligible for
+// the rest of the epoch, even if the admin raises the requirement.
+
+/// Whether an epoch's activity meets the requirement
+pub(crate) fn qualifies(requirement: &ActivityRequirement, games_completed: u32, fp: i128) -> bool {
+    games_completed >= requirement.min_games && fp >= requirement.min_fp
+}
+
+/// Whether a player starting an epoch is reward eligible before playing
+pub(crate) fn eligible_from_start(env: &Env) -> bool {
+    qualifies(&storage::get_activity_requirement(env), 0, 0)
+}
+
+/// Count a finished session for a player, making them eligible once they qualify
+///
+/// Call after the session's contribution was credited (and, for the winner,
+/// added to the epoch by `update_epoch_on_game_end`).
+///
+/// # Errors
+/// * `PlayerNotFound` - If the player has no data for the epoch
+/// * `FactionNotSelected` - If a qualifying player isn't locked into a faction
+/// * `OverflowError` - If a reward denominator overflows
+pub(crate) fn record_session(env: &Env, epoch: u32, player: &Address) -> Result<(), Error> {
+    let mut epoch_player =
+        storage::get_epoch_player(env, epoch, player).ok_or(Error::PlayerNotFound)?;
+    epoch_player.games_completed = epoch_player.games_completed.saturating_add(1);
+
+    if !epoch_player.reward_eligible
+        && qualifies(
+            &storage::get_activity_requirement(env),
+            epoch_player.games_completed,
+            epoch_player.total_fp_contributed,
+        )
+    {
+        epoch_player.reward_eligible = true;
+        add_reward_weights(env, epoch, player, &epoch_player)?;
+    }
+
+    storage::set_epoch_player(env, epoch, player, &epoch_player);
+    Ok(())
+}
+
+/// Add a newly eligible player's whole contribution to their faction's reward denominators
+fn add_reward_weights(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+    epoch_player: &EpochPlayer,
+) -> Result<(), Error> {
+    let contribution = epoch_player.total_fp_contributed;
+    if contribution == 0 {
+        return Ok(());
+    }
+
+    let faction = epoch_player
+        .epoch_faction
+        .ok_or(Error::FactionNotSelected)?;
+    let weight =
+        reward_weight(contribution, epoch_player.streak_bonus).ok_or(Error::OverflowError)?;
+    let sqrt_weight = checked_fixed_sqrt(contribution, SCALAR_7)
+        .and_then(|sqrt| reward_weight(sqrt, epoch_player.streak_bonus))
+        .ok_or(Error::OverflowError)?;
+
+    let mut epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let weighted = epoch_info
+        .faction_weighted_standings
+        .get(faction)
+        .unwrap_or(0)
+        .checked_add(weight)
+        .ok_or(Error::OverflowError)?;
+    epoch_info.faction_weighted_standings.set(faction, weighted);
+    let sqrt = epoch_info
+        .faction_sqrt_standings
+        .get(faction)
+        .unwrap_or(0)
+        .checked_add(sqrt_weight)
+        .ok_or(Error::OverflowError)?;
+    epoch_info.faction_sqrt_standings.set(faction, sqrt);
+    storage::set_epoch(env, epoch, &epoch_info);
+
+    crate::reward_cap::record_weight(
+        env,
+        epoch,
+        epoch_info.distribution_mode,
+        faction,
+        player,
+        epoch_player,
+    )?;
+
+    Ok(())
+}
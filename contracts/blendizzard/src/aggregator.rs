@@ -0,0 +1,39 @@
+// Soroswap Aggregator interface
+// Only the subset of functions used by Blendizzard is declared.
+#[allow(dead_code)]
+#[soroban_sdk::contractargs(name = "Args")]
+#[soroban_sdk::contractclient(name = "Client")]
+pub trait Contract {
+    #[allow(clippy::too_many_arguments)]
+    fn swap_exact_tokens_for_tokens(
+        env: soroban_sdk::Env,
+        token_in: soroban_sdk::Address,
+        token_out: soroban_sdk::Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        distribution: soroban_sdk::Vec<DexDistribution>,
+        to: soroban_sdk::Address,
+        deadline: u64,
+    ) -> Result<soroban_sdk::Vec<soroban_sdk::Vec<i128>>, AggregatorError>;
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DexDistribution {
+    pub protocol_id: soroban_sdk::String,
+    pub path: soroban_sdk::Vec<soroban_sdk::Address>,
+    pub parts: u32,
+}
+#[soroban_sdk::contracterror(export = false)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum AggregatorError {
+    NotInitialized = 401,
+    AlreadyInitialized = 402,
+    NegativeNotAllowed = 403,
+    ProtocolAddressNotFound = 404,
+    DeadlineExpired = 405,
+    ExternalError = 406,
+    AmountsOutArithmeticError = 407,
+    ArithmeticError = 408,
+    InvalidTotalParts = 409,
+    InsufficientOutputAmount = 410,
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_approval_revoked, emit_operation_approved};
+use crate::storage;
+use crate::types::{AdminOperation, ApproverSet};
+
+// ============================================================================
+// Admin Operation Approvals
+// ============================================================================
+//
+// With an `ApproverSet` threshold configured, sensitive admin operations
+// (`AdminOperation`: upgrade, vault migration, admin transfer, approver
+// changes, router or BLND token changes and treasurer changes) need `threshold` approver approvals on top of the admin's
+// signature. Approvers approve the exact operation in their own transactions;
+// the admin's call then checks and uses up the approvals. Only approvals of
+// current approvers count, and pending approvals expire with their temporary
+// storage entry (about 30 days after the last approval).
+
+/// Maximum number of approvers
+pub(crate) const MAX_APPROVERS: u32 = 10;
+
+/// Replace the approver set
+///
+/// Caller must have authenticated the admin. Once a threshold is set, changing
+/// the set is itself an operation that needs approvals.
+///
+/// # Errors
+/// * `InvalidConfig` - If the threshold exceeds the number of approvers, approvers
+///   are set without a threshold, an approver is repeated or there are too many
+/// * `ApprovalThresholdNotMet` - If the current approvers haven't approved the change
+pub(crate) fn set_approvers(
+    env: &Env,
+    approvers: Vec<Address>,
+    threshold: u32,
+) -> Result<(), ContractError> {
+    if approvers.len() > MAX_APPROVERS
+        || threshold > approvers.len()
+        || (threshold == 0 && !approvers.is_empty())
+    {
+        return Err(Error::InvalidConfig.into());
+    }
+    for (i, approver) in approvers.iter().enumerate() {
+        if approvers.first_index_of(&approver) != Some(i as u32) {
+            return Err(Error::InvalidConfig.into());
+        }
+    }
+
+    require_approval(
+        env,
+        &AdminOperation::SetApprovers(approvers.clone(), threshold),
+    )?;
+    storage::set_approvers(
+        env,
+        &ApproverSet {
+            approvers,
+            threshold,
+        },
+    );
+    Ok(())
+}
+
+/// Approve an operation as an approver
+///
+/// Approving an operation twice is a no-op.
+///
+/// # Errors
+/// * `NotApprover` - If the caller isn't a current approver
+pub(crate) fn approve(
+    env: &Env,
+    approver: &Address,
+    operation: &AdminOperation,
+) -> Result<(), ExtError> {
+    approver.require_auth();
+    check_approver(env, approver)?;
+
+    let mut approvals = storage::get_approvals(env, operation);
+    if approvals.contains(approver) {
+        return Ok(());
+    }
+    approvals.push_back(approver.clone());
+    storage::set_approvals(env, operation, &approvals);
+
+    emit_operation_approved(env, approver, operation, approvals.len());
+    Ok(())
+}
+
+/// Withdraw an approval before the operation is executed
+///
+/// # Errors
+/// * `NotApprover` - If the caller isn't a current approver
+/// * `ApprovalNotFound` - If the caller hasn't approved the operation
+pub(crate) fn revoke(
+    env: &Env,
+    approver: &Address,
+    operation: &AdminOperation,
+) -> Result<(), ExtError> {
+    approver.require_auth();
+    check_approver(env, approver)?;
+
+    let mut approvals = storage::get_approvals(env, operation);
+    let index = approvals
+        .first_index_of(approver)
+        .ok_or(ExtError::ApprovalNotFound)?;
+    approvals.remove(index);
+    if approvals.is_empty() {
+        storage::remove_approvals(env, operation);
+    } else {
+        storage::set_approvals(env, operation, &approvals);
+    }
+
+    emit_approval_revoked(env, approver, operation, approvals.len());
+    Ok(())
+}
+
+/// Check that an operation has enough approvals, and use them up
+///
+/// A no-op while no threshold is configured.
+///
+/// # Errors
+/// * `ApprovalThresholdNotMet` - If fewer than `threshold` current approvers approved
+pub(crate) fn require_approval(env: &Env, operation: &AdminOperation) -> Result<(), ExtError> {
+    let set = storage::get_approvers(env);
+    if set.threshold == 0 {
+        return Ok(());
+    }
+
+    let approved = storage::get_approvals(env, operation)
+        .iter()
+        .filter(|approver| set.approvers.contains(approver))
+        .count() as u32;
+    if approved < set.threshold {
+        return Err(ExtError::ApprovalThresholdNotMet);
+    }
+
+    storage::remove_approvals(env, operation);
+    Ok(())
+}
+
+/// Reject callers that aren't current approvers
+fn check_approver(env: &Env, approver: &Address) -> Result<(), ExtError> {
+    if !storage::get_approvers(env).approvers.contains(approver) {
+        return Err(ExtError::NotApprover);
+    }
+    Ok(())
+}
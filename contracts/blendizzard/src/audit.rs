@@ -0,0 +1,86 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Val, Vec};
+
+use crate::events::emit_config_updated;
+use crate::storage;
+use crate::types::{AdminAction, Config};
+
+// ============================================================================
+// Admin Log
+// ============================================================================
+//
+// Every admin and role action (config setters, game registration, pause,
+// guardian/arbiter changes, upgrades...) is recorded in a ring buffer of the
+// last `ADMIN_LOG_SIZE` actions, so a watcher can see governance changes from
+// contract state alone. Entries hold SHA-256 hashes of the action name and of
+// the old and new values (XDR encoded); config setters hash the whole `Config`
+// before and after.
+
+/// Number of admin actions kept (the oldest are overwritten first)
+pub(crate) const ADMIN_LOG_SIZE: u32 = 128;
+
+/// Record an admin action in the log
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `caller` - Address that authorized the action
+/// * `action` - Entrypoint name
+/// * `old_value` - Value before the action
+/// * `new_value` - Value after the action
+pub(crate) fn record<O, N>(env: &Env, caller: &Address, action: &str, old_value: &O, new_value: &N)
+where
+    O: IntoVal<Env, Val>,
+    N: IntoVal<Env, Val>,
+{
+    let index = storage::get_admin_log_count(env);
+    let entry = AdminAction {
+        index,
+        action: env
+            .crypto()
+            .sha256(&Bytes::from_slice(env, action.as_bytes()))
+            .into(),
+        old_value: hash_value(env, old_value),
+        new_value: hash_value(env, new_value),
+        ledger: env.ledger().sequence(),
+        caller: caller.clone(),
+    };
+    storage::set_admin_log_entry(env, index % ADMIN_LOG_SIZE, &entry);
+    storage::set_admin_log_count(env, index + 1);
+}
+
+/// Record a config setter in the log and emit `ConfigUpdated`
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `admin` - Admin that authorized the change
+/// * `action` - Entrypoint name
+/// * `old_config` - Config before the change (the stored config is the new one)
+pub(crate) fn config_updated(env: &Env, admin: &Address, action: &str, old_config: &Config) {
+    record(env, admin, action, old_config, &storage::get_config(env));
+    emit_config_updated(env, admin);
+}
+
+/// Get recorded admin actions, newest first
+///
+/// # Arguments
+/// * `offset` - Number of newest actions to skip
+/// * `limit` - Maximum number of actions to return (capped at `ADMIN_LOG_SIZE`)
+pub(crate) fn get_admin_log(env: &Env, offset: u32, limit: u32) -> Vec<AdminAction> {
+    let count = storage::get_admin_log_count(env);
+    let kept = count.min(ADMIN_LOG_SIZE);
+    let mut actions = Vec::new(env);
+
+    let end = kept.min(offset.saturating_add(limit.min(ADMIN_LOG_SIZE)));
+    for back in offset..end {
+        let index = count - 1 - back;
+        if let Some(entry) = storage::get_admin_log_entry(env, index % ADMIN_LOG_SIZE) {
+            actions.push_back(entry);
+        }
+    }
+    actions
+}
+
+/// SHA-256 of a value's XDR encoding
+fn hash_value<T: IntoVal<Env, Val>>(env: &Env, value: &T) -> BytesN<32> {
+    let val: Val = value.into_val(env);
+    env.crypto().sha256(&val.to_xdr(env)).into()
+}
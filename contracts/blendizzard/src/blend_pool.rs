@@ -0,0 +1,104 @@
+#[allow(dead_code)]
+#[soroban_sdk::contractargs(name = "Args")]
+#[soroban_sdk::contractclient(name = "Client")]
+pub trait Contract {
+    fn get_config(env: soroban_sdk::Env) -> PoolConfig;
+    fn get_reserve(env: soroban_sdk::Env, asset: soroban_sdk::Address) -> Reserve;
+    fn get_positions(env: soroban_sdk::Env, address: soroban_sdk::Address) -> Positions;
+    fn get_reserve_list(env: soroban_sdk::Env) -> soroban_sdk::Vec<soroban_sdk::Address>;
+    fn get_reserve_emissions(
+        env: soroban_sdk::Env,
+        reserve_token_index: u32,
+    ) -> Option<ReserveEmissionData>;
+    fn get_user_emissions(
+        env: soroban_sdk::Env,
+        user: soroban_sdk::Address,
+        reserve_token_index: u32,
+    ) -> Option<UserEmissionData>;
+    fn submit(
+        env: soroban_sdk::Env,
+        from: soroban_sdk::Address,
+        spender: soroban_sdk::Address,
+        to: soroban_sdk::Address,
+        requests: soroban_sdk::Vec<Request>,
+    ) -> Positions;
+    fn claim(
+        env: soroban_sdk::Env,
+        from: soroban_sdk::Address,
+        reserve_token_ids: soroban_sdk::Vec<u32>,
+        to: soroban_sdk::Address,
+    ) -> i128;
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PoolConfig {
+    pub bstop_rate: u32,
+    pub max_positions: u32,
+    pub min_collateral: i128,
+    pub oracle: soroban_sdk::Address,
+    pub status: u32,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Request {
+    pub address: soroban_sdk::Address,
+    pub amount: i128,
+    pub request_type: u32,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Positions {
+    pub collateral: soroban_sdk::Map<u32, i128>,
+    pub liabilities: soroban_sdk::Map<u32, i128>,
+    pub supply: soroban_sdk::Map<u32, i128>,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ReserveConfig {
+    pub c_factor: u32,
+    pub decimals: u32,
+    pub enabled: bool,
+    pub index: u32,
+    pub l_factor: u32,
+    pub max_util: u32,
+    pub r_base: u32,
+    pub r_one: u32,
+    pub r_three: u32,
+    pub r_two: u32,
+    pub reactivity: u32,
+    pub supply_cap: i128,
+    pub util: u32,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ReserveData {
+    pub b_rate: i128,
+    pub b_supply: i128,
+    pub backstop_credit: i128,
+    pub d_rate: i128,
+    pub d_supply: i128,
+    pub ir_mod: i128,
+    pub last_time: u64,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Reserve {
+    pub asset: soroban_sdk::Address,
+    pub config: ReserveConfig,
+    pub data: ReserveData,
+    pub scalar: i128,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ReserveEmissionData {
+    pub eps: u64,
+    pub expiration: u64,
+    pub index: i128,
+    pub last_time: u64,
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct UserEmissionData {
+    pub accrued: i128,
+    pub index: i128,
+}
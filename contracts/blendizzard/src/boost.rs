@@ -0,0 +1,119 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::emit_epoch_boosted;
+use crate::storage;
+use crate::swap;
+use crate::types::EpochBoost;
+
+// ============================================================================
+// Sponsor Boosts
+// ============================================================================
+//
+// Anyone can donate to the current epoch's reward pools with `boost_epoch`:
+//
+// - USDC, and BLND swapped to USDC on the spot (subject to the oracle price
+//   check), join the pending rewards that the next `cycle_epoch` folds into
+//   the closed epoch's reward pool, so they are split like yield.
+// - Whitelisted reward tokens (see `reward_tokens.rs`) are kept as they are
+//   and paid out as the epoch's pool of that token.
+//
+// Sponsors are listed per epoch for recognition (`get_epoch_boosts`), one
+// entry per sponsor and pool token. Boosts below `MIN_BOOST` and new entries
+// past `MAX_BOOSTS_PER_EPOCH` are rejected so the list stays small.
+
+/// Smallest boost: 1 USDC after any swap, or 1 unit (7 decimals) of a reward token
+pub(crate) const MIN_BOOST: i128 = 1_0000000;
+
+/// Most sponsor entries listed per epoch
+pub(crate) const MAX_BOOSTS_PER_EPOCH: u32 = 50;
+
+/// Donate USDC, BLND or a reward token to the current epoch's reward pools
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `sponsor` - Address funding the boost
+/// * `token` - USDC, BLND or a whitelisted reward token
+/// * `amount` - Amount of `token` to donate
+///
+/// # Returns
+/// Amount added to the pools (in USDC for USDC and BLND, else in `token`)
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0 or the boost is below `MIN_BOOST`
+/// * `InvalidConfig` - If the token is neither USDC, BLND nor a reward token
+/// * `SlippageExceeded` - If the BLND swap price is far below the oracle price
+/// * `SwapError` - If the BLND swap fails
+/// * `BoostListFull` - If the epoch already lists `MAX_BOOSTS_PER_EPOCH` other entries
+pub(crate) fn boost_epoch(
+    env: &Env,
+    sponsor: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    sponsor.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+    let config = storage::get_config(env);
+    let is_reward_token = crate::reward_tokens::is_reward_token(env, token);
+    if *token != config.usdc_token && *token != config.blnd_token && !is_reward_token {
+        return Err(Error::InvalidConfig.into());
+    }
+
+    token::Client::new(env, token).transfer(sponsor, &env.current_contract_address(), &amount);
+
+    let (pool_token, pool_amount) = if *token == config.blnd_token {
+        if !swap::oracle_allows_swap(env, &config, token, &config.usdc_token, amount) {
+            return Err(Error::SlippageExceeded.into());
+        }
+        let received = swap::swap_best(env, &config, token, &config.usdc_token, amount, 0)?;
+        (config.usdc_token.clone(), received)
+    } else {
+        (token.clone(), amount)
+    };
+    if pool_amount < MIN_BOOST {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let epoch = storage::get_current_epoch(env);
+    let mut boosts = storage::get_epoch_boosts(env, epoch);
+    let entry = boosts
+        .iter()
+        .position(|boost| boost.sponsor == *sponsor && boost.token == pool_token);
+    match entry {
+        Some(index) => {
+            let mut boost = boosts.get_unchecked(index as u32);
+            boost.amount = boost
+                .amount
+                .checked_add(pool_amount)
+                .ok_or(Error::OverflowError)?;
+            boosts.set(index as u32, boost);
+        }
+        None => {
+            if boosts.len() >= MAX_BOOSTS_PER_EPOCH {
+                return Err(ExtError::BoostListFull.into());
+            }
+            boosts.push_back(EpochBoost {
+                sponsor: sponsor.clone(),
+                token: pool_token.clone(),
+                amount: pool_amount,
+            });
+        }
+    }
+    storage::set_epoch_boosts(env, epoch, &boosts);
+
+    if pool_token == config.usdc_token {
+        let pending = storage::get_pending_rewards(env)
+            .checked_add(pool_amount)
+            .ok_or(Error::OverflowError)?;
+        storage::set_pending_rewards(env, pending);
+    } else {
+        crate::reward_tokens::add_pending(env, &pool_token, pool_amount)?;
+    }
+
+    emit_epoch_boosted(env, sponsor, epoch, token, amount, pool_amount);
+
+    Ok(pool_amount)
+}
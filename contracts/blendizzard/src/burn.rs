@@ -0,0 +1,77 @@
+use soroban_sdk::{token, Env};
+
+use crate::errors::Error;
+use crate::events::emit_blnd_burned;
+use crate::math::{checked_bps, Rounding};
+use crate::storage;
+use crate::types::Config;
+
+// ============================================================================
+// BLND Burn
+// ============================================================================
+//
+// With `BlndBurn::bps` set, every claim of yield (`cycle_epoch` or `harvest`)
+// burns that share of the BLND it newly claimed instead of swapping it, as a
+// deflation lever. BLND carried over from skipped swaps, retained for players
+// or reserved for claims is never burned. With a burn address the BLND is sent
+// there; otherwise it is destroyed with the token's `burn`.
+//
+// Burns are recorded per epoch (`get_epoch_blnd_burned`) and in total (see
+// `get_global_stats`).
+
+/// Burn the configured share of newly claimed BLND
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (BLND token)
+/// * `epoch` - Epoch whose yield was claimed
+/// * `claimed` - BLND claimed by this call
+/// * `available` - Swappable BLND held (caps the burn)
+///
+/// # Returns
+/// BLND burned (to deduct from the BLND swapped)
+///
+/// # Errors
+/// * `OverflowError` - If a burn total overflows
+pub(crate) fn burn_claimed_blnd(
+    env: &Env,
+    config: &Config,
+    epoch: u32,
+    claimed: i128,
+    available: i128,
+) -> Result<i128, Error> {
+    let mut burn = storage::get_blnd_burn(env);
+    let burned = burn_share(burn.bps, claimed).min(available);
+    if burned <= 0 {
+        return Ok(0);
+    }
+
+    let blnd_client = token::Client::new(env, &config.blnd_token);
+    let current_contract = env.current_contract_address();
+    match &burn.burn_address {
+        Some(burn_address) => blnd_client.transfer(&current_contract, burn_address, &burned),
+        None => blnd_client.burn(&current_contract, &burned),
+    }
+
+    burn.total_burned = burn
+        .total_burned
+        .checked_add(burned)
+        .ok_or(Error::OverflowError)?;
+    storage::set_blnd_burn(env, &burn);
+    let epoch_burned = storage::get_epoch_blnd_burned(env, epoch)
+        .checked_add(burned)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_blnd_burned(env, epoch, epoch_burned);
+
+    emit_blnd_burned(env, epoch, burned);
+
+    Ok(burned)
+}
+
+/// Share of `claimed` BLND burned at `bps`
+pub(crate) fn burn_share(bps: u32, claimed: i128) -> i128 {
+    if bps == 0 || claimed <= 0 {
+        return 0;
+    }
+    checked_bps(claimed, bps, Rounding::Floor).unwrap_or(0)
+}
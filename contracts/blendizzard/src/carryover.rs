@@ -0,0 +1,161 @@
+use soroban_sdk::Env;
+
+use crate::errors::Error;
+use crate::events::emit_expired_rewards_swept;
+use crate::storage;
+use crate::types::Config;
+
+// ============================================================================
+// Reward Carryover
+// ============================================================================
+//
+// Two kinds of USDC/BLND would otherwise sit idle in the contract balance:
+//
+// - BLND whose swap was skipped (oracle check failed or the swap reverted).
+//   It is tracked in `CarryoverBlnd` and swapped with the next cycle's BLND
+//   (see `epoch::withdraw_and_convert_rewards`).
+// - Player and developer rewards left unclaimed after `claim_window_epochs`.
+//   Each cycle marks those epochs as expired and adds their unclaimed
+//   remainder (USDC and retained BLND) to the reward pools being finalized.
+
+/// Maximum number of epochs checked for expiry in one cycle
+/// (bounds the read budget after enabling the window on an old contract)
+const MAX_SWEPT_EPOCHS_PER_CYCLE: u32 = 16;
+
+/// Reject claims against an epoch whose unclaimed rewards were carried over
+///
+/// # Errors
+/// * `ClaimWindowExpired` - If the epoch's claim window has passed
+pub(crate) fn require_claimable(env: &Env, epoch: u32) -> Result<(), Error> {
+    if storage::get_epoch_payouts(env, epoch).expired {
+        return Err(Error::ClaimWindowExpired);
+    }
+    Ok(())
+}
+
+/// Record a claim paid out of an epoch's rewards
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch the reward is claimed from
+/// * `amount` - Gross USDC amount claimed (before any referral share)
+pub(crate) fn record_claim(env: &Env, epoch: u32, amount: i128) -> Result<(), Error> {
+    let mut payouts = storage::get_epoch_payouts(env, epoch);
+    payouts.total_claimed = payouts
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_payouts(env, epoch, &payouts);
+    crate::stats::record_reward_paid(env, amount)
+}
+
+/// Record a claim paid out of an epoch's player reward pool
+///
+/// Same as `record_claim`, and also tracks the player-pool total that a
+/// later Merkle root must account for (see `merkle::set_merkle_root`).
+pub(crate) fn record_player_claim(env: &Env, epoch: u32, amount: i128) -> Result<(), Error> {
+    record_claim(env, epoch, amount)?;
+    let mut payouts = storage::get_epoch_payouts(env, epoch);
+    payouts.player_claimed = payouts
+        .player_claimed
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_payouts(env, epoch, &payouts);
+    Ok(())
+}
+
+/// Record a BLND claim paid out of an epoch's `reward_pool_blnd`
+pub(crate) fn record_blnd_claim(env: &Env, epoch: u32, amount: i128) -> Result<(), Error> {
+    let mut payouts = storage::get_epoch_payouts(env, epoch);
+    payouts.blnd_claimed = payouts
+        .blnd_claimed
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_payouts(env, epoch, &payouts);
+    crate::stats::record_blnd_paid(env, amount)
+}
+
+/// Expire epochs whose claim window has passed and collect their unclaimed rewards
+///
+/// An epoch `e` expires once the new current epoch is past `e + claim_window_epochs`.
+/// The epoch being finalized in this cycle is never expired, so every epoch is
+/// claimable for at least one full epoch.
+///
+/// Epochs whose data already left temporary storage are skipped (their rewards
+/// can no longer be computed).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (claim window)
+/// * `closed_epoch` - Epoch being finalized in this cycle
+/// * `next_epoch` - Epoch opened by this cycle
+///
+/// # Returns
+/// Total unclaimed (USDC, BLND) to add to the closed epoch's reward pools
+pub(crate) fn sweep_expired_rewards(
+    env: &Env,
+    config: &Config,
+    closed_epoch: u32,
+    next_epoch: u32,
+) -> Result<(i128, i128), Error> {
+    // Epoch 0 has no earlier epochs to expire
+    if config.claim_window_epochs == 0 || closed_epoch == 0 {
+        return Ok((0, 0));
+    }
+
+    // Epochs up to `last_expired` are out of their window
+    let Some(last_expired) = next_epoch.checked_sub(config.claim_window_epochs.saturating_add(1))
+    else {
+        return Ok((0, 0));
+    };
+    let last_expired = last_expired.min(closed_epoch - 1);
+
+    let first = storage::get_next_sweep_epoch(env);
+    if first > last_expired {
+        return Ok((0, 0));
+    }
+    let last = last_expired.min(first + MAX_SWEPT_EPOCHS_PER_CYCLE - 1);
+
+    let mut total_swept: i128 = 0;
+    let mut total_swept_blnd: i128 = 0;
+    for epoch in first..=last {
+        let Some(epoch_info) = storage::get_epoch(env, epoch) else {
+            continue;
+        };
+
+        let mut payouts = storage::get_epoch_payouts(env, epoch);
+        if payouts.expired {
+            continue;
+        }
+
+        let unclaimed = epoch_info
+            .reward_pool
+            .checked_add(epoch_info.dev_reward_pool)
+            .ok_or(Error::OverflowError)?
+            .saturating_sub(payouts.total_claimed)
+            .max(0);
+
+        let unclaimed_blnd = epoch_info
+            .reward_pool_blnd
+            .saturating_sub(payouts.blnd_claimed)
+            .max(0);
+
+        payouts.expired = true;
+        storage::set_epoch_payouts(env, epoch, &payouts);
+        crate::reward_tokens::sweep_expired(env, epoch)?;
+
+        if unclaimed > 0 {
+            total_swept = total_swept
+                .checked_add(unclaimed)
+                .ok_or(Error::OverflowError)?;
+            emit_expired_rewards_swept(env, epoch, closed_epoch, unclaimed);
+        }
+        total_swept_blnd = total_swept_blnd
+            .checked_add(unclaimed_blnd)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    storage::set_next_sweep_epoch(env, last + 1);
+
+    Ok((total_swept, total_swept_blnd))
+}
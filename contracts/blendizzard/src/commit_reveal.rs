@@ -0,0 +1,212 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_move_committed, emit_move_revealed, emit_reveal_timeout_claimed};
+use crate::storage;
+use crate::types::{GameSession, MoveCommits};
+
+// ============================================================================
+// Commit-Reveal Moves
+// ============================================================================
+//
+// Optional helper for games resolved fully on-chain (rock-paper-scissors and
+// similar). Each player of a 1v1 session commits to a move with
+// `sha256(move as 4 big-endian bytes || salt)`, then reveals it once both have
+// committed. The game contract reads the revealed moves with `get_moves`,
+// applies its own rules and reports the outcome through `end_game` as usual.
+//
+// If only one player reveals before the deadline, anyone can settle the
+// session in their favor with `claim_reveal_timeout`: the non-revealer's wager
+// is burned like any other loss.
+
+/// Time both players have to reveal once the second commitment lands (seconds)
+pub(crate) const REVEAL_WINDOW_SECONDS: u64 = 3600;
+
+/// Commit to a move for a session
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - Pending 1v1 session
+/// * `player` - Committing player (must be one of the session's players)
+/// * `commitment` - `sha256(move || salt)`
+///
+/// # Errors
+/// * `SessionNotFound` - If session doesn't exist
+/// * `SessionAlreadyFinalized` - If the session already has an outcome
+/// * `GameExpired` - If the session is from a previous epoch
+/// * `PlayerNotFound` - If player is not in the session
+/// * `SeriesNotSupported` - If the session is a series
+/// * `MoveAlreadyCommitted` - If the player already committed
+pub(crate) fn commit_move(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    commitment: &BytesN<32>,
+) -> Result<(), ContractError> {
+    player.require_auth();
+
+    let session = get_pending_session(env, session_id)?;
+    let is_player1 = player_slot(&session, player)?;
+
+    let mut moves = storage::get_moves(env, session_id).unwrap_or(MoveCommits {
+        player1_commit: None,
+        player2_commit: None,
+        player1_move: None,
+        player2_move: None,
+        reveal_deadline: 0,
+    });
+
+    let slot = if is_player1 {
+        &mut moves.player1_commit
+    } else {
+        &mut moves.player2_commit
+    };
+    if slot.is_some() {
+        return Err(ExtError::MoveAlreadyCommitted.into());
+    }
+    *slot = Some(commitment.clone());
+
+    // Both committed: open the reveal window
+    if moves.player1_commit.is_some() && moves.player2_commit.is_some() {
+        moves.reveal_deadline = env
+            .ledger()
+            .timestamp()
+            .saturating_add(REVEAL_WINDOW_SECONDS);
+    }
+    storage::set_moves(env, session_id, &moves);
+
+    emit_move_committed(env, session_id, player, commitment);
+
+    Ok(())
+}
+
+/// Reveal a committed move
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - Session the move was committed for
+/// * `player` - Revealing player
+/// * `player_move` - The committed move
+/// * `salt` - The salt used in the commitment
+///
+/// # Returns
+/// Updated move state
+///
+/// # Errors
+/// * `CommitmentMismatch` - If the move and salt don't match the commitment
+/// * `RevealNotOpen` - If both players haven't committed yet
+/// * `RevealWindowClosed` - If the reveal window has closed
+/// * `MoveAlreadyRevealed` - If the player already revealed
+/// * Same as `commit_move`
+pub(crate) fn reveal_move(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    player_move: u32,
+    salt: &BytesN<32>,
+) -> Result<MoveCommits, ContractError> {
+    player.require_auth();
+
+    let session = get_pending_session(env, session_id)?;
+    let is_player1 = player_slot(&session, player)?;
+
+    let mut moves = storage::get_moves(env, session_id).ok_or(ExtError::RevealNotOpen)?;
+    if moves.reveal_deadline == 0 {
+        return Err(ExtError::RevealNotOpen.into());
+    }
+    if env.ledger().timestamp() > moves.reveal_deadline {
+        return Err(ExtError::RevealWindowClosed.into());
+    }
+
+    let (commitment, revealed) = if is_player1 {
+        (&moves.player1_commit, &mut moves.player1_move)
+    } else {
+        (&moves.player2_commit, &mut moves.player2_move)
+    };
+    if revealed.is_some() {
+        return Err(ExtError::MoveAlreadyRevealed.into());
+    }
+    if commitment.as_ref() != Some(&move_commitment(env, player_move, salt)) {
+        return Err(ExtError::CommitmentMismatch.into());
+    }
+    *revealed = Some(player_move);
+    storage::set_moves(env, session_id, &moves);
+
+    emit_move_revealed(env, session_id, player, player_move);
+
+    Ok(moves)
+}
+
+/// Settle a session whose opponent failed to reveal in time
+///
+/// Callable by anyone once the reveal window has closed with exactly one move
+/// revealed. The revealing player wins.
+///
+/// # Returns
+/// true if player1 won, false if player2 won
+///
+/// # Errors
+/// * `RevealNotOpen` - If both players haven't committed yet
+/// * `RevealWindowOpen` - If the reveal window is still open
+/// * `RevealTimeoutNotClaimable` - If not exactly one player revealed
+/// * Same as `commit_move`
+pub(crate) fn claim_reveal_timeout(env: &Env, session_id: u32) -> Result<bool, ContractError> {
+    let session = get_pending_session(env, session_id)?;
+    let moves = storage::get_moves(env, session_id).ok_or(ExtError::RevealNotOpen)?;
+
+    if moves.reveal_deadline == 0 {
+        return Err(ExtError::RevealNotOpen.into());
+    }
+    if env.ledger().timestamp() <= moves.reveal_deadline {
+        return Err(ExtError::RevealWindowOpen.into());
+    }
+
+    let player1_won = match (moves.player1_move, moves.player2_move) {
+        (Some(_), None) => true,
+        (None, Some(_)) => false,
+        _ => return Err(ExtError::RevealTimeoutNotClaimable.into()),
+    };
+
+    crate::game::settle_session(env, session_id, session, player1_won)?;
+
+    emit_reveal_timeout_claimed(env, session_id, player1_won);
+
+    Ok(player1_won)
+}
+
+/// Commitment for a move: `sha256(move as 4 big-endian bytes || salt)`
+pub(crate) fn move_commitment(env: &Env, player_move: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &player_move.to_be_bytes());
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Load a pending 1v1 session of the current epoch
+fn get_pending_session(env: &Env, session_id: u32) -> Result<GameSession, ContractError> {
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    if session.player1_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized.into());
+    }
+    crate::dispute::require_no_provisional(env, session_id)?;
+    if session.epoch_id != storage::get_current_epoch(env) {
+        return Err(Error::GameExpired.into());
+    }
+    // Series are decided game by game through `report_series_game`
+    if storage::get_series(env, session_id).is_some() {
+        return Err(ExtError::SeriesNotSupported.into());
+    }
+
+    Ok(session)
+}
+
+/// true if player is the session's player1, false if player2
+fn player_slot(session: &GameSession, player: &Address) -> Result<bool, Error> {
+    if *player == session.player1 {
+        Ok(true)
+    } else if *player == session.player2 {
+        Ok(false)
+    } else {
+        Err(Error::PlayerNotFound)
+    }
+}
@@ -0,0 +1,223 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_dispute_resolved, emit_result_disputed, emit_result_provisional};
+use crate::storage;
+use crate::types::{GameSession, ProvisionalResult};
+
+// ============================================================================
+// Result Disputes
+// ============================================================================
+//
+// Games registered with a dispute window (`GameInfo::dispute_ledgers`) don't
+// settle on `end_game`. The reported outcome is stored as provisional and the
+// session stays pending (FP locked, standings, ratings and predictions
+// untouched) for that many ledgers:
+//
+// - Undisputed results are settled by anyone with `finalize_result` once
+//   the window has passed.
+// - Either player can `dispute` within the window by posting
+//   `config.dispute_bond` USDC. The arbiter then settles the session with
+//   `resolve_dispute`. If the arbiter confirms the reported outcome the
+//   dispute was frivolous and the bond goes to the next reward pool (via
+//   `PendingRewards`); otherwise it is refunded.
+//
+// Like any session, a provisional result must be settled within the epoch it
+// was played in. Disputes left unresolved past that epoch are refunded.
+// Series and team games always settle immediately.
+
+/// Store a game's reported outcome as provisional
+///
+/// Caller must have authenticated the game contract and checked the session
+/// has no outcome yet.
+///
+/// # Errors
+/// * `GameExpired` - If the session is from a previous epoch
+pub(crate) fn record_provisional(
+    env: &Env,
+    session_id: u32,
+    session: &GameSession,
+    player1_won: bool,
+    dispute_ledgers: u32,
+) -> Result<(), Error> {
+    if session.epoch_id != storage::get_current_epoch(env) {
+        return Err(Error::GameExpired);
+    }
+
+    let final_ledger = env.ledger().sequence().saturating_add(dispute_ledgers);
+    storage::set_provisional_result(
+        env,
+        session_id,
+        &ProvisionalResult {
+            player1_won,
+            final_ledger,
+            disputer: None,
+            bond: 0,
+        },
+    );
+
+    emit_result_provisional(env, session_id, player1_won, final_ledger);
+
+    Ok(())
+}
+
+/// Reject actions on a session whose outcome was already reported
+///
+/// # Errors
+/// * `SessionAlreadyFinalized` - If the session has a provisional result
+pub(crate) fn require_no_provisional(env: &Env, session_id: u32) -> Result<(), Error> {
+    if storage::has_provisional_result(env, session_id) {
+        return Err(Error::SessionAlreadyFinalized);
+    }
+    Ok(())
+}
+
+/// Dispute a provisional result
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Disputing player (must be one of the session's players)
+/// * `session_id` - Session with a provisional result
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If no arbiter is set
+/// * `SessionNotFound` - If the session doesn't exist
+/// * `PlayerNotFound` - If the caller is not one of the session's players
+/// * `NoProvisionalResult` - If the session has no provisional result
+/// * `ResultAlreadyDisputed` - If the result was already disputed
+/// * `DisputeWindowClosed` - If the dispute window has passed
+pub(crate) fn dispute(env: &Env, player: &Address, session_id: u32) -> Result<(), ContractError> {
+    player.require_auth();
+
+    if storage::get_arbiter(env).is_none() {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    if *player != session.player1 && *player != session.player2 {
+        return Err(Error::PlayerNotFound.into());
+    }
+
+    let mut result =
+        storage::get_provisional_result(env, session_id).ok_or(ExtError::NoProvisionalResult)?;
+    if result.disputer.is_some() {
+        return Err(ExtError::ResultAlreadyDisputed.into());
+    }
+    if env.ledger().sequence() > result.final_ledger {
+        return Err(ExtError::DisputeWindowClosed.into());
+    }
+
+    let config = storage::get_config(env);
+    let bond = config.dispute_bond;
+    if bond > 0 {
+        token::Client::new(env, &config.usdc_token).transfer(
+            player,
+            &env.current_contract_address(),
+            &bond,
+        );
+    }
+
+    result.disputer = Some(player.clone());
+    result.bond = bond;
+    storage::set_provisional_result(env, session_id, &result);
+
+    emit_result_disputed(env, session_id, player, bond);
+
+    Ok(())
+}
+
+/// Settle an undisputed provisional result once its dispute window has passed
+///
+/// Permissionless: the outcome was already authorized by the game contract.
+///
+/// # Errors
+/// * `SessionNotFound` - If the session doesn't exist
+/// * `NoProvisionalResult` - If the session has no provisional result
+/// * `ResultDisputed` - If the result is disputed (the arbiter settles it)
+/// * `DisputeWindowOpen` - If the dispute window is still open
+/// * `GameExpired` - If the session is from a previous epoch
+pub(crate) fn finalize_result(env: &Env, session_id: u32) -> Result<(), ContractError> {
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    let result =
+        storage::get_provisional_result(env, session_id).ok_or(ExtError::NoProvisionalResult)?;
+    if result.disputer.is_some() {
+        return Err(ExtError::ResultDisputed.into());
+    }
+    if env.ledger().sequence() <= result.final_ledger {
+        return Err(ExtError::DisputeWindowOpen.into());
+    }
+
+    storage::remove_provisional_result(env, session_id);
+    crate::game::settle_session(env, session_id, session, result.player1_won)?;
+    Ok(())
+}
+
+/// Resolve a disputed result as the arbiter
+///
+/// Settles the session with the arbiter's outcome. The disputer's bond is
+/// slashed into the pending reward pool if the reported outcome is confirmed,
+/// and refunded otherwise. If the session's epoch has already ended the bond
+/// is refunded and the session is left unsettled (it expired).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - Disputed session
+/// * `player1_won` - Arbiter's outcome (true = player1 won)
+///
+/// # Returns
+/// true if the disputer's bond was slashed
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If no arbiter is set
+/// * `SessionNotFound` - If the session doesn't exist
+/// * `NoProvisionalResult` - If the session has no provisional result
+/// * `ResultNotDisputed` - If the result isn't disputed
+pub(crate) fn resolve_dispute(
+    env: &Env,
+    session_id: u32,
+    player1_won: bool,
+) -> Result<bool, ContractError> {
+    let arbiter = storage::get_arbiter(env).ok_or(Error::FeatureNotConfigured)?;
+    arbiter.require_auth();
+
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    let result =
+        storage::get_provisional_result(env, session_id).ok_or(ExtError::NoProvisionalResult)?;
+    let disputer = result.disputer.ok_or(ExtError::ResultNotDisputed)?;
+
+    storage::remove_provisional_result(env, session_id);
+
+    let expired = session.epoch_id != storage::get_current_epoch(env);
+    let slashed = !expired && player1_won == result.player1_won;
+
+    if result.bond > 0 {
+        if slashed {
+            let pending = storage::get_pending_rewards(env)
+                .checked_add(result.bond)
+                .ok_or(Error::OverflowError)?;
+            storage::set_pending_rewards(env, pending);
+        } else {
+            let config = storage::get_config(env);
+            token::Client::new(env, &config.usdc_token).transfer(
+                &env.current_contract_address(),
+                &disputer,
+                &result.bond,
+            );
+        }
+    }
+
+    if !expired {
+        crate::game::settle_session(env, session_id, session, player1_won)?;
+    }
+
+    crate::audit::record(
+        env,
+        &arbiter,
+        "resolve_dispute",
+        &(session_id, result.player1_won),
+        &(session_id, player1_won),
+    );
+    emit_dispute_resolved(env, session_id, &arbiter, player1_won, slashed);
+
+    Ok(slashed)
+}
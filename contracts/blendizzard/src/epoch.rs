@@ -1,16 +1,17 @@
-use soroban_sdk::{
-    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
-    token, vec, Address, Env, IntoVal, Map, Symbol, Vec,
-};
-
-use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{token, Address, Env, Map, Vec};
 
-use crate::errors::Error;
-use crate::events::emit_epoch_cycled;
-use crate::fee_vault_v2::Client as FeeVaultClient;
-use crate::router::Client as SoroswapRouterClient;
+use crate::errors::{ContractError, Error};
+use crate::events::{
+    emit_carryover_blnd_swapped, emit_epoch_aborted, emit_epoch_cycled, emit_epochs_skipped,
+    emit_rewards_harvested, emit_swap_skipped,
+};
+use crate::math::{checked_bps, checked_mul_scalar_7, Rounding};
 use crate::storage;
-use crate::types::{EpochInfo, SCALAR_7};
+use crate::swap;
+use crate::types::{
+    Config, CycleProgress, CycleStage, DistributionMode, EpochInfo, EpochPhase, EpochSchedule,
+    PendingYield,
+};
 
 // ============================================================================
 // Epoch Management
@@ -22,96 +23,367 @@ use crate::types::{EpochInfo, SCALAR_7};
 /// "Close current epoch, decide faction winner for closed epoch, lock in claimable
 ///  rewards by contributed faction points, open next epoch"
 ///
+/// Runs both stages in one call: `finalize_epoch` closes the current epoch and
+/// opens the next one, then `process_rewards` collects and distributes the
+/// closed epoch's rewards.
+///
+/// Resumable: if a closed epoch still awaits `process_rewards` (its cycle was
+/// left unfinished by `finalize_epoch` or `cycle_epoch_step` calls; a call
+/// that runs out of budget commits nothing), this finishes it from the last
+/// completed stage instead of failing, and returns the epoch already open.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `keeper` - Bonded keeper cycling (already authenticated), or None for a
+///   public call
+///
+/// # Returns
+/// The new epoch number
+///
+/// # Errors
+/// * Same errors as `finalize_epoch` and `process_rewards`
+pub(crate) fn cycle_epoch(env: &Env, keeper: Option<&Address>) -> Result<u32, ContractError> {
+    if let Some(closed_epoch_num) = storage::get_unprocessed_epoch(env) {
+        process_rewards(env, closed_epoch_num, keeper)?;
+        return Ok(storage::get_current_epoch(env));
+    }
+
+    let closed_epoch_num = storage::get_current_epoch(env);
+    let next_epoch_num = finalize_epoch(env, keeper)?;
+    process_rewards(env, closed_epoch_num, keeper)?;
+    Ok(next_epoch_num)
+}
+
+/// Run the next unfinished stage of the epoch cycle
+///
+/// Stages, in order: finalize (`finalize_epoch`), claim the vault yield, swap
+/// BLND to USDC, then distribute (the rest of `process_rewards`). Each call
+/// runs and commits one stage, so a cycle too costly for one transaction can
+/// be completed over several; `cycle_epoch` resumes from the same progress.
+///
+/// # Returns
+/// The stage this call ran
+///
+/// # Errors
+/// * Same errors as `finalize_epoch` (finalize stage) and `process_rewards`
+pub(crate) fn cycle_epoch_step(env: &Env) -> Result<CycleStage, ContractError> {
+    let Some(closed_epoch_num) = storage::get_unprocessed_epoch(env) else {
+        finalize_epoch(env, None)?;
+        return Ok(CycleStage::Finalize);
+    };
+
+    let mut progress = storage::get_cycle_progress(env, closed_epoch_num);
+    if !progress.claimed {
+        claim_stage(env, &mut CycleReads::load(env), &mut progress)?;
+        storage::set_cycle_progress(env, closed_epoch_num, &progress);
+        Ok(CycleStage::Claim)
+    } else if !progress.swapped {
+        swap_stage(
+            env,
+            &mut CycleReads::load(env),
+            closed_epoch_num,
+            &mut progress,
+        )?;
+        storage::set_cycle_progress(env, closed_epoch_num, &progress);
+        Ok(CycleStage::Swap)
+    } else {
+        process_rewards(env, closed_epoch_num, None)?;
+        Ok(CycleStage::Distribute)
+    }
+}
+
+/// Close the current epoch and open the next one (first cycle stage)
+///
 /// Process:
 /// 1. Validate current epoch is ready to cycle (time has passed)
-/// 2. Finalize current epoch:
-///    a. Determine winning faction (highest total fp)
-///    b. Withdraw BLND from fee-vault admin balance
-///    c. Convert BLND -> USDC via Soroswap
-///    d. Set reward_pool to USDC amount
-/// 3. Create next epoch
+/// 2. Snapshot the current epoch: its standings are frozen, since sessions of a
+///    closed epoch can't be settled
+/// 3. Apply the epoch's parameter votes (`governance.rs`) and an epoch duration
+///    scheduled with `set_next_epoch_duration`
+/// 4. Fast-forward over any epochs nobody cycled (stored as empty, finalized epochs)
+/// 5. Create next epoch, aligned to the `end_time + k * epoch_duration` schedule
+///
+/// The closed epoch stays unfinalized (no winner, nothing claimable) until
+/// `process_rewards` runs for it. Only one epoch can await reward processing:
+/// the next epoch can't be closed before it is processed.
 ///
 /// # Arguments
 /// * `env` - Contract environment
+/// * `keeper` - Bonded keeper cycling (already authenticated), or None for a
+///   public call
 ///
 /// # Returns
 /// The new epoch number
 ///
 /// # Errors
 /// * `EpochNotReady` - If not enough time has passed
+/// * `KeeperPriorityWindow` - If a public call falls inside the keeper priority window
 /// * `EpochAlreadyFinalized` - If current epoch is already finalized
-/// * `FeeVaultError` - If fee-vault withdrawal fails
-/// * `SwapError` - If BLND � USDC swap fails
-pub(crate) fn cycle_epoch(env: &Env) -> Result<u32, Error> {
+/// * `EpochNotFinalized` - If a closed epoch still awaits `process_rewards`
+pub(crate) fn finalize_epoch(env: &Env, keeper: Option<&Address>) -> Result<u32, ContractError> {
+    if storage::get_unprocessed_epoch(env).is_some() {
+        return Err(Error::EpochNotFinalized.into());
+    }
+
     let current_epoch_num = storage::get_current_epoch(env);
 
     // Get current epoch info
-    let mut current_epoch =
+    let current_epoch =
         storage::get_epoch(env, current_epoch_num).ok_or(Error::EpochNotFinalized)?;
 
     // Check if already finalized
     if current_epoch.is_finalized {
-        return Err(Error::EpochAlreadyFinalized);
+        return Err(Error::EpochAlreadyFinalized.into());
     }
 
     // Check if enough time has passed
-    let current_time = env.ledger().timestamp();
+    let current_time = crate::epoch_clock::now(env);
     if current_time < current_epoch.end_time {
-        return Err(Error::EpochNotReady);
+        return Err(Error::EpochNotReady.into());
+    }
+    crate::keeper::check_cycle_access(env, keeper, current_epoch.end_time)?;
+
+    let mut config = storage::get_config(env);
+    crate::governance::apply_votes(
+        env,
+        &mut config,
+        current_epoch_num,
+        &crate::governance::OPENING_PARAMETERS,
+    );
+    apply_next_epoch_duration(env, &mut config);
+
+    // Fast-forward over epoch durations that fully elapsed without a cycle,
+    // so epoch boundaries stay on the advertised wall-clock schedule
+    let skipped = (current_time - current_epoch.end_time)
+        .checked_div(config.epoch_duration)
+        .unwrap_or(0) as u32;
+    let next_epoch_num = current_epoch_num + skipped + 1;
+    let next_start_time = current_epoch.end_time + skipped as u64 * config.epoch_duration;
+    if skipped > 0 {
+        fast_forward_epochs(
+            env,
+            current_epoch_num,
+            current_epoch.end_time,
+            skipped,
+            &config,
+        );
+    }
+
+    // Create next epoch
+    let next_epoch = open_epoch(
+        env,
+        &config,
+        next_start_time,
+        crate::underdog::compute_multipliers(env, &config, &current_epoch.faction_stakes),
+    );
+
+    storage::set_epoch(env, next_epoch_num, &next_epoch);
+    storage::set_current_epoch(env, next_epoch_num);
+    storage::set_last_cycle_ledger(env);
+    crate::reward_cap::open_epoch(env, next_epoch_num);
+    storage::set_unprocessed_epoch(env, Some(current_epoch_num));
+    storage::set_cycle_progress(
+        env,
+        current_epoch_num,
+        &CycleProgress {
+            finalized: true,
+            claimed: false,
+            claimed_blnd: 0,
+            swapped: false,
+        },
+    );
+
+    Ok(next_epoch_num)
+}
+
+/// Collect and distribute a closed epoch's rewards (second cycle stage)
+///
+/// Process:
+/// 1. Determine winning faction (highest total fp)
+/// 2. Withdraw BLND from fee-vault admin balance
+/// 3. Convert BLND -> USDC via Soroswap (BLND carries over if the swap is skipped;
+///    `blnd_retention_bps` of it is kept as BLND and paid out alongside USDC)
+/// 4. Add USDC harvested earlier (see `harvest`)
+/// 5. Route the winning faction's game rakes (see `rake.rs`)
+/// 6. Pay `faction_treasury_share` of the player pool into faction treasuries
+/// 7. Settle the jackpot and carry over unclaimed USDC from epochs whose claim
+///    window has passed
+/// 8. Pay the keeper bounty (when cycled by a bonded keeper)
+/// 9. Set reward_pool to USDC amount and finalize the epoch
+///
+/// All yield withdrawn in this call goes to the closed epoch: it is the only one
+/// with contributions, since games can't be played in skipped epochs.
+///
+/// Calling it again for an epoch that was already processed changes nothing
+/// and returns its reward pool. The claim and swap stages aren't repeated if
+/// `cycle_epoch_step` already committed them (see `CycleProgress`). The config
+/// and this contract's token balances are read once for all stages (see
+/// `CycleReads`).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch closed by `finalize_epoch`
+/// * `keeper` - Bonded keeper cycling (already authenticated), or None for a
+///   public call
+///
+/// # Returns
+/// The player reward pool of the epoch
+///
+/// # Errors
+/// * `EpochNotFinalized` - If the epoch wasn't closed by `finalize_epoch`
+///
+/// A failed yield claim or BLND → USDC swap doesn't fail processing: the epoch
+/// is finalized with only harvested USDC, and unswapped BLND is banked as
+/// carryover (`swap_skipped`) for the next `harvest` or `cycle_epoch` to convert.
+pub(crate) fn process_rewards(
+    env: &Env,
+    epoch: u32,
+    keeper: Option<&Address>,
+) -> Result<i128, Error> {
+    let current_epoch_num = epoch;
+    let mut current_epoch =
+        storage::get_epoch(env, current_epoch_num).ok_or(Error::EpochNotFinalized)?;
+    if storage::get_unprocessed_epoch(env) != Some(current_epoch_num) {
+        if current_epoch.is_finalized {
+            return Ok(current_epoch.reward_pool);
+        }
+        return Err(Error::EpochNotFinalized);
     }
 
+    // The epoch after the closed one (skipped epochs included)
+    let next_epoch_num = storage::get_current_epoch(env);
+
     // Determine winning faction (faction with highest total fp)
     let winning_faction = determine_winning_faction(&current_epoch)?;
 
     // SECURITY FIX: Withdraw BLND from fee-vault and convert to USDC
-    // Make swap failures non-fatal to prevent epoch cycling DoS
-    // If swap fails, epoch still cycles but reward_pool is 0
-    let total_reward_pool = match withdraw_and_convert_rewards(env) {
-        Ok(amount) => amount,
-        Err(_) => {
-            // Swap failed but we must continue cycling to prevent protocol freeze
-            // This could happen due to:
-            // - Insufficient Soroswap liquidity
-            // - Soroswap contract issues
-            // - Price impact too high
-            // Reward pool will be 0 for this epoch
-            0
-        }
-    };
+    // Claim and swap failures are non-fatal to prevent epoch cycling DoS;
+    // both stages add their USDC to the pending buffer and are skipped if an
+    // earlier call already committed them. Their progress isn't stored: it's
+    // removed below in the same call.
+    let mut reads = CycleReads::load(env);
+    let mut progress = storage::get_cycle_progress(env, current_epoch_num);
+    claim_stage(env, &mut reads, &mut progress)?;
+    swap_stage(env, &mut reads, current_epoch_num, &mut progress)?;
+
+    // The pending buffer holds the claimed and swapped USDC plus USDC
+    // harvested mid-epoch
+    let total_reward_pool = storage::get_pending_rewards(env);
+    storage::set_pending_rewards(env, 0);
 
     // Split reward pool between developers and players
     // dev_reward_pool = total_reward_pool * dev_reward_share
     // player_reward_pool = total_reward_pool - dev_reward_pool
-    let config = storage::get_config(env);
-    let dev_reward_pool = total_reward_pool
-        .fixed_mul_floor(config.dev_reward_share, SCALAR_7)
-        .unwrap_or(0);
+    let mut config = reads.config;
+    let dev_reward_pool =
+        checked_mul_scalar_7(total_reward_pool, config.dev_reward_share, Rounding::Floor)
+            .unwrap_or(0);
     let player_reward_pool = total_reward_pool.saturating_sub(dev_reward_pool);
 
-    // Finalize current epoch
+    // A voted developer share applies from the next epoch's rewards on
+    crate::governance::apply_votes(
+        env,
+        &mut config,
+        current_epoch_num,
+        &crate::governance::REWARD_PARAMETERS,
+    );
+
+    // Game rakes take every faction's raked share of the player pool
+    let raked = crate::rake::route_rakes(
+        env,
+        &config,
+        current_epoch_num,
+        &current_epoch,
+        winning_faction,
+        player_reward_pool,
+    )?;
+    let player_reward_pool = player_reward_pool - raked;
+
+    // Faction treasuries take their share of this epoch's new rewards
+    let treasury_share = crate::treasury::fund_treasuries(
+        env,
+        &config,
+        current_epoch_num,
+        player_reward_pool,
+        &current_epoch.faction_standings,
+    )?;
+    let player_reward_pool = player_reward_pool - treasury_share;
+
+    // The jackpot takes its share of this epoch's new rewards, and pays out
+    // to the winning faction every `interval` epochs
+    let player_reward_pool = crate::jackpot::settle_jackpot(
+        env,
+        current_epoch_num,
+        next_epoch_num,
+        &current_epoch,
+        winning_faction,
+        player_reward_pool,
+    )?;
+
+    // Unclaimed rewards of expired epochs go to players only (the developer
+    // share was already taken when they were first distributed)
+    let (expired_rewards, expired_blnd) =
+        crate::carryover::sweep_expired_rewards(env, &config, current_epoch_num, next_epoch_num)?;
+    let player_reward_pool = player_reward_pool
+        .checked_add(expired_rewards)
+        .ok_or(Error::OverflowError)?;
+
+    let bounty = crate::keeper::pay_bounty(
+        env,
+        keeper,
+        current_epoch_num,
+        next_epoch_num,
+        player_reward_pool,
+    )?;
+    let player_reward_pool = player_reward_pool - bounty;
+
+    // The winning faction's top contributor takes the MVP bonus and title
+    let mvp_bonus = crate::mvp::award_mvp(
+        env,
+        &config,
+        current_epoch_num,
+        &current_epoch,
+        winning_faction,
+        player_reward_pool,
+    )?;
+    let player_reward_pool = player_reward_pool - mvp_bonus;
+
+    // Retained BLND is paid to players alongside USDC (no developer share).
+    // Expired BLND is already reserved; newly retained BLND becomes reserved.
+    let retained_blnd = storage::get_pending_blnd(env);
+    storage::set_pending_blnd(env, 0);
+    let reserved_blnd = storage::get_reserved_blnd(env)
+        .checked_add(retained_blnd)
+        .ok_or(Error::OverflowError)?;
+    storage::set_reserved_blnd(env, reserved_blnd);
+    let player_reward_pool_blnd = retained_blnd
+        .checked_add(expired_blnd)
+        .ok_or(Error::OverflowError)?;
+
+    // Donated reward tokens (and expired ones) become the epoch's token pools
+    crate::reward_tokens::open_pools(env, current_epoch_num);
+
+    // Finalize closed epoch
     current_epoch.winning_faction = Some(winning_faction);
     current_epoch.reward_pool = player_reward_pool; // Only player portion
     current_epoch.dev_reward_pool = dev_reward_pool; // Developer portion
+    current_epoch.reward_pool_blnd = player_reward_pool_blnd;
     current_epoch.is_finalized = true;
     storage::set_epoch(env, current_epoch_num, &current_epoch);
+    storage::set_unprocessed_epoch(env, None);
+    storage::remove_cycle_progress(env, current_epoch_num);
 
-    // Create next epoch
-    let next_epoch_num = current_epoch_num + 1;
-    // config already fetched above
-
-    let next_epoch = EpochInfo {
-        start_time: current_time,
-        end_time: current_time + config.epoch_duration,
-        faction_standings: Map::new(env),
-        reward_pool: 0,
-        winning_faction: None,
-        is_finalized: false,
-        total_game_fp: 0,
-        dev_reward_pool: 0,
-    };
+    // Badge the winning faction's top contributor
+    crate::achievements::record_epoch_mvp(env, &current_epoch, winning_faction);
 
-    storage::set_epoch(env, next_epoch_num, &next_epoch);
-    storage::set_current_epoch(env, next_epoch_num);
+    // Snapshot the vault backend's yield for `get_yield_stats`
+    crate::yield_stats::record_epoch_yield(
+        env,
+        &config,
+        current_epoch_num,
+        current_epoch.start_time,
+        total_reward_pool,
+    );
 
     // Emit event (report player reward pool for consistency)
     emit_epoch_cycled(
@@ -122,13 +394,279 @@ pub(crate) fn cycle_epoch(env: &Env) -> Result<u32, Error> {
         player_reward_pool,
     );
 
+    Ok(player_reward_pool)
+}
+
+/// End the current epoch immediately, with no winner and no rewards
+///
+/// Emergency unwind for when the vault backend or Soroswap is compromised
+/// mid-epoch. Nothing is withdrawn from the vault or swapped:
+/// 1. The current epoch is finalized as aborted (`EpochInfo::aborted`), with
+///    `end_time` moved to now
+/// 2. USDC harvested into `PendingRewards` stays there and funds the next
+///    epoch's reward pool instead (a closed epoch still awaiting
+///    `process_rewards` must be processed first)
+/// 3. The next epoch opens now, without underdog multipliers
+///
+/// Sessions of the aborted epoch are cancelled by the epoch change: they can
+/// no longer be settled, their spectator predictions become refundable and
+/// disputed results refund the bond when the arbiter resolves them. Wagers
+/// need no refund: they are the epoch's faction points, which the next epoch
+/// grants afresh, and the deposits behind them never leave the vault.
+///
+/// Caller must have authenticated the admin and the guardian.
+///
+/// # Returns
+/// The new epoch number
+///
+/// # Errors
+/// * `EpochNotFinalized` - If a closed epoch still awaits `process_rewards`
+/// * `EpochAlreadyFinalized` - If the current epoch is already finalized
+pub(crate) fn abort_epoch(env: &Env) -> Result<u32, Error> {
+    // Pending rewards may belong to the closed epoch, which must be paid first
+    if storage::get_unprocessed_epoch(env).is_some() {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let aborted_epoch_num = storage::get_current_epoch(env);
+    let mut aborted_epoch =
+        storage::get_epoch(env, aborted_epoch_num).ok_or(Error::EpochNotFinalized)?;
+    if aborted_epoch.is_finalized {
+        return Err(Error::EpochAlreadyFinalized);
+    }
+
+    let now = crate::epoch_clock::now(env);
+    aborted_epoch.end_time = now.max(aborted_epoch.start_time);
+    aborted_epoch.is_finalized = true;
+    aborted_epoch.aborted = true;
+    storage::set_epoch(env, aborted_epoch_num, &aborted_epoch);
+
+    // Left in PendingRewards for the next epoch's pool
+    let carried_rewards = storage::get_pending_rewards(env);
+
+    let mut config = storage::get_config(env);
+    apply_next_epoch_duration(env, &mut config);
+    let next_epoch_num = aborted_epoch_num + 1;
+    let next_epoch = open_epoch(env, &config, aborted_epoch.end_time, Map::new(env));
+    storage::set_epoch(env, next_epoch_num, &next_epoch);
+    storage::set_current_epoch(env, next_epoch_num);
+    storage::set_last_cycle_ledger(env);
+    crate::reward_cap::open_epoch(env, next_epoch_num);
+
+    emit_epoch_aborted(env, aborted_epoch_num, next_epoch_num, carried_rewards);
+
     Ok(next_epoch_num)
 }
 
+/// Get the phase of the current epoch
+///
+/// The selection phase covers the first `config.selection_phase_duration`
+/// seconds of the epoch; the rest of the epoch (including any time after
+/// `end_time` until the next cycle) is the battle phase.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (selection phase length)
+pub(crate) fn current_phase(env: &Env, config: &Config) -> EpochPhase {
+    if config.selection_phase_duration == 0 {
+        return EpochPhase::Battle;
+    }
+
+    let Some(epoch) = storage::get_epoch(env, storage::get_current_epoch(env)) else {
+        return EpochPhase::Battle;
+    };
+
+    let battle_start = epoch
+        .start_time
+        .saturating_add(config.selection_phase_duration);
+    if crate::epoch_clock::now(env) < battle_start {
+        EpochPhase::Selection
+    } else {
+        EpochPhase::Battle
+    }
+}
+
+/// Get the current epoch's timing and whether it can be cycled now
+///
+/// # Errors
+/// * `EpochNotFinalized` - If the current epoch doesn't exist
+pub(crate) fn get_epoch_schedule(env: &Env) -> Result<EpochSchedule, Error> {
+    let epoch = storage::get_current_epoch(env);
+    let info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let now = crate::epoch_clock::now(env);
+
+    let can_cycle = !info.is_finalized
+        && now >= info.end_time
+        && storage::get_unprocessed_epoch(env).is_none()
+        && !crate::keeper::in_priority_window(env, info.end_time);
+
+    Ok(EpochSchedule {
+        epoch,
+        start_time: info.start_time,
+        end_time: info.end_time,
+        seconds_remaining: info.end_time.saturating_sub(now),
+        can_cycle,
+    })
+}
+
+/// Maximum number of epochs returned by one `get_epochs` call
+const MAX_EPOCHS_PER_QUERY: u32 = 50;
+
+/// Get a range of epochs for historical views
+///
+/// The range is inclusive and clamped to the current epoch and to
+/// `MAX_EPOCHS_PER_QUERY` epochs starting at `from`. Epochs whose data already
+/// left temporary storage are skipped (use `start_time` to place results).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `from` - First epoch number
+/// * `to` - Last epoch number
+pub(crate) fn get_epochs(env: &Env, from: u32, to: u32) -> Vec<EpochInfo> {
+    let last = to
+        .min(storage::get_current_epoch(env))
+        .min(from.saturating_add(MAX_EPOCHS_PER_QUERY - 1));
+
+    let mut epochs = Vec::new(env);
+    for epoch_num in from..=last {
+        if let Some(epoch) = storage::get_epoch(env, epoch_num) {
+            epochs.push_back(epoch);
+        }
+    }
+    epochs
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Maximum number of skipped epochs written to storage in one cycle
+/// (bounds the write budget of a very late `cycle_epoch` call)
+const MAX_STORED_SKIPPED_EPOCHS: u32 = 16;
+
+/// Record epochs that elapsed without being cycled
+///
+/// Each skipped epoch is stored as an empty, finalized epoch with no rewards.
+/// If more than `MAX_STORED_SKIPPED_EPOCHS` were skipped, only the most recent
+/// ones are stored; older epoch numbers are still skipped but have no entry.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `closed_epoch` - Epoch number that was just finalized
+/// * `closed_end_time` - End time of the finalized epoch
+/// * `skipped` - Number of full epoch durations elapsed since `closed_end_time`
+/// * `config` - Contract configuration (epoch duration, distribution mode)
+fn fast_forward_epochs(
+    env: &Env,
+    closed_epoch: u32,
+    closed_end_time: u64,
+    skipped: u32,
+    config: &Config,
+) {
+    let first_skipped = closed_epoch + 1;
+    let last_skipped = closed_epoch + skipped;
+    let first_stored =
+        first_skipped.max(last_skipped.saturating_sub(MAX_STORED_SKIPPED_EPOCHS - 1));
+
+    for epoch_num in first_stored..=last_skipped {
+        let start_time =
+            closed_end_time + (epoch_num - first_skipped) as u64 * config.epoch_duration;
+        let empty_epoch = EpochInfo {
+            start_time,
+            end_time: start_time + config.epoch_duration,
+            duration: config.epoch_duration,
+            faction_standings: Map::new(env),
+            reward_pool: 0,
+            winning_faction: Some(0), // Same default as determine_winning_faction
+            is_finalized: true,
+            total_game_fp: 0,
+            dev_reward_pool: 0,
+            distribution_mode: config.distribution_mode,
+            faction_weighted_standings: Map::new(env),
+            faction_sqrt_standings: Map::new(env),
+            faction_leaders: Map::new(env),
+            faction_multipliers: Map::new(env),
+            reward_pool_blnd: 0,
+            aborted: false,
+            faction_member_counts: Map::new(env),
+            faction_stakes: Map::new(env),
+            faction_rakes: Map::new(env),
+        };
+        storage::set_epoch(env, epoch_num, &empty_epoch);
+    }
+
+    emit_epochs_skipped(env, first_skipped, last_skipped);
+}
+
+/// Schedule a new epoch duration, applied when the next epoch opens
+///
+/// The current epoch keeps its `end_time`; `cycle_epoch` (or an abort) switches
+/// `config.epoch_duration` over before laying out the following epochs.
+///
+/// # Errors
+/// * `InvalidConfig` - If the duration is 0, not longer than the selection
+///   phase, or longer than the session TTL (`max_session_ledgers`)
+pub(crate) fn schedule_epoch_duration(
+    env: &Env,
+    config: &mut Config,
+    duration: u64,
+) -> Result<(), Error> {
+    let epoch_ledgers = crate::epoch_clock::to_ledgers(env, duration);
+    if duration == 0
+        || duration <= config.selection_phase_duration
+        || (config.max_session_ledgers != 0 && epoch_ledgers > config.max_session_ledgers as u64)
+    {
+        return Err(Error::InvalidConfig);
+    }
+
+    config.next_epoch_duration = Some(duration);
+    Ok(())
+}
+
+/// Switch to the scheduled epoch duration, if any (see `schedule_epoch_duration`)
+fn apply_next_epoch_duration(env: &Env, config: &mut Config) {
+    if let Some(duration) = config.next_epoch_duration.take() {
+        config.epoch_duration = duration;
+        storage::set_config(env, config);
+    }
+}
+
+/// Build an open epoch starting at `start_time`
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (epoch duration, distribution mode)
+/// * `start_time` - Start of the epoch
+/// * `faction_multipliers` - Underdog multipliers (see `underdog::compute_multipliers`)
+fn open_epoch(
+    env: &Env,
+    config: &Config,
+    start_time: u64,
+    faction_multipliers: Map<u32, i128>,
+) -> EpochInfo {
+    EpochInfo {
+        start_time,
+        end_time: start_time + config.epoch_duration,
+        duration: config.epoch_duration,
+        faction_standings: Map::new(env),
+        reward_pool: 0,
+        winning_faction: None,
+        is_finalized: false,
+        total_game_fp: 0,
+        dev_reward_pool: 0,
+        distribution_mode: config.distribution_mode,
+        faction_weighted_standings: Map::new(env),
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers,
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    }
+}
+
 /// Determine the winning faction based on faction standings
 ///
 /// Returns the faction with the highest total fp contributed.
@@ -142,7 +680,7 @@ pub(crate) fn cycle_epoch(env: &Env) -> Result<u32, Error> {
 ///
 /// # Errors
 /// * `DivisionByZero` - If no factions have any contributions (shouldn't happen)
-fn determine_winning_faction(epoch: &EpochInfo) -> Result<u32, Error> {
+pub(crate) fn determine_winning_faction(epoch: &EpochInfo) -> Result<u32, Error> {
     let mut max_fp: i128 = 0;
     let mut winning_faction: u32 = 0;
 
@@ -159,6 +697,82 @@ fn determine_winning_faction(epoch: &EpochInfo) -> Result<u32, Error> {
     Ok(winning_faction)
 }
 
+/// Estimate the yield the next `cycle_epoch` would collect, without claiming
+///
+/// Mirrors `withdraw_and_convert_rewards`: the vault backend's admin balance,
+/// plus a router quote for the BLND it would swap (BLND held here and the
+/// estimated unclaimed emissions, less retained and reserved BLND), plus USDC
+/// already harvested. Backend or router queries that fail count as 0.
+pub(crate) fn get_pending_yield(env: &Env) -> PendingYield {
+    let config = storage::get_config(env);
+    let admin_balance = crate::vault::try_get_admin_balance(env, &config).unwrap_or(0);
+    let unclaimed_emissions = crate::vault::try_estimate_emissions(env, &config).unwrap_or(0);
+
+    let held_blnd = token::Client::new(env, &config.blnd_token)
+        .balance(&env.current_contract_address())
+        .saturating_sub(storage::get_pending_blnd(env))
+        .saturating_sub(storage::get_reserved_blnd(env))
+        .max(0);
+    let available_blnd = held_blnd.saturating_add(unclaimed_emissions);
+    let retained_blnd =
+        checked_bps(available_blnd, config.blnd_retention_bps, Rounding::Floor).unwrap_or(0);
+    let burned_blnd = crate::burn::burn_share(storage::get_blnd_burn(env).bps, unclaimed_emissions)
+        .min(available_blnd - retained_blnd);
+    let swappable_blnd = available_blnd - retained_blnd - burned_blnd;
+
+    let swap_quote = if swappable_blnd > 0 {
+        swap::quote_exact_in(
+            env,
+            &config.soroswap_router,
+            &config.blnd_token,
+            &config.usdc_token,
+            swappable_blnd,
+        )
+        .unwrap_or(0)
+    } else {
+        0
+    };
+
+    let harvested = storage::get_pending_rewards(env);
+    PendingYield {
+        admin_balance,
+        unclaimed_emissions,
+        swappable_blnd,
+        harvested,
+        estimated_rewards: admin_balance
+            .saturating_add(harvested)
+            .saturating_add(swap_quote),
+    }
+}
+
+/// Claim and swap yield mid-epoch without finalizing the epoch
+///
+/// Runs the same claim and BLND → USDC swap as `cycle_epoch` and adds the USDC
+/// to a pending-reward buffer, which the next `cycle_epoch` folds into the
+/// closed epoch's reward pool. Lets the admin spread the swap over the epoch.
+///
+/// # Returns
+/// USDC added to the buffer by this harvest (0 if the swap was skipped;
+/// the BLND then carries over as usual)
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If the vault backend is missing its pool
+pub(crate) fn harvest(env: &Env) -> Result<i128, Error> {
+    let epoch = storage::get_current_epoch(env);
+    let harvested = match withdraw_and_convert_rewards(env, epoch) {
+        Ok(amount) => amount,
+        // Nothing to swap (or swap skipped) is not a failure mid-epoch
+        Err(Error::SwapError) => 0,
+        Err(e) => return Err(e),
+    };
+
+    let pending = add_pending_rewards(env, harvested)?;
+
+    emit_rewards_harvested(env, epoch, harvested, pending);
+
+    Ok(harvested)
+}
+
 /// Withdraw BLND from fee-vault and convert to USDC
 ///
 /// From PLAN.md:
@@ -170,8 +784,14 @@ fn determine_winning_faction(epoch: &EpochInfo) -> Result<u32, Error> {
 /// 2. Get available BLND balance from fee-vault admin
 /// 3. Withdraw BLND using admin_withdraw (admin fees)
 /// 4. Claim BLND emissions from Blend pool (CRITICAL - was missing!)
+///    (DirectPool backend: steps 2-3 don't apply; emissions are claimed from the pool)
+///    (skip 5-6 if the oracle price check fails; BLND carries over)
+///    Total BLND includes any amount carried over from earlier skipped swaps,
+///    less the `blnd_retention_bps` share kept as BLND (see `PendingBlnd`) and
+///    the `BlndBurn::bps` share of newly claimed BLND burned (see `burn.rs`)
 /// 5. Authorize BLND transfer to Soroswap
 /// 6. Swap total BLND to USDC using Soroswap router
+///    (or the Soroswap Aggregator when configured, see `swap::swap_best`)
 /// 7. Calculate USDC delta (prevents over-committing rewards)
 ///
 /// # Returns
@@ -179,105 +799,274 @@ fn determine_winning_faction(epoch: &EpochInfo) -> Result<u32, Error> {
 ///
 /// # Errors
 /// * `FeeVaultError` - If fee-vault operations fail
-/// * `SwapError` - If Soroswap swap fails
-fn withdraw_and_convert_rewards(env: &Env) -> Result<i128, Error> {
-    let config = storage::get_config(env);
-    let current_contract = env.current_contract_address();
+/// * `SwapError` - If no USDC was received and no BLND was retained or burned
+fn withdraw_and_convert_rewards(env: &Env, epoch: u32) -> Result<i128, Error> {
+    let mut reads = CycleReads::load(env);
+    let (claimed_usdc, claimed_blnd) = claim_rewards(env, &mut reads)?;
+    let (swapped_usdc, blnd_kept) = convert_rewards(env, &mut reads, epoch, claimed_blnd)?;
+    let usdc_received = claimed_usdc
+        .checked_add(swapped_usdc)
+        .ok_or(Error::OverflowError)?;
+
+    if usdc_received == 0 && !blnd_kept {
+        return Err(Error::SwapError);
+    }
+
+    Ok(usdc_received)
+}
+
+/// Claim the closed epoch's vault yield into the pending reward buffer (cycle stage)
+///
+/// Does nothing if `progress` shows it already claimed. A failed claim doesn't
+/// fail the cycle: the stage completes with nothing claimed. The caller
+/// stores `progress` if the stage must survive the call.
+fn claim_stage(
+    env: &Env,
+    reads: &mut CycleReads,
+    progress: &mut CycleProgress,
+) -> Result<(), Error> {
+    if progress.claimed {
+        return Ok(());
+    }
+
+    let (claimed_usdc, claimed_blnd) = claim_rewards(env, reads).unwrap_or((0, 0));
+    add_pending_rewards(env, claimed_usdc)?;
+
+    progress.claimed = true;
+    progress.claimed_blnd = claimed_blnd;
+    Ok(())
+}
+
+/// Swap the BLND held for the closed epoch into the pending reward buffer (cycle stage)
+///
+/// Does nothing if `progress` shows it already swapped. A failed swap doesn't
+/// fail the cycle: the BLND carries over as usual. The caller stores
+/// `progress` if the stage must survive the call.
+fn swap_stage(
+    env: &Env,
+    reads: &mut CycleReads,
+    epoch: u32,
+    progress: &mut CycleProgress,
+) -> Result<(), Error> {
+    if progress.swapped {
+        return Ok(());
+    }
+
+    let (swapped_usdc, _) =
+        convert_rewards(env, reads, epoch, progress.claimed_blnd).unwrap_or((0, false));
+    add_pending_rewards(env, swapped_usdc)?;
+
+    progress.swapped = true;
+    Ok(())
+}
+
+/// State the stages of one epoch cycle (or harvest) read more than once
+///
+/// The config is read once, and this contract's USDC and BLND balances are
+/// read through: fetched on first use and reused until a call that moves
+/// tokens (`forget_balances`). The balances after the yield claim are thus
+/// also the balances before the swap.
+struct CycleReads {
+    config: Config,
+    usdc_balance: Option<i128>,
+    blnd_balance: Option<i128>,
+}
+
+impl CycleReads {
+    fn load(env: &Env) -> Self {
+        Self {
+            config: storage::get_config(env),
+            usdc_balance: None,
+            blnd_balance: None,
+        }
+    }
+
+    /// USDC held by this contract
+    fn usdc_balance(&mut self, env: &Env) -> i128 {
+        *self.usdc_balance.get_or_insert_with(|| {
+            token::Client::new(env, &self.config.usdc_token)
+                .balance(&env.current_contract_address())
+        })
+    }
+
+    /// BLND held by this contract
+    fn blnd_balance(&mut self, env: &Env) -> i128 {
+        *self.blnd_balance.get_or_insert_with(|| {
+            token::Client::new(env, &self.config.blnd_token)
+                .balance(&env.current_contract_address())
+        })
+    }
+
+    /// Drop the balances after tokens moved (they're fetched again on next use)
+    fn forget_balances(&mut self) {
+        self.usdc_balance = None;
+        self.blnd_balance = None;
+    }
+}
+
+/// Add USDC to the pending reward buffer
+///
+/// # Returns
+/// The new buffer total
+fn add_pending_rewards(env: &Env, amount: i128) -> Result<i128, Error> {
+    let pending = storage::get_pending_rewards(env)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_pending_rewards(env, pending);
+    Ok(pending)
+}
 
-    // Step 1: Capture pre-swap USDC balance
+/// Withdraw fee-vault admin fees and claim BLND emissions (steps 1-4 above)
+///
+/// # Returns
+/// (USDC received, BLND received), as balance deltas
+///
+/// # Errors
+/// * `FeeVaultError` - If fee-vault operations fail
+fn claim_rewards(env: &Env, reads: &mut CycleReads) -> Result<(i128, i128), Error> {
+    // Step 1: Capture pre-claim balances
     // Following blend-together pattern: only count delta from this operation
-    let blnd_client = token::Client::new(&env, &config.blnd_token);
-    let usdc_client = token::Client::new(env, &config.usdc_token);
-    let pre_usdc_balance = usdc_client.balance(&current_contract);
+    let pre_usdc_balance = reads.usdc_balance(env);
+    let pre_blnd_balance = reads.blnd_balance(env);
+
+    // Steps 2-4: Withdraw fee-vault admin fees and claim BLND emissions
+    // (DirectPool backend: claim the contract's own pool emissions).
+    // A failed claim may still have withdrawn the admin fees.
+    let claimed = crate::vault::claim_yield(env, &reads.config);
+    reads.forget_balances();
+    claimed?;
+    let claimed_usdc = reads.usdc_balance(env).saturating_sub(pre_usdc_balance);
+    let claimed_blnd = reads.blnd_balance(env).saturating_sub(pre_blnd_balance);
 
-    // Step 2: Get available BLND from fee-vault admin balance
-    let vault_client = FeeVaultClient::new(env, &config.fee_vault);
-    let admin_balance = vault_client.get_underlying_admin_balance();
+    Ok((claimed_usdc, claimed_blnd))
+}
 
-    // Step 3: Withdraw BLND from fee-vault admin balance (contract is admin)
-    if admin_balance > 0 {
-        vault_client.admin_withdraw(&admin_balance);
+/// Retain, burn and swap the BLND held by the contract (steps 5-7 above)
+///
+/// # Arguments
+/// * `claimed_blnd` - BLND newly claimed for this conversion (the burn applies
+///   to it only)
+///
+/// # Returns
+/// (USDC received from the swap, whether any BLND was retained or burned)
+fn convert_rewards(
+    env: &Env,
+    reads: &mut CycleReads,
+    epoch: u32,
+    claimed_blnd: i128,
+) -> Result<(i128, bool), Error> {
+    let pre_usdc_balance = reads.usdc_balance(env);
+
+    // BLND already retained for (or owed to) players is never swapped
+    let pending_blnd = storage::get_pending_blnd(env);
+    let available_blnd = reads
+        .blnd_balance(env)
+        .saturating_sub(pending_blnd)
+        .saturating_sub(storage::get_reserved_blnd(env))
+        .max(0);
+    let config = &reads.config;
+
+    // Keep `blnd_retention_bps` of it as BLND for the next epoch's BLND reward pool
+    let retained_blnd =
+        checked_bps(available_blnd, config.blnd_retention_bps, Rounding::Floor).unwrap_or(0);
+    if retained_blnd > 0 {
+        storage::set_pending_blnd(env, pending_blnd + retained_blnd);
     }
 
-    // Step 4: Claim BLND emissions from Blend pool
-    // CRITICAL: This claims BLND token emissions that accrue to the vault from the Blend pool
-    // Emissions are separate from admin fees and MUST be claimed explicitly
-    // Without this, we're leaving significant BLND rewards unclaimed!
-    vault_client.claim_emissions(&config.reserve_token_ids, &current_contract);
+    // Burn `BlndBurn::bps` of the newly claimed BLND instead of swapping it
+    let burned_blnd = crate::burn::burn_claimed_blnd(
+        env,
+        config,
+        epoch,
+        claimed_blnd,
+        available_blnd - retained_blnd,
+    )?;
 
-    let total_blnd = blnd_client.balance(&current_contract);
+    let total_blnd = available_blnd - retained_blnd - burned_blnd;
+    let carryover_blnd = storage::get_carryover_blnd(env);
 
     // Early return if no BLND available from either source
     if total_blnd > 0 {
-        // Step 5: Authorize contract to transfer BLND tokens to router
-        // Critical: Without this, the BLND token contract will reject the transfer
-        let router_client = SoroswapRouterClient::new(env, &config.soroswap_router);
-
-        // Get the router pair address for BLND/USDC liquidity pool
-        // Note: Using non-try version as generated client handles Result internally
-        let router_pair = router_client.router_pair_for(&config.blnd_token, &config.usdc_token);
-
-        // Authorize the BLND token contract to transfer from this contract to router pair
-        env.authorize_as_current_contract(vec![
+        // Skip the swap if the pair price is far below the oracle price
+        // (drained or manipulated pair), or if the swap itself reverts
+        // (e.g. no liquidity). BLND stays in the contract, is tracked as
+        // carryover, and is swapped in the next cycle with that epoch's BLND.
+        // Steps 5-6: Authorize BLND transfer and execute swap (BLND → USDC)
+        // Price is bounded by the oracle check (when configured)
+        let swapped = swap::oracle_allows_swap(
             env,
-            InvokerContractAuthEntry::Contract(SubContractInvocation {
-                context: ContractContext {
-                    contract: config.blnd_token.clone(),
-                    fn_name: Symbol::new(env, "transfer"),
-                    args: (current_contract.clone(), router_pair, total_blnd).into_val(env),
-                },
-                sub_invocations: vec![env],
-            }),
-        ]);
-
-        // Step 6: Execute swap (BLND → USDC)
-        let path: Vec<Address> = vec![env, config.blnd_token.clone(), config.usdc_token.clone()];
-        let deadline = env.ledger().timestamp() + 300; // 5 min deadline
-
-        // Execute swap (accepting any output amount)
-        // Soroban has protocol-level frontrunning protection via authorization framework
-        router_client.swap_exact_tokens_for_tokens(
-            &total_blnd,
-            &0, // No minimum - trust Soroswap pricing
-            &path,
-            &current_contract, // Send USDC to this contract
-            &deadline,
-        );
+            config,
+            &config.blnd_token,
+            &config.usdc_token,
+            total_blnd,
+        ) && swap::swap_best(
+            env,
+            config,
+            &config.blnd_token,
+            &config.usdc_token,
+            total_blnd,
+            0, // Quote already checked against oracle
+        )
+        .is_ok();
+
+        if swapped {
+            if carryover_blnd > 0 {
+                emit_carryover_blnd_swapped(env, epoch, carryover_blnd);
+            }
+            storage::set_carryover_blnd(env, 0);
+        } else {
+            storage::set_carryover_blnd(env, total_blnd);
+            emit_swap_skipped(env, epoch, total_blnd);
+        }
     }
 
     // Step 7: Calculate USDC delta (only new USDC from this swap)
     // This prevents double-counting if contract already held USDC
     // Critical for not over-committing rewards epoch-to-epoch
-    let post_usdc_balance = usdc_client.balance(&current_contract);
+    reads.forget_balances();
+    let post_usdc_balance = reads.usdc_balance(env);
     let usdc_received = post_usdc_balance.saturating_sub(pre_usdc_balance);
 
-    if usdc_received == 0 {
-        return Err(Error::SwapError);
-    }
-
-    Ok(usdc_received)
+    Ok((usdc_received, retained_blnd > 0 || burned_blnd > 0))
 }
 
 /// Initialize the first epoch (called during contract initialization)
 ///
 /// # Arguments
 /// * `env` - Contract environment
-/// * `epoch_duration` - Duration of each epoch in seconds
-pub(crate) fn initialize_first_epoch(env: &Env, epoch_duration: u64) {
-    let start_time = env.ledger().timestamp();
+/// * `epoch_duration` - Duration of each epoch on the epoch clock
+/// * `distribution_mode` - Reward distribution mode for the first epoch
+pub(crate) fn initialize_first_epoch(
+    env: &Env,
+    epoch_duration: u64,
+    distribution_mode: DistributionMode,
+) {
+    let start_time = crate::epoch_clock::now(env);
     let end_time = start_time + epoch_duration;
 
     let epoch = EpochInfo {
         start_time,
         end_time,
+        duration: epoch_duration,
         faction_standings: Map::new(env),
         reward_pool: 0,
         winning_faction: None,
         is_finalized: false,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode,
+        faction_weighted_standings: Map::new(env),
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
     };
 
     storage::set_epoch(env, 0, &epoch);
     storage::set_current_epoch(env, 0);
+    storage::set_last_cycle_ledger(env);
 }
@@ -0,0 +1,40 @@
+use soroban_sdk::Env;
+
+use crate::storage;
+use crate::types::EpochClock;
+
+// ============================================================================
+// Epoch Clock
+// ============================================================================
+//
+// Every check against an epoch boundary reads "now" from here. The clock is
+// chosen once at initialization:
+//
+// - `Timestamp`: epoch start/end times are unix timestamps and durations
+//   (epoch, selection phase, keeper priority window) are seconds.
+// - `LedgerSequence`: the same fields hold ledger sequence numbers and ledger
+//   counts, which don't drift and allow epochs of a few ledgers in tests.
+//
+// Ledger-based limits such as `max_session_ledgers` convert epoch durations
+// with `to_ledgers`, assuming `SECONDS_PER_LEDGER` for timestamp epochs.
+
+/// Current time on the epoch clock
+pub(crate) fn now(env: &Env) -> u64 {
+    match storage::get_epoch_clock(env) {
+        EpochClock::Timestamp => env.ledger().timestamp(),
+        EpochClock::LedgerSequence => env.ledger().sequence() as u64,
+    }
+}
+
+/// Convert an epoch clock duration to (approximate) ledgers
+pub(crate) fn to_ledgers(env: &Env, duration: u64) -> u64 {
+    match storage::get_epoch_clock(env) {
+        EpochClock::Timestamp => duration / storage::SECONDS_PER_LEDGER,
+        EpochClock::LedgerSequence => duration,
+    }
+}
+
+/// Ledgers left until `end` on the epoch clock (0 once it has passed)
+pub(crate) fn ledgers_until(env: &Env, end: u64) -> u64 {
+    to_ledgers(env, end.saturating_sub(now(env)))
+}
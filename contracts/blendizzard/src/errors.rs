@@ -1,9 +1,15 @@
-use soroban_sdk::contracterror;
+use soroban_sdk::{contracterror, InvokeError};
 
 /// Error codes for the Blendizzard contract
 ///
 /// All errors are represented as u32 values for efficient storage and transmission.
 /// Error codes are grouped by category for better organization.
+///
+/// Codes are stable: frontends and keeper bots branch on them via `try_*`
+/// calls, so existing values are never renumbered or reused.
+///
+/// The contract spec allows at most 50 error cases, so new codes go in
+/// `ExtError`.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -11,7 +17,20 @@ pub enum Error {
     // ========================================================================
     // Admin errors (1-9)
     // ========================================================================
-    // (No admin errors currently defined)
+    /// Optional feature has not been configured by the admin
+    FeatureNotConfigured = 1,
+
+    /// Faction metadata is invalid (empty or too long name/symbol)
+    InvalidFactionMetadata = 2,
+
+    /// Token can't be rescued (reward asset, BLND or fee-vault, or rescue moved them)
+    TokenNotRescuable = 3,
+
+    /// Vault backend can't be switched while the DirectPool backend holds deposits
+    VaultBackendInUse = 4,
+
+    /// Admin setting is out of its allowed range
+    InvalidConfig = 5,
 
     // ========================================================================
     // Player errors (10-19)
@@ -34,6 +53,15 @@ pub enum Error {
     /// Player must select a faction before playing games
     FactionNotSelected = 16,
 
+    /// Player already has a referrer (referrers are write-once)
+    ReferrerAlreadySet = 17,
+
+    /// Referrer is invalid (e.g., player referring themselves)
+    InvalidReferrer = 18,
+
+    /// Player's vault balance is too low for the requested withdrawal
+    InsufficientBalance = 19,
+
     // ========================================================================
     // Game errors (20-29)
     // ========================================================================
@@ -55,6 +83,18 @@ pub enum Error {
     /// Game is from a previous epoch and cannot be completed
     GameExpired = 25,
 
+    /// Game session already has an outcome (cannot be ended twice)
+    SessionAlreadyFinalized = 26,
+
+    /// Player already has the maximum number of active sessions
+    TooManyActiveSessions = 27,
+
+    /// No series exists for this session
+    SeriesNotFound = 28,
+
+    /// Series length is invalid (must be odd and at most 9)
+    InvalidSeriesLength = 29,
+
     // ========================================================================
     // Epoch errors (30-39)
     // ========================================================================
@@ -67,6 +107,9 @@ pub enum Error {
     /// Epoch cannot be cycled yet (not enough time has passed)
     EpochNotReady = 32,
 
+    /// Games are disabled during the epoch's faction-selection phase
+    SelectionPhaseActive = 33,
+
     // ========================================================================
     // Reward errors (40-49)
     // ========================================================================
@@ -82,12 +125,27 @@ pub enum Error {
     /// Player must deposit minimum amount to claim rewards (anti-sybil)
     DepositRequiredToClaim = 43,
 
+    /// Epoch is settled via Merkle root; claim with `claim_merkle_reward` instead
+    MerkleDistributionActive = 44,
+
+    /// Merkle root was already posted for this epoch
+    MerkleRootAlreadySet = 45,
+
+    /// Merkle proof does not verify against the epoch's root
+    InvalidMerkleProof = 46,
+
+    /// Claim window for this epoch has passed (unclaimed rewards were carried over)
+    ClaimWindowExpired = 47,
+
     // ========================================================================
     // External contract errors (50-59)
     // ========================================================================
     /// Soroswap swap operation failed
     SwapError = 51,
 
+    /// Swap output would be below the caller's minimum (price moved or pool too thin)
+    SlippageExceeded = 52,
+
     // ========================================================================
     // Math errors (60-69)
     // ========================================================================
@@ -117,4 +175,285 @@ pub enum Error {
 
     /// Caller is not the registered developer for this game
     NotGameDeveloper = 83,
+
+    // ========================================================================
+    // Invariant errors (90-99)
+    // ========================================================================
+    /// Internal accounting invariant was violated (strict-invariants builds only)
+    InvariantViolation = 90,
+
+    // ========================================================================
+    // Deposit cap errors (100-109)
+    // ========================================================================
+    /// Deposit would take the user's vault balance above the per-user cap
+    UserDepositCapExceeded = 100,
+
+    /// Deposit would take the vault's total deposits above the global cap
+    GlobalDepositCapExceeded = 101,
+
+    /// Whitelist mode is enabled and the user is not on the whitelist
+    NotWhitelisted = 102,
+
+    // ========================================================================
+    // Team game errors (110-119)
+    // ========================================================================
+    /// Teams are uneven, too small or large, share a player, or mix factions
+    InvalidTeam = 110,
+}
+
+/// Error codes added after `Error` reached the contract spec's 50-case limit
+///
+/// Entrypoints that can fail with one of these return `ContractError`. Codes
+/// continue after `Error`'s and are just as stable.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtError {
+    // ========================================================================
+    // Vault errors (120-129)
+    // ========================================================================
+    /// No migration to this vault is queued (see `queue_vault_migration`)
+    VaultMigrationNotQueued = 120,
+
+    /// The queued vault migration's timelock hasn't passed yet
+    VaultMigrationTimelocked = 121,
+
+    /// Blend pool is on ice or frozen and refuses deposits (and game starts,
+    /// if `set_pool_halt_pauses_games` is on)
+    PoolHalted = 122,
+
+    /// Vault balance is locked until a later epoch (see `deposit_with_lock`)
+    DepositLocked = 123,
+
+    // ========================================================================
+    // Player errors (130-139)
+    // ========================================================================
+    /// Faction has outgrown the smallest one by more than `Config::faction_cap`
+    FactionFull = 130,
+
+    /// Caller isn't the player's approved claimer (see `approve_claimer`)
+    NotApprovedClaimer = 131,
+
+    // ========================================================================
+    // Emergency errors (140-149)
+    // ========================================================================
+    /// A fund-moving call was re-entered while another one is in progress
+    Reentrancy = 140,
+
+    // ========================================================================
+    // Approval errors (150-159)
+    // ========================================================================
+    /// Fewer than `threshold` current approvers approved the operation
+    ApprovalThresholdNotMet = 150,
+
+    /// Caller isn't one of the current approvers (see `set_approvers`)
+    NotApprover = 151,
+
+    /// Caller hasn't approved the operation
+    ApprovalNotFound = 152,
+
+    // ========================================================================
+    // Treasury errors (160-169)
+    // ========================================================================
+    /// Treasury proposal doesn't exist (or was already executed)
+    ProposalNotFound = 160,
+
+    /// Voter already voted on the treasury proposal
+    ProposalAlreadyVoted = 161,
+
+    /// The treasury proposal's voting epoch has ended
+    ProposalVotingClosed = 162,
+
+    /// The treasury proposal's voting epoch hasn't ended yet
+    ProposalVotingOpen = 163,
+
+    /// Voter isn't a member of the proposal's faction
+    NotFactionMember = 164,
+
+    /// Faction treasury holds less than the proposal's amount
+    InsufficientTreasury = 165,
+
+    // ========================================================================
+    // Keeper errors (170-179)
+    // ========================================================================
+    /// Address isn't a registered keeper
+    KeeperNotRegistered = 170,
+
+    /// Keeper's bond is below `KeeperRegistry::min_bond`
+    KeeperBondTooLow = 171,
+
+    /// Keeper's bond is locked for a full epoch after its last cycle
+    KeeperBondLocked = 172,
+
+    /// Public cycling waits for the keeper priority window to pass
+    KeeperPriorityWindow = 173,
+
+    /// Slash amount exceeds the keeper's bond
+    SlashExceedsBond = 174,
+
+    // ========================================================================
+    // Governance errors (180-189)
+    // ========================================================================
+    /// Value isn't one of the parameter's governance options
+    ParameterOptionNotOffered = 180,
+
+    /// Voter already voted on the parameter this epoch
+    ParameterAlreadyVoted = 181,
+
+    // ========================================================================
+    // Prediction errors (190-199)
+    // ========================================================================
+    /// Players can't predict their own session
+    PredictorIsPlayer = 190,
+
+    /// Predictor already backed the other player in this session
+    PredictionSideMismatch = 191,
+
+    /// Session can still be settled, so its unresolved prediction can't be refunded yet
+    PredictionNotRefundable = 192,
+
+    // ========================================================================
+    // Dispute errors (200-209)
+    // ========================================================================
+    /// Session has no provisional result (it wasn't reported or was already settled)
+    NoProvisionalResult = 200,
+
+    /// Provisional result was already disputed
+    ResultAlreadyDisputed = 201,
+
+    /// Provisional result's dispute window has passed
+    DisputeWindowClosed = 202,
+
+    /// Provisional result's dispute window is still open
+    DisputeWindowOpen = 203,
+
+    /// Provisional result is disputed and must be settled by the arbiter
+    ResultDisputed = 204,
+
+    /// Provisional result isn't disputed (settle it with `finalize_result`)
+    ResultNotDisputed = 205,
+
+    // ========================================================================
+    // Commit-reveal errors (210-219)
+    // ========================================================================
+    /// Player already committed a move for this session
+    MoveAlreadyCommitted = 210,
+
+    /// Both players haven't committed yet, so the reveal window isn't open
+    RevealNotOpen = 211,
+
+    /// Reveal window has closed
+    RevealWindowClosed = 212,
+
+    /// Reveal window is still open
+    RevealWindowOpen = 213,
+
+    /// Player already revealed their move
+    MoveAlreadyRevealed = 214,
+
+    /// Move and salt don't match the player's commitment
+    CommitmentMismatch = 215,
+
+    /// Reveal timeout needs exactly one revealed move
+    RevealTimeoutNotClaimable = 216,
+
+    /// Series sessions are decided game by game and can't use commit-reveal
+    SeriesNotSupported = 217,
+
+    // ========================================================================
+    // Boost errors (220-229)
+    // ========================================================================
+    /// Epoch already lists `MAX_BOOSTS_PER_EPOCH` boost entries
+    BoostListFull = 220,
+
+    // ========================================================================
+    // Randomness errors (230-239)
+    // ========================================================================
+    /// Session belongs to another game
+    SessionGameMismatch = 230,
+
+    // ========================================================================
+    // Protocol treasury errors (240-249)
+    // ========================================================================
+    /// No treasury transfer with these parameters is queued
+    TreasuryTransferNotQueued = 240,
+
+    /// The queued treasury transfer's timelock hasn't passed yet
+    TreasuryTransferTimelocked = 241,
+}
+
+/// Error of entrypoints that can fail with either an `Error` or an `ExtError`
+///
+/// Both enums are in the contract spec; this carries either code across the
+/// entrypoint boundary, so `try_*` calls get a typed error for both.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ContractError {
+    Error(Error),
+    Ext(ExtError),
+}
+
+impl From<Error> for ContractError {
+    fn from(error: Error) -> Self {
+        ContractError::Error(error)
+    }
+}
+
+impl From<ExtError> for ContractError {
+    fn from(error: ExtError) -> Self {
+        ContractError::Ext(error)
+    }
+}
+
+impl From<ContractError> for soroban_sdk::Error {
+    fn from(error: ContractError) -> Self {
+        match error {
+            ContractError::Error(error) => error.into(),
+            ContractError::Ext(error) => error.into(),
+        }
+    }
+}
+
+impl From<&ContractError> for soroban_sdk::Error {
+    fn from(error: &ContractError) -> Self {
+        (*error).into()
+    }
+}
+
+impl TryFrom<soroban_sdk::Error> for ContractError {
+    type Error = soroban_sdk::Error;
+
+    fn try_from(error: soroban_sdk::Error) -> Result<Self, soroban_sdk::Error> {
+        match Error::try_from(error) {
+            Ok(error) => Ok(error.into()),
+            Err(error) => ExtError::try_from(error).map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<&soroban_sdk::Error> for ContractError {
+    type Error = soroban_sdk::Error;
+
+    fn try_from(error: &soroban_sdk::Error) -> Result<Self, soroban_sdk::Error> {
+        (*error).try_into()
+    }
+}
+
+impl From<ContractError> for InvokeError {
+    fn from(error: ContractError) -> Self {
+        InvokeError::Contract(soroban_sdk::Error::from(error).get_code())
+    }
+}
+
+impl TryFrom<InvokeError> for ContractError {
+    type Error = InvokeError;
+
+    fn try_from(error: InvokeError) -> Result<Self, InvokeError> {
+        match error {
+            InvokeError::Contract(code) => {
+                ContractError::try_from(soroban_sdk::Error::from_contract_error(code))
+                    .map_err(|_| error)
+            }
+            InvokeError::Abort => Err(error),
+        }
+    }
 }
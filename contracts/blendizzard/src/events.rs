@@ -1,4 +1,10 @@
-use soroban_sdk::{contractevent, Address, Env};
+use soroban_sdk::{contractevent, Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::storage;
+use crate::types::{
+    Achievement, AdminOperation, FactionInfo, GameSeries, GovernanceParameter, LockTier,
+    QueuedTreasuryTransfer, TeamSession, TokenAmount, TreasurySpend,
+};
 
 // ============================================================================
 // Event Definitions using #[contractevent] Macro
@@ -12,36 +18,135 @@ use soroban_sdk::{contractevent, Address, Env};
 
 #[contractevent]
 pub struct AdminChanged {
+    #[topic]
+    pub seq: u64,
     pub old_admin: Address,
     pub new_admin: Address,
 }
 
+#[contractevent]
+pub struct GuardianChanged {
+    #[topic]
+    pub seq: u64,
+    pub admin: Address,
+    pub guardian: Option<Address>,
+}
+
+#[contractevent]
+pub struct ArbiterChanged {
+    #[topic]
+    pub seq: u64,
+    pub admin: Address,
+    pub arbiter: Option<Address>,
+}
+
+#[contractevent]
+pub struct WhitelistUpdated {
+    #[topic]
+    pub seq: u64,
+    pub admin: Address,
+    pub users: Vec<Address>,
+    pub whitelisted: bool,
+}
+
+#[contractevent]
+pub struct VaultMigrated {
+    #[topic]
+    pub seq: u64,
+    pub old_vault: Address,
+    pub new_vault: Address,
+    pub amount_moved: i128,
+}
+
 #[contractevent]
 pub struct GameAdded {
+    #[topic]
+    pub seq: u64,
+    pub game_id: Address,
+    pub developer: Address,
+}
+
+#[contractevent]
+pub struct GameRegistrationRequested {
+    #[topic]
+    pub seq: u64,
     pub game_id: Address,
     pub developer: Address,
+    pub name: String,
 }
 
 #[contractevent]
 pub struct GameRemoved {
+    #[topic]
+    pub seq: u64,
     pub game_id: Address,
 }
 
 #[contractevent]
 pub struct ConfigUpdated {
+    #[topic]
+    pub seq: u64,
     pub admin: Address,
 }
 
+#[contractevent]
+pub struct TokenRescued {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub seq: u64,
+    pub to: Address,
+    pub amount: i128,
+}
+
 // ============================================================================
-// Vault Events (REMOVED - Players interact directly with fee-vault-v2)
+// Vault Events
 // ============================================================================
 //
-// Deposit and Withdraw events have been removed because:
-// - Players deposit/withdraw directly to fee-vault-v2
-// - Blendizzard no longer intermediates vault operations
-// - Fee-vault-v2 emits its own events for these operations
-//
-// Monitor fee-vault-v2's vault_deposit and vault_withdraw events instead.
+// With the FeeVault backend players usually deposit into fee-vault-v2
+// directly, and fee-vault-v2 emits its own vault_deposit/vault_withdraw
+// events for those. Deposits and withdrawals that go through this contract
+// (DirectPool `deposit`, `deposit_from_allowance` and `withdraw`, and
+// `migrate_position` between fee-vaults) emit Deposited/Withdrawn, with
+// `vault` the fee-vault or Blend pool holding the funds. `deposit_xlm` emits
+// XlmDeposited for the conversion.
+
+#[contractevent]
+pub struct Deposited {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub from: Address,
+    pub vault: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct Withdrawn {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub vault: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct XlmDeposited {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub xlm_amount: i128,
+    pub usdc_amount: i128,
+}
 
 // ============================================================================
 // Faction Events
@@ -51,7 +156,22 @@ pub struct ConfigUpdated {
 pub struct FactionSelected {
     #[topic]
     pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub faction: u32,
+}
+
+#[contractevent]
+pub struct FactionInfoSet {
+    #[topic]
     pub faction: u32,
+    #[topic]
+    pub seq: u64,
+    pub name: String,
+    pub symbol: String,
+    pub icon_hash: BytesN<32>,
 }
 
 // FactionLocked event REMOVED
@@ -65,24 +185,106 @@ pub struct FactionSelected {
 pub struct TimeMultiplierReset {
     #[topic]
     pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
     pub epoch: u32,
     pub previous_balance: i128,
     pub current_balance: i128,
     pub withdrawal_percentage: i128, // Fixed-point (SCALAR_7)
 }
 
+// ============================================================================
+// Referral Events
+// ============================================================================
+
+#[contractevent]
+pub struct ReferrerSet {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub referrer: Address,
+}
+
+#[contractevent]
+pub struct ReferralRewardAccrued {
+    #[topic]
+    pub referrer: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub player: Address,
+    pub epoch: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct ClaimerApproved {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub claimer: Address,
+}
+
+#[contractevent]
+pub struct ClaimerRevoked {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub claimer: Address,
+}
+
+#[contractevent]
+pub struct DelegatedClaim {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub claimer: Address,
+    pub epoch: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct ReferralRewardsClaimed {
+    #[topic]
+    pub referrer: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub amount: i128,
+}
+
 // ============================================================================
 // Pause Events
 // ============================================================================
 
 #[contractevent]
 pub struct ContractPaused {
+    #[topic]
+    pub seq: u64,
     pub admin: Address,
     pub timestamp: u64,
 }
 
 #[contractevent]
 pub struct ContractUnpaused {
+    #[topic]
+    pub seq: u64,
     pub admin: Address,
     pub timestamp: u64,
 }
@@ -97,6 +299,8 @@ pub struct GameStarted {
     pub game_id: Address,
     #[topic]
     pub session_id: u32,
+    #[topic]
+    pub seq: u64,
     pub player1: Address,
     pub player2: Address,
     pub player1_wager: i128,
@@ -113,220 +317,1911 @@ pub struct GameEnded {
     pub game_id: Address,
     #[topic]
     pub session_id: u32,
+    #[topic]
+    pub seq: u64,
     pub winner: Address,
     pub loser: Address,
     pub fp_contributed: i128, // Winner's FP that contributes to faction standings
+    pub winner_rating: u32,   // Winner's Elo rating for this game after the update
+    pub loser_rating: u32,    // Loser's Elo rating for this game after the update
+    pub rating_change: u32,   // Points gained by winner (and lost by loser)
 }
 
-// ============================================================================
-// Epoch Events
-// ============================================================================
-
 #[contractevent]
-pub struct EpochCycled {
-    pub old_epoch: u32,
-    pub new_epoch: u32,
-    pub winning_faction: u32,
-    pub reward_pool: i128,
+pub struct SeriesStarted {
+    #[topic]
+    pub game_id: Address,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub best_of: u32,
 }
 
 #[contractevent]
-pub struct RewardsClaimed {
+pub struct SeriesGameRecorded {
     #[topic]
-    pub player: Address,
-    pub epoch: u32,
-    pub faction: u32,
-    pub amount: i128,
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub game_number: u32, // 1-based index of the game within the series
+    pub player1_won: bool,
+    pub player1_wins: u32,
+    pub player2_wins: u32,
 }
 
 #[contractevent]
-pub struct DevRewardClaimed {
+pub struct TeamGameStarted {
     #[topic]
-    pub developer: Address,
-    pub epoch: u32,
-    pub fp_contributed: i128,
-    pub amount: i128,
+    pub game_id: Address,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub team_a: Vec<Address>,
+    pub team_b: Vec<Address>,
+    pub team_a_faction: u32,
+    pub team_b_faction: u32,
+    pub wager: i128, // FP wagered by each member
 }
 
-// ============================================================================
-// Event Emission Helper Functions
-// ============================================================================
+#[contractevent]
+pub struct TeamGameEnded {
+    #[topic]
+    pub game_id: Address,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub team_a_won: bool,
+    pub winning_faction: u32,
+    pub fp_contributed: i128, // Winning team's total FP added to faction standings
+}
 
-/// Emit admin changed event
-pub(crate) fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
-    AdminChanged {
-        old_admin: old_admin.clone(),
-        new_admin: new_admin.clone(),
-    }
-    .publish(env);
+#[contractevent]
+pub struct MoveCommitted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub commitment: BytesN<32>,
 }
 
-/// Emit game added event
-pub(crate) fn emit_game_added(env: &Env, game_id: &Address, developer: &Address) {
-    GameAdded {
-        game_id: game_id.clone(),
-        developer: developer.clone(),
-    }
-    .publish(env);
+#[contractevent]
+pub struct MoveRevealed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub player_move: u32,
 }
 
-/// Emit game removed event
-pub(crate) fn emit_game_removed(env: &Env, game_id: &Address) {
-    GameRemoved {
-        game_id: game_id.clone(),
-    }
-    .publish(env);
+#[contractevent]
+pub struct RevealTimeoutClaimed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub player1_won: bool,
 }
 
-/// Emit config updated event
-pub(crate) fn emit_config_updated(env: &Env, admin: &Address) {
-    ConfigUpdated {
-        admin: admin.clone(),
-    }
-    .publish(env);
+#[contractevent]
+pub struct PredictionPlaced {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub predictor: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub player1_wins: bool,
+    pub amount: i128,
 }
 
-/// Emit faction selected event
-pub(crate) fn emit_faction_selected(env: &Env, player: &Address, faction: u32) {
-    FactionSelected {
-        player: player.clone(),
-        faction,
-    }
-    .publish(env);
+#[contractevent]
+pub struct PredictionsResolved {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub player1_won: bool,
+    pub rake: i128,
+    pub payout_pool: i128,
 }
 
-/// Emit time multiplier reset event
-pub(crate) fn emit_time_multiplier_reset(
-    env: &Env,
-    player: &Address,
-    epoch: u32,
-    previous_balance: i128,
-    current_balance: i128,
-    withdrawal_percentage: i128,
-) {
-    TimeMultiplierReset {
-        player: player.clone(),
-        epoch,
-        previous_balance,
-        current_balance,
-        withdrawal_percentage,
-    }
-    .publish(env);
+#[contractevent]
+pub struct PredictionClaimed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub predictor: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub amount: i128,
 }
 
-/// Emit contract paused event
-pub(crate) fn emit_contract_paused(env: &Env, admin: &Address) {
-    ContractPaused {
-        admin: admin.clone(),
-        timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+#[contractevent]
+pub struct ResultProvisional {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub player1_won: bool,
+    pub final_ledger: u32, // Last ledger the result can be disputed in
+}
+
+#[contractevent]
+pub struct ResultDisputed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub disputer: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub bond: i128,
+}
+
+#[contractevent]
+pub struct DisputeResolved {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub arbiter: Address,
+    pub player1_won: bool,
+    pub bond_slashed: bool,
+}
+
+// ============================================================================
+// Epoch Events
+// ============================================================================
+
+#[contractevent]
+pub struct EpochCycled {
+    #[topic]
+    pub seq: u64,
+    pub old_epoch: u32,
+    pub new_epoch: u32,
+    pub winning_faction: u32,
+    pub reward_pool: i128,
+}
+
+#[contractevent]
+pub struct EpochAborted {
+    #[topic]
+    pub seq: u64,
+    pub aborted_epoch: u32,
+    pub new_epoch: u32,
+    pub carried_rewards: i128,
+}
+
+#[contractevent]
+pub struct TreasuryFunded {
+    #[topic]
+    pub faction: u32,
+    #[topic]
+    pub seq: u64,
+    pub epoch: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct TreasuryProposalCreated {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub proposer: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub faction: u32,
+    pub spend: TreasurySpend,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct TreasuryVoted {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub voter: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub support: bool,
+    pub weight: i128,
+}
+
+#[contractevent]
+pub struct TreasuryProposalExecuted {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub passed: bool,
+}
+
+#[contractevent]
+pub struct KeeperBonded {
+    #[topic]
+    pub keeper: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub amount: i128,
+    pub bond: i128,
+}
+
+#[contractevent]
+pub struct KeeperUnregistered {
+    #[topic]
+    pub keeper: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub bond: i128,
+}
+
+#[contractevent]
+pub struct KeeperSlashed {
+    #[topic]
+    pub keeper: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub amount: i128,
+    pub bond: i128,
+}
+
+#[contractevent]
+pub struct KeeperCycled {
+    #[topic]
+    pub keeper: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub bounty: i128,
+}
+
+#[contractevent]
+pub struct RakeRouted {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub to_reward_pool: i128,
+    pub to_treasury: i128,
+    pub burned: i128,
+    pub to_protocol: i128,
+}
+
+#[contractevent]
+pub struct EpochsSkipped {
+    #[topic]
+    pub seq: u64,
+    pub first_epoch: u32,
+    pub last_epoch: u32, // Inclusive; the new epoch is last_epoch + 1
+}
+
+#[contractevent]
+pub struct SwapSkipped {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub blnd_amount: i128, // BLND carried over to the next cycle
+}
+
+#[contractevent]
+pub struct CarryoverBlndSwapped {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub blnd_amount: i128, // BLND from earlier skipped swaps included in this swap
+}
+
+#[contractevent]
+pub struct RewardsHarvested {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub amount: i128, // USDC added to the pending-reward buffer by this harvest
+    pub pending_total: i128, // Buffer total to be folded into the epoch's reward pool
+}
+
+#[contractevent]
+pub struct ExpiredRewardsSwept {
+    #[topic]
+    pub expired_epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub into_epoch: u32, // Epoch whose reward pool received the unclaimed USDC
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct RewardsClaimed {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub faction: u32,
+    pub amount: i128,
+    pub blnd_amount: i128, // Share of the epoch's retained BLND
+}
+
+#[contractevent]
+pub struct RewardVestingStarted {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub amount: i128,
+    pub end_ledger: u32,
+}
+
+#[contractevent]
+pub struct VestedRewardsClaimed {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct MerkleRootSet {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub root: BytesN<32>,
+}
+
+#[contractevent]
+pub struct DevRewardClaimed {
+    #[topic]
+    pub developer: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub fp_contributed: i128,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct OperationApproved {
+    #[topic]
+    pub approver: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub operation: AdminOperation,
+    pub approvals: u32,
+}
+
+#[contractevent]
+pub struct ApprovalRevoked {
+    #[topic]
+    pub approver: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub operation: AdminOperation,
+    pub approvals: u32,
+}
+
+#[contractevent]
+pub struct ParameterVoted {
+    #[topic]
+    pub voter: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub parameter: GovernanceParameter,
+    pub epoch: u32,
+    pub value: i128,
+    pub weight: i128,
+}
+
+#[contractevent]
+pub struct ParameterApplied {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub parameter: GovernanceParameter,
+    pub value: i128,
+}
+
+#[contractevent]
+pub struct BlndBurned {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SessionForfeited {
+    #[topic]
+    pub game_id: Address,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub afk_player: Address,
+}
+
+#[contractevent]
+pub struct QuestCompleted {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub quest: u32,
+    pub bonus_fp: i128,
+}
+
+#[contractevent]
+pub struct SessionMetadataAttached {
+    #[topic]
+    pub game_id: Address,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub seq: u64,
+    pub result: bool, // false: attached at start, true: with the result
+    pub metadata: Bytes,
+}
+
+#[contractevent]
+pub struct EpochDataPruned {
+    #[topic]
+    pub caller: Address,
+    #[topic]
+    pub seq: u64,
+    pub first_epoch: u32,
+    pub last_epoch: u32,
+    pub entries_removed: u32,
+    pub bounty: i128,
+}
+
+#[contractevent]
+pub struct EpochBoosted {
+    #[topic]
+    pub sponsor: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub token: Address,
+    pub amount: i128,
+    pub pool_amount: i128, // Added to the pools (USDC for BLND boosts)
+}
+
+#[contractevent]
+pub struct RewardTokensClaimed {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub tokens: Vec<TokenAmount>,
+}
+
+#[contractevent]
+pub struct PoolStatusChanged {
+    #[topic]
+    pub pool: Address,
+    #[topic]
+    pub seq: u64,
+    pub old_status: Option<u32>,
+    pub status: u32,
+    pub halted: bool,
+}
+
+#[contractevent]
+pub struct JackpotFunded {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+#[contractevent]
+pub struct JackpotPaid {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub winning_faction: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct InsuranceFunded {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+#[contractevent]
+pub struct ShortfallCovered {
+    #[topic]
+    pub seq: u64,
+    pub amount: i128,
+    pub b_tokens: i128,
+    pub remaining_shortfall: i128,
+    pub balance: i128,
+}
+
+#[contractevent]
+pub struct TreasurerChanged {
+    #[topic]
+    pub seq: u64,
+    pub admin: Address,
+    pub treasurer: Option<Address>,
+}
+
+#[contractevent]
+pub struct ProtocolTreasuryFunded {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub seq: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+#[contractevent]
+pub struct TreasuryTransferQueued {
+    #[topic]
+    pub to: Address,
+    #[topic]
+    pub seq: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub executable_at: u64,
+}
+
+#[contractevent]
+pub struct TreasuryTransferred {
+    #[topic]
+    pub to: Address,
+    #[topic]
+    pub seq: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+#[contractevent]
+pub struct AchievementUnlocked {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub achievement: Achievement,
+}
+
+#[contractevent]
+pub struct MvpAwarded {
+    #[topic]
+    pub mvp: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub epoch: u32,
+    pub faction: u32,
+    pub bonus: i128,
+}
+
+#[contractevent]
+pub struct DepositLocked {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub seq: u64,
+    #[topic]
+    pub user_seq: u64,
+    pub tier: LockTier,
+    pub unlock_epoch: u32,
+}
+
+// ============================================================================
+// Event Sequence Numbers
+// ============================================================================
+//
+// Every event carries a `seq` topic from one global, gap-free counter, so an
+// indexer can detect missed events and resume from its last seen `seq`.
+// Events keyed by a user address (player, referrer, developer) also carry a
+// `user_seq` topic from that user's own counter. Counters only advance when
+// the emitting transaction succeeds.
+
+/// Take the next global event sequence number
+fn next_seq(env: &Env) -> u64 {
+    let seq = storage::get_event_seq(env) + 1;
+    storage::set_event_seq(env, seq);
+    seq
+}
+
+/// Take the next event sequence number for `user`
+fn next_user_seq(env: &Env, user: &Address) -> u64 {
+    let seq = storage::get_user_event_seq(env, user) + 1;
+    storage::set_user_event_seq(env, user, seq);
+    seq
+}
+
+// ============================================================================
+// Event Emission Helper Functions
+// ============================================================================
+
+/// Emit admin changed event
+pub(crate) fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
+    AdminChanged {
+        old_admin: old_admin.clone(),
+        new_admin: new_admin.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit guardian changed event
+pub(crate) fn emit_guardian_changed(env: &Env, admin: &Address, guardian: &Option<Address>) {
+    GuardianChanged {
+        admin: admin.clone(),
+        guardian: guardian.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit arbiter changed event
+pub(crate) fn emit_arbiter_changed(env: &Env, admin: &Address, arbiter: &Option<Address>) {
+    ArbiterChanged {
+        admin: admin.clone(),
+        arbiter: arbiter.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit whitelist updated event
+pub(crate) fn emit_whitelist_updated(
+    env: &Env,
+    admin: &Address,
+    users: &Vec<Address>,
+    whitelisted: bool,
+) {
+    WhitelistUpdated {
+        admin: admin.clone(),
+        users: users.clone(),
+        whitelisted,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit vault migrated event
+pub(crate) fn emit_vault_migrated(
+    env: &Env,
+    old_vault: &Address,
+    new_vault: &Address,
+    amount_moved: i128,
+) {
+    VaultMigrated {
+        old_vault: old_vault.clone(),
+        new_vault: new_vault.clone(),
+        amount_moved,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit token rescued event
+pub(crate) fn emit_token_rescued(env: &Env, token: &Address, to: &Address, amount: i128) {
+    TokenRescued {
+        token: token.clone(),
+        to: to.clone(),
+        amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit game added event
+pub(crate) fn emit_game_added(env: &Env, game_id: &Address, developer: &Address) {
+    GameAdded {
+        game_id: game_id.clone(),
+        developer: developer.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit game registration requested event
+pub(crate) fn emit_game_registration_requested(
+    env: &Env,
+    game_id: &Address,
+    developer: &Address,
+    name: &String,
+) {
+    GameRegistrationRequested {
+        game_id: game_id.clone(),
+        developer: developer.clone(),
+        name: name.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit game removed event
+pub(crate) fn emit_game_removed(env: &Env, game_id: &Address) {
+    GameRemoved {
+        game_id: game_id.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit config updated event
+pub(crate) fn emit_config_updated(env: &Env, admin: &Address) {
+    ConfigUpdated {
+        admin: admin.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit faction selected event
+pub(crate) fn emit_faction_selected(env: &Env, player: &Address, faction: u32) {
+    FactionSelected {
+        player: player.clone(),
+        faction,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit faction metadata set event
+pub(crate) fn emit_faction_info_set(env: &Env, info: &FactionInfo) {
+    FactionInfoSet {
+        faction: info.id,
+        name: info.name.clone(),
+        symbol: info.symbol.clone(),
+        icon_hash: info.icon_hash.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit time multiplier reset event
+pub(crate) fn emit_time_multiplier_reset(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    previous_balance: i128,
+    current_balance: i128,
+    withdrawal_percentage: i128,
+) {
+    TimeMultiplierReset {
+        player: player.clone(),
+        epoch,
+        previous_balance,
+        current_balance,
+        withdrawal_percentage,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit contract paused event
+pub(crate) fn emit_contract_paused(env: &Env, admin: &Address) {
+    ContractPaused {
+        admin: admin.clone(),
+        timestamp: env.ledger().timestamp(),
+        seq: next_seq(env),
+    }
+    .publish(env);
 }
 
 /// Emit contract unpaused event
 pub(crate) fn emit_contract_unpaused(env: &Env, admin: &Address) {
     ContractUnpaused {
         admin: admin.clone(),
-        timestamp: env.ledger().timestamp(),
+        timestamp: env.ledger().timestamp(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit game started event
+pub(crate) fn emit_game_started(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_wager: i128,
+    player2_wager: i128,
+    player1_faction: u32,
+    player2_faction: u32,
+    player1_fp_remaining: i128,
+    player2_fp_remaining: i128,
+) {
+    GameStarted {
+        game_id: game_id.clone(),
+        session_id,
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_wager,
+        player2_wager,
+        player1_faction,
+        player2_faction,
+        player1_fp_remaining,
+        player2_fp_remaining,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit game ended event
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn emit_game_ended(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    winner: &Address,
+    loser: &Address,
+    fp_contributed: i128,
+    winner_rating: u32,
+    loser_rating: u32,
+    rating_change: u32,
+) {
+    GameEnded {
+        game_id: game_id.clone(),
+        session_id,
+        winner: winner.clone(),
+        loser: loser.clone(),
+        fp_contributed,
+        winner_rating,
+        loser_rating,
+        rating_change,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit series started event
+pub(crate) fn emit_series_started(env: &Env, game_id: &Address, session_id: u32, best_of: u32) {
+    SeriesStarted {
+        game_id: game_id.clone(),
+        session_id,
+        best_of,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit series game recorded event
+pub(crate) fn emit_series_game_recorded(env: &Env, session_id: u32, series: &GameSeries) {
+    SeriesGameRecorded {
+        session_id,
+        game_number: series.results.len(),
+        player1_won: series.results.last().unwrap_or(false),
+        player1_wins: series.player1_wins,
+        player2_wins: series.player2_wins,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit team game started event
+pub(crate) fn emit_team_game_started(env: &Env, session_id: u32, session: &TeamSession) {
+    TeamGameStarted {
+        game_id: session.game_id.clone(),
+        session_id,
+        team_a: session.team_a.clone(),
+        team_b: session.team_b.clone(),
+        team_a_faction: session.team_a_faction,
+        team_b_faction: session.team_b_faction,
+        wager: session.wager,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit team game ended event
+pub(crate) fn emit_team_game_ended(
+    env: &Env,
+    session_id: u32,
+    session: &TeamSession,
+    team_a_won: bool,
+    fp_contributed: i128,
+) {
+    TeamGameEnded {
+        game_id: session.game_id.clone(),
+        session_id,
+        team_a_won,
+        winning_faction: if team_a_won {
+            session.team_a_faction
+        } else {
+            session.team_b_faction
+        },
+        fp_contributed,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit move committed event
+pub(crate) fn emit_move_committed(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    commitment: &BytesN<32>,
+) {
+    MoveCommitted {
+        session_id,
+        player: player.clone(),
+        commitment: commitment.clone(),
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit move revealed event
+pub(crate) fn emit_move_revealed(env: &Env, session_id: u32, player: &Address, player_move: u32) {
+    MoveRevealed {
+        session_id,
+        player: player.clone(),
+        player_move,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit reveal timeout claimed event
+pub(crate) fn emit_reveal_timeout_claimed(env: &Env, session_id: u32, player1_won: bool) {
+    RevealTimeoutClaimed {
+        session_id,
+        player1_won,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit prediction placed event
+pub(crate) fn emit_prediction_placed(
+    env: &Env,
+    session_id: u32,
+    predictor: &Address,
+    player1_wins: bool,
+    amount: i128,
+) {
+    PredictionPlaced {
+        session_id,
+        predictor: predictor.clone(),
+        player1_wins,
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, predictor),
+    }
+    .publish(env);
+}
+
+/// Emit predictions resolved event (session settled with a prediction pool)
+pub(crate) fn emit_predictions_resolved(
+    env: &Env,
+    session_id: u32,
+    player1_won: bool,
+    rake: i128,
+    payout_pool: i128,
+) {
+    PredictionsResolved {
+        session_id,
+        player1_won,
+        rake,
+        payout_pool,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit prediction claimed event (winnings or refund)
+pub(crate) fn emit_prediction_claimed(
+    env: &Env,
+    session_id: u32,
+    predictor: &Address,
+    amount: i128,
+) {
+    PredictionClaimed {
+        session_id,
+        predictor: predictor.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, predictor),
+    }
+    .publish(env);
+}
+
+/// Emit result provisional event (outcome reported for a game with a dispute window)
+pub(crate) fn emit_result_provisional(
+    env: &Env,
+    session_id: u32,
+    player1_won: bool,
+    final_ledger: u32,
+) {
+    ResultProvisional {
+        session_id,
+        player1_won,
+        final_ledger,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit result disputed event
+pub(crate) fn emit_result_disputed(env: &Env, session_id: u32, disputer: &Address, bond: i128) {
+    ResultDisputed {
+        session_id,
+        disputer: disputer.clone(),
+        bond,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, disputer),
+    }
+    .publish(env);
+}
+
+/// Emit dispute resolved event
+pub(crate) fn emit_dispute_resolved(
+    env: &Env,
+    session_id: u32,
+    arbiter: &Address,
+    player1_won: bool,
+    bond_slashed: bool,
+) {
+    DisputeResolved {
+        session_id,
+        arbiter: arbiter.clone(),
+        player1_won,
+        bond_slashed,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit epoch cycled event
+pub(crate) fn emit_epoch_cycled(
+    env: &Env,
+    old_epoch: u32,
+    new_epoch: u32,
+    winning_faction: u32,
+    reward_pool: i128,
+) {
+    EpochCycled {
+        old_epoch,
+        new_epoch,
+        winning_faction,
+        reward_pool,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit epoch aborted event
+pub(crate) fn emit_epoch_aborted(
+    env: &Env,
+    aborted_epoch: u32,
+    new_epoch: u32,
+    carried_rewards: i128,
+) {
+    EpochAborted {
+        aborted_epoch,
+        new_epoch,
+        carried_rewards,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit treasury funded event (a faction's slice of a closed epoch's pool)
+pub(crate) fn emit_treasury_funded(env: &Env, faction: u32, epoch: u32, amount: i128) {
+    TreasuryFunded {
+        faction,
+        epoch,
+        amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit treasury proposal created event
+pub(crate) fn emit_treasury_proposal_created(
+    env: &Env,
+    proposal_id: u32,
+    proposer: &Address,
+    faction: u32,
+    spend: &TreasurySpend,
+    amount: i128,
+) {
+    TreasuryProposalCreated {
+        proposal_id,
+        proposer: proposer.clone(),
+        faction,
+        spend: spend.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, proposer),
+    }
+    .publish(env);
+}
+
+/// Emit treasury vote event
+pub(crate) fn emit_treasury_voted(
+    env: &Env,
+    proposal_id: u32,
+    voter: &Address,
+    support: bool,
+    weight: i128,
+) {
+    TreasuryVoted {
+        proposal_id,
+        voter: voter.clone(),
+        support,
+        weight,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, voter),
+    }
+    .publish(env);
+}
+
+/// Emit treasury proposal executed event
+pub(crate) fn emit_treasury_proposal_executed(env: &Env, proposal_id: u32, passed: bool) {
+    TreasuryProposalExecuted {
+        proposal_id,
+        passed,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit rake routed event (USDC taken from a closed epoch's player pool by game rakes)
+pub(crate) fn emit_rake_routed(
+    env: &Env,
+    epoch: u32,
+    to_reward_pool: i128,
+    to_treasury: i128,
+    burned: i128,
+    to_protocol: i128,
+) {
+    RakeRouted {
+        epoch,
+        to_reward_pool,
+        to_treasury,
+        burned,
+        to_protocol,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit keeper bonded event (registration or top-up; `bond` is the new total)
+pub(crate) fn emit_keeper_bonded(env: &Env, keeper: &Address, amount: i128, bond: i128) {
+    KeeperBonded {
+        keeper: keeper.clone(),
+        amount,
+        bond,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, keeper),
+    }
+    .publish(env);
+}
+
+/// Emit keeper unregistered event (bond returned)
+pub(crate) fn emit_keeper_unregistered(env: &Env, keeper: &Address, bond: i128) {
+    KeeperUnregistered {
+        keeper: keeper.clone(),
+        bond,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, keeper),
+    }
+    .publish(env);
+}
+
+/// Emit keeper slashed event (`bond` is what remains)
+pub(crate) fn emit_keeper_slashed(env: &Env, keeper: &Address, amount: i128, bond: i128) {
+    KeeperSlashed {
+        keeper: keeper.clone(),
+        amount,
+        bond,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, keeper),
+    }
+    .publish(env);
+}
+
+/// Emit keeper cycled event (`epoch` is the epoch closed by the keeper)
+pub(crate) fn emit_keeper_cycled(env: &Env, keeper: &Address, epoch: u32, bounty: i128) {
+    KeeperCycled {
+        keeper: keeper.clone(),
+        epoch,
+        bounty,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, keeper),
+    }
+    .publish(env);
+}
+
+/// Emit rewards claimed event
+pub(crate) fn emit_rewards_claimed(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    faction: u32,
+    amount: i128,
+    blnd_amount: i128,
+) {
+    RewardsClaimed {
+        player: player.clone(),
+        epoch,
+        faction,
+        amount,
+        blnd_amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit reward vesting started event (claim above the vesting threshold)
+pub(crate) fn emit_reward_vesting_started(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+    end_ledger: u32,
+) {
+    RewardVestingStarted {
+        player: player.clone(),
+        epoch,
+        amount,
+        end_ledger,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit vested rewards claimed event
+pub(crate) fn emit_vested_rewards_claimed(env: &Env, player: &Address, amount: i128) {
+    VestedRewardsClaimed {
+        player: player.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit XLM deposited event
+pub(crate) fn emit_xlm_deposited(env: &Env, user: &Address, xlm_amount: i128, usdc_amount: i128) {
+    XlmDeposited {
+        user: user.clone(),
+        xlm_amount,
+        usdc_amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit deposited event (deposit routed through this contract)
+pub(crate) fn emit_deposited(
+    env: &Env,
+    user: &Address,
+    from: &Address,
+    vault: &Address,
+    amount: i128,
+) {
+    Deposited {
+        user: user.clone(),
+        from: from.clone(),
+        vault: vault.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit withdrawn event (withdrawal routed through this contract)
+pub(crate) fn emit_withdrawn(env: &Env, user: &Address, vault: &Address, amount: i128) {
+    Withdrawn {
+        user: user.clone(),
+        vault: vault.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit epochs skipped event (cycle_epoch fast-forwarded over missed epochs)
+pub(crate) fn emit_epochs_skipped(env: &Env, first_epoch: u32, last_epoch: u32) {
+    EpochsSkipped {
+        first_epoch,
+        last_epoch,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit swap skipped event (oracle price check failed or swap reverted)
+pub(crate) fn emit_swap_skipped(env: &Env, epoch: u32, blnd_amount: i128) {
+    SwapSkipped {
+        epoch,
+        blnd_amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit carried-over BLND swapped event
+pub(crate) fn emit_carryover_blnd_swapped(env: &Env, epoch: u32, blnd_amount: i128) {
+    CarryoverBlndSwapped {
+        epoch,
+        blnd_amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit rewards harvested event (mid-epoch yield claim and swap)
+pub(crate) fn emit_rewards_harvested(env: &Env, epoch: u32, amount: i128, pending_total: i128) {
+    RewardsHarvested {
+        epoch,
+        amount,
+        pending_total,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit expired rewards swept event (unclaimed USDC carried into a new reward pool)
+pub(crate) fn emit_expired_rewards_swept(
+    env: &Env,
+    expired_epoch: u32,
+    into_epoch: u32,
+    amount: i128,
+) {
+    ExpiredRewardsSwept {
+        expired_epoch,
+        into_epoch,
+        amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit developer reward claimed event
+pub(crate) fn emit_dev_reward_claimed(
+    env: &Env,
+    developer: &Address,
+    epoch: u32,
+    fp_contributed: i128,
+    amount: i128,
+) {
+    DevRewardClaimed {
+        developer: developer.clone(),
+        epoch,
+        fp_contributed,
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, developer),
+    }
+    .publish(env);
+}
+
+/// Emit merkle root set event
+pub(crate) fn emit_merkle_root_set(env: &Env, epoch: u32, root: &BytesN<32>) {
+    MerkleRootSet {
+        epoch,
+        root: root.clone(),
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit referrer set event
+pub(crate) fn emit_referrer_set(env: &Env, player: &Address, referrer: &Address) {
+    ReferrerSet {
+        player: player.clone(),
+        referrer: referrer.clone(),
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit referral reward accrued event
+pub(crate) fn emit_referral_reward_accrued(
+    env: &Env,
+    referrer: &Address,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+) {
+    ReferralRewardAccrued {
+        referrer: referrer.clone(),
+        player: player.clone(),
+        epoch,
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, referrer),
+    }
+    .publish(env);
+}
+
+/// Emit claimer approved event
+pub(crate) fn emit_claimer_approved(env: &Env, user: &Address, claimer: &Address) {
+    ClaimerApproved {
+        user: user.clone(),
+        claimer: claimer.clone(),
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit claimer revoked event
+pub(crate) fn emit_claimer_revoked(env: &Env, user: &Address, claimer: &Address) {
+    ClaimerRevoked {
+        user: user.clone(),
+        claimer: claimer.clone(),
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit delegated claim event (a claimer claimed `amount` USDC for the player)
+pub(crate) fn emit_delegated_claim(
+    env: &Env,
+    player: &Address,
+    claimer: &Address,
+    epoch: u32,
+    amount: i128,
+) {
+    DelegatedClaim {
+        player: player.clone(),
+        claimer: claimer.clone(),
+        epoch,
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit referral rewards claimed event
+pub(crate) fn emit_referral_rewards_claimed(env: &Env, referrer: &Address, amount: i128) {
+    ReferralRewardsClaimed {
+        referrer: referrer.clone(),
+        amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, referrer),
+    }
+    .publish(env);
+}
+
+/// Emit insurance fund funded event
+pub(crate) fn emit_insurance_funded(env: &Env, epoch: u32, amount: i128, balance: i128) {
+    InsuranceFunded {
+        epoch,
+        amount,
+        balance,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit vault shortfall covered event
+pub(crate) fn emit_shortfall_covered(
+    env: &Env,
+    amount: i128,
+    b_tokens: i128,
+    remaining_shortfall: i128,
+    balance: i128,
+) {
+    ShortfallCovered {
+        amount,
+        b_tokens,
+        remaining_shortfall,
+        balance,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit treasurer changed event
+pub(crate) fn emit_treasurer_changed(env: &Env, admin: &Address, treasurer: &Option<Address>) {
+    TreasurerChanged {
+        admin: admin.clone(),
+        treasurer: treasurer.clone(),
+        seq: next_seq(env),
     }
     .publish(env);
 }
 
-/// Emit game started event
-pub(crate) fn emit_game_started(
+/// Emit protocol treasury funded event
+pub(crate) fn emit_protocol_treasury_funded(
+    env: &Env,
+    epoch: u32,
+    token: &Address,
+    amount: i128,
+    balance: i128,
+) {
+    ProtocolTreasuryFunded {
+        epoch,
+        token: token.clone(),
+        amount,
+        balance,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit treasury transfer queued event
+pub(crate) fn emit_treasury_transfer_queued(env: &Env, transfer: &QueuedTreasuryTransfer) {
+    TreasuryTransferQueued {
+        to: transfer.to.clone(),
+        token: transfer.token.clone(),
+        amount: transfer.amount,
+        executable_at: transfer.executable_at,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit treasury transferred event
+pub(crate) fn emit_treasury_transferred(
+    env: &Env,
+    to: &Address,
+    token: &Address,
+    amount: i128,
+    balance: i128,
+) {
+    TreasuryTransferred {
+        to: to.clone(),
+        token: token.clone(),
+        amount,
+        balance,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit achievement unlocked event
+pub(crate) fn emit_achievement_unlocked(env: &Env, player: &Address, achievement: Achievement) {
+    AchievementUnlocked {
+        player: player.clone(),
+        achievement,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit MVP awarded event
+pub(crate) fn emit_mvp_awarded(env: &Env, mvp: &Address, epoch: u32, faction: u32, bonus: i128) {
+    MvpAwarded {
+        mvp: mvp.clone(),
+        epoch,
+        faction,
+        bonus,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, mvp),
+    }
+    .publish(env);
+}
+
+/// Emit deposit locked event
+pub(crate) fn emit_deposit_locked(env: &Env, user: &Address, tier: LockTier, unlock_epoch: u32) {
+    DepositLocked {
+        user: user.clone(),
+        tier,
+        unlock_epoch,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, user),
+    }
+    .publish(env);
+}
+
+/// Emit jackpot funded event
+pub(crate) fn emit_jackpot_funded(env: &Env, epoch: u32, amount: i128, balance: i128) {
+    JackpotFunded {
+        epoch,
+        amount,
+        balance,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit jackpot paid event
+pub(crate) fn emit_jackpot_paid(env: &Env, epoch: u32, winning_faction: u32, amount: i128) {
+    JackpotPaid {
+        epoch,
+        winning_faction,
+        amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit operation approved event
+pub(crate) fn emit_operation_approved(
+    env: &Env,
+    approver: &Address,
+    operation: &AdminOperation,
+    approvals: u32,
+) {
+    OperationApproved {
+        approver: approver.clone(),
+        operation: operation.clone(),
+        approvals,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, approver),
+    }
+    .publish(env);
+}
+
+/// Emit approval revoked event
+pub(crate) fn emit_approval_revoked(
+    env: &Env,
+    approver: &Address,
+    operation: &AdminOperation,
+    approvals: u32,
+) {
+    ApprovalRevoked {
+        approver: approver.clone(),
+        operation: operation.clone(),
+        approvals,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, approver),
+    }
+    .publish(env);
+}
+
+/// Emit governance vote event
+pub(crate) fn emit_parameter_voted(
+    env: &Env,
+    voter: &Address,
+    parameter: GovernanceParameter,
+    epoch: u32,
+    value: i128,
+    weight: i128,
+) {
+    ParameterVoted {
+        voter: voter.clone(),
+        parameter,
+        epoch,
+        value,
+        weight,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, voter),
+    }
+    .publish(env);
+}
+
+/// Emit governance result applied event
+pub(crate) fn emit_parameter_applied(
+    env: &Env,
+    epoch: u32,
+    parameter: GovernanceParameter,
+    value: i128,
+) {
+    ParameterApplied {
+        epoch,
+        parameter,
+        value,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit BLND burned event
+pub(crate) fn emit_blnd_burned(env: &Env, epoch: u32, amount: i128) {
+    BlndBurned {
+        epoch,
+        amount,
+        seq: next_seq(env),
+    }
+    .publish(env);
+}
+
+/// Emit session forfeited event
+pub(crate) fn emit_session_forfeited(
     env: &Env,
     game_id: &Address,
     session_id: u32,
-    player1: &Address,
-    player2: &Address,
-    player1_wager: i128,
-    player2_wager: i128,
-    player1_faction: u32,
-    player2_faction: u32,
-    player1_fp_remaining: i128,
-    player2_fp_remaining: i128,
+    afk_player: &Address,
 ) {
-    GameStarted {
+    SessionForfeited {
         game_id: game_id.clone(),
         session_id,
-        player1: player1.clone(),
-        player2: player2.clone(),
-        player1_wager,
-        player2_wager,
-        player1_faction,
-        player2_faction,
-        player1_fp_remaining,
-        player2_fp_remaining,
+        afk_player: afk_player.clone(),
+        seq: next_seq(env),
     }
     .publish(env);
 }
 
-/// Emit game ended event
-pub(crate) fn emit_game_ended(
+/// Emit quest completed event
+pub(crate) fn emit_quest_completed(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    quest: u32,
+    bonus_fp: i128,
+) {
+    QuestCompleted {
+        player: player.clone(),
+        epoch,
+        quest,
+        bonus_fp,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit session metadata attached event
+pub(crate) fn emit_session_metadata_attached(
     env: &Env,
     game_id: &Address,
     session_id: u32,
-    winner: &Address,
-    loser: &Address,
-    fp_contributed: i128,
+    result: bool,
+    metadata: &Bytes,
 ) {
-    GameEnded {
+    SessionMetadataAttached {
         game_id: game_id.clone(),
         session_id,
-        winner: winner.clone(),
-        loser: loser.clone(),
-        fp_contributed,
+        result,
+        metadata: metadata.clone(),
+        seq: next_seq(env),
     }
     .publish(env);
 }
 
-/// Emit epoch cycled event
-pub(crate) fn emit_epoch_cycled(
+/// Emit epoch data pruned event
+pub(crate) fn emit_epoch_data_pruned(
     env: &Env,
-    old_epoch: u32,
-    new_epoch: u32,
-    winning_faction: u32,
-    reward_pool: i128,
+    caller: &Address,
+    first_epoch: u32,
+    last_epoch: u32,
+    entries_removed: u32,
+    bounty: i128,
 ) {
-    EpochCycled {
-        old_epoch,
-        new_epoch,
-        winning_faction,
-        reward_pool,
+    EpochDataPruned {
+        caller: caller.clone(),
+        first_epoch,
+        last_epoch,
+        entries_removed,
+        bounty,
+        seq: next_seq(env),
     }
     .publish(env);
 }
 
-/// Emit rewards claimed event
-pub(crate) fn emit_rewards_claimed(
+/// Emit epoch boosted event
+pub(crate) fn emit_epoch_boosted(
     env: &Env,
-    player: &Address,
+    sponsor: &Address,
     epoch: u32,
-    faction: u32,
+    token: &Address,
     amount: i128,
+    pool_amount: i128,
 ) {
-    RewardsClaimed {
-        player: player.clone(),
+    EpochBoosted {
+        sponsor: sponsor.clone(),
         epoch,
-        faction,
+        token: token.clone(),
         amount,
+        pool_amount,
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, sponsor),
     }
     .publish(env);
 }
 
-/// Emit developer reward claimed event
-pub(crate) fn emit_dev_reward_claimed(
+/// Emit reward tokens claimed event
+pub(crate) fn emit_reward_tokens_claimed(
     env: &Env,
-    developer: &Address,
+    player: &Address,
     epoch: u32,
-    fp_contributed: i128,
-    amount: i128,
+    tokens: &Vec<TokenAmount>,
 ) {
-    DevRewardClaimed {
-        developer: developer.clone(),
+    RewardTokensClaimed {
+        player: player.clone(),
         epoch,
-        fp_contributed,
-        amount,
+        tokens: tokens.clone(),
+        seq: next_seq(env),
+        user_seq: next_user_seq(env, player),
+    }
+    .publish(env);
+}
+
+/// Emit Blend pool status changed event (a warning when `halted`)
+pub(crate) fn emit_pool_status_changed(
+    env: &Env,
+    pool: &Address,
+    old_status: Option<u32>,
+    status: u32,
+    halted: bool,
+) {
+    PoolStatusChanged {
+        pool: pool.clone(),
+        old_status,
+        status,
+        halted,
+        seq: next_seq(env),
     }
     .publish(env);
 }
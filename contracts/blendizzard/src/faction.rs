@@ -1,9 +1,19 @@
-use soroban_sdk::{Address, Env};
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-use crate::errors::Error;
-use crate::events::emit_faction_selected;
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_faction_info_set, emit_faction_selected};
 use crate::storage;
-use crate::types::Faction;
+use crate::types::{
+    Config, EpochPhase, Faction, FactionCap, FactionInfo, FactionLock, FactionLockReason,
+    FactionTotals, FACTION_COUNT, SCALAR_7,
+};
+
+/// Maximum faction name length (bytes)
+const MAX_FACTION_NAME_LEN: u32 = 32;
+
+/// Maximum faction symbol length (bytes)
+const MAX_FACTION_SYMBOL_LEN: u32 = 12;
 
 // ============================================================================
 // Faction Selection
@@ -30,24 +40,58 @@ use crate::types::Faction;
 /// * `player` - Player selecting the faction
 /// * `faction` - Faction ID (0=WholeNoodle, 1=PointyStick, 2=SpecialRock)
 ///
+/// Two-phase epochs: during the battle phase (see `epoch::current_phase`)
+/// players who already have a faction can't switch. First-time selection is
+/// always allowed so new players can join mid-epoch.
+///
+/// With `config.faction_cap` set, a faction that has outgrown the smallest
+/// one is full: players can't switch to it until the others catch up.
+///
 /// # Errors
 /// * `InvalidFaction` - If faction ID is not 0, 1, or 2
-pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Result<(), Error> {
+/// * `FactionFull` - If the faction has outgrown the smallest one (see `config.faction_cap`)
+/// * `NotWhitelisted` - If whitelist mode is on and the player isn't whitelisted
+/// * `FactionAlreadyLocked` - If switching factions during the battle phase
+pub(crate) fn select_faction(
+    env: &Env,
+    player: &Address,
+    faction: u32,
+) -> Result<(), ContractError> {
     // Validate faction
     if !Faction::is_valid(faction) {
-        return Err(Error::InvalidFaction);
+        return Err(Error::InvalidFaction.into());
     }
 
     // Authenticate player
     player.require_auth();
 
+    let config = storage::get_config(env);
+    crate::vault::require_whitelisted(env, &config, player)?;
+
+    // Factions are locked during the battle phase (except first-time selection)
+    let existing = storage::get_player(env, player);
+    if let Some(existing) = &existing {
+        if existing.selected_faction != faction
+            && config.selection_phase_duration > 0
+            && crate::epoch::current_phase(env, &config) == EpochPhase::Battle
+        {
+            return Err(Error::FactionAlreadyLocked.into());
+        }
+    }
+
+    // Keeping the current faction is always allowed
+    if existing.as_ref().map(|existing| existing.selected_faction) != Some(faction) {
+        require_faction_open(env, &config, faction)?;
+    }
+
     // Get or create player data
-    let mut player_data =
-        storage::get_player(env, player).unwrap_or_else(|| crate::types::Player {
-            selected_faction: faction,
-            time_multiplier_start: 0,
-            last_epoch_balance: 0,
-        });
+    let mut player_data = existing.unwrap_or_else(|| crate::types::Player {
+        selected_faction: faction,
+        time_multiplier_start: 0,
+        last_epoch_balance: 0,
+        streak: 0,
+        last_active_epoch: 0,
+    });
 
     // Update faction selection (always allowed - affects future epochs)
     player_data.selected_faction = faction;
@@ -55,8 +99,206 @@ pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Resul
     // Save player data
     storage::set_player(env, player, &player_data);
 
+    // Observe the balance for start-of-epoch snapshots
+    crate::snapshot::record(env, &config, player);
+
     // Emit event
     emit_faction_selected(env, player, faction);
 
     Ok(())
 }
+
+/// Get whether, and why, a player's faction is locked
+///
+/// Reasons are checked from most to least restrictive: the battle phase
+/// (switching fails), sessions in progress, then having played this epoch
+/// (switching only applies from the next epoch). Every lock ends with the
+/// current epoch.
+pub(crate) fn get_faction_lock(env: &Env, player: &Address) -> FactionLock {
+    let config = storage::get_config(env);
+    let current_epoch = storage::get_current_epoch(env);
+    let epoch_faction = storage::get_epoch_player(env, current_epoch, player)
+        .and_then(|epoch_player| epoch_player.epoch_faction);
+
+    let reason = if storage::get_player(env, player).is_some()
+        && config.selection_phase_duration > 0
+        && crate::epoch::current_phase(env, &config) == EpochPhase::Battle
+    {
+        FactionLockReason::BattlePhase
+    } else if !crate::game::get_active_sessions(env, player).is_empty() {
+        FactionLockReason::ActiveSessions
+    } else if epoch_faction.is_some() {
+        FactionLockReason::EpochFaction
+    } else {
+        FactionLockReason::Unlocked
+    };
+
+    let unlock_ledger = match (reason, storage::get_epoch(env, current_epoch)) {
+        (FactionLockReason::Unlocked, _) | (_, None) => 0,
+        (_, Some(epoch_info)) => {
+            let ledgers_left = crate::epoch_clock::ledgers_until(env, epoch_info.end_time);
+            env.ledger()
+                .sequence()
+                .saturating_add(ledgers_left.min(u32::MAX as u64) as u32)
+        }
+    };
+
+    FactionLock {
+        reason,
+        epoch_faction,
+        unlock_ledger,
+    }
+}
+
+/// Check that a faction isn't full under `config.faction_cap`
+///
+/// A faction is full while its members (or stake) locked in the current epoch
+/// exceed `ratio` times the smallest faction's. The smallest faction counts as
+/// at least one member (or `min_deposit_to_claim` of stake, at least 1), so a
+/// fresh epoch isn't locked up by its first players.
+///
+/// # Errors
+/// * `FactionFull` - If the faction is full
+fn require_faction_open(env: &Env, config: &Config, faction: u32) -> Result<(), ContractError> {
+    let ratio = match config.faction_cap {
+        FactionCap::None => return Ok(()),
+        FactionCap::Members(ratio) | FactionCap::Stake(ratio) => ratio,
+    };
+
+    let Some(epoch_info) = storage::get_epoch(env, storage::get_current_epoch(env)) else {
+        return Ok(());
+    };
+    let size = |faction: u32| match config.faction_cap {
+        FactionCap::Stake(_) => epoch_info.faction_stakes.get(faction).unwrap_or(0),
+        _ => epoch_info.faction_member_counts.get(faction).unwrap_or(0) as i128,
+    };
+    let floor = match config.faction_cap {
+        FactionCap::Stake(_) => config.min_deposit_to_claim.max(1),
+        _ => 1,
+    };
+
+    let smallest = (0..FACTION_COUNT).map(size).min().unwrap_or(0).max(floor);
+    let limit = smallest
+        .fixed_mul_floor(ratio, SCALAR_7)
+        .ok_or(Error::OverflowError)?;
+    if size(faction) > limit {
+        return Err(ExtError::FactionFull.into());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Faction Metadata
+// ============================================================================
+
+/// Set display metadata for a faction
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `faction` - Faction ID
+/// * `name` - Display name (1-32 bytes)
+/// * `symbol` - Ticker-style symbol (1-12 bytes)
+/// * `icon_hash` - Hash of the faction icon
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `InvalidFaction` - If faction ID is not valid
+/// * `InvalidFactionMetadata` - If name or symbol is empty or too long
+pub(crate) fn set_faction_info(
+    env: &Env,
+    faction: u32,
+    name: &String,
+    symbol: &String,
+    icon_hash: &BytesN<32>,
+) -> Result<(), Error> {
+    // Authenticate admin
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    if !Faction::is_valid(faction) {
+        return Err(Error::InvalidFaction);
+    }
+
+    if name.is_empty()
+        || name.len() > MAX_FACTION_NAME_LEN
+        || symbol.is_empty()
+        || symbol.len() > MAX_FACTION_SYMBOL_LEN
+    {
+        return Err(Error::InvalidFactionMetadata);
+    }
+
+    let info = FactionInfo {
+        id: faction,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        icon_hash: icon_hash.clone(),
+    };
+    let old_info = storage::get_faction_info(env, faction);
+    storage::set_faction_info(env, faction, &info);
+    crate::audit::record(env, &admin, "set_faction_info", &old_info, &info);
+
+    emit_faction_info_set(env, &info);
+
+    Ok(())
+}
+
+/// Get display metadata for all factions, ordered by ID
+///
+/// Factions without admin-set metadata use their built-in name and symbol
+/// and an all-zero icon hash.
+pub(crate) fn get_factions(env: &Env) -> Vec<FactionInfo> {
+    let mut factions = Vec::new(env);
+    for faction in 0..FACTION_COUNT {
+        let info = storage::get_faction_info(env, faction)
+            .unwrap_or_else(|| default_faction_info(env, faction));
+        factions.push_back(info);
+    }
+    factions
+}
+
+/// Get every faction's members, stake, points and projected pool share for an epoch
+///
+/// One call for live scoreboards. The player reward pool goes to a single
+/// faction, so `pool_share` is 100% for the faction that would win if the
+/// epoch ended now (the winner once finalized) and 0 for the others; all
+/// shares are 0 for an aborted epoch.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If the epoch doesn't exist
+pub(crate) fn get_faction_totals(env: &Env, epoch: u32) -> Result<Vec<FactionTotals>, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let leader = if epoch_info.aborted {
+        None
+    } else if epoch_info.is_finalized {
+        epoch_info.winning_faction
+    } else {
+        Some(crate::epoch::determine_winning_faction(&epoch_info)?)
+    };
+
+    let mut totals = Vec::new(env);
+    for faction in 0..FACTION_COUNT {
+        totals.push_back(FactionTotals {
+            faction,
+            members: epoch_info.faction_member_counts.get(faction).unwrap_or(0),
+            stake: epoch_info.faction_stakes.get(faction).unwrap_or(0),
+            points: epoch_info.faction_standings.get(faction).unwrap_or(0),
+            pool_share: if leader == Some(faction) { SCALAR_7 } else { 0 },
+        });
+    }
+    Ok(totals)
+}
+
+/// Built-in metadata for a faction
+fn default_faction_info(env: &Env, faction: u32) -> FactionInfo {
+    let (name, symbol) = match faction {
+        0 => ("WholeNoodle", "NOODLE"),
+        1 => ("PointyStick", "STICK"),
+        _ => ("SpecialRock", "ROCK"),
+    };
+    FactionInfo {
+        id: faction,
+        name: String::from_str(env, name),
+        symbol: String::from_str(env, symbol),
+        icon_hash: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
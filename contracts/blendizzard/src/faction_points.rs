@@ -2,6 +2,7 @@ use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{Address, Env};
 
 use crate::errors::Error;
+use crate::math::{checked_decay, checked_mul_div, Rounding, BPS_SCALE};
 use crate::storage;
 use crate::types::{
     EpochPlayer, BASE_FP_PER_USDC, COMPONENT_PEAK, FIXED_POINT_ONE, MAX_AMOUNT_USD,
@@ -19,10 +20,13 @@ use crate::types::{
 ///
 /// # Formula
 /// ```
-/// total_fp = free_fp_per_epoch + deposit_fp
+/// total_fp = (free_fp_per_epoch + deposit_fp) * underdog_multiplier * achievement_multiplier
 /// deposit_fp = (deposit_amount * 100) * amount_multiplier * time_multiplier
 /// ```
-/// Where: **1 USDC = 100 FP** (before multipliers)
+/// Where: **1 USDC = 100 FP** (before multipliers), and `underdog_multiplier` is
+/// the current epoch's multiplier for the player's faction (see `underdog`) and
+/// `achievement_multiplier` adds the achievement FP bonus per unlocked
+/// achievement (see `achievements`)
 ///
 /// # Free Play Mechanics
 /// - All players receive `config.free_fp_per_epoch` FP each epoch (default: 100 FP)
@@ -55,7 +59,8 @@ use crate::types::{
 /// * `player` - Player to calculate FP for
 ///
 /// # Returns
-/// Total faction points for the player (free FP + deposit FP)
+/// Total faction points for the player (free FP + deposit FP, boosted for
+/// underdogs and achievements)
 ///
 /// # Errors
 /// * `OverflowError` - If calculation overflows
@@ -66,12 +71,14 @@ pub(crate) fn calculate_faction_points(env: &Env, player: &Address) -> Result<i1
     // Get config for free FP allocation
     let config = storage::get_config(env);
 
-    // Query vault balance
-    let base_amount = crate::vault::get_vault_balance(env, player);
+    // Query vault balance (start-of-epoch balance when snapshots are enabled)
+    let base_amount = crate::snapshot::fp_balance(env, &config, player);
 
     // If no deposit, return only the free FP allocation
     if base_amount == 0 {
-        return Ok(config.free_fp_per_epoch);
+        let fp =
+            apply_underdog_multiplier(env, player_data.selected_faction, config.free_fp_per_epoch)?;
+        return crate::achievements::apply_fp_bonus(env, player, fp);
     }
 
     // Calculate deposit-based FP with multipliers
@@ -91,7 +98,21 @@ pub(crate) fn calculate_faction_points(env: &Env, player: &Address) -> Result<i1
         .checked_add(deposit_fp)
         .ok_or(Error::OverflowError)?;
 
-    Ok(total_fp)
+    let fp = apply_underdog_multiplier(env, player_data.selected_faction, total_fp)?;
+    crate::achievements::apply_fp_bonus(env, player, fp)
+}
+
+/// Scale FP by the current epoch's underdog multiplier for a faction
+fn apply_underdog_multiplier(env: &Env, faction: u32, fp: i128) -> Result<i128, Error> {
+    let Some(epoch) = storage::get_epoch(env, storage::get_current_epoch(env)) else {
+        return Ok(fp);
+    };
+    let multiplier = crate::underdog::faction_multiplier(&epoch, faction);
+    if multiplier == SCALAR_7 {
+        return Ok(fp);
+    }
+    fp.fixed_mul_floor(multiplier, SCALAR_7)
+        .ok_or(Error::OverflowError)
 }
 
 /// Calculate amount multiplier using smooth piecewise (cubic Hermite spline)
@@ -375,6 +396,7 @@ fn calculate_fp_from_multipliers(
 /// * `env` - Contract environment
 /// * `player` - Player to initialize FP for
 /// * `current_epoch` - Current epoch number
+/// * `streak_bonus` - Participation streak bonus to snapshot for this epoch
 ///
 /// # Returns
 /// Total faction points calculated
@@ -382,12 +404,13 @@ pub(crate) fn initialize_epoch_fp(
     env: &Env,
     player: &Address,
     current_epoch: u32,
+    streak_bonus: i128,
 ) -> Result<i128, Error> {
     // Calculate total FP (queries vault internally)
     let total_fp = calculate_faction_points(env, player)?;
 
-    // Get current vault balance for snapshot
-    let current_balance = crate::vault::get_vault_balance(env, player);
+    // Get the balance the FP was based on, for the snapshot
+    let current_balance = crate::snapshot::fp_balance(env, &storage::get_config(env), player);
 
     // Get or create epoch player data
     let mut epoch_player =
@@ -396,12 +419,16 @@ pub(crate) fn initialize_epoch_fp(
             epoch_balance_snapshot: current_balance, // Snapshot current balance
             available_fp: 0,
             total_fp_contributed: 0,
+            streak_bonus,
+            games_completed: 0,
+            reward_eligible: crate::activity::eligible_from_start(env),
         });
 
     // Set available FP (only if not already set)
     if epoch_player.available_fp == 0 && epoch_player.total_fp_contributed == 0 {
         epoch_player.available_fp = total_fp;
         epoch_player.epoch_balance_snapshot = current_balance; // Update snapshot
+        epoch_player.streak_bonus = streak_bonus;
     }
 
     // Save epoch player data
@@ -442,9 +469,10 @@ pub(crate) fn prepare_player_for_game(
     let mut epoch_player =
         storage::get_epoch_player(env, current_epoch, player).ok_or(Error::PlayerNotFound)?;
 
-    // Lock faction if not already locked
+    // Lock faction if not already locked (counting the player as a member)
     if epoch_player.epoch_faction.is_none() {
         epoch_player.epoch_faction = Some(selected_faction);
+        add_faction_member(env, current_epoch, selected_faction, &epoch_player)?;
     }
 
     // Check if player has enough available FP
@@ -463,3 +491,136 @@ pub(crate) fn prepare_player_for_game(
 
     Ok(epoch_player)
 }
+
+/// Count a newly locked player towards its faction's members and stake
+fn add_faction_member(
+    env: &Env,
+    current_epoch: u32,
+    faction: u32,
+    epoch_player: &EpochPlayer,
+) -> Result<(), Error> {
+    let mut epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+
+    let members = epoch_info
+        .faction_member_counts
+        .get(faction)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(Error::OverflowError)?;
+    epoch_info.faction_member_counts.set(faction, members);
+
+    let stake = epoch_info
+        .faction_stakes
+        .get(faction)
+        .unwrap_or(0)
+        .checked_add(epoch_player.epoch_balance_snapshot)
+        .ok_or(Error::OverflowError)?;
+    epoch_info.faction_stakes.set(faction, stake);
+
+    storage::set_epoch(env, current_epoch, &epoch_info);
+    Ok(())
+}
+
+/// Whether a winner's stake is high enough for their wins to credit faction points
+///
+/// Anti-sybil floor (`set_min_fp_stake`): winners whose epoch balance snapshot
+/// is below the minimum still settle their wagers, but credit no faction
+/// points, so dust accounts can't farm the participation share.
+pub(crate) fn meets_min_stake(env: &Env, epoch_player: &EpochPlayer) -> bool {
+    epoch_player.epoch_balance_snapshot >= storage::get_min_fp_stake(env)
+}
+
+/// Faction contribution credited for a winning wager
+///
+/// With `config.fp_half_life_ledgers` set, FP contributed early in an epoch
+/// decays towards the epoch's end: the wager is credited at its decayed value
+/// as of `end_time`, halving every half-life of ledgers left until then.
+/// Every contribution is decayed to the same point, so standings, player
+/// contributions and leaders compare exactly as if all points decayed lazily
+/// and were evaluated when the epoch ends, without a per-player checkpoint.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `current_epoch` - Epoch the game is settled in
+/// * `wager` - Winner's wager in FP
+///
+/// # Errors
+/// * `EpochNotFinalized` - If the epoch doesn't exist
+/// * `OverflowError` - If the decay calculation overflows
+pub(crate) fn decayed_contribution(
+    env: &Env,
+    current_epoch: u32,
+    wager: i128,
+) -> Result<i128, Error> {
+    let half_life = storage::get_config(env).fp_half_life_ledgers;
+    if half_life == 0 {
+        return Ok(wager);
+    }
+
+    let epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+    let ledgers_left = crate::epoch_clock::ledgers_until(env, epoch_info.end_time);
+
+    checked_decay(wager, ledgers_left, half_life as u64).ok_or(Error::OverflowError)
+}
+
+/// Expected score of an even match, in basis points
+const EVEN_ODDS_BPS: i128 = 5_000;
+
+/// Scale a winning contribution by how unlikely the win was
+///
+/// With `WinOddsCurve::strength_bps` set, the winner's expected score blends
+/// their Elo expectation against the loser (ratings before this game, see
+/// `rating.rs`) with their share of the two wagers:
+///
+///   expected   = w * elo_expected + (1 - w) * winner_wager / (winner_wager + loser_wager)
+///   multiplier = 1 + strength * (0.5 - expected) / 0.5
+///
+/// An even match credits the contribution unchanged; beating a higher-rated
+/// or higher-staked opponent earns up to (1 + strength)x, and stomping a
+/// weaker or smaller stake down to (1 - strength)x.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Game the ratings are read for
+/// * `winner` / `loser` - Players of the game
+/// * `winner_wager` / `loser_wager` - Their wagers in FP
+/// * `contribution` - Winner's contribution before the adjustment
+///
+/// # Errors
+/// * `OverflowError` - If the scaled contribution overflows
+pub(crate) fn odds_adjusted_contribution(
+    env: &Env,
+    game_id: &Address,
+    winner: &Address,
+    loser: &Address,
+    winner_wager: i128,
+    loser_wager: i128,
+    contribution: i128,
+) -> Result<i128, Error> {
+    let curve = storage::get_win_odds_curve(env);
+    if curve.strength_bps == 0 || contribution <= 0 {
+        return Ok(contribution);
+    }
+
+    let rating_expected = crate::rating::expected_score_bps(
+        crate::rating::get_rating(env, winner, game_id),
+        crate::rating::get_rating(env, loser, game_id),
+    ) as i128;
+    let total_wager = winner_wager
+        .checked_add(loser_wager)
+        .ok_or(Error::OverflowError)?;
+    let stake_expected = if total_wager > 0 {
+        checked_mul_div(winner_wager, BPS_SCALE, total_wager, Rounding::Floor)
+            .ok_or(Error::OverflowError)?
+    } else {
+        EVEN_ODDS_BPS
+    };
+    let rating_weight = curve.rating_weight_bps as i128;
+    let expected = (rating_expected * rating_weight + stake_expected * (BPS_SCALE - rating_weight))
+        / BPS_SCALE;
+
+    let multiplier =
+        BPS_SCALE + curve.strength_bps as i128 * (EVEN_ODDS_BPS - expected) / EVEN_ODDS_BPS;
+    checked_mul_div(contribution, multiplier, BPS_SCALE, Rounding::Floor)
+        .ok_or(Error::OverflowError)
+}
@@ -1,10 +1,30 @@
-use soroban_sdk::{vec, Address, Env, IntoVal as _};
+use soroban_sdk::{vec, xdr::ToXdr, Address, Bytes, Env, IntoVal as _, Vec};
 
-use crate::errors::Error;
-use crate::events::{emit_game_ended, emit_game_started};
+use crate::errors::{ContractError, Error};
+use crate::events::{
+    emit_game_ended, emit_game_started, emit_session_forfeited, emit_session_metadata_attached,
+};
 use crate::faction_points::initialize_epoch_fp;
+use crate::math::checked_fixed_sqrt;
 use crate::storage;
-use crate::types::{EpochGame, GameInfo, GameSession};
+use crate::streak::{next_streak, reward_weight, streak_bonus};
+use crate::types::{
+    ActiveSession, EpochGame, EpochInfo, EpochPhase, EpochPlayer, GameInfo, GameListing,
+    GameMetadata, GameSession, GameStatus, PointFormula, RakeRoute, SessionMetadata, SessionState,
+    SCALAR_7,
+};
+
+/// Maximum game name length accepted from `game_metadata` (bytes)
+const MAX_GAME_NAME_LEN: u32 = 32;
+
+/// Default minimum session age before a forfeit can be reported (~1 hour at 5s per ledger)
+pub(crate) const DEFAULT_FORFEIT_DELAY: u32 = 720;
+
+/// Maximum size of the match details a game attaches to a session (bytes)
+const MAX_SESSION_METADATA_LEN: u32 = 256;
+
+/// Most faction points a practice win can contribute (1 FP)
+pub(crate) const MAX_PRACTICE_FP: i128 = 1_0000000;
 
 // ============================================================================
 // Game Registry
@@ -15,7 +35,10 @@ use crate::types::{EpochGame, GameInfo, GameSession};
 /// Only registered games can be played. This prevents malicious contracts
 /// from interacting with the Blendizzard system.
 ///
-/// Can be called multiple times to update the developer address.
+/// Can be called multiple times to update the developer address. Wager
+/// limits, the dispute window and the rake already configured for the game
+/// are kept. Also approves a pending self-registration, without calling its
+/// init hook (see `approve_game`).
 ///
 /// # Arguments
 /// * `env` - Contract environment
@@ -29,13 +52,17 @@ pub(crate) fn add_game(env: &Env, game_id: &Address, developer: &Address) -> Res
     let admin = storage::get_admin(env);
     admin.require_auth();
 
-    // Create game info with developer address
-    let game_info = GameInfo {
-        developer: developer.clone(),
-    };
+    // Create game info with developer address, keeping any wager limits
+    // and dispute window
+    let old_info = storage::get_game_registration(env, game_id);
+    let mut game_info = old_info.clone().unwrap_or_else(|| new_game_info(developer));
+    game_info.developer = developer.clone();
+    game_info.pending = false;
 
     // Save game registration
     storage::set_game_info(env, game_id, &game_info);
+    list_game(env, game_id);
+    crate::audit::record(env, &admin, "add_game", &old_info, &game_info);
 
     // Emit event
     crate::events::emit_game_added(env, game_id, developer);
@@ -43,8 +70,116 @@ pub(crate) fn add_game(env: &Env, game_id: &Address, developer: &Address) -> Res
     Ok(())
 }
 
+/// Request registration for a game contract implementing the standard
+/// Blendizzard game interface (see `game_interface.rs`)
+///
+/// Called by the game contract itself, with the metadata its `game_metadata`
+/// returns. The game stays pending (and can't be played) until the admin
+/// approves it with `approve_game` or rejects it with `remove_game`.
+/// Re-registering while pending replaces the metadata.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract registering itself
+/// * `metadata` - Game name and developer address
+///
+/// # Errors
+/// * `InvalidConfig` - If the game is already approved, or its name is empty
+///   or too long
+pub(crate) fn register_game(
+    env: &Env,
+    game_id: &Address,
+    metadata: &GameMetadata,
+) -> Result<(), Error> {
+    game_id.require_auth();
+
+    if storage::is_game_registered(env, game_id) {
+        return Err(Error::InvalidConfig);
+    }
+    if metadata.name.is_empty() || metadata.name.len() > MAX_GAME_NAME_LEN {
+        return Err(Error::InvalidConfig);
+    }
+
+    let mut game_info = new_game_info(&metadata.developer);
+    game_info.pending = true;
+    storage::set_game_info(env, game_id, &game_info);
+    list_game(env, game_id);
+
+    crate::events::emit_game_registration_requested(
+        env,
+        game_id,
+        &metadata.developer,
+        &metadata.name,
+    );
+
+    Ok(())
+}
+
+/// Approve a pending game self-registration
+///
+/// Calls the game's `blendizzard_init` hook with this contract's address, then
+/// registers the game as `add_game` would.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the pending game contract
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `GameNotRegistered` - If the game has no pending registration
+pub(crate) fn approve_game(env: &Env, game_id: &Address) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_registration(env, game_id)
+        .filter(|info| info.pending)
+        .ok_or(Error::GameNotRegistered)?;
+    let old_info = game_info.clone();
+    game_info.pending = false;
+    storage::set_game_info(env, game_id, &game_info);
+    crate::audit::record(env, &admin, "approve_game", &old_info, &game_info);
+
+    crate::game_interface::Client::new(env, game_id)
+        .blendizzard_init(&env.current_contract_address());
+
+    crate::events::emit_game_added(env, game_id, &game_info.developer);
+
+    Ok(())
+}
+
+/// Registration for a new game with default limits, no dispute window and no rake
+fn new_game_info(developer: &Address) -> GameInfo {
+    GameInfo {
+        developer: developer.clone(),
+        min_wager: 0,
+        max_wager: 0,
+        dispute_ledgers: 0,
+        rake_bps: 0,
+        rake_route: RakeRoute::RewardPool,
+        pending: false,
+        max_active_sessions: 0,
+        max_sessions_per_ledger: 0,
+        practice_fp: None,
+        point_formula: PointFormula::Stake,
+    }
+}
+
+/// Add a game to the registry list shown by `get_games` (no-op if listed)
+///
+/// Games registered before the list was kept are listed on their next `add_game`.
+fn list_game(env: &Env, game_id: &Address) {
+    let mut games = storage::get_game_list(env);
+    if !games.contains(game_id) {
+        games.push_back(game_id.clone());
+        storage::set_game_list(env, &games);
+    }
+}
+
 /// Remove a game contract from the approved list
 ///
+/// Also drops it from `get_games` and clears its deprecation; its lifetime
+/// session counters are kept.
+///
 /// Note: If the game has contributions in the current epoch, those will be
 /// forfeited (developer cannot claim rewards for removed games).
 ///
@@ -60,7 +195,15 @@ pub(crate) fn remove_game(env: &Env, game_id: &Address) -> Result<(), Error> {
     admin.require_auth();
 
     // Remove game registration
+    let old_info = storage::get_game_registration(env, game_id);
     storage::remove_game_info(env, game_id);
+    storage::set_game_deprecated(env, game_id, false);
+    let mut games = storage::get_game_list(env);
+    if let Some(index) = games.first_index_of(game_id) {
+        games.remove(index);
+        storage::set_game_list(env, &games);
+    }
+    crate::audit::record(env, &admin, "remove_game", &old_info, &());
 
     // Emit event
     crate::events::emit_game_removed(env, game_id);
@@ -68,6 +211,408 @@ pub(crate) fn remove_game(env: &Env, game_id: &Address) -> Result<(), Error> {
     Ok(())
 }
 
+/// Deprecate a registered game, or reinstate it
+///
+/// A deprecated game can't start new sessions (1v1, series or team), but its
+/// in-flight sessions still settle and its developer keeps claiming rewards.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `deprecated` - Whether new sessions are refused
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `GameNotWhitelisted` - If the game is not registered
+pub(crate) fn set_deprecated(env: &Env, game_id: &Address, deprecated: bool) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    if !storage::is_game_registered(env, game_id) {
+        return Err(Error::GameNotWhitelisted);
+    }
+
+    let was_deprecated = storage::is_game_deprecated(env, game_id);
+    storage::set_game_deprecated(env, game_id, deprecated);
+
+    crate::audit::record(
+        env,
+        &admin,
+        "set_game_deprecated",
+        &was_deprecated,
+        &deprecated,
+    );
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Get a registered game that accepts new sessions
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered, pending or deprecated
+pub(crate) fn get_playable_game(env: &Env, game_id: &Address) -> Result<GameInfo, Error> {
+    let game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+    if storage::is_game_deprecated(env, game_id) {
+        return Err(Error::GameNotWhitelisted);
+    }
+    Ok(game_info)
+}
+
+/// Maximum number of games returned by one `get_games` call
+const MAX_GAMES_PER_QUERY: u32 = 50;
+
+/// Get a page of the game registry, in registration order
+///
+/// Lists approved and pending games with their limits, rake, status and
+/// lifetime session counters.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `offset` - Games to skip
+/// * `limit` - Maximum games to return (capped at `MAX_GAMES_PER_QUERY`)
+pub(crate) fn get_games(env: &Env, offset: u32, limit: u32) -> Vec<GameListing> {
+    let limit = limit.min(MAX_GAMES_PER_QUERY);
+
+    let mut listings = Vec::new(env);
+    for game_id in storage::get_game_list(env).iter().skip(offset as usize) {
+        if listings.len() >= limit {
+            break;
+        }
+        let Some(info) = storage::get_game_registration(env, &game_id) else {
+            continue;
+        };
+        let status = if info.pending {
+            GameStatus::Pending
+        } else if storage::is_game_deprecated(env, &game_id) {
+            GameStatus::Deprecated
+        } else {
+            GameStatus::Active
+        };
+        let totals = storage::get_game_totals(env, &game_id);
+        listings.push_back(GameListing {
+            weight_bps: crate::stats::game_weight_bps(env, &totals),
+            game_id,
+            developer: info.developer,
+            rake_bps: info.rake_bps,
+            rake_route: info.rake_route,
+            min_wager: info.min_wager,
+            max_wager: info.max_wager,
+            status,
+            sessions_started: totals.sessions_started,
+            sessions_finished: totals.sessions_finished,
+        });
+    }
+    listings
+}
+
+/// Set the per-player wager bounds for a registered game
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `min_wager` - Smallest FP wager per player (0 = no minimum)
+/// * `max_wager` - Largest FP wager per player (0 = no maximum)
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+/// * `InvalidConfig` - If a bound is negative or max_wager is below min_wager
+pub(crate) fn set_wager_limits(
+    env: &Env,
+    game_id: &Address,
+    min_wager: i128,
+    max_wager: i128,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+
+    if min_wager < 0 || max_wager < 0 || (max_wager > 0 && max_wager < min_wager) {
+        return Err(Error::InvalidConfig);
+    }
+
+    let old_info = game_info.clone();
+    game_info.min_wager = min_wager;
+    game_info.max_wager = max_wager;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(env, &admin, "set_game_wager_limits", &old_info, &game_info);
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Set the dispute window for a registered game's results
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `dispute_ledgers` - Ledgers a result stays disputable (0 = settle immediately)
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+pub(crate) fn set_dispute_window(
+    env: &Env,
+    game_id: &Address,
+    dispute_ledgers: u32,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+    let old_info = game_info.clone();
+    game_info.dispute_ledgers = dispute_ledgers;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(
+        env,
+        &admin,
+        "set_game_dispute_window",
+        &old_info,
+        &game_info,
+    );
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Set the rake taken from a registered game's wagers
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `rake_bps` - House cut of each wager, in basis points
+/// * `rake_route` - Where the raked share of the reward pool goes
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+/// * `InvalidConfig` - If rake_bps is above 10_000
+pub(crate) fn set_rake(
+    env: &Env,
+    game_id: &Address,
+    rake_bps: u32,
+    rake_route: RakeRoute,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+
+    if rake_bps > crate::rake::MAX_RAKE_BPS {
+        return Err(Error::InvalidConfig);
+    }
+
+    let old_info = game_info.clone();
+    game_info.rake_bps = rake_bps;
+    game_info.rake_route = rake_route;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(env, &admin, "set_game_rake", &old_info, &game_info);
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Set the session limits for a registered game
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `max_active_sessions` - Most sessions active at once (0 = no limit)
+/// * `max_sessions_per_ledger` - Most sessions started per ledger (0 = no limit)
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+pub(crate) fn set_session_limits(
+    env: &Env,
+    game_id: &Address,
+    max_active_sessions: u32,
+    max_sessions_per_ledger: u32,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+    let old_info = game_info.clone();
+    game_info.max_active_sessions = max_active_sessions;
+    game_info.max_sessions_per_ledger = max_sessions_per_ledger;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(
+        env,
+        &admin,
+        "set_game_session_limits",
+        &old_info,
+        &game_info,
+    );
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Allow or disallow practice (zero-wager) sessions for a registered game
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `practice_fp` - Faction points a practice win contributes (0 = none),
+///   or None to disallow practice sessions
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+/// * `InvalidConfig` - If practice_fp is negative or above `MAX_PRACTICE_FP`
+pub(crate) fn set_practice(
+    env: &Env,
+    game_id: &Address,
+    practice_fp: Option<i128>,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+
+    if practice_fp.is_some_and(|fp| !(0..=MAX_PRACTICE_FP).contains(&fp)) {
+        return Err(Error::InvalidConfig);
+    }
+
+    let old_info = game_info.clone();
+    game_info.practice_fp = practice_fp;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(env, &admin, "set_game_practice", &old_info, &game_info);
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Set how a registered game's wins convert to faction points
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `point_formula` - Formula applied when the game's sessions settle
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If the game is not registered
+/// * `InvalidConfig` - If a flat amount isn't positive or a margin floor is above 10_000 bps
+pub(crate) fn set_point_formula(
+    env: &Env,
+    game_id: &Address,
+    point_formula: PointFormula,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut game_info = storage::get_game_info(env, game_id).ok_or(Error::GameNotWhitelisted)?;
+
+    if !crate::point_formula::is_valid(&point_formula) {
+        return Err(Error::InvalidConfig);
+    }
+
+    let old_info = game_info.clone();
+    game_info.point_formula = point_formula;
+    storage::set_game_info(env, game_id, &game_info);
+
+    crate::audit::record(env, &admin, "set_game_point_formula", &old_info, &game_info);
+    crate::events::emit_config_updated(env, &admin);
+
+    Ok(())
+}
+
+/// Check whether a session is a practice session (nothing wagered by either player)
+fn is_practice(session: &GameSession) -> bool {
+    session.player1_wager == 0 && session.player2_wager == 0
+}
+
+/// Count a new session against its game's session limits
+///
+/// Sessions are only tracked while a limit is set, so `max_active_sessions`
+/// counts sessions started since it was set.
+///
+/// # Errors
+/// * `TooManyActiveSessions` - If the game is at either limit
+pub(crate) fn reserve_game_session(
+    env: &Env,
+    game_id: &Address,
+    game_info: &GameInfo,
+    session_id: u32,
+) -> Result<(), Error> {
+    if game_info.max_active_sessions == 0 && game_info.max_sessions_per_ledger == 0 {
+        return Ok(());
+    }
+
+    let mut load = storage::get_game_load(env, game_id);
+    let ledger = env.ledger().sequence();
+    if load.ledger != ledger {
+        load.ledger = ledger;
+        load.started_in_ledger = 0;
+    }
+    if game_info.max_sessions_per_ledger > 0
+        && load.started_in_ledger >= game_info.max_sessions_per_ledger
+    {
+        return Err(Error::TooManyActiveSessions);
+    }
+    load.started_in_ledger += 1;
+
+    if game_info.max_active_sessions > 0 {
+        let current_epoch = storage::get_current_epoch(env);
+        let mut active = Vec::new(env);
+        for id in load.active_sessions.iter() {
+            if is_session_active(env, id, current_epoch) {
+                active.push_back(id);
+            }
+        }
+        if active.len() >= game_info.max_active_sessions {
+            return Err(Error::TooManyActiveSessions);
+        }
+        active.push_back(session_id);
+        load.active_sessions = active;
+    } else {
+        load.active_sessions = Vec::new(env);
+    }
+
+    storage::set_game_load(env, game_id, &load);
+    Ok(())
+}
+
+/// Reject a wager outside the game's configured bounds
+///
+/// # Errors
+/// * `InvalidAmount` - If the wager is <= 0 or outside [min_wager, max_wager]
+pub(crate) fn require_wager_in_limits(game_info: &GameInfo, wager: i128) -> Result<(), Error> {
+    if wager <= 0
+        || wager < game_info.min_wager
+        || (game_info.max_wager > 0 && wager > game_info.max_wager)
+    {
+        return Err(Error::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Reject wagers the game doesn't accept for a new 1v1 session
+///
+/// Zero wagers on both sides start a practice session, accepted if the game
+/// allows practice (see `set_practice`).
+///
+/// # Errors
+/// * `InvalidAmount` - If a wager is outside the game's wager limits, or both
+///   are zero and the game doesn't allow practice sessions
+fn require_wagers_allowed(
+    game_info: &GameInfo,
+    player1_wager: i128,
+    player2_wager: i128,
+) -> Result<(), Error> {
+    if player1_wager == 0 && player2_wager == 0 {
+        return match game_info.practice_fp {
+            Some(_) => Ok(()),
+            None => Err(Error::InvalidAmount),
+        };
+    }
+    require_wager_in_limits(game_info, player1_wager)?;
+    require_wager_in_limits(game_info, player2_wager)
+}
+
 /// Check if a contract is an approved game
 ///
 /// # Arguments
@@ -103,9 +648,14 @@ pub(crate) fn is_game(env: &Env, game_id: &Address) -> bool {
 /// * `player2_wager` - Faction points wagered by player2
 ///
 /// # Errors
-/// * `GameNotWhitelisted` - If game_id is not in the whitelist
-/// * `SessionAlreadyExists` - If session_id already exists
-/// * `InvalidAmount` - If wagers are <= 0
+/// * `GameNotWhitelisted` - If game_id is not in the whitelist or is deprecated
+/// * `SessionAlreadyExists` - If session_id has ever been used (by any game)
+/// * `SelectionPhaseActive` - If the epoch is still in its faction-selection phase
+/// * `PoolHalted` - If game starts are blocked and the Blend pool is on ice or frozen
+/// * `TooManyActiveSessions` - If either player is at `config.max_active_sessions`,
+///   or the game is at its session limits
+/// * `InvalidAmount` - If wagers are <= 0 or outside the game's wager limits
+///   (both 0 is a practice session, if the game allows them)
 /// * `PlayerNotFound` - If players don't exist
 /// * `InsufficientFactionPoints` - If players don't have enough FP
 pub(crate) fn start_game(
@@ -116,27 +666,32 @@ pub(crate) fn start_game(
     player2: &Address,
     player1_wager: i128,
     player2_wager: i128,
-) -> Result<(), Error> {
+) -> Result<(), ContractError> {
     // SECURITY: Require game contract to authorize this call
     // Only the registered game contract should be able to start sessions
     // This prevents fake sessions from being created with a registered game_id
     game_id.require_auth();
 
-    // Validate game is registered
-    if !storage::is_game_registered(env, game_id) {
-        return Err(Error::GameNotWhitelisted);
-    }
+    // Validate game is registered and not deprecated
+    let game_info = get_playable_game(env, game_id)?;
 
-    // Validate session doesn't already exist
-    if storage::has_session(env, session_id) {
-        return Err(Error::SessionAlreadyExists);
+    // Games only count during the battle phase
+    let config = storage::get_config(env);
+    if crate::epoch::current_phase(env, &config) == EpochPhase::Selection {
+        return Err(Error::SelectionPhaseActive.into());
     }
+    crate::vault::require_pool_open_for_games(env, &config)?;
 
-    // Validate wagers
-    if player1_wager <= 0 || player2_wager <= 0 {
-        return Err(Error::InvalidAmount);
+    // Validate session ID was never used by any game
+    // Sessions live in temporary storage, so the persistent marker is what
+    // prevents an ID from being replayed after its session entry expires
+    if storage::has_session(env, session_id) || storage::is_session_id_used(env, session_id) {
+        return Err(Error::SessionAlreadyExists.into());
     }
 
+    // Validate wagers against the game's bounds (or its practice mode)
+    require_wagers_allowed(&game_info, player1_wager, player2_wager)?;
+
     // Authenticate players (for their consent to lock FP)
     player1.require_auth_for_args(vec![
         &env,
@@ -156,6 +711,20 @@ pub(crate) fn start_game(
     storage::get_player(env, player1).ok_or(Error::FactionNotSelected)?;
     storage::get_player(env, player2).ok_or(Error::FactionNotSelected)?;
 
+    // Enforce the per-player active session cap
+    let max_active_sessions = config.max_active_sessions;
+    let mut p1_sessions = get_active_sessions(env, player1);
+    let mut p2_sessions = get_active_sessions(env, player2);
+    if max_active_sessions > 0
+        && (p1_sessions.len() >= max_active_sessions || p2_sessions.len() >= max_active_sessions)
+    {
+        return Err(Error::TooManyActiveSessions.into());
+    }
+
+    // Enforce the game's session limits
+    reserve_game_session(env, game_id, &game_info, session_id)?;
+    crate::stats::record_session_started(env, game_id);
+
     // Get current epoch
     let current_epoch = storage::get_current_epoch(env);
 
@@ -182,8 +751,20 @@ pub(crate) fn start_game(
         player1_won: None,
     };
 
-    // Save session
+    // Save session and permanently reserve its ID
     storage::set_session(env, session_id, &session);
+    storage::set_session_id_used(env, session_id);
+
+    // Index the session under its game until it settles (see `get_sessions`)
+    let mut game_sessions = storage::get_game_sessions(env, game_id, current_epoch);
+    game_sessions.set(session_id, env.ledger().sequence());
+    storage::set_game_sessions(env, game_id, current_epoch, &game_sessions);
+
+    // Track the session as active for both players
+    p1_sessions.push_back(session_id);
+    p2_sessions.push_back(session_id);
+    storage::set_active_sessions(env, player1, &p1_sessions);
+    storage::set_active_sessions(env, player2, &p2_sessions);
 
     // Emit event with enhanced data
     emit_game_started(
@@ -203,24 +784,169 @@ pub(crate) fn start_game(
     Ok(())
 }
 
+/// Start a new game session with an on-chain derived session ID
+///
+/// Instead of trusting a caller-supplied ID, the ID is derived from the game's
+/// monotonically increasing nonce: `sha256(game_id XDR || nonce)`, truncated
+/// to u32. Nonces whose ID collides with an already-used ID are skipped.
+///
+/// Players authorize the derived ID, which can be fetched beforehand with
+/// `next_session_id`.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `player1` - First player's address
+/// * `player2` - Second player's address
+/// * `player1_wager` - Faction points wagered by player1
+/// * `player2_wager` - Faction points wagered by player2
+///
+/// # Returns
+/// The derived session ID
+///
+/// # Errors
+/// Same as `start_game`
+pub(crate) fn start_game_with_nonce(
+    env: &Env,
+    game_id: &Address,
+    player1: &Address,
+    player2: &Address,
+    player1_wager: i128,
+    player2_wager: i128,
+) -> Result<u32, ContractError> {
+    let (session_id, next_nonce) = find_next_session_id(env, game_id)?;
+    storage::set_game_nonce(env, game_id, next_nonce);
+
+    start_game(
+        env,
+        game_id,
+        session_id,
+        player1,
+        player2,
+        player1_wager,
+        player2_wager,
+    )?;
+
+    Ok(session_id)
+}
+
+/// Get the session ID the next `start_game_with_nonce` call for a game will use
+///
+/// # Errors
+/// * `OverflowError` - If the game's nonce space is exhausted
+pub(crate) fn next_session_id(env: &Env, game_id: &Address) -> Result<u32, Error> {
+    Ok(find_next_session_id(env, game_id)?.0)
+}
+
+/// Get a player's active sessions
+///
+/// A session (1v1 or team) is active while it has no outcome and belongs to
+/// the current epoch (sessions from earlier epochs can never be ended, so they
+/// no longer lock anything). Finished, expired and missing sessions are
+/// filtered out.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player address
+///
+/// # Returns
+/// Active session IDs, oldest first
+pub(crate) fn get_active_sessions(env: &Env, player: &Address) -> Vec<u32> {
+    let current_epoch = storage::get_current_epoch(env);
+    let mut active = Vec::new(env);
+    for session_id in storage::get_active_sessions(env, player).iter() {
+        if is_session_active(env, session_id, current_epoch) {
+            active.push_back(session_id);
+        }
+    }
+    active
+}
+
+/// Maximum number of sessions returned by one `get_sessions` call
+const MAX_SESSIONS_PER_QUERY: u32 = 50;
+
+/// Get a page of a game's in-flight 1v1 sessions in the current epoch
+///
+/// Sessions are ordered by session ID. Sessions of past epochs can no longer
+/// settle and aren't listed; team sessions are read with `get_team_session`.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Game contract
+/// * `offset` - Sessions to skip
+/// * `limit` - Maximum sessions to return (capped at `MAX_SESSIONS_PER_QUERY`)
+pub(crate) fn get_sessions(
+    env: &Env,
+    game_id: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<ActiveSession> {
+    let current_epoch = storage::get_current_epoch(env);
+    let game_sessions = storage::get_game_sessions(env, game_id, current_epoch);
+    let limit = limit.min(MAX_SESSIONS_PER_QUERY);
+
+    let mut sessions = Vec::new(env);
+    for (session_id, start_ledger) in game_sessions.iter().skip(offset as usize) {
+        if sessions.len() >= limit {
+            break;
+        }
+        let Some(session) = storage::get_session(env, session_id) else {
+            continue;
+        };
+        let state = match storage::get_provisional_result(env, session_id) {
+            Some(result) if result.disputer.is_some() => SessionState::Disputed,
+            Some(_) => SessionState::Provisional,
+            None => SessionState::Active,
+        };
+        sessions.push_back(ActiveSession {
+            session_id,
+            session,
+            start_ledger,
+            state,
+        });
+    }
+    sessions
+}
+
+/// Check if a session (1v1 or team) has no outcome and belongs to `current_epoch`
+fn is_session_active(env: &Env, session_id: u32, current_epoch: u32) -> bool {
+    let pending_epoch = match storage::get_session(env, session_id) {
+        Some(session) => session.player1_won.is_none().then_some(session.epoch_id),
+        None => storage::get_team_session(env, session_id)
+            .and_then(|team| team.team_a_won.is_none().then_some(team.epoch_id)),
+    };
+    pending_epoch == Some(current_epoch)
+}
+
 /// End a game session with outcome verification
 ///
 /// Outcome verification is handled by the individual game contracts.
 /// Each game is responsible for implementing its own verification mechanism
 /// (multi-sig oracle, ZK proofs, etc.) before calling this function.
 ///
+/// For games with a dispute window the outcome is only recorded as provisional
+/// (see `crate::dispute`).
+///
 /// # Arguments
 /// * `env` - Contract environment
 /// * `session_id` - The unique session identifier
 /// * `player1_won` - true if player1 won, false if player2 won
+/// * `margin_bps` - Margin of victory for margin-based point formulas, if reported
 ///
 /// # Errors
 /// * `SessionNotFound` - If session doesn't exist
-/// * `InvalidSessionState` - If session is not in Pending state
+/// * `SessionAlreadyFinalized` - If the session already has a (provisional) outcome
+/// * `InvalidSessionState` - If the session belongs to a series
 /// * `GameExpired` - If game is from a previous epoch
-pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+/// * `InvalidAmount` - If `margin_bps` is above 10_000
+pub(crate) fn end_game(
+    env: &Env,
+    session_id: u32,
+    player1_won: bool,
+    margin_bps: Option<u32>,
+) -> Result<(), Error> {
     // Get session
-    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
 
     // SECURITY: Require game contract to authorize this call
     // Only the whitelisted game contract should be able to submit outcomes
@@ -228,9 +954,159 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
 
     // Validate session state (game must not be completed yet)
     if session.player1_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized);
+    }
+
+    // Series sessions are settled by `report_series_game` once a side has a majority
+    if storage::get_series(env, session_id).is_some() {
         return Err(Error::InvalidSessionState);
     }
 
+    // Margin-based point formulas read the margin when the session settles
+    if let Some(margin_bps) = margin_bps {
+        crate::point_formula::record_margin(env, session_id, margin_bps)?;
+    }
+
+    report_outcome(env, session_id, session, player1_won)
+}
+
+/// Report a session as forfeited by an inactive player
+///
+/// The opponent wins and the outcome is reported as with `end_game` (so a
+/// dispute window applies). To keep games from ending sessions they don't
+/// like, a forfeit is only accepted once the session is at least
+/// `get_forfeit_delay` ledgers old.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - The unique session identifier
+/// * `afk_player` - Player who stopped playing (loses the session)
+///
+/// # Errors
+/// * `SessionNotFound` - If session doesn't exist
+/// * `SessionAlreadyFinalized` - If the session already has a (provisional) outcome
+/// * `PlayerNotFound` - If `afk_player` isn't in the session
+/// * `InvalidSessionState` - If the session belongs to a series or is younger
+///   than the forfeit delay
+/// * `GameExpired` - If game is from a previous epoch
+pub(crate) fn forfeit(env: &Env, session_id: u32, afk_player: &Address) -> Result<(), Error> {
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    // Only the game contract can report on its sessions
+    session.game_id.require_auth();
+
+    if session.player1_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized);
+    }
+
+    let player1_won = if *afk_player == session.player2 {
+        true
+    } else if *afk_player == session.player1 {
+        false
+    } else {
+        return Err(Error::PlayerNotFound);
+    };
+
+    if storage::get_series(env, session_id).is_some() {
+        return Err(Error::InvalidSessionState);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    if session.epoch_id != current_epoch {
+        return Err(Error::GameExpired);
+    }
+
+    // Sessions are indexed with their start ledger until they settle
+    let start_ledger = storage::get_game_sessions(env, &session.game_id, current_epoch)
+        .get(session_id)
+        .ok_or(Error::InvalidSessionState)?;
+    let forfeit_ledger = start_ledger.saturating_add(storage::get_forfeit_delay(env));
+    if env.ledger().sequence() < forfeit_ledger {
+        return Err(Error::InvalidSessionState);
+    }
+
+    emit_session_forfeited(env, &session.game_id, session_id, afk_player);
+
+    report_outcome(env, session_id, session, player1_won)
+}
+
+/// Check match details are small enough to attach to a session
+///
+/// # Errors
+/// * `InvalidConfig` - If the metadata exceeds `MAX_SESSION_METADATA_LEN` bytes
+pub(crate) fn require_metadata_len(metadata: &Bytes) -> Result<(), Error> {
+    if metadata.len() > MAX_SESSION_METADATA_LEN {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Attach a game's match details to a session (at start or with the result)
+///
+/// Caller must have authenticated the game contract (via `start_game` or
+/// `end_game`) and checked the metadata length.
+pub(crate) fn attach_metadata(env: &Env, session_id: u32, result: bool, metadata: &Bytes) {
+    let Some(session) = storage::get_session(env, session_id) else {
+        return;
+    };
+    let mut session_metadata =
+        storage::get_session_metadata(env, session_id).unwrap_or(SessionMetadata {
+            start: Bytes::new(env),
+            result: Bytes::new(env),
+        });
+    if result {
+        session_metadata.result = metadata.clone();
+    } else {
+        session_metadata.start = metadata.clone();
+    }
+    storage::set_session_metadata(env, session_id, &session_metadata);
+
+    emit_session_metadata_attached(env, &session.game_id, session_id, result, metadata);
+}
+
+/// Record a reported outcome, provisionally for games with a dispute window
+///
+/// Caller must have authenticated the game contract and checked the session
+/// has no outcome yet.
+fn report_outcome(
+    env: &Env,
+    session_id: u32,
+    session: GameSession,
+    player1_won: bool,
+) -> Result<(), Error> {
+    // A result already reported is pending its dispute window
+    crate::dispute::require_no_provisional(env, session_id)?;
+
+    // Games with a dispute window settle once the window has passed
+    let dispute_ledgers = storage::get_game_info(env, &session.game_id)
+        .map(|info| info.dispute_ledgers)
+        .unwrap_or(0);
+    if dispute_ledgers > 0 {
+        return crate::dispute::record_provisional(
+            env,
+            session_id,
+            &session,
+            player1_won,
+            dispute_ledgers,
+        );
+    }
+
+    settle_session(env, session_id, session, player1_won)
+}
+
+/// Settle a pending session's outcome (shared by `end_game` and series)
+///
+/// Caller must have authenticated the game contract and checked the session
+/// has no outcome yet.
+///
+/// # Errors
+/// * `GameExpired` - If game is from a previous epoch
+pub(crate) fn settle_session(
+    env: &Env,
+    session_id: u32,
+    mut session: GameSession,
+    player1_won: bool,
+) -> Result<(), Error> {
     // Validate game is from current epoch
     // Games cannot be completed in a different epoch than they were started
     let current_epoch = storage::get_current_epoch(env);
@@ -239,7 +1115,7 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
     }
 
     // Determine winner and loser
-    let (winner, loser, winner_wager, _loser_wager) = if player1_won {
+    let (winner, loser, winner_wager, loser_wager) = if player1_won {
         // Player1 won
         (
             &session.player1,
@@ -265,11 +1141,61 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
     let mut winner_epoch =
         storage::get_epoch_player(env, current_epoch, winner).ok_or(Error::PlayerNotFound)?;
 
+    // The game's rake comes off both wagers, each recorded against the
+    // player's own faction (see `rake.rs`); practice sessions have no wagers
+    let winner_rake = if is_practice(&session) {
+        0
+    } else {
+        let loser_epoch =
+            storage::get_epoch_player(env, current_epoch, loser).ok_or(Error::PlayerNotFound)?;
+        crate::rake::take_rake(
+            env,
+            &session.game_id,
+            current_epoch,
+            &loser_epoch,
+            loser_wager,
+        )?;
+        crate::rake::take_rake(
+            env,
+            &session.game_id,
+            current_epoch,
+            &winner_epoch,
+            winner_wager,
+        )?
+    };
+
     // Only winner's wager contributes to faction standings
     // Note: Wager is already in FP units with multipliers applied
+    // (net of the game's rake, scaled by the win odds and the winner's
+    // deposit lock, see `rake.rs` and `lock.rs`; nothing below the minimum
+    // stake). The game's point formula gives the base points of the win (see
+    // `point_formula.rs`); a practice win contributes its flat `practice_fp`.
+    let game_info = storage::get_game_info(env, &session.game_id);
+    let contribution = if !crate::faction_points::meets_min_stake(env, &winner_epoch) {
+        0
+    } else if is_practice(&session) {
+        game_info.and_then(|info| info.practice_fp).unwrap_or(0)
+    } else {
+        let staked = winner_wager - winner_rake;
+        let base = match &game_info {
+            Some(info) => crate::point_formula::base_points(env, info, session_id, staked)?,
+            None => staked,
+        };
+        let contribution = crate::faction_points::decayed_contribution(env, current_epoch, base)?;
+        let contribution = crate::faction_points::odds_adjusted_contribution(
+            env,
+            &session.game_id,
+            winner,
+            loser,
+            winner_wager,
+            loser_wager,
+            contribution,
+        )?;
+        crate::lock::locked_contribution(env, winner, contribution)?
+    };
     winner_epoch.total_fp_contributed = winner_epoch
         .total_fp_contributed
-        .checked_add(winner_wager)
+        .checked_add(contribution)
         .ok_or(Error::OverflowError)?;
 
     // Save winner's updated data
@@ -278,21 +1204,70 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
     // Update session (marking it as completed)
     session.player1_won = Some(player1_won);
     storage::set_session(env, session_id, &session);
+    let mut game_sessions = storage::get_game_sessions(env, &session.game_id, current_epoch);
+    game_sessions.remove(session_id);
+    storage::set_game_sessions(env, &session.game_id, current_epoch, &game_sessions);
+
+    // Settle spectator predictions on this session
+    crate::prediction::resolve(env, session_id, player1_won)?;
+
+    // Completed session no longer counts towards either player's cap
+    storage::set_active_sessions(
+        env,
+        &session.player1,
+        &get_active_sessions(env, &session.player1),
+    );
+    storage::set_active_sessions(
+        env,
+        &session.player2,
+        &get_active_sessions(env, &session.player2),
+    );
 
     // Update epoch info: faction standings + game contributions (single read/write)
-    let total_game_wager = session
-        .player1_wager
-        .checked_add(session.player2_wager)
-        .ok_or(Error::OverflowError)?;
+    // A practice win has no wagers, so its points count towards total_game_fp
+    // instead (keeping the sum of standings within it)
+    let total_game_wager = if is_practice(&session) {
+        contribution
+    } else {
+        session
+            .player1_wager
+            .checked_add(session.player2_wager)
+            .ok_or(Error::OverflowError)?
+    };
     update_epoch_on_game_end(
         env,
         winner,
-        winner_wager,
+        contribution,
         &session.game_id,
         total_game_wager,
         current_epoch,
     )?;
 
+    // Count the session towards both players' activity requirement
+    crate::activity::record_session(env, current_epoch, winner)?;
+    crate::activity::record_session(env, current_epoch, loser)?;
+
+    // Update per-game Elo ratings
+    let (winner_rating, loser_rating, rating_change) =
+        crate::rating::update_ratings(env, &session.game_id, winner, loser);
+
+    // Update per-game lifetime stats
+    crate::stats::record_game(
+        env,
+        &session.game_id,
+        winner,
+        winner_wager,
+        loser,
+        loser_wager,
+    )?;
+    crate::stats::record_session_finished(env, &session.game_id);
+
+    // Update achievement progress
+    crate::achievements::record_game(env, winner, loser);
+
+    // Update quest progress (bonuses add to available FP)
+    crate::quests::record_game(env, current_epoch, winner, winner_wager, loser, loser_wager)?;
+
     // Emit event (only winner's wager counts as faction contribution)
     emit_game_ended(
         env,
@@ -300,7 +1275,10 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
         session_id,
         winner,
         loser,
-        winner_wager,
+        contribution,
+        winner_rating,
+        loser_rating,
+        rating_change,
     );
 
     Ok(())
@@ -310,22 +1288,53 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
 // Helper Functions
 // ============================================================================
 
+/// Derive a session ID from a game's nonce: first 4 bytes of sha256(game_id XDR || nonce)
+fn derive_session_id(env: &Env, game_id: &Address, nonce: u32) -> u32 {
+    let mut preimage: Bytes = game_id.to_xdr(env);
+    preimage.extend_from_array(&nonce.to_be_bytes());
+
+    let hash = env.crypto().sha256(&preimage).to_array();
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+/// Find the first unused nonce-derived session ID for a game
+///
+/// # Returns
+/// `(session_id, nonce to store afterwards)`
+fn find_next_session_id(env: &Env, game_id: &Address) -> Result<(u32, u32), Error> {
+    let mut nonce = storage::get_game_nonce(env, game_id);
+    loop {
+        let session_id = derive_session_id(env, game_id, nonce);
+        nonce = nonce.checked_add(1).ok_or(Error::OverflowError)?;
+        if !storage::has_session(env, session_id) && !storage::is_session_id_used(env, session_id) {
+            return Ok((session_id, nonce));
+        }
+    }
+}
+
 /// Initialize faction points for a player if this is their first game in the epoch
 ///
 /// **NEW ARCHITECTURE (Cross-Epoch Balance Comparison):**
 /// 1. Query current vault balance
 /// 2. Check for >50% withdrawal since last epoch
 /// 3. Initialize time_multiplier_start if first-time player
-/// 4. Calculate FP based on current balance + multipliers
-/// 5. Save epoch snapshot and update last_epoch_balance
-fn initialize_player_epoch(env: &Env, player: &Address, current_epoch: u32) -> Result<(), Error> {
+/// 4. Update participation streak (bonus snapshotted into EpochPlayer)
+/// 5. Calculate FP based on current balance + multipliers
+/// 6. Save epoch snapshot and update last_epoch_balance
+pub(crate) fn initialize_player_epoch(
+    env: &Env,
+    player: &Address,
+    current_epoch: u32,
+) -> Result<(), Error> {
     // Check if player already has epoch data
     if storage::has_epoch_player(env, current_epoch, player) {
         // Already initialized this epoch
         return Ok(());
     }
 
-    // STEP 1: Query current vault balance
+    // STEP 1: Query current vault balance (and checkpoint it for balance snapshots)
+    let config = storage::get_config(env);
+    crate::snapshot::record(env, &config, player);
     let current_balance = crate::vault::get_vault_balance(env, player);
 
     // STEP 2: Get or create player record
@@ -333,6 +1342,8 @@ fn initialize_player_epoch(env: &Env, player: &Address, current_epoch: u32) -> R
         selected_faction: 0, // Default to WholeNoodle
         time_multiplier_start: 0,
         last_epoch_balance: 0,
+        streak: 0,
+        last_active_epoch: 0,
     });
 
     // STEP 3: Initialize time_multiplier_start if first-time player
@@ -350,14 +1361,20 @@ fn initialize_player_epoch(env: &Env, player: &Address, current_epoch: u32) -> R
         current_epoch,
     )?;
 
-    // STEP 5: Calculate FP based on current balance and multipliers
+    // STEP 5: Extend (or restart) the participation streak
+    let streak = next_streak(&player_data, current_epoch);
+    let bonus = streak_bonus(&config, streak);
+
+    // STEP 6: Calculate FP based on current balance and multipliers
     // This calls initialize_epoch_fp which will use the balance we pass
-    initialize_epoch_fp(env, player, current_epoch)?;
+    initialize_epoch_fp(env, player, current_epoch, bonus)?;
 
-    // STEP 6: Reload player data after potential reset, then update last_epoch_balance
+    // STEP 7: Reload player data after potential reset, then update last_epoch_balance
     // CRITICAL: Must reload to get the updated time_multiplier_start from step 4
     player_data = storage::get_player(env, player).ok_or(Error::PlayerNotFound)?;
     player_data.last_epoch_balance = current_balance;
+    player_data.streak = streak;
+    player_data.last_active_epoch = current_epoch;
     storage::set_player(env, player, &player_data);
 
     Ok(())
@@ -369,10 +1386,11 @@ fn initialize_player_epoch(env: &Env, player: &Address, current_epoch: u32) -> R
 /// double read/write of EpochInfo storage.
 ///
 /// Updates:
-/// 1. Faction standings (winner's wager)
-/// 2. Total game FP (both wagers for dev rewards)
+/// 1. Faction standings (winner's wager, after any decay), weighted/sqrt standings and
+///    faction leader
+/// 2. Total game FP (both wagers, or a practice win's points, for dev rewards)
 /// 3. Per-developer FP contribution (aggregated across all games for the developer)
-fn update_epoch_on_game_end(
+pub(crate) fn update_epoch_on_game_end(
     env: &Env,
     winner: &Address,
     winner_wager: i128,
@@ -398,6 +1416,32 @@ fn update_epoch_on_game_end(
         .ok_or(Error::OverflowError)?;
     epoch_info.faction_standings.set(faction, new_standing);
 
+    // 1b. Track distribution denominators, weighted by the winner's streak bonus
+    // The deltas telescope, so each faction sum is exactly the sum of player weights
+    // (players yet to meet the activity requirement are added once they do)
+    if epoch_player.reward_eligible {
+        add_reward_weight_deltas(&mut epoch_info, &epoch_player, faction, winner_wager)?;
+        crate::reward_cap::record_weight(
+            env,
+            current_epoch,
+            epoch_info.distribution_mode,
+            faction,
+            winner,
+            &epoch_player,
+        )?;
+    }
+
+    let new_contribution = epoch_player.total_fp_contributed;
+    let leader_contribution = epoch_info
+        .faction_leaders
+        .get(faction)
+        .and_then(|leader| storage::get_epoch_player(env, current_epoch, &leader))
+        .map(|leader_epoch| leader_epoch.total_fp_contributed)
+        .unwrap_or(0);
+    if new_contribution > leader_contribution {
+        epoch_info.faction_leaders.set(faction, winner.clone());
+    }
+
     // 2. Update total game FP (both wagers for dev reward calculation)
     epoch_info.total_game_fp = epoch_info
         .total_game_fp
@@ -425,3 +1469,48 @@ fn update_epoch_on_game_end(
 
     Ok(())
 }
+
+/// Add a winner's contribution to their faction's reward denominators
+///
+/// # Arguments
+/// * `epoch_info` - Current epoch (saved by the caller)
+/// * `epoch_player` - Winner's epoch data, already credited with `contribution`
+/// * `faction` - Winner's faction
+/// * `contribution` - FP credited for this game
+fn add_reward_weight_deltas(
+    epoch_info: &mut EpochInfo,
+    epoch_player: &EpochPlayer,
+    faction: u32,
+    contribution: i128,
+) -> Result<(), Error> {
+    let weighted_delta =
+        reward_weight(contribution, epoch_player.streak_bonus).ok_or(Error::OverflowError)?;
+    let current_weighted_standing = epoch_info
+        .faction_weighted_standings
+        .get(faction)
+        .unwrap_or(0);
+    epoch_info.faction_weighted_standings.set(
+        faction,
+        current_weighted_standing
+            .checked_add(weighted_delta)
+            .ok_or(Error::OverflowError)?,
+    );
+
+    let new_contribution = epoch_player.total_fp_contributed;
+    let old_contribution = new_contribution
+        .checked_sub(contribution)
+        .ok_or(Error::OverflowError)?;
+    let sqrt_delta = checked_fixed_sqrt(new_contribution, SCALAR_7).ok_or(Error::OverflowError)?
+        - checked_fixed_sqrt(old_contribution, SCALAR_7).ok_or(Error::OverflowError)?;
+    let weighted_sqrt_delta =
+        reward_weight(sqrt_delta, epoch_player.streak_bonus).ok_or(Error::OverflowError)?;
+    let current_sqrt_standing = epoch_info.faction_sqrt_standings.get(faction).unwrap_or(0);
+    epoch_info.faction_sqrt_standings.set(
+        faction,
+        current_sqrt_standing
+            .checked_add(weighted_sqrt_delta)
+            .ok_or(Error::OverflowError)?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use crate::types::GameMetadata;
+
+// Standard Blendizzard game interface
+// Games implement this to self-register: the game calls `register_game` with
+// its own metadata (it can't be called back mid-call, so Blendizzard doesn't
+// query `game_metadata` itself), and Blendizzard calls `blendizzard_init` when
+// the admin approves it. Results are reported back by calling Blendizzard's
+// `end_game(session_id, player1_won)` (or `end_team_game`), authorized by the
+// game contract.
+#[allow(dead_code)]
+#[soroban_sdk::contractargs(name = "Args")]
+#[soroban_sdk::contractclient(name = "Client")]
+pub trait Contract {
+    /// Called once by Blendizzard when the game's registration is approved
+    fn blendizzard_init(env: soroban_sdk::Env, blendizzard: soroban_sdk::Address);
+    /// Game name and developer, as passed to `register_game`
+    fn game_metadata(env: soroban_sdk::Env) -> GameMetadata;
+}
@@ -0,0 +1,187 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_parameter_applied, emit_parameter_voted};
+use crate::storage;
+use crate::types::{Config, GovernanceParameter, SCALAR_7};
+
+// ============================================================================
+// Parameter Governance
+// ============================================================================
+//
+// Players vote during an epoch on the next epoch's value of a few parameters
+// (`GovernanceParameter`). The admin whitelists the values that can be voted
+// for; a parameter without options isn't governed.
+//
+// - Any staked player (locked into a faction this epoch with a non-zero
+//   balance snapshot, as for treasury votes) casts one vote per parameter and
+//   epoch, weighted by their balance snapshot. Votes are global, across
+//   factions.
+// - When the epoch cycles, the whitelisted value with the most stake wins
+//   (ties go to the value listed first) and is written to the config. The
+//   epoch duration and underdog bonus apply as the next epoch opens, the
+//   developer share from the next epoch's rewards on.
+// - An epoch duration scheduled by the admin (`set_next_epoch_duration`)
+//   takes precedence over the vote.
+
+/// Maximum number of values per parameter
+pub(crate) const MAX_GOVERNANCE_OPTIONS: u32 = 5;
+
+/// Parameters applied as the next epoch opens (`epoch::finalize_epoch`)
+pub(crate) const OPENING_PARAMETERS: [GovernanceParameter; 2] = [
+    GovernanceParameter::UnderdogBonus,
+    GovernanceParameter::EpochDuration,
+];
+
+/// Parameters applied once the closed epoch's rewards are split
+/// (`epoch::process_rewards`)
+pub(crate) const REWARD_PARAMETERS: [GovernanceParameter; 1] =
+    [GovernanceParameter::DevRewardShare];
+
+/// Replace the values players can vote for on a parameter
+///
+/// Caller must have authenticated the admin. An empty list stops governing the
+/// parameter.
+///
+/// # Errors
+/// * `InvalidConfig` - If there are too many values, a value repeats or is out
+///   of the parameter's range
+pub(crate) fn set_options(
+    env: &Env,
+    parameter: GovernanceParameter,
+    options: &Vec<i128>,
+) -> Result<(), Error> {
+    if options.len() > MAX_GOVERNANCE_OPTIONS {
+        return Err(Error::InvalidConfig);
+    }
+    let config = storage::get_config(env);
+    for (i, value) in options.iter().enumerate() {
+        if options.first_index_of(value) != Some(i as u32)
+            || !is_valid(env, &config, parameter, value)
+        {
+            return Err(Error::InvalidConfig);
+        }
+    }
+
+    storage::set_governance_options(env, parameter, options);
+    Ok(())
+}
+
+/// Vote for the next epoch's value of a parameter
+///
+/// # Returns
+/// The voter's weight
+///
+/// # Errors
+/// * `ParameterOptionNotOffered` - If the value isn't one of the parameter's options
+/// * `ParameterAlreadyVoted` - If the voter already voted on the parameter this epoch
+/// * `PlayerNotFound` - If the voter hasn't played this epoch
+/// * `FactionNotSelected` - If the voter isn't locked into a faction
+/// * `DepositRequiredToClaim` - If the voter has no stake this epoch
+pub(crate) fn vote(
+    env: &Env,
+    voter: &Address,
+    parameter: GovernanceParameter,
+    value: i128,
+) -> Result<i128, ContractError> {
+    voter.require_auth();
+
+    if !storage::get_governance_options(env, parameter).contains(value) {
+        return Err(ExtError::ParameterOptionNotOffered.into());
+    }
+
+    let epoch = storage::get_current_epoch(env);
+    let (_faction, stake) = crate::treasury::member_stake(env, epoch, voter)?;
+    if storage::has_parameter_vote(env, parameter, epoch, voter) {
+        return Err(ExtError::ParameterAlreadyVoted.into());
+    }
+
+    let mut votes = storage::get_parameter_votes(env, parameter, epoch);
+    let total = votes
+        .get(value)
+        .unwrap_or(0)
+        .checked_add(stake)
+        .ok_or(Error::OverflowError)?;
+    votes.set(value, total);
+    storage::set_parameter_votes(env, parameter, epoch, &votes);
+    storage::set_parameter_vote(env, parameter, epoch, voter);
+
+    emit_parameter_voted(env, voter, parameter, epoch, value, stake);
+
+    Ok(stake)
+}
+
+/// Write the winning values of an epoch's votes to the config
+///
+/// Values that are no longer whitelisted don't count, and a winner that is
+/// out of range for the current config (e.g. an epoch duration no longer
+/// longer than the selection phase) is skipped.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (updated and stored if a vote applies)
+/// * `epoch` - Epoch the votes were cast in
+/// * `parameters` - Parameters to tally
+pub(crate) fn apply_votes(
+    env: &Env,
+    config: &mut Config,
+    epoch: u32,
+    parameters: &[GovernanceParameter],
+) {
+    let mut changed = false;
+    for &parameter in parameters {
+        let Some(value) = winning_value(env, parameter, epoch) else {
+            continue;
+        };
+        if !is_valid(env, config, parameter, value) {
+            continue;
+        }
+
+        match parameter {
+            GovernanceParameter::DevRewardShare => config.dev_reward_share = value,
+            GovernanceParameter::UnderdogBonus => config.max_underdog_bonus = value,
+            GovernanceParameter::EpochDuration => {
+                if config.next_epoch_duration.is_some() {
+                    continue;
+                }
+                config.next_epoch_duration = Some(value as u64);
+            }
+        }
+        changed = true;
+        emit_parameter_applied(env, epoch, parameter, value);
+    }
+
+    if changed {
+        storage::set_config(env, config);
+    }
+}
+
+/// Get the whitelisted value with the most stake voted for it in an epoch
+fn winning_value(env: &Env, parameter: GovernanceParameter, epoch: u32) -> Option<i128> {
+    let votes = storage::get_parameter_votes(env, parameter, epoch);
+    let mut winner = None;
+    let mut max_stake = 0;
+    for value in storage::get_governance_options(env, parameter).iter() {
+        let stake = votes.get(value).unwrap_or(0);
+        if stake > max_stake {
+            max_stake = stake;
+            winner = Some(value);
+        }
+    }
+    winner
+}
+
+/// Check a value against the range its parameter's admin setter enforces
+fn is_valid(env: &Env, config: &Config, parameter: GovernanceParameter, value: i128) -> bool {
+    match parameter {
+        GovernanceParameter::DevRewardShare | GovernanceParameter::UnderdogBonus => {
+            (0..=SCALAR_7).contains(&value)
+        }
+        GovernanceParameter::EpochDuration => {
+            let Ok(duration) = u64::try_from(value) else {
+                return false;
+            };
+            crate::epoch::schedule_epoch_duration(env, &mut config.clone(), duration).is_ok()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::Env;
+
+use crate::storage;
+use crate::types::HealthCheck;
+
+// ============================================================================
+// Health Check
+// ============================================================================
+//
+// `health` gathers, in one cheap read-only call, what monitoring should alert
+// on: vault accounting, epoch liveness, pause and re-entrancy flags, swap pair
+// liquidity, the Blend pool status and the presence of the entries every call
+// depends on. External queries that fail count as unhealthy instead of failing
+// the call, except the pool status, which not every fee-vault exposes.
+//
+// Contract code can't read entry TTLs, so `core_entries_live` reports whether
+// the core entries still exist; exact TTLs are available from RPC
+// (`getLedgerEntries`), and `bump_entries` extends them.
+
+/// Compute the health indicators
+pub(crate) fn health(env: &Env) -> HealthCheck {
+    let core_entries_live = storage::core_entries_live(env);
+    let paused = storage::is_paused(env);
+    let in_flight = storage::is_in_flight(env);
+    let ledger = env.ledger().sequence();
+    let ledgers_since_cycle =
+        storage::get_last_cycle_ledger(env).map(|opened| ledger.saturating_sub(opened));
+    let rewards_unprocessed = storage::get_unprocessed_epoch(env).is_some();
+
+    // Without the config or current epoch nothing else can be checked
+    if !core_entries_live {
+        return HealthCheck {
+            healthy: false,
+            vault_balanced: false,
+            vault_b_tokens: 0,
+            accounted_b_tokens: 0,
+            ledger,
+            ledgers_since_cycle,
+            cycle_overdue: 0,
+            rewards_unprocessed,
+            paused,
+            in_flight,
+            swap_reserves: (0, 0),
+            pool_status: None,
+            core_entries_live,
+        };
+    }
+
+    let config = storage::get_config(env);
+    let (vault_balanced, vault_b_tokens, accounted_b_tokens) =
+        match crate::vault::try_get_b_token_balances(env, &config) {
+            Some((held, credited)) => (held >= credited, held, credited),
+            None => (false, 0, 0),
+        };
+
+    let cycle_overdue = storage::get_epoch(env, storage::get_current_epoch(env))
+        .map(|epoch| crate::epoch_clock::now(env).saturating_sub(epoch.end_time))
+        .unwrap_or(0);
+
+    let swap_reserves = crate::swap::try_pair_reserves(
+        env,
+        &config.soroswap_router,
+        &config.blnd_token,
+        &config.usdc_token,
+    )
+    .unwrap_or((0, 0));
+
+    let pool_status = crate::vault::try_pool_status(env, &config).map(|(_, status)| status);
+
+    HealthCheck {
+        healthy: vault_balanced
+            && !paused
+            && !in_flight
+            && swap_reserves.0 > 0
+            && swap_reserves.1 > 0
+            && !pool_status.is_some_and(crate::vault::is_pool_halted),
+        vault_balanced,
+        vault_b_tokens,
+        accounted_b_tokens,
+        ledger,
+        ledgers_since_cycle,
+        cycle_overdue,
+        rewards_unprocessed,
+        paused,
+        in_flight,
+        swap_reserves,
+        pool_status,
+        core_entries_live,
+    }
+}
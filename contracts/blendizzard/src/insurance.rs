@@ -0,0 +1,96 @@
+use soroban_sdk::Env;
+
+use crate::errors::Error;
+use crate::events::{emit_insurance_funded, emit_shortfall_covered};
+use crate::math::{checked_mul_div, Rounding, BPS_SCALE};
+use crate::storage;
+use crate::types::{VaultBackend, SCALAR_12};
+
+// ============================================================================
+// Insurance Fund
+// ============================================================================
+//
+// With `InsuranceFund::bps` set, that share of the USDC routed from game rakes
+// each cycle (see `rake.rs`) goes into the insurance fund instead. The USDC
+// stays in this contract until the admin calls `cover_shortfall`.
+//
+// A shortfall is the vault backend holding fewer b-tokens than it credits to
+// players (a pool loss or an accounting bug). Covering supplies fund USDC to
+// the Blend pool for this contract's position without crediting anyone, so
+// the held b-tokens back the credited ones again. Only the DirectPool backend
+// credits players here; with FeeVault players hold vault shares directly and
+// there is nothing this contract can top up.
+
+/// Largest insurance share of routed rakes (100%)
+pub(crate) const MAX_INSURANCE_BPS: u32 = BPS_SCALE as u32;
+
+/// Add skimmed rake USDC to the insurance fund
+///
+/// # Errors
+/// * `OverflowError` - If the balance overflows
+pub(crate) fn fund(env: &Env, epoch: u32, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let mut fund = storage::get_insurance_fund(env);
+    fund.balance = fund
+        .balance
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_insurance_fund(env, &fund);
+    emit_insurance_funded(env, epoch, amount, fund.balance);
+    Ok(())
+}
+
+/// Cover a vault shortfall from the insurance fund
+///
+/// Uses as much of the fund as the shortfall needs, valued at the reserve's
+/// current b_rate (rounded up).
+///
+/// # Returns
+/// USDC spent from the fund
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `FeatureNotConfigured` - If the vault backend isn't DirectPool or can't
+///   be queried
+/// * `InvalidAmount` - If the held b-tokens cover the credited ones
+/// * `InsufficientBalance` - If the fund is empty
+pub(crate) fn cover_shortfall(env: &Env) -> Result<i128, Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::DirectPool {
+        return Err(Error::FeatureNotConfigured);
+    }
+    let (held, credited) =
+        crate::vault::try_get_b_token_balances(env, &config).ok_or(Error::FeatureNotConfigured)?;
+    if held >= credited {
+        return Err(Error::InvalidAmount);
+    }
+    let (b_rate, _) =
+        crate::vault::try_get_b_rate_and_tvl(env, &config).ok_or(Error::FeatureNotConfigured)?;
+    let shortfall = checked_mul_div(credited - held, b_rate, SCALAR_12, Rounding::Ceil)
+        .ok_or(Error::OverflowError)?;
+
+    let mut fund = storage::get_insurance_fund(env);
+    let amount = shortfall.min(fund.balance);
+    if amount <= 0 {
+        return Err(Error::InsufficientBalance);
+    }
+    let b_tokens = crate::vault::supply_uncredited(env, &config, amount)?;
+
+    let old_balance = fund.balance;
+    fund.balance -= amount;
+    fund.total_covered = fund
+        .total_covered
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_insurance_fund(env, &fund);
+
+    crate::audit::record(env, &admin, "cover_shortfall", &old_balance, &fund.balance);
+    emit_shortfall_covered(env, amount, b_tokens, shortfall - amount, fund.balance);
+
+    Ok(amount)
+}
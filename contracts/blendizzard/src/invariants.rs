@@ -0,0 +1,214 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::errors::Error;
+use crate::storage;
+use crate::types::VaultBackend;
+
+// ============================================================================
+// Accounting Invariants (strict-invariants feature)
+// ============================================================================
+//
+// Internal consistency checks run after state-mutating entrypoints.
+// Every check is a no-op unless the contract is built with the
+// `strict-invariants` feature, so production WASM pays nothing for them:
+//
+//   cargo test --features strict-invariants
+//
+// A violated invariant panics with `Error::InvariantViolation`, reverting the
+// whole invocation. This turns silent accounting drift into a loud test failure.
+//
+// Balances are checked against the vault backend: with DirectPool the contract
+// keeps each player's credited b-tokens and their running sum
+// (`TotalPoolBTokens`), which must stay backed by the contract's own position
+// in the Blend pool. With FeeVault, deposits are fee-vault-v2 shares held by
+// the players themselves, so only the DirectPool ledger is reconciled.
+
+/// Check that an epoch's standings and reward pools are internally consistent
+///
+/// Invariants:
+/// - Every faction standing is non-negative
+/// - Sum of faction standings <= total_game_fp (only the winner's wager counts
+///   towards standings, while total_game_fp counts both wagers, or a practice
+///   win's points)
+/// - `winning_faction` is set if and only if the epoch is finalized, unless it
+///   was aborted (finalized without a winner or reward pools)
+/// - Reward pools are non-negative and only set on finalized epochs
+/// - `end_time >= start_time`
+pub(crate) fn check_epoch(env: &Env, epoch: u32) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    let Some(epoch_info) = storage::get_epoch(env, epoch) else {
+        return;
+    };
+
+    let mut standings_total: i128 = 0;
+    for (_faction, fp) in epoch_info.faction_standings.iter() {
+        if fp < 0 {
+            panic_with_error!(env, Error::InvariantViolation);
+        }
+        standings_total = standings_total
+            .checked_add(fp)
+            .unwrap_or_else(|| panic_with_error!(env, Error::InvariantViolation));
+    }
+
+    if standings_total > epoch_info.total_game_fp {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if epoch_info.aborted {
+        if !epoch_info.is_finalized
+            || epoch_info.winning_faction.is_some()
+            || epoch_info.reward_pool != 0
+            || epoch_info.dev_reward_pool != 0
+            || epoch_info.reward_pool_blnd != 0
+        {
+            panic_with_error!(env, Error::InvariantViolation);
+        }
+    } else if epoch_info.is_finalized != epoch_info.winning_faction.is_some() {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if epoch_info.reward_pool < 0
+        || epoch_info.dev_reward_pool < 0
+        || epoch_info.reward_pool_blnd < 0
+    {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if !epoch_info.is_finalized
+        && (epoch_info.reward_pool != 0
+            || epoch_info.dev_reward_pool != 0
+            || epoch_info.reward_pool_blnd != 0)
+    {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if epoch_info.end_time < epoch_info.start_time {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+}
+
+/// Check that a player's epoch data is internally consistent
+///
+/// Invariants:
+/// - `available_fp` and `total_fp_contributed` are non-negative
+/// - A player with contributions has a locked faction
+/// - A player's contribution never exceeds their faction's standing
+pub(crate) fn check_epoch_player(env: &Env, epoch: u32, player: &Address) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    let Some(epoch_player) = storage::get_epoch_player(env, epoch, player) else {
+        return;
+    };
+
+    if epoch_player.available_fp < 0 || epoch_player.total_fp_contributed < 0 {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if epoch_player.total_fp_contributed > 0 {
+        let Some(faction) = epoch_player.epoch_faction else {
+            panic_with_error!(env, Error::InvariantViolation);
+        };
+
+        let standing = storage::get_epoch(env, epoch)
+            .and_then(|info| info.faction_standings.get(faction))
+            .unwrap_or(0);
+
+        if epoch_player.total_fp_contributed > standing {
+            panic_with_error!(env, Error::InvariantViolation);
+        }
+    }
+}
+
+/// Check that a game session is internally consistent
+///
+/// Invariants:
+/// - Both wagers are strictly positive, or both zero (a practice session)
+/// - The session's epoch is not in the future
+pub(crate) fn check_session(env: &Env, session_id: u32) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    let Some(session) = storage::get_session(env, session_id) else {
+        return;
+    };
+
+    let is_practice = session.player1_wager == 0 && session.player2_wager == 0;
+    if !is_practice && (session.player1_wager <= 0 || session.player2_wager <= 0) {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    if session.epoch_id > storage::get_current_epoch(env) {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+}
+
+/// Run all checks touched by a game session (session, its epoch, both players)
+pub(crate) fn check_game(env: &Env, session_id: u32) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    let Some(session) = storage::get_session(env, session_id) else {
+        return;
+    };
+
+    check_session(env, session_id);
+    check_epoch(env, session.epoch_id);
+    check_epoch_player(env, session.epoch_id, &session.player1);
+    check_epoch_player(env, session.epoch_id, &session.player2);
+}
+
+/// Check that the credited vault balances are backed by the vault backend
+///
+/// Invariants:
+/// - `TotalPoolBTokens` is non-negative
+/// - DirectPool: the contract's USDC b-tokens in the pool cover the b-tokens
+///   credited to players (sum of player balances <= vault value)
+pub(crate) fn check_balances(env: &Env) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    if storage::get_total_pool_b_tokens(env) < 0 {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::DirectPool {
+        return;
+    }
+    // An unreachable pool is the backend's failure, not an accounting one
+    if let Some((held, credited)) = crate::vault::try_get_b_token_balances(env, &config) {
+        if credited > held {
+            panic_with_error!(env, Error::InvariantViolation);
+        }
+    }
+}
+
+/// Run all checks touched by a player's deposit, withdrawal or claim
+///
+/// Invariants:
+/// - The player's current-epoch data is consistent (`check_epoch_player`)
+/// - The player's credited b-tokens are non-negative and within
+///   `TotalPoolBTokens`
+/// - Credited balances are backed by the vault backend (`check_balances`)
+pub(crate) fn check_player(env: &Env, player: &Address) {
+    if !cfg!(feature = "strict-invariants") {
+        return;
+    }
+
+    check_epoch_player(env, storage::get_current_epoch(env), player);
+
+    let credited = storage::get_pool_b_tokens(env, player);
+    if credited < 0 || credited > storage::get_total_pool_b_tokens(env) {
+        panic_with_error!(env, Error::InvariantViolation);
+    }
+
+    check_balances(env);
+}
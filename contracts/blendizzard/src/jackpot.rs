@@ -0,0 +1,87 @@
+use soroban_sdk::Env;
+
+use crate::errors::Error;
+use crate::events::{emit_jackpot_funded, emit_jackpot_paid};
+use crate::math::{checked_bps, Rounding};
+use crate::storage;
+use crate::types::EpochInfo;
+
+// ============================================================================
+// Jackpot
+// ============================================================================
+//
+// With `Jackpot::bps` set, each `cycle_epoch` moves that share of the closed
+// epoch's new player reward pool into the jackpot. Every `Jackpot::interval`-th
+// epoch (epochs `interval - 1`, `2 * interval - 1`, ...) the whole jackpot is
+// added to the closed epoch's player reward pool, so the winning faction
+// claims it with its regular rewards. If nobody in the winning faction played,
+// the jackpot rolls over to the next payout epoch instead. The USDC stays in
+// this contract until then.
+
+/// Largest jackpot share of an epoch's player reward pool (10%)
+pub(crate) const MAX_JACKPOT_BPS: u32 = 1_000;
+
+/// Fund the jackpot from a closed epoch's player reward pool and pay it out
+/// on payout epochs
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch being closed
+/// * `next_epoch` - Epoch being opened (later than `epoch + 1` if epochs were
+///   skipped; a payout epoch among the skipped ones pays out now)
+/// * `epoch_info` - The closed epoch (faction standings)
+/// * `winning_faction` - The closed epoch's winning faction
+/// * `player_reward_pool` - Player reward pool before the jackpot
+///
+/// # Returns
+/// The player reward pool after the jackpot share is taken and any payout added
+///
+/// # Errors
+/// * `OverflowError` - If an amount overflows
+pub(crate) fn settle_jackpot(
+    env: &Env,
+    epoch: u32,
+    next_epoch: u32,
+    epoch_info: &EpochInfo,
+    winning_faction: u32,
+    player_reward_pool: i128,
+) -> Result<i128, Error> {
+    let mut jackpot = storage::get_jackpot(env);
+
+    let share = if jackpot.bps == 0 || player_reward_pool <= 0 {
+        0
+    } else {
+        checked_bps(player_reward_pool, jackpot.bps, Rounding::Floor).ok_or(Error::OverflowError)?
+    };
+    let mut player_reward_pool = player_reward_pool - share;
+    if share > 0 {
+        jackpot.balance = jackpot
+            .balance
+            .checked_add(share)
+            .ok_or(Error::OverflowError)?;
+        emit_jackpot_funded(env, epoch, share, jackpot.balance);
+    }
+
+    let winner_played = epoch_info
+        .faction_standings
+        .get(winning_faction)
+        .unwrap_or(0)
+        > 0;
+    let payout_due = next_payout_epoch(jackpot.interval, epoch) < next_epoch;
+    if payout_due && winner_played && jackpot.balance > 0 {
+        player_reward_pool = player_reward_pool
+            .checked_add(jackpot.balance)
+            .ok_or(Error::OverflowError)?;
+        emit_jackpot_paid(env, epoch, winning_faction, jackpot.balance);
+        jackpot.balance = 0;
+    }
+
+    storage::set_jackpot(env, &jackpot);
+    Ok(player_reward_pool)
+}
+
+/// First epoch at or after `epoch` that pays out the jackpot when it closes
+fn next_payout_epoch(interval: u32, epoch: u32) -> u32 {
+    let remaining = interval - 1 - epoch % interval;
+    epoch.saturating_add(remaining)
+}
@@ -0,0 +1,247 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{
+    emit_keeper_bonded, emit_keeper_cycled, emit_keeper_slashed, emit_keeper_unregistered,
+};
+use crate::storage;
+use crate::types::{KeeperBond, KeeperRegistry};
+
+// ============================================================================
+// Bonded Keepers
+// ============================================================================
+//
+// With `KeeperRegistry::min_bond` set, cycling an epoch is reserved for bonded
+// keepers during the first `priority_window` seconds after the epoch ends;
+// after that `cycle_epoch` opens to everyone. Keepers cycle through
+// `keeper_cycle_epoch` and earn `bounty` USDC from the closed epoch's player
+// reward pool.
+//
+// Bonds are USDC held by this contract. The admin can slash a keeper that
+// cycled badly (e.g. accepted a swap far below the oracle price when a better
+// quote existed); slashed USDC goes to the next reward pool. A keeper's bond
+// stays locked until one full epoch after the last epoch it cycled, so there
+// is time to slash before it can be withdrawn.
+
+/// Bond USDC as a keeper (registers the keeper, or tops up an existing bond)
+///
+/// # Returns
+/// The keeper's total bond
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If the keeper registry is disabled
+/// * `InvalidAmount` - If amount <= 0
+/// * `KeeperBondTooLow` - If the total bond would be below `min_bond`
+pub(crate) fn register(env: &Env, keeper: &Address, amount: i128) -> Result<i128, ContractError> {
+    keeper.require_auth();
+
+    let mut registry = storage::get_keeper_registry(env);
+    if registry.min_bond == 0 {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let mut bond = registry.keepers.get(keeper.clone()).unwrap_or(KeeperBond {
+        amount: 0,
+        unlock_epoch: 0,
+    });
+    bond.amount = bond
+        .amount
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    if bond.amount < registry.min_bond {
+        return Err(ExtError::KeeperBondTooLow.into());
+    }
+
+    let config = storage::get_config(env);
+    token::Client::new(env, &config.usdc_token).transfer(
+        keeper,
+        &env.current_contract_address(),
+        &amount,
+    );
+
+    registry.total_bonded = registry
+        .total_bonded
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    registry.keepers.set(keeper.clone(), bond.clone());
+    storage::set_keeper_registry(env, &registry);
+
+    emit_keeper_bonded(env, keeper, amount, bond.amount);
+
+    Ok(bond.amount)
+}
+
+/// Leave the registry and withdraw the whole bond
+///
+/// # Returns
+/// USDC returned to the keeper
+///
+/// # Errors
+/// * `KeeperNotRegistered` - If the address isn't a registered keeper
+/// * `KeeperBondLocked` - If the bond is still locked after a recent cycle
+pub(crate) fn unregister(env: &Env, keeper: &Address) -> Result<i128, ContractError> {
+    keeper.require_auth();
+
+    let mut registry = storage::get_keeper_registry(env);
+    let bond = get_bond(&registry, keeper)?;
+    if storage::get_current_epoch(env) < bond.unlock_epoch {
+        return Err(ExtError::KeeperBondLocked.into());
+    }
+
+    registry.keepers.remove(keeper.clone());
+    registry.total_bonded -= bond.amount;
+    storage::set_keeper_registry(env, &registry);
+
+    if bond.amount > 0 {
+        let config = storage::get_config(env);
+        token::Client::new(env, &config.usdc_token).transfer(
+            &env.current_contract_address(),
+            keeper,
+            &bond.amount,
+        );
+    }
+
+    emit_keeper_unregistered(env, keeper, bond.amount);
+
+    Ok(bond.amount)
+}
+
+/// Slash part of a keeper's bond into the next reward pool
+///
+/// Caller must have authenticated the admin. A keeper slashed below
+/// `min_bond` stays registered but can't cycle until it tops up.
+///
+/// # Returns
+/// The keeper's remaining bond
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `KeeperNotRegistered` - If the address isn't a registered keeper
+/// * `SlashExceedsBond` - If amount exceeds the keeper's bond
+pub(crate) fn slash(env: &Env, keeper: &Address, amount: i128) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let mut registry = storage::get_keeper_registry(env);
+    let mut bond = get_bond(&registry, keeper)?;
+    if amount > bond.amount {
+        return Err(ExtError::SlashExceedsBond.into());
+    }
+
+    bond.amount -= amount;
+    registry.total_bonded -= amount;
+    registry.keepers.set(keeper.clone(), bond.clone());
+    storage::set_keeper_registry(env, &registry);
+
+    let pending = storage::get_pending_rewards(env)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_pending_rewards(env, pending);
+
+    emit_keeper_slashed(env, keeper, amount, bond.amount);
+
+    Ok(bond.amount)
+}
+
+/// Check who may cycle an epoch that ended at `epoch_end`
+///
+/// # Arguments
+/// * `keeper` - Bonded keeper cycling (already authenticated), or None for a
+///   public `cycle_epoch` call
+///
+/// # Errors
+/// * `KeeperPriorityWindow` - If a public call falls inside the priority window
+/// * `FeatureNotConfigured` - If a keeper cycles while the registry is disabled
+/// * `KeeperNotRegistered` - If the keeper isn't registered
+/// * `KeeperBondTooLow` - If the keeper's bond is below `min_bond`
+pub(crate) fn check_cycle_access(
+    env: &Env,
+    keeper: Option<&Address>,
+    epoch_end: u64,
+) -> Result<(), ContractError> {
+    let registry = storage::get_keeper_registry(env);
+
+    let Some(keeper) = keeper else {
+        if in_priority_window(env, epoch_end) {
+            return Err(ExtError::KeeperPriorityWindow.into());
+        }
+        return Ok(());
+    };
+
+    if registry.min_bond == 0 {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+    let bond = get_bond(&registry, keeper)?;
+    if bond.amount < registry.min_bond {
+        return Err(ExtError::KeeperBondTooLow.into());
+    }
+    Ok(())
+}
+
+/// Whether cycling an epoch that ended at `epoch_end` is still reserved for
+/// bonded keepers
+pub(crate) fn in_priority_window(env: &Env, epoch_end: u64) -> bool {
+    let registry = storage::get_keeper_registry(env);
+    let window_end = epoch_end.saturating_add(registry.priority_window);
+    registry.min_bond > 0 && crate::epoch_clock::now(env) < window_end
+}
+
+/// Look up a registered keeper's bond
+///
+/// # Errors
+/// * `KeeperNotRegistered` - If the address isn't a registered keeper
+fn get_bond(registry: &KeeperRegistry, keeper: &Address) -> Result<KeeperBond, ExtError> {
+    registry
+        .keepers
+        .get(keeper.clone())
+        .ok_or(ExtError::KeeperNotRegistered)
+}
+
+/// Pay the keeper's bounty out of the closed epoch's player reward pool
+///
+/// Also locks the keeper's bond until one full epoch after `next_epoch`.
+///
+/// # Arguments
+/// * `keeper` - Keeper cycling, or None for a public `cycle_epoch` call (no bounty)
+/// * `closed_epoch` - Epoch being closed
+/// * `next_epoch` - Epoch being opened
+/// * `player_reward_pool` - Player reward pool of the closed epoch
+///
+/// # Returns
+/// USDC paid (to deduct from the player reward pool)
+pub(crate) fn pay_bounty(
+    env: &Env,
+    keeper: Option<&Address>,
+    closed_epoch: u32,
+    next_epoch: u32,
+    player_reward_pool: i128,
+) -> Result<i128, Error> {
+    let Some(keeper) = keeper else {
+        return Ok(0);
+    };
+
+    let mut registry = storage::get_keeper_registry(env);
+    if let Some(mut bond) = registry.keepers.get(keeper.clone()) {
+        bond.unlock_epoch = next_epoch.saturating_add(1);
+        registry.keepers.set(keeper.clone(), bond);
+        storage::set_keeper_registry(env, &registry);
+    }
+
+    let bounty = registry.bounty.min(player_reward_pool).max(0);
+    if bounty > 0 {
+        let config = storage::get_config(env);
+        token::Client::new(env, &config.usdc_token).transfer(
+            &env.current_contract_address(),
+            keeper,
+            &bounty,
+        );
+    }
+
+    emit_keeper_cycled(env, keeper, closed_epoch, bounty);
+
+    Ok(bounty)
+}
@@ -11,6 +11,17 @@
 //! - Players compete in games by wagering fp
 //! - Every 4-day epoch, winning faction shares BLND yield (converted to USDC)
 //!
+//! ## Smart Wallets
+//! Players may be custom account contracts (e.g. passkey wallets). Each player
+//! flow asks the player for one auth context on this contract, so wallet
+//! session-key policies can scope to it:
+//! - `select_faction`, `claim_epoch_reward`: the call itself
+//! - `deposit` (DirectPool): the call, plus the nested USDC `transfer` to this contract
+//! - `start_game`: `(game_id, session_id, wager)` under the game contract's own
+//!   context, and nothing else
+//!
+//! See `testutils::custom_account_utils` for a mock wallet.
+//!
 //! ## External Dependencies
 //! - fee-vault-v2: Yield-generating vault
 //! - Soroswap: DEX for BLND → USDC conversion
@@ -20,7 +31,7 @@ use soroban_sdk::{
     auth::{Context, CustomAccountInterface},
     contract, contractimpl,
     crypto::Hash,
-    vec, Address, BytesN, Env, Val, Vec,
+    vec, Address, Bytes, BytesN, Env, Map, String, Val, Vec,
 };
 
 mod errors;
@@ -28,19 +39,67 @@ mod events;
 mod storage;
 mod types;
 
+mod achievements;
+mod activity;
+mod approvals;
+mod audit;
+mod boost;
+mod burn;
+mod carryover;
+mod commit_reveal;
+mod dispute;
 mod epoch;
+mod epoch_clock;
 mod faction;
 mod faction_points;
 mod game;
+mod governance;
+mod health;
+mod insurance;
+mod invariants;
+mod jackpot;
+mod keeper;
+mod lock;
+mod math;
+mod merkle;
+mod mvp;
+mod onboarding;
+mod point_formula;
+mod prediction;
+mod protocol_treasury;
+mod prune;
+mod quests;
+mod rake;
+mod random;
+mod rating;
+mod reentrancy;
+mod referral;
+mod rescue;
+mod reward_cap;
+mod reward_tokens;
 mod rewards;
+mod series;
+mod snapshot;
+mod stats;
+mod streak;
+mod swap;
+mod team;
+mod treasury;
+mod underdog;
 mod vault;
+mod vesting;
+mod yield_stats;
 
 // External contract type definitions
+mod aggregator;
+mod blend_pool;
 mod fee_vault_v2;
+mod game_interface;
+mod price_oracle;
 mod router;
 
-use errors::Error;
-use types::{Config, EpochInfo};
+use errors::{ContractError, Error, ExtError};
+use types::{Config, DistributionMode, EpochInfo};
 
 // ============================================================================
 // Contract Definition
@@ -65,7 +124,9 @@ impl Blendizzard {
     /// * `soroswap_router` - Soroswap router contract address
     /// * `blnd_token` - BLND token address
     /// * `usdc_token` - USDC token address
-    /// * `epoch_duration` - Duration of each epoch in seconds (default: 345,600 = 4 days)
+    /// * `epoch_duration` - Duration of each epoch on the epoch clock (e.g. 345,600 seconds = 4 days)
+    /// * `epoch_clock` - Whether epoch times and durations are timestamps/seconds
+    ///   or ledger sequences/ledgers (see `epoch_clock.rs`)
     /// * `reserve_token_ids` - Reserve token IDs for claiming BLND emissions (e.g., vec![&env, 1] for reserve 0 b-tokens)
     /// * `free_fp_per_epoch` - Base FP granted to all players each epoch (enables free play)
     /// * `min_deposit_to_claim` - Minimum vault balance required to claim rewards (anti-sybil)
@@ -80,6 +141,7 @@ impl Blendizzard {
         blnd_token: Address,
         usdc_token: Address,
         epoch_duration: u64,
+        epoch_clock: types::EpochClock,
         reserve_token_ids: Vec<u32>,
         free_fp_per_epoch: i128,
         min_deposit_to_claim: i128,
@@ -96,18 +158,51 @@ impl Blendizzard {
             free_fp_per_epoch,
             min_deposit_to_claim,
             dev_reward_share,
+            xlm_token: None,
+            distribution_mode: DistributionMode::Linear,
+            price_oracle: None,
+            max_price_deviation: 0,
+            streak_bonus_per_epoch: 0,
+            max_streak_bonus: 0,
+            referral_share: 0,
+            claim_window_epochs: 0,
+            max_active_sessions: 0,
+            selection_phase_duration: 0,
+            max_deposit_per_user: 0,
+            max_total_deposits: 0,
+            vault_backend: types::VaultBackend::FeeVault,
+            blend_pool: None,
+            max_underdog_bonus: 0,
+            max_session_ledgers: 0,
+            vesting_threshold: 0,
+            vesting_ledgers: 0,
+            max_prediction: 0,
+            prediction_rake: 0,
+            balance_snapshots: false,
+            blnd_retention_bps: 0,
+            dispute_bond: 0,
+            swap_aggregator: None,
+            aggregator_protocols: Vec::new(&env),
+            fp_half_life_ledgers: 0,
+            whitelist_enabled: false,
+            faction_treasury_share: 0,
+            auto_reserve_emissions: false,
+            next_epoch_duration: None,
+            faction_cap: types::FactionCap::None,
         };
 
         // Save config, admin, and pause state (all stored separately for single source of truth)
         storage::set_config(&env, &config);
         storage::set_admin(&env, &admin);
         storage::set_pause_state(&env, false); // Contract starts unpaused
+        storage::set_storage_version(&env, storage::STORAGE_VERSION);
+        storage::set_epoch_clock(&env, epoch_clock);
 
         // Extend instance TTL for contract-wide data
         storage::extend_instance_ttl(&env);
 
         // Initialize first epoch
-        epoch::initialize_first_epoch(&env, epoch_duration);
+        epoch::initialize_first_epoch(&env, epoch_duration, DistributionMode::Linear);
     }
 
     // ========================================================================
@@ -116,13 +211,18 @@ impl Blendizzard {
 
     /// Update the admin address
     ///
+    /// Needs approver sign-off once approvals are configured (see `set_approvers`).
+    ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the current admin
-    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+    /// * `ApprovalThresholdNotMet` - If the approval threshold isn't met
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
+        approvals::require_approval(&env, &types::AdminOperation::SetAdmin(new_admin.clone()))?;
 
         storage::set_admin(&env, &new_admin);
+        audit::record(&env, &admin, "set_admin", &admin, &new_admin);
         events::emit_admin_changed(&env, &admin, &new_admin);
 
         Ok(())
@@ -133,51 +233,176 @@ impl Blendizzard {
         storage::get_admin(&env)
     }
 
+    /// Get recorded admin and role actions, newest first
+    ///
+    /// Only the last 128 actions are kept. Each entry holds hashes of the
+    /// action name and of the old and new values, the ledger and the caller.
+    ///
+    /// # Arguments
+    /// * `offset` - Number of newest actions to skip
+    /// * `limit` - Maximum number of actions to return
+    pub fn get_admin_log(env: Env, offset: u32, limit: u32) -> Vec<types::AdminAction> {
+        audit::get_admin_log(&env, offset, limit)
+    }
+
+    /// Set the approvers for sensitive admin operations
+    ///
+    /// With `threshold > 0`, `upgrade`, `migrate_vault`, `set_admin`,
+    /// `set_approvers`, `set_treasurer` and `update_config` calls that change
+    /// the router or BLND token also need `threshold` of `approvers` to approve the exact
+    /// call via `approve_operation` first. The approvals are used up by the call.
+    ///
+    /// # Arguments
+    /// * `approvers` - Up to 10 distinct approver addresses (empty to disable)
+    /// * `threshold` - Approvals required per operation (0 to disable)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the threshold exceeds the number of approvers, is 0
+    ///   with approvers set, an approver is repeated or there are too many
+    /// * `ApprovalThresholdNotMet` - If the current approval threshold isn't met
+    pub fn set_approvers(
+        env: Env,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old_approvers = storage::get_approvers(&env);
+        approvals::set_approvers(&env, approvers, threshold)?;
+
+        audit::record(
+            &env,
+            &admin,
+            "set_approvers",
+            &old_approvers,
+            &storage::get_approvers(&env),
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the approvers and approval threshold
+    pub fn get_approvers(env: Env) -> types::ApproverSet {
+        storage::get_approvers(&env)
+    }
+
+    /// Approve an admin operation as an approver
+    ///
+    /// # Errors
+    /// * `NotApprover` - If the caller isn't an approver
+    pub fn approve_operation(
+        env: Env,
+        approver: Address,
+        operation: types::AdminOperation,
+    ) -> Result<(), ExtError> {
+        approvals::approve(&env, &approver, &operation)
+    }
+
+    /// Withdraw an approval of an admin operation that hasn't been executed
+    ///
+    /// # Errors
+    /// * `NotApprover` - If the caller isn't an approver
+    /// * `ApprovalNotFound` - If the caller hasn't approved the operation
+    pub fn revoke_approval(
+        env: Env,
+        approver: Address,
+        operation: types::AdminOperation,
+    ) -> Result<(), ExtError> {
+        approvals::revoke(&env, &approver, &operation)
+    }
+
+    /// Get the approvers that approved a pending admin operation
+    pub fn get_approvals(env: Env, operation: types::AdminOperation) -> Vec<Address> {
+        storage::get_approvals(&env, &operation)
+    }
+
     /// Get the current configuration
     pub fn get_config(env: Env) -> Config {
         storage::get_config(&env)
     }
 
+    /// Get the full deployment parameter set
+    ///
+    /// Configuration plus admin, pause state, current epoch, swap path and
+    /// contract version, for verifying a deployment without reading raw storage.
+    pub fn get_full_config(env: Env) -> types::FullConfig {
+        let config = storage::get_config(&env);
+        types::FullConfig {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            admin: storage::get_admin(&env),
+            paused: storage::is_paused(&env),
+            current_epoch: storage::get_current_epoch(&env),
+            swap_path: vec![&env, config.blnd_token.clone(), config.usdc_token.clone()],
+            config,
+        }
+    }
+
+    /// Get liveness and consistency indicators for monitoring
+    ///
+    /// Vault accounting, ledgers since the last epoch cycle, pause and
+    /// re-entrancy flags, swap pair reserves and whether the core entries are
+    /// still live (see `HealthCheck`). Never fails: queries of external
+    /// contracts that fail are reported as unhealthy.
+    pub fn health(env: Env) -> types::HealthCheck {
+        health::health(&env)
+    }
+
     /// Update global configuration
     ///
     /// Allows admin to update specific configuration parameters.
     /// Only updates parameters that are provided (non-None).
     ///
+    /// The vault and its USDC asset can only change through
+    /// `queue_vault_migration` and `migrate_vault`, behind the migration
+    /// timelock. The router and BLND token aren't timelocked: they never hold
+    /// player deposits (only the BLND harvested for the epoch being cycled
+    /// passes through them), and a broken router has to be replaceable before
+    /// the next cycle. Changing either needs approver sign-off once approvals
+    /// are configured (see `set_approvers`).
+    ///
     /// # Arguments
-    /// * `new_fee_vault` - New fee-vault-v2 contract address (optional)
     /// * `new_soroswap_router` - New Soroswap router contract address (optional)
     /// * `new_blnd_token` - New BLND token address (optional)
-    /// * `new_usdc_token` - New USDC token address (optional)
-    /// * `new_epoch_duration` - New epoch duration in seconds (optional)
-    /// * `new_reserve_token_ids` - New reserve token IDs for claiming BLND emissions (optional)
+    /// * `new_epoch_duration` - New epoch duration in seconds, from the next epoch (optional)
+    /// * `new_reserve_token_ids` - New reserve token IDs for claiming BLND emissions, not checked
+    ///   against the pool (optional, see `set_reserve_token_ids`)
     /// * `new_free_fp_per_epoch` - New base FP for free play (optional)
     /// * `new_min_deposit_to_claim` - New minimum deposit to claim rewards (optional)
     /// * `new_dev_reward_share` - New portion of epoch rewards for game developers (optional)
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the epoch duration is invalid (see `set_next_epoch_duration`)
+    /// * `ApprovalThresholdNotMet` - If the router or BLND token changes and the
+    ///   approval threshold isn't met
     #[allow(clippy::too_many_arguments)]
     pub fn update_config(
         env: Env,
-        new_fee_vault: Option<Address>,
         new_soroswap_router: Option<Address>,
         new_blnd_token: Option<Address>,
-        new_usdc_token: Option<Address>,
         new_epoch_duration: Option<u64>,
         new_reserve_token_ids: Option<Vec<u32>>,
         new_free_fp_per_epoch: Option<i128>,
         new_min_deposit_to_claim: Option<i128>,
         new_dev_reward_share: Option<i128>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ContractError> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
+        if new_soroswap_router.is_some() || new_blnd_token.is_some() {
+            approvals::require_approval(
+                &env,
+                &types::AdminOperation::SetSwapContracts(
+                    new_soroswap_router.clone(),
+                    new_blnd_token.clone(),
+                ),
+            )?;
+        }
 
         let mut config = storage::get_config(&env);
-
-        // Update fee vault if provided
-        if let Some(vault) = new_fee_vault {
-            config.fee_vault = vault;
-        }
+        let old_config = config.clone();
 
         // Update soroswap router if provided
         if let Some(router) = new_soroswap_router {
@@ -189,14 +414,9 @@ impl Blendizzard {
             config.blnd_token = blnd;
         }
 
-        // Update USDC token if provided
-        if let Some(usdc) = new_usdc_token {
-            config.usdc_token = usdc;
-        }
-
-        // Update epoch duration if provided
+        // Schedule epoch duration if provided (takes effect next epoch)
         if let Some(duration) = new_epoch_duration {
-            config.epoch_duration = duration;
+            epoch::schedule_epoch_duration(&env, &mut config, duration)?;
         }
 
         // Update reserve token IDs if provided
@@ -222,356 +442,4035 @@ impl Blendizzard {
         storage::set_config(&env, &config);
 
         // Emit config updated event
-        events::emit_config_updated(&env, &admin);
+        audit::config_updated(&env, &admin, "update_config", &old_config);
 
         Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
+    /// Set the native XLM token (SAC) address used by `deposit_xlm`
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+    pub fn set_xlm_token(env: Env, xlm_token: Address) -> Result<(), Error> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.xlm_token = Some(xlm_token);
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_xlm_token", &old_config);
 
         Ok(())
     }
 
-    /// Pause the contract (emergency stop)
+    /// Set the reward distribution mode
     ///
-    /// When paused, all player-facing functions are disabled except admin functions.
-    /// This is an emergency mechanism to protect player funds in case of discovered vulnerabilities.
+    /// Takes effect from the next epoch created. The current epoch keeps the
+    /// mode it was created with, so players know the rules before they play.
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
-    pub fn pause(env: Env) -> Result<(), Error> {
+    pub fn set_distribution_mode(env: Env, mode: DistributionMode) -> Result<(), Error> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
 
-        storage::set_pause_state(&env, true);
-        events::emit_contract_paused(&env, &admin);
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.distribution_mode = mode;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_distribution_mode", &old_config);
 
         Ok(())
     }
 
-    /// Unpause the contract
+    /// Configure the price oracle used to sanity-check the BLND → USDC swap
     ///
-    /// Restores normal contract functionality after emergency pause.
+    /// During `cycle_epoch`, if the Soroswap quote is more than `max_deviation`
+    /// below the oracle-implied output, the swap is skipped and BLND carries
+    /// over to the next epoch.
+    ///
+    /// # Arguments
+    /// * `oracle` - SEP-40 oracle address (None disables the check)
+    /// * `max_deviation` - Maximum allowed shortfall, 7 decimals (e.g. 500_000 = 5%)
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
-    pub fn unpause(env: Env) -> Result<(), Error> {
+    /// * `InvalidConfig` - If max_deviation is not in (0, 1.0)
+    pub fn set_price_oracle(
+        env: Env,
+        oracle: Option<Address>,
+        max_deviation: i128,
+    ) -> Result<(), Error> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
 
-        storage::set_pause_state(&env, false);
-        events::emit_contract_unpaused(&env, &admin);
+        if oracle.is_some() && (max_deviation <= 0 || max_deviation >= types::SCALAR_7) {
+            return Err(Error::InvalidConfig);
+        }
 
-        Ok(())
-    }
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.price_oracle = oracle;
+        config.max_price_deviation = max_deviation;
+        storage::set_config(&env, &config);
 
-    /// Check if contract is paused
-    pub fn is_paused(env: Env) -> bool {
-        storage::is_paused(&env)
-    }
+        audit::config_updated(&env, &admin, "set_price_oracle", &old_config);
 
-    // ========================================================================
-    // Game Registry
-    // ========================================================================
+        Ok(())
+    }
 
-    /// Add or update a game contract registration
+    /// Route the BLND → USDC swap through the Soroswap Aggregator
     ///
-    /// Registers a game contract with a developer address for reward distribution.
-    /// Can be called multiple times to update the developer address.
+    /// The swap is split evenly across `protocols`. If the aggregated swap
+    /// fails, the Soroswap router is used instead. The oracle check (if any)
+    /// still uses the router quote.
     ///
     /// # Arguments
-    /// * `game_id` - Address of the game contract to register
-    /// * `developer` - Address to receive developer rewards for this game
+    /// * `aggregator` - Aggregator contract address, or None to use the router only
+    /// * `protocols` - Aggregator protocol IDs to route across (e.g. "soroswap")
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
-    pub fn add_game(env: Env, game_id: Address, developer: Address) -> Result<(), Error> {
-        game::add_game(&env, &game_id, &developer)
+    /// * `InvalidConfig` - If an aggregator is set without any protocol
+    pub fn set_swap_aggregator(
+        env: Env,
+        aggregator: Option<Address>,
+        protocols: Vec<String>,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if aggregator.is_some() && protocols.is_empty() {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.swap_aggregator = aggregator;
+        config.aggregator_protocols = protocols;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_swap_aggregator", &old_config);
+
+        Ok(())
     }
 
-    /// Remove a game contract from the approved list
+    /// Configure the participation streak bonus
     ///
-    /// Note: If the game has contributions in the current epoch, those will be
-    /// forfeited (developer cannot claim rewards for removed games).
+    /// Players who play in consecutive epochs earn `bonus_per_epoch` extra reward
+    /// weight for each consecutive epoch after the first, capped at `max_bonus`.
+    /// The bonus is snapshotted on a player's first game of each epoch.
+    ///
+    /// # Arguments
+    /// * `bonus_per_epoch` - Bonus per consecutive epoch, 7 decimals (e.g. 500_000 = +5%)
+    /// * `max_bonus` - Maximum bonus, 7 decimals (e.g. 5_000_000 = +50%)
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
-    pub fn remove_game(env: Env, game_id: Address) -> Result<(), Error> {
-        game::remove_game(&env, &game_id)
-    }
+    /// * `InvalidConfig` - If either value is negative or max_bonus exceeds 1.0
+    pub fn set_streak_bonus(env: Env, bonus_per_epoch: i128, max_bonus: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
 
-    /// Check if a contract is an approved game
-    pub fn is_game(env: Env, game_id: Address) -> bool {
-        game::is_game(&env, &game_id)
-    }
+        if bonus_per_epoch < 0 || max_bonus < 0 || max_bonus > types::SCALAR_7 {
+            return Err(Error::InvalidConfig);
+        }
 
-    // ========================================================================
-    // Vault Operations (REMOVED - Players interact directly with fee-vault-v2)
-    // ========================================================================
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.streak_bonus_per_epoch = bonus_per_epoch;
+        config.max_streak_bonus = max_bonus;
+        storage::set_config(&env, &config);
 
-    // ARCHITECTURE CHANGE: deposit() and withdraw() have been removed.
-    // Players now interact directly with the fee-vault-v2 contract for deposits/withdrawals.
-    // Blendizzard queries vault balances on-demand at game start and performs
-    // cross-epoch withdrawal detection at that time.
-    //
-    // To deposit: Call fee-vault-v2.deposit() directly
-    // To withdraw: Call fee-vault-v2.withdraw() directly
-    //
-    // The 50% withdrawal reset rule is enforced via cross-epoch balance comparison
-    // when players play their first game of a new epoch.
+        audit::config_updated(&env, &admin, "set_streak_bonus", &old_config);
 
-    // ========================================================================
-    // Faction Selection
-    // ========================================================================
+        Ok(())
+    }
 
-    /// Select a faction for the player
-    ///
-    /// Sets the player's persistent faction preference. Can be changed at ANY time.
-    /// If you haven't played a game this epoch, the new faction applies immediately.
-    /// If you've already played this epoch, the current epoch stays locked to your
-    /// old faction, and the new selection applies starting next epoch.
+    /// Configure vesting of large reward claims
     ///
     /// # Arguments
-    /// * `faction` - Faction ID (0=WholeNoodle, 1=PointyStick, 2=SpecialRock)
+    /// * `threshold` - Claims above this amount (USDC) vest. 0 disables vesting.
+    /// * `ledgers` - Vesting length in ledgers (~5s each)
     ///
     /// # Errors
-    /// * `InvalidFaction` - If faction ID is not 0, 1, or 2
-    pub fn select_faction(env: Env, player: Address, faction: u32) -> Result<(), Error> {
-        faction::select_faction(&env, &player, faction)
-    }
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If threshold is negative, or vesting is enabled with 0 ledgers
+    pub fn set_vesting(env: Env, threshold: i128, ledgers: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
 
-    // ========================================================================
-    // Player Queries
-    // ========================================================================
+        if threshold < 0 || (threshold > 0 && ledgers == 0) {
+            return Err(Error::InvalidConfig);
+        }
 
-    /// Get player information
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.vesting_threshold = threshold;
+        config.vesting_ledgers = ledgers;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_vesting", &old_config);
+
+        Ok(())
+    }
+
+    /// Base FP on start-of-epoch balances instead of live vault balances
     ///
-    /// Returns complete persistent player data including selected faction, total deposited,
-    /// and deposit timestamp.
+    /// Stops deposits made right before playing (e.g. just before `cycle_epoch`)
+    /// from counting towards the current epoch's FP and reward weight.
     ///
     /// # Errors
-    /// * `PlayerNotFound` - If player has never interacted with the contract
-    pub fn get_player(env: Env, player: Address) -> Result<types::Player, Error> {
-        storage::get_player(&env, &player).ok_or(Error::PlayerNotFound)
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_balance_snapshots(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.balance_snapshots = enabled;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_balance_snapshots", &old_config);
+
+        Ok(())
     }
 
-    /// Get player's epoch-specific information for any epoch
+    /// Set the share of claimed BLND kept as BLND and paid to winners alongside USDC
     ///
-    /// Returns complete epoch-specific data including locked faction, available FP,
-    /// total FP contributed, and balance snapshot. Consistent with `get_epoch(epoch)`
-    /// which also requires an epoch parameter.
+    /// # Arguments
+    /// * `bps` - Retained share in basis points (10_000 = 100%). 0 swaps all BLND.
     ///
-    /// **Behavior for current epoch:** If player hasn't played any games this epoch yet,
-    /// calculates what their FP WOULD be based on current vault balance without writing
-    /// to storage. This allows UIs to display FP before the player's first game.
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bps is above 10_000
+    pub fn set_blnd_retention(env: Env, bps: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bps as i128 > math::BPS_SCALE {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.blnd_retention_bps = bps;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_blnd_retention", &old_config);
+
+        Ok(())
+    }
+
+    /// Burn a share of claimed BLND instead of swapping it to USDC
     ///
-    /// **Behavior for historical epochs:** Only returns data if player participated in
-    /// that epoch (played at least one game).
+    /// Applies to BLND newly claimed by `cycle_epoch` and `harvest`. Burns are
+    /// recorded per epoch (`get_epoch_blnd_burned`) and in `get_global_stats`.
     ///
     /// # Arguments
-    /// * `epoch` - Epoch number to query
-    /// * `player` - Player address
-    ///
-    /// # Examples
-    /// ```ignore
-    /// // Current epoch
-    /// let current = contract.get_current_epoch();
-    /// let player_data = contract.get_epoch_player(&current, &player)?;
-    ///
-    /// // Historical epoch
-    /// let epoch0_data = contract.get_epoch_player(&0, &player)?;
-    /// ```
+    /// * `bps` - Burned share in basis points (10_000 = 100%). 0 disables burning.
+    /// * `burn_address` - Address to send the BLND to, or None to burn it with
+    ///   the token's `burn`
     ///
     /// # Errors
-    /// * `FactionNotSelected` - If querying current epoch and player hasn't selected faction
-    /// * `PlayerNotFound` - If querying historical epoch and player didn't participate
-    pub fn get_epoch_player(
-        env: Env,
-        epoch: u32,
-        player: Address,
-    ) -> Result<types::EpochPlayer, Error> {
-        // Try to get existing epoch player data first
-        if let Some(epoch_player) = storage::get_epoch_player(&env, epoch, &player) {
-            return Ok(epoch_player);
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bps is above 10_000 or the burn address is this contract
+    pub fn set_blnd_burn(env: Env, bps: u32, burn_address: Option<Address>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bps as i128 > math::BPS_SCALE
+            || burn_address.as_ref() == Some(&env.current_contract_address())
+        {
+            return Err(Error::InvalidConfig);
         }
 
-        // If querying current epoch and no data exists yet, compute it on-the-fly
-        if epoch == storage::get_current_epoch(&env) {
-            // Check if player has selected a faction
-            storage::get_player(&env, &player).ok_or(Error::FactionNotSelected)?;
+        let old_burn = storage::get_blnd_burn(&env);
+        let mut new_burn = old_burn.clone();
+        new_burn.bps = bps;
+        new_burn.burn_address = burn_address;
+        storage::set_blnd_burn(&env, &new_burn);
 
-            // Calculate FP using same logic as initialize_player_epoch
-            let total_fp = faction_points::calculate_faction_points(&env, &player)?;
-            let current_balance = vault::get_vault_balance(&env, &player);
+        audit::record(
+            &env,
+            &admin,
+            "set_blnd_burn",
+            &(old_burn.bps, old_burn.burn_address),
+            &(new_burn.bps, new_burn.burn_address),
+        );
+        events::emit_config_updated(&env, &admin);
 
-            // Return computed EpochPlayer (not saved to storage yet)
-            Ok(types::EpochPlayer {
-                epoch_faction: None, // Faction not locked until first game
-                epoch_balance_snapshot: current_balance,
-                available_fp: total_fp,
-                total_fp_contributed: 0,
-            })
-        } else {
-            // For historical epochs, player must have participated
-            Err(Error::PlayerNotFound)
-        }
+        Ok(())
     }
 
-    // ========================================================================
-    // Game Lifecycle
-    // ========================================================================
+    /// Get the BLND burn settings and lifetime total burned
+    pub fn get_blnd_burn(env: Env) -> types::BlndBurn {
+        storage::get_blnd_burn(&env)
+    }
+
+    /// Get the BLND burned while claiming yield in an epoch
+    pub fn get_epoch_blnd_burned(env: Env, epoch: u32) -> i128 {
+        storage::get_epoch_blnd_burned(&env, epoch)
+    }
+
+    /// Set the half-life of faction points contributed within an epoch
+    ///
+    /// Wins are credited at their value decayed to the epoch's end, so points
+    /// earned early count for less than points earned late (see
+    /// `faction_points::decayed_contribution`).
+    ///
+    /// # Arguments
+    /// * `half_life_ledgers` - Half-life in ledgers. 0 disables decay.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_fp_decay(env: Env, half_life_ledgers: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.fp_half_life_ledgers = half_life_ledgers;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_fp_decay", &old_config);
+
+        Ok(())
+    }
+
+    /// Cap faction imbalance
+    ///
+    /// While a faction's members (or stake) locked in this epoch exceed the
+    /// smallest faction's by more than the ratio, `select_faction` rejects
+    /// new players choosing it.
+    ///
+    /// # Arguments
+    /// * `cap` - `None`, or a member count or stake ratio, 7 decimals
+    ///   (e.g. 2_0000000 = twice the smallest faction)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the ratio is below 1.0
+    pub fn set_faction_cap(env: Env, cap: types::FactionCap) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if let types::FactionCap::Members(ratio) | types::FactionCap::Stake(ratio) = cap {
+            if ratio < types::SCALAR_7 {
+                return Err(Error::InvalidConfig);
+            }
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.faction_cap = cap;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_faction_cap", &old_config);
+
+        Ok(())
+    }
+
+    /// Require a minimum of activity per epoch for a share of the reward pool
+    ///
+    /// Winners below either threshold can't claim, and their wins don't count
+    /// towards the reward split until they meet both. Players who already met
+    /// the requirement this epoch stay eligible if it is raised.
+    ///
+    /// # Arguments
+    /// * `min_games` - Sessions to finish in the epoch (won or lost). 0 disables.
+    /// * `min_fp` - FP to contribute in the epoch. 0 disables.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If min_fp is negative
+    pub fn set_activity_requirement(env: Env, min_games: u32, min_fp: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if min_fp < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_requirement = storage::get_activity_requirement(&env);
+        let requirement = types::ActivityRequirement { min_games, min_fp };
+        storage::set_activity_requirement(&env, &requirement);
+
+        audit::record(
+            &env,
+            &admin,
+            "set_activity_requirement",
+            &old_requirement,
+            &requirement,
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the minimum activity per epoch for reward eligibility
+    pub fn get_activity_requirement(env: Env) -> types::ActivityRequirement {
+        storage::get_activity_requirement(&env)
+    }
+
+    /// Set the minimum stake for a player's wins to credit faction points
+    ///
+    /// Anti-sybil floor: winners whose epoch balance snapshot is below it
+    /// still settle wagers normally, but their wins add nothing to faction
+    /// standings or their own contribution (so no share of the reward pool).
+    ///
+    /// # Arguments
+    /// * `min_stake` - Minimum vault balance in USDC (7 decimals). 0 disables.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If min_stake is negative
+    pub fn set_min_fp_stake(env: Env, min_stake: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if min_stake < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_min_stake = storage::get_min_fp_stake(&env);
+        storage::set_min_fp_stake(&env, min_stake);
+
+        audit::record(&env, &admin, "set_min_fp_stake", &old_min_stake, &min_stake);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the minimum stake for wins to credit faction points
+    pub fn get_min_fp_stake(env: Env) -> i128 {
+        storage::get_min_fp_stake(&env)
+    }
+
+    /// Set the win-probability curve that scales faction points for wins
+    ///
+    /// Beating a higher-rated or higher-staked opponent credits up to
+    /// (1 + strength)x the contribution, and expected wins down to
+    /// (1 - strength)x. Team games are not adjusted.
+    ///
+    /// # Arguments
+    /// * `curve` - Elo vs stake weighting and strength (strength 0 disables)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If a weight or the strength exceeds 10000 bps
+    pub fn set_win_odds_curve(env: Env, curve: types::WinOddsCurve) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if curve.rating_weight_bps > 10_000 || curve.strength_bps > 10_000 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_curve = storage::get_win_odds_curve(&env);
+        storage::set_win_odds_curve(&env, &curve);
+
+        audit::record(&env, &admin, "set_win_odds_curve", &old_curve, &curve);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the win-probability curve for faction points
+    pub fn get_win_odds_curve(env: Env) -> types::WinOddsCurve {
+        storage::get_win_odds_curve(&env)
+    }
+
+    /// Set the largest share of an epoch's reward pools any address can claim
+    ///
+    /// Applies from the next epoch to Linear and Quadratic distribution: the
+    /// excess above the cap goes pro-rata to the rest of the winning faction.
+    ///
+    /// # Arguments
+    /// * `cap_bps` - Largest share in basis points, from 500 (5%) to 10,000.
+    ///   0 disables the cap.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If cap_bps is below 500 (and not 0) or above 10,000
+    pub fn set_reward_cap(env: Env, cap_bps: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if cap_bps != 0
+            && (cap_bps < reward_cap::MIN_REWARD_CAP_BPS || cap_bps as i128 > math::BPS_SCALE)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_cap_bps = storage::get_reward_cap(&env);
+        storage::set_reward_cap(&env, cap_bps);
+
+        audit::record(&env, &admin, "set_reward_cap", &old_cap_bps, &cap_bps);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the reward cap (basis points, 0 = no cap)
+    pub fn get_reward_cap(env: Env) -> u32 {
+        storage::get_reward_cap(&env)
+    }
+
+    /// Get the reward cap an epoch opened with (basis points, 0 = no cap)
+    pub fn get_epoch_reward_cap(env: Env, epoch: u32) -> u32 {
+        storage::get_epoch_reward_cap(&env, epoch)
+    }
+
+    /// Set the minimum session age before games can report a forfeit
+    ///
+    /// # Arguments
+    /// * `ledgers` - Ledgers since the session started (720 by default)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If ledgers is 0
+    pub fn set_forfeit_delay(env: Env, ledgers: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if ledgers == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_ledgers = storage::get_forfeit_delay(&env);
+        storage::set_forfeit_delay(&env, ledgers);
+
+        audit::record(&env, &admin, "set_forfeit_delay", &old_ledgers, &ledgers);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the minimum session age before games can report a forfeit
+    pub fn get_forfeit_delay(env: Env) -> u32 {
+        storage::get_forfeit_delay(&env)
+    }
+
+    /// Set the achievement thresholds and the FP bonus per unlocked achievement
+    ///
+    /// # Arguments
+    /// * `win_streak` - Consecutive wins for the win streak badge
+    /// * `games_played` - Finished sessions for the games played badge
+    /// * `fp_bonus` - Extra epoch FP per unlocked achievement (7 decimals, at most
+    ///   10%). 0 makes achievements badges only.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If a threshold is 0 or fp_bonus is out of range
+    pub fn set_achievement_settings(
+        env: Env,
+        win_streak: u32,
+        games_played: u32,
+        fp_bonus: i128,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if win_streak == 0
+            || games_played == 0
+            || fp_bonus < 0
+            || fp_bonus > achievements::MAX_ACHIEVEMENT_FP_BONUS
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_settings = storage::get_achievement_settings(&env);
+        let settings = types::AchievementSettings {
+            win_streak,
+            games_played,
+            fp_bonus,
+        };
+        storage::set_achievement_settings(&env, &settings);
+
+        audit::record(
+            &env,
+            &admin,
+            "set_achievement_settings",
+            &old_settings,
+            &settings,
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the achievement thresholds and FP bonus
+    pub fn get_achievement_settings(env: Env) -> types::AchievementSettings {
+        storage::get_achievement_settings(&env)
+    }
+
+    /// Set the epoch MVP bonus
+    ///
+    /// When an epoch's rewards are processed, the winning faction's top
+    /// contributor is paid `bps` of the player reward pool in USDC, before the
+    /// pool is split, and earns an "MVP of epoch N" title (see `get_titles`).
+    ///
+    /// # Arguments
+    /// * `bps` - Share of the player reward pool in basis points (at most 10%,
+    ///   0 awards the title only)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bps is above 10%
+    pub fn set_mvp_bonus(env: Env, bps: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bps > mvp::MAX_MVP_BONUS_BPS {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_bps = storage::get_mvp_bonus(&env);
+        storage::set_mvp_bonus(&env, bps);
+
+        audit::record(&env, &admin, "set_mvp_bonus", &old_bps, &bps);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the epoch MVP bonus in basis points
+    pub fn get_mvp_bonus(env: Env) -> u32 {
+        storage::get_mvp_bonus(&env)
+    }
+
+    /// Get a player's titles (e.g. "MVP of epoch N"), oldest first
+    ///
+    /// Keeps the most recent 32.
+    pub fn get_titles(env: Env, user: Address) -> Vec<types::Title> {
+        storage::get_titles(&env, &user)
+    }
+
+    /// Configure the jackpot
+    ///
+    /// Each cycle moves `bps` of the closed epoch's new player reward pool into
+    /// the jackpot, and every `interval`-th epoch (epochs `interval - 1`,
+    /// `2 * interval - 1`, ...) pays the whole jackpot to its winning faction
+    /// with the epoch's rewards. The accumulated balance is kept when the
+    /// settings change.
+    ///
+    /// # Arguments
+    /// * `bps` - Share of the player reward pool in basis points (at most 10%,
+    ///   0 stops accrual)
+    /// * `interval` - Epochs per payout
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bps is out of range or interval is 0
+    pub fn set_jackpot_settings(env: Env, bps: u32, interval: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bps > jackpot::MAX_JACKPOT_BPS || interval == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old_jackpot = storage::get_jackpot(&env);
+        let mut new_jackpot = old_jackpot.clone();
+        new_jackpot.bps = bps;
+        new_jackpot.interval = interval;
+        storage::set_jackpot(&env, &new_jackpot);
+
+        audit::record(
+            &env,
+            &admin,
+            "set_jackpot_settings",
+            &(old_jackpot.bps, old_jackpot.interval),
+            &(bps, interval),
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the jackpot settings and accumulated USDC
+    pub fn get_jackpot(env: Env) -> types::Jackpot {
+        storage::get_jackpot(&env)
+    }
+
+    /// Set the insurance fund's share of routed game rakes
+    ///
+    /// Each cycle moves `bps` of the USDC routed from the winning faction's
+    /// rakes (to the reward pool, treasury and burn alike) into the insurance
+    /// fund. The fund balance is kept when the share changes.
+    ///
+    /// # Arguments
+    /// * `bps` - Share in basis points (0 stops accrual)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bps is above 100%
+    pub fn set_insurance_bps(env: Env, bps: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bps > insurance::MAX_INSURANCE_BPS {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut fund = storage::get_insurance_fund(&env);
+        let old_bps = fund.bps;
+        fund.bps = bps;
+        storage::set_insurance_fund(&env, &fund);
+
+        audit::record(&env, &admin, "set_insurance_bps", &old_bps, &bps);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the insurance fund's share, balance and total paid out
+    pub fn get_insurance_fund(env: Env) -> types::InsuranceFund {
+        storage::get_insurance_fund(&env)
+    }
+
+    /// Cover a vault shortfall from the insurance fund (admin only)
+    ///
+    /// When the DirectPool backend holds fewer pool b-tokens than it credits
+    /// to players, supplies insurance USDC to the pool for this contract
+    /// (without crediting anyone) until the gap closes or the fund runs out.
+    ///
+    /// # Returns
+    /// USDC spent from the fund
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `FeatureNotConfigured` - If the backend isn't DirectPool
+    /// * `InvalidAmount` - If there is no shortfall
+    /// * `InsufficientBalance` - If the fund is empty
+    pub fn cover_shortfall(env: Env) -> Result<i128, ContractError> {
+        reentrancy::non_reentrant(&env, || insurance::cover_shortfall(&env))
+    }
+
+    // ========================================================================
+    // Protocol Treasury
+    // ========================================================================
+
+    /// Set or clear the treasurer
+    ///
+    /// The treasurer spends the protocol treasury (funded by game rakes routed
+    /// to `RakeRoute::Protocol`) with `queue_treasury_transfer` and
+    /// `treasury_transfer`, and has no other authority. Needs approver sign-off
+    /// once approvals are configured (see `set_approvers`).
+    ///
+    /// # Arguments
+    /// * `treasurer` - Treasurer address, or None to disable treasury spending
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `ApprovalThresholdNotMet` - If the approval threshold isn't met
+    pub fn set_treasurer(env: Env, treasurer: Option<Address>) -> Result<(), ContractError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        approvals::require_approval(
+            &env,
+            &types::AdminOperation::SetTreasurer(treasurer.clone()),
+        )?;
+
+        let old_treasurer = protocol_treasury::set_treasurer(&env, &treasurer);
+        audit::record(&env, &admin, "set_treasurer", &old_treasurer, &treasurer);
+        events::emit_treasurer_changed(&env, &admin, &treasurer);
+
+        Ok(())
+    }
+
+    /// Get the treasurer address (None if no treasurer is set)
+    pub fn get_treasurer(env: Env) -> Option<Address> {
+        storage::get_protocol_treasury(&env).treasurer
+    }
+
+    /// Get the protocol treasury's balance of each token it holds
+    pub fn treasury_balance(env: Env) -> Map<Address, i128> {
+        protocol_treasury::balances(&env)
+    }
+
+    /// Queue a transfer out of the protocol treasury (treasurer only)
+    ///
+    /// Executable with `treasury_transfer` after a 2-day timelock, during which
+    /// the admin can cancel it. Replaces any transfer queued earlier.
+    ///
+    /// # Arguments
+    /// * `to` - Recipient
+    /// * `token` - Token to transfer
+    /// * `amount` - Amount to transfer
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If no treasurer is set
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `InsufficientTreasury` - If the treasury holds less than amount of token
+    pub fn queue_treasury_transfer(
+        env: Env,
+        to: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<types::QueuedTreasuryTransfer, ContractError> {
+        protocol_treasury::queue_transfer(&env, &to, &token, amount)
+    }
+
+    /// Cancel the queued treasury transfer
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn cancel_treasury_transfer(env: Env) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let cancelled = protocol_treasury::cancel_transfer(&env);
+        audit::record(&env, &admin, "cancel_treasury_transfer", &cancelled, &());
+
+        Ok(())
+    }
+
+    /// Get the treasury transfer waiting for its timelock, if any
+    pub fn get_queued_treasury_transfer(env: Env) -> Option<types::QueuedTreasuryTransfer> {
+        storage::get_protocol_treasury(&env).queued_transfer
+    }
+
+    /// Transfer tokens out of the protocol treasury (treasurer only)
+    ///
+    /// Executes the transfer queued with `queue_treasury_transfer` once its
+    /// timelock has passed; the arguments must match it exactly.
+    ///
+    /// # Arguments
+    /// * `to` - Recipient
+    /// * `token` - Token to transfer
+    /// * `amount` - Amount to transfer
+    ///
+    /// # Returns
+    /// The treasury's remaining balance of token
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If no treasurer is set
+    /// * `TreasuryTransferNotQueued` - If no matching transfer is queued
+    /// * `TreasuryTransferTimelocked` - If the queued transfer's timelock hasn't passed
+    /// * `InsufficientTreasury` - If the treasury holds less than amount of token
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn treasury_transfer(
+        env: Env,
+        to: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        reentrancy::non_reentrant(&env, || {
+            protocol_treasury::transfer(&env, &to, &token, amount)
+        })
+    }
+
+    /// Set the share of each epoch's player reward pool paid into faction treasuries
+    ///
+    /// # Arguments
+    /// * `share` - Share of the player reward pool, 7 decimals. 0 disables treasuries.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If share is not in [0, 1.0]
+    pub fn set_faction_treasury_share(env: Env, share: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if !(0..=types::SCALAR_7).contains(&share) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.faction_treasury_share = share;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_faction_treasury_share", &old_config);
+
+        Ok(())
+    }
+
+    /// Configure spectator predictions
+    ///
+    /// # Arguments
+    /// * `max_stake` - Maximum USDC one spectator can stake per session. 0 disables predictions.
+    /// * `rake` - Share of losing stakes sent to the reward pool, 7 decimals
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If max_stake is negative or rake is not in [0, 1.0]
+    pub fn set_predictions(env: Env, max_stake: i128, rake: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if max_stake < 0 || !(0..=types::SCALAR_7).contains(&rake) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.max_prediction = max_stake;
+        config.prediction_rake = rake;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_predictions", &old_config);
+
+        Ok(())
+    }
+
+    /// Set the share of referred players' claimed rewards credited to their referrer
+    ///
+    /// # Arguments
+    /// * `share` - Referral share, 7 decimals (e.g. 500_000 = 5%). 0 disables referrals.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If share is not in [0, 1.0]
+    pub fn set_referral_share(env: Env, share: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if !(0..=types::SCALAR_7).contains(&share) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.referral_share = share;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_referral_share", &old_config);
+
+        Ok(())
+    }
+
+    /// Set how many epochs rewards stay claimable before being carried over
+    ///
+    /// Once an epoch's window has passed, the next `cycle_epoch` adds its
+    /// unclaimed player and developer rewards to the reward pool being finalized.
+    ///
+    /// # Arguments
+    /// * `epochs` - Claim window in epochs. 0 disables expiry.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_claim_window(env: Env, epochs: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.claim_window_epochs = epochs;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_claim_window", &old_config);
+
+        Ok(())
+    }
+
+    /// Set the maximum number of simultaneous active sessions per player
+    ///
+    /// Bounds how much FP a player can have locked in stalled sessions.
+    ///
+    /// # Arguments
+    /// * `max_sessions` - Maximum unfinished current-epoch sessions. 0 = unlimited.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_max_active_sessions(env: Env, max_sessions: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.max_active_sessions = max_sessions;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_max_active_sessions", &old_config);
+
+        Ok(())
+    }
+
+    /// Set the length of each epoch's faction-selection phase
+    ///
+    /// During the selection phase factions can be switched but games can't be
+    /// started; during the remaining battle phase games count and factions are
+    /// locked.
+    ///
+    /// # Arguments
+    /// * `duration` - Selection phase length in seconds. 0 disables the phase.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If duration is not shorter than the current or
+    ///   scheduled epoch duration
+    pub fn set_selection_phase(env: Env, duration: u64) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        let shortest_epoch = config
+            .next_epoch_duration
+            .map_or(config.epoch_duration, |next| {
+                next.min(config.epoch_duration)
+            });
+        if duration >= shortest_epoch {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.selection_phase_duration = duration;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_selection_phase", &old_config);
+
+        Ok(())
+    }
+
+    /// Schedule a new epoch duration, taking effect when the next epoch opens
+    ///
+    /// The current epoch keeps its end time. Each epoch records the duration
+    /// it was opened with in `EpochInfo::duration`.
+    ///
+    /// # Arguments
+    /// * `secs` - Epoch duration in seconds (ledgers with the ledger sequence epoch clock)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If secs is 0, not longer than the selection phase, or
+    ///   longer than the session TTL (`max_session_ledgers`)
+    pub fn set_next_epoch_duration(env: Env, secs: u64) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        epoch::schedule_epoch_duration(&env, &mut config, secs)?;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_next_epoch_duration", &old_config);
+
+        Ok(())
+    }
+
+    /// Configure the underdog faction multiplier
+    ///
+    /// When an epoch opens, factions with less stake than the largest faction
+    /// had in the previous epoch get an FP multiplier of
+    /// `1.0 + max_bonus * (largest - stake) / largest`. The largest faction
+    /// stays at 1.0x. Takes effect from the next epoch.
+    ///
+    /// # Arguments
+    /// * `max_bonus` - Bonus for a faction with no stake, 7 decimals
+    ///   (e.g. 2_500_000 = 1.25x). 0 disables the multiplier.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If max_bonus is negative or exceeds 1.0
+    pub fn set_underdog_bonus(env: Env, max_bonus: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if !(0..=types::SCALAR_7).contains(&max_bonus) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.max_underdog_bonus = max_bonus;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_underdog_bonus", &old_config);
+
+        Ok(())
+    }
+
+    /// Get the current epoch's FP multiplier for every faction
+    ///
+    /// # Returns
+    /// Map of faction_id -> FP multiplier (7 decimals, 10_000_000 = 1.0x)
+    pub fn get_faction_multipliers(env: Env) -> Map<u32, i128> {
+        underdog::current_multipliers(&env)
+    }
+
+    /// Set the TTL of session entries
+    ///
+    /// Session data (sessions, series, team sessions, commit-reveal moves) is
+    /// extended to this many ledgers on every touch, so abandoned sessions stop
+    /// paying rent soon after their last interaction. Must cover at least one
+    /// epoch, since sessions can be ended until the epoch cycles.
+    ///
+    /// # Arguments
+    /// * `ledgers` - Session TTL in ledgers (~5s each). 0 restores the 30-day default.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If ledgers is shorter than one epoch or longer than 30 days
+    pub fn set_max_session_ledgers(env: Env, ledgers: u32) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        let longest_epoch = config
+            .next_epoch_duration
+            .map_or(config.epoch_duration, |next| {
+                next.max(config.epoch_duration)
+            });
+        let epoch_ledgers = epoch_clock::to_ledgers(&env, longest_epoch);
+        if ledgers != 0
+            && ((ledgers as u64) < epoch_ledgers || ledgers > storage::TEMPORARY_TTL_EXTEND_TO)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.max_session_ledgers = ledgers;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_max_session_ledgers", &old_config);
+
+        Ok(())
+    }
+
+    /// Set guarded-launch deposit caps
+    ///
+    /// Enforced on every deposit made through this contract (`deposit`,
+    /// `deposit_from_allowance`, `deposit_with_lock`, `deposit_xlm` and
+    /// `onboard`). With the FeeVault backend players can also deposit into the
+    /// fee-vault directly, which this contract can't stop. For those deposits
+    /// the per-user cap still bounds FP, which is computed from the balance
+    /// capped at `per_user`; the global cap only bounds deposits routed through
+    /// Blendizzard. Use the DirectPool backend for a hard global cap.
+    ///
+    /// # Arguments
+    /// * `per_user` - Maximum vault balance per user (and the most balance that earns FP). 0 disables the cap.
+    /// * `global` - Maximum total underlying in the vault backend. 0 disables the cap.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If either cap is negative
+    pub fn set_deposit_caps(env: Env, per_user: i128, global: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if per_user < 0 || global < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.max_deposit_per_user = per_user;
+        config.max_total_deposits = global;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_deposit_caps", &old_config);
+
+        Ok(())
+    }
+
+    /// Configure the bonded keeper registry
+    ///
+    /// With `min_bond > 0`, only keepers bonding at least `min_bond` USDC may
+    /// cycle an epoch during the first `priority_window` seconds after it ends
+    /// (via `keeper_cycle_epoch`); `cycle_epoch` opens to everyone afterwards.
+    /// Keepers earn `bounty` USDC per cycle from the closed epoch's player
+    /// reward pool. Existing bonds are kept when the settings change.
+    ///
+    /// # Arguments
+    /// * `min_bond` - Minimum keeper bond in USDC (0 disables the registry)
+    /// * `priority_window` - Seconds after epoch end reserved for keepers
+    /// * `bounty` - USDC paid to the keeper per cycle
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If an amount is negative
+    pub fn set_keeper_settings(
+        env: Env,
+        min_bond: i128,
+        priority_window: u64,
+        bounty: i128,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if min_bond < 0 || bounty < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut registry = storage::get_keeper_registry(&env);
+        let old_settings = (registry.min_bond, registry.priority_window, registry.bounty);
+        registry.min_bond = min_bond;
+        registry.priority_window = priority_window;
+        registry.bounty = bounty;
+        storage::set_keeper_registry(&env, &registry);
+
+        audit::record(
+            &env,
+            &admin,
+            "set_keeper_settings",
+            &old_settings,
+            &(min_bond, priority_window, bounty),
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Enable or disable automatic detection of emission-bearing reserves
+    ///
+    /// When enabled, yield claims query the pool's reserves and claim b-token
+    /// and d-token emissions for every reserve with emissions, ignoring
+    /// `reserve_token_ids`. Emissions from newly listed reserves are then
+    /// claimed without a config change.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_auto_reserve_emissions(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.auto_reserve_emissions = enabled;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_auto_reserve_emissions", &old_config);
+
+        Ok(())
+    }
+
+    /// Set the reserve token IDs to claim pool emissions for
+    ///
+    /// Accepts b-token (`index * 2 + 1`, suppliers) and d-token (`index * 2`,
+    /// borrowers) IDs. Every ID is checked against the backend's pool, so a
+    /// typo can't silently stop emissions from being claimed.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If an ID is repeated or its reserve isn't listed on the pool
+    /// * `FeatureNotConfigured` - If DirectPool is active without a pool
+    pub fn set_reserve_token_ids(env: Env, reserve_token_ids: Vec<u32>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        vault::validate_reserve_token_ids(&env, &config, &reserve_token_ids)?;
+        config.reserve_token_ids = reserve_token_ids;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_reserve_token_ids", &old_config);
+
+        Ok(())
+    }
+
+    /// Enable or disable whitelist mode
+    ///
+    /// While enabled, only whitelisted users can deposit through this contract
+    /// and select a faction. Users who already selected a faction keep playing.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_whitelist_enabled(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        config.whitelist_enabled = enabled;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_whitelist_enabled", &old_config);
+
+        Ok(())
+    }
+
+    /// Add users to the whitelist
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn add_to_whitelist(env: Env, users: Vec<Address>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        for user in users.iter() {
+            storage::set_whitelisted(&env, &user, true);
+        }
+
+        audit::record(&env, &admin, "add_to_whitelist", &(), &users);
+        events::emit_whitelist_updated(&env, &admin, &users, true);
+
+        Ok(())
+    }
+
+    /// Remove users from the whitelist
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn remove_from_whitelist(env: Env, users: Vec<Address>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        for user in users.iter() {
+            storage::set_whitelisted(&env, &user, false);
+        }
+
+        audit::record(&env, &admin, "remove_from_whitelist", &(), &users);
+        events::emit_whitelist_updated(&env, &admin, &users, false);
+
+        Ok(())
+    }
+
+    /// Check if a user is on the whitelist
+    pub fn is_whitelisted(env: Env, user: Address) -> bool {
+        storage::is_whitelisted(&env, &user)
+    }
+
+    /// Choose where player deposits are held
+    ///
+    /// With `DirectPool`, players deposit and withdraw through this contract's
+    /// `deposit`/`withdraw`, the contract holds the Blend pool b-tokens, and
+    /// epoch yield is the pool's BLND emissions.
+    ///
+    /// # Arguments
+    /// * `backend` - Vault backend to use
+    /// * `blend_pool` - Blend pool address (required for DirectPool)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `FeatureNotConfigured` - If DirectPool is chosen without a pool
+    /// * `VaultBackendInUse` - If the current DirectPool backend still holds deposits
+    pub fn set_vault_backend(
+        env: Env,
+        backend: types::VaultBackend,
+        blend_pool: Option<Address>,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if backend == types::VaultBackend::DirectPool && blend_pool.is_none() {
+            return Err(Error::FeatureNotConfigured);
+        }
+
+        // Switching away would strand the b-tokens held for players
+        let mut config = storage::get_config(&env);
+        let old_config = config.clone();
+        if config.vault_backend == types::VaultBackend::DirectPool
+            && (backend != config.vault_backend || blend_pool != config.blend_pool)
+            && storage::get_total_pool_b_tokens(&env) > 0
+        {
+            return Err(Error::VaultBackendInUse);
+        }
+
+        config.vault_backend = backend;
+        config.blend_pool = blend_pool;
+        storage::set_config(&env, &config);
+
+        audit::config_updated(&env, &admin, "set_vault_backend", &old_config);
+
+        Ok(())
+    }
+
+    /// Queue a vault migration, executable with `migrate_vault` after a 2-day timelock
+    ///
+    /// Replaces any migration queued earlier.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If new_vault is the current vault
+    pub fn queue_vault_migration(
+        env: Env,
+        new_vault: Address,
+    ) -> Result<types::QueuedVaultMigration, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old = storage::get_queued_vault_migration(&env);
+        let migration = vault::queue_migration(&env, &new_vault)?;
+        audit::record(&env, &admin, "queue_vault_migration", &old, &migration);
+
+        Ok(migration)
+    }
+
+    /// Cancel the queued vault migration
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn cancel_vault_migration(env: Env) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old = storage::get_queued_vault_migration(&env);
+        storage::set_queued_vault_migration(&env, None);
+        audit::record(&env, &admin, "cancel_vault_migration", &old, &());
+
+        Ok(())
+    }
+
+    /// Get the vault migration waiting for its timelock, if any
+    pub fn get_queued_vault_migration(env: Env) -> Option<types::QueuedVaultMigration> {
+        storage::get_queued_vault_migration(&env)
+    }
+
+    /// Move deposits and yield to a new fee-vault or Blend pool
+    ///
+    /// Executes the migration queued with `queue_vault_migration` once its
+    /// timelock has passed. Claims the old backend's pending yield, then points
+    /// the contract at `new_vault` (a USDC fee-vault-v2 with FeeVault, a Blend
+    /// pool with DirectPool) and re-derives `reserve_token_ids` from its pool.
+    /// With DirectPool the contract's whole USDC position is withdrawn and
+    /// supplied to the new pool. With FeeVault players own their vault shares,
+    /// so each player withdraws and re-deposits theirs with
+    /// `migrate_vault_position`.
+    ///
+    /// # Returns
+    /// USDC moved to the new pool (always 0 with FeeVault)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `VaultMigrationNotQueued` - If no migration to new_vault is queued
+    /// * `VaultMigrationTimelocked` - If the queued migration's timelock hasn't passed
+    /// * `InvalidConfig` - If new_vault is the current vault, or a fee-vault for another asset
+    /// * `FeatureNotConfigured` - If DirectPool is active without a pool
+    /// * `ApprovalThresholdNotMet` - If the approval threshold isn't met (see `set_approvers`)
+    pub fn migrate_vault(env: Env, new_vault: Address) -> Result<i128, ContractError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        approvals::require_approval(
+            &env,
+            &types::AdminOperation::MigrateVault(new_vault.clone()),
+        )?;
+
+        let (old_vault, moved) = vault::migrate_vault(&env, &new_vault)?;
+        audit::record(&env, &admin, "migrate_vault", &old_vault, &new_vault);
+        events::emit_vault_migrated(&env, &old_vault, &new_vault, moved);
+
+        Ok(moved)
+    }
+
+    /// Move a player's deposit from the pre-migration fee-vault into the current one
+    ///
+    /// Withdraws the player's whole balance from the fee-vault used before the
+    /// last `migrate_vault` and deposits it into the current one. The player
+    /// must authorize the nested vault withdrawal and deposit.
+    ///
+    /// # Returns
+    /// USDC moved into the current fee-vault
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If the FeeVault backend isn't active, or it was never migrated
+    /// * `InsufficientBalance` - If the player has nothing left in the previous vault
+    pub fn migrate_vault_position(env: Env, player: Address) -> Result<i128, ContractError> {
+        let moved = reentrancy::non_reentrant(&env, || vault::migrate_position(&env, &player))?;
+        invariants::check_player(&env, &player);
+        Ok(moved)
+    }
+
+    /// Return tokens sent to the contract by mistake
+    ///
+    /// Only tokens the contract doesn't account for can be rescued: USDC
+    /// (owed to players), BLND (epoch yield) and the fee-vault are refused.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `TokenNotRescuable` - If the token is protected
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `InsufficientBalance` - If amount exceeds the contract's balance
+    pub fn rescue_token(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        rescue::rescue_token(&env, &token, &to, amount)
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// The new code takes over once this call completes. If it changes the
+    /// storage layout, call its `migrate_storage` next.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `ApprovalThresholdNotMet` - If the approval threshold isn't met (see `set_approvers`)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        approvals::require_approval(&env, &types::AdminOperation::Upgrade(new_wasm_hash.clone()))?;
+
+        audit::record(&env, &admin, "upgrade", &(), &new_wasm_hash);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Pause the contract (emergency stop)
+    ///
+    /// When paused, all player-facing functions are disabled except admin functions.
+    /// This is an emergency mechanism to protect player funds in case of discovered vulnerabilities.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn pause(env: Env) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let was_paused = storage::is_paused(&env);
+        storage::set_pause_state(&env, true);
+        audit::record(&env, &admin, "pause", &was_paused, &true);
+        events::emit_contract_paused(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Unpause the contract
+    ///
+    /// Restores normal contract functionality after emergency pause.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let was_paused = storage::is_paused(&env);
+        storage::set_pause_state(&env, false);
+        audit::record(&env, &admin, "unpause", &was_paused, &false);
+        events::emit_contract_unpaused(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Set or clear the guardian
+    ///
+    /// The guardian can pause the contract with `guardian_pause` but has no
+    /// other authority: it cannot unpause, change parameters or move funds.
+    ///
+    /// # Arguments
+    /// * `guardian` - Guardian address, or None to remove the guardian
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_guardian(env: Env, guardian: Option<Address>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old_guardian = storage::get_guardian(&env);
+        storage::set_guardian(&env, &guardian);
+        audit::record(&env, &admin, "set_guardian", &old_guardian, &guardian);
+        events::emit_guardian_changed(&env, &admin, &guardian);
+
+        Ok(())
+    }
+
+    /// Get the guardian address (None if no guardian is set)
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        storage::get_guardian(&env)
+    }
+
+    /// Set or clear the arbiter and the dispute bond
+    ///
+    /// The arbiter settles results disputed within a game's dispute window
+    /// (see `set_game_dispute_window`). Disputes are disabled while no arbiter
+    /// is set.
+    ///
+    /// # Arguments
+    /// * `arbiter` - Arbiter address, or None to remove the arbiter
+    /// * `bond` - USDC a player posts to dispute a result (0 = no bond)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If bond is negative
+    pub fn set_arbiter(env: Env, arbiter: Option<Address>, bond: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if bond < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut config = storage::get_config(&env);
+        let old_arbiter = (storage::get_arbiter(&env), config.dispute_bond);
+        config.dispute_bond = bond;
+        storage::set_config(&env, &config);
+
+        storage::set_arbiter(&env, &arbiter);
+        audit::record(
+            &env,
+            &admin,
+            "set_arbiter",
+            &old_arbiter,
+            &(arbiter.clone(), bond),
+        );
+        events::emit_arbiter_changed(&env, &admin, &arbiter);
+
+        Ok(())
+    }
+
+    /// Get the arbiter address (None if no arbiter is set)
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        storage::get_arbiter(&env)
+    }
+
+    /// Pause the contract as the guardian
+    ///
+    /// Same effect as `pause`. Only the admin can unpause.
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If no guardian is set
+    pub fn guardian_pause(env: Env) -> Result<(), Error> {
+        let guardian = storage::get_guardian(&env).ok_or(Error::FeatureNotConfigured)?;
+        guardian.require_auth();
+
+        let was_paused = storage::is_paused(&env);
+        storage::set_pause_state(&env, true);
+        audit::record(&env, &guardian, "guardian_pause", &was_paused, &true);
+        events::emit_contract_paused(&env, &guardian);
+
+        Ok(())
+    }
+
+    /// Check if contract is paused
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    // ========================================================================
+    // Game Registry
+    // ========================================================================
+
+    /// Add or update a game contract registration
+    ///
+    /// Registers a game contract with a developer address for reward distribution.
+    /// Can be called multiple times to update the developer address. Also
+    /// approves a pending registration, without calling the game's init hook.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract to register
+    /// * `developer` - Address to receive developer rewards for this game
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn add_game(env: Env, game_id: Address, developer: Address) -> Result<(), Error> {
+        game::add_game(&env, &game_id, &developer)
+    }
+
+    /// Request registration as a game (called by the game contract itself)
+    ///
+    /// The game must implement the standard Blendizzard game interface
+    /// (`blendizzard_init` and `game_metadata`). It can't be played until the
+    /// admin approves it with `approve_game` (or rejects it with `remove_game`).
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract (must authorize)
+    /// * `metadata` - Game name and developer address for rewards
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `InvalidConfig` - If the game is already approved, or its name is
+    ///   empty or longer than 32 bytes
+    pub fn register_game(
+        env: Env,
+        game_id: Address,
+        metadata: types::GameMetadata,
+    ) -> Result<(), Error> {
+        storage::require_not_paused(&env)?;
+        game::register_game(&env, &game_id, &metadata)
+    }
+
+    /// Approve a pending game registration and call the game's init hook
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotRegistered` - If the game has no pending registration
+    pub fn approve_game(env: Env, game_id: Address) -> Result<(), Error> {
+        game::approve_game(&env, &game_id)
+    }
+
+    /// Remove a game contract from the approved list
+    ///
+    /// Also rejects a pending registration.
+    ///
+    /// Note: If the game has contributions in the current epoch, those will be
+    /// forfeited (developer cannot claim rewards for removed games).
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn remove_game(env: Env, game_id: Address) -> Result<(), Error> {
+        game::remove_game(&env, &game_id)
+    }
+
+    /// Check if a contract is an approved game
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        game::is_game(&env, &game_id)
+    }
+
+    /// Set the per-player FP wager bounds for a registered game
+    ///
+    /// Enforced on every session start (single games, series and team games).
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `min_wager` - Smallest wager per player (0 = no minimum)
+    /// * `max_wager` - Largest wager per player (0 = no maximum)
+    ///
+    /// # Errors
+    /// * `GameNotWhitelisted` - If the game is not registered
+    /// * `InvalidConfig` - If a bound is negative or max_wager is below min_wager
+    pub fn set_game_wager_limits(
+        env: Env,
+        game_id: Address,
+        min_wager: i128,
+        max_wager: i128,
+    ) -> Result<(), Error> {
+        game::set_wager_limits(&env, &game_id, min_wager, max_wager)
+    }
+
+    /// Set the rake (house cut) taken from a registered game's wagers
+    ///
+    /// `rake_bps` of both players' wagers is raked at settlement, and the
+    /// winner only contributes the rest of theirs to their faction. When the
+    /// epoch cycles, every faction's raked share of the player reward pool goes
+    /// to `rake_route`. Shown in `get_game_info` and `get_games`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `rake_bps` - House cut of each wager, in basis points (0 = none)
+    /// * `rake_route` - Next reward pool, the raked player's faction treasury, burn, or the protocol treasury
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotWhitelisted` - If the game is not registered
+    /// * `InvalidConfig` - If rake_bps is above 10_000
+    pub fn set_game_rake(
+        env: Env,
+        game_id: Address,
+        rake_bps: u32,
+        rake_route: types::RakeRoute,
+    ) -> Result<(), Error> {
+        game::set_rake(&env, &game_id, rake_bps, rake_route)
+    }
+
+    /// Set the session limits for a registered game
+    ///
+    /// Caps the game's sessions (1v1, series and team) active at once and
+    /// started per ledger, so a misbehaving game contract can't flood storage
+    /// or pile up sessions within one ledger. Shown in `get_game_info`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `max_active_sessions` - Most sessions active at once (0 = no limit);
+    ///   counts sessions started since a limit was set
+    /// * `max_sessions_per_ledger` - Most sessions started per ledger (0 = no limit)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotWhitelisted` - If the game is not registered
+    pub fn set_game_session_limits(
+        env: Env,
+        game_id: Address,
+        max_active_sessions: u32,
+        max_sessions_per_ledger: u32,
+    ) -> Result<(), Error> {
+        game::set_session_limits(&env, &game_id, max_active_sessions, max_sessions_per_ledger)
+    }
+
+    /// Set how a registered game's wins convert to faction points
+    ///
+    /// Applied centrally when the game's sessions settle, so a game can change
+    /// formulas without a redeploy: `Stake` (the winner's wager, the default),
+    /// `Flat(fp)` per win (at most the wager) or `Margin(min_bps)`, the wager
+    /// scaled by the margin of victory reported with `end_game_with_margin`.
+    /// Win odds, deposit locks, decay and rake then apply as usual. Shown in
+    /// `get_game_info`.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotWhitelisted` - If the game is not registered
+    /// * `InvalidConfig` - If a flat amount isn't positive or a margin floor
+    ///   is above 10_000 bps
+    pub fn set_game_point_formula(
+        env: Env,
+        game_id: Address,
+        point_formula: types::PointFormula,
+    ) -> Result<(), Error> {
+        game::set_point_formula(&env, &game_id, point_formula)
+    }
+
+    /// Allow or disallow practice sessions for a registered game
+    ///
+    /// A practice session is started like any other, with both wagers 0: no
+    /// faction points are locked, but factions are checked and locked and the
+    /// result counts towards stats, ratings, quests and achievements. The
+    /// winner contributes the flat `practice_fp` to their faction instead of
+    /// a wager. Shown in `get_game_info`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `practice_fp` - Faction points a practice win contributes (0 = none,
+    ///   at most 1 FP), or None to disallow practice sessions
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotWhitelisted` - If the game is not registered
+    /// * `InvalidConfig` - If practice_fp is negative or above 1 FP
+    pub fn set_game_practice(
+        env: Env,
+        game_id: Address,
+        practice_fp: Option<i128>,
+    ) -> Result<(), Error> {
+        game::set_practice(&env, &game_id, practice_fp)
+    }
+
+    /// Set how many ledgers a registered game's results stay disputable
+    ///
+    /// While the window is open, `end_game` results are provisional: either
+    /// player can `dispute` them, and undisputed results are settled with
+    /// `finalize_result` once the window has passed.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `dispute_ledgers` - Length of the dispute window (0 = settle immediately)
+    ///
+    /// # Errors
+    /// * `GameNotWhitelisted` - If the game is not registered
+    pub fn set_game_dispute_window(
+        env: Env,
+        game_id: Address,
+        dispute_ledgers: u32,
+    ) -> Result<(), Error> {
+        game::set_dispute_window(&env, &game_id, dispute_ledgers)
+    }
+
+    /// Get a registered game's developer, wager limits, dispute window and rake
+    ///
+    /// Also returns pending registrations (with `pending` set).
+    ///
+    /// # Errors
+    /// * `GameNotWhitelisted` - If the game is not registered or pending
+    pub fn get_game_info(env: Env, game_id: Address) -> Result<types::GameInfo, Error> {
+        storage::get_game_registration(&env, &game_id).ok_or(Error::GameNotWhitelisted)
+    }
+
+    /// Deprecate a registered game, or reinstate it
+    ///
+    /// A deprecated game can't start new sessions; in-flight sessions still
+    /// settle and its developer keeps claiming rewards. Shown in `get_games`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `deprecated` - Whether new sessions are refused
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `GameNotWhitelisted` - If the game is not registered
+    pub fn set_game_deprecated(env: Env, game_id: Address, deprecated: bool) -> Result<(), Error> {
+        game::set_deprecated(&env, &game_id, deprecated)
+    }
+
+    /// Get a page of the game registry, in registration order
+    ///
+    /// Lists each approved or pending game's developer, weight (share of all
+    /// FP wagered), rake, wager limits, status and lifetime session counts, so
+    /// a game browser can be built from chain state. `limit` is capped at 50.
+    /// Games registered before the registry list was kept appear once the
+    /// admin calls `add_game` for them again.
+    pub fn get_games(env: Env, offset: u32, limit: u32) -> Vec<types::GameListing> {
+        game::get_games(&env, offset, limit)
+    }
+
+    // ========================================================================
+    // Vault Operations (REMOVED - Players interact directly with fee-vault-v2)
+    // ========================================================================
+
+    // ARCHITECTURE CHANGE: deposit() and withdraw() have been removed.
+    // Players now interact directly with the fee-vault-v2 contract for deposits/withdrawals.
+    // Blendizzard queries vault balances on-demand at game start and performs
+    // cross-epoch withdrawal detection at that time.
+    //
+    // To deposit: Call fee-vault-v2.deposit() directly (or deposit_xlm below)
+    // To withdraw: Call fee-vault-v2.withdraw() directly
+    // (DirectPool backend: use deposit/withdraw below instead)
+    //
+    // The 50% withdrawal reset rule is enforced via cross-epoch balance comparison
+    // when players play their first game of a new epoch.
+
+    /// Deposit XLM, converting it to USDC via Soroswap before depositing into fee-vault
+    ///
+    /// The resulting vault position belongs to `user` exactly as if they had
+    /// deposited USDC into fee-vault-v2 directly.
+    ///
+    /// # Arguments
+    /// * `user` - Player depositing XLM
+    /// * `amount` - Amount of XLM to convert
+    /// * `min_usdc_out` - Minimum USDC to receive from the swap (slippage protection)
+    ///
+    /// # Returns
+    /// Amount of USDC deposited into fee-vault
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If paused
+    /// * `PoolHalted` - If the Blend pool is on ice or frozen
+    /// * `InvalidAmount` - If amount <= 0 or min_usdc_out < 0
+    /// * `FeatureNotConfigured` - If the XLM token address hasn't been set
+    /// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+    /// * `SlippageExceeded` - If the swap yields less than min_usdc_out
+    /// * `SwapError` - If the swap fails
+    /// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+    /// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+    pub fn deposit_xlm(
+        env: Env,
+        user: Address,
+        amount: i128,
+        min_usdc_out: i128,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let deposited = vault::deposit_xlm(&env, &user, amount, min_usdc_out)?;
+        invariants::check_player(&env, &user);
+        Ok(deposited)
+    }
+
+    /// Deposit USDC into the Blend pool (DirectPool backend only)
+    ///
+    /// # Arguments
+    /// * `user` - Player depositing USDC
+    /// * `amount` - Amount of USDC to deposit
+    ///
+    /// # Returns
+    /// Player's vault balance after the deposit
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If paused
+    /// * `PoolHalted` - If the Blend pool is on ice or frozen
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+    /// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+    /// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+    /// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+    pub fn deposit(env: Env, user: Address, amount: i128) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let balance = vault::deposit(&env, &user, amount)?;
+        invariants::check_player(&env, &user);
+        Ok(balance)
+    }
+
+    /// Deposit USDC into the Blend pool and lock the vault balance (DirectPool backend only)
+    ///
+    /// Same as `deposit`, then locks the user's whole vault balance for the
+    /// tier: `OneEpoch` until the current epoch closes (1.1x), `FourEpochs`
+    /// for it and the next three epochs (1.25x). While locked, withdrawals are
+    /// refused and the user's winning contributions are scaled by the tier's
+    /// multiplier, raising their share of the epoch's rewards. Locking again
+    /// only ever extends a running lock.
+    ///
+    /// # Arguments
+    /// * `user` - Player depositing USDC
+    /// * `amount` - Amount of USDC to deposit
+    /// * `tier` - Lock tier (`None` deposits without locking)
+    ///
+    /// # Returns
+    /// Player's vault balance after the deposit
+    ///
+    /// # Errors
+    /// Same as `deposit`
+    pub fn deposit_with_lock(
+        env: Env,
+        user: Address,
+        amount: i128,
+        tier: types::LockTier,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let balance = vault::deposit(&env, &user, amount)?;
+        lock::lock_deposit(&env, &user, tier)?;
+        invariants::check_player(&env, &user);
+        Ok(balance)
+    }
+
+    /// Get a player's running deposit lock (None once it has expired, or without DirectPool)
+    pub fn get_deposit_lock(env: Env, user: Address) -> Option<types::DepositLock> {
+        lock::active_lock(&env, &user)
+    }
+
+    /// Deposit USDC from a token allowance into the Blend pool (DirectPool backend only)
+    ///
+    /// For integrator contracts and smart wallets that can't authorize the
+    /// nested USDC transfer of `deposit`. `from` first calls `approve` on the
+    /// USDC token with this contract as spender; the deposit is then pulled
+    /// with `transfer_from` and credited to `user`.
+    ///
+    /// # Arguments
+    /// * `from` - Account whose allowance funds the deposit (authorizes the call)
+    /// * `user` - Player credited with the deposit
+    /// * `amount` - Amount of USDC to deposit
+    ///
+    /// # Returns
+    /// Player's vault balance after the deposit
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If paused
+    /// * `PoolHalted` - If the Blend pool is on ice or frozen
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+    /// * `InsufficientBalance` - If the allowance doesn't cover the amount
+    /// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+    /// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+    /// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+    pub fn deposit_from_allowance(
+        env: Env,
+        from: Address,
+        user: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let balance = vault::deposit_from_allowance(&env, &from, &user, amount)?;
+        invariants::check_player(&env, &user);
+        Ok(balance)
+    }
+
+    /// Withdraw USDC from the Blend pool (DirectPool backend only)
+    ///
+    /// Withdrawals stay available while paused so players can always exit.
+    ///
+    /// # Arguments
+    /// * `user` - Player withdrawing USDC
+    /// * `amount` - Amount of USDC to withdraw
+    ///
+    /// # Returns
+    /// Player's vault balance after the withdrawal
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+    /// * `InsufficientBalance` - If the player's balance doesn't cover the withdrawal
+    /// * `DepositLocked` - If the player's balance is locked (see `deposit_with_lock`)
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn withdraw(env: Env, user: Address, amount: i128) -> Result<i128, ContractError> {
+        let balance = reentrancy::non_reentrant(&env, || vault::withdraw(&env, &user, amount))?;
+        invariants::check_player(&env, &user);
+        Ok(balance)
+    }
+
+    /// Record the Blend pool's status, emitting `PoolStatusChanged` if it changed
+    ///
+    /// Callable by anyone (e.g. a keeper). While the pool is on ice or frozen
+    /// (status >= 2) deposits are refused, and game starts too with
+    /// `set_pool_halt_pauses_games`; withdrawals stay available.
+    ///
+    /// # Returns
+    /// The pool status (0-1 active, 2-3 on ice, 4-5 frozen, 6 setup)
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If the pool or its status can't be read
+    pub fn sync_pool_status(env: Env) -> Result<u32, Error> {
+        vault::sync_pool_status(&env)
+    }
+
+    /// Get the Blend pool status last recorded by `sync_pool_status`
+    pub fn get_pool_status(env: Env) -> Option<u32> {
+        storage::get_pool_status(&env)
+    }
+
+    /// Block or allow game starts while the Blend pool is on ice or frozen
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_pool_halt_pauses_games(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old = storage::get_pool_halt_pauses_games(&env);
+        storage::set_pool_halt_pauses_games(&env, enabled);
+        audit::record(&env, &admin, "set_pool_halt_pauses_games", &old, &enabled);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Check if game starts are blocked while the Blend pool is halted
+    pub fn get_pool_halt_pauses_games(env: Env) -> bool {
+        storage::get_pool_halt_pauses_games(&env)
+    }
+
+    /// Get the remaining deposit room under the deposit caps
+    ///
+    /// # Arguments
+    /// * `user` - User whose per-user room to report
+    ///
+    /// # Returns
+    /// Remaining room per cap (`None` if that cap is disabled)
+    pub fn get_capacity(env: Env, user: Address) -> Result<types::DepositCapacity, Error> {
+        vault::get_capacity(&env, &user)
+    }
+
+    // ========================================================================
+    // Faction Selection
+    // ========================================================================
+
+    /// Select a faction for the player
+    ///
+    /// Sets the player's persistent faction preference. Can be changed at ANY time.
+    /// If you haven't played a game this epoch, the new faction applies immediately.
+    /// If you've already played this epoch, the current epoch stays locked to your
+    /// old faction, and the new selection applies starting next epoch.
+    ///
+    /// # Arguments
+    /// * `faction` - Faction ID (0=WholeNoodle, 1=PointyStick, 2=SpecialRock)
+    ///
+    /// # Errors
+    /// * `InvalidFaction` - If faction ID is not 0, 1, or 2
+    /// * `FactionFull` - If the faction has outgrown the smallest one (see `set_faction_cap`)
+    /// * `NotWhitelisted` - If whitelist mode is on and the player isn't whitelisted
+    /// * `FactionAlreadyLocked` - If switching factions during the battle phase
+    pub fn select_faction(env: Env, player: Address, faction: u32) -> Result<(), ContractError> {
+        faction::select_faction(&env, &player, faction)
+    }
+
+    /// Deposit USDC and select a faction in one transaction
+    ///
+    /// Onboarding helper for wallets that want a single signature: deposits
+    /// into the vault backend (skipped when amount is 0) and selects the
+    /// faction atomically, emitting the same events as the separate calls.
+    ///
+    /// # Arguments
+    /// * `user` - New player
+    /// * `amount` - USDC to deposit (0 = free play, no deposit)
+    /// * `faction` - Faction ID (0=WholeNoodle, 1=PointyStick, 2=SpecialRock)
+    ///
+    /// # Returns
+    /// Player's vault balance after the deposit
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If paused
+    /// * `PoolHalted` - If the Blend pool is on ice or frozen
+    /// * `InvalidAmount` - If amount < 0
+    /// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+    /// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+    /// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+    /// * `InvalidFaction` - If faction ID is not 0, 1, or 2
+    /// * `FactionFull` - If the faction has outgrown the smallest one
+    /// * `FactionAlreadyLocked` - If switching factions during the battle phase
+    pub fn onboard(
+        env: Env,
+        user: Address,
+        amount: i128,
+        faction: u32,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let balance = onboarding::onboard(&env, &user, amount, faction)?;
+        invariants::check_player(&env, &user);
+        Ok(balance)
+    }
+
+    /// Set display metadata (name, symbol, icon hash) for a faction
+    ///
+    /// # Arguments
+    /// * `faction` - Faction ID
+    /// * `name` - Display name (1-32 bytes)
+    /// * `symbol` - Ticker-style symbol (1-12 bytes)
+    /// * `icon_hash` - Hash of the faction icon
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidFaction` - If faction ID is not valid
+    /// * `InvalidFactionMetadata` - If name or symbol is empty or too long
+    pub fn set_faction_info(
+        env: Env,
+        faction: u32,
+        name: String,
+        symbol: String,
+        icon_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        faction::set_faction_info(&env, faction, &name, &symbol, &icon_hash)
+    }
+
+    /// Get display metadata for all factions, ordered by ID
+    pub fn get_factions(env: Env) -> Vec<types::FactionInfo> {
+        faction::get_factions(&env)
+    }
+
+    // ========================================================================
+    // Player Queries
+    // ========================================================================
+
+    /// Get player information
+    ///
+    /// Returns complete persistent player data including selected faction, total deposited,
+    /// and deposit timestamp.
+    ///
+    /// # Errors
+    /// * `PlayerNotFound` - If player has never interacted with the contract
+    pub fn get_player(env: Env, player: Address) -> Result<types::Player, Error> {
+        storage::get_player(&env, &player).ok_or(Error::PlayerNotFound)
+    }
+
+    /// Get whether and why a player's faction is locked, with the unlock ledger
+    ///
+    /// Lets frontends explain a rejected `select_faction` (battle phase) or a
+    /// selection that only applies from the next epoch (sessions in progress,
+    /// already played this epoch).
+    pub fn get_faction_lock(env: Env, player: Address) -> types::FactionLock {
+        faction::get_faction_lock(&env, &player)
+    }
+
+    /// Get all of a user's packed persistent state (None for unknown users)
+    pub fn get_user_data(env: Env, user: Address) -> Option<types::UserData> {
+        storage::get_user_data(&env, &user)
+    }
+
+    /// Migrate a user from the legacy per-field storage layout
+    ///
+    /// Permissionless and idempotent. Users are also migrated automatically
+    /// on their first write, so this only saves a later transaction the cost.
+    ///
+    /// # Returns
+    /// true if legacy entries were migrated
+    pub fn migrate_user_data(env: Env, user: Address) -> bool {
+        storage::migrate_user_data(&env, &user)
+    }
+
+    /// Get player's epoch-specific information for any epoch
+    ///
+    /// Returns complete epoch-specific data including locked faction, available FP,
+    /// total FP contributed, and balance snapshot. Consistent with `get_epoch(epoch)`
+    /// which also requires an epoch parameter.
+    ///
+    /// **Behavior for current epoch:** If player hasn't played any games this epoch yet,
+    /// calculates what their FP WOULD be based on current vault balance without writing
+    /// to storage. This allows UIs to display FP before the player's first game.
+    ///
+    /// **Behavior for historical epochs:** Only returns data if player participated in
+    /// that epoch (played at least one game).
+    ///
+    /// # Arguments
+    /// * `epoch` - Epoch number to query
+    /// * `player` - Player address
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // Current epoch
+    /// let current = contract.get_current_epoch();
+    /// let player_data = contract.get_epoch_player(&current, &player)?;
+    ///
+    /// // Historical epoch
+    /// let epoch0_data = contract.get_epoch_player(&0, &player)?;
+    /// ```
+    ///
+    /// # Errors
+    /// * `FactionNotSelected` - If querying current epoch and player hasn't selected faction
+    /// * `PlayerNotFound` - If querying historical epoch and player didn't participate
+    pub fn get_epoch_player(
+        env: Env,
+        epoch: u32,
+        player: Address,
+    ) -> Result<types::EpochPlayer, Error> {
+        // Try to get existing epoch player data first
+        if let Some(epoch_player) = storage::get_epoch_player(&env, epoch, &player) {
+            return Ok(epoch_player);
+        }
+
+        // If querying current epoch and no data exists yet, compute it on-the-fly
+        if epoch == storage::get_current_epoch(&env) {
+            // Check if player has selected a faction
+            storage::get_player(&env, &player).ok_or(Error::FactionNotSelected)?;
+
+            // Calculate FP using same logic as initialize_player_epoch
+            let total_fp = faction_points::calculate_faction_points(&env, &player)?;
+            let current_balance = snapshot::fp_balance(&env, &storage::get_config(&env), &player);
+
+            // Return computed EpochPlayer (not saved to storage yet)
+            Ok(types::EpochPlayer {
+                epoch_faction: None, // Faction not locked until first game
+                epoch_balance_snapshot: current_balance,
+                available_fp: total_fp,
+                total_fp_contributed: 0,
+                streak_bonus: 0, // Snapshot at first game
+                games_completed: 0,
+                reward_eligible: activity::eligible_from_start(&env),
+            })
+        } else {
+            // For historical epochs, player must have participated
+            Err(Error::PlayerNotFound)
+        }
+    }
+
+    /// Record a player's current vault balance for start-of-epoch snapshots
+    ///
+    /// Permissionless. A deposit counts towards FP from the epoch after it was
+    /// first observed, so frontends should call this after a player deposits.
+    ///
+    /// # Returns
+    /// Balance the player's FP is based on in the current epoch
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If balance snapshots are disabled
+    pub fn checkpoint_balance(env: Env, player: Address) -> Result<i128, Error> {
+        let config = storage::get_config(&env);
+        if !config.balance_snapshots {
+            return Err(Error::FeatureNotConfigured);
+        }
+        snapshot::record(&env, &config, &player);
+        Ok(snapshot::fp_balance(&env, &config, &player))
+    }
+
+    /// Get a player's last balance checkpoint
+    pub fn get_balance_checkpoint(env: Env, player: Address) -> Option<types::BalanceCheckpoint> {
+        storage::get_balance_checkpoint(&env, &player)
+    }
+
+    // ========================================================================
+    // Game Lifecycle
+    // ========================================================================
 
     /// Start a new game session
     ///
-    /// Locks factions and fp for both players. If this is a player's first game
-    /// in the epoch, initializes their fp and locks their faction.
+    /// Locks factions and fp for both players. If this is a player's first game
+    /// in the epoch, initializes their fp and locks their faction.
+    ///
+    /// # Errors
+    /// * `GameNotWhitelisted` - If game_id is not approved
+    /// * `SessionAlreadyExists` - If session_id already exists
+    /// * `SelectionPhaseActive` - If the epoch is in its faction-selection phase
+    /// * `TooManyActiveSessions` - If either player is at the active session limit,
+    ///   or the game is at its session limits (see `set_game_session_limits`)
+    /// * `InvalidAmount` - If wagers are <= 0 (both 0 starts a practice
+    ///   session, if the game allows them, see `set_game_practice`)
+    /// * `PlayerNotFound` - If players don't exist
+    /// * `InsufficientFactionPoints` - If players don't have enough fp
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+    ) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        game::start_game(
+            &env,
+            &game_id,
+            session_id,
+            &player1,
+            &player2,
+            player1_wager,
+            player2_wager,
+        )?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Start a new game session with match details attached
+    ///
+    /// Same as `start_game`; `metadata` (opaque to Blendizzard, e.g. map seed
+    /// and mode) is stored with the session and emitted for frontends and
+    /// explorers.
+    ///
+    /// # Errors
+    /// * `InvalidConfig` - If metadata exceeds 256 bytes
+    /// * Same as `start_game`
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_game_with_metadata(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+        metadata: Bytes,
+    ) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        game::require_metadata_len(&metadata)?;
+        game::start_game(
+            &env,
+            &game_id,
+            session_id,
+            &player1,
+            &player2,
+            player1_wager,
+            player2_wager,
+        )?;
+        game::attach_metadata(&env, session_id, false, &metadata);
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Start a new game session with an on-chain derived session ID
+    ///
+    /// Same as `start_game`, but the session ID is derived from the game's
+    /// monotonically increasing nonce instead of being supplied by the caller.
+    /// Players authorize the derived ID (see `next_session_id`).
+    ///
+    /// # Returns
+    /// The derived session ID
+    ///
+    /// # Errors
+    /// Same as `start_game`
+    pub fn start_game_with_nonce(
+        env: Env,
+        game_id: Address,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+    ) -> Result<u32, ContractError> {
+        storage::require_not_paused(&env)?;
+        let session_id = game::start_game_with_nonce(
+            &env,
+            &game_id,
+            &player1,
+            &player2,
+            player1_wager,
+            player2_wager,
+        )?;
+        invariants::check_game(&env, session_id);
+        Ok(session_id)
+    }
+
+    /// Get the session ID the next `start_game_with_nonce` call for a game will use
+    pub fn next_session_id(env: Env, game_id: Address) -> Result<u32, Error> {
+        game::next_session_id(&env, &game_id)
+    }
+
+    /// Get a player's active sessions (started, not yet ended, in the current epoch)
+    pub fn get_active_sessions(env: Env, player: Address) -> Vec<u32> {
+        game::get_active_sessions(&env, &player)
+    }
+
+    /// Get a page of a game's in-flight 1v1 sessions in the current epoch
+    ///
+    /// Lets a game server recover its sessions (players, wagers, start ledger
+    /// and outcome state) without replaying events. `limit` is capped at 50.
+    pub fn get_sessions(
+        env: Env,
+        game_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<types::ActiveSession> {
+        game::get_sessions(&env, &game_id, offset, limit)
+    }
+
+    /// Get a game's session nonce (number of nonces consumed deriving session IDs)
+    pub fn get_game_nonce(env: Env, game_id: Address) -> u32 {
+        storage::get_game_nonce(&env, &game_id)
+    }
+
+    /// Draw a random integer in `min..=max` for a game session
+    ///
+    /// For coin flips, dice and matchmaking tiebreaks. Requires the session's
+    /// game contract authorization. Seeded from the host PRNG plus the session
+    /// ID, so the result can't be predicted by players but differs between
+    /// simulation and submission.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If min > max
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `SessionGameMismatch` - If the session belongs to another game
+    pub fn random_range(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        min: u64,
+        max: u64,
+    ) -> Result<u64, ContractError> {
+        random::random_range(&env, &game_id, session_id, min, max)
+    }
+
+    /// Shuffle values for a game session (same seeding and checks as `random_range`)
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `SessionGameMismatch` - If the session belongs to another game
+    pub fn shuffle(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        values: Vec<u32>,
+    ) -> Result<Vec<u32>, ContractError> {
+        random::shuffle(&env, &game_id, session_id, values)
+    }
+
+    /// End a game session with outcome verification
+    ///
+    /// Requires game contract authorization. Both players' FP wagers are spent/burned.
+    /// Only the winner's wager contributes to their faction standings.
+    ///
+    /// Outcome verification is handled by the individual game contracts.
+    /// Each game is responsible for implementing its own verification mechanism
+    /// (multi-sig oracle, ZK proofs, etc.) before calling this function.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique session identifier
+    /// * `player1_won` - true if player1 won, false if player2 won
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If session doesn't exist
+    /// * `SessionAlreadyFinalized` - If the session already has an outcome
+    /// * `InvalidSessionState` - If the session is a series (use `report_series_game`)
+    /// * `GameExpired` - If game is from a previous epoch
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), ContractError> {
+        reentrancy::non_reentrant(&env, || game::end_game(&env, session_id, player1_won, None))?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// End a game session, reporting the winner's margin of victory
+    ///
+    /// Same as `end_game`. Games using the `Margin` point formula (see
+    /// `set_game_point_formula`) scale the winner's contribution by
+    /// `margin_bps`; results reported with `end_game` count as a full margin.
+    /// Other formulas ignore it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique session identifier
+    /// * `player1_won` - true if player1 won, false if player2 won
+    /// * `margin_bps` - How decisive the win was, from 0 to 10_000 (a shutout)
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If margin_bps is above 10_000
+    /// * Same as `end_game`
+    pub fn end_game_with_margin(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+        margin_bps: u32,
+    ) -> Result<(), ContractError> {
+        reentrancy::non_reentrant(&env, || {
+            game::end_game(&env, session_id, player1_won, Some(margin_bps))
+        })?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// End a game session with match details attached to the result
+    ///
+    /// Same as `end_game`; `metadata` (opaque to Blendizzard, e.g. a replay
+    /// hash) is stored with the session and emitted for frontends and
+    /// explorers.
+    ///
+    /// # Errors
+    /// * `InvalidConfig` - If metadata exceeds 256 bytes
+    /// * Same as `end_game`
+    pub fn end_game_with_metadata(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+        metadata: Bytes,
+    ) -> Result<(), ContractError> {
+        game::require_metadata_len(&metadata)?;
+        reentrancy::non_reentrant(&env, || game::end_game(&env, session_id, player1_won, None))?;
+        game::attach_metadata(&env, session_id, true, &metadata);
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Get the match details a game attached to a session
+    pub fn get_session_metadata(env: Env, session_id: u32) -> Option<types::SessionMetadata> {
+        storage::get_session_metadata(&env, session_id)
+    }
+
+    /// Report that a player stopped playing, awarding the session to the opponent
+    ///
+    /// Requires game contract authorization. Settles like `end_game`, but only
+    /// once the session is at least `get_forfeit_delay` ledgers old.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique session identifier
+    /// * `afk_player` - Player who forfeits the session
+    ///
+    /// # Errors
+    /// * `PlayerNotFound` - If afk_player isn't in the session
+    /// * `InvalidSessionState` - If the session is a series or younger than the
+    ///   forfeit delay
+    /// * Same as `end_game`
+    pub fn forfeit(env: Env, session_id: u32, afk_player: Address) -> Result<(), ContractError> {
+        reentrancy::non_reentrant(&env, || game::forfeit(&env, session_id, &afk_player))?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Start a best-of-N series
+    ///
+    /// Locks both players' FP wagers once (same checks as `start_game`). Game
+    /// results are reported with `report_series_game`; the series settles like a
+    /// single game when one side wins a majority. `end_game` is rejected for
+    /// series sessions.
+    ///
+    /// # Arguments
+    /// * `best_of` - Series length (odd, 1 to 9)
+    ///
+    /// # Errors
+    /// * `InvalidSeriesLength` - If best_of is even, zero or above 9
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * Same as `start_game`
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_series(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+        best_of: u32,
+    ) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        series::start_series(
+            &env,
+            &game_id,
+            session_id,
+            &player1,
+            &player2,
+            player1_wager,
+            player2_wager,
+            best_of,
+        )?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Record the result of one game in a series
+    ///
+    /// Requires game contract authorization. Settles the series session once
+    /// either player has won a majority of games.
+    ///
+    /// # Returns
+    /// Updated series state
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If session doesn't exist
+    /// * `SeriesNotFound` - If the session is not a series
+    /// * `SessionAlreadyFinalized` - If the series was already decided
+    /// * `GameExpired` - If the series is from a previous epoch
+    pub fn report_series_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+    ) -> Result<types::GameSeries, Error> {
+        let series = series::report_series_game(&env, session_id, player1_won)?;
+        invariants::check_game(&env, session_id);
+        Ok(series)
+    }
+
+    /// Get the state of a series
+    ///
+    /// # Errors
+    /// * `SeriesNotFound` - If the session is not a series
+    pub fn get_series(env: Env, session_id: u32) -> Result<types::GameSeries, Error> {
+        storage::get_series(&env, session_id).ok_or(Error::SeriesNotFound)
+    }
+
+    /// Start a team game
+    ///
+    /// Each team plays for one faction: all members must be locked into the
+    /// same faction for the epoch. Every member wagers `wager` FP and must
+    /// authorize `(game_id, session_id, wager)`. Team sessions share the
+    /// session ID space and active-session cap with 1v1 games.
+    ///
+    /// # Arguments
+    /// * `team_a` - Team A members (2 to 4 players)
+    /// * `team_b` - Team B members (same size as team A)
+    /// * `wager` - Faction points wagered by each member
+    ///
+    /// # Errors
+    /// * `InvalidTeam` - If teams are uneven, too small or large, share a
+    ///   player, or mix factions
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * Same as `start_game`
+    pub fn start_team_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        team_a: Vec<Address>,
+        team_b: Vec<Address>,
+        wager: i128,
+    ) -> Result<(), Error> {
+        storage::require_not_paused(&env)?;
+        team::start_team_game(&env, &game_id, session_id, &team_a, &team_b, wager)
+    }
+
+    /// End a team game
+    ///
+    /// Requires game contract authorization. Each member of the winning team
+    /// contributes their own wager to the faction's standings.
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If the team session doesn't exist
+    /// * `SessionAlreadyFinalized` - If the session already has an outcome
+    /// * `GameExpired` - If the session is from a previous epoch
+    pub fn end_team_game(env: Env, session_id: u32, team_a_won: bool) -> Result<(), Error> {
+        team::end_team_game(&env, session_id, team_a_won)
+    }
+
+    /// Get a team game session
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If the team session doesn't exist
+    pub fn get_team_session(env: Env, session_id: u32) -> Result<types::TeamSession, Error> {
+        storage::get_team_session(&env, session_id).ok_or(Error::SessionNotFound)
+    }
+
+    /// Commit to a move for an on-chain resolved game
+    ///
+    /// `commitment` is `sha256(move as 4 big-endian bytes || salt)`. Once both
+    /// players of the session have committed, they have one hour to reveal
+    /// with `reveal_move`. The game contract resolves the revealed moves and
+    /// reports the outcome with `end_game`.
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If session doesn't exist
+    /// * `SessionAlreadyFinalized` - If the session already has an outcome
+    /// * `GameExpired` - If the session is from a previous epoch
+    /// * `PlayerNotFound` - If player is not in the session
+    /// * `SeriesNotSupported` - If the session is a series
+    /// * `MoveAlreadyCommitted` - If the player already committed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn commit_move(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        commit_reveal::commit_move(&env, session_id, &player, &commitment)
+    }
+
+    /// Reveal a committed move
+    ///
+    /// # Returns
+    /// Updated move state for the session
+    ///
+    /// # Errors
+    /// * `CommitmentMismatch` - If the move and salt don't match the commitment
+    /// * `RevealNotOpen` - If both players haven't committed
+    /// * `RevealWindowClosed` - If the reveal window has closed
+    /// * `MoveAlreadyRevealed` - If the player already revealed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * Same as `commit_move`
+    pub fn reveal_move(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        player_move: u32,
+        salt: BytesN<32>,
+    ) -> Result<types::MoveCommits, ContractError> {
+        storage::require_not_paused(&env)?;
+        commit_reveal::reveal_move(&env, session_id, &player, player_move, &salt)
+    }
+
+    /// Settle a session whose opponent didn't reveal in time
+    ///
+    /// Callable by anyone after the reveal window closes with exactly one move
+    /// revealed: the revealing player wins and the session settles like
+    /// `end_game`.
+    ///
+    /// # Returns
+    /// true if player1 won, false if player2 won
+    ///
+    /// # Errors
+    /// * `RevealNotOpen` - If both players haven't committed
+    /// * `RevealWindowOpen` - If the reveal window is still open
+    /// * `RevealTimeoutNotClaimable` - If not exactly one move was revealed
+    /// * Same as `commit_move`
+    pub fn claim_reveal_timeout(env: Env, session_id: u32) -> Result<bool, ContractError> {
+        let player1_won = commit_reveal::claim_reveal_timeout(&env, session_id)?;
+        invariants::check_game(&env, session_id);
+        Ok(player1_won)
+    }
+
+    /// Get the commit-reveal move state of a session
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If no move was committed for the session
+    pub fn get_moves(env: Env, session_id: u32) -> Result<types::MoveCommits, Error> {
+        storage::get_moves(&env, session_id).ok_or(Error::SessionNotFound)
+    }
+
+    /// Stake USDC on a pending session's winner (spectators only)
+    ///
+    /// When the session settles, correct predictors split the losing stakes
+    /// minus the prediction rake, which goes to the reward pool.
+    ///
+    /// # Arguments
+    /// * `predictor` - Spectator placing the prediction
+    /// * `session_id` - Session to predict
+    /// * `player1_wins` - Predicted winner (true = player1)
+    /// * `amount` - USDC to stake
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If predictions are disabled
+    /// * `InvalidAmount` - If amount <= 0 or the total stake exceeds the maximum
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `SessionAlreadyFinalized` - If the session was already settled
+    /// * `GameExpired` - If the session is from a previous epoch
+    /// * `PredictorIsPlayer` - If the predictor plays in the session
+    /// * `PredictionSideMismatch` - If the predictor switches side
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn predict(
+        env: Env,
+        predictor: Address,
+        session_id: u32,
+        player1_wins: bool,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        prediction::predict(&env, &predictor, session_id, player1_wins, amount)
+    }
+
+    /// Claim a prediction's winnings, or a refund if the session never settled
+    ///
+    /// # Returns
+    /// Amount of USDC transferred to the predictor
+    ///
+    /// # Errors
+    /// * `NoRewardsAvailable` - If there is no prediction or it was wrong
+    /// * `PredictionNotRefundable` - If the session is still pending
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_prediction(
+        env: Env,
+        predictor: Address,
+        session_id: u32,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        prediction::claim_prediction(&env, &predictor, session_id)
+    }
+
+    /// Get the spectator prediction totals for a session
+    pub fn get_prediction_pool(env: Env, session_id: u32) -> Option<types::PredictionPool> {
+        storage::get_prediction_pool(&env, session_id)
+    }
+
+    /// Get a spectator's unclaimed prediction on a session
+    pub fn get_prediction(
+        env: Env,
+        session_id: u32,
+        predictor: Address,
+    ) -> Option<types::Prediction> {
+        storage::get_prediction(&env, session_id, &predictor)
+    }
+
+    /// Dispute a provisional session result
+    ///
+    /// Posts `config.dispute_bond` USDC, which is slashed if the arbiter
+    /// confirms the reported outcome and refunded otherwise.
+    ///
+    /// # Arguments
+    /// * `player` - Disputing player (one of the session's players)
+    /// * `session_id` - Session with a provisional result
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If no arbiter is set
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `PlayerNotFound` - If the caller didn't play in the session
+    /// * `NoProvisionalResult` - If there is no result to dispute
+    /// * `ResultAlreadyDisputed` - If the result was already disputed
+    /// * `DisputeWindowClosed` - If the dispute window has passed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn dispute(env: Env, player: Address, session_id: u32) -> Result<(), ContractError> {
+        storage::require_not_paused(&env)?;
+        dispute::dispute(&env, &player, session_id)
+    }
+
+    /// Settle an undisputed provisional result after its dispute window
+    ///
+    /// Permissionless.
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `NoProvisionalResult` - If the session has no provisional result
+    /// * `ResultDisputed` - If the result is disputed
+    /// * `DisputeWindowOpen` - If the dispute window is still open
+    /// * `GameExpired` - If the session is from a previous epoch
+    pub fn finalize_result(env: Env, session_id: u32) -> Result<(), ContractError> {
+        dispute::finalize_result(&env, session_id)?;
+        invariants::check_game(&env, session_id);
+        Ok(())
+    }
+
+    /// Resolve a disputed result (arbiter only)
+    ///
+    /// # Arguments
+    /// * `session_id` - Disputed session
+    /// * `player1_won` - Arbiter's outcome (true = player1 won)
+    ///
+    /// # Returns
+    /// true if the disputer's bond was slashed (reported outcome confirmed)
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If no arbiter is set
+    /// * `SessionNotFound` - If the session doesn't exist
+    /// * `NoProvisionalResult` - If the session has no provisional result
+    /// * `ResultNotDisputed` - If the result isn't disputed
+    pub fn resolve_dispute(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+    ) -> Result<bool, ContractError> {
+        let slashed = dispute::resolve_dispute(&env, session_id, player1_won)?;
+        invariants::check_game(&env, session_id);
+        Ok(slashed)
+    }
+
+    /// Get a session's provisional result (None if it has none pending)
+    pub fn get_provisional_result(env: Env, session_id: u32) -> Option<types::ProvisionalResult> {
+        storage::get_provisional_result(&env, session_id)
+    }
+
+    /// Get a player's Elo rating for a game
+    ///
+    /// Ratings are tracked independently per game contract and updated in
+    /// `end_game`. Players who haven't finished a game start at 1200.
+    ///
+    /// # Arguments
+    /// * `player` - Player address
+    /// * `game_id` - Game contract address
+    pub fn get_rating(env: Env, player: Address, game_id: Address) -> u32 {
+        rating::get_rating(&env, &player, &game_id)
+    }
+
+    /// Get a player's lifetime stats for a game
+    ///
+    /// Wins, losses, total FP wagered and total FP wagered in won games, updated
+    /// in `end_game`. Zeroed if the player hasn't finished a game on `game_id`.
+    ///
+    /// # Arguments
+    /// * `player` - Player address
+    /// * `game_id` - Game contract address
+    pub fn get_player_stats(env: Env, player: Address, game_id: Address) -> types::PlayerGameStats {
+        stats::get_player_stats(&env, &player, &game_id)
+    }
+
+    /// Get a player's lifetime stats for every game they have played
+    ///
+    /// # Arguments
+    /// * `player` - Player address
+    ///
+    /// # Returns
+    /// Map of game contract address to stats
+    pub fn get_player_stats_all(env: Env, player: Address) -> Map<Address, types::PlayerGameStats> {
+        stats::get_player_stats_all(&env, &player)
+    }
+
+    /// Get protocol-wide stats for dashboards
+    ///
+    /// Lifetime totals of deposits made through this contract, FP wagered,
+    /// USDC and BLND rewards claimed, plus the vault backend's current TVL.
+    ///
+    /// # Errors
+    /// * `OverflowError` - If the TVL computation overflows
+    pub fn get_global_stats(env: Env) -> Result<types::GlobalStats, Error> {
+        stats::get_global_stats(&env)
+    }
+
+    /// Get a player's unlocked achievements and progress towards them
+    ///
+    /// Achievements are the first win, a win streak, a number of finished
+    /// sessions and being an epoch's MVP (top contributor of the winning faction).
+    pub fn get_achievements(env: Env, player: Address) -> types::Achievements {
+        storage::get_achievements(&env, &player)
+    }
+
+    // ========================================================================
+    // Epoch Management
+    // ========================================================================
+
+    /// Get the current epoch number
+    ///
+    /// # Returns
+    /// The current epoch number
+    pub fn get_current_epoch(env: Env) -> u32 {
+        storage::get_current_epoch(&env)
+    }
+
+    /// Get the last global event sequence number
+    ///
+    /// Every event carries its sequence number as a topic; indexers can
+    /// compare against this to detect gaps. Returns 0 before the first event.
+    pub fn get_event_seq(env: Env) -> u64 {
+        storage::get_event_seq(&env)
+    }
+
+    /// Get the last event sequence number for a user
+    ///
+    /// Counts events keyed by the user's address (faction selection, claims,
+    /// referrals, deposits). Returns 0 before the user's first event.
+    pub fn get_user_event_seq(env: Env, user: Address) -> u64 {
+        storage::get_user_event_seq(&env, &user)
+    }
+
+    /// Get the phase (faction selection or battle) of the current epoch
+    pub fn get_epoch_phase(env: Env) -> types::EpochPhase {
+        let config = storage::get_config(&env);
+        epoch::current_phase(&env, &config)
+    }
+
+    /// Get the current epoch's schedule
+    ///
+    /// Epoch number, start and end time, seconds remaining and whether a
+    /// public `cycle_epoch` call is possible now, in one call (for countdowns).
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If the current epoch doesn't exist
+    pub fn get_epoch_schedule(env: Env) -> Result<types::EpochSchedule, Error> {
+        epoch::get_epoch_schedule(&env)
+    }
+
+    /// Get the epoch clock: whether epoch times are timestamps or ledger sequences
+    pub fn get_epoch_clock(env: Env) -> types::EpochClock {
+        storage::get_epoch_clock(&env)
+    }
+
+    /// Get epoch information for a specific epoch
+    ///
+    /// # Arguments
+    /// * `epoch` - The epoch number to retrieve
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If requested epoch doesn't exist
+    pub fn get_epoch(env: Env, epoch: u32) -> Result<EpochInfo, Error> {
+        storage::get_epoch(&env, epoch).ok_or(Error::EpochNotFinalized)
+    }
+
+    /// Get live per-faction totals for an epoch
+    ///
+    /// Members, total stake, faction points and projected share of the player
+    /// reward pool for every faction, in one call (for live scoreboards).
+    ///
+    /// # Arguments
+    /// * `epoch_id` - The epoch number to report
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If requested epoch doesn't exist
+    pub fn get_faction_totals(env: Env, epoch_id: u32) -> Result<Vec<types::FactionTotals>, Error> {
+        faction::get_faction_totals(&env, epoch_id)
+    }
+
+    /// Get epoch information for a range of epochs
+    ///
+    /// Returns epochs `from..=to` in order, clamped to the current epoch and to
+    /// at most 50 epochs per call. Epochs no longer in storage are skipped.
+    ///
+    /// # Arguments
+    /// * `from` - First epoch number
+    /// * `to` - Last epoch number (inclusive)
+    pub fn get_epochs(env: Env, from: u32, to: u32) -> Vec<EpochInfo> {
+        epoch::get_epochs(&env, from, to)
+    }
+
+    /// Get realized yield of recently closed epochs and a trailing APY estimate
+    ///
+    /// Each epoch cycle snapshots the vault backend's b_rate and TVL; realized
+    /// yield is b_rate growth plus the epoch's reward pools relative to TVL.
+    /// Covers up to the last 12 closed epochs.
+    pub fn get_yield_stats(env: Env) -> types::YieldStats {
+        yield_stats::get_yield_stats(&env)
+    }
+
+    /// Claim and swap vault yield mid-epoch (admin only)
+    ///
+    /// Converts yield to USDC now and holds it in a pending-reward buffer that
+    /// the next `cycle_epoch` adds to the closed epoch's reward pool.
+    ///
+    /// # Returns
+    /// USDC added to the buffer (0 if the swap was skipped)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn harvest(env: Env) -> Result<i128, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        epoch::harvest(&env)
+    }
+
+    /// Get the USDC harvested since the last epoch cycle
+    pub fn get_pending_rewards(env: Env) -> i128 {
+        storage::get_pending_rewards(&env)
+    }
+
+    /// Donate USDC, BLND or a reward token to the current epoch's reward pools
+    ///
+    /// BLND is swapped to USDC right away. USDC is added to the pending
+    /// rewards that the next `cycle_epoch` folds into the epoch's reward pool;
+    /// reward tokens (see `set_reward_tokens`) become the epoch's pool of that
+    /// token. The sponsor is listed in `get_epoch_boosts`.
+    ///
+    /// # Arguments
+    /// * `sponsor` - Address funding the boost
+    /// * `token` - USDC, BLND or a whitelisted reward token
+    /// * `amount` - Amount of `token` to donate
+    ///
+    /// # Returns
+    /// Amount added to the pools (in USDC for USDC and BLND, else in `token`)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `InvalidAmount` - If amount <= 0 or the boost is below 1 USDC (or 1
+    ///   unit of a reward token)
+    /// * `InvalidConfig` - If the token is neither USDC, BLND nor a reward token
+    /// * `SlippageExceeded` - If the BLND swap price is far below the oracle price
+    /// * `SwapError` - If the BLND swap fails
+    /// * `BoostListFull` - If the epoch already lists 50 other sponsor entries
+    pub fn boost_epoch(
+        env: Env,
+        sponsor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        reentrancy::non_reentrant(&env, || boost::boost_epoch(&env, &sponsor, &token, amount))
+    }
+
+    /// Get the sponsors who boosted an epoch's reward pools, in order of
+    /// first boost
+    pub fn get_epoch_boosts(env: Env, epoch: u32) -> Vec<types::EpochBoost> {
+        storage::get_epoch_boosts(&env, epoch)
+    }
+
+    /// Replace the tokens other than USDC and BLND accepted as rewards
+    ///
+    /// Boosts in these tokens are paid out as per-epoch pools, split among the
+    /// winning faction in the same claim as USDC. Removing a token stops new
+    /// boosts; pools already funded are still paid out.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If there are more than 3 tokens, a token repeats or
+    ///   is USDC, BLND or the fee-vault
+    pub fn set_reward_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old = storage::get_reward_tokens(&env);
+        reward_tokens::set_reward_tokens(&env, &tokens)?;
+        audit::record(&env, &admin, "set_reward_tokens", &old, &tokens);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the tokens other than USDC and BLND accepted as rewards
+    pub fn get_reward_tokens(env: Env) -> Vec<Address> {
+        storage::get_reward_tokens(&env)
+    }
+
+    /// Get the reward tokens donated since the last epoch cycle
+    pub fn get_pending_reward_tokens(env: Env) -> Map<Address, i128> {
+        storage::get_pending_reward_tokens(&env)
+    }
+
+    /// Get every reward pool of an epoch: USDC, BLND (if any), then the other
+    /// reward tokens
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If the epoch doesn't exist
+    pub fn get_epoch_rewards(env: Env, epoch: u32) -> Result<Vec<types::TokenAmount>, Error> {
+        reward_tokens::get_epoch_rewards(&env, epoch)
+    }
+
+    /// Estimate the yield the next epoch cycle would collect (for "next pot" views)
+    ///
+    /// Reads the fee-vault admin balance and the pool's emission data, and
+    /// quotes the BLND swap on the configured router, without claiming
+    /// anything. Emissions are estimated from the pool's emission indexes, and
+    /// parts that can't be queried count as 0.
+    pub fn get_pending_yield(env: Env) -> types::PendingYield {
+        epoch::get_pending_yield(&env)
+    }
+
+    /// Quote the USDC a BLND → USDC reward swap would return right now
+    ///
+    /// Dry run on the configured Soroswap router (fees and price impact
+    /// included), for showing the "pot if cycled now" or deciding when to
+    /// cycle. A configured aggregator may fill better than this quote.
+    ///
+    /// # Arguments
+    /// * `blnd_amount` - BLND to sell
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If blnd_amount <= 0
+    /// * `SwapError` - If the router can't quote the pair
+    pub fn quote_reward_swap(env: Env, blnd_amount: i128) -> Result<i128, Error> {
+        let config = storage::get_config(&env);
+        swap::quote_exact_in(
+            &env,
+            &config.soroswap_router,
+            &config.blnd_token,
+            &config.usdc_token,
+            blnd_amount,
+        )
+    }
+
+    /// Cycle to the next epoch
+    ///
+    /// Finalizes current epoch (determines winner, withdraws BLND, swaps to USDC,
+    /// sets reward pool) and opens next epoch.
+    ///
+    /// BLND from skipped swaps rolls into the next cycle's swap. USDC from
+    /// `harvest` and unclaimed rewards of epochs past their claim window are
+    /// added to the reward pool.
+    ///
+    /// With the keeper registry enabled, public calls are rejected during the
+    /// keeper priority window (see `keeper_cycle_epoch`).
+    ///
+    /// # Returns
+    /// The new epoch number
+    ///
+    /// Same as `finalize_epoch` followed by `process_rewards` for the closed
+    /// epoch, in one call. If a closed epoch still awaits `process_rewards`,
+    /// only finishes its cycle from the last completed stage (claims and swaps
+    /// aren't repeated) and returns the epoch already open.
+    ///
+    /// # Errors
+    /// * `EpochNotReady` - If not enough time has passed
+    /// * `KeeperPriorityWindow` - If the keeper priority window is still open
+    /// * `EpochAlreadyFinalized` - If current epoch is already finalized
+    /// * `FeeVaultError` - If fee-vault operations fail
+    /// * `SwapError` - If BLND → USDC swap fails
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn cycle_epoch(env: Env) -> Result<u32, ContractError> {
+        let new_epoch = reentrancy::non_reentrant(&env, || epoch::cycle_epoch(&env, None))?;
+        invariants::check_epoch(&env, new_epoch - 1);
+        invariants::check_epoch(&env, new_epoch);
+        Ok(new_epoch)
+    }
+
+    /// Cycle to the next epoch as a bonded keeper, earning the keeper bounty
+    ///
+    /// Same as `cycle_epoch`, but allowed during the keeper priority window.
+    /// The keeper's bond stays locked until one full epoch after the new one.
+    ///
+    /// # Returns
+    /// The new epoch number
+    ///
+    /// # Errors
+    /// * `FeatureNotConfigured` - If the keeper registry is disabled
+    /// * `KeeperNotRegistered` - If the caller isn't a registered keeper
+    /// * `KeeperBondTooLow` - If the keeper's bond is below the minimum
+    /// * Same errors as `cycle_epoch`
+    pub fn keeper_cycle_epoch(env: Env, keeper: Address) -> Result<u32, ContractError> {
+        keeper.require_auth();
+
+        let new_epoch =
+            reentrancy::non_reentrant(&env, || epoch::cycle_epoch(&env, Some(&keeper)))?;
+        invariants::check_epoch(&env, new_epoch - 1);
+        invariants::check_epoch(&env, new_epoch);
+        Ok(new_epoch)
+    }
+
+    /// Close the current epoch and open the next one, without processing rewards
+    ///
+    /// First stage of `cycle_epoch`: freezes the closed epoch's standings and
+    /// opens the next epoch, so play continues even if reward processing has
+    /// to be retried. The closed epoch has no winner and nothing claimable
+    /// until `process_rewards` runs for it, and the next epoch can't be closed
+    /// before that. Retrying is safe: a second call fails without changes.
+    ///
+    /// # Returns
+    /// The new epoch number
+    ///
+    /// # Errors
+    /// * `EpochNotReady` - If not enough time has passed
+    /// * `KeeperPriorityWindow` - If the keeper priority window is still open
+    /// * `EpochAlreadyFinalized` - If current epoch is already finalized
+    /// * `EpochNotFinalized` - If a closed epoch still awaits `process_rewards`
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn finalize_epoch(env: Env) -> Result<u32, ContractError> {
+        let new_epoch = reentrancy::non_reentrant(&env, || epoch::finalize_epoch(&env, None))?;
+        invariants::check_epoch(&env, new_epoch - 1);
+        invariants::check_epoch(&env, new_epoch);
+        Ok(new_epoch)
+    }
+
+    /// Collect and distribute the rewards of an epoch closed by `finalize_epoch`
+    ///
+    /// Second stage of `cycle_epoch`: claims yield, swaps BLND to USDC, takes
+    /// the developer, rake, treasury and jackpot shares and finalizes the
+    /// epoch with its winner and reward pools. Calling it again for a
+    /// processed epoch changes nothing.
+    ///
+    /// # Arguments
+    /// * `epoch` - Epoch awaiting processing (see `get_unprocessed_epoch`)
+    ///
+    /// # Returns
+    /// The epoch's player reward pool (USDC)
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If the epoch wasn't closed by `finalize_epoch`
+    /// * `Reentrancy` - If re-entered from another fund-moving call
+    pub fn process_rewards(env: Env, epoch: u32) -> Result<i128, ContractError> {
+        let reward_pool =
+            reentrancy::non_reentrant(&env, || epoch::process_rewards(&env, epoch, None))?;
+        invariants::check_epoch(&env, epoch);
+        Ok(reward_pool)
+    }
+
+    /// Get the closed epoch awaiting `process_rewards`, if any
+    pub fn get_unprocessed_epoch(env: Env) -> Option<u32> {
+        storage::get_unprocessed_epoch(&env)
+    }
+
+    /// Run the next unfinished stage of the epoch cycle
+    ///
+    /// Stages: finalize (as `finalize_epoch`), claim the vault yield, swap BLND
+    /// to USDC, distribute. Each call commits one stage, for cycles too costly
+    /// to run in one transaction; `cycle_epoch` resumes from the same progress.
+    ///
+    /// # Returns
+    /// The stage this call ran (`Distribute` completes the cycle)
+    ///
+    /// # Errors
+    /// * Same errors as `finalize_epoch` (finalize stage) and `process_rewards`
+    pub fn cycle_epoch_step(env: Env) -> Result<types::CycleStage, ContractError> {
+        let stage = reentrancy::non_reentrant(&env, || epoch::cycle_epoch_step(&env))?;
+        invariants::check_epoch(&env, storage::get_current_epoch(&env));
+        Ok(stage)
+    }
+
+    /// Get the cycle stages done for an epoch awaiting `process_rewards`
+    ///
+    /// All stages read as not done once the epoch's rewards are distributed.
+    pub fn get_cycle_progress(env: Env, epoch: u32) -> types::CycleProgress {
+        storage::get_cycle_progress(&env, epoch)
+    }
+
+    /// Abort the current epoch in an emergency
+    ///
+    /// For when the vault backend or Soroswap is compromised mid-epoch: ends
+    /// the epoch now with no winner and no rewards (marked `aborted`), carries
+    /// the USDC harvested into the pending pool over to the next epoch, cancels
+    /// the epoch's sessions (wagers are epoch FP, so nothing is refunded),
+    /// opens the next epoch and lifts the pause so players can withdraw.
+    /// Requires both the admin and the guardian.
+    ///
+    /// # Returns
+    /// The new epoch number
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `FeatureNotConfigured` - If no guardian is set
+    /// * `EpochNotFinalized` - If a closed epoch still awaits `process_rewards`
+    /// * `EpochAlreadyFinalized` - If current epoch is already finalized
+    pub fn emergency_abort_epoch(env: Env) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+        let guardian = storage::get_guardian(&env).ok_or(Error::FeatureNotConfigured)?;
+        guardian.require_auth();
+
+        let new_epoch = epoch::abort_epoch(&env)?;
+        invariants::check_epoch(&env, new_epoch - 1);
+        invariants::check_epoch(&env, new_epoch);
+        invariants::check_balances(&env);
+        audit::record(
+            &env,
+            &admin,
+            "emergency_abort_epoch",
+            &(new_epoch - 1),
+            &new_epoch,
+        );
+
+        // Players must be able to exit and claim refunds
+        if storage::is_paused(&env) {
+            storage::set_pause_state(&env, false);
+            events::emit_contract_unpaused(&env, &admin);
+        }
+
+        Ok(new_epoch)
+    }
+
+    /// Get the BLND held over from skipped swaps (swapped in the next cycle)
+    pub fn get_carryover_blnd(env: Env) -> i128 {
+        storage::get_carryover_blnd(&env)
+    }
+
+    /// Get reward payout tracking for an epoch (total claimed, expiry status)
+    pub fn get_epoch_payouts(env: Env, epoch: u32) -> types::EpochPayouts {
+        storage::get_epoch_payouts(&env, epoch)
+    }
+
+    // ========================================================================
+    // Reward Claims
+    // ========================================================================
+
+    /// Claim epoch reward for a player for a specific epoch
+    ///
+    /// Players who contributed FP to the winning faction can claim their share
+    /// of the epoch's reward pool (USDC converted from BLND yield).
+    ///
+    /// **Note:** To check claimable amounts or claim status before calling,
+    /// use transaction simulation. This is the idiomatic Soroban pattern.
+    ///
+    /// # Returns
+    /// Amount of USDC claimed
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+    /// * `NotWinningFaction` - If player wasn't in the winning faction
+    /// * `NoRewardsAvailable` - If player has no rewards to claim
+    /// * `MerkleDistributionActive` - If the epoch is settled via Merkle root
+    /// * `ClaimWindowExpired` - If the epoch's claim window has passed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_epoch_reward(env: Env, player: Address, epoch: u32) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        let amount = rewards::claim_epoch_reward(&env, &player, epoch)?;
+        invariants::check_epoch_player(&env, epoch, &player);
+        invariants::check_player(&env, &player);
+        Ok(amount)
+    }
+
+    /// Claim a player's rewards from all their unclaimed epochs
+    ///
+    /// Scans the finalized epochs after the ones previous `claim_all` calls
+    /// covered (and still within the claim window), at most `max_epochs` per
+    /// call (capped at 20), paying each reward as `claim_epoch_reward` does.
+    /// Epochs with nothing to claim are skipped.
+    ///
+    /// # Returns
+    /// (USDC paid, last epoch scanned). If the last epoch scanned is older
+    /// than the latest finalized epoch the scan was truncated: call again to
+    /// continue. None means there was nothing left to scan.
+    ///
+    /// # Errors
+    /// * `DepositRequiredToClaim` - If the player's vault balance is below the minimum
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * Any other error of `claim_epoch_reward` for a scanned epoch
+    pub fn claim_all(
+        env: Env,
+        player: Address,
+        max_epochs: u32,
+    ) -> Result<(i128, Option<u32>), Error> {
+        storage::require_not_paused(&env)?;
+        let claimed = rewards::claim_all(&env, &player, max_epochs)?;
+        invariants::check_player(&env, &player);
+        Ok(claimed)
+    }
+
+    /// Claim a player's epoch reward as their approved claimer
+    ///
+    /// For smart-wallet recovery services or guild managers. The reward is
+    /// always paid to the player: with the FeeVault backend it is transferred
+    /// as USDC (not deposited, which would need the player's signature).
+    ///
+    /// # Returns
+    /// Amount of USDC claimed
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `NotApprovedClaimer` - If `claimer` isn't the player's approved claimer
+    /// * Same errors as `claim_epoch_reward`
+    pub fn claim_epoch_reward_for(
+        env: Env,
+        claimer: Address,
+        player: Address,
+        epoch: u32,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        let amount = rewards::claim_epoch_reward_for(&env, &claimer, &player, epoch)?;
+        invariants::check_epoch_player(&env, epoch, &player);
+        invariants::check_player(&env, &player);
+        Ok(amount)
+    }
+
+    /// Approve an address to claim epoch rewards on the user's behalf
+    ///
+    /// Replaces any previously approved claimer.
+    pub fn approve_claimer(env: Env, user: Address, claimer: Address) {
+        rewards::approve_claimer(&env, &user, &claimer)
+    }
+
+    /// Revoke the user's approved claimer
+    pub fn revoke_claimer(env: Env, user: Address) {
+        rewards::revoke_claimer(&env, &user)
+    }
+
+    /// Get the user's approved claimer
+    pub fn get_claimer(env: Env, user: Address) -> Option<Address> {
+        storage::get_claimer(&env, &user)
+    }
+
+    /// Get a page of the user's reward payouts, newest first
+    ///
+    /// Covers epoch reward, Merkle and vested claims (USDC and BLND). Only the
+    /// last 32 payouts are kept.
+    ///
+    /// # Arguments
+    /// * `user` - User to look up
+    /// * `offset` - Number of most recent records to skip
+    /// * `limit` - Maximum records to return (at most 32)
+    pub fn get_claim_history(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<types::ClaimRecord> {
+        rewards::get_claim_history(&env, &user, offset, limit)
+    }
+
+    /// Post the Merkle root for a finalized epoch (Merkle-drop distribution)
+    ///
+    /// For epochs with too many participants to settle per-player on-chain, the admin
+    /// computes payouts off-chain and posts a single root. Once set, players claim with
+    /// `claim_merkle_reward` and `claim_epoch_reward` is disabled for that epoch.
+    ///
+    /// Leaf encoding: `sha256(xdr(player) || amount.to_be_bytes())`, with sorted-pair
+    /// hashing for internal nodes.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `MerkleRootAlreadySet` - If a root was already posted for this epoch
+    pub fn set_merkle_root(env: Env, epoch: u32, root: BytesN<32>) -> Result<(), Error> {
+        merkle::set_merkle_root(&env, epoch, &root)
+    }
+
+    /// Claim a player's reward from a Merkle-distributed epoch
+    ///
+    /// Verifies the (player, amount) leaf against the epoch's root and deposits the
+    /// reward into the fee-vault on behalf of the player.
+    ///
+    /// # Returns
+    /// Amount of USDC claimed
+    ///
+    /// # Errors
+    /// * `DepositRequiredToClaim` - If player's vault balance is below minimum threshold
+    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+    /// * `NoRewardsAvailable` - If no root is posted or the pool would be exceeded
+    /// * `InvalidMerkleProof` - If the proof doesn't verify
+    /// * `ClaimWindowExpired` - If the epoch's claim window has passed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_merkle_reward(
+        env: Env,
+        player: Address,
+        epoch: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        let claimed = merkle::claim_merkle_reward(&env, &player, epoch, amount, &proof)?;
+        invariants::check_player(&env, &player);
+        Ok(claimed)
+    }
+
+    /// Claim developer reward for a specific epoch
+    ///
+    /// Developers claim their aggregated share of the epoch's dev reward pool
+    /// proportional to total FP contributed through all their registered games.
+    ///
+    /// **Note:** To check claimable amounts or claim status before calling,
+    /// use transaction simulation. This is the idiomatic Soroban pattern.
+    ///
+    /// # Arguments
+    /// * `developer` - Developer address claiming rewards
+    /// * `epoch` - Epoch number to claim from
+    ///
+    /// # Returns
+    /// Amount of USDC claimed and transferred to developer
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `DevRewardAlreadyClaimed` - If already claimed for this epoch
+    /// * `GameNoContributions` - If developer has no contributions this epoch
+    /// * `ClaimWindowExpired` - If the epoch's claim window has passed
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_dev_reward(env: Env, developer: Address, epoch: u32) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        let amount = rewards::claim_dev_reward(&env, &developer, epoch)?;
+        invariants::check_balances(&env);
+        Ok(amount)
+    }
+
+    // ========================================================================
+    // Reward Vesting
+    // ========================================================================
+
+    /// Claim vested rewards released so far
+    ///
+    /// Reward claims above the vesting threshold stream linearly over
+    /// `vesting_ledgers`; this deposits the released part into the fee-vault.
+    ///
+    /// # Returns
+    /// Amount of USDC deposited into fee-vault
     ///
     /// # Errors
-    /// * `GameNotWhitelisted` - If game_id is not approved
-    /// * `SessionAlreadyExists` - If session_id already exists
-    /// * `InvalidAmount` - If wagers are <= 0
-    /// * `PlayerNotFound` - If players don't exist
-    /// * `InsufficientFactionPoints` - If players don't have enough fp
+    /// * `NoRewardsAvailable` - If nothing has been released yet
     /// * `ContractPaused` - If contract is in emergency pause mode
-    pub fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_wager: i128,
-        player2_wager: i128,
-    ) -> Result<(), Error> {
+    pub fn claim_vested(env: Env, player: Address) -> Result<i128, Error> {
         storage::require_not_paused(&env)?;
-        game::start_game(
-            &env,
-            &game_id,
-            session_id,
-            &player1,
-            &player2,
-            player1_wager,
-            player2_wager,
-        )
+        let amount = vesting::claim_vested(&env, &player)?;
+        invariants::check_player(&env, &player);
+        Ok(amount)
     }
 
-    /// End a game session with outcome verification
+    /// Get a player's vesting schedule (None if nothing is vesting)
+    pub fn get_vesting(env: Env, player: Address) -> Option<types::VestingSchedule> {
+        storage::get_vesting(&env, &player)
+    }
+
+    /// Get the vested rewards a player can claim right now
+    pub fn get_claimable_vested(env: Env, player: Address) -> Result<i128, Error> {
+        vesting::claimable_vested(&env, &player)
+    }
+
+    // ========================================================================
+    // Referrals
+    // ========================================================================
+
+    /// Set the player's referrer (one-time)
     ///
-    /// Requires game contract authorization. Both players' FP wagers are spent/burned.
-    /// Only the winner's wager contributes to their faction standings.
+    /// From then on, the configured referral share of every reward the player
+    /// claims is credited to the referrer's referral balance.
     ///
-    /// Outcome verification is handled by the individual game contracts.
-    /// Each game is responsible for implementing its own verification mechanism
-    /// (multi-sig oracle, ZK proofs, etc.) before calling this function.
+    /// # Errors
+    /// * `InvalidReferrer` - If the player refers themselves
+    /// * `ReferrerAlreadySet` - If the player already has a referrer
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn set_referrer(env: Env, player: Address, referrer: Address) -> Result<(), Error> {
+        storage::require_not_paused(&env)?;
+        referral::set_referrer(&env, &player, &referrer)
+    }
+
+    /// Get the player's referrer (None if not referred)
+    pub fn get_referrer(env: Env, player: Address) -> Option<Address> {
+        storage::get_referrer(&env, &player)
+    }
+
+    /// Get a referrer's unclaimed referral rewards
+    pub fn get_referral_balance(env: Env, referrer: Address) -> i128 {
+        storage::get_referral_balance(&env, &referrer)
+    }
+
+    /// Claim all accrued referral rewards
     ///
-    /// # Arguments
-    /// * `session_id` - The unique session identifier
-    /// * `player1_won` - true if player1 won, false if player2 won
+    /// Deposits the referrer's balance into the fee-vault on their behalf.
+    ///
+    /// # Returns
+    /// Amount of USDC claimed
     ///
     /// # Errors
-    /// * `SessionNotFound` - If session doesn't exist
-    /// * `InvalidSessionState` - If session is not Pending
-    /// * `GameExpired` - If game is from a previous epoch
-    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        game::end_game(&env, session_id, player1_won)
+    /// * `NoRewardsAvailable` - If the referrer has no accrued rewards
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_referral_rewards(env: Env, referrer: Address) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        let amount = referral::claim_referral_rewards(&env, &referrer)?;
+        invariants::check_player(&env, &referrer);
+        Ok(amount)
     }
 
     // ========================================================================
-    // Epoch Management
+    // Faction Treasuries
     // ========================================================================
 
-    /// Get the current epoch number
+    /// Get the USDC held in a faction's treasury
+    pub fn get_faction_treasury(env: Env, faction: u32) -> i128 {
+        storage::get_faction_treasuries(&env)
+            .balances
+            .get(faction)
+            .unwrap_or(0)
+    }
+
+    /// Propose spending from the proposer's faction treasury
+    ///
+    /// Members of the faction vote on it for the rest of the current epoch.
+    ///
+    /// # Arguments
+    /// * `proposer` - Player locked into the faction this epoch, with a balance snapshot
+    /// * `spend` - Bounty recipient, or a boost of the next reward pool
+    /// * `amount` - USDC to spend
     ///
     /// # Returns
-    /// The current epoch number
-    pub fn get_current_epoch(env: Env) -> u32 {
-        storage::get_current_epoch(&env)
+    /// The new proposal ID
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `PlayerNotFound` - If the proposer hasn't played this epoch
+    /// * `DepositRequiredToClaim` - If the proposer has no stake this epoch
+    /// * `InsufficientTreasury` - If the treasury holds less than amount
+    pub fn propose_treasury_spend(
+        env: Env,
+        proposer: Address,
+        spend: types::TreasurySpend,
+        amount: i128,
+    ) -> Result<u32, ContractError> {
+        storage::require_not_paused(&env)?;
+        treasury::propose(&env, &proposer, spend, amount)
     }
 
-    /// Get epoch information for a specific epoch
+    /// Vote on a treasury proposal, weighted by the voter's epoch balance snapshot
+    ///
+    /// # Returns
+    /// The voter's weight
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `ProposalNotFound` - If the proposal doesn't exist
+    /// * `ProposalVotingClosed` - If the proposal's voting epoch has ended
+    /// * `NotFactionMember` - If the voter is in another faction
+    /// * `ProposalAlreadyVoted` - If the voter already voted
+    /// * `DepositRequiredToClaim` - If the voter has no stake this epoch
+    pub fn vote_treasury_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        support: bool,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        treasury::vote(&env, &voter, proposal_id, support)
+    }
+
+    /// Execute a treasury proposal after its voting epoch has ended (permissionless)
+    ///
+    /// # Returns
+    /// true if the proposal passed and was paid
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `ProposalNotFound` - If the proposal doesn't exist
+    /// * `ProposalVotingOpen` - If the proposal's voting epoch hasn't ended
+    /// * `InsufficientTreasury` - If the treasury no longer covers the proposal
+    pub fn execute_treasury_proposal(env: Env, proposal_id: u32) -> Result<bool, ContractError> {
+        storage::require_not_paused(&env)?;
+        treasury::execute(&env, proposal_id)
+    }
+
+    /// Get a pending treasury proposal
+    pub fn get_treasury_proposal(env: Env, proposal_id: u32) -> Option<types::TreasuryProposal> {
+        storage::get_treasury_proposal(&env, proposal_id)
+    }
+
+    // ========================================================================
+    // Parameter Governance
+    // ========================================================================
+
+    /// Set the values players can vote for on a next-epoch parameter
+    ///
+    /// Each epoch, staked players vote for one of the values; the value with
+    /// the most stake applies when the epoch cycles (see `vote_parameter`).
+    /// An empty list stops governing the parameter.
     ///
     /// # Arguments
-    /// * `epoch` - The epoch number to retrieve
+    /// * `parameter` - Developer share, underdog bonus or epoch duration
+    /// * `options` - Up to 5 values, in the parameter's units (7 decimals for
+    ///   shares and bonuses, seconds for the epoch duration)
     ///
     /// # Errors
-    /// * `EpochNotFinalized` - If requested epoch doesn't exist
-    pub fn get_epoch(env: Env, epoch: u32) -> Result<EpochInfo, Error> {
-        storage::get_epoch(&env, epoch).ok_or(Error::EpochNotFinalized)
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If there are too many values, a value repeats or is
+    ///   outside the range its admin setter accepts
+    pub fn set_governance_options(
+        env: Env,
+        parameter: types::GovernanceParameter,
+        options: Vec<i128>,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old_options = storage::get_governance_options(&env, parameter);
+        governance::set_options(&env, parameter, &options)?;
+
+        audit::record(
+            &env,
+            &admin,
+            "set_governance_options",
+            &(parameter, old_options),
+            &(parameter, options),
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
     }
 
-    /// Cycle to the next epoch
+    /// Get the values players can vote for on a parameter
+    pub fn get_governance_options(env: Env, parameter: types::GovernanceParameter) -> Vec<i128> {
+        storage::get_governance_options(&env, parameter)
+    }
+
+    /// Vote for the next epoch's value of a parameter
     ///
-    /// Finalizes current epoch (determines winner, withdraws BLND, swaps to USDC,
-    /// sets reward pool) and opens next epoch.
+    /// One vote per parameter and epoch, weighted by the voter's epoch balance
+    /// snapshot. Votes tally when the epoch cycles: the developer share applies
+    /// from the next epoch's rewards, the underdog bonus and epoch duration to
+    /// the next epoch (an admin-scheduled duration takes precedence).
     ///
     /// # Returns
-    /// The new epoch number
+    /// The voter's weight
     ///
     /// # Errors
-    /// * `EpochNotReady` - If not enough time has passed
-    /// * `EpochAlreadyFinalized` - If current epoch is already finalized
-    /// * `FeeVaultError` - If fee-vault operations fail
-    /// * `SwapError` - If BLND → USDC swap fails
-    pub fn cycle_epoch(env: Env) -> Result<u32, Error> {
-        epoch::cycle_epoch(&env)
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    /// * `ParameterOptionNotOffered` - If the value isn't one of the parameter's options
+    /// * `ParameterAlreadyVoted` - If the voter already voted on the parameter this epoch
+    /// * `PlayerNotFound` - If the voter hasn't played this epoch
+    /// * `DepositRequiredToClaim` - If the voter has no stake this epoch
+    pub fn vote_parameter(
+        env: Env,
+        voter: Address,
+        parameter: types::GovernanceParameter,
+        value: i128,
+    ) -> Result<i128, ContractError> {
+        storage::require_not_paused(&env)?;
+        governance::vote(&env, &voter, parameter, value)
+    }
+
+    /// Get the stake voted for each value of a parameter in an epoch
+    pub fn get_parameter_votes(
+        env: Env,
+        parameter: types::GovernanceParameter,
+        epoch: u32,
+    ) -> Map<i128, i128> {
+        storage::get_parameter_votes(&env, parameter, epoch)
     }
 
     // ========================================================================
-    // Reward Claims
+    // Quests
     // ========================================================================
 
-    /// Claim epoch reward for a player for a specific epoch
+    /// Set an epoch's quests
     ///
-    /// Players who contributed FP to the winning faction can claim their share
-    /// of the epoch's reward pool (USDC converted from BLND yield).
+    /// Quests count sessions played or won (with a minimum wager) as 1v1
+    /// sessions settle; completing one adds its bonus to the player's
+    /// available FP for the epoch. Quests can be set for the current epoch
+    /// until some are set, and for any later epoch.
     ///
-    /// **Note:** To check claimable amounts or claim status before calling,
-    /// use transaction simulation. This is the idiomatic Soroban pattern.
+    /// # Arguments
+    /// * `epoch` - Epoch the quests run in
+    /// * `quests` - Up to 5 quests (an empty list clears a later epoch's quests)
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the epoch has passed or already has quests
+    ///   running, there are too many quests, or a quest has no target, a
+    ///   negative minimum wager or a non-positive bonus
+    pub fn set_quests(env: Env, epoch: u32, quests: Vec<types::Quest>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let old_quests = storage::get_quests(&env, epoch);
+        quests::set_quests(&env, epoch, &quests)?;
+
+        audit::record(
+            &env,
+            &admin,
+            "set_quests",
+            &(epoch, old_quests),
+            &(epoch, quests),
+        );
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get an epoch's quests
+    pub fn get_quests(env: Env, epoch: u32) -> Vec<types::Quest> {
+        storage::get_quests(&env, epoch)
+    }
+
+    /// Get a player's progress on an epoch's quests
+    ///
+    /// Sessions counted per quest, in the order of `get_quests` (quests the
+    /// player hasn't progressed on may be missing at the end). A quest is
+    /// complete once its count reaches the target.
+    pub fn get_quest_progress(env: Env, epoch: u32, player: Address) -> Vec<u32> {
+        storage::get_quest_progress(&env, epoch, &player)
+    }
+
+    // ========================================================================
+    // Keepers
+    // ========================================================================
+
+    /// Bond USDC as a keeper (registers the keeper, or tops up its bond)
     ///
     /// # Returns
-    /// Amount of USDC claimed
+    /// The keeper's total bond
     ///
     /// # Errors
-    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
-    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
-    /// * `NotWinningFaction` - If player wasn't in the winning faction
-    /// * `NoRewardsAvailable` - If player has no rewards to claim
     /// * `ContractPaused` - If contract is in emergency pause mode
-    pub fn claim_epoch_reward(env: Env, player: Address, epoch: u32) -> Result<i128, Error> {
+    /// * `FeatureNotConfigured` - If the keeper registry is disabled
+    /// * `InvalidAmount` - If bond <= 0
+    /// * `KeeperBondTooLow` - If the total bond would be below the minimum
+    pub fn register_keeper(env: Env, keeper: Address, bond: i128) -> Result<i128, ContractError> {
         storage::require_not_paused(&env)?;
-        rewards::claim_epoch_reward(&env, &player, epoch)
+        keeper::register(&env, &keeper, bond)
     }
 
-    /// Claim developer reward for a specific epoch
+    /// Leave the keeper registry and withdraw the whole bond
     ///
-    /// Developers claim their aggregated share of the epoch's dev reward pool
-    /// proportional to total FP contributed through all their registered games.
+    /// # Returns
+    /// USDC returned to the keeper
     ///
-    /// **Note:** To check claimable amounts or claim status before calling,
-    /// use transaction simulation. This is the idiomatic Soroban pattern.
+    /// # Errors
+    /// * `KeeperNotRegistered` - If the caller isn't a registered keeper
+    /// * `KeeperBondLocked` - If the bond is still locked after a recent cycle
+    pub fn unregister_keeper(env: Env, keeper: Address) -> Result<i128, ContractError> {
+        keeper::unregister(&env, &keeper)
+    }
+
+    /// Slash a misbehaving keeper (e.g. one that cycled with a swap far below
+    /// the oracle price when a better quote existed)
+    ///
+    /// The slashed USDC goes to the next reward pool.
+    ///
+    /// # Returns
+    /// The keeper's remaining bond
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidAmount` - If amount <= 0
+    /// * `KeeperNotRegistered` - If the address isn't a registered keeper
+    /// * `SlashExceedsBond` - If amount exceeds the keeper's bond
+    pub fn slash_keeper(env: Env, keeper: Address, amount: i128) -> Result<i128, ContractError> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let remaining = keeper::slash(&env, &keeper, amount)?;
+        audit::record(
+            &env,
+            &admin,
+            "slash_keeper",
+            &(keeper.clone(), remaining + amount),
+            &(keeper, remaining),
+        );
+
+        Ok(remaining)
+    }
+
+    /// Get a keeper's bond
+    pub fn get_keeper(env: Env, keeper: Address) -> Option<types::KeeperBond> {
+        storage::get_keeper_registry(&env).keepers.get(keeper)
+    }
+
+    /// Get the keeper registry settings and bonds
+    pub fn get_keeper_registry(env: Env) -> types::KeeperRegistry {
+        storage::get_keeper_registry(&env)
+    }
+
+    // ========================================================================
+    // Storage Maintenance
+    // ========================================================================
+
+    /// Extend the TTL of the given storage entries (permissionless)
+    ///
+    /// Entries are already extended whenever they are touched, but inactive
+    /// players' data can approach archival. Anyone (players, keepers, frontends)
+    /// can call this to keep entries alive. Each key is extended according to the
+    /// strategy of its storage class (see `storage.rs`). Missing keys are skipped.
     ///
     /// # Arguments
-    /// * `developer` - Developer address claiming rewards
-    /// * `epoch` - Epoch number to claim from
+    /// * `keys` - Storage keys to extend
     ///
     /// # Returns
-    /// Amount of USDC claimed and transferred to developer
+    /// Number of entries that existed and were extended
+    pub fn bump_entries(env: Env, keys: Vec<storage::DataKey>) -> u32 {
+        let mut bumped = 0;
+        for key in keys.iter() {
+            if storage::bump_entry(&env, &key) {
+                bumped += 1;
+            }
+        }
+        bumped
+    }
+
+    /// Delete the data of epochs that can no longer be claimed (permissionless)
+    ///
+    /// Epochs are pruned oldest first once their claim window has passed and
+    /// they are `PRUNE_RETENTION_EPOCHS` (4) epochs old. An epoch is only pruned
+    /// whole, so `keys_limit` should allow at least one epoch's entries. The
+    /// caller earns the prune bounty per epoch that still had entries to
+    /// remove, paid from the admin-funded bounty fund while it holds enough USDC.
+    ///
+    /// # Arguments
+    /// * `caller` - Address paid the bounty
+    /// * `keys_limit` - Most entries to visit in this call
+    ///
+    /// # Returns
+    /// Number of epochs pruned
+    pub fn prune(env: Env, caller: Address, keys_limit: u32) -> Result<u32, ContractError> {
+        reentrancy::non_reentrant(&env, || prune::prune(&env, &caller, keys_limit))
+    }
+
+    /// Set the USDC paid per pruned epoch (0 disables the bounty)
     ///
     /// # Errors
-    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
-    /// * `DevRewardAlreadyClaimed` - If already claimed for this epoch
-    /// * `GameNoContributions` - If developer has no contributions this epoch
-    /// * `ContractPaused` - If contract is in emergency pause mode
-    pub fn claim_dev_reward(env: Env, developer: Address, epoch: u32) -> Result<i128, Error> {
-        storage::require_not_paused(&env)?;
-        rewards::claim_dev_reward(&env, &developer, epoch)
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the bounty is negative or above 1 USDC
+    pub fn set_prune_bounty(env: Env, bounty: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if !(0..=prune::MAX_PRUNE_BOUNTY).contains(&bounty) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let old = storage::get_prune_bounty(&env);
+        storage::set_prune_bounty(&env, bounty);
+        audit::record(&env, &admin, "set_prune_bounty", &old, &bounty);
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the USDC paid per pruned epoch
+    pub fn get_prune_bounty(env: Env) -> i128 {
+        storage::get_prune_bounty(&env)
+    }
+
+    /// Add admin USDC to the fund prune bounties are paid from
+    ///
+    /// # Returns
+    /// The new bounty fund balance
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidAmount` - If amount <= 0
+    pub fn fund_prune_bounty(env: Env, amount: i128) -> Result<i128, Error> {
+        prune::fund_bounty(&env, amount)
+    }
+
+    /// Get the USDC left in the prune bounty fund
+    pub fn get_prune_bounty_fund(env: Env) -> i128 {
+        storage::get_prune_bounty_fund(&env)
+    }
+
+    /// Get the oldest epoch whose data hasn't been pruned
+    pub fn get_prune_cursor(env: Env) -> u32 {
+        storage::get_prune_cursor(&env)
+    }
+
+    /// Bring the storage layout up to this code's version after an upgrade
+    ///
+    /// Runs each migration step between the stored layout version and the
+    /// current one (see `storage.rs`); does nothing once up to date. Steps
+    /// over per-user entries migrate `users`, and the remaining users migrate
+    /// on their next write or with `migrate_user_data`.
+    ///
+    /// # Arguments
+    /// * `users` - Users whose per-user entries to migrate
+    ///
+    /// # Returns
+    /// Number of entries migrated
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidConfig` - If the stored layout is newer than this code
+    pub fn migrate_storage(env: Env, users: Vec<Address>) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let (from, migrated) = storage::migrate_storage(&env, &users)?;
+        if from != storage::STORAGE_VERSION {
+            audit::record(
+                &env,
+                &admin,
+                "migrate_storage",
+                &from,
+                &storage::STORAGE_VERSION,
+            );
+        }
+
+        Ok(migrated)
+    }
+
+    /// Get the storage layout version
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
     }
 }
 
@@ -596,5 +4495,8 @@ impl CustomAccountInterface for Blendizzard {
 // Tests
 // ============================================================================
 
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
 #[cfg(test)]
 mod tests;
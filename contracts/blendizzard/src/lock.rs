@@ -0,0 +1,123 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::{Error, ExtError};
+use crate::events::emit_deposit_locked;
+use crate::math::{checked_mul_div, Rounding};
+use crate::storage;
+use crate::types::{DepositLock, LockTier, VaultBackend, SCALAR_7};
+
+// ============================================================================
+// Deposit Locks
+// ============================================================================
+//
+// With the DirectPool backend a user can lock their vault balance when
+// depositing (`deposit_with_lock`). While the lock runs, withdrawals are
+// refused and the user's winning contributions are scaled by the tier's
+// multiplier, so they also earn a larger share of the epoch's rewards:
+//
+// - `OneEpoch`: until the current epoch closes, 1.1x
+// - `FourEpochs`: for the current epoch and the next three, 1.25x
+//
+// Locking again while a lock runs never shortens it: the later unlock epoch
+// and the higher tier are kept. The multiplier applies to contributions at
+// settlement (see `game::settle_session`), after the win odds and before the
+// game's rake.
+//
+// Locks only exist with DirectPool: with FeeVault players withdraw from
+// fee-vault-v2 directly, so this contract can't hold their balance. Locks are
+// refused there, and any left from before a backend switch are ignored
+// (neither enforced nor boosting contributions).
+
+/// Point multiplier of a lock tier (7 decimals)
+pub(crate) fn multiplier(tier: LockTier) -> i128 {
+    match tier {
+        LockTier::None => SCALAR_7,
+        LockTier::OneEpoch => 1_1000000,
+        LockTier::FourEpochs => 1_2500000,
+    }
+}
+
+/// Number of epochs a tier locks for, counting the current one
+fn lock_epochs(tier: LockTier) -> u32 {
+    match tier {
+        LockTier::None => 0,
+        LockTier::OneEpoch => 1,
+        LockTier::FourEpochs => 4,
+    }
+}
+
+/// Get a user's lock if it is still running (always None without DirectPool)
+pub(crate) fn active_lock(env: &Env, user: &Address) -> Option<DepositLock> {
+    if storage::get_config(env).vault_backend != VaultBackend::DirectPool {
+        return None;
+    }
+    storage::get_deposit_lock(env, user)
+        .filter(|lock| lock.unlock_epoch > storage::get_current_epoch(env))
+}
+
+/// Lock a user's vault balance for `tier`, extending any running lock
+///
+/// # Returns
+/// The user's lock afterwards (None for `LockTier::None` without a running lock)
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+pub(crate) fn lock_deposit(
+    env: &Env,
+    user: &Address,
+    tier: LockTier,
+) -> Result<Option<DepositLock>, Error> {
+    if storage::get_config(env).vault_backend != VaultBackend::DirectPool {
+        return Err(Error::FeatureNotConfigured);
+    }
+
+    let running = active_lock(env, user);
+    if tier == LockTier::None {
+        return Ok(running);
+    }
+
+    let unlock_epoch = storage::get_current_epoch(env).saturating_add(lock_epochs(tier));
+    let lock = match running {
+        Some(running) => DepositLock {
+            tier: running.tier.max(tier),
+            unlock_epoch: running.unlock_epoch.max(unlock_epoch),
+        },
+        None => DepositLock { tier, unlock_epoch },
+    };
+    storage::set_deposit_lock(env, user, &lock);
+    emit_deposit_locked(env, user, lock.tier, lock.unlock_epoch);
+
+    Ok(Some(lock))
+}
+
+/// Refuse to move a user's vault balance out while their lock runs
+///
+/// # Errors
+/// * `DepositLocked` - If the user's balance is locked until a later epoch
+pub(crate) fn require_unlocked(env: &Env, user: &Address) -> Result<(), ExtError> {
+    if active_lock(env, user).is_some() {
+        return Err(ExtError::DepositLocked);
+    }
+    Ok(())
+}
+
+/// Scale a winning contribution by the winner's lock multiplier
+///
+/// # Errors
+/// * `OverflowError` - If the scaled contribution overflows
+pub(crate) fn locked_contribution(
+    env: &Env,
+    winner: &Address,
+    contribution: i128,
+) -> Result<i128, Error> {
+    let Some(lock) = active_lock(env, winner) else {
+        return Ok(contribution);
+    };
+    checked_mul_div(
+        contribution,
+        multiplier(lock.tier),
+        SCALAR_7,
+        Rounding::Floor,
+    )
+    .ok_or(Error::OverflowError)
+}
@@ -0,0 +1,141 @@
+// ============================================================================
+// Shared Math Helpers
+// ============================================================================
+//
+// Checked fixed-point and integer math shared by the epoch, reward and swap
+// code. Every helper returns `None` instead of overflowing, and takes the
+// rounding direction explicitly where it matters: payouts round down (in
+// favor of the protocol), amounts owed to the protocol round up.
+
+use soroban_fixed_point_math::FixedPoint;
+
+use crate::types::{SCALAR_12, SCALAR_7};
+
+/// Basis point scale (10_000 = 100%)
+pub(crate) const BPS_SCALE: i128 = 10_000;
+
+/// Rounding direction of a fixed-point operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Rounding {
+    /// Towards negative infinity
+    Floor,
+    /// Towards positive infinity
+    Ceil,
+}
+
+/// x * y / denominator, rounded in the given direction
+///
+/// Pro-rata shares are `checked_mul_div(amount, part, total, Rounding::Floor)`.
+///
+/// # Returns
+/// `None` if `denominator` is 0 or the result overflows
+pub(crate) fn checked_mul_div(
+    x: i128,
+    y: i128,
+    denominator: i128,
+    rounding: Rounding,
+) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    match rounding {
+        Rounding::Floor => x.fixed_mul_floor(y, denominator),
+        Rounding::Ceil => x.fixed_mul_ceil(y, denominator),
+    }
+}
+
+/// `bps` basis points of `amount`
+///
+/// # Returns
+/// `None` if the result overflows
+pub(crate) fn checked_bps(amount: i128, bps: u32, rounding: Rounding) -> Option<i128> {
+    checked_mul_div(amount, bps as i128, BPS_SCALE, rounding)
+}
+
+/// `amount` scaled by a 7-decimal fraction or multiplier (SCALAR_7 = 1.0)
+///
+/// # Returns
+/// `None` if the result overflows
+pub(crate) fn checked_mul_scalar_7(amount: i128, factor: i128, rounding: Rounding) -> Option<i128> {
+    checked_mul_div(amount, factor, SCALAR_7, rounding)
+}
+
+/// Integer square root, rounded down
+///
+/// Uses Newton's method starting from a power of two >= sqrt(n), so the
+/// sequence decreases monotonically to floor(sqrt(n)).
+///
+/// # Returns
+/// `None` if `n` is negative
+pub(crate) fn checked_isqrt(n: i128) -> Option<i128> {
+    if n < 0 {
+        return None;
+    }
+    if n < 2 {
+        return Some(n);
+    }
+
+    let n = n as u128;
+    let bits = 128 - n.leading_zeros();
+    let mut x = 1u128 << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return Some(x as i128);
+        }
+        x = y;
+    }
+}
+
+/// Square root of a fixed-point value, rounded down
+///
+/// sqrt(x / scalar) * scalar = sqrt(x * scalar)
+///
+/// # Returns
+/// `None` if `x` is negative or `x * scalar` overflows
+pub(crate) fn checked_fixed_sqrt(x: i128, scalar: i128) -> Option<i128> {
+    checked_isqrt(x.checked_mul(scalar)?)
+}
+
+/// ln(2) with 12 decimals
+const LN_2_SCALAR_12: i128 = 693_147_180_560;
+
+/// Exponentially decay a non-negative value, rounded down
+///
+/// value * 2^(-elapsed / half_life). Whole half-lives are applied as shifts;
+/// the remaining fraction uses a Taylor series of e^(-x ln 2) at 12 decimals.
+/// A half-life of 0 disables decay.
+///
+/// # Returns
+/// `None` if `value` is negative or the result overflows
+pub(crate) fn checked_decay(value: i128, elapsed: u64, half_life: u64) -> Option<i128> {
+    if value < 0 {
+        return None;
+    }
+    if half_life == 0 {
+        return Some(value);
+    }
+
+    let halvings = elapsed / half_life;
+    if halvings >= 127 {
+        return Some(0);
+    }
+    let halved = value >> halvings;
+
+    // x = fraction of a half-life left over, in [0, 1), so x ln 2 < 0.7 and
+    // twelve terms are well below the 12th decimal
+    let remainder = (elapsed % half_life) as i128;
+    let exponent = LN_2_SCALAR_12.checked_mul(remainder)? / half_life as i128;
+    let mut term = SCALAR_12;
+    let mut factor = SCALAR_12;
+    for k in 1..=12 {
+        term = term.checked_mul(exponent)? / SCALAR_12 / k;
+        if k % 2 == 1 {
+            factor -= term;
+        } else {
+            factor += term;
+        }
+    }
+
+    checked_mul_div(halved, factor, SCALAR_12, Rounding::Floor)
+}
@@ -0,0 +1,205 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::Error;
+use crate::events::{emit_merkle_root_set, emit_rewards_claimed};
+use crate::math::{checked_mul_div, Rounding};
+use crate::storage;
+use crate::types::EpochMerkle;
+
+// ============================================================================
+// Merkle-Drop Distribution
+// ============================================================================
+//
+// Alternative settlement mode for epochs with many participants.
+// Instead of every player computing their pro-rata share on-chain, the admin
+// computes payouts off-chain and posts a single Merkle root per epoch.
+// Players then claim by presenting a proof of their (address, amount) leaf.
+//
+// Leaf encoding (must match off-chain tooling):
+//   leaf = sha256(xdr(player_address) || amount as 16-byte big-endian i128)
+//
+// Internal nodes use sorted-pair hashing (OpenZeppelin-compatible):
+//   node = sha256(min(a, b) || max(a, b))
+
+/// Maximum proof length accepted (supports trees of up to 2^32 leaves)
+const MAX_PROOF_DEPTH: u32 = 32;
+
+/// Post the Merkle root for a finalized epoch
+///
+/// Once a root is posted, per-player `claim_epoch_reward` is disabled for that
+/// epoch and all player rewards must be claimed via `claim_merkle_reward`.
+/// Developer rewards are unaffected.
+///
+/// Players who already claimed on-chain stay marked as claimed, and the USDC
+/// they were paid counts against the pool, so the root can't pay it out twice.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Finalized epoch number
+/// * `root` - Merkle root of all (player, amount) leaves
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `MerkleRootAlreadySet` - If a root was already posted for this epoch
+pub(crate) fn set_merkle_root(env: &Env, epoch: u32, root: &BytesN<32>) -> Result<(), Error> {
+    // Authenticate admin
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.is_finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    // Root is write-once: changing it after claims start would allow double payouts
+    if storage::get_epoch_merkle(env, epoch).is_some() {
+        return Err(Error::MerkleRootAlreadySet);
+    }
+
+    // Seed with on-chain claims made before the root was posted
+    let merkle = EpochMerkle {
+        root: root.clone(),
+        total_claimed: storage::get_epoch_payouts(env, epoch).player_claimed,
+    };
+    storage::set_epoch_merkle(env, epoch, &merkle);
+    crate::audit::record(env, &admin, "set_merkle_root", &(), &(epoch, root.clone()));
+
+    emit_merkle_root_set(env, epoch, root);
+
+    Ok(())
+}
+
+/// Claim a player's reward for a Merkle-distributed epoch
+///
+/// Verifies the proof against the epoch's root, then deposits the reward into
+/// the fee-vault on behalf of the player (same payout path as `claim_epoch_reward`).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player claiming rewards
+/// * `epoch` - Epoch number to claim from
+/// * `amount` - Reward amount encoded in the player's leaf
+/// * `proof` - Sibling hashes from leaf to root
+///
+/// # Returns
+/// Amount of USDC deposited into fee-vault (net of any referral share).
+/// A pro-rata share of the epoch's `reward_pool_blnd` is transferred as BLND,
+/// and of each extra reward token pool in that token.
+///
+/// # Errors
+/// * `DepositRequiredToClaim` - If player's vault balance is below minimum threshold
+/// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+/// * `NoRewardsAvailable` - If no root is posted or the pool would be exceeded
+/// * `InvalidAmount` - If amount <= 0
+/// * `InvalidMerkleProof` - If the proof doesn't verify against the root
+/// * `ClaimWindowExpired` - If the epoch's claim window has passed
+pub(crate) fn claim_merkle_reward(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+    proof: &Vec<BytesN<32>>,
+) -> Result<i128, Error> {
+    // Authenticate player
+    player.require_auth();
+
+    // Check minimum deposit requirement for claiming (anti-sybil gate)
+    let config = storage::get_config(env);
+    let vault_balance = crate::vault::get_vault_balance(env, player);
+    if vault_balance < config.min_deposit_to_claim {
+        return Err(Error::DepositRequiredToClaim);
+    }
+
+    // Shares the claimed flag with claim_epoch_reward
+    if storage::has_claimed(env, player, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let winning_faction = epoch_info.winning_faction.ok_or(Error::EpochNotFinalized)?;
+    crate::carryover::require_claimable(env, epoch)?;
+    let mut merkle = storage::get_epoch_merkle(env, epoch).ok_or(Error::NoRewardsAvailable)?;
+
+    // Verify proof
+    let leaf = leaf_hash(env, player, amount);
+    if !verify_proof(env, &merkle.root, &leaf, proof) {
+        return Err(Error::InvalidMerkleProof);
+    }
+
+    // Never pay out more than the epoch's player reward pool, even with a bad root
+    let new_total = merkle
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    if new_total > epoch_info.reward_pool {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    // Mark as claimed and track total paid
+    storage::set_claimed(env, player, epoch);
+    merkle.total_claimed = new_total;
+    storage::set_epoch_merkle(env, epoch, &merkle);
+    crate::carryover::record_player_claim(env, epoch, amount)?;
+
+    // Credit the referrer's share (if any); the player receives the rest
+    let payout = crate::referral::apply_referral_share(env, &config, player, epoch, amount)?;
+
+    crate::vesting::pay_reward(env, &config, player, epoch, payout, true)?;
+
+    // Retained BLND and extra reward tokens are paid pro rata to the player's
+    // share of the USDC pool
+    let blnd_amount = checked_mul_div(
+        amount,
+        epoch_info.reward_pool_blnd,
+        epoch_info.reward_pool,
+        Rounding::Floor,
+    )
+    .unwrap_or(0);
+    crate::rewards::pay_blnd_reward(env, &config, player, epoch, blnd_amount)?;
+    let token_pools = storage::get_epoch_reward_tokens(env, epoch);
+    let token_shares =
+        crate::reward_tokens::pro_rata_shares(env, &token_pools, amount, epoch_info.reward_pool);
+    crate::reward_tokens::pay_shares(env, player, epoch, &token_pools, &token_shares)?;
+    crate::rewards::record_claim_history(env, &config, player, epoch, payout, blnd_amount);
+
+    emit_rewards_claimed(env, player, epoch, winning_faction, payout, blnd_amount);
+
+    Ok(payout)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Compute the leaf hash for a (player, amount) pair
+pub(crate) fn leaf_hash(env: &Env, player: &Address, amount: i128) -> BytesN<32> {
+    let mut data = player.clone().to_xdr(env);
+    data.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Hash two nodes in sorted order
+pub(crate) fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.append(&Bytes::from_array(env, &second.to_array()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Verify a Merkle proof for a leaf against a root
+fn verify_proof(env: &Env, root: &BytesN<32>, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    if proof.len() > MAX_PROOF_DEPTH {
+        return false;
+    }
+
+    let mut computed = leaf.clone();
+    for sibling in proof.iter() {
+        computed = hash_pair(env, &computed, &sibling);
+    }
+
+    computed == *root
+}
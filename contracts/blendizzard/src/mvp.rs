@@ -0,0 +1,76 @@
+use soroban_sdk::{token, Env};
+
+use crate::errors::Error;
+use crate::events::emit_mvp_awarded;
+use crate::math::{checked_bps, Rounding};
+use crate::storage;
+use crate::types::{Config, EpochInfo, Title, TitleKind};
+
+// ============================================================================
+// Epoch MVP
+// ============================================================================
+//
+// The winning faction's top contributor of an epoch is its MVP. The leader of
+// each faction is tracked incrementally as sessions settle
+// (`EpochInfo::faction_leaders`, see `game::update_epoch_on_game_end`), so
+// finalization only reads it.
+//
+// When the epoch's rewards are processed the MVP earns an "MVP of epoch N"
+// title (`get_titles`) and, with the MVP bonus set, that share of the player
+// reward pool paid in USDC straight to them (before the pool is split).
+
+/// Largest MVP bonus (10% of the player reward pool)
+pub(crate) const MAX_MVP_BONUS_BPS: u32 = 1_000;
+
+/// Award the closed epoch's MVP their title and bonus
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (USDC token)
+/// * `epoch` - Closed epoch
+/// * `epoch_info` - Closed epoch's info (faction leaders)
+/// * `winning_faction` - Closed epoch's winning faction
+/// * `player_reward_pool` - Player reward pool of the closed epoch
+///
+/// # Returns
+/// USDC paid (to deduct from the player reward pool)
+pub(crate) fn award_mvp(
+    env: &Env,
+    config: &Config,
+    epoch: u32,
+    epoch_info: &EpochInfo,
+    winning_faction: u32,
+    player_reward_pool: i128,
+) -> Result<i128, Error> {
+    let Some(mvp) = epoch_info.faction_leaders.get(winning_faction) else {
+        return Ok(0);
+    };
+
+    let bonus = checked_bps(
+        player_reward_pool.max(0),
+        storage::get_mvp_bonus(env),
+        Rounding::Floor,
+    )
+    .ok_or(Error::OverflowError)?;
+    if bonus > 0 {
+        token::Client::new(env, &config.usdc_token).transfer(
+            &env.current_contract_address(),
+            &mvp,
+            &bonus,
+        );
+    }
+
+    storage::push_title(
+        env,
+        &mvp,
+        Title {
+            kind: TitleKind::EpochMvp,
+            epoch,
+            faction: winning_faction,
+            bonus,
+        },
+    );
+    emit_mvp_awarded(env, &mvp, epoch, winning_faction, bonus);
+
+    Ok(bonus)
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::{ContractError, Error};
+use crate::storage;
+
+// ============================================================================
+// One-Signature Onboarding
+// ============================================================================
+
+/// Deposit USDC and select a faction in a single call
+///
+/// Runs the same steps (and emits the same events) as `select_faction`
+/// followed by a deposit into the vault backend, so a new player is set up with
+/// one signature. Either every step succeeds or none does.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - New player
+/// * `amount` - USDC to deposit (0 to skip the deposit and start on free FP)
+/// * `faction` - Faction ID to select
+///
+/// # Returns
+/// Player's vault balance after the deposit
+///
+/// # Errors
+/// * `InvalidAmount` - If amount < 0
+/// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+/// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+/// * Same as `select_faction`
+pub(crate) fn onboard(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+    faction: u32,
+) -> Result<i128, ContractError> {
+    if amount < 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    // Authenticates the user (once for the whole call)
+    crate::faction::select_faction(env, user, faction)?;
+
+    if amount > 0 {
+        let config = storage::get_config(env);
+        crate::vault::deposit_from_user(env, &config, user, amount)?;
+    }
+
+    Ok(crate::vault::get_vault_balance(env, user))
+}
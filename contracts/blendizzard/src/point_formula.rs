@@ -0,0 +1,72 @@
+use soroban_sdk::Env;
+
+use crate::errors::Error;
+use crate::math::{checked_bps, Rounding, BPS_SCALE};
+use crate::storage;
+use crate::types::{GameInfo, PointFormula};
+
+// ============================================================================
+// Point Formulas
+// ============================================================================
+//
+// Each registered game picks how its wins convert to faction points
+// (`GameInfo::point_formula`, set with `set_game_point_formula`), so games can
+// change formulas without a redeploy. The formula gives the base points of a
+// win; settlement then applies decay, win odds, deposit locks and rake to it
+// as usual (see `game::settle_session`).
+//
+// Margin-based games report each result's margin of victory with
+// `end_game_with_margin`. It's kept with the session until it settles, so
+// results awaiting a dispute window keep their margin; results reported
+// without one count as a full margin.
+
+/// Check that a formula's parameters are usable
+///
+/// Flat amounts must be positive and margin floors at most 100%.
+pub(crate) fn is_valid(formula: &PointFormula) -> bool {
+    match formula {
+        PointFormula::Stake => true,
+        PointFormula::Flat(fp) => *fp > 0,
+        PointFormula::Margin(min_bps) => *min_bps as i128 <= BPS_SCALE,
+    }
+}
+
+/// Base faction points of a winning wager under the game's formula
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_info` - Game the session belongs to
+/// * `session_id` - Settling session (for its reported margin)
+/// * `winner_wager` - FP wagered by the winner
+///
+/// # Errors
+/// * `OverflowError` - If the margin scaling overflows
+pub(crate) fn base_points(
+    env: &Env,
+    game_info: &GameInfo,
+    session_id: u32,
+    winner_wager: i128,
+) -> Result<i128, Error> {
+    match game_info.point_formula {
+        PointFormula::Stake => Ok(winner_wager),
+        PointFormula::Flat(fp) => Ok(fp.min(winner_wager)),
+        PointFormula::Margin(min_bps) => {
+            let margin = storage::get_session_margin(env, session_id)
+                .unwrap_or(BPS_SCALE as u32)
+                .max(min_bps);
+            checked_bps(winner_wager, margin, Rounding::Floor).ok_or(Error::OverflowError)
+        }
+    }
+}
+
+/// Keep the margin of victory a game reported with a session's result
+///
+/// # Errors
+/// * `InvalidAmount` - If the margin is above 100% (10_000 bps)
+pub(crate) fn record_margin(env: &Env, session_id: u32, margin_bps: u32) -> Result<(), Error> {
+    if margin_bps as i128 > BPS_SCALE {
+        return Err(Error::InvalidAmount);
+    }
+    storage::set_session_margin(env, session_id, margin_bps);
+    Ok(())
+}
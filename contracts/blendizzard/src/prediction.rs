@@ -0,0 +1,226 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{emit_prediction_claimed, emit_prediction_placed, emit_predictions_resolved};
+use crate::storage;
+use crate::types::{Prediction, PredictionPool, SCALAR_7};
+
+// ============================================================================
+// Spectator Predictions
+// ============================================================================
+//
+// Anyone except the two players can stake USDC on a pending session's winner.
+// When the session is settled (`end_game`, a decided series or a reveal
+// timeout), `config.prediction_rake` of the losing side's stakes goes to the
+// next epoch's reward pool (via `PendingRewards`) and the rest is shared by
+// correct predictors pro rata to their stake. Predictions on sessions that
+// never settle are refunded once the session's epoch has ended.
+//
+// Stakes stay in this contract as USDC until claimed with `claim_prediction`.
+
+/// Stake USDC on a session's winner
+///
+/// Repeated predictions on the same session add to the stake and must pick
+/// the same side.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `predictor` - Spectator placing the prediction
+/// * `session_id` - Session to predict
+/// * `player1_wins` - Predicted winner (true = player1)
+/// * `amount` - USDC to stake
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If predictions are disabled
+/// * `InvalidAmount` - If amount <= 0 or the total stake exceeds `config.max_prediction`
+/// * `SessionNotFound` - If the session doesn't exist
+/// * `SessionAlreadyFinalized` - If the session was already settled (or has a provisional result)
+/// * `GameExpired` - If the session is from a previous epoch
+/// * `PredictorIsPlayer` - If the predictor plays in the session
+/// * `PredictionSideMismatch` - If the predictor switches side
+pub(crate) fn predict(
+    env: &Env,
+    predictor: &Address,
+    session_id: u32,
+    player1_wins: bool,
+    amount: i128,
+) -> Result<(), ContractError> {
+    // Authenticate predictor
+    predictor.require_auth();
+
+    let config = storage::get_config(env);
+    if config.max_prediction == 0 {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    if session.player1_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized.into());
+    }
+    // Outcome already reported, pending its dispute window
+    crate::dispute::require_no_provisional(env, session_id)?;
+    if session.epoch_id != storage::get_current_epoch(env) {
+        return Err(Error::GameExpired.into());
+    }
+
+    // Players can't bet on their own game
+    if *predictor == session.player1 || *predictor == session.player2 {
+        return Err(ExtError::PredictorIsPlayer.into());
+    }
+
+    let mut prediction =
+        storage::get_prediction(env, session_id, predictor).unwrap_or(Prediction {
+            player1_wins,
+            amount: 0,
+        });
+    if prediction.player1_wins != player1_wins {
+        return Err(ExtError::PredictionSideMismatch.into());
+    }
+    prediction.amount = prediction
+        .amount
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    if prediction.amount > config.max_prediction {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let mut pool = storage::get_prediction_pool(env, session_id).unwrap_or(PredictionPool {
+        player1_stake: 0,
+        player2_stake: 0,
+        player1_won: None,
+        payout_pool: 0,
+    });
+    let side_stake = if player1_wins {
+        &mut pool.player1_stake
+    } else {
+        &mut pool.player2_stake
+    };
+    *side_stake = side_stake.checked_add(amount).ok_or(Error::OverflowError)?;
+
+    token::Client::new(env, &config.usdc_token).transfer(
+        predictor,
+        &env.current_contract_address(),
+        &amount,
+    );
+
+    storage::set_prediction(env, session_id, predictor, &prediction);
+    storage::set_prediction_pool(env, session_id, &pool);
+
+    emit_prediction_placed(env, session_id, predictor, player1_wins, amount);
+
+    Ok(())
+}
+
+/// Resolve a session's predictions once it is settled
+///
+/// Moves the rake on the losing stakes (or all of them, if nobody predicted
+/// the winner) into the pending reward pool. No-op for sessions without
+/// predictions.
+pub(crate) fn resolve(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+    let Some(mut pool) = storage::get_prediction_pool(env, session_id) else {
+        return Ok(());
+    };
+
+    let (winning_stake, losing_stake) = if player1_won {
+        (pool.player1_stake, pool.player2_stake)
+    } else {
+        (pool.player2_stake, pool.player1_stake)
+    };
+
+    let rake = if winning_stake == 0 {
+        losing_stake
+    } else {
+        losing_stake
+            .fixed_mul_floor(storage::get_config(env).prediction_rake, SCALAR_7)
+            .ok_or(Error::OverflowError)?
+    };
+
+    pool.player1_won = Some(player1_won);
+    pool.payout_pool = losing_stake - rake;
+    storage::set_prediction_pool(env, session_id, &pool);
+
+    if rake > 0 {
+        let pending = storage::get_pending_rewards(env)
+            .checked_add(rake)
+            .ok_or(Error::OverflowError)?;
+        storage::set_pending_rewards(env, pending);
+    }
+
+    emit_predictions_resolved(env, session_id, player1_won, rake, pool.payout_pool);
+
+    Ok(())
+}
+
+/// Claim a prediction's winnings, or its refund if the session never settled
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `predictor` - Spectator claiming
+/// * `session_id` - Predicted session
+///
+/// # Returns
+/// Amount of USDC transferred to the predictor
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If there is no prediction or it was wrong
+/// * `PredictionNotRefundable` - If the session is still pending in the current epoch
+pub(crate) fn claim_prediction(
+    env: &Env,
+    predictor: &Address,
+    session_id: u32,
+) -> Result<i128, ContractError> {
+    // Authenticate predictor
+    predictor.require_auth();
+
+    let prediction =
+        storage::get_prediction(env, session_id, predictor).ok_or(Error::NoRewardsAvailable)?;
+    let pool = storage::get_prediction_pool(env, session_id).ok_or(Error::NoRewardsAvailable)?;
+
+    let amount = match pool.player1_won {
+        Some(player1_won) => {
+            if prediction.player1_wins != player1_won {
+                return Err(Error::NoRewardsAvailable.into());
+            }
+            let winning_stake = if player1_won {
+                pool.player1_stake
+            } else {
+                pool.player2_stake
+            };
+            let winnings = pool
+                .payout_pool
+                .fixed_mul_floor(prediction.amount, winning_stake)
+                .ok_or(Error::OverflowError)?;
+            prediction
+                .amount
+                .checked_add(winnings)
+                .ok_or(Error::OverflowError)?
+        }
+        None => {
+            // Refund once the session can no longer be settled
+            let pending = storage::get_session(env, session_id)
+                .is_some_and(|session| session.epoch_id == storage::get_current_epoch(env));
+            if pending {
+                return Err(ExtError::PredictionNotRefundable.into());
+            }
+            prediction.amount
+        }
+    };
+
+    // Remove the prediction before paying out
+    storage::remove_prediction(env, session_id, predictor);
+
+    let config = storage::get_config(env);
+    token::Client::new(env, &config.usdc_token).transfer(
+        &env.current_contract_address(),
+        predictor,
+        &amount,
+    );
+
+    emit_prediction_claimed(env, session_id, predictor, amount);
+
+    Ok(amount)
+}
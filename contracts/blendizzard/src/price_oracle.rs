@@ -0,0 +1,23 @@
+// SEP-40 price oracle interface (Reflector-compatible)
+// Only the subset of functions used by Blendizzard is declared.
+#[allow(dead_code)]
+#[soroban_sdk::contractargs(name = "Args")]
+#[soroban_sdk::contractclient(name = "Client")]
+pub trait Contract {
+    fn base(env: soroban_sdk::Env) -> Asset;
+    fn decimals(env: soroban_sdk::Env) -> u32;
+    fn resolution(env: soroban_sdk::Env) -> u32;
+    fn lastprice(env: soroban_sdk::Env, asset: Asset) -> Option<PriceData>;
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Asset {
+    Stellar(soroban_sdk::Address),
+    Other(soroban_sdk::Symbol),
+}
+#[soroban_sdk::contracttype(export = false)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
@@ -0,0 +1,175 @@
+use soroban_sdk::{token, Address, Env, Map};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{
+    emit_protocol_treasury_funded, emit_treasury_transfer_queued, emit_treasury_transferred,
+};
+use crate::storage;
+use crate::types::QueuedTreasuryTransfer;
+
+// ============================================================================
+// Protocol Treasury
+// ============================================================================
+//
+// Game rakes routed to `RakeRoute::Protocol` (see `rake.rs`) are credited to
+// the protocol treasury each cycle. The tokens stay in this contract, tracked
+// per token in `ProtocolTreasury::balances`, so they are never mistaken for
+// player funds or rescued as stray tokens.
+//
+// Only the treasurer (set by the admin) can spend them, and only through the
+// same two-step timelock as vault migrations: `queue_transfer` first,
+// `transfer` once `TREASURY_TRANSFER_DELAY` has passed. The admin can cancel a
+// queued transfer during the delay.
+
+/// Delay between queueing a treasury transfer and executing it (same as vault migrations)
+pub(crate) const TREASURY_TRANSFER_DELAY: u64 = crate::vault::VAULT_MIGRATION_DELAY;
+
+/// Token balances held for the protocol treasury
+pub(crate) fn balances(env: &Env) -> Map<Address, i128> {
+    storage::get_protocol_treasury(env).balances
+}
+
+/// Credit routed rake to the protocol treasury
+///
+/// # Errors
+/// * `OverflowError` - If the balance overflows
+pub(crate) fn fund(env: &Env, epoch: u32, token: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let mut treasury = storage::get_protocol_treasury(env);
+    let balance = treasury
+        .balances
+        .get(token.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    treasury.balances.set(token.clone(), balance);
+    storage::set_protocol_treasury(env, &treasury);
+    emit_protocol_treasury_funded(env, epoch, token, amount, balance);
+    Ok(())
+}
+
+/// Set or clear the treasurer
+///
+/// Caller must have authenticated the admin.
+///
+/// # Returns
+/// The previous treasurer
+pub(crate) fn set_treasurer(env: &Env, treasurer: &Option<Address>) -> Option<Address> {
+    let mut treasury = storage::get_protocol_treasury(env);
+    let old = treasury.treasurer.clone();
+    treasury.treasurer = treasurer.clone();
+    storage::set_protocol_treasury(env, &treasury);
+    old
+}
+
+/// Queue a transfer out of the protocol treasury
+///
+/// `transfer` can execute it once `TREASURY_TRANSFER_DELAY` has passed.
+/// Queueing replaces any transfer queued earlier.
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If no treasurer is set
+/// * `InvalidAmount` - If amount <= 0
+/// * `InsufficientTreasury` - If the treasury holds less than amount of token
+pub(crate) fn queue_transfer(
+    env: &Env,
+    to: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<QueuedTreasuryTransfer, ContractError> {
+    let mut treasury = storage::get_protocol_treasury(env);
+    let treasurer = treasury
+        .treasurer
+        .clone()
+        .ok_or(Error::FeatureNotConfigured)?;
+    treasurer.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+    if amount > treasury.balances.get(token.clone()).unwrap_or(0) {
+        return Err(ExtError::InsufficientTreasury.into());
+    }
+
+    let transfer = QueuedTreasuryTransfer {
+        to: to.clone(),
+        token: token.clone(),
+        amount,
+        executable_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(TREASURY_TRANSFER_DELAY),
+    };
+    treasury.queued_transfer = Some(transfer.clone());
+    storage::set_protocol_treasury(env, &treasury);
+
+    emit_treasury_transfer_queued(env, &transfer);
+
+    Ok(transfer)
+}
+
+/// Cancel the queued treasury transfer
+///
+/// Caller must have authenticated the admin.
+///
+/// # Returns
+/// The cancelled transfer, if any
+pub(crate) fn cancel_transfer(env: &Env) -> Option<QueuedTreasuryTransfer> {
+    let mut treasury = storage::get_protocol_treasury(env);
+    let cancelled = treasury.queued_transfer.take();
+    storage::set_protocol_treasury(env, &treasury);
+    cancelled
+}
+
+/// Execute the queued treasury transfer once its timelock has passed
+///
+/// The arguments must match the queued transfer exactly.
+///
+/// # Returns
+/// The treasury's remaining balance of token
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If no treasurer is set
+/// * `TreasuryTransferNotQueued` - If no matching transfer is queued
+/// * `TreasuryTransferTimelocked` - If the queued transfer's timelock hasn't passed
+/// * `InsufficientTreasury` - If the treasury holds less than amount of token
+pub(crate) fn transfer(
+    env: &Env,
+    to: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let mut treasury = storage::get_protocol_treasury(env);
+    let treasurer = treasury
+        .treasurer
+        .clone()
+        .ok_or(Error::FeatureNotConfigured)?;
+    treasurer.require_auth();
+
+    let Some(queued) = treasury.queued_transfer.clone() else {
+        return Err(ExtError::TreasuryTransferNotQueued.into());
+    };
+    if queued.to != *to || queued.token != *token || queued.amount != amount {
+        return Err(ExtError::TreasuryTransferNotQueued.into());
+    }
+    if env.ledger().timestamp() < queued.executable_at {
+        return Err(ExtError::TreasuryTransferTimelocked.into());
+    }
+
+    let balance = treasury.balances.get(token.clone()).unwrap_or(0);
+    if amount > balance {
+        return Err(ExtError::InsufficientTreasury.into());
+    }
+    let remaining = balance - amount;
+    treasury.balances.set(token.clone(), remaining);
+    treasury.queued_transfer = None;
+    storage::set_protocol_treasury(env, &treasury);
+
+    token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+
+    emit_treasury_transferred(env, to, token, amount, remaining);
+
+    Ok(remaining)
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::Error;
+use crate::events::emit_epoch_data_pruned;
+use crate::storage;
+
+// ============================================================================
+// Epoch Data Pruning
+// ============================================================================
+//
+// Per-epoch entries stay in temporary storage, paying rent, until they expire.
+// Once an epoch's claim window has passed (its unclaimed rewards were swept,
+// see `carryover.rs`) and it is at least `PRUNE_RETENTION_EPOCHS` old, nothing
+// reads it again, and anyone can `prune` it. Epochs are pruned oldest first,
+// each one whole, and the caller earns `prune_bounty` USDC per epoch that still
+// had entries to remove. Bounties are paid from USDC the admin sets aside with
+// `fund_bounty`, never from player rewards.
+//
+// Without a claim window epochs never expire and nothing is pruned. Entries
+// that can't be enumerated on-chain (sessions, per-player epoch data) are left
+// to expire on their own; claim histories are already capped on write.
+
+/// Epochs kept after the current one before their data can be pruned
+pub(crate) const PRUNE_RETENTION_EPOCHS: u32 = 4;
+
+/// Largest USDC bounty per pruned epoch
+pub(crate) const MAX_PRUNE_BOUNTY: i128 = 1_0000000;
+
+/// Delete the data of expired epochs
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `caller` - Address paid the bounty
+/// * `keys_limit` - Most entries to visit (each epoch visits `EPOCH_DATA_KEYS`)
+///
+/// # Returns
+/// Number of epochs pruned
+///
+/// # Errors
+/// * `OverflowError` - If the bounty overflows
+pub(crate) fn prune(env: &Env, caller: &Address, keys_limit: u32) -> Result<u32, Error> {
+    caller.require_auth();
+
+    let current = storage::get_current_epoch(env);
+    let swept_until = storage::get_next_sweep_epoch(env);
+    let first = storage::get_prune_cursor(env);
+
+    let mut epoch = first;
+    let mut visited: u32 = 0;
+    let mut removed: u32 = 0;
+    // Epochs whose entries had already expired earn no bounty
+    let mut paid_epochs: u32 = 0;
+    while epoch < swept_until
+        && epoch.saturating_add(PRUNE_RETENTION_EPOCHS) <= current
+        && visited + storage::EPOCH_DATA_KEYS <= keys_limit
+    {
+        let epoch_removed = storage::remove_epoch_data(env, epoch);
+        if epoch_removed > 0 {
+            paid_epochs += 1;
+        }
+        removed += epoch_removed;
+        visited += storage::EPOCH_DATA_KEYS;
+        epoch += 1;
+    }
+
+    let pruned = epoch - first;
+    if pruned == 0 {
+        return Ok(0);
+    }
+    storage::set_prune_cursor(env, epoch);
+
+    let fund = storage::get_prune_bounty_fund(env);
+    let bounty = storage::get_prune_bounty(env)
+        .checked_mul(paid_epochs as i128)
+        .ok_or(Error::OverflowError)?
+        .min(fund)
+        .max(0);
+    if bounty > 0 {
+        storage::set_prune_bounty_fund(env, fund - bounty);
+        let config = storage::get_config(env);
+        token::Client::new(env, &config.usdc_token).transfer(
+            &env.current_contract_address(),
+            caller,
+            &bounty,
+        );
+    }
+
+    emit_epoch_data_pruned(env, caller, first, epoch - 1, removed, bounty);
+
+    Ok(pruned)
+}
+
+/// Set aside admin USDC for prune bounties
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `amount` - USDC transferred from the admin into the bounty fund
+///
+/// # Returns
+/// The new bounty fund balance
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `InvalidAmount` - If amount <= 0
+/// * `OverflowError` - If the fund balance overflows
+pub(crate) fn fund_bounty(env: &Env, amount: i128) -> Result<i128, Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let old = storage::get_prune_bounty_fund(env);
+    let balance = old.checked_add(amount).ok_or(Error::OverflowError)?;
+
+    let config = storage::get_config(env);
+    token::Client::new(env, &config.usdc_token).transfer(
+        &admin,
+        &env.current_contract_address(),
+        &amount,
+    );
+    storage::set_prune_bounty_fund(env, balance);
+    crate::audit::record(env, &admin, "fund_prune_bounty", &old, &balance);
+
+    Ok(balance)
+}
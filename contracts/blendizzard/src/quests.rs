@@ -0,0 +1,134 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::Error;
+use crate::events::emit_quest_completed;
+use crate::storage;
+use crate::types::{Quest, QuestKind};
+
+// ============================================================================
+// Quests
+// ============================================================================
+//
+// The admin defines up to `MAX_QUESTS` challenges per epoch: play a number of
+// sessions, or win a number of sessions, counting only wagers of at least the
+// quest's `min_wager`. Progress is tracked as 1v1 sessions settle. On
+// completing a quest the player's available FP for the epoch grows by the
+// quest's `bonus_fp` (to wager in further sessions) and `QuestCompleted` is
+// emitted. Each quest completes once per player.
+//
+// An epoch's quests can be set until the epoch starts; once players are
+// making progress on them they can't be replaced.
+
+/// Maximum number of quests per epoch
+pub(crate) const MAX_QUESTS: u32 = 5;
+
+/// Set an epoch's quests
+///
+/// Caller must have authenticated the admin.
+///
+/// # Errors
+/// * `InvalidConfig` - If the epoch has passed or already started with
+///   quests, there are too many quests, or a quest has no target, a negative
+///   minimum wager or a non-positive bonus
+pub(crate) fn set_quests(env: &Env, epoch: u32, quests: &Vec<Quest>) -> Result<(), Error> {
+    let current_epoch = storage::get_current_epoch(env);
+    if epoch < current_epoch
+        || (epoch == current_epoch && !storage::get_quests(env, epoch).is_empty())
+    {
+        return Err(Error::InvalidConfig);
+    }
+
+    if quests.len() > MAX_QUESTS {
+        return Err(Error::InvalidConfig);
+    }
+    for quest in quests.iter() {
+        if quest.target == 0 || quest.min_wager < 0 || quest.bonus_fp <= 0 {
+            return Err(Error::InvalidConfig);
+        }
+    }
+
+    storage::set_quests(env, epoch, quests);
+    Ok(())
+}
+
+/// Update both players' quest progress after a finished session
+///
+/// Caller must have saved both players' epoch data.
+///
+/// # Errors
+/// * `PlayerNotFound` - If a player has no epoch data
+/// * `OverflowError` - If a bonus overflows the player's FP
+pub(crate) fn record_game(
+    env: &Env,
+    epoch: u32,
+    winner: &Address,
+    winner_wager: i128,
+    loser: &Address,
+    loser_wager: i128,
+) -> Result<(), Error> {
+    let quests = storage::get_quests(env, epoch);
+    if quests.is_empty() {
+        return Ok(());
+    }
+    record_result(env, epoch, &quests, winner, winner_wager, true)?;
+    record_result(env, epoch, &quests, loser, loser_wager, false)
+}
+
+/// Update one player's quest progress, paying out bonuses of completed quests
+fn record_result(
+    env: &Env,
+    epoch: u32,
+    quests: &Vec<Quest>,
+    player: &Address,
+    wager: i128,
+    won: bool,
+) -> Result<(), Error> {
+    let mut progress = storage::get_quest_progress(env, epoch, player);
+    let mut bonus_fp: i128 = 0;
+    let mut changed = false;
+
+    for (index, quest) in quests.iter().enumerate() {
+        let index = index as u32;
+        let counts = match quest.kind {
+            QuestKind::PlayGames => true,
+            QuestKind::WinGames => won,
+        };
+        if !counts || wager < quest.min_wager {
+            continue;
+        }
+
+        while progress.len() <= index {
+            progress.push_back(0);
+        }
+        let done = progress.get_unchecked(index);
+        if done >= quest.target {
+            continue;
+        }
+
+        progress.set(index, done + 1);
+        changed = true;
+        if done + 1 == quest.target {
+            bonus_fp = bonus_fp
+                .checked_add(quest.bonus_fp)
+                .ok_or(Error::OverflowError)?;
+            emit_quest_completed(env, player, epoch, index, quest.bonus_fp);
+        }
+    }
+
+    if !changed {
+        return Ok(());
+    }
+    storage::set_quest_progress(env, epoch, player, &progress);
+
+    if bonus_fp > 0 {
+        let mut epoch_player =
+            storage::get_epoch_player(env, epoch, player).ok_or(Error::PlayerNotFound)?;
+        epoch_player.available_fp = epoch_player
+            .available_fp
+            .checked_add(bonus_fp)
+            .ok_or(Error::OverflowError)?;
+        storage::set_epoch_player(env, epoch, player, &epoch_player);
+    }
+
+    Ok(())
+}
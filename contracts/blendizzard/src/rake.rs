@@ -0,0 +1,194 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::Error;
+use crate::events::emit_rake_routed;
+use crate::math::{checked_bps, checked_mul_div, Rounding, BPS_SCALE};
+use crate::storage;
+use crate::types::{Config, EpochInfo, EpochPlayer, FactionRake, RakeRoute};
+
+// ============================================================================
+// Game Rake
+// ============================================================================
+//
+// A game can take `GameInfo::rake_bps` of every wager placed on it. At
+// settlement both players' raked FP is recorded against their own faction in
+// `EpochInfo::faction_rakes`, by the game's `rake_route`, and the winner only
+// contributes what's left of their wager (so the rake never reaches the
+// faction standings or reward weights).
+//
+// Wagers are FP, so the rake is paid in USDC when the epoch cycles: the
+// player reward pool is split between the winning faction's standing and
+// every faction's raked FP, and each faction's raked part is routed to the
+// next reward pool, that faction's treasury, the protocol treasury (see
+// `protocol_treasury.rs`) or burned, less the insurance fund's share (see
+// `insurance.rs`).
+
+/// Largest allowed rake (100%)
+pub(crate) const MAX_RAKE_BPS: u32 = BPS_SCALE as u32;
+
+/// Take the game's rake from a player's wager
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Game the session was played on
+/// * `current_epoch` - Current epoch number
+/// * `player_epoch` - Player's epoch data (faction locked)
+/// * `wager` - FP the player wagered
+///
+/// # Returns
+/// FP raked from the wager
+pub(crate) fn take_rake(
+    env: &Env,
+    game_id: &Address,
+    current_epoch: u32,
+    player_epoch: &EpochPlayer,
+    wager: i128,
+) -> Result<i128, Error> {
+    // Sessions of a removed game settle without a rake
+    let Some(game_info) = storage::get_game_info(env, game_id) else {
+        return Ok(0);
+    };
+    if game_info.rake_bps == 0 || wager <= 0 {
+        return Ok(0);
+    }
+
+    let rake =
+        checked_bps(wager, game_info.rake_bps, Rounding::Floor).ok_or(Error::OverflowError)?;
+    if rake == 0 {
+        return Ok(0);
+    }
+
+    let faction = player_epoch
+        .epoch_faction
+        .ok_or(Error::FactionNotSelected)?;
+    let mut epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+    let mut raked = epoch_info
+        .faction_rakes
+        .get(faction)
+        .unwrap_or(FactionRake {
+            reward_pool: 0,
+            treasury: 0,
+            burn: 0,
+            protocol: 0,
+        });
+    let bucket = match game_info.rake_route {
+        RakeRoute::RewardPool => &mut raked.reward_pool,
+        RakeRoute::Treasury => &mut raked.treasury,
+        RakeRoute::Burn => &mut raked.burn,
+        RakeRoute::Protocol => &mut raked.protocol,
+    };
+    *bucket = bucket.checked_add(rake).ok_or(Error::OverflowError)?;
+    epoch_info.faction_rakes.set(faction, raked);
+    storage::set_epoch(env, current_epoch, &epoch_info);
+
+    Ok(rake)
+}
+
+/// Pay out every faction's raked share of a closing epoch's player pool
+///
+/// The pool is split pro rata between the winning faction's standing and all
+/// FP raked in the epoch.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (USDC token)
+/// * `epoch` - Epoch being closed
+/// * `epoch_info` - The closing epoch
+/// * `winning_faction` - The closing epoch's winner
+/// * `player_reward_pool` - Player reward pool before the rake
+///
+/// # Returns
+/// USDC routed away (to deduct from the player reward pool)
+pub(crate) fn route_rakes(
+    env: &Env,
+    config: &Config,
+    epoch: u32,
+    epoch_info: &EpochInfo,
+    winning_faction: u32,
+    player_reward_pool: i128,
+) -> Result<i128, Error> {
+    if epoch_info.faction_rakes.is_empty() || player_reward_pool <= 0 {
+        return Ok(0);
+    }
+
+    let standing = epoch_info
+        .faction_standings
+        .get(winning_faction)
+        .unwrap_or(0);
+    let mut total = standing;
+    for raked in epoch_info.faction_rakes.values() {
+        total = [
+            total,
+            raked.reward_pool,
+            raked.treasury,
+            raked.burn,
+            raked.protocol,
+        ]
+        .into_iter()
+        .try_fold(0i128, |total, fp| total.checked_add(fp))
+        .ok_or(Error::OverflowError)?;
+    }
+    if total == 0 {
+        return Ok(0);
+    }
+
+    let share = |fp: i128| {
+        checked_mul_div(player_reward_pool, fp, total, Rounding::Floor).ok_or(Error::OverflowError)
+    };
+    // The insurance fund skims its share of every route first
+    let insurance_bps = storage::get_insurance_fund(env).bps;
+    let mut insured = 0;
+    let mut route = |fp: i128| -> Result<i128, Error> {
+        let amount = share(fp)?;
+        let skimmed =
+            checked_bps(amount, insurance_bps, Rounding::Floor).ok_or(Error::OverflowError)?;
+        insured += skimmed;
+        Ok(amount - skimmed)
+    };
+
+    let mut to_reward_pool: i128 = 0;
+    let mut to_treasury: i128 = 0;
+    let mut burned: i128 = 0;
+    let mut to_protocol: i128 = 0;
+    let mut treasuries = storage::get_faction_treasuries(env);
+    for (faction, raked) in epoch_info.faction_rakes.iter() {
+        to_reward_pool += route(raked.reward_pool)?;
+        burned += route(raked.burn)?;
+        to_protocol += route(raked.protocol)?;
+
+        // Each faction's treasury-routed rake goes to its own treasury
+        let faction_treasury = route(raked.treasury)?;
+        if faction_treasury > 0 {
+            let balance = treasuries
+                .balances
+                .get(faction)
+                .unwrap_or(0)
+                .checked_add(faction_treasury)
+                .ok_or(Error::OverflowError)?;
+            treasuries.balances.set(faction, balance);
+            to_treasury += faction_treasury;
+        }
+    }
+    crate::insurance::fund(env, epoch, insured)?;
+
+    if to_reward_pool > 0 {
+        let pending = storage::get_pending_rewards(env)
+            .checked_add(to_reward_pool)
+            .ok_or(Error::OverflowError)?;
+        storage::set_pending_rewards(env, pending);
+    }
+
+    if to_treasury > 0 {
+        storage::set_faction_treasuries(env, &treasuries);
+    }
+
+    if burned > 0 {
+        token::Client::new(env, &config.usdc_token).burn(&env.current_contract_address(), &burned);
+    }
+
+    crate::protocol_treasury::fund(env, epoch, &config.usdc_token, to_protocol)?;
+
+    emit_rake_routed(env, epoch, to_reward_pool, to_treasury, burned, to_protocol);
+
+    Ok(to_reward_pool + to_treasury + burned + to_protocol + insured)
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::storage;
+
+// ============================================================================
+// Randomness for Games
+// ============================================================================
+//
+// Registered games can draw coin flips, dice rolls, shuffles and matchmaking
+// tiebreaks through Blendizzard instead of rolling their own. Each draw reseeds
+// this contract's PRNG from the host PRNG plus the session ID, then uses the
+// SDK's unbiased `gen_range` / `shuffle`.
+//
+// The host PRNG is seeded per transaction: results can't be predicted or
+// chosen by the players, but they differ between simulation and submission.
+// Games must act on the submitted result, never on a simulated one. Games that
+// need a result reproducible from public inputs (like number-guess) should
+// keep seeding their own PRNG from committed data.
+
+/// Draw a random integer in `min..=max` for a session
+///
+/// # Errors
+/// * `InvalidAmount` - If min > max
+/// * Same as `seed_for_session`
+pub(crate) fn random_range(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    min: u64,
+    max: u64,
+) -> Result<u64, ContractError> {
+    if min > max {
+        return Err(Error::InvalidAmount.into());
+    }
+    seed_for_session(env, game_id, session_id)?;
+    Ok(env.prng().gen_range::<u64>(min..=max))
+}
+
+/// Shuffle values for a session (e.g. a deck or a matchmaking queue)
+///
+/// # Errors
+/// * Same as `seed_for_session`
+pub(crate) fn shuffle(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    values: Vec<u32>,
+) -> Result<Vec<u32>, ContractError> {
+    seed_for_session(env, game_id, session_id)?;
+    let mut values = values;
+    env.prng().shuffle(&mut values);
+    Ok(values)
+}
+
+/// Reseed the PRNG for a draw by a session's game
+///
+/// # Errors
+/// * `SessionNotFound` - If no two-player or team session has this ID
+/// * `SessionGameMismatch` - If the session belongs to another game
+fn seed_for_session(env: &Env, game_id: &Address, session_id: u32) -> Result<(), ContractError> {
+    game_id.require_auth();
+
+    let session_game = storage::get_session(env, session_id)
+        .map(|session| session.game_id)
+        .or_else(|| storage::get_team_session(env, session_id).map(|session| session.game_id))
+        .ok_or(Error::SessionNotFound)?;
+    if session_game != *game_id {
+        return Err(ExtError::SessionGameMismatch.into());
+    }
+
+    let mut seed = Bytes::from(env.prng().gen::<BytesN<32>>());
+    seed.extend_from_array(&session_id.to_be_bytes());
+    env.prng().seed(env.crypto().keccak256(&seed).into());
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+
+// ============================================================================
+// Elo Ratings
+// ============================================================================
+//
+// Each player has an independent rating per game contract, updated when a
+// session ends. Ratings are integer-only:
+//
+//   expected = 1 / (1 + 10^((opponent - rating) / 400))
+//   change   = K * (1 - expected_winner)
+//
+// `expected` is read from a lookup table (basis points, 25-point steps) with
+// linear interpolation, which is accurate to within 1 bp of the float formula.
+// Ratings are zero-sum: the loser loses exactly what the winner gains.
+
+/// Rating assigned to a player's first game on a given game contract
+pub(crate) const INITIAL_RATING: u32 = 1200;
+
+/// Maximum rating change per game (K-factor)
+const K_FACTOR: u32 = 32;
+
+/// Basis points denominator (10000 = 100%)
+const BPS: u32 = 10_000;
+
+/// Rating difference step between table entries
+const TABLE_STEP: u32 = 25;
+
+/// Expected score (bps) of the LOWER rated player for a rating gap of
+/// 0, 25, 50, ..., 800. Gaps beyond 800 are clamped.
+const EXPECTED_SCORE_BPS: [u32; 33] = [
+    5000, 4641, 4285, 3937, 3599, 3275, 2966, 2675, 2403, 2150, 1917, 1704, 1510, 1334, 1177, 1035,
+    909, 797, 698, 610, 532, 464, 405, 352, 307, 267, 232, 201, 175, 152, 132, 114, 99,
+];
+
+/// Get a player's rating for a game (INITIAL_RATING if they haven't played it)
+pub(crate) fn get_rating(env: &Env, player: &Address, game_id: &Address) -> u32 {
+    storage::get_rating(env, player, game_id).unwrap_or(INITIAL_RATING)
+}
+
+/// Update both players' ratings for a finished game
+///
+/// # Returns
+/// (winner_new_rating, loser_new_rating, rating_change)
+pub(crate) fn update_ratings(
+    env: &Env,
+    game_id: &Address,
+    winner: &Address,
+    loser: &Address,
+) -> (u32, u32, u32) {
+    let winner_rating = get_rating(env, winner, game_id);
+    let loser_rating = get_rating(env, loser, game_id);
+
+    let change = rating_change(winner_rating, loser_rating);
+
+    let new_winner_rating = winner_rating.saturating_add(change);
+    let new_loser_rating = loser_rating.saturating_sub(change);
+
+    storage::set_rating(env, winner, game_id, new_winner_rating);
+    storage::set_rating(env, loser, game_id, new_loser_rating);
+
+    (new_winner_rating, new_loser_rating, change)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Rating points the winner gains (and loser loses), rounded to nearest
+pub(crate) fn rating_change(winner_rating: u32, loser_rating: u32) -> u32 {
+    let expected = expected_score_bps(winner_rating, loser_rating);
+    (K_FACTOR * (BPS - expected) + BPS / 2) / BPS
+}
+
+/// Expected score of `rating` against `opponent`, in basis points
+pub(crate) fn expected_score_bps(rating: u32, opponent: u32) -> u32 {
+    let gap = rating.abs_diff(opponent);
+    let max_gap = TABLE_STEP * (EXPECTED_SCORE_BPS.len() as u32 - 1);
+
+    let lower_expected = if gap >= max_gap {
+        EXPECTED_SCORE_BPS[EXPECTED_SCORE_BPS.len() - 1]
+    } else {
+        // Linear interpolation between neighbouring table entries
+        let idx = (gap / TABLE_STEP) as usize;
+        let rem = gap % TABLE_STEP;
+        let hi = EXPECTED_SCORE_BPS[idx];
+        let lo = EXPECTED_SCORE_BPS[idx + 1];
+        hi - (hi - lo) * rem / TABLE_STEP
+    };
+
+    if rating <= opponent {
+        lower_expected
+    } else {
+        BPS - lower_expected
+    }
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::Env;
+
+use crate::errors::{ContractError, ExtError};
+use crate::storage;
+
+// ============================================================================
+// Reentrancy Guard
+// ============================================================================
+//
+// Soroban already rejects a contract calling back into itself, but
+// `cycle_epoch`, `withdraw` and `end_game` call out to the vault backend,
+// Soroswap and game contracts. The guard makes the "one fund-moving call at a
+// time" ordering explicit instead of relying on the host alone: while one of
+// them runs, any other guarded entrypoint fails with `Reentrancy`.
+//
+// The flag is cleared when the call returns. A failed call reverts its storage
+// writes, so the flag can't be left set.
+
+/// Run `f` with the reentrancy guard held
+///
+/// # Errors
+/// * `Reentrancy` - If another guarded call is already in progress
+/// * Whatever `f` returns
+pub(crate) fn non_reentrant<T, E: Into<ContractError>>(
+    env: &Env,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, ContractError> {
+    if storage::is_in_flight(env) {
+        return Err(ExtError::Reentrancy.into());
+    }
+    storage::set_in_flight(env, true);
+    let result = f();
+    storage::set_in_flight(env, false);
+    result.map_err(Into::into)
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::events::{
+    emit_referral_reward_accrued, emit_referral_rewards_claimed, emit_referrer_set,
+};
+use crate::math::{checked_mul_scalar_7, Rounding};
+use crate::storage;
+use crate::types::Config;
+
+// ============================================================================
+// Referrals
+// ============================================================================
+//
+// A player can name a referrer once. From then on, `config.referral_share` of
+// every epoch reward the player claims is credited to the referrer instead.
+// Referral credits stay in this contract (as USDC) until the referrer calls
+// `claim_referral_rewards`, which deposits them into the fee-vault on their behalf.
+
+/// Set the referrer for a player (one-time)
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player being referred
+/// * `referrer` - Address that referred the player
+///
+/// # Errors
+/// * `InvalidReferrer` - If the player refers themselves
+/// * `ReferrerAlreadySet` - If the player already has a referrer
+pub(crate) fn set_referrer(env: &Env, player: &Address, referrer: &Address) -> Result<(), Error> {
+    // Authenticate player
+    player.require_auth();
+
+    if player == referrer {
+        return Err(Error::InvalidReferrer);
+    }
+
+    // Write-once: referrers can't be swapped to redirect future kickbacks
+    if storage::get_referrer(env, player).is_some() {
+        return Err(Error::ReferrerAlreadySet);
+    }
+
+    storage::set_referrer(env, player, referrer);
+
+    emit_referrer_set(env, player, referrer);
+
+    Ok(())
+}
+
+/// Split a claimed reward between the player and their referrer
+///
+/// Credits the referrer's share to their referral balance and returns the
+/// remainder to be paid to the player. Players without a referrer (or with
+/// referrals disabled) keep the full amount.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (referral share)
+/// * `player` - Player claiming the reward
+/// * `epoch` - Epoch the reward is claimed from
+/// * `amount` - Full reward amount
+///
+/// # Returns
+/// Amount to pay the player
+pub(crate) fn apply_referral_share(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+) -> Result<i128, Error> {
+    if config.referral_share == 0 {
+        return Ok(amount);
+    }
+
+    let Some(referrer) = storage::get_referrer(env, player) else {
+        return Ok(amount);
+    };
+
+    let referral_amount = checked_mul_scalar_7(amount, config.referral_share, Rounding::Floor)
+        .ok_or(Error::OverflowError)?;
+    if referral_amount == 0 {
+        return Ok(amount);
+    }
+
+    let balance = storage::get_referral_balance(env, &referrer);
+    storage::set_referral_balance(
+        env,
+        &referrer,
+        balance
+            .checked_add(referral_amount)
+            .ok_or(Error::OverflowError)?,
+    );
+
+    emit_referral_reward_accrued(env, &referrer, player, epoch, referral_amount);
+
+    Ok(amount - referral_amount)
+}
+
+/// Claim all accrued referral rewards
+///
+/// Deposits the referrer's balance into the fee-vault on their behalf
+/// (same payout path as `claim_epoch_reward`).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `referrer` - Referrer claiming their kickbacks
+///
+/// # Returns
+/// Amount of USDC claimed and deposited into fee-vault
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If the referrer has no accrued rewards
+pub(crate) fn claim_referral_rewards(env: &Env, referrer: &Address) -> Result<i128, Error> {
+    // Authenticate referrer
+    referrer.require_auth();
+
+    let amount = storage::get_referral_balance(env, referrer);
+    if amount <= 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    // Zero the balance before paying out
+    storage::set_referral_balance(env, referrer, 0);
+
+    let config = storage::get_config(env);
+    crate::vault::deposit_for_player(env, &config, referrer, amount)?;
+
+    emit_referral_rewards_claimed(env, referrer, amount);
+
+    Ok(amount)
+}
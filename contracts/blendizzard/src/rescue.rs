@@ -0,0 +1,88 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::Error;
+use crate::events::emit_token_rescued;
+use crate::storage;
+
+// ============================================================================
+// Stranded Token Rescue
+// ============================================================================
+//
+// The contract only ever needs to hold USDC (reward pools, unclaimed rewards,
+// referral balances, prediction stakes, dispute bonds, faction treasuries, the
+// protocol treasury, keeper bonds, the jackpot) and BLND (in-epoch yield and carryover), plus the
+// amounts of whitelisted reward tokens owed to reward pools. Anything else that
+// ends up here was airdropped or sent by mistake and can be returned.
+
+/// Transfer a stranded token out of the contract
+///
+/// Refuses the reward asset (USDC), BLND and the fee-vault, leaves the amounts
+/// of reward tokens owed to reward pools in place, and verifies after
+/// the transfer that the contract's USDC and BLND balances are unchanged (so a
+/// token that forwards to them can't be used to drain them either).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `token` - Token contract to rescue
+/// * `to` - Recipient
+/// * `amount` - Amount to transfer
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `TokenNotRescuable` - If the token is protected, the amount is owed to
+///   reward pools or protected balances moved
+/// * `InvalidAmount` - If amount <= 0
+/// * `InsufficientBalance` - If amount exceeds the contract's balance
+pub(crate) fn rescue_token(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Authenticate admin
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let config = storage::get_config(env);
+    if *token == config.usdc_token || *token == config.blnd_token || *token == config.fee_vault {
+        return Err(Error::TokenNotRescuable);
+    }
+
+    let current_contract = env.current_contract_address();
+    let token_client = token::Client::new(env, token);
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    let balance = token_client.balance(&current_contract);
+    if amount > balance {
+        return Err(Error::InsufficientBalance);
+    }
+    if amount > balance - crate::reward_tokens::reserved(env, token) {
+        return Err(Error::TokenNotRescuable);
+    }
+
+    // Snapshot protected balances
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    let blnd_client = token::Client::new(env, &config.blnd_token);
+    let usdc_before = usdc_client.balance(&current_contract);
+    let blnd_before = blnd_client.balance(&current_contract);
+
+    token_client.transfer(&current_contract, to, &amount);
+
+    if usdc_client.balance(&current_contract) != usdc_before
+        || blnd_client.balance(&current_contract) != blnd_before
+    {
+        return Err(Error::TokenNotRescuable);
+    }
+
+    crate::audit::record(
+        env,
+        &admin,
+        "rescue_token",
+        &(),
+        &(token.clone(), to.clone(), amount),
+    );
+    emit_token_rescued(env, token, to, amount);
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use soroban_sdk::{Address, Env, Map, Vec};
+
+use crate::errors::Error;
+use crate::math::{checked_bps, checked_fixed_sqrt, Rounding, BPS_SCALE};
+use crate::storage;
+use crate::streak::reward_weight;
+use crate::types::{DistributionMode, EpochPlayer, SCALAR_7};
+
+// ============================================================================
+// Reward Cap
+// ============================================================================
+//
+// With a reward cap set (`set_reward_cap`), no address receives more than
+// that share of a Linear or Quadratic epoch's reward pools. The excess goes,
+// pro-rata, to the winning faction's other members. Capping works like water
+// filling: the largest weight is capped if its share of what is left exceeds
+// the cap, which raises everyone else's share. Repeat until the next weight
+// fits.
+//
+// Only players among the top weights can end up capped (fewer than one per
+// cap share of the pool), so each faction's `MAX_TOP_CONTRIBUTORS` largest
+// reward weights are tracked as sessions settle. That is why the cap can't
+// go below `MIN_REWARD_CAP_BPS`. The cap is snapshotted when an epoch opens,
+// so a change applies from the next epoch.
+//
+// If every member is capped (a faction smaller than one member per cap share),
+// the rest of the pool stays unclaimed and is carried over once the claim
+// window passes. WinnerTakeAll and Merkle-distributed epochs aren't capped.
+
+/// Number of largest reward weights tracked per faction and epoch
+const MAX_TOP_CONTRIBUTORS: u32 = 20;
+
+/// Smallest cap (5%): fewer than `MAX_TOP_CONTRIBUTORS` addresses can exceed it
+pub(crate) const MIN_REWARD_CAP_BPS: u32 = (BPS_SCALE as u32) / MAX_TOP_CONTRIBUTORS;
+
+/// Snapshot the reward cap for a newly opened epoch
+pub(crate) fn open_epoch(env: &Env, epoch: u32) {
+    let cap_bps = storage::get_reward_cap(env);
+    if cap_bps > 0 {
+        storage::set_epoch_reward_cap(env, epoch, cap_bps);
+    }
+}
+
+/// Track a player's reward weight after it changed (eligible players only)
+///
+/// # Errors
+/// * `OverflowError` - If the weight overflows
+pub(crate) fn record_weight(
+    env: &Env,
+    epoch: u32,
+    mode: DistributionMode,
+    faction: u32,
+    player: &Address,
+    epoch_player: &EpochPlayer,
+) -> Result<(), Error> {
+    if mode == DistributionMode::WinnerTakeAll || storage::get_epoch_reward_cap(env, epoch) == 0 {
+        return Ok(());
+    }
+
+    let weight = player_weight(mode, epoch_player)?;
+    let mut top = storage::get_top_contributors(env, epoch, faction);
+    top.set(player.clone(), weight);
+    if top.len() > MAX_TOP_CONTRIBUTORS {
+        let mut smallest: Option<(Address, i128)> = None;
+        for (address, weight) in top.iter() {
+            let is_smaller = match &smallest {
+                Some((_, min)) => weight < *min,
+                None => true,
+            };
+            if is_smaller {
+                smallest = Some((address, weight));
+            }
+        }
+        if let Some((address, _)) = smallest {
+            top.remove(address);
+        }
+    }
+    storage::set_top_contributors(env, epoch, faction, &top);
+
+    Ok(())
+}
+
+/// Reward weight of a player under an epoch's distribution mode
+fn player_weight(mode: DistributionMode, epoch_player: &EpochPlayer) -> Result<i128, Error> {
+    let contribution = match mode {
+        DistributionMode::Quadratic => {
+            checked_fixed_sqrt(epoch_player.total_fp_contributed, SCALAR_7)
+                .ok_or(Error::OverflowError)?
+        }
+        _ => epoch_player.total_fp_contributed,
+    };
+    reward_weight(contribution, epoch_player.streak_bonus).ok_or(Error::OverflowError)
+}
+
+/// Get a winning-faction member's share of a pool, applying the epoch's cap
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Finalized epoch
+/// * `faction` - Winning faction
+/// * `player` - Claiming member
+/// * `weight` - Member's reward weight
+/// * `total_weight` - Faction's total reward weight
+/// * `pools` - Pools to split (USDC, BLND, then extra reward tokens)
+///
+/// # Returns
+/// The member's share of each pool, in pool order
+///
+/// # Errors
+/// * `OverflowError` - If a calculation overflows
+/// * `DivisionByZero` - If total_weight is 0
+pub(crate) fn reward_shares(
+    env: &Env,
+    epoch: u32,
+    faction: u32,
+    player: &Address,
+    weight: i128,
+    total_weight: i128,
+    pools: &Vec<i128>,
+) -> Result<Vec<i128>, Error> {
+    let mut shares = Vec::new(env);
+    let cap_bps = storage::get_epoch_reward_cap(env, epoch);
+    if cap_bps == 0 {
+        for pool in pools.iter() {
+            shares.push_back(crate::rewards::calculate_reward_share(
+                weight,
+                total_weight,
+                pool,
+            )?);
+        }
+        return Ok(shares);
+    }
+
+    let (capped, uncapped_weight) = capped_members(env, epoch, faction, cap_bps, total_weight)?;
+    let capped_count = capped.len() as i128;
+    for pool in pools.iter() {
+        let cap_amount = checked_bps(pool, cap_bps, Rounding::Floor).ok_or(Error::OverflowError)?;
+        let share = if capped.contains(player) {
+            cap_amount
+        } else if uncapped_weight == 0 {
+            0
+        } else {
+            let uncapped_pool = cap_amount
+                .checked_mul(capped_count)
+                .and_then(|capped_total| pool.checked_sub(capped_total))
+                .ok_or(Error::OverflowError)?;
+            crate::rewards::calculate_reward_share(weight, uncapped_weight, uncapped_pool)?
+        };
+        shares.push_back(share);
+    }
+
+    Ok(shares)
+}
+
+/// Get the capped members of a winning faction and the uncapped members' total weight
+fn capped_members(
+    env: &Env,
+    epoch: u32,
+    faction: u32,
+    cap_bps: u32,
+    total_weight: i128,
+) -> Result<(Vec<Address>, i128), Error> {
+    let mut top = storage::get_top_contributors(env, epoch, faction);
+    let cap = cap_bps as i128;
+    let mut capped = Vec::new(env);
+    let mut remaining_bps = BPS_SCALE;
+    let mut remaining_weight = total_weight;
+
+    // Largest weights first, while their share of what's left exceeds the cap
+    while let Some((address, weight)) = largest(&top) {
+        let share = weight
+            .checked_mul(remaining_bps)
+            .ok_or(Error::OverflowError)?;
+        let capped_share = cap
+            .checked_mul(remaining_weight)
+            .ok_or(Error::OverflowError)?;
+        if share <= capped_share {
+            break;
+        }
+        remaining_bps -= cap;
+        remaining_weight = remaining_weight
+            .checked_sub(weight)
+            .ok_or(Error::OverflowError)?;
+        top.remove(address.clone());
+        capped.push_back(address);
+    }
+
+    Ok((capped, remaining_weight.max(0)))
+}
+
+/// Get the entry with the largest weight (the first address on ties)
+fn largest(top: &Map<Address, i128>) -> Option<(Address, i128)> {
+    let mut largest: Option<(Address, i128)> = None;
+    for (address, weight) in top.iter() {
+        let is_larger = match &largest {
+            Some((_, max)) => weight > *max,
+            None => true,
+        };
+        if is_larger {
+            largest = Some((address, weight));
+        }
+    }
+    largest
+}
@@ -0,0 +1,248 @@
+use soroban_sdk::{token, Address, Env, Map, Vec};
+
+use crate::errors::Error;
+use crate::events::emit_reward_tokens_claimed;
+use crate::math::{checked_mul_div, Rounding};
+use crate::storage;
+use crate::types::{RewardTokenPool, TokenAmount};
+
+// ============================================================================
+// Extra Reward Tokens
+// ============================================================================
+//
+// Besides USDC (`EpochInfo::reward_pool`) and retained BLND
+// (`EpochInfo::reward_pool_blnd`), an epoch pays out pools of the tokens the
+// admin whitelisted with `set_reward_tokens` (e.g. sponsor boosts in a
+// partner's token). They are never swapped:
+//
+// 1. Donations accumulate in `PendingRewardTokens` until the epoch cycles,
+//    then become the closed epoch's pools (`EpochRewardTokens`). They go to
+//    players only: no developer, treasury or jackpot share.
+// 2. Claims pay every pool in the same pass as USDC, with the same weights
+//    and reward cap; Merkle claims pay pro rata to the player's share of the
+//    USDC pool, as for BLND.
+// 3. Pools expire with the epoch's claim window, and the unclaimed remainder
+//    goes back to `PendingRewardTokens` for the next epoch.
+//
+// Amounts owed to pools and players are tracked in `RewardTokenReserves`, so
+// `rescue_token` can't take them.
+
+/// Maximum number of whitelisted reward tokens
+pub(crate) const MAX_REWARD_TOKENS: u32 = 3;
+
+/// Replace the whitelisted reward tokens
+///
+/// Caller must have authenticated the admin. Removing a token stops new
+/// donations; its pending and epoch pools are still paid out.
+///
+/// # Errors
+/// * `InvalidConfig` - If there are too many tokens, a token repeats or is
+///   USDC, BLND or the fee-vault
+pub(crate) fn set_reward_tokens(env: &Env, tokens: &Vec<Address>) -> Result<(), Error> {
+    if tokens.len() > MAX_REWARD_TOKENS {
+        return Err(Error::InvalidConfig);
+    }
+    let config = storage::get_config(env);
+    for (i, token) in tokens.iter().enumerate() {
+        if tokens.first_index_of(&token) != Some(i as u32)
+            || token == config.usdc_token
+            || token == config.blnd_token
+            || token == config.fee_vault
+        {
+            return Err(Error::InvalidConfig);
+        }
+    }
+
+    storage::set_reward_tokens(env, tokens);
+    Ok(())
+}
+
+/// Check if a token is whitelisted as a reward token
+pub(crate) fn is_reward_token(env: &Env, token: &Address) -> bool {
+    storage::get_reward_tokens(env).contains(token)
+}
+
+/// Get the amount of a token owed to pools and players
+pub(crate) fn reserved(env: &Env, token: &Address) -> i128 {
+    storage::get_reward_token_reserves(env)
+        .get(token.clone())
+        .unwrap_or(0)
+}
+
+/// Add tokens (already held by this contract) to the next epoch cycle's pools
+///
+/// # Errors
+/// * `OverflowError` - If a total overflows
+pub(crate) fn add_pending(env: &Env, token: &Address, amount: i128) -> Result<(), Error> {
+    let mut pending = storage::get_pending_reward_tokens(env);
+    let total = pending
+        .get(token.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    pending.set(token.clone(), total);
+    storage::set_pending_reward_tokens(env, &pending);
+
+    let mut reserves = storage::get_reward_token_reserves(env);
+    let reserve = reserves
+        .get(token.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    reserves.set(token.clone(), reserve);
+    storage::set_reward_token_reserves(env, &reserves);
+
+    Ok(())
+}
+
+/// Turn the pending tokens into the pools of the epoch being finalized
+pub(crate) fn open_pools(env: &Env, epoch: u32) {
+    let pending = storage::get_pending_reward_tokens(env);
+    if pending.is_empty() {
+        return;
+    }
+
+    let mut pools = Vec::new(env);
+    for (token, amount) in pending.iter() {
+        if amount > 0 {
+            pools.push_back(RewardTokenPool {
+                token,
+                amount,
+                claimed: 0,
+            });
+        }
+    }
+    storage::set_epoch_reward_tokens(env, epoch, &pools);
+    storage::set_pending_reward_tokens(env, &Map::new(env));
+}
+
+/// Hand the unclaimed remainder of an expired epoch's pools back to pending
+///
+/// # Errors
+/// * `OverflowError` - If a total overflows
+pub(crate) fn sweep_expired(env: &Env, epoch: u32) -> Result<(), Error> {
+    let pools = storage::get_epoch_reward_tokens(env, epoch);
+    if pools.is_empty() {
+        return Ok(());
+    }
+
+    let mut pending = storage::get_pending_reward_tokens(env);
+    for pool in pools.iter() {
+        let unclaimed = pool.amount.saturating_sub(pool.claimed).max(0);
+        if unclaimed == 0 {
+            continue;
+        }
+        let total = pending
+            .get(pool.token.clone())
+            .unwrap_or(0)
+            .checked_add(unclaimed)
+            .ok_or(Error::OverflowError)?;
+        pending.set(pool.token, total);
+    }
+    storage::set_pending_reward_tokens(env, &pending);
+    Ok(())
+}
+
+/// Shares of an epoch's pools for a Merkle claim of `amount` out of the USDC
+/// `reward_pool`, in pool order
+pub(crate) fn pro_rata_shares(
+    env: &Env,
+    pools: &Vec<RewardTokenPool>,
+    amount: i128,
+    reward_pool: i128,
+) -> Vec<i128> {
+    let mut shares = Vec::new(env);
+    for pool in pools.iter() {
+        shares.push_back(
+            checked_mul_div(amount, pool.amount, reward_pool, Rounding::Floor).unwrap_or(0),
+        );
+    }
+    shares
+}
+
+/// Transfer a player's shares of an epoch's pools
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player claiming
+/// * `epoch` - Epoch claimed from
+/// * `pools` - The epoch's pools
+/// * `shares` - The player's share of each pool, in pool order
+///
+/// # Returns
+/// The tokens paid
+///
+/// # Errors
+/// * `OverflowError` - If a total overflows
+pub(crate) fn pay_shares(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    pools: &Vec<RewardTokenPool>,
+    shares: &Vec<i128>,
+) -> Result<Vec<TokenAmount>, Error> {
+    let mut pools = pools.clone();
+    let mut reserves = storage::get_reward_token_reserves(env);
+    let mut paid = Vec::new(env);
+    for (i, share) in shares.iter().enumerate() {
+        if share <= 0 {
+            continue;
+        }
+        let mut pool = pools.get_unchecked(i as u32);
+        pool.claimed = pool
+            .claimed
+            .checked_add(share)
+            .ok_or(Error::OverflowError)?;
+        pools.set(i as u32, pool.clone());
+
+        let reserve = reserves.get(pool.token.clone()).unwrap_or(0);
+        reserves.set(pool.token.clone(), reserve.saturating_sub(share).max(0));
+
+        token::Client::new(env, &pool.token).transfer(
+            &env.current_contract_address(),
+            player,
+            &share,
+        );
+        paid.push_back(TokenAmount {
+            token: pool.token,
+            amount: share,
+        });
+    }
+
+    if !paid.is_empty() {
+        storage::set_epoch_reward_tokens(env, epoch, &pools);
+        storage::set_reward_token_reserves(env, &reserves);
+        emit_reward_tokens_claimed(env, player, epoch, &paid);
+    }
+
+    Ok(paid)
+}
+
+/// Get every reward pool of a finalized epoch: USDC, BLND (if any), then the
+/// extra reward tokens
+///
+/// # Errors
+/// * `EpochNotFinalized` - If the epoch doesn't exist
+pub(crate) fn get_epoch_rewards(env: &Env, epoch: u32) -> Result<Vec<TokenAmount>, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let config = storage::get_config(env);
+
+    let mut rewards = Vec::new(env);
+    rewards.push_back(TokenAmount {
+        token: config.usdc_token,
+        amount: epoch_info.reward_pool,
+    });
+    if epoch_info.reward_pool_blnd > 0 {
+        rewards.push_back(TokenAmount {
+            token: config.blnd_token,
+            amount: epoch_info.reward_pool_blnd,
+        });
+    }
+    for pool in storage::get_epoch_reward_tokens(env, epoch).iter() {
+        rewards.push_back(TokenAmount {
+            token: pool.token,
+            amount: pool.amount,
+        });
+    }
+    Ok(rewards)
+}
@@ -1,11 +1,14 @@
-use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{token, Address, Env};
-
-use crate::errors::Error;
-use crate::events::{emit_dev_reward_claimed, emit_rewards_claimed};
-use crate::fee_vault_v2::Client as FeeVaultClient;
+use soroban_sdk::{token, vec, Address, Env, Vec};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{
+    emit_claimer_approved, emit_claimer_revoked, emit_delegated_claim, emit_dev_reward_claimed,
+    emit_rewards_claimed,
+};
+use crate::math::{checked_fixed_sqrt, checked_mul_div, checked_mul_scalar_7, Rounding};
 use crate::storage;
-use crate::types::SCALAR_7;
+use crate::streak::reward_weight;
+use crate::types::{ClaimRecord, Config, DistributionMode, SCALAR_7};
 
 // ============================================================================
 // Reward Distribution
@@ -31,18 +34,28 @@ use crate::types::SCALAR_7;
 /// - Authorization for `blendizzard.claim_epoch_reward()`
 /// - Authorization for `fee_vault.deposit()`
 ///
-/// Formula:
+/// Formula (depends on the epoch's `DistributionMode`):
 /// ```
-/// player_reward = (player_fp_contributed / total_winning_faction_fp) * reward_pool
+/// Linear:        player_reward = (player_fp / total_winning_faction_fp) * reward_pool
+/// Quadratic:     player_reward = (sqrt(player_fp) / sum(sqrt(faction_fp))) * reward_pool
+/// WinnerTakeAll: player_reward = reward_pool (top contributor only)
 /// ```
 ///
+/// In Linear and Quadratic modes each player's weight (and the faction total) is
+/// scaled by (1 + streak_bonus) for consecutive-epoch participation.
+/// With a reward cap, no player gets more than the cap's share of a pool and
+/// the excess is split among the rest (see `reward_cap.rs`).
+///
 /// # Arguments
 /// * `env` - Contract environment
 /// * `player` - Player claiming rewards
 /// * `epoch` - Epoch number to claim from
 ///
 /// # Returns
-/// Amount of USDC claimed and deposited into fee-vault
+/// Amount of USDC deposited into fee-vault (net of any referral share). Claims
+/// above `config.vesting_threshold` are vested instead (see `claim_vested`).
+/// The player's share of the epoch's `reward_pool_blnd` is transferred as BLND,
+/// and their share of each extra reward token pool in that token.
 ///
 /// # Errors
 /// * `DepositRequiredToClaim` - If player's vault balance is below minimum threshold
@@ -50,10 +63,137 @@ use crate::types::SCALAR_7;
 /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
 /// * `NotWinningFaction` - If player wasn't in the winning faction
 /// * `NoRewardsAvailable` - If player has no rewards to claim
+/// * `MerkleDistributionActive` - If the epoch is settled via Merkle root
+/// * `ClaimWindowExpired` - If the epoch's claim window has passed
 pub(crate) fn claim_epoch_reward(env: &Env, player: &Address, epoch: u32) -> Result<i128, Error> {
     // Authenticate player
     player.require_auth();
 
+    settle_epoch_reward(env, player, epoch, true)
+}
+
+/// Most epochs scanned by one `claim_all` call
+pub(crate) const MAX_CLAIM_ALL_EPOCHS: u32 = 20;
+
+/// Claim a player's rewards from every finalized epoch not yet scanned
+///
+/// Scans from the player's claim cursor (or the oldest epoch still in its
+/// claim window) up to the latest finalized epoch, at most `max_epochs`
+/// epochs (capped at `MAX_CLAIM_ALL_EPOCHS`), and advances the cursor past
+/// them. Epochs the player has nothing to claim from (not played, losing
+/// faction, already claimed, Merkle-distributed, expired or never stored)
+/// are skipped. The scan stops at the closed epoch awaiting `process_rewards`.
+///
+/// # Returns
+/// (USDC paid, last epoch scanned or None if there was nothing to scan);
+/// call again to continue after a truncated scan
+///
+/// # Errors
+/// * `DepositRequiredToClaim` - If the player's vault balance is below the minimum
+/// * Any other error of `claim_epoch_reward`
+pub(crate) fn claim_all(
+    env: &Env,
+    player: &Address,
+    max_epochs: u32,
+) -> Result<(i128, Option<u32>), Error> {
+    player.require_auth();
+
+    let config = storage::get_config(env);
+    if crate::vault::get_vault_balance(env, player) < config.min_deposit_to_claim {
+        return Err(Error::DepositRequiredToClaim);
+    }
+
+    let start = storage::get_claim_cursor(env, player).max(storage::get_next_sweep_epoch(env));
+    let end = storage::get_current_epoch(env)
+        .min(start.saturating_add(max_epochs.min(MAX_CLAIM_ALL_EPOCHS)));
+
+    let mut paid: i128 = 0;
+    let mut last_epoch = None;
+    for epoch in start..end {
+        // Epochs below the current one without an entry hold nothing to claim
+        // (skipped epochs beyond those `finalize_epoch` stores, or expired)
+        let Some(epoch_info) = storage::get_epoch(env, epoch) else {
+            last_epoch = Some(epoch);
+            continue;
+        };
+        if !epoch_info.is_finalized {
+            break;
+        }
+
+        match settle_epoch_reward(env, player, epoch, true) {
+            Ok(amount) => paid = paid.checked_add(amount).ok_or(Error::OverflowError)?,
+            // Rejected before anything was written
+            Err(Error::NoRewardsAvailable)
+            | Err(Error::NotWinningFaction)
+            | Err(Error::RewardAlreadyClaimed)
+            | Err(Error::MerkleDistributionActive)
+            | Err(Error::ClaimWindowExpired) => {}
+            Err(error) => return Err(error),
+        }
+        last_epoch = Some(epoch);
+    }
+
+    if let Some(epoch) = last_epoch {
+        storage::set_claim_cursor(env, player, epoch + 1);
+    }
+    Ok((paid, last_epoch))
+}
+
+/// Claim a player's epoch reward as their approved claimer
+///
+/// The reward is paid to the player exactly as in `claim_epoch_reward`,
+/// except that with the FeeVault backend the USDC is transferred to the
+/// player rather than deposited (the vault deposit needs the player's
+/// signature).
+///
+/// # Errors
+/// * `NotApprovedClaimer` - If `claimer` isn't the player's approved claimer
+/// * Same errors as `claim_epoch_reward`
+pub(crate) fn claim_epoch_reward_for(
+    env: &Env,
+    claimer: &Address,
+    player: &Address,
+    epoch: u32,
+) -> Result<i128, ContractError> {
+    claimer.require_auth();
+
+    if storage::get_claimer(env, player).as_ref() != Some(claimer) {
+        return Err(ExtError::NotApprovedClaimer.into());
+    }
+
+    let amount = settle_epoch_reward(env, player, epoch, false)?;
+    emit_delegated_claim(env, player, claimer, epoch, amount);
+
+    Ok(amount)
+}
+
+/// Approve an address to claim epoch rewards on the user's behalf
+///
+/// Replaces any previously approved claimer.
+pub(crate) fn approve_claimer(env: &Env, user: &Address, claimer: &Address) {
+    user.require_auth();
+
+    storage::set_claimer(env, user, &Some(claimer.clone()));
+    emit_claimer_approved(env, user, claimer);
+}
+
+/// Revoke the user's approved claimer (no-op if none is approved)
+pub(crate) fn revoke_claimer(env: &Env, user: &Address) {
+    user.require_auth();
+
+    if let Some(claimer) = storage::get_claimer(env, user) {
+        storage::set_claimer(env, user, &None);
+        emit_claimer_revoked(env, user, &claimer);
+    }
+}
+
+/// Compute and pay a player's epoch reward (caller has checked authorization)
+fn settle_epoch_reward(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    player_authorized: bool,
+) -> Result<i128, Error> {
     // Check minimum deposit requirement for claiming (anti-sybil gate)
     let vault_balance = crate::vault::get_vault_balance(env, player);
     let config = storage::get_config(env);
@@ -75,6 +215,14 @@ pub(crate) fn claim_epoch_reward(env: &Env, player: &Address, epoch: u32) -> Res
         return Err(Error::EpochNotFinalized);
     }
 
+    // Unclaimed rewards of expired epochs were carried over
+    crate::carryover::require_claimable(env, epoch)?;
+
+    // Merkle-distributed epochs must be claimed with a proof
+    if storage::get_epoch_merkle(env, epoch).is_some() {
+        return Err(Error::MerkleDistributionActive);
+    }
+
     // Get winning faction
     let winning_faction = epoch_info.winning_faction.ok_or(Error::EpochNotFinalized)?;
 
@@ -91,56 +239,226 @@ pub(crate) fn claim_epoch_reward(env: &Env, player: &Address, epoch: u32) -> Res
         return Err(Error::NotWinningFaction);
     }
 
-    // Get player's fp contribution
+    // Get player's fp contribution (only counted once the activity requirement was met)
     let player_fp_contributed = epoch_player.total_fp_contributed;
 
-    if player_fp_contributed == 0 {
+    if player_fp_contributed == 0 || !epoch_player.reward_eligible {
         return Err(Error::NoRewardsAvailable);
     }
 
-    // Get total fp for winning faction
-    let total_winning_fp = epoch_info
-        .faction_standings
-        .get(winning_faction)
-        .ok_or(Error::NoRewardsAvailable)?;
-
-    if total_winning_fp == 0 {
-        return Err(Error::DivisionByZero);
+    // Calculate player's share of rewards according to the epoch's distribution mode
+    // (the BLND and extra reward token pools, if any, are split the same way as
+    // the USDC pool)
+    let token_pools = storage::get_epoch_reward_tokens(env, epoch);
+    let mut pools = vec![env, epoch_info.reward_pool, epoch_info.reward_pool_blnd];
+    for pool in token_pools.iter() {
+        pools.push_back(pool.amount);
     }
-
-    // Calculate player's share of rewards
-    // Formula: (player_fp / total_fp) * reward_pool
-    let reward_amount = calculate_reward_share(
-        player_fp_contributed,
-        total_winning_fp,
-        epoch_info.reward_pool,
-    )?;
-
-    if reward_amount == 0 {
+    let shares = match epoch_info.distribution_mode {
+        DistributionMode::Linear => {
+            // Formula: (player_weight / total_weight) * reward_pool
+            let total_winning_weight = epoch_info
+                .faction_weighted_standings
+                .get(winning_faction)
+                .ok_or(Error::NoRewardsAvailable)?;
+
+            if total_winning_weight == 0 {
+                return Err(Error::DivisionByZero);
+            }
+
+            let player_weight = reward_weight(player_fp_contributed, epoch_player.streak_bonus)
+                .ok_or(Error::OverflowError)?;
+
+            crate::reward_cap::reward_shares(
+                env,
+                epoch,
+                winning_faction,
+                player,
+                player_weight,
+                total_winning_weight,
+                &pools,
+            )?
+        }
+        DistributionMode::Quadratic => {
+            // Formula: (sqrt_weight / sum(sqrt_weights)) * reward_pool
+            let total_winning_sqrt = epoch_info
+                .faction_sqrt_standings
+                .get(winning_faction)
+                .ok_or(Error::NoRewardsAvailable)?;
+
+            if total_winning_sqrt == 0 {
+                return Err(Error::DivisionByZero);
+            }
+
+            let player_sqrt =
+                checked_fixed_sqrt(player_fp_contributed, SCALAR_7).ok_or(Error::OverflowError)?;
+
+            let player_weight = reward_weight(player_sqrt, epoch_player.streak_bonus)
+                .ok_or(Error::OverflowError)?;
+
+            crate::reward_cap::reward_shares(
+                env,
+                epoch,
+                winning_faction,
+                player,
+                player_weight,
+                total_winning_sqrt,
+                &pools,
+            )?
+        }
+        DistributionMode::WinnerTakeAll => {
+            // Entire pool to the winning faction's top contributor
+            let leader = epoch_info
+                .faction_leaders
+                .get(winning_faction)
+                .ok_or(Error::NoRewardsAvailable)?;
+
+            if leader != *player {
+                return Err(Error::NoRewardsAvailable);
+            }
+
+            pools
+        }
+    };
+
+    if shares.iter().all(|share| share == 0) {
         return Err(Error::NoRewardsAvailable);
     }
+    let reward_amount = shares.get_unchecked(0);
+    let blnd_amount = shares.get_unchecked(1);
 
     // Mark as claimed
     storage::set_claimed(env, player, epoch);
 
-    // Transfer USDC to player, then deposit into fee-vault
-    // (reuse config from earlier check)
-    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
-
-    // Step 1: Transfer USDC from contract to player
-    usdc_client.transfer(&env.current_contract_address(), player, &reward_amount);
-
-    // Step 2: Deposit into fee-vault on behalf of player
-    // Note: Player must authorize both the claim AND the vault deposit in their transaction
-    let vault_client = FeeVaultClient::new(env, &config.fee_vault);
-    let _shares_minted = vault_client.deposit(player, &reward_amount);
+    let reward_amount = if reward_amount > 0 {
+        crate::carryover::record_player_claim(env, epoch, reward_amount)?;
+
+        // Credit the referrer's share (if any); the player receives the rest
+        let reward_amount =
+            crate::referral::apply_referral_share(env, &config, player, epoch, reward_amount)?;
+
+        // Transfer USDC to player, then deposit into fee-vault
+        // (reuse config from earlier check); large claims vest instead
+        crate::vesting::pay_reward(
+            env,
+            &config,
+            player,
+            epoch,
+            reward_amount,
+            player_authorized,
+        )?;
+        reward_amount
+    } else {
+        0
+    };
+
+    // Retained BLND and extra reward tokens are transferred directly (no
+    // referral share or vesting)
+    pay_blnd_reward(env, &config, player, epoch, blnd_amount)?;
+    crate::reward_tokens::pay_shares(env, player, epoch, &token_pools, &shares.slice(2..))?;
+
+    record_claim_history(env, &config, player, epoch, reward_amount, blnd_amount);
 
     // Emit event
-    emit_rewards_claimed(env, player, epoch, player_faction, reward_amount);
+    emit_rewards_claimed(
+        env,
+        player,
+        epoch,
+        player_faction,
+        reward_amount,
+        blnd_amount,
+    );
 
     Ok(reward_amount)
 }
 
+/// Transfer a player's share of an epoch's retained BLND
+///
+/// Releases the amount from `ReservedBlnd` and records it against the epoch,
+/// so unclaimed BLND can be carried over once the claim window passes.
+pub(crate) fn pay_blnd_reward(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    crate::carryover::record_blnd_claim(env, epoch, amount)?;
+    storage::set_reserved_blnd(
+        env,
+        storage::get_reserved_blnd(env)
+            .saturating_sub(amount)
+            .max(0),
+    );
+
+    token::Client::new(env, &config.blnd_token).transfer(
+        &env.current_contract_address(),
+        player,
+        &amount,
+    );
+    Ok(())
+}
+
+/// Add a claim's USDC and BLND payouts to the player's claim history
+pub(crate) fn record_claim_history(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    epoch: u32,
+    usdc_amount: i128,
+    blnd_amount: i128,
+) {
+    for (token, amount) in [
+        (&config.usdc_token, usdc_amount),
+        (&config.blnd_token, blnd_amount),
+    ] {
+        if amount > 0 {
+            storage::push_claim_record(
+                env,
+                player,
+                ClaimRecord {
+                    epoch,
+                    amount,
+                    token: token.clone(),
+                    ledger: env.ledger().sequence(),
+                },
+            );
+        }
+    }
+}
+
+/// Get a page of a user's claim history, newest first
+///
+/// Only the last `storage::MAX_CLAIM_HISTORY` payouts are kept.
+///
+/// # Arguments
+/// * `offset` - Number of most recent records to skip
+/// * `limit` - Maximum records to return (capped at `MAX_CLAIM_HISTORY`)
+pub(crate) fn get_claim_history(
+    env: &Env,
+    user: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<ClaimRecord> {
+    let history = storage::get_claim_history(env, user);
+    let limit = limit.min(storage::MAX_CLAIM_HISTORY);
+
+    let mut page = Vec::new(env);
+    for index in (0..history.len().saturating_sub(offset)).rev() {
+        if page.len() >= limit {
+            break;
+        }
+        if let Some(record) = history.get(index) {
+            page.push_back(record);
+        }
+    }
+    page
+}
+
 /// Claim developer reward for a specific epoch
 ///
 /// Developers claim their aggregated share of the epoch's dev reward pool
@@ -166,6 +484,7 @@ pub(crate) fn claim_epoch_reward(env: &Env, player: &Address, epoch: u32) -> Res
 /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
 /// * `DevRewardAlreadyClaimed` - If already claimed for this epoch
 /// * `GameNoContributions` - If developer has no contributions this epoch
+/// * `ClaimWindowExpired` - If the epoch's claim window has passed
 pub(crate) fn claim_dev_reward(env: &Env, developer: &Address, epoch: u32) -> Result<i128, Error> {
     // Authenticate developer directly
     developer.require_auth();
@@ -183,6 +502,9 @@ pub(crate) fn claim_dev_reward(env: &Env, developer: &Address, epoch: u32) -> Re
         return Err(Error::EpochNotFinalized);
     }
 
+    // Unclaimed rewards of expired epochs were carried over
+    crate::carryover::require_claimable(env, epoch)?;
+
     // Get developer's epoch contribution (aggregated across all their games)
     let epoch_game =
         storage::get_epoch_game(env, epoch, developer).ok_or(Error::GameNoContributions)?;
@@ -210,6 +532,7 @@ pub(crate) fn claim_dev_reward(env: &Env, developer: &Address, epoch: u32) -> Re
 
     // Mark as claimed
     storage::set_dev_claimed(env, developer, epoch);
+    crate::carryover::record_claim(env, epoch, reward_amount)?;
 
     // Transfer USDC directly to developer (no vault deposit)
     let config = storage::get_config(env);
@@ -248,20 +571,18 @@ pub(crate) fn claim_dev_reward(env: &Env, developer: &Address, epoch: u32) -> Re
 /// # Errors
 /// * `OverflowError` - If calculation overflows
 /// * `DivisionByZero` - If total_fp is 0
-fn calculate_reward_share(
+pub(crate) fn calculate_reward_share(
     player_fp: i128,
     total_fp: i128,
     reward_pool: i128,
 ) -> Result<i128, Error> {
     // Calculate player's share as a fraction: player_fp / total_fp
-    let share = player_fp
-        .fixed_div_floor(total_fp, SCALAR_7)
+    let share = checked_mul_div(player_fp, SCALAR_7, total_fp, Rounding::Floor)
         .ok_or(Error::DivisionByZero)?;
 
     // Calculate reward: share * reward_pool
-    let reward = reward_pool
-        .fixed_mul_floor(share, SCALAR_7)
-        .ok_or(Error::OverflowError)?;
+    let reward =
+        checked_mul_scalar_7(reward_pool, share, Rounding::Floor).ok_or(Error::OverflowError)?;
 
     Ok(reward)
 }
@@ -0,0 +1,130 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::{ContractError, Error};
+use crate::events::{emit_series_game_recorded, emit_series_started};
+use crate::storage;
+use crate::types::GameSeries;
+
+// ============================================================================
+// Best-of-N Series
+// ============================================================================
+//
+// A series is a regular session whose outcome is decided over several games.
+// FP is locked once by `start_series` (same checks as `start_game`); each game
+// result is reported with `report_series_game`, and the session is settled
+// (standings, ratings, stats) as soon as one side wins a majority.
+
+/// Longest supported series
+const MAX_SERIES_LENGTH: u32 = 9;
+
+/// Start a best-of-N series between two players
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `session_id` - Unique session identifier for the whole series
+/// * `player1` - First player's address
+/// * `player2` - Second player's address
+/// * `player1_wager` - Faction points wagered by player1 on the series
+/// * `player2_wager` - Faction points wagered by player2 on the series
+/// * `best_of` - Series length (odd, 1 to 9)
+///
+/// # Errors
+/// * `InvalidSeriesLength` - If best_of is even, zero or above the maximum
+/// * Same as `start_game`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn start_series(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_wager: i128,
+    player2_wager: i128,
+    best_of: u32,
+) -> Result<(), ContractError> {
+    // Odd lengths only, so a series can't end in a tie
+    if best_of % 2 != 1 || best_of > MAX_SERIES_LENGTH {
+        return Err(Error::InvalidSeriesLength.into());
+    }
+
+    crate::game::start_game(
+        env,
+        game_id,
+        session_id,
+        player1,
+        player2,
+        player1_wager,
+        player2_wager,
+    )?;
+
+    let series = GameSeries {
+        best_of,
+        player1_wins: 0,
+        player2_wins: 0,
+        results: Vec::new(env),
+    };
+    storage::set_series(env, session_id, &series);
+
+    emit_series_started(env, game_id, session_id, best_of);
+
+    Ok(())
+}
+
+/// Record the result of one game in a series
+///
+/// Settles the underlying session once either player has won a majority
+/// (`best_of / 2 + 1` games).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - Series session identifier
+/// * `player1_won` - true if player1 won this game, false if player2 won
+///
+/// # Returns
+/// Updated series state
+///
+/// # Errors
+/// * `SessionNotFound` - If session doesn't exist
+/// * `SeriesNotFound` - If the session is not a series
+/// * `SessionAlreadyFinalized` - If the series was already decided
+/// * `GameExpired` - If the series is from a previous epoch
+pub(crate) fn report_series_game(
+    env: &Env,
+    session_id: u32,
+    player1_won: bool,
+) -> Result<GameSeries, Error> {
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    // SECURITY: Only the session's game contract can report results
+    session.game_id.require_auth();
+
+    if session.player1_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized);
+    }
+
+    let mut series = storage::get_series(env, session_id).ok_or(Error::SeriesNotFound)?;
+
+    // Series must be decided within the epoch it started in
+    if session.epoch_id != storage::get_current_epoch(env) {
+        return Err(Error::GameExpired);
+    }
+
+    series.results.push_back(player1_won);
+    if player1_won {
+        series.player1_wins += 1;
+    } else {
+        series.player2_wins += 1;
+    }
+    storage::set_series(env, session_id, &series);
+
+    emit_series_game_recorded(env, session_id, &series);
+
+    // Settle FP, standings and ratings once a side has the majority
+    let wins_needed = series.best_of / 2 + 1;
+    if series.player1_wins >= wins_needed || series.player2_wins >= wins_needed {
+        crate::game::settle_session(env, session_id, session, series.player1_wins >= wins_needed)?;
+    }
+
+    Ok(series)
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::types::{BalanceCheckpoint, Config};
+
+// ============================================================================
+// Epoch Balance Snapshots
+// ============================================================================
+//
+// With `config.balance_snapshots` enabled, FP (and so reward weight) is based
+// on the balance a player held at the start of the epoch instead of their
+// live vault balance, so a deposit made just before playing doesn't count
+// until the following epoch.
+//
+// Deposits go straight to the fee-vault, so the contract can't see the balance
+// at the epoch boundary. It is captured lazily instead: every observation of a
+// player's balance (first game of an epoch, `select_faction`,
+// `checkpoint_balance`) records a checkpoint, and the first observation in an
+// epoch fixes that epoch's start balance as the lower of the live balance and
+// the last balance observed in an earlier epoch. Players without a checkpoint
+// fall back to `last_epoch_balance`.
+
+/// Balance used to calculate a player's FP in the current epoch
+///
+/// The live vault balance when snapshots are disabled. Read-only.
+///
+/// Capped at `config.max_deposit_per_user` when that cap is set: deposits made
+/// into the fee-vault directly can't be refused, so anything above the cap
+/// simply earns no FP.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (snapshot toggle, per-user cap)
+/// * `player` - Player to compute the balance for
+pub(crate) fn fp_balance(env: &Env, config: &Config, player: &Address) -> i128 {
+    let live = crate::vault::get_vault_balance(env, player);
+    let balance = if config.balance_snapshots {
+        start_balance(env, player, storage::get_current_epoch(env), live)
+    } else {
+        live
+    };
+    if config.max_deposit_per_user > 0 {
+        balance.min(config.max_deposit_per_user)
+    } else {
+        balance
+    }
+}
+
+/// Record an observation of a player's balance
+///
+/// No-op when snapshots are disabled.
+pub(crate) fn record(env: &Env, config: &Config, player: &Address) {
+    if !config.balance_snapshots {
+        return;
+    }
+
+    let live = crate::vault::get_vault_balance(env, player);
+    let epoch = storage::get_current_epoch(env);
+    let checkpoint = BalanceCheckpoint {
+        epoch,
+        balance: live,
+        start_balance: start_balance(env, player, epoch, live),
+    };
+    storage::set_balance_checkpoint(env, player, &checkpoint);
+}
+
+/// Start-of-epoch balance for `epoch`, capped by the live balance
+fn start_balance(env: &Env, player: &Address, epoch: u32, live: i128) -> i128 {
+    let held = match storage::get_balance_checkpoint(env, player) {
+        Some(checkpoint) if checkpoint.epoch == epoch => checkpoint.start_balance,
+        Some(checkpoint) => checkpoint.balance,
+        None => storage::get_player(env, player)
+            .map(|player_data| player_data.last_epoch_balance)
+            .unwrap_or(0),
+    };
+    held.min(live)
+}
@@ -0,0 +1,193 @@
+use soroban_sdk::{Address, Env, Map};
+
+use crate::errors::Error;
+use crate::math::{checked_mul_div, Rounding, BPS_SCALE};
+use crate::storage;
+use crate::types::{GameTotals, GlobalStats, LifetimeTotals, PlayerGameStats};
+
+// ============================================================================
+// Player Game Stats
+// ============================================================================
+//
+// Lifetime per-(player, game) stats, updated when a session ends, so profile
+// pages can show them without an indexer. Each player also keeps the list of
+// games they have stats for (bounded by the number of whitelisted games).
+
+/// Stats for a player who hasn't finished a game yet
+fn empty_stats() -> PlayerGameStats {
+    PlayerGameStats {
+        wins: 0,
+        losses: 0,
+        total_wagered: 0,
+        total_won: 0,
+    }
+}
+
+/// Get a player's stats for a game (zeroed if they haven't finished one)
+pub(crate) fn get_player_stats(env: &Env, player: &Address, game_id: &Address) -> PlayerGameStats {
+    storage::get_player_stats(env, player, game_id).unwrap_or_else(empty_stats)
+}
+
+/// Get a player's stats for every game they have finished sessions in
+pub(crate) fn get_player_stats_all(env: &Env, player: &Address) -> Map<Address, PlayerGameStats> {
+    let mut all_stats = Map::new(env);
+    for game_id in storage::get_player_games(env, player).iter() {
+        let stats = get_player_stats(env, player, &game_id);
+        all_stats.set(game_id, stats);
+    }
+    all_stats
+}
+
+/// Record a finished game for both players
+///
+/// # Errors
+/// * `OverflowError` - If a wagered total overflows
+pub(crate) fn record_game(
+    env: &Env,
+    game_id: &Address,
+    winner: &Address,
+    winner_wager: i128,
+    loser: &Address,
+    loser_wager: i128,
+) -> Result<(), Error> {
+    record_result(env, game_id, winner, winner_wager, true)?;
+    record_result(env, game_id, loser, loser_wager, false)?;
+
+    let mut game_totals = storage::get_game_totals(env, game_id);
+    game_totals.total_wagered = game_totals
+        .total_wagered
+        .checked_add(winner_wager)
+        .and_then(|total| total.checked_add(loser_wager))
+        .ok_or(Error::OverflowError)?;
+    storage::set_game_totals(env, game_id, &game_totals);
+
+    add_to_totals(env, |totals| &mut totals.total_wagered, winner_wager)?;
+    add_to_totals(env, |totals| &mut totals.total_wagered, loser_wager)
+}
+
+/// Record one player's result for a game
+fn record_result(
+    env: &Env,
+    game_id: &Address,
+    player: &Address,
+    wager: i128,
+    won: bool,
+) -> Result<(), Error> {
+    let mut stats = match storage::get_player_stats(env, player, game_id) {
+        Some(stats) => stats,
+        None => {
+            // First finished game on this contract: track it in the player's game list
+            let mut games = storage::get_player_games(env, player);
+            games.push_back(game_id.clone());
+            storage::set_player_games(env, player, &games);
+            empty_stats()
+        }
+    };
+
+    stats.total_wagered = stats
+        .total_wagered
+        .checked_add(wager)
+        .ok_or(Error::OverflowError)?;
+    if won {
+        stats.wins = stats.wins.saturating_add(1);
+        stats.total_won = stats
+            .total_won
+            .checked_add(wager)
+            .ok_or(Error::OverflowError)?;
+    } else {
+        stats.losses = stats.losses.saturating_add(1);
+    }
+
+    storage::set_player_stats(env, player, game_id, &stats);
+    Ok(())
+}
+
+// ============================================================================
+// Game Totals
+// ============================================================================
+//
+// Lifetime per-game session counters for the game browser (see `get_games`).
+// Team games count once per session; their wagers count per member.
+
+/// Count a session started on a game
+pub(crate) fn record_session_started(env: &Env, game_id: &Address) {
+    let mut totals = storage::get_game_totals(env, game_id);
+    totals.sessions_started = totals.sessions_started.saturating_add(1);
+    storage::set_game_totals(env, game_id, &totals);
+}
+
+/// Count a session of a game settled with an outcome
+pub(crate) fn record_session_finished(env: &Env, game_id: &Address) {
+    let mut totals = storage::get_game_totals(env, game_id);
+    totals.sessions_finished = totals.sessions_finished.saturating_add(1);
+    storage::set_game_totals(env, game_id, &totals);
+}
+
+/// A game's share of all FP ever wagered, in basis points (0 before any wager)
+pub(crate) fn game_weight_bps(env: &Env, totals: &GameTotals) -> u32 {
+    let all_wagered = storage::get_lifetime_totals(env).total_wagered;
+    checked_mul_div(
+        totals.total_wagered,
+        BPS_SCALE,
+        all_wagered,
+        Rounding::Floor,
+    )
+    .map(|bps| bps.clamp(0, BPS_SCALE) as u32)
+    .unwrap_or(0)
+}
+
+// ============================================================================
+// Global Stats
+// ============================================================================
+//
+// Lifetime protocol-wide counters for the landing-page dashboard, kept next to
+// the per-player stats. TVL isn't a counter: it is read from the vault backend
+// when the stats are queried.
+
+/// Get the lifetime counters and current TVL
+///
+/// # Errors
+/// * `OverflowError` - If the TVL computation overflows
+pub(crate) fn get_global_stats(env: &Env) -> Result<GlobalStats, Error> {
+    let config = storage::get_config(env);
+    let totals = storage::get_lifetime_totals(env);
+    Ok(GlobalStats {
+        total_deposits: totals.total_deposits,
+        total_wagered: totals.total_wagered,
+        total_rewards_distributed: totals.total_rewards_distributed,
+        total_blnd_claimed: totals.total_blnd_claimed,
+        total_blnd_burned: storage::get_blnd_burn(env).total_burned,
+        tvl: crate::vault::get_vault_tvl(env, &config)?,
+    })
+}
+
+/// Count USDC deposited through this contract
+pub(crate) fn record_deposit(env: &Env, amount: i128) -> Result<(), Error> {
+    add_to_totals(env, |totals| &mut totals.total_deposits, amount)
+}
+
+/// Count USDC rewards paid to a player or developer
+pub(crate) fn record_reward_paid(env: &Env, amount: i128) -> Result<(), Error> {
+    add_to_totals(env, |totals| &mut totals.total_rewards_distributed, amount)
+}
+
+/// Count BLND rewards paid to a player
+pub(crate) fn record_blnd_paid(env: &Env, amount: i128) -> Result<(), Error> {
+    add_to_totals(env, |totals| &mut totals.total_blnd_claimed, amount)
+}
+
+/// Add `amount` to one of the lifetime counters
+fn add_to_totals(
+    env: &Env,
+    counter: impl FnOnce(&mut LifetimeTotals) -> &mut i128,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let mut totals = storage::get_lifetime_totals(env);
+    let total = counter(&mut totals);
+    *total = total.checked_add(amount).ok_or(Error::OverflowError)?;
+    storage::set_lifetime_totals(env, &totals);
+    Ok(())
+}
@@ -1,6 +1,15 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, Val, Vec};
 
-use crate::types::{Config, EpochGame, EpochInfo, EpochPlayer, GameInfo, GameSession, Player};
+use crate::types::{
+    AchievementSettings, Achievements, ActivityRequirement, AdminAction, AdminOperation,
+    ApproverSet, BalanceCheckpoint, BlndBurn, ClaimRecord, Config, CycleProgress, DepositLock,
+    EpochBoost, EpochClock, EpochGame, EpochInfo, EpochMerkle, EpochPayouts, EpochPlayer,
+    EpochYield, FactionInfo, FactionTreasuries, GameInfo, GameSeries, GameSession, GameTotals,
+    GovernanceParameter, InsuranceFund, Jackpot, KeeperRegistry, LifetimeTotals, MoveCommits,
+    Player, PlayerGameStats, Prediction, PredictionPool, PreviousFeeVault, ProtocolTreasury,
+    ProvisionalResult, Quest, QueuedVaultMigration, RewardTokenPool, SessionMetadata, TeamSession,
+    Title, TreasuryProposal, UserData, VestingSchedule, WinOddsCurve, FACTION_COUNT,
+};
 
 // ============================================================================
 // Storage Keys
@@ -8,9 +17,31 @@ use crate::types::{Config, EpochGame, EpochInfo, EpochPlayer, GameInfo, GameSess
 // Uses type-safe enum keys to prevent storage collisions and improve type safety
 //
 // Storage Types:
-// - Instance: Admin, Config, CurrentEpoch, Paused
-// - Persistent: Player, Game
-// - Temporary: EpochPlayer, Epoch, Session, Claimed
+// - Instance: Admin, Config, CurrentEpoch, Paused, CarryoverBlnd, NextSweepEpoch,
+//   TotalPoolBTokens, EventSeq, PendingRewards, Guardian, PoolBTokenRate, YieldHistory,
+//   PendingBlnd, ReservedBlnd, Arbiter, FactionTreasuries, KeeperRegistry,
+//   ExtDataKey::LifetimeTotals, ExtDataKey::InFlight, ExtDataKey::ActivityRequirement,
+//   ExtDataKey::AdminLogCount, ExtDataKey::AchievementSettings, ExtDataKey::Jackpot,
+//   ExtDataKey::Approvers, ExtDataKey::UnprocessedEpoch, ExtDataKey::GovernanceOptions,
+//   ExtDataKey::BlndBurn, ExtDataKey::MinFpStake, ExtDataKey::ForfeitDelay,
+//   ExtDataKey::StorageVersion, ExtDataKey::RewardCap, ExtDataKey::PruneCursor,
+//   ExtDataKey::PruneBounty, ExtDataKey::RewardTokens, ExtDataKey::PendingRewardTokens,
+//   ExtDataKey::RewardTokenReserves, ExtDataKey::LastCycleLedger, ExtDataKey::PoolStatus,
+//   ExtDataKey::PoolHaltPausesGames, ExtDataKey::WinOddsCurve, ExtDataKey::InsuranceFund,
+//   ExtDataKey::EpochClock, ExtDataKey::MvpBonus, ExtDataKey::PruneBountyFund,
+//   ExtDataKey::QueuedVaultMigration, ExtDataKey::PreviousFeeVault
+// - Persistent: User, Game, Rating, ReferralBalance, PlayerStats, PlayerGames, SessionUsed,
+//   GameNonce, FactionInfo, PoolBTokens, UserEventSeq, Whitelisted, TreasuryProposal,
+//   ExtDataKey::AdminLog, ExtDataKey::GameList, ExtDataKey::GameDeprecated,
+//   ExtDataKey::GameTotals
+//   (legacy, read-only until migrated into User: Player, Referrer, Vesting, BalanceCheckpoint)
+// - Temporary: EpochPlayer, Epoch, Session, Claimed, EpochMerkle, EpochPayouts,
+//   ActiveSessions, Series, TeamSession, Moves, PredictionPool, Prediction, ProvisionalResult,
+//   ProposalVote, ExtDataKey::Approvals, ExtDataKey::GameLoad, ExtDataKey::ParameterVotes,
+//   ExtDataKey::ParameterVoted, ExtDataKey::EpochBlndBurned, ExtDataKey::GameSessions,
+//   ExtDataKey::Quests, ExtDataKey::QuestProgress, ExtDataKey::SessionMetadata,
+//   ExtDataKey::EpochRewardCap, ExtDataKey::TopContributors, ExtDataKey::EpochBoosts,
+//   ExtDataKey::EpochRewardTokens, ExtDataKey::CycleProgress, ExtDataKey::SessionMargin
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,7 +58,47 @@ pub enum DataKey {
     /// Pause state - singleton (Instance storage)
     Paused,
 
-    /// Player persistent data - Player(player_address) -> Player (Persistent storage)
+    /// BLND left unswapped by previous cycles - singleton (Instance storage)
+    CarryoverBlnd,
+
+    /// Oldest epoch not yet checked for expired rewards - singleton (Instance storage)
+    NextSweepEpoch,
+
+    /// Blend pool b-tokens held for all players (DirectPool backend) - singleton (Instance storage)
+    TotalPoolBTokens,
+
+    /// Last global event sequence number - singleton (Instance storage)
+    EventSeq,
+
+    /// USDC harvested mid-epoch, not yet in a reward pool - singleton (Instance storage)
+    PendingRewards,
+
+    /// Address allowed to pause (but not unpause) the contract - singleton (Instance storage)
+    Guardian,
+
+    /// Current pool b-tokens per recorded PoolBTokens unit, 12 decimals - singleton (Instance storage)
+    PoolBTokenRate,
+
+    /// Yield snapshots of recently closed epochs - singleton (Instance storage)
+    YieldHistory,
+
+    /// BLND retained since the last epoch cycle, not yet in a reward pool - singleton (Instance storage)
+    PendingBlnd,
+
+    /// BLND owed to players from finalized epochs' BLND reward pools - singleton (Instance storage)
+    ReservedBlnd,
+
+    /// Address resolving disputed session results - singleton (Instance storage)
+    Arbiter,
+
+    /// Faction treasury balances and proposal counter - singleton (Instance storage)
+    FactionTreasuries,
+
+    /// Keeper registry settings and bonds - singleton (Instance storage)
+    KeeperRegistry,
+
+    /// Legacy player data - Player(player_address) -> Player (Persistent storage)
+    /// Superseded by `User`; only read to migrate users who haven't been written since
     Player(Address),
 
     /// Player epoch-specific data - EpochPlayer(epoch_number, player_address) -> EpochPlayer (Temporary storage)
@@ -42,252 +113,2536 @@ pub enum DataKey {
     /// Registered game contracts - Game(game_address) -> GameInfo (Persistent storage)
     Game(Address),
 
-    /// Per-epoch game contribution - EpochGame(epoch_number, game_address) -> EpochGame (Temporary storage)
-    EpochGame(u32, Address),
+    /// Per-epoch game contribution - EpochGame(epoch_number, game_address) -> EpochGame (Temporary storage)
+    EpochGame(u32, Address),
+
+    /// Reward claim tracking - Claimed(player_address, epoch_number) -> bool (Temporary storage)
+    Claimed(Address, u32),
+
+    /// Developer reward claim tracking - DevClaimed(game_address, epoch_number) -> bool (Temporary storage)
+    DevClaimed(Address, u32),
+
+    /// Merkle-drop distribution - EpochMerkle(epoch_number) -> EpochMerkle (Temporary storage)
+    EpochMerkle(u32),
+
+    /// Reward payout tracking - EpochPayouts(epoch_number) -> EpochPayouts (Temporary storage)
+    EpochPayouts(u32),
+
+    /// Sessions a player started - ActiveSessions(player_address) -> Vec<u32> (Temporary storage)
+    /// May include finished or expired sessions until pruned
+    ActiveSessions(Address),
+
+    /// Best-of-N series state - Series(session_id) -> GameSeries (Temporary storage)
+    Series(u32),
+
+    /// Team game session data - TeamSession(session_id) -> TeamSession (Temporary storage)
+    TeamSession(u32),
+
+    /// Commit-reveal move state - Moves(session_id) -> MoveCommits (Temporary storage)
+    Moves(u32),
+
+    /// Disputable session outcome - ProvisionalResult(session_id) -> ProvisionalResult (Temporary storage)
+    ProvisionalResult(u32),
+
+    /// Spectator prediction totals - PredictionPool(session_id) -> PredictionPool (Temporary storage)
+    PredictionPool(u32),
+
+    /// Spectator prediction - Prediction(session_id, predictor_address) -> Prediction (Temporary storage)
+    Prediction(u32, Address),
+
+    /// Elo rating - Rating(player_address, game_address) -> u32 (Persistent storage)
+    Rating(Address, Address),
+
+    /// Legacy referrer of a player - Referrer(player_address) -> Address (Persistent storage)
+    /// Superseded by `User`
+    Referrer(Address),
+
+    /// Faction display metadata - FactionInfo(faction_id) -> FactionInfo (Persistent storage)
+    FactionInfo(u32),
+
+    /// Unclaimed referral rewards - ReferralBalance(referrer_address) -> i128 (Persistent storage)
+    ReferralBalance(Address),
+
+    /// Per-game player stats - PlayerStats(player_address, game_address) -> PlayerGameStats (Persistent storage)
+    PlayerStats(Address, Address),
+
+    /// Games a player has finished sessions in - PlayerGames(player_address) -> Vec<Address> (Persistent storage)
+    PlayerGames(Address),
+
+    /// Session ID ever used - SessionUsed(session_id) -> bool (Persistent storage)
+    /// Outlives the temporary Session entry so IDs can never be reused
+    SessionUsed(u32),
+
+    /// Per-game session nonce - GameNonce(game_address) -> u32 (Persistent storage)
+    GameNonce(Address),
+
+    /// Blend pool b-tokens held for a player (DirectPool backend) - PoolBTokens(player_address) -> i128 (Persistent storage)
+    PoolBTokens(Address),
+
+    /// Last event sequence number for a user - UserEventSeq(user_address) -> u64 (Persistent storage)
+    /// Per-user keys are unbounded, so they live outside instance storage
+    UserEventSeq(Address),
+
+    /// Legacy vesting reward claim - Vesting(player_address) -> VestingSchedule (Persistent storage)
+    /// Superseded by `User`
+    Vesting(Address),
+
+    /// Legacy last observed vault balance - BalanceCheckpoint(player_address) -> BalanceCheckpoint (Persistent storage)
+    /// Superseded by `User`
+    BalanceCheckpoint(Address),
+
+    /// Packed per-user state - User(user_address) -> UserData (Persistent storage)
+    User(Address),
+
+    /// Whitelist membership - Whitelisted(user_address) -> bool (Persistent storage)
+    /// Only present for whitelisted users
+    Whitelisted(Address),
+
+    /// Faction treasury spending proposal - TreasuryProposal(proposal_id) -> TreasuryProposal (Persistent storage)
+    TreasuryProposal(u32),
+
+    /// Treasury proposal vote cast - ProposalVote(proposal_id, voter_address) -> bool (Temporary storage)
+    ProposalVote(u32, Address),
+}
+
+/// Storage keys added after `DataKey` reached the contract spec's 50-variant limit
+///
+/// Both enums encode as a vector of the variant name, so variant names here
+/// must not repeat a `DataKey` variant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExtDataKey {
+    /// Lifetime protocol-wide counters - singleton (Instance storage)
+    LifetimeTotals,
+
+    /// Fund-moving entrypoint in progress - singleton (Instance storage)
+    /// Only present while the guarded call runs
+    InFlight,
+
+    /// Minimum activity per epoch for reward eligibility - singleton (Instance storage)
+    ActivityRequirement,
+
+    /// Number of admin actions ever recorded - singleton (Instance storage)
+    AdminLogCount,
+
+    /// Admin log ring slot - AdminLog(slot) -> AdminAction (Persistent storage)
+    AdminLog(u32),
+
+    /// Achievement thresholds and FP bonus - singleton (Instance storage)
+    AchievementSettings,
+
+    /// Jackpot settings and accumulated USDC - singleton (Instance storage)
+    Jackpot,
+
+    /// Approvers for sensitive admin operations - singleton (Instance storage)
+    Approvers,
+
+    /// Approvals of an admin operation - Approvals(operation) -> Vec<Address> (Temporary storage)
+    Approvals(AdminOperation),
+
+    /// Session load of a game with session limits - GameLoad(game_id) -> GameLoad (Temporary storage)
+    GameLoad(Address),
+
+    /// Closed epoch awaiting `process_rewards` - singleton (Instance storage)
+    UnprocessedEpoch,
+
+    /// Values players can vote for - GovernanceOptions(parameter) -> Vec<i128> (Instance storage)
+    GovernanceOptions(GovernanceParameter),
+
+    /// Stake voted per value - ParameterVotes(parameter, epoch) -> Map<i128, i128> (Temporary storage)
+    ParameterVotes(GovernanceParameter, u32),
+
+    /// Vote marker - ParameterVoted(parameter, epoch, voter) -> bool (Temporary storage)
+    ParameterVoted(GovernanceParameter, u32, Address),
+
+    /// BLND burn settings and lifetime total - singleton (Instance storage)
+    BlndBurn,
+
+    /// BLND burned while claiming yield in an epoch - EpochBlndBurned(epoch) -> i128 (Temporary storage)
+    EpochBlndBurned(u32),
+
+    /// Minimum epoch balance snapshot for wins to credit faction points - singleton (Instance storage)
+    MinFpStake,
+
+    /// Unsettled 1v1 sessions of a game in an epoch - GameSessions(game_id, epoch) -> Map<session_id, start_ledger> (Temporary storage)
+    GameSessions(Address, u32),
+
+    /// Minimum session age in ledgers before a game can report a forfeit - singleton (Instance storage)
+    ForfeitDelay,
+
+    /// Quests of an epoch - Quests(epoch) -> Vec<Quest> (Temporary storage)
+    Quests(u32),
+
+    /// Player's progress per quest - QuestProgress(epoch, player) -> Vec<u32> (Temporary storage)
+    QuestProgress(u32, Address),
+
+    /// Storage layout version (see `migrate_storage`) - singleton (Instance storage)
+    StorageVersion,
+
+    /// Match details attached by a game - SessionMetadata(session_id) -> SessionMetadata (Temporary storage)
+    SessionMetadata(u32),
+
+    /// Largest share of a reward pool per address, in basis points - singleton (Instance storage)
+    RewardCap,
+
+    /// Reward cap snapshotted when an epoch opened - EpochRewardCap(epoch) -> u32 (Temporary storage)
+    EpochRewardCap(u32),
+
+    /// Largest reward weights of a faction - TopContributors(epoch, faction) -> Map<Address, i128> (Temporary storage)
+    TopContributors(u32, u32),
+
+    /// Oldest epoch whose data hasn't been pruned - singleton (Instance storage)
+    PruneCursor,
+
+    /// USDC paid per epoch pruned - singleton (Instance storage)
+    PruneBounty,
+
+    /// Sponsors who boosted an epoch's reward pool - EpochBoosts(epoch) -> Vec<EpochBoost> (Temporary storage)
+    EpochBoosts(u32),
+
+    /// Tokens other than USDC and BLND accepted as rewards - singleton (Instance storage)
+    RewardTokens,
+
+    /// Extra reward tokens donated since the last epoch cycle - singleton (Instance storage)
+    PendingRewardTokens,
+
+    /// Extra reward tokens owed to pools and players - singleton (Instance storage)
+    RewardTokenReserves,
+
+    /// Extra reward token pools of an epoch - EpochRewardTokens(epoch) -> Vec<RewardTokenPool> (Temporary storage)
+    EpochRewardTokens(u32),
+
+    /// Ledger sequence the current epoch was opened at - singleton (Instance storage)
+    LastCycleLedger,
+
+    /// Last observed Blend pool status - singleton (Instance storage)
+    PoolStatus,
+
+    /// Whether game starts are blocked while the Blend pool is halted - singleton (Instance storage)
+    PoolHaltPausesGames,
+
+    /// Win-probability curve for faction points - singleton (Instance storage)
+    WinOddsCurve,
+
+    /// Registered and pending games, in registration order - singleton (Persistent storage)
+    GameList,
+
+    /// Deprecation marker - GameDeprecated(game_id) -> bool (Persistent storage)
+    GameDeprecated(Address),
+
+    /// Lifetime session counters of a game - GameTotals(game_id) -> GameTotals (Persistent storage)
+    GameTotals(Address),
+
+    /// Cycle stages done for a closed epoch - CycleProgress(epoch) -> CycleProgress (Temporary storage)
+    CycleProgress(u32),
+
+    /// Insurance fund settings and USDC balance - singleton (Instance storage)
+    InsuranceFund,
+
+    /// Unit of epoch times and durations - singleton (Instance storage)
+    EpochClock,
+
+    /// Share of the player reward pool paid to the epoch MVP, in bps - singleton (Instance storage)
+    MvpBonus,
+
+    /// Margin of victory a game reported, in bps - SessionMargin(session_id) -> u32 (Temporary storage)
+    SessionMargin(u32),
+
+    /// Admin USDC set aside for prune bounties - singleton (Instance storage)
+    PruneBountyFund,
+
+    /// Vault migration waiting for its timelock - singleton (Instance storage)
+    QueuedVaultMigration,
+
+    /// Fee-vault players still move deposits out of after a migration - singleton (Instance storage)
+    PreviousFeeVault,
+
+    /// Protocol treasury balances, treasurer and queued transfer - singleton (Instance storage)
+    ProtocolTreasury,
+}
+
+// ============================================================================
+// Storage Utilities
+// ============================================================================
+
+/// Get the admin address
+pub(crate) fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("Admin not set")
+}
+
+/// Set the admin address
+pub(crate) fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the guardian address (None if no guardian is set)
+pub(crate) fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Guardian)
+}
+
+/// Set or clear the guardian address
+pub(crate) fn set_guardian(env: &Env, guardian: &Option<Address>) {
+    match guardian {
+        Some(guardian) => env.storage().instance().set(&DataKey::Guardian, guardian),
+        None => env.storage().instance().remove(&DataKey::Guardian),
+    }
+}
+
+/// Get the arbiter address (None if no arbiter is set)
+pub(crate) fn get_arbiter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Arbiter)
+}
+
+/// Set or clear the arbiter address
+pub(crate) fn set_arbiter(env: &Env, arbiter: &Option<Address>) {
+    match arbiter {
+        Some(arbiter) => env.storage().instance().set(&DataKey::Arbiter, arbiter),
+        None => env.storage().instance().remove(&DataKey::Arbiter),
+    }
+}
+
+/// Get the faction treasury balances and proposal counter
+pub(crate) fn get_faction_treasuries(env: &Env) -> FactionTreasuries {
+    env.storage()
+        .instance()
+        .get(&DataKey::FactionTreasuries)
+        .unwrap_or(FactionTreasuries {
+            balances: Map::new(env),
+            next_proposal_id: 0,
+        })
+}
+
+/// Set the faction treasury balances and proposal counter
+pub(crate) fn set_faction_treasuries(env: &Env, treasuries: &FactionTreasuries) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FactionTreasuries, treasuries);
+}
+
+/// Get the keeper registry (disabled and empty by default)
+pub(crate) fn get_keeper_registry(env: &Env) -> KeeperRegistry {
+    env.storage()
+        .instance()
+        .get(&DataKey::KeeperRegistry)
+        .unwrap_or(KeeperRegistry {
+            min_bond: 0,
+            priority_window: 0,
+            bounty: 0,
+            keepers: Map::new(env),
+            total_bonded: 0,
+        })
+}
+
+/// Set the keeper registry
+pub(crate) fn set_keeper_registry(env: &Env, registry: &KeeperRegistry) {
+    env.storage()
+        .instance()
+        .set(&DataKey::KeeperRegistry, registry);
+}
+
+/// Get a faction treasury spending proposal
+pub(crate) fn get_treasury_proposal(env: &Env, proposal_id: u32) -> Option<TreasuryProposal> {
+    let key = DataKey::TreasuryProposal(proposal_id);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_treasury_proposal_ttl(env, proposal_id);
+    }
+    result
+}
+
+/// Set a faction treasury spending proposal
+pub(crate) fn set_treasury_proposal(env: &Env, proposal_id: u32, proposal: &TreasuryProposal) {
+    let key = DataKey::TreasuryProposal(proposal_id);
+    env.storage().persistent().set(&key, proposal);
+    extend_treasury_proposal_ttl(env, proposal_id);
+}
+
+/// Remove an executed treasury proposal
+pub(crate) fn remove_treasury_proposal(env: &Env, proposal_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TreasuryProposal(proposal_id));
+}
+
+/// Check if a member already voted on a treasury proposal
+pub(crate) fn has_proposal_vote(env: &Env, proposal_id: u32, voter: &Address) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::ProposalVote(proposal_id, voter.clone()))
+}
+
+/// Record that a member voted on a treasury proposal
+pub(crate) fn set_proposal_vote(env: &Env, proposal_id: u32, voter: &Address) {
+    let key = DataKey::ProposalVote(proposal_id, voter.clone());
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the global configuration
+pub(crate) fn get_config(env: &Env) -> Config {
+    let config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .expect("Config not set");
+    extend_instance_ttl(env);
+    config
+}
+
+/// Set the global configuration
+pub(crate) fn set_config(env: &Env, config: &Config) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Get the current epoch number
+pub(crate) fn get_current_epoch(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CurrentEpoch)
+        .unwrap_or(0)
+}
+
+/// Set the current epoch number
+pub(crate) fn set_current_epoch(env: &Env, epoch: u32) {
+    env.storage().instance().set(&DataKey::CurrentEpoch, &epoch);
+}
+
+/// Get the ledger sequence the current epoch was opened at
+pub(crate) fn get_last_cycle_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&ExtDataKey::LastCycleLedger)
+}
+
+/// Record the current ledger sequence as the current epoch's opening
+pub(crate) fn set_last_cycle_ledger(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::LastCycleLedger, &env.ledger().sequence());
+}
+
+/// Check that the entries every call depends on are present: admin, config,
+/// current epoch number and the current epoch's (temporary) entry
+pub(crate) fn core_entries_live(env: &Env) -> bool {
+    let instance = env.storage().instance();
+    if !instance.has(&DataKey::Admin) || !instance.has(&DataKey::Config) {
+        return false;
+    }
+    let Some(epoch) = instance.get::<_, u32>(&DataKey::CurrentEpoch) else {
+        return false;
+    };
+    env.storage().temporary().has(&DataKey::Epoch(epoch))
+}
+
+/// Get the BLND amount carried over from skipped swaps (0 if none)
+pub(crate) fn get_carryover_blnd(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CarryoverBlnd)
+        .unwrap_or(0)
+}
+
+/// Set the BLND amount carried over from skipped swaps
+pub(crate) fn set_carryover_blnd(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CarryoverBlnd, &amount);
+}
+
+/// Get the BLND retained since the last epoch cycle
+pub(crate) fn get_pending_blnd(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingBlnd)
+        .unwrap_or(0)
+}
+
+/// Set the BLND retained since the last epoch cycle
+pub(crate) fn set_pending_blnd(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::PendingBlnd, &amount);
+}
+
+/// Get the BLND owed to players from finalized epochs
+pub(crate) fn get_reserved_blnd(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReservedBlnd)
+        .unwrap_or(0)
+}
+
+/// Set the BLND owed to players from finalized epochs
+pub(crate) fn set_reserved_blnd(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReservedBlnd, &amount);
+}
+
+/// Get the Blend pool b-tokens held for all players (DirectPool backend)
+pub(crate) fn get_total_pool_b_tokens(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalPoolBTokens)
+        .unwrap_or(0)
+}
+
+/// Set the Blend pool b-tokens held for all players (DirectPool backend)
+pub(crate) fn set_total_pool_b_tokens(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalPoolBTokens, &amount);
+}
+
+/// Get the current pool b-tokens per recorded PoolBTokens unit (12 decimals)
+///
+/// 1.0 until the first pool migration (see `vault::migrate_vault`).
+pub(crate) fn get_pool_b_token_rate(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PoolBTokenRate)
+        .unwrap_or(crate::types::SCALAR_12)
+}
+
+/// Get the vault migration waiting for its timelock, if any
+pub(crate) fn get_queued_vault_migration(env: &Env) -> Option<QueuedVaultMigration> {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::QueuedVaultMigration)
+}
+
+/// Set or clear the vault migration waiting for its timelock
+pub(crate) fn set_queued_vault_migration(env: &Env, migration: Option<&QueuedVaultMigration>) {
+    match migration {
+        Some(migration) => env
+            .storage()
+            .instance()
+            .set(&ExtDataKey::QueuedVaultMigration, migration),
+        None => env
+            .storage()
+            .instance()
+            .remove(&ExtDataKey::QueuedVaultMigration),
+    }
+}
+
+/// Get the fee-vault used before the last FeeVault migration, if not drained yet
+pub(crate) fn get_previous_fee_vault(env: &Env) -> Option<PreviousFeeVault> {
+    env.storage().instance().get(&ExtDataKey::PreviousFeeVault)
+}
+
+/// Set the fee-vault used before the last FeeVault migration
+pub(crate) fn set_previous_fee_vault(env: &Env, previous: &PreviousFeeVault) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PreviousFeeVault, previous);
+}
+
+/// Forget the previous fee-vault once it is drained
+pub(crate) fn remove_previous_fee_vault(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&ExtDataKey::PreviousFeeVault);
+}
+
+/// Get the protocol treasury (empty, without a treasurer, by default)
+pub(crate) fn get_protocol_treasury(env: &Env) -> ProtocolTreasury {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::ProtocolTreasury)
+        .unwrap_or(ProtocolTreasury {
+            treasurer: None,
+            balances: Map::new(env),
+            queued_transfer: None,
+        })
+}
+
+/// Set the protocol treasury
+pub(crate) fn set_protocol_treasury(env: &Env, treasury: &ProtocolTreasury) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::ProtocolTreasury, treasury);
+}
+
+/// Set the current pool b-tokens per recorded PoolBTokens unit (12 decimals)
+pub(crate) fn set_pool_b_token_rate(env: &Env, rate: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PoolBTokenRate, &rate);
+}
+
+/// Get the last global event sequence number (0 before the first event)
+pub(crate) fn get_event_seq(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventSeq)
+        .unwrap_or(0)
+}
+
+/// Set the last global event sequence number
+pub(crate) fn set_event_seq(env: &Env, seq: u64) {
+    env.storage().instance().set(&DataKey::EventSeq, &seq);
+}
+
+/// Get the USDC harvested since the last epoch cycle
+pub(crate) fn get_pending_rewards(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingRewards)
+        .unwrap_or(0)
+}
+
+/// Set the USDC harvested since the last epoch cycle
+pub(crate) fn set_pending_rewards(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingRewards, &amount);
+}
+
+/// Get the yield snapshots of recently closed epochs (oldest first)
+pub(crate) fn get_yield_history(env: &Env) -> Vec<EpochYield> {
+    env.storage()
+        .instance()
+        .get(&DataKey::YieldHistory)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the yield snapshots of recently closed epochs
+pub(crate) fn set_yield_history(env: &Env, history: &Vec<EpochYield>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::YieldHistory, history);
+}
+
+/// Get the lifetime protocol-wide counters (zeroed by default)
+pub(crate) fn get_lifetime_totals(env: &Env) -> LifetimeTotals {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::LifetimeTotals)
+        .unwrap_or(LifetimeTotals {
+            total_deposits: 0,
+            total_wagered: 0,
+            total_rewards_distributed: 0,
+            total_blnd_claimed: 0,
+        })
+}
+
+/// Set the lifetime protocol-wide counters
+pub(crate) fn set_lifetime_totals(env: &Env, totals: &LifetimeTotals) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::LifetimeTotals, totals);
+}
+
+/// Get the activity requirement for reward eligibility (none by default)
+pub(crate) fn get_activity_requirement(env: &Env) -> ActivityRequirement {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::ActivityRequirement)
+        .unwrap_or(ActivityRequirement {
+            min_games: 0,
+            min_fp: 0,
+        })
+}
+
+/// Set the activity requirement for reward eligibility
+pub(crate) fn set_activity_requirement(env: &Env, requirement: &ActivityRequirement) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::ActivityRequirement, requirement);
+}
+
+/// Get the minimum stake for wins to credit faction points (0 = no minimum)
+pub(crate) fn get_min_fp_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::MinFpStake)
+        .unwrap_or(0)
+}
+
+/// Set the minimum stake for wins to credit faction points
+pub(crate) fn set_min_fp_stake(env: &Env, min_stake: i128) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::MinFpStake, &min_stake);
+}
+
+/// Get the win-probability curve for faction points (disabled by default)
+pub(crate) fn get_win_odds_curve(env: &Env) -> WinOddsCurve {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::WinOddsCurve)
+        .unwrap_or_default()
+}
+
+/// Set the win-probability curve for faction points
+pub(crate) fn set_win_odds_curve(env: &Env, curve: &WinOddsCurve) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::WinOddsCurve, curve);
+}
+
+/// Get the largest share of a reward pool per address (0 = no cap)
+pub(crate) fn get_reward_cap(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::RewardCap)
+        .unwrap_or(0)
+}
+
+/// Set the largest share of a reward pool per address
+pub(crate) fn set_reward_cap(env: &Env, cap_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::RewardCap, &cap_bps);
+}
+
+/// Get the reward cap an epoch opened with (0 = no cap)
+pub(crate) fn get_epoch_reward_cap(env: &Env, epoch: u32) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::EpochRewardCap(epoch))
+        .unwrap_or(0)
+}
+
+/// Set the reward cap an epoch opened with
+pub(crate) fn set_epoch_reward_cap(env: &Env, epoch: u32, cap_bps: u32) {
+    let key = ExtDataKey::EpochRewardCap(epoch);
+    env.storage().temporary().set(&key, &cap_bps);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the largest reward weights of a faction in an epoch
+pub(crate) fn get_top_contributors(env: &Env, epoch: u32, faction: u32) -> Map<Address, i128> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::TopContributors(epoch, faction))
+        .unwrap_or(Map::new(env))
+}
+
+/// Set the largest reward weights of a faction in an epoch
+pub(crate) fn set_top_contributors(env: &Env, epoch: u32, faction: u32, top: &Map<Address, i128>) {
+    let key = ExtDataKey::TopContributors(epoch, faction);
+    env.storage().temporary().set(&key, top);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the minimum session age for forfeits (`DEFAULT_FORFEIT_DELAY` by default)
+pub(crate) fn get_forfeit_delay(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::ForfeitDelay)
+        .unwrap_or(crate::game::DEFAULT_FORFEIT_DELAY)
+}
+
+/// Set the minimum session age for forfeits
+pub(crate) fn set_forfeit_delay(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::ForfeitDelay, &ledgers);
+}
+
+/// Get the achievement thresholds and FP bonus (10 wins, 100 games, no bonus by default)
+pub(crate) fn get_achievement_settings(env: &Env) -> AchievementSettings {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::AchievementSettings)
+        .unwrap_or(AchievementSettings {
+            win_streak: 10,
+            games_played: 100,
+            fp_bonus: 0,
+        })
+}
+
+/// Set the achievement thresholds and FP bonus
+pub(crate) fn set_achievement_settings(env: &Env, settings: &AchievementSettings) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::AchievementSettings, settings);
+}
+
+/// Get the jackpot (disabled, paid every 10 epochs by default)
+pub(crate) fn get_jackpot(env: &Env) -> Jackpot {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::Jackpot)
+        .unwrap_or(Jackpot {
+            bps: 0,
+            interval: 10,
+            balance: 0,
+        })
+}
+
+/// Set the jackpot
+pub(crate) fn set_jackpot(env: &Env, jackpot: &Jackpot) {
+    env.storage().instance().set(&ExtDataKey::Jackpot, jackpot);
+}
+
+/// Get the insurance fund (no accrual and empty by default)
+pub(crate) fn get_insurance_fund(env: &Env) -> InsuranceFund {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::InsuranceFund)
+        .unwrap_or(InsuranceFund {
+            bps: 0,
+            balance: 0,
+            total_covered: 0,
+        })
+}
+
+/// Set the insurance fund
+pub(crate) fn set_insurance_fund(env: &Env, fund: &InsuranceFund) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::InsuranceFund, fund);
+}
+
+/// Get the MVP bonus in bps (0 by default)
+pub(crate) fn get_mvp_bonus(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::MvpBonus)
+        .unwrap_or(0)
+}
+
+/// Set the MVP bonus in bps
+pub(crate) fn set_mvp_bonus(env: &Env, bps: u32) {
+    env.storage().instance().set(&ExtDataKey::MvpBonus, &bps);
+}
+
+/// Get the epoch clock (timestamps by default)
+pub(crate) fn get_epoch_clock(env: &Env) -> EpochClock {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::EpochClock)
+        .unwrap_or(EpochClock::Timestamp)
+}
+
+/// Set the epoch clock
+pub(crate) fn set_epoch_clock(env: &Env, clock: EpochClock) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::EpochClock, &clock);
+}
+
+/// Get the closed epoch whose rewards are not processed yet, if any
+pub(crate) fn get_unprocessed_epoch(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&ExtDataKey::UnprocessedEpoch)
+}
+
+/// Set or clear the closed epoch awaiting reward processing
+pub(crate) fn set_unprocessed_epoch(env: &Env, epoch: Option<u32>) {
+    match epoch {
+        Some(epoch) => env
+            .storage()
+            .instance()
+            .set(&ExtDataKey::UnprocessedEpoch, &epoch),
+        None => env
+            .storage()
+            .instance()
+            .remove(&ExtDataKey::UnprocessedEpoch),
+    }
+}
+
+/// Get the cycle stages done for a closed epoch (none by default)
+pub(crate) fn get_cycle_progress(env: &Env, epoch: u32) -> CycleProgress {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::CycleProgress(epoch))
+        .unwrap_or(CycleProgress {
+            finalized: false,
+            claimed: false,
+            claimed_blnd: 0,
+            swapped: false,
+        })
+}
+
+/// Set the cycle stages done for a closed epoch
+pub(crate) fn set_cycle_progress(env: &Env, epoch: u32, progress: &CycleProgress) {
+    let key = ExtDataKey::CycleProgress(epoch);
+    env.storage().temporary().set(&key, progress);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Remove the cycle progress of an epoch once its rewards are processed
+pub(crate) fn remove_cycle_progress(env: &Env, epoch: u32) {
+    env.storage()
+        .temporary()
+        .remove(&ExtDataKey::CycleProgress(epoch));
+}
+
+/// Get the values players can vote for on a parameter (none by default)
+pub(crate) fn get_governance_options(env: &Env, parameter: GovernanceParameter) -> Vec<i128> {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::GovernanceOptions(parameter))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the values players can vote for on a parameter
+pub(crate) fn set_governance_options(
+    env: &Env,
+    parameter: GovernanceParameter,
+    options: &Vec<i128>,
+) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::GovernanceOptions(parameter), options);
+}
+
+/// Get the stake voted for each value of a parameter in an epoch
+pub(crate) fn get_parameter_votes(
+    env: &Env,
+    parameter: GovernanceParameter,
+    epoch: u32,
+) -> Map<i128, i128> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::ParameterVotes(parameter, epoch))
+        .unwrap_or(Map::new(env))
+}
+
+/// Set the stake voted for each value of a parameter in an epoch
+pub(crate) fn set_parameter_votes(
+    env: &Env,
+    parameter: GovernanceParameter,
+    epoch: u32,
+    votes: &Map<i128, i128>,
+) {
+    let key = ExtDataKey::ParameterVotes(parameter, epoch);
+    env.storage().temporary().set(&key, votes);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Check if a player voted on a parameter in an epoch
+pub(crate) fn has_parameter_vote(
+    env: &Env,
+    parameter: GovernanceParameter,
+    epoch: u32,
+    voter: &Address,
+) -> bool {
+    env.storage()
+        .temporary()
+        .has(&ExtDataKey::ParameterVoted(parameter, epoch, voter.clone()))
+}
+
+/// Record that a player voted on a parameter in an epoch
+pub(crate) fn set_parameter_vote(
+    env: &Env,
+    parameter: GovernanceParameter,
+    epoch: u32,
+    voter: &Address,
+) {
+    let key = ExtDataKey::ParameterVoted(parameter, epoch, voter.clone());
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the BLND burn settings (disabled by default)
+pub(crate) fn get_blnd_burn(env: &Env) -> BlndBurn {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::BlndBurn)
+        .unwrap_or(BlndBurn {
+            bps: 0,
+            burn_address: None,
+            total_burned: 0,
+        })
+}
+
+/// Set the BLND burn settings
+pub(crate) fn set_blnd_burn(env: &Env, burn: &BlndBurn) {
+    env.storage().instance().set(&ExtDataKey::BlndBurn, burn);
+}
+
+/// Get the BLND burned while claiming yield in an epoch
+pub(crate) fn get_epoch_blnd_burned(env: &Env, epoch: u32) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::EpochBlndBurned(epoch))
+        .unwrap_or(0)
+}
+
+/// Set the BLND burned while claiming yield in an epoch
+pub(crate) fn set_epoch_blnd_burned(env: &Env, epoch: u32, amount: i128) {
+    let key = ExtDataKey::EpochBlndBurned(epoch);
+    env.storage().temporary().set(&key, &amount);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the sponsors who boosted an epoch's reward pool, in order of first boost
+pub(crate) fn get_epoch_boosts(env: &Env, epoch: u32) -> Vec<EpochBoost> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::EpochBoosts(epoch))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the sponsors who boosted an epoch's reward pool
+pub(crate) fn set_epoch_boosts(env: &Env, epoch: u32, boosts: &Vec<EpochBoost>) {
+    let key = ExtDataKey::EpochBoosts(epoch);
+    env.storage().temporary().set(&key, boosts);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the tokens other than USDC and BLND accepted as rewards
+pub(crate) fn get_reward_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::RewardTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the tokens other than USDC and BLND accepted as rewards
+pub(crate) fn set_reward_tokens(env: &Env, tokens: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::RewardTokens, tokens);
+}
+
+/// Get the extra reward tokens donated since the last epoch cycle
+pub(crate) fn get_pending_reward_tokens(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::PendingRewardTokens)
+        .unwrap_or(Map::new(env))
+}
+
+/// Set the extra reward tokens donated since the last epoch cycle
+pub(crate) fn set_pending_reward_tokens(env: &Env, pending: &Map<Address, i128>) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PendingRewardTokens, pending);
+}
+
+/// Get the extra reward tokens owed to pools and players
+pub(crate) fn get_reward_token_reserves(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::RewardTokenReserves)
+        .unwrap_or(Map::new(env))
+}
+
+/// Set the extra reward tokens owed to pools and players
+pub(crate) fn set_reward_token_reserves(env: &Env, reserves: &Map<Address, i128>) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::RewardTokenReserves, reserves);
+}
+
+/// Get an epoch's extra reward token pools
+pub(crate) fn get_epoch_reward_tokens(env: &Env, epoch: u32) -> Vec<RewardTokenPool> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::EpochRewardTokens(epoch))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set an epoch's extra reward token pools
+pub(crate) fn set_epoch_reward_tokens(env: &Env, epoch: u32, pools: &Vec<RewardTokenPool>) {
+    let key = ExtDataKey::EpochRewardTokens(epoch);
+    env.storage().temporary().set(&key, pools);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the approvers for sensitive admin operations (none by default)
+pub(crate) fn get_approvers(env: &Env) -> ApproverSet {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::Approvers)
+        .unwrap_or(ApproverSet {
+            approvers: Vec::new(env),
+            threshold: 0,
+        })
+}
+
+/// Set the approvers for sensitive admin operations
+pub(crate) fn set_approvers(env: &Env, approvers: &ApproverSet) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::Approvers, approvers);
+}
+
+/// Get the approvals recorded for an admin operation (empty if none or expired)
+pub(crate) fn get_approvals(env: &Env, operation: &AdminOperation) -> Vec<Address> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::Approvals(operation.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the approvals recorded for an admin operation
+pub(crate) fn set_approvals(env: &Env, operation: &AdminOperation, approvals: &Vec<Address>) {
+    let key = ExtDataKey::Approvals(operation.clone());
+    env.storage().temporary().set(&key, approvals);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Remove the approvals of an admin operation (executed or all revoked)
+pub(crate) fn remove_approvals(env: &Env, operation: &AdminOperation) {
+    env.storage()
+        .temporary()
+        .remove(&ExtDataKey::Approvals(operation.clone()));
+}
+
+/// Get the number of admin actions ever recorded
+pub(crate) fn get_admin_log_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::AdminLogCount)
+        .unwrap_or(0)
+}
+
+/// Set the number of admin actions ever recorded
+pub(crate) fn set_admin_log_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::AdminLogCount, &count);
+}
+
+/// Get the admin action in a log ring slot
+pub(crate) fn get_admin_log_entry(env: &Env, slot: u32) -> Option<AdminAction> {
+    let key = ExtDataKey::AdminLog(slot);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_admin_log_ttl(env, slot);
+    }
+    result
+}
+
+/// Set the admin action in a log ring slot (overwrites the oldest action)
+pub(crate) fn set_admin_log_entry(env: &Env, slot: u32, entry: &AdminAction) {
+    let key = ExtDataKey::AdminLog(slot);
+    env.storage().persistent().set(&key, entry);
+    extend_admin_log_ttl(env, slot);
+}
+
+/// Get the oldest epoch not yet checked for expired rewards
+pub(crate) fn get_next_sweep_epoch(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextSweepEpoch)
+        .unwrap_or(0)
+}
+
+/// Set the oldest epoch not yet checked for expired rewards
+pub(crate) fn set_next_sweep_epoch(env: &Env, epoch: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSweepEpoch, &epoch);
+}
+
+/// Get player persistent data
+pub(crate) fn get_player(env: &Env, player: &Address) -> Option<Player> {
+    get_user_data(env, player).and_then(|data| data.player)
+}
+
+/// Set player persistent data
+pub(crate) fn set_player(env: &Env, player: &Address, data: &Player) {
+    update_user_data(env, player, |user| user.player = Some(data.clone()));
+}
+
+/// Get a user's packed persistent state
+///
+/// Falls back to the legacy per-field entries for users not yet migrated.
+pub(crate) fn get_user_data(env: &Env, user: &Address) -> Option<UserData> {
+    let key = DataKey::User(user.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_user_data_ttl(env, user);
+        return result;
+    }
+    get_legacy_user_data(env, user)
+}
+
+/// Set a user's packed persistent state
+///
+/// The first write for a user migrates them: legacy per-field entries are
+/// removed (their values must already be in `data`, see `get_user_data`).
+pub(crate) fn set_user_data(env: &Env, user: &Address, data: &UserData) {
+    let key = DataKey::User(user.clone());
+    let storage = env.storage().persistent();
+    if !storage.has(&key) {
+        storage.remove(&DataKey::Player(user.clone()));
+        storage.remove(&DataKey::Referrer(user.clone()));
+        storage.remove(&DataKey::Vesting(user.clone()));
+        storage.remove(&DataKey::BalanceCheckpoint(user.clone()));
+    }
+    storage.set(&key, data);
+    extend_user_data_ttl(env, user);
+}
+
+/// Move a user's legacy per-field entries into the packed entry
+///
+/// # Returns
+/// true if legacy entries were migrated, false if there was nothing to migrate
+pub(crate) fn migrate_user_data(env: &Env, user: &Address) -> bool {
+    if env.storage().persistent().has(&DataKey::User(user.clone())) {
+        return false;
+    }
+    match get_legacy_user_data(env, user) {
+        Some(data) => {
+            set_user_data(env, user, &data);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Read-modify-write a user's packed state (creating it if needed)
+fn update_user_data(env: &Env, user: &Address, update: impl FnOnce(&mut UserData)) {
+    let mut data = get_user_data(env, user).unwrap_or(UserData {
+        player: None,
+        referrer: None,
+        vesting: None,
+        balance_checkpoint: None,
+        claimer: None,
+        claim_history: Vec::new(env),
+        achievements: no_achievements(env),
+        deposit_lock: None,
+        titles: Vec::new(env),
+        claim_cursor: 0,
+    });
+    update(&mut data);
+    set_user_data(env, user, &data);
+}
+
+/// Assemble a user's state from the legacy per-field entries (None if there are none)
+fn get_legacy_user_data(env: &Env, user: &Address) -> Option<UserData> {
+    let storage = env.storage().persistent();
+    let data = UserData {
+        player: storage.get(&DataKey::Player(user.clone())),
+        referrer: storage.get(&DataKey::Referrer(user.clone())),
+        vesting: storage.get(&DataKey::Vesting(user.clone())),
+        balance_checkpoint: storage.get(&DataKey::BalanceCheckpoint(user.clone())),
+        claimer: None,
+        claim_history: Vec::new(env),
+        achievements: no_achievements(env),
+        deposit_lock: None,
+        titles: Vec::new(env),
+        claim_cursor: 0,
+    };
+    let is_empty = data.player.is_none()
+        && data.referrer.is_none()
+        && data.vesting.is_none()
+        && data.balance_checkpoint.is_none();
+    (!is_empty).then_some(data)
+}
+
+/// Check if a user is on the whitelist
+pub(crate) fn is_whitelisted(env: &Env, user: &Address) -> bool {
+    let key = DataKey::Whitelisted(user.clone());
+    let storage = env.storage().persistent();
+    let whitelisted = storage.has(&key);
+    if whitelisted {
+        storage.extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+    }
+    whitelisted
+}
+
+/// Add a user to or remove them from the whitelist
+pub(crate) fn set_whitelisted(env: &Env, user: &Address, whitelisted: bool) {
+    let key = DataKey::Whitelisted(user.clone());
+    let storage = env.storage().persistent();
+    if whitelisted {
+        storage.set(&key, &true);
+        storage.extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+    } else {
+        storage.remove(&key);
+    }
+}
+
+/// Get epoch-specific player data
+pub(crate) fn get_epoch_player(env: &Env, epoch: u32, player: &Address) -> Option<EpochPlayer> {
+    let key = DataKey::EpochPlayer(epoch, player.clone());
+    let result: Option<EpochPlayer> = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_player_ttl(env, epoch, player);
+    }
+    result
+}
+
+/// Set epoch-specific player data
+pub(crate) fn set_epoch_player(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
+    let key = DataKey::EpochPlayer(epoch, player.clone());
+    env.storage().temporary().set(&key, data);
+    extend_epoch_player_ttl(env, epoch, player);
+}
+
+/// Check if epoch player exists
+pub(crate) fn has_epoch_player(env: &Env, epoch: u32, player: &Address) -> bool {
+    let exists = env
+        .storage()
+        .temporary()
+        .has(&DataKey::EpochPlayer(epoch, player.clone()));
+    if exists {
+        extend_epoch_player_ttl(env, epoch, player);
+    }
+    exists
+}
+
+/// Get epoch metadata
+pub(crate) fn get_epoch(env: &Env, epoch: u32) -> Option<EpochInfo> {
+    let key = DataKey::Epoch(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_ttl(env, epoch);
+    }
+    result
+}
+
+/// Set epoch metadata
+pub(crate) fn set_epoch(env: &Env, epoch: u32, data: &EpochInfo) {
+    let key = DataKey::Epoch(epoch);
+    env.storage().temporary().set(&key, data);
+    extend_epoch_ttl(env, epoch);
+}
+
+/// Get game session
+pub(crate) fn get_session(env: &Env, session_id: u32) -> Option<GameSession> {
+    let key = DataKey::Session(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_session_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set game session
+pub(crate) fn set_session(env: &Env, session_id: u32, data: &GameSession) {
+    let key = DataKey::Session(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_session_ttl(env, session_id);
+}
+
+/// Get the match details a game attached to a session
+pub(crate) fn get_session_metadata(env: &Env, session_id: u32) -> Option<SessionMetadata> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::SessionMetadata(session_id))
+}
+
+/// Set the match details a game attached to a session
+pub(crate) fn set_session_metadata(env: &Env, session_id: u32, metadata: &SessionMetadata) {
+    let key = ExtDataKey::SessionMetadata(session_id);
+    env.storage().temporary().set(&key, metadata);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get the margin of victory a game reported for a session, in bps
+pub(crate) fn get_session_margin(env: &Env, session_id: u32) -> Option<u32> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::SessionMargin(session_id))
+}
+
+/// Set the margin of victory a game reported for a session, in bps
+pub(crate) fn set_session_margin(env: &Env, session_id: u32, margin_bps: u32) {
+    let key = ExtDataKey::SessionMargin(session_id);
+    env.storage().temporary().set(&key, &margin_bps);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Check if session exists
+pub(crate) fn has_session(env: &Env, session_id: u32) -> bool {
+    let exists = env.storage().temporary().has(&DataKey::Session(session_id));
+    if exists {
+        extend_session_ttl(env, session_id);
+    }
+    exists
+}
+
+/// Get series state for a session (None if the session is not a series)
+pub(crate) fn get_series(env: &Env, session_id: u32) -> Option<GameSeries> {
+    let key = DataKey::Series(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_series_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set series state for a session
+pub(crate) fn set_series(env: &Env, session_id: u32, data: &GameSeries) {
+    let key = DataKey::Series(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_series_ttl(env, session_id);
+}
+
+/// Get the provisional result of a session (None if none is pending)
+pub(crate) fn get_provisional_result(env: &Env, session_id: u32) -> Option<ProvisionalResult> {
+    let key = DataKey::ProvisionalResult(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_provisional_result_ttl(env, session_id);
+    }
+    result
+}
+
+/// Check if a session has a provisional result
+pub(crate) fn has_provisional_result(env: &Env, session_id: u32) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::ProvisionalResult(session_id))
+}
+
+/// Set the provisional result of a session
+pub(crate) fn set_provisional_result(env: &Env, session_id: u32, data: &ProvisionalResult) {
+    let key = DataKey::ProvisionalResult(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_provisional_result_ttl(env, session_id);
+}
+
+/// Remove the provisional result of a session (finalized or resolved)
+pub(crate) fn remove_provisional_result(env: &Env, session_id: u32) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::ProvisionalResult(session_id));
+}
+
+/// Get team game session data
+pub(crate) fn get_team_session(env: &Env, session_id: u32) -> Option<TeamSession> {
+    let key = DataKey::TeamSession(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_team_session_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set team game session data
+pub(crate) fn set_team_session(env: &Env, session_id: u32, data: &TeamSession) {
+    let key = DataKey::TeamSession(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_team_session_ttl(env, session_id);
+}
+
+/// Get commit-reveal move state for a session
+pub(crate) fn get_moves(env: &Env, session_id: u32) -> Option<MoveCommits> {
+    let key = DataKey::Moves(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_moves_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set commit-reveal move state for a session
+pub(crate) fn set_moves(env: &Env, session_id: u32, data: &MoveCommits) {
+    let key = DataKey::Moves(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_moves_ttl(env, session_id);
+}
+
+/// Get the spectator prediction totals for a session
+pub(crate) fn get_prediction_pool(env: &Env, session_id: u32) -> Option<PredictionPool> {
+    let key = DataKey::PredictionPool(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_prediction_pool_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set the spectator prediction totals for a session
+pub(crate) fn set_prediction_pool(env: &Env, session_id: u32, pool: &PredictionPool) {
+    let key = DataKey::PredictionPool(session_id);
+    env.storage().temporary().set(&key, pool);
+    extend_prediction_pool_ttl(env, session_id);
+}
+
+/// Get a spectator's prediction on a session
+pub(crate) fn get_prediction(
+    env: &Env,
+    session_id: u32,
+    predictor: &Address,
+) -> Option<Prediction> {
+    let key = DataKey::Prediction(session_id, predictor.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_prediction_ttl(env, session_id, predictor);
+    }
+    result
+}
+
+/// Set a spectator's prediction on a session
+pub(crate) fn set_prediction(
+    env: &Env,
+    session_id: u32,
+    predictor: &Address,
+    prediction: &Prediction,
+) {
+    let key = DataKey::Prediction(session_id, predictor.clone());
+    env.storage().temporary().set(&key, prediction);
+    extend_prediction_ttl(env, session_id, predictor);
+}
+
+/// Remove a claimed prediction
+pub(crate) fn remove_prediction(env: &Env, session_id: u32, predictor: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::Prediction(session_id, predictor.clone()));
+}
+
+/// Get the session IDs tracked for a player (empty if none)
+pub(crate) fn get_active_sessions(env: &Env, player: &Address) -> Vec<u32> {
+    let key = DataKey::ActiveSessions(player.clone());
+    let result: Option<Vec<u32>> = env.storage().temporary().get(&key);
+    match result {
+        Some(sessions) => {
+            extend_active_sessions_ttl(env, player);
+            sessions
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Set the session IDs tracked for a player
+pub(crate) fn set_active_sessions(env: &Env, player: &Address, sessions: &Vec<u32>) {
+    let key = DataKey::ActiveSessions(player.clone());
+    env.storage().temporary().set(&key, sessions);
+    extend_active_sessions_ttl(env, player);
+}
+
+/// Get game registration info (None for pending self-registrations)
+pub(crate) fn get_game_info(env: &Env, game_id: &Address) -> Option<GameInfo> {
+    get_game_registration(env, game_id).filter(|info| !info.pending)
+}
+
+/// Get game registration info, including pending self-registrations
+pub(crate) fn get_game_registration(env: &Env, game_id: &Address) -> Option<GameInfo> {
+    let key = DataKey::Game(game_id.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_game_ttl(env, game_id);
+    }
+    result
+}
+
+/// Set game registration info
+pub(crate) fn set_game_info(env: &Env, game_id: &Address, info: &GameInfo) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Game(game_id.clone()), info);
+    extend_game_ttl(env, game_id);
+}
+
+/// Check if a game contract is registered (replaces is_game_whitelisted)
+pub(crate) fn is_game_registered(env: &Env, game_id: &Address) -> bool {
+    get_game_info(env, game_id).is_some()
+}
+
+/// Remove game registration (approved or pending)
+pub(crate) fn remove_game_info(env: &Env, game_id: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Game(game_id.clone()));
+}
+
+/// Get the registered and pending games, in registration order
+pub(crate) fn get_game_list(env: &Env) -> Vec<Address> {
+    let key = ExtDataKey::GameList;
+    match env.storage().persistent().get(&key) {
+        Some(games) => {
+            extend_game_list_ttl(env);
+            games
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Set the registered and pending games
+pub(crate) fn set_game_list(env: &Env, games: &Vec<Address>) {
+    env.storage().persistent().set(&ExtDataKey::GameList, games);
+    extend_game_list_ttl(env);
+}
+
+/// Check if a registered game is deprecated
+pub(crate) fn is_game_deprecated(env: &Env, game_id: &Address) -> bool {
+    let key = ExtDataKey::GameDeprecated(game_id.clone());
+    let deprecated = env.storage().persistent().has(&key);
+    if deprecated {
+        extend_game_deprecated_ttl(env, game_id);
+    }
+    deprecated
+}
+
+/// Mark a game as deprecated, or clear the marker
+pub(crate) fn set_game_deprecated(env: &Env, game_id: &Address, deprecated: bool) {
+    let key = ExtDataKey::GameDeprecated(game_id.clone());
+    if deprecated {
+        env.storage().persistent().set(&key, &true);
+        extend_game_deprecated_ttl(env, game_id);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get a game's lifetime session counters (zeroed if it never had a session)
+pub(crate) fn get_game_totals(env: &Env, game_id: &Address) -> GameTotals {
+    let key = ExtDataKey::GameTotals(game_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(totals) => {
+            extend_game_totals_ttl(env, game_id);
+            totals
+        }
+        None => GameTotals {
+            sessions_started: 0,
+            sessions_finished: 0,
+            total_wagered: 0,
+        },
+    }
+}
+
+/// Set a game's lifetime session counters
+pub(crate) fn set_game_totals(env: &Env, game_id: &Address, totals: &GameTotals) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::GameTotals(game_id.clone()), totals);
+    extend_game_totals_ttl(env, game_id);
+}
+
+/// Get a game's unsettled 1v1 sessions in an epoch (session ID -> start ledger)
+pub(crate) fn get_game_sessions(env: &Env, game_id: &Address, epoch: u32) -> Map<u32, u32> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::GameSessions(game_id.clone(), epoch))
+        .unwrap_or(Map::new(env))
+}
+
+/// Set a game's unsettled 1v1 sessions in an epoch
+pub(crate) fn set_game_sessions(
+    env: &Env,
+    game_id: &Address,
+    epoch: u32,
+    sessions: &Map<u32, u32>,
+) {
+    let key = ExtDataKey::GameSessions(game_id.clone(), epoch);
+    env.storage().temporary().set(&key, sessions);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get a game's session load (nothing started yet by default)
+pub(crate) fn get_game_load(env: &Env, game_id: &Address) -> GameLoad {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::GameLoad(game_id.clone()))
+        .unwrap_or(GameLoad {
+            active_sessions: Vec::new(env),
+            ledger: 0,
+            started_in_ledger: 0,
+        })
+}
+
+/// Set a game's session load
+pub(crate) fn set_game_load(env: &Env, game_id: &Address, load: &GameLoad) {
+    let key = ExtDataKey::GameLoad(game_id.clone());
+    env.storage().temporary().set(&key, load);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get per-epoch game contribution data
+pub(crate) fn get_epoch_game(env: &Env, epoch: u32, game_id: &Address) -> Option<EpochGame> {
+    let key = DataKey::EpochGame(epoch, game_id.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_game_ttl(env, epoch, game_id);
+    }
+    result
+}
+
+/// Set per-epoch game contribution data
+pub(crate) fn set_epoch_game(env: &Env, epoch: u32, game_id: &Address, data: &EpochGame) {
+    let key = DataKey::EpochGame(epoch, game_id.clone());
+    env.storage().temporary().set(&key, data);
+    extend_epoch_game_ttl(env, epoch, game_id);
+}
+
+/// Check if player has claimed rewards for an epoch
+pub(crate) fn has_claimed(env: &Env, player: &Address, epoch: u32) -> bool {
+    let claimed = env
+        .storage()
+        .temporary()
+        .has(&DataKey::Claimed(player.clone(), epoch));
+    if claimed {
+        extend_claimed_ttl(env, player, epoch);
+    }
+    claimed
+}
+
+/// Mark rewards as claimed for player and epoch
+pub(crate) fn set_claimed(env: &Env, player: &Address, epoch: u32) {
+    let key = DataKey::Claimed(player.clone(), epoch);
+    env.storage().temporary().set(&key, &true);
+    extend_claimed_ttl(env, player, epoch);
+}
+
+/// Check if developer has claimed rewards for a game in an epoch
+pub(crate) fn has_dev_claimed(env: &Env, game_id: &Address, epoch: u32) -> bool {
+    let claimed = env
+        .storage()
+        .temporary()
+        .has(&DataKey::DevClaimed(game_id.clone(), epoch));
+    if claimed {
+        extend_dev_claimed_ttl(env, game_id, epoch);
+    }
+    claimed
+}
+
+/// Mark developer rewards as claimed for game and epoch
+pub(crate) fn set_dev_claimed(env: &Env, game_id: &Address, epoch: u32) {
+    let key = DataKey::DevClaimed(game_id.clone(), epoch);
+    env.storage().temporary().set(&key, &true);
+    extend_dev_claimed_ttl(env, game_id, epoch);
+}
+
+/// Get Merkle-drop distribution data for an epoch
+pub(crate) fn get_epoch_merkle(env: &Env, epoch: u32) -> Option<EpochMerkle> {
+    let key = DataKey::EpochMerkle(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_merkle_ttl(env, epoch);
+    }
+    result
+}
+
+/// Set Merkle-drop distribution data for an epoch
+pub(crate) fn set_epoch_merkle(env: &Env, epoch: u32, data: &EpochMerkle) {
+    let key = DataKey::EpochMerkle(epoch);
+    env.storage().temporary().set(&key, data);
+    extend_epoch_merkle_ttl(env, epoch);
+}
+
+/// Get reward payout tracking for an epoch (defaults to nothing paid, not expired)
+pub(crate) fn get_epoch_payouts(env: &Env, epoch: u32) -> EpochPayouts {
+    let key = DataKey::EpochPayouts(epoch);
+    let result: Option<EpochPayouts> = env.storage().temporary().get(&key);
+    match result {
+        Some(payouts) => {
+            extend_epoch_payouts_ttl(env, epoch);
+            payouts
+        }
+        None => EpochPayouts {
+            total_claimed: 0,
+            expired: false,
+            blnd_claimed: 0,
+            player_claimed: 0,
+        },
+    }
+}
+
+/// Set reward payout tracking for an epoch
+pub(crate) fn set_epoch_payouts(env: &Env, epoch: u32, data: &EpochPayouts) {
+    let key = DataKey::EpochPayouts(epoch);
+    env.storage().temporary().set(&key, data);
+    extend_epoch_payouts_ttl(env, epoch);
+}
+
+/// Get a player's Elo rating for a game
+pub(crate) fn get_rating(env: &Env, player: &Address, game_id: &Address) -> Option<u32> {
+    let key = DataKey::Rating(player.clone(), game_id.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_rating_ttl(env, player, game_id);
+    }
+    result
+}
+
+/// Set a player's Elo rating for a game
+pub(crate) fn set_rating(env: &Env, player: &Address, game_id: &Address, rating: u32) {
+    let key = DataKey::Rating(player.clone(), game_id.clone());
+    env.storage().persistent().set(&key, &rating);
+    extend_rating_ttl(env, player, game_id);
+}
+
+/// Get a faction's display metadata (None if never set)
+pub(crate) fn get_faction_info(env: &Env, faction: u32) -> Option<FactionInfo> {
+    let key = DataKey::FactionInfo(faction);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_faction_info_ttl(env, faction);
+    }
+    result
+}
+
+/// Set a faction's display metadata
+pub(crate) fn set_faction_info(env: &Env, faction: u32, info: &FactionInfo) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FactionInfo(faction), info);
+    extend_faction_info_ttl(env, faction);
+}
+
+/// Get a player's referrer
+pub(crate) fn get_referrer(env: &Env, player: &Address) -> Option<Address> {
+    get_user_data(env, player).and_then(|data| data.referrer)
+}
+
+/// Set a player's referrer
+pub(crate) fn set_referrer(env: &Env, player: &Address, referrer: &Address) {
+    update_user_data(env, player, |user| user.referrer = Some(referrer.clone()));
+}
+
+/// Get the address a user approved to claim rewards on their behalf
+pub(crate) fn get_claimer(env: &Env, user: &Address) -> Option<Address> {
+    get_user_data(env, user).and_then(|data| data.claimer)
+}
+
+/// Set or clear a user's approved claimer
+pub(crate) fn set_claimer(env: &Env, user: &Address, claimer: &Option<Address>) {
+    update_user_data(env, user, |data| data.claimer = claimer.clone());
+}
+
+/// Get a user's deposit lock, expired or not (None if they never locked)
+pub(crate) fn get_deposit_lock(env: &Env, user: &Address) -> Option<DepositLock> {
+    get_user_data(env, user).and_then(|data| data.deposit_lock)
+}
+
+/// Set a user's deposit lock
+pub(crate) fn set_deposit_lock(env: &Env, user: &Address, lock: &DepositLock) {
+    update_user_data(env, user, |data| data.deposit_lock = Some(lock.clone()));
+}
+
+/// Maximum claim records kept per user (the oldest are dropped first)
+pub(crate) const MAX_CLAIM_HISTORY: u32 = 32;
+
+/// Get the first epoch `claim_all` hasn't scanned for a user (0 if never run)
+pub(crate) fn get_claim_cursor(env: &Env, user: &Address) -> u32 {
+    get_user_data(env, user).map_or(0, |data| data.claim_cursor)
+}
+
+/// Set the first epoch `claim_all` hasn't scanned for a user
+pub(crate) fn set_claim_cursor(env: &Env, user: &Address, epoch: u32) {
+    update_user_data(env, user, |data| data.claim_cursor = epoch);
+}
+
+/// Maximum titles kept per user (the oldest are dropped first)
+pub(crate) const MAX_TITLES: u32 = 32;
+
+/// Get a user's titles, oldest first (empty if none)
+pub(crate) fn get_titles(env: &Env, user: &Address) -> Vec<Title> {
+    get_user_data(env, user)
+        .map(|data| data.titles)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Award a title, dropping the oldest beyond `MAX_TITLES`
+pub(crate) fn push_title(env: &Env, user: &Address, title: Title) {
+    update_user_data(env, user, |data| {
+        data.titles.push_back(title);
+        while data.titles.len() > MAX_TITLES {
+            data.titles.pop_front();
+        }
+    });
+}
+
+/// Achievements of a user who hasn't finished a game yet
+fn no_achievements(env: &Env) -> Achievements {
+    Achievements {
+        unlocked: Vec::new(env),
+        games_played: 0,
+        win_streak: 0,
+    }
+}
+
+/// Get an epoch's quests (none by default)
+pub(crate) fn get_quests(env: &Env, epoch: u32) -> Vec<Quest> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::Quests(epoch))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set an epoch's quests
+pub(crate) fn set_quests(env: &Env, epoch: u32, quests: &Vec<Quest>) {
+    let key = ExtDataKey::Quests(epoch);
+    env.storage().temporary().set(&key, quests);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get a player's progress on an epoch's quests (sessions counted per quest)
+pub(crate) fn get_quest_progress(env: &Env, epoch: u32, player: &Address) -> Vec<u32> {
+    env.storage()
+        .temporary()
+        .get(&ExtDataKey::QuestProgress(epoch, player.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set a player's progress on an epoch's quests
+pub(crate) fn set_quest_progress(env: &Env, epoch: u32, player: &Address, progress: &Vec<u32>) {
+    let key = ExtDataKey::QuestProgress(epoch, player.clone());
+    env.storage().temporary().set(&key, progress);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+}
+
+/// Get a user's achievements (none unlocked if they haven't played)
+pub(crate) fn get_achievements(env: &Env, user: &Address) -> Achievements {
+    get_user_data(env, user)
+        .map(|data| data.achievements)
+        .unwrap_or_else(|| no_achievements(env))
+}
+
+/// Set a user's achievements
+pub(crate) fn set_achievements(env: &Env, user: &Address, achievements: &Achievements) {
+    update_user_data(env, user, |data| data.achievements = achievements.clone());
+}
+
+/// Get a user's claim records, oldest first
+pub(crate) fn get_claim_history(env: &Env, user: &Address) -> Vec<ClaimRecord> {
+    get_user_data(env, user)
+        .map(|data| data.claim_history)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a claim record, dropping the oldest beyond `MAX_CLAIM_HISTORY`
+pub(crate) fn push_claim_record(env: &Env, user: &Address, record: ClaimRecord) {
+    update_user_data(env, user, |data| {
+        data.claim_history.push_back(record);
+        while data.claim_history.len() > MAX_CLAIM_HISTORY {
+            data.claim_history.pop_front();
+        }
+    });
+}
+
+/// Get a referrer's unclaimed referral rewards (0 if none)
+pub(crate) fn get_referral_balance(env: &Env, referrer: &Address) -> i128 {
+    let key = DataKey::ReferralBalance(referrer.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_referral_balance_ttl(env, referrer);
+    }
+    result.unwrap_or(0)
+}
+
+/// Set a referrer's unclaimed referral rewards
+pub(crate) fn set_referral_balance(env: &Env, referrer: &Address, amount: i128) {
+    let key = DataKey::ReferralBalance(referrer.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_referral_balance_ttl(env, referrer);
+}
+
+/// Get the Blend pool b-tokens held for a player (DirectPool backend)
+pub(crate) fn get_pool_b_tokens(env: &Env, player: &Address) -> i128 {
+    let key = DataKey::PoolBTokens(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_pool_b_tokens_ttl(env, player);
+    }
+    result.unwrap_or(0)
+}
+
+/// Set the Blend pool b-tokens held for a player (DirectPool backend)
+pub(crate) fn set_pool_b_tokens(env: &Env, player: &Address, amount: i128) {
+    let key = DataKey::PoolBTokens(player.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_pool_b_tokens_ttl(env, player);
+}
+
+/// Get a player's vesting schedule
+pub(crate) fn get_vesting(env: &Env, player: &Address) -> Option<VestingSchedule> {
+    get_user_data(env, player).and_then(|data| data.vesting)
+}
+
+/// Set a player's vesting schedule
+pub(crate) fn set_vesting(env: &Env, player: &Address, schedule: &VestingSchedule) {
+    update_user_data(env, player, |user| user.vesting = Some(schedule.clone()));
+}
+
+/// Remove a fully claimed vesting schedule
+pub(crate) fn remove_vesting(env: &Env, player: &Address) {
+    update_user_data(env, player, |user| user.vesting = None);
+}
+
+/// Get a player's last balance checkpoint
+pub(crate) fn get_balance_checkpoint(env: &Env, player: &Address) -> Option<BalanceCheckpoint> {
+    get_user_data(env, player).and_then(|data| data.balance_checkpoint)
+}
+
+/// Set a player's balance checkpoint
+pub(crate) fn set_balance_checkpoint(env: &Env, player: &Address, checkpoint: &BalanceCheckpoint) {
+    update_user_data(env, player, |user| {
+        user.balance_checkpoint = Some(checkpoint.clone())
+    });
+}
+
+/// Get the last event sequence number for a user (0 before their first event)
+pub(crate) fn get_user_event_seq(env: &Env, user: &Address) -> u64 {
+    let key = DataKey::UserEventSeq(user.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_user_event_seq_ttl(env, user);
+    }
+    result.unwrap_or(0)
+}
+
+/// Set the last event sequence number for a user
+pub(crate) fn set_user_event_seq(env: &Env, user: &Address, seq: u64) {
+    let key = DataKey::UserEventSeq(user.clone());
+    env.storage().persistent().set(&key, &seq);
+    extend_user_event_seq_ttl(env, user);
+}
+
+/// Get a player's stats for a game
+pub(crate) fn get_player_stats(
+    env: &Env,
+    player: &Address,
+    game_id: &Address,
+) -> Option<PlayerGameStats> {
+    let key = DataKey::PlayerStats(player.clone(), game_id.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_player_stats_ttl(env, player, game_id);
+    }
+    result
+}
+
+/// Set a player's stats for a game
+pub(crate) fn set_player_stats(
+    env: &Env,
+    player: &Address,
+    game_id: &Address,
+    stats: &PlayerGameStats,
+) {
+    let key = DataKey::PlayerStats(player.clone(), game_id.clone());
+    env.storage().persistent().set(&key, stats);
+    extend_player_stats_ttl(env, player, game_id);
+}
+
+/// Get the games a player has finished sessions in (empty if none)
+pub(crate) fn get_player_games(env: &Env, player: &Address) -> Vec<Address> {
+    let key = DataKey::PlayerGames(player.clone());
+    let result: Option<Vec<Address>> = env.storage().persistent().get(&key);
+    match result {
+        Some(games) => {
+            extend_player_games_ttl(env, player);
+            games
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Set the games a player has finished sessions in
+pub(crate) fn set_player_games(env: &Env, player: &Address, games: &Vec<Address>) {
+    let key = DataKey::PlayerGames(player.clone());
+    env.storage().persistent().set(&key, games);
+    extend_player_games_ttl(env, player);
+}
+
+/// Check if a session ID has ever been used (even if its session has expired)
+pub(crate) fn is_session_id_used(env: &Env, session_id: u32) -> bool {
+    let used = env
+        .storage()
+        .persistent()
+        .has(&DataKey::SessionUsed(session_id));
+    if used {
+        extend_session_used_ttl(env, session_id);
+    }
+    used
+}
+
+/// Permanently mark a session ID as used
+pub(crate) fn set_session_id_used(env: &Env, session_id: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SessionUsed(session_id), &true);
+    extend_session_used_ttl(env, session_id);
+}
+
+/// Get a game's session nonce (0 if it never derived a session ID)
+pub(crate) fn get_game_nonce(env: &Env, game_id: &Address) -> u32 {
+    let key = DataKey::GameNonce(game_id.clone());
+    let result: Option<u32> = env.storage().persistent().get(&key);
+    match result {
+        Some(nonce) => {
+            extend_game_nonce_ttl(env, game_id);
+            nonce
+        }
+        None => 0,
+    }
+}
+
+/// Set a game's session nonce
+pub(crate) fn set_game_nonce(env: &Env, game_id: &Address, nonce: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::GameNonce(game_id.clone()), &nonce);
+    extend_game_nonce_ttl(env, game_id);
+}
+
+// ============================================================================
+// Storage Layout Migrations
+// ============================================================================
+// The storage layout is versioned (`ExtDataKey::StorageVersion`):
+//
+// - v1: per-user state in per-field entries (Player, Referrer, Vesting,
+//   BalanceCheckpoint)
+// - v2: per-user state packed into one `User` entry
+//
+// Contracts deployed before versioning have no stored version and are v1;
+// new deployments start at `STORAGE_VERSION`. After an upgrade, the admin
+// calls `migrate_storage` on the new code, which runs each `migrate_vN_to_vM`
+// step between the stored version and `STORAGE_VERSION` in order. Steps
+// over entries that can't be enumerated on-chain (such as per-user ones)
+// migrate the keys passed in; code reading those entries must keep a
+// fallback for keys migrated later.
+//
+// To change the layout: bump `STORAGE_VERSION`, add a step with per-key
+// migration functions and run it from `migrate_storage`.
+
+/// Storage layout version of this code
+pub(crate) const STORAGE_VERSION: u32 = 2;
+
+/// Get the stored layout version (1 for contracts deployed before versioning)
+pub(crate) fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::StorageVersion)
+        .unwrap_or(1)
+}
+
+/// Set the stored layout version
+pub(crate) fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::StorageVersion, &version);
+}
+
+/// Run the migrations from the stored layout version up to `STORAGE_VERSION`
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `users` - Users whose per-user entries to migrate
+///
+/// # Returns
+/// (version migrated from, entries migrated)
+///
+/// # Errors
+/// * `InvalidConfig` - If the stored layout is newer than this code
+pub(crate) fn migrate_storage(
+    env: &Env,
+    users: &Vec<Address>,
+) -> Result<(u32, u32), crate::errors::Error> {
+    let from = get_storage_version(env);
+    if from > STORAGE_VERSION {
+        return Err(crate::errors::Error::InvalidConfig);
+    }
+
+    let mut migrated = 0;
+    if from < 2 {
+        migrated += migrate_v1_to_v2(env, users);
+    }
 
-    /// Reward claim tracking - Claimed(player_address, epoch_number) -> bool (Temporary storage)
-    Claimed(Address, u32),
+    set_storage_version(env, STORAGE_VERSION);
+    Ok((from, migrated))
+}
 
-    /// Developer reward claim tracking - DevClaimed(game_address, epoch_number) -> bool (Temporary storage)
-    DevClaimed(Address, u32),
+/// v1 -> v2: pack the given users' per-field entries into `User` entries
+///
+/// Users not passed in are migrated on their next write (or with
+/// `migrate_user_data`); until then `get_user_data` reads the legacy entries.
+fn migrate_v1_to_v2(env: &Env, users: &Vec<Address>) -> u32 {
+    let mut migrated = 0;
+    for user in users.iter() {
+        if migrate_user_data(env, &user) {
+            migrated += 1;
+        }
+    }
+    migrated
 }
 
 // ============================================================================
-// Storage Utilities
+// Epoch Data Pruning
 // ============================================================================
 
-/// Get the admin address
-pub(crate) fn get_admin(env: &Env) -> Address {
+/// Number of temporary entries kept per epoch (see `remove_epoch_data`)
+pub(crate) const EPOCH_DATA_KEYS: u32 = 8 + FACTION_COUNT + 3;
+
+/// Get the oldest epoch whose data hasn't been pruned
+pub(crate) fn get_prune_cursor(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .get(&DataKey::Admin)
-        .expect("Admin not set")
+        .get(&ExtDataKey::PruneCursor)
+        .unwrap_or(0)
 }
 
-/// Set the admin address
-pub(crate) fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&DataKey::Admin, admin);
+/// Set the oldest epoch whose data hasn't been pruned
+pub(crate) fn set_prune_cursor(env: &Env, epoch: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PruneCursor, &epoch);
 }
 
-/// Get the global configuration
-pub(crate) fn get_config(env: &Env) -> Config {
+/// Get the USDC paid per epoch pruned
+pub(crate) fn get_prune_bounty(env: &Env) -> i128 {
     env.storage()
         .instance()
-        .get(&DataKey::Config)
-        .expect("Config not set")
+        .get(&ExtDataKey::PruneBounty)
+        .unwrap_or(0)
 }
 
-/// Set the global configuration
-pub(crate) fn set_config(env: &Env, config: &Config) {
-    env.storage().instance().set(&DataKey::Config, config);
+/// Set the USDC paid per epoch pruned
+pub(crate) fn set_prune_bounty(env: &Env, bounty: i128) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PruneBounty, &bounty);
 }
 
-/// Get the current epoch number
-pub(crate) fn get_current_epoch(env: &Env) -> u32 {
+/// Get the USDC set aside for prune bounties
+pub(crate) fn get_prune_bounty_fund(env: &Env) -> i128 {
     env.storage()
         .instance()
-        .get(&DataKey::CurrentEpoch)
+        .get(&ExtDataKey::PruneBountyFund)
         .unwrap_or(0)
 }
 
-/// Set the current epoch number
-pub(crate) fn set_current_epoch(env: &Env, epoch: u32) {
-    env.storage().instance().set(&DataKey::CurrentEpoch, &epoch);
+/// Set the USDC set aside for prune bounties
+pub(crate) fn set_prune_bounty_fund(env: &Env, balance: i128) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PruneBountyFund, &balance);
 }
 
-/// Get player persistent data
-pub(crate) fn get_player(env: &Env, player: &Address) -> Option<Player> {
-    let key = DataKey::Player(player.clone());
-    let result = env.storage().persistent().get(&key);
-    if result.is_some() {
-        extend_player_ttl(env, player);
+/// Delete an epoch's entries: its info (first, so claims stop resolving),
+/// merkle root, payouts, BLND burned, quests, reward cap, boosts, extra
+/// reward token pools, top contributors and parameter votes
+///
+/// Per-player entries of the epoch (EpochPlayer, Claimed, QuestProgress,
+/// ParameterVoted) can't be enumerated and are left to expire.
+///
+/// # Returns
+/// Number of entries that existed and were removed
+pub(crate) fn remove_epoch_data(env: &Env, epoch: u32) -> u32 {
+    let mut removed = 0;
+    for key in [
+        DataKey::Epoch(epoch),
+        DataKey::EpochMerkle(epoch),
+        DataKey::EpochPayouts(epoch),
+    ] {
+        removed += remove_temporary(env, &key);
     }
-    result
-}
-
-/// Set player persistent data
-pub(crate) fn set_player(env: &Env, player: &Address, data: &Player) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::Player(player.clone()), data);
-    extend_player_ttl(env, player);
+    for key in [
+        ExtDataKey::EpochBlndBurned(epoch),
+        ExtDataKey::Quests(epoch),
+        ExtDataKey::EpochRewardCap(epoch),
+        ExtDataKey::EpochBoosts(epoch),
+        ExtDataKey::EpochRewardTokens(epoch),
+    ] {
+        removed += remove_temporary(env, &key);
+    }
+    for faction in 0..FACTION_COUNT {
+        removed += remove_temporary(env, &ExtDataKey::TopContributors(epoch, faction));
+    }
+    for parameter in [
+        GovernanceParameter::DevRewardShare,
+        GovernanceParameter::UnderdogBonus,
+        GovernanceParameter::EpochDuration,
+    ] {
+        removed += remove_temporary(env, &ExtDataKey::ParameterVotes(parameter, epoch));
+    }
+    removed
 }
 
-/// Get epoch-specific player data
-pub(crate) fn get_epoch_player(env: &Env, epoch: u32, player: &Address) -> Option<EpochPlayer> {
-    let key = DataKey::EpochPlayer(epoch, player.clone());
-    let result: Option<EpochPlayer> = env.storage().temporary().get(&key);
-    if result.is_some() {
-        extend_epoch_player_ttl(env, epoch, player);
+/// Remove a temporary entry if present (1 if it existed)
+fn remove_temporary<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> u32 {
+    let storage = env.storage().temporary();
+    if !storage.has(key) {
+        return 0;
     }
-    result
+    storage.remove(key);
+    1
 }
 
-/// Set epoch-specific player data
-pub(crate) fn set_epoch_player(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
-    let key = DataKey::EpochPlayer(epoch, player.clone());
-    env.storage().temporary().set(&key, data);
-    extend_epoch_player_ttl(env, epoch, player);
-}
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) management ensures data doesn't expire unexpectedly.
+// Every getter/setter (and `has_*` check that finds an entry) extends the TTL of
+// the entry it touches, and `bump_entries` lets anyone extend arbitrary keys.
+//
+// TTL Strategy per storage class (in ledgers, ~5 seconds per ledger):
+// - Instance: Contract-wide data (Admin, Config, CurrentEpoch, Paused)
+//   Extended whenever config is read, i.e. on practically every entrypoint.
+//   Threshold 7 days, extend to 30 days.
+// - Persistent: Cross-epoch data (Player, Game whitelist, Rating, referrals, stats)
+//   Archived (not deleted) on expiry, but restoring requires a separate
+//   transaction, so long-inactive players get a longer window.
+//   Threshold 30 days, extend to 120 days.
+// - Temporary: Epoch-specific data (EpochPlayer, Epoch, Session, Claimed, ...)
+//   Deleted permanently on expiry. Only needs to outlive the claim window.
+//   Threshold 7 days, extend to 30 days from last interaction.
+//   Session entries (Session, Series, TeamSession, Moves) only matter until the
+//   game ends, so `Config::max_session_ledgers` can shorten their TTL; outcomes
+//   remain in GameEnded events, stats and faction standings.
 
-/// Check if epoch player exists
-pub(crate) fn has_epoch_player(env: &Env, epoch: u32, player: &Address) -> bool {
-    env.storage()
-        .temporary()
-        .has(&DataKey::EpochPlayer(epoch, player.clone()))
-}
+/// Instance storage: extend if < 7 days remaining
+pub(crate) const INSTANCE_TTL_THRESHOLD: u32 = 120_960;
+/// Instance storage: extend to 30 days
+pub(crate) const INSTANCE_TTL_EXTEND_TO: u32 = 518_400;
 
-/// Get epoch metadata
-pub(crate) fn get_epoch(env: &Env, epoch: u32) -> Option<EpochInfo> {
-    let key = DataKey::Epoch(epoch);
-    let result = env.storage().temporary().get(&key);
-    if result.is_some() {
-        extend_epoch_ttl(env, epoch);
-    }
-    result
-}
+/// Persistent storage: extend if < 30 days remaining
+pub(crate) const PERSISTENT_TTL_THRESHOLD: u32 = 518_400;
+/// Persistent storage: extend to 120 days
+pub(crate) const PERSISTENT_TTL_EXTEND_TO: u32 = 2_073_600;
 
-/// Set epoch metadata
-pub(crate) fn set_epoch(env: &Env, epoch: u32, data: &EpochInfo) {
-    let key = DataKey::Epoch(epoch);
-    env.storage().temporary().set(&key, data);
-    extend_epoch_ttl(env, epoch);
+/// Temporary storage: extend if < 7 days remaining
+pub(crate) const TEMPORARY_TTL_THRESHOLD: u32 = 120_960;
+/// Temporary storage: extend to 30 days
+pub(crate) const TEMPORARY_TTL_EXTEND_TO: u32 = 518_400;
+
+/// Approximate ledger close time, for converting durations to ledgers
+pub(crate) const SECONDS_PER_LEDGER: u64 = 5;
+
+/// TTL threshold and extension for session entries
+///
+/// Uses `Config::max_session_ledgers` when set, otherwise the temporary defaults.
+fn session_ttl(env: &Env) -> (u32, u32) {
+    match get_config(env).max_session_ledgers {
+        0 => (TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO),
+        extend_to => (TEMPORARY_TTL_THRESHOLD.min(extend_to / 2), extend_to),
+    }
 }
 
-/// Get game session
-pub(crate) fn get_session(env: &Env, session_id: u32) -> Option<GameSession> {
-    let key = DataKey::Session(session_id);
-    let result = env.storage().temporary().get(&key);
-    if result.is_some() {
-        extend_session_ttl(env, session_id);
+/// Extend the TTL of an arbitrary storage entry, if it exists
+///
+/// Dispatches on the key to the storage class it lives in. Missing and
+/// already-expired entries are skipped (extending them would fail).
+///
+/// # Returns
+/// `true` if the entry exists and its TTL was extended
+pub(crate) fn bump_entry(env: &Env, key: &DataKey) -> bool {
+    match key {
+        DataKey::Admin
+        | DataKey::Config
+        | DataKey::CurrentEpoch
+        | DataKey::Paused
+        | DataKey::CarryoverBlnd
+        | DataKey::NextSweepEpoch
+        | DataKey::TotalPoolBTokens
+        | DataKey::EventSeq
+        | DataKey::PendingRewards
+        | DataKey::Guardian
+        | DataKey::PoolBTokenRate
+        | DataKey::YieldHistory
+        | DataKey::PendingBlnd
+        | DataKey::ReservedBlnd
+        | DataKey::Arbiter
+        | DataKey::FactionTreasuries
+        | DataKey::KeeperRegistry => {
+            extend_instance_ttl(env);
+            true
+        }
+        DataKey::Player(_)
+        | DataKey::Game(_)
+        | DataKey::Rating(_, _)
+        | DataKey::Referrer(_)
+        | DataKey::FactionInfo(_)
+        | DataKey::ReferralBalance(_)
+        | DataKey::PlayerStats(_, _)
+        | DataKey::PlayerGames(_)
+        | DataKey::SessionUsed(_)
+        | DataKey::GameNonce(_)
+        | DataKey::PoolBTokens(_)
+        | DataKey::UserEventSeq(_)
+        | DataKey::Vesting(_)
+        | DataKey::BalanceCheckpoint(_)
+        | DataKey::User(_)
+        | DataKey::Whitelisted(_)
+        | DataKey::TreasuryProposal(_) => {
+            let storage = env.storage().persistent();
+            if !storage.has(key) {
+                return false;
+            }
+            storage.extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+            true
+        }
+        DataKey::Session(_)
+        | DataKey::Series(_)
+        | DataKey::TeamSession(_)
+        | DataKey::Moves(_)
+        | DataKey::ProvisionalResult(_) => {
+            let storage = env.storage().temporary();
+            if !storage.has(key) {
+                return false;
+            }
+            let (threshold, extend_to) = session_ttl(env);
+            storage.extend_ttl(key, threshold, extend_to);
+            true
+        }
+        _ => {
+            let storage = env.storage().temporary();
+            if !storage.has(key) {
+                return false;
+            }
+            storage.extend_ttl(key, TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO);
+            true
+        }
     }
-    result
 }
 
-/// Set game session
-pub(crate) fn set_session(env: &Env, session_id: u32, data: &GameSession) {
-    let key = DataKey::Session(session_id);
-    env.storage().temporary().set(&key, data);
-    extend_session_ttl(env, session_id);
+/// Extend TTL for a user's packed state (persistent storage)
+/// Should be called whenever user data is read/written
+pub(crate) fn extend_user_data_ttl(env: &Env, user: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::User(user.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Check if session exists
-pub(crate) fn has_session(env: &Env, session_id: u32) -> bool {
-    env.storage().temporary().has(&DataKey::Session(session_id))
+/// Extend TTL for game registration data (persistent storage)
+/// Should be called whenever game data is read/written
+pub(crate) fn extend_game_ttl(env: &Env, game_id: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Game(game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Get game registration info
-pub(crate) fn get_game_info(env: &Env, game_id: &Address) -> Option<GameInfo> {
-    let key = DataKey::Game(game_id.clone());
-    let result = env.storage().persistent().get(&key);
-    if result.is_some() {
-        extend_game_ttl(env, game_id);
-    }
-    result
+/// Extend TTL for the game list (persistent storage)
+pub(crate) fn extend_game_list_ttl(env: &Env) {
+    env.storage().persistent().extend_ttl(
+        &ExtDataKey::GameList,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Set game registration info
-pub(crate) fn set_game_info(env: &Env, game_id: &Address, info: &GameInfo) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::Game(game_id.clone()), info);
-    extend_game_ttl(env, game_id);
+/// Extend TTL for a game's deprecation marker (persistent storage)
+pub(crate) fn extend_game_deprecated_ttl(env: &Env, game_id: &Address) {
+    env.storage().persistent().extend_ttl(
+        &ExtDataKey::GameDeprecated(game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Check if a game contract is registered (replaces is_game_whitelisted)
-pub(crate) fn is_game_registered(env: &Env, game_id: &Address) -> bool {
-    get_game_info(env, game_id).is_some()
+/// Extend TTL for a game's lifetime session counters (persistent storage)
+pub(crate) fn extend_game_totals_ttl(env: &Env, game_id: &Address) {
+    env.storage().persistent().extend_ttl(
+        &ExtDataKey::GameTotals(game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Remove game registration
-pub(crate) fn remove_game_info(env: &Env, game_id: &Address) {
-    env.storage()
-        .persistent()
-        .remove(&DataKey::Game(game_id.clone()));
+/// Extend TTL for Elo rating data (persistent storage)
+/// Should be called whenever rating data is read/written
+pub(crate) fn extend_rating_ttl(env: &Env, player: &Address, game_id: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Rating(player.clone(), game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Get per-epoch game contribution data
-pub(crate) fn get_epoch_game(env: &Env, epoch: u32, game_id: &Address) -> Option<EpochGame> {
-    let key = DataKey::EpochGame(epoch, game_id.clone());
-    let result = env.storage().temporary().get(&key);
-    if result.is_some() {
-        extend_epoch_game_ttl(env, epoch, game_id);
-    }
-    result
+/// Extend TTL for faction metadata (persistent storage)
+/// Should be called whenever faction metadata is read/written
+pub(crate) fn extend_faction_info_ttl(env: &Env, faction: u32) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::FactionInfo(faction),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Set per-epoch game contribution data
-pub(crate) fn set_epoch_game(env: &Env, epoch: u32, game_id: &Address, data: &EpochGame) {
-    let key = DataKey::EpochGame(epoch, game_id.clone());
-    env.storage().temporary().set(&key, data);
-    extend_epoch_game_ttl(env, epoch, game_id);
+/// Extend TTL for referral balance data (persistent storage)
+/// Should be called whenever referral balance data is read/written
+pub(crate) fn extend_referral_balance_ttl(env: &Env, referrer: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::ReferralBalance(referrer.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Check if player has claimed rewards for an epoch
-pub(crate) fn has_claimed(env: &Env, player: &Address, epoch: u32) -> bool {
-    env.storage()
-        .temporary()
-        .has(&DataKey::Claimed(player.clone(), epoch))
+/// Extend TTL for a player's pool b-token balance (persistent storage)
+/// Should be called whenever the balance is read/written
+pub(crate) fn extend_pool_b_tokens_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::PoolBTokens(player.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Mark rewards as claimed for player and epoch
-pub(crate) fn set_claimed(env: &Env, player: &Address, epoch: u32) {
-    let key = DataKey::Claimed(player.clone(), epoch);
-    env.storage().temporary().set(&key, &true);
-    extend_claimed_ttl(env, player, epoch);
+/// Extend TTL for a user's event sequence number (persistent storage)
+/// Should be called whenever the sequence number is read/written
+pub(crate) fn extend_user_event_seq_ttl(env: &Env, user: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::UserEventSeq(user.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Check if developer has claimed rewards for a game in an epoch
-pub(crate) fn has_dev_claimed(env: &Env, game_id: &Address, epoch: u32) -> bool {
-    env.storage()
-        .temporary()
-        .has(&DataKey::DevClaimed(game_id.clone(), epoch))
+/// Extend TTL for player stats data (persistent storage)
+/// Should be called whenever player stats data is read/written
+pub(crate) fn extend_player_stats_ttl(env: &Env, player: &Address, game_id: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::PlayerStats(player.clone(), game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-/// Mark developer rewards as claimed for game and epoch
-pub(crate) fn set_dev_claimed(env: &Env, game_id: &Address, epoch: u32) {
-    let key = DataKey::DevClaimed(game_id.clone(), epoch);
-    env.storage().temporary().set(&key, &true);
-    extend_dev_claimed_ttl(env, game_id, epoch);
+/// Extend TTL for player games list (persistent storage)
+/// Should be called whenever the player games list is read/written
+pub(crate) fn extend_player_games_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::PlayerGames(player.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) management ensures data doesn't expire unexpectedly
-// Based on Soroban best practices:
-// - Instance storage: Tied to contract lifetime (Admin, Config, CurrentEpoch, Paused)
-// - Persistent storage: Cross-epoch data (Player, Game whitelist) - extends to 30 days when accessed
-// - Temporary storage: Epoch-specific data (EpochPlayer, Epoch, Claimed, Session) - 30 days from last interaction
-//
-// Storage Type Summary:
-// - Instance: Config-type variables that persist for contract lifetime
-// - Persistent: Player data and game whitelist that must survive across epochs
-// - Temporary: Epoch-specific data that expires 30 days after last access
-
-/// TTL thresholds and extensions (in ledgers, ~5 seconds per ledger)
-/// ~30 days = 518,400 ledgers
-/// ~7 days = 120,960 ledgers
-const TTL_THRESHOLD_LEDGERS: u32 = 120_960; // Extend if < 7 days remaining
-const TTL_EXTEND_TO_LEDGERS: u32 = 518_400; // Extend to 30 days
-
-/// Extend TTL for player data
-/// Should be called whenever player data is read/written
-pub(crate) fn extend_player_ttl(env: &Env, player: &Address) {
+/// Extend TTL for a used session ID marker (persistent storage)
+/// Should be called whenever the marker is read/written
+pub(crate) fn extend_session_used_ttl(env: &Env, session_id: u32) {
     env.storage().persistent().extend_ttl(
-        &DataKey::Player(player.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        &DataKey::SessionUsed(session_id),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
     );
 }
 
-/// Extend TTL for game registration data (persistent storage)
-/// Should be called whenever game data is read/written
-pub(crate) fn extend_game_ttl(env: &Env, game_id: &Address) {
+/// Extend TTL for a game's session nonce (persistent storage)
+/// Should be called whenever the nonce is read/written
+pub(crate) fn extend_game_nonce_ttl(env: &Env, game_id: &Address) {
     env.storage().persistent().extend_ttl(
-        &DataKey::Game(game_id.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        &DataKey::GameNonce(game_id.clone()),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
     );
 }
 
@@ -296,8 +2651,8 @@ pub(crate) fn extend_game_ttl(env: &Env, game_id: &Address) {
 pub(crate) fn extend_epoch_player_ttl(env: &Env, epoch: u32, player: &Address) {
     env.storage().temporary().extend_ttl(
         &DataKey::EpochPlayer(epoch, player.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
@@ -306,8 +2661,8 @@ pub(crate) fn extend_epoch_player_ttl(env: &Env, epoch: u32, player: &Address) {
 pub(crate) fn extend_epoch_ttl(env: &Env, epoch: u32) {
     env.storage().temporary().extend_ttl(
         &DataKey::Epoch(epoch),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
@@ -316,18 +2671,103 @@ pub(crate) fn extend_epoch_ttl(env: &Env, epoch: u32) {
 pub(crate) fn extend_claimed_ttl(env: &Env, player: &Address, epoch: u32) {
     env.storage().temporary().extend_ttl(
         &DataKey::Claimed(player.clone(), epoch),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
 /// Extend TTL for game session data (temporary storage)
 /// Should be called whenever session data is read/written
 pub(crate) fn extend_session_ttl(env: &Env, session_id: u32) {
+    let (threshold, extend_to) = session_ttl(env);
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::Session(session_id), threshold, extend_to);
+}
+
+/// Extend TTL for series data (temporary storage)
+/// Should be called whenever series data is read/written
+pub(crate) fn extend_series_ttl(env: &Env, session_id: u32) {
+    let (threshold, extend_to) = session_ttl(env);
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::Series(session_id), threshold, extend_to);
+}
+
+/// Extend TTL for a session's provisional result (temporary storage)
+/// Should be called whenever the provisional result is read/written
+pub(crate) fn extend_provisional_result_ttl(env: &Env, session_id: u32) {
+    let (threshold, extend_to) = session_ttl(env);
+    env.storage().temporary().extend_ttl(
+        &DataKey::ProvisionalResult(session_id),
+        threshold,
+        extend_to,
+    );
+}
+
+/// Extend TTL for team game session data (temporary storage)
+/// Should be called whenever the team session is read/written
+pub(crate) fn extend_team_session_ttl(env: &Env, session_id: u32) {
+    let (threshold, extend_to) = session_ttl(env);
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::TeamSession(session_id), threshold, extend_to);
+}
+
+/// Extend TTL for commit-reveal move state (temporary storage)
+/// Should be called whenever the move state is read/written
+pub(crate) fn extend_moves_ttl(env: &Env, session_id: u32) {
+    let (threshold, extend_to) = session_ttl(env);
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::Moves(session_id), threshold, extend_to);
+}
+
+/// Extend TTL for a session's prediction totals (temporary storage)
+/// Uses the regular temporary TTL so predictions stay claimable after the session expires
+pub(crate) fn extend_prediction_pool_ttl(env: &Env, session_id: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::PredictionPool(session_id),
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for a spectator's prediction (temporary storage)
+pub(crate) fn extend_prediction_ttl(env: &Env, session_id: u32, predictor: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::Prediction(session_id, predictor.clone()),
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for a treasury proposal (persistent storage)
+/// Should be called whenever the proposal is read/written
+pub(crate) fn extend_treasury_proposal_ttl(env: &Env, proposal_id: u32) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::TreasuryProposal(proposal_id),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for an admin log ring slot (persistent storage)
+pub(crate) fn extend_admin_log_ttl(env: &Env, slot: u32) {
+    env.storage().persistent().extend_ttl(
+        &ExtDataKey::AdminLog(slot),
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for a player's active session list (temporary storage)
+/// Should be called whenever the list is read/written
+pub(crate) fn extend_active_sessions_ttl(env: &Env, player: &Address) {
     env.storage().temporary().extend_ttl(
-        &DataKey::Session(session_id),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        &DataKey::ActiveSessions(player.clone()),
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
@@ -336,8 +2776,8 @@ pub(crate) fn extend_session_ttl(env: &Env, session_id: u32) {
 pub(crate) fn extend_epoch_game_ttl(env: &Env, epoch: u32, game_id: &Address) {
     env.storage().temporary().extend_ttl(
         &DataKey::EpochGame(epoch, game_id.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
@@ -346,17 +2786,37 @@ pub(crate) fn extend_epoch_game_ttl(env: &Env, epoch: u32, game_id: &Address) {
 pub(crate) fn extend_dev_claimed_ttl(env: &Env, game_id: &Address, epoch: u32) {
     env.storage().temporary().extend_ttl(
         &DataKey::DevClaimed(game_id.clone(), epoch),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for Merkle-drop distribution data (temporary storage)
+/// Should be called whenever merkle data is read/written
+pub(crate) fn extend_epoch_merkle_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochMerkle(epoch),
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
+    );
+}
+
+/// Extend TTL for reward payout tracking (temporary storage)
+/// Should be called whenever payout data is read/written
+pub(crate) fn extend_epoch_payouts_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochPayouts(epoch),
+        TEMPORARY_TTL_THRESHOLD,
+        TEMPORARY_TTL_EXTEND_TO,
     );
 }
 
 /// Extend TTL for instance storage (contract-wide data)
-/// Should be called during initialization and periodically
+/// Called during initialization and whenever config is read
 pub(crate) fn extend_instance_ttl(env: &Env) {
     env.storage()
         .instance()
-        .extend_ttl(TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
 }
 
 // ============================================================================
@@ -376,6 +2836,47 @@ pub(crate) fn set_pause_state(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::Paused, &paused);
 }
 
+/// Check if a guarded fund-moving entrypoint is in progress
+pub(crate) fn is_in_flight(env: &Env) -> bool {
+    env.storage().instance().has(&ExtDataKey::InFlight)
+}
+
+/// Mark a guarded fund-moving entrypoint as started or finished
+pub(crate) fn set_in_flight(env: &Env, in_flight: bool) {
+    if in_flight {
+        env.storage().instance().set(&ExtDataKey::InFlight, &true);
+    } else {
+        env.storage().instance().remove(&ExtDataKey::InFlight);
+    }
+}
+
+/// Get the last observed Blend pool status (None until first observed)
+pub(crate) fn get_pool_status(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&ExtDataKey::PoolStatus)
+}
+
+/// Record the last observed Blend pool status
+pub(crate) fn set_pool_status(env: &Env, status: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PoolStatus, &status);
+}
+
+/// Check if game starts are blocked while the Blend pool is halted
+pub(crate) fn get_pool_halt_pauses_games(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::PoolHaltPausesGames)
+        .unwrap_or(false)
+}
+
+/// Set whether game starts are blocked while the Blend pool is halted
+pub(crate) fn set_pool_halt_pauses_games(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::PoolHaltPausesGames, &enabled);
+}
+
 /// Check if contract is not paused, return error if paused
 /// Call this at the start of all player-facing functions
 pub(crate) fn require_not_paused(env: &Env) -> Result<(), crate::errors::Error> {
@@ -0,0 +1,42 @@
+use crate::types::{Config, Player, SCALAR_7};
+
+// ============================================================================
+// Participation Streaks
+// ============================================================================
+//
+// A player's streak counts consecutive epochs in which they played at least
+// one game. It is updated at the player's first game of each epoch, and the
+// resulting bonus is snapshotted into their EpochPlayer for that epoch:
+//
+//   streak_bonus  = min((streak - 1) * streak_bonus_per_epoch, max_streak_bonus)
+//   reward_weight = contribution * (1.0 + streak_bonus)
+//
+// Faction totals accumulate the same weights, so boosted shares still sum to
+// at most the reward pool. Faction standings (winner selection) are unboosted.
+
+/// Streak after the player's first game in `current_epoch`
+pub(crate) fn next_streak(player: &Player, current_epoch: u32) -> u32 {
+    if player.streak > 0 && player.last_active_epoch == current_epoch {
+        player.streak
+    } else if player.streak > 0 && player.last_active_epoch + 1 == current_epoch {
+        player.streak.saturating_add(1)
+    } else {
+        1
+    }
+}
+
+/// Streak bonus for a streak length (7 decimals, e.g. 500_000 = +5%)
+pub(crate) fn streak_bonus(config: &Config, streak: u32) -> i128 {
+    let consecutive = streak.saturating_sub(1) as i128;
+    consecutive
+        .saturating_mul(config.streak_bonus_per_epoch)
+        .min(config.max_streak_bonus)
+}
+
+/// Reward weight of a contribution with a streak bonus applied
+///
+/// Kept unscaled (contribution * (SCALAR_7 + bonus)) so faction totals are
+/// exact sums of player weights, with no per-game rounding.
+pub(crate) fn reward_weight(contribution: i128, streak_bonus: i128) -> Option<i128> {
+    contribution.checked_mul(SCALAR_7.checked_add(streak_bonus)?)
+}
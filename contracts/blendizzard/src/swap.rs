@@ -0,0 +1,382 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    token, vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+
+use crate::aggregator::{Client as AggregatorClient, DexDistribution};
+use crate::errors::Error;
+use crate::math::{checked_mul_div, checked_mul_scalar_7, Rounding};
+use crate::price_oracle::{Asset, Client as PriceOracleClient};
+use crate::router::Client as SoroswapRouterClient;
+use crate::types::{Config, SCALAR_7};
+
+// ============================================================================
+// Soroswap Swaps
+// ============================================================================
+
+/// Swap deadline offset (5 minutes)
+const SWAP_DEADLINE_SECONDS: u64 = 300;
+
+/// Oracle prices older than this are treated as unavailable (1 hour)
+const ORACLE_MAX_AGE_SECONDS: u64 = 3600;
+
+/// Swap an exact amount of `token_in` held by this contract into `token_out`
+///
+/// Process:
+/// 1. Capture pre-swap `token_out` balance (for delta calculation)
+/// 2. Authorize `token_in` transfer from this contract to the router pair
+/// 3. Execute swap via Soroswap router (output sent to this contract)
+/// 4. Return the `token_out` delta (only what this swap produced)
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `router` - Soroswap router contract address
+/// * `token_in` - Token sold (must be held by this contract)
+/// * `token_out` - Token bought
+/// * `amount_in` - Exact amount of `token_in` to sell
+/// * `min_out` - Minimum acceptable `token_out` (0 = accept any price)
+///
+/// # Returns
+/// Amount of `token_out` received by this contract
+///
+/// # Errors
+/// * `SlippageExceeded` - If the router quote or the actual output is below `min_out`
+/// * `SwapError` - If the router can't resolve the pair or the swap fails for
+///   any other reason
+pub(crate) fn swap_exact_in(
+    env: &Env,
+    router: &Address,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+) -> Result<i128, Error> {
+    let current_contract = env.current_contract_address();
+
+    // Step 1: Capture pre-swap balance
+    // Following blend-together pattern: only count delta from this operation
+    let out_client = token::Client::new(env, token_out);
+    let pre_balance = out_client.balance(&current_contract);
+
+    // Step 2: Authorize contract to transfer token_in to router pair
+    // Critical: Without this, the token contract will reject the transfer
+    // (a router that can't resolve the pair is a failed swap, not a panic)
+    let router_client = SoroswapRouterClient::new(env, router);
+    let router_pair = match router_client.try_router_pair_for(token_in, token_out) {
+        Ok(Ok(pair)) => pair,
+        _ => return Err(Error::SwapError),
+    };
+
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (current_contract.clone(), router_pair, amount_in).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    // Step 3: Execute swap
+    let path: Vec<Address> = vec![env, token_in.clone(), token_out.clone()];
+    let deadline = env.ledger().timestamp() + SWAP_DEADLINE_SECONDS;
+
+    // Quote first so a below-minimum fill is reported as slippage rather
+    // than as an opaque router revert
+    if min_out > 0 {
+        if let Ok(Ok(amounts)) = router_client.try_router_get_amounts_out(&amount_in, &path) {
+            if amounts.get(1).unwrap_or(0) < min_out {
+                return Err(Error::SlippageExceeded);
+            }
+        }
+    }
+
+    // A reverting swap (e.g. no liquidity) surfaces as SwapError instead of a panic
+    let swap_result = router_client.try_swap_exact_tokens_for_tokens(
+        &amount_in,
+        &min_out,
+        &path,
+        &current_contract, // Send output to this contract
+        &deadline,
+    );
+    if !matches!(swap_result, Ok(Ok(_))) {
+        return Err(Error::SwapError);
+    }
+
+    // Step 4: Calculate delta (only new tokens from this swap)
+    let post_balance = out_client.balance(&current_contract);
+    let received = post_balance.saturating_sub(pre_balance);
+
+    if received < min_out {
+        return Err(Error::SlippageExceeded);
+    }
+
+    Ok(received)
+}
+
+/// Quote swapping an exact amount of `token_in` into `token_out` on Soroswap
+///
+/// Dry run of `swap_exact_in` along the same direct path, via the router's
+/// `get_amounts_out` (includes fees and price impact).
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `router` - Soroswap router contract address
+/// * `token_in` - Token sold
+/// * `token_out` - Token bought
+/// * `amount_in` - Exact amount of `token_in` to sell
+///
+/// # Returns
+/// Expected amount of `token_out`
+///
+/// # Errors
+/// * `InvalidAmount` - If amount_in <= 0
+/// * `SwapError` - If the router can't quote the path (e.g. no pair)
+pub(crate) fn quote_exact_in(
+    env: &Env,
+    router: &Address,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+) -> Result<i128, Error> {
+    if amount_in <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let router_client = SoroswapRouterClient::new(env, router);
+    let path: Vec<Address> = vec![env, token_in.clone(), token_out.clone()];
+    match router_client.try_router_get_amounts_out(&amount_in, &path) {
+        Ok(Ok(amounts)) => Ok(amounts.get(1).unwrap_or(0)),
+        _ => Err(Error::SwapError),
+    }
+}
+
+/// Get the reserves of the router's pair for two tokens, in the order given
+///
+/// Returns None if the router, its factory or the pair can't be queried.
+pub(crate) fn try_pair_reserves(
+    env: &Env,
+    router: &Address,
+    token_a: &Address,
+    token_b: &Address,
+) -> Option<(i128, i128)> {
+    let router_client = SoroswapRouterClient::new(env, router);
+    let factory = router_client.try_get_factory().ok()?.ok()?;
+    router_client
+        .try_get_reserves(&factory, token_a, token_b)
+        .ok()?
+        .ok()
+}
+
+// ============================================================================
+// Soroswap Aggregator
+// ============================================================================
+
+/// Swap with the configured backend
+///
+/// Uses the Soroswap Aggregator when one is configured, falling back to the
+/// Soroswap router if the aggregated swap fails. Without an aggregator this
+/// is `swap_exact_in` on `config.soroswap_router`.
+///
+/// # Errors
+/// Same as `swap_exact_in`
+pub(crate) fn swap_best(
+    env: &Env,
+    config: &Config,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+) -> Result<i128, Error> {
+    if let Some(aggregator) = &config.swap_aggregator {
+        if let Ok(received) = swap_exact_in_aggregated(
+            env,
+            aggregator,
+            &config.aggregator_protocols,
+            token_in,
+            token_out,
+            amount_in,
+            min_out,
+        ) {
+            return Ok(received);
+        }
+    }
+
+    swap_exact_in(
+        env,
+        &config.soroswap_router,
+        token_in,
+        token_out,
+        amount_in,
+        min_out,
+    )
+}
+
+/// Swap an exact amount of `token_in` through the Soroswap Aggregator
+///
+/// `amount_in` is split evenly (one part each) across `protocols`, each
+/// routed directly `token_in -> token_out`. The aggregator pulls `amount_in`
+/// from this contract; the output is sent back to this contract.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `aggregator` - Soroswap Aggregator contract address
+/// * `protocols` - Aggregator protocol IDs to split the swap across
+/// * `token_in` - Token sold (must be held by this contract)
+/// * `token_out` - Token bought
+/// * `amount_in` - Exact amount of `token_in` to sell
+/// * `min_out` - Minimum acceptable `token_out` across all protocols
+///
+/// # Returns
+/// Amount of `token_out` received by this contract
+///
+/// # Errors
+/// * `SwapError` - If no protocol is configured or the aggregator swap fails
+/// * `SlippageExceeded` - If the output is below `min_out`
+pub(crate) fn swap_exact_in_aggregated(
+    env: &Env,
+    aggregator: &Address,
+    protocols: &Vec<String>,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+) -> Result<i128, Error> {
+    if protocols.is_empty() {
+        return Err(Error::SwapError);
+    }
+
+    let current_contract = env.current_contract_address();
+    let out_client = token::Client::new(env, token_out);
+    let pre_balance = out_client.balance(&current_contract);
+
+    let mut distribution: Vec<DexDistribution> = Vec::new(env);
+    for protocol_id in protocols.iter() {
+        distribution.push_back(DexDistribution {
+            protocol_id,
+            path: vec![env, token_in.clone(), token_out.clone()],
+            parts: 1,
+        });
+    }
+
+    // The aggregator transfers token_in from this contract before routing it
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (current_contract.clone(), aggregator.clone(), amount_in).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    let deadline = env.ledger().timestamp() + SWAP_DEADLINE_SECONDS;
+    let swap_result = AggregatorClient::new(env, aggregator).try_swap_exact_tokens_for_tokens(
+        token_in,
+        token_out,
+        &amount_in,
+        &min_out,
+        &distribution,
+        &current_contract,
+        &deadline,
+    );
+    if !matches!(swap_result, Ok(Ok(_))) {
+        return Err(Error::SwapError);
+    }
+
+    let received = out_client
+        .balance(&current_contract)
+        .saturating_sub(pre_balance);
+    if received < min_out {
+        return Err(Error::SlippageExceeded);
+    }
+
+    Ok(received)
+}
+
+// ============================================================================
+// Oracle Price Sanity Check
+// ============================================================================
+
+/// Check that swapping `amount_in` on Soroswap would not fill far below the oracle price
+///
+/// Compares the router quote against the output implied by the configured
+/// SEP-40 oracle (cross price token_in / token_out). Swapping at a better
+/// price than the oracle is always allowed; only the downside is bounded by
+/// `config.max_price_deviation`.
+///
+/// Fails closed: a missing, stale or non-positive oracle price, or a failing
+/// router quote, all return `false`.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (oracle, router, deviation threshold)
+/// * `token_in` - Token sold
+/// * `token_out` - Token bought
+/// * `amount_in` - Amount of `token_in` to sell
+///
+/// # Returns
+/// `true` if no oracle is configured or the quote is within tolerance
+pub(crate) fn oracle_allows_swap(
+    env: &Env,
+    config: &Config,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+) -> bool {
+    let Some(oracle) = &config.price_oracle else {
+        return true;
+    };
+
+    // Oracle prices share the same base and decimals, so they cancel out
+    let oracle_client = PriceOracleClient::new(env, oracle);
+    let (Some(price_in), Some(price_out)) = (
+        oracle_price(env, &oracle_client, token_in),
+        oracle_price(env, &oracle_client, token_out),
+    ) else {
+        return false;
+    };
+
+    let Some(oracle_out) = checked_mul_div(amount_in, price_in, price_out, Rounding::Floor) else {
+        return false;
+    };
+    let Some(min_acceptable) = checked_mul_scalar_7(
+        oracle_out,
+        SCALAR_7 - config.max_price_deviation,
+        Rounding::Floor,
+    ) else {
+        return false;
+    };
+
+    // Router quote (includes price impact and fees)
+    let router_client = SoroswapRouterClient::new(env, &config.soroswap_router);
+    let path: Vec<Address> = vec![env, token_in.clone(), token_out.clone()];
+    let quoted_out = match router_client.try_router_get_amounts_out(&amount_in, &path) {
+        Ok(Ok(amounts)) => amounts.get(1).unwrap_or(0),
+        _ => return false,
+    };
+
+    quoted_out >= min_acceptable
+}
+
+/// Fetch a fresh, positive oracle price for a Stellar asset
+fn oracle_price(env: &Env, oracle_client: &PriceOracleClient, token: &Address) -> Option<i128> {
+    let price_data = match oracle_client.try_lastprice(&Asset::Stellar(token.clone())) {
+        Ok(Ok(price_data)) => price_data?,
+        _ => return None,
+    };
+
+    let age = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(price_data.timestamp);
+    if price_data.price <= 0 || age > ORACLE_MAX_AGE_SECONDS {
+        return None;
+    }
+
+    Some(price_data.price)
+}
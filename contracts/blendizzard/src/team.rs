@@ -0,0 +1,275 @@
+use soroban_sdk::{vec, Address, Env, IntoVal as _, Vec};
+
+use crate::errors::Error;
+use crate::events::{emit_team_game_ended, emit_team_game_started};
+use crate::storage;
+use crate::types::{EpochPhase, TeamSession};
+
+// ============================================================================
+// Team Games
+// ============================================================================
+//
+// Team sessions pit two equal-sized teams against each other. Each team plays
+// for a single faction: every member's epoch faction must match. All members
+// wager the same FP, and on settlement each winner's wager counts towards the
+// faction, so the team's contribution is split evenly among teammates. Team
+// sessions share the session ID space and active-session cap with 1v1 games.
+
+/// Smallest and largest supported team
+const MIN_TEAM_SIZE: u32 = 2;
+const MAX_TEAM_SIZE: u32 = 4;
+
+/// Start a team game session
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Address of the game contract
+/// * `session_id` - Unique session identifier
+/// * `team_a` - Team A members
+/// * `team_b` - Team B members
+/// * `wager` - Faction points wagered by each member
+///
+/// # Errors
+/// * `InvalidTeam` - If teams are uneven, outside 2-4 players, share a player,
+///   or a team's members are in different factions
+/// * Same as `start_game`
+pub(crate) fn start_team_game(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    team_a: &Vec<Address>,
+    team_b: &Vec<Address>,
+    wager: i128,
+) -> Result<(), Error> {
+    // SECURITY: Only the registered game contract can start sessions
+    game_id.require_auth();
+
+    let game_info = crate::game::get_playable_game(env, game_id)?;
+
+    let config = storage::get_config(env);
+    if crate::epoch::current_phase(env, &config) == EpochPhase::Selection {
+        return Err(Error::SelectionPhaseActive);
+    }
+
+    if storage::has_session(env, session_id)
+        || storage::get_team_session(env, session_id).is_some()
+        || storage::is_session_id_used(env, session_id)
+    {
+        return Err(Error::SessionAlreadyExists);
+    }
+
+    crate::game::require_wager_in_limits(&game_info, wager)?;
+
+    let team_size = team_a.len();
+    if team_size != team_b.len() || !(MIN_TEAM_SIZE..=MAX_TEAM_SIZE).contains(&team_size) {
+        return Err(Error::InvalidTeam);
+    }
+
+    // Every player may appear only once across both teams
+    let mut members: Vec<Address> = Vec::new(env);
+    for member in team_a.iter().chain(team_b.iter()) {
+        if members.contains(&member) {
+            return Err(Error::InvalidTeam);
+        }
+        members.push_back(member);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    let mut member_sessions = Vec::new(env);
+    for member in members.iter() {
+        // Each member consents to locking their FP
+        member.require_auth_for_args(vec![
+            env,
+            game_id.to_val(),
+            session_id.into_val(env),
+            wager.into_val(env),
+        ]);
+
+        storage::get_player(env, &member).ok_or(Error::FactionNotSelected)?;
+
+        let sessions = crate::game::get_active_sessions(env, &member);
+        if config.max_active_sessions > 0 && sessions.len() >= config.max_active_sessions {
+            return Err(Error::TooManyActiveSessions);
+        }
+        member_sessions.push_back(sessions);
+    }
+    crate::game::reserve_game_session(env, game_id, &game_info, session_id)?;
+    crate::stats::record_session_started(env, game_id);
+
+    let team_a_faction = prepare_team(env, team_a, wager, current_epoch)?;
+    let team_b_faction = prepare_team(env, team_b, wager, current_epoch)?;
+
+    let session = TeamSession {
+        game_id: game_id.clone(),
+        epoch_id: current_epoch,
+        team_a: team_a.clone(),
+        team_b: team_b.clone(),
+        team_a_faction,
+        team_b_faction,
+        wager,
+        team_a_won: None,
+    };
+
+    // Save session and permanently reserve its ID
+    storage::set_team_session(env, session_id, &session);
+    storage::set_session_id_used(env, session_id);
+
+    for (member, mut sessions) in members.iter().zip(member_sessions.iter()) {
+        sessions.push_back(session_id);
+        storage::set_active_sessions(env, &member, &sessions);
+    }
+
+    emit_team_game_started(env, session_id, &session);
+
+    Ok(())
+}
+
+/// End a team game session
+///
+/// Both teams' wagers are consumed; each winner's wager is added to their
+/// contribution and to the winning faction's standings.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - The unique session identifier
+/// * `team_a_won` - true if team A won, false if team B won
+///
+/// # Errors
+/// * `SessionNotFound` - If the team session doesn't exist
+/// * `SessionAlreadyFinalized` - If the session already has an outcome
+/// * `GameExpired` - If game is from a previous epoch
+pub(crate) fn end_team_game(env: &Env, session_id: u32, team_a_won: bool) -> Result<(), Error> {
+    let mut session = storage::get_team_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    // SECURITY: Only the session's game contract can submit outcomes
+    session.game_id.require_auth();
+
+    if session.team_a_won.is_some() {
+        return Err(Error::SessionAlreadyFinalized);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    if session.epoch_id != current_epoch {
+        return Err(Error::GameExpired);
+    }
+
+    session.team_a_won = Some(team_a_won);
+    storage::set_team_session(env, session_id, &session);
+
+    let (winners, losers) = if team_a_won {
+        (&session.team_a, &session.team_b)
+    } else {
+        (&session.team_b, &session.team_a)
+    };
+
+    // Each winner is credited with their own wager plus one opponent's worth of
+    // game FP, so developer totals add up to every wager in the game
+    let per_winner_game_fp = session.wager.checked_mul(2).ok_or(Error::OverflowError)?;
+    let mut fp_contributed: i128 = 0;
+    for (winner, loser) in winners.iter().zip(losers.iter()) {
+        let mut winner_epoch =
+            storage::get_epoch_player(env, current_epoch, &winner).ok_or(Error::PlayerNotFound)?;
+        let loser_epoch =
+            storage::get_epoch_player(env, current_epoch, &loser).ok_or(Error::PlayerNotFound)?;
+
+        // The game's rake comes off every wager (see `rake.rs`)
+        crate::rake::take_rake(
+            env,
+            &session.game_id,
+            current_epoch,
+            &loser_epoch,
+            session.wager,
+        )?;
+        let winner_rake = crate::rake::take_rake(
+            env,
+            &session.game_id,
+            current_epoch,
+            &winner_epoch,
+            session.wager,
+        )?;
+
+        // Net of the rake, scaled by the winner's deposit lock (see `lock.rs`;
+        // nothing below the minimum stake)
+        let credited = if crate::faction_points::meets_min_stake(env, &winner_epoch) {
+            let contribution = crate::faction_points::decayed_contribution(
+                env,
+                current_epoch,
+                session.wager - winner_rake,
+            )?;
+            crate::lock::locked_contribution(env, &winner, contribution)?
+        } else {
+            0
+        };
+        winner_epoch.total_fp_contributed = winner_epoch
+            .total_fp_contributed
+            .checked_add(credited)
+            .ok_or(Error::OverflowError)?;
+        fp_contributed = fp_contributed
+            .checked_add(credited)
+            .ok_or(Error::OverflowError)?;
+        storage::set_epoch_player(env, current_epoch, &winner, &winner_epoch);
+
+        crate::game::update_epoch_on_game_end(
+            env,
+            &winner,
+            credited,
+            &session.game_id,
+            per_winner_game_fp,
+            current_epoch,
+        )?;
+        crate::activity::record_session(env, current_epoch, &winner)?;
+        crate::activity::record_session(env, current_epoch, &loser)?;
+
+        // Ratings and stats pair team members by position
+        crate::rating::update_ratings(env, &session.game_id, &winner, &loser);
+        crate::stats::record_game(
+            env,
+            &session.game_id,
+            &winner,
+            session.wager,
+            &loser,
+            session.wager,
+        )?;
+        crate::achievements::record_game(env, &winner, &loser);
+    }
+    crate::stats::record_session_finished(env, &session.game_id);
+
+    // Completed session no longer counts towards any member's cap
+    for member in session.team_a.iter().chain(session.team_b.iter()) {
+        storage::set_active_sessions(
+            env,
+            &member,
+            &crate::game::get_active_sessions(env, &member),
+        );
+    }
+
+    emit_team_game_ended(env, session_id, &session, team_a_won, fp_contributed);
+
+    Ok(())
+}
+
+/// Lock FP for every member of a team and check they share one faction
+///
+/// # Returns
+/// The team's faction
+fn prepare_team(
+    env: &Env,
+    team: &Vec<Address>,
+    wager: i128,
+    current_epoch: u32,
+) -> Result<u32, Error> {
+    let mut team_faction = None;
+    for member in team.iter() {
+        crate::game::initialize_player_epoch(env, &member, current_epoch)?;
+        let epoch_data =
+            crate::faction_points::prepare_player_for_game(env, &member, wager, current_epoch)?;
+        let faction = epoch_data.epoch_faction.ok_or(Error::FactionNotSelected)?;
+
+        match team_faction {
+            None => team_faction = Some(faction),
+            Some(f) if f != faction => return Err(Error::InvalidTeam),
+            Some(_) => {}
+        }
+    }
+    team_faction.ok_or(Error::InvalidTeam)
+}
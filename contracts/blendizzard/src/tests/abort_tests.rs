@@ -0,0 +1,113 @@
+/// Emergency Epoch Abort Tests
+///
+/// Tests that `emergency_abort_epoch` ends the current epoch with no winner,
+/// carries pending rewards over, cancels the epoch's sessions and lifts the pause.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a guardian and a started session 1
+fn setup_session(env: &Env) -> BlendizzardClient<'_> {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_guardian(&Some(Address::generate(env)));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+
+    blendizzard
+}
+
+#[test]
+fn test_abort_ends_epoch_and_cancels_sessions() {
+    let env = setup_test_env();
+    let blendizzard = setup_session(&env);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, 100_0000000);
+    });
+
+    env.ledger().with_mut(|li| li.timestamp += 1_000);
+    blendizzard.guardian_pause();
+    let abort_time = env.ledger().timestamp();
+
+    assert_eq!(blendizzard.emergency_abort_epoch(), 1);
+
+    let aborted = blendizzard.get_epoch(&0);
+    assert!(aborted.aborted && aborted.is_finalized);
+    assert_eq!(aborted.winning_faction, None);
+    assert_eq!(aborted.reward_pool, 0);
+    assert_eq!(aborted.end_time, abort_time);
+
+    let next = blendizzard.get_epoch(&1);
+    assert!(!next.aborted && !next.is_finalized);
+    assert_eq!(next.start_time, abort_time);
+
+    // Harvested rewards fund the next epoch instead
+    assert_eq!(blendizzard.get_pending_rewards(), 100_0000000);
+    assert!(!blendizzard.is_paused());
+
+    // The aborted epoch's session can no longer be settled
+    assert_contract_error(&blendizzard.try_end_game(&1, &true), Error::GameExpired);
+}
+
+#[test]
+fn test_abort_requires_guardian_and_open_epoch() {
+    let env = setup_test_env();
+    let blendizzard = setup_session(&env);
+
+    blendizzard.set_guardian(&None);
+    assert_contract_error(
+        &blendizzard.try_emergency_abort_epoch(),
+        Error::FeatureNotConfigured,
+    );
+
+    blendizzard.set_guardian(&Some(Address::generate(&env)));
+    blendizzard.emergency_abort_epoch();
+
+    // The new epoch can't be cycled early, but can be aborted again
+    assert_contract_error(&blendizzard.try_cycle_epoch(), Error::EpochNotReady);
+    assert_eq!(blendizzard.emergency_abort_epoch(), 2);
+}
+
+#[test]
+fn test_abort_waits_for_unprocessed_epoch() {
+    let env = setup_test_env();
+    let blendizzard = setup_session(&env);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    assert_eq!(blendizzard.finalize_epoch(), 1);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, 100_0000000);
+    });
+
+    // The closed epoch's harvested rewards must be paid out first
+    assert_contract_error(
+        &blendizzard.try_emergency_abort_epoch(),
+        Error::EpochNotFinalized,
+    );
+    assert_eq!(blendizzard.get_pending_rewards(), 100_0000000);
+    assert!(!blendizzard.get_epoch(&1).aborted);
+}
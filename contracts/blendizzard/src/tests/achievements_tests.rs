@@ -0,0 +1,167 @@
+/// Achievements Tests
+///
+/// Tests that players unlock first win, win streak, games played and epoch MVP
+/// achievements once each, and that unlocked achievements boost epoch FP.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_blendizzard_with_soroswap,
+    setup_test_env, Error,
+};
+use crate::types::{Achievement, AchievementSettings, Achievements};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_milestones_unlock_once_each() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    blendizzard.set_achievement_settings(&2, &3, &0);
+
+    assert_eq!(
+        blendizzard.get_achievements(&player1),
+        Achievements {
+            unlocked: vec![&env],
+            games_played: 0,
+            win_streak: 0,
+        }
+    );
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&2, &true);
+
+    assert_eq!(
+        blendizzard.get_achievements(&player1),
+        Achievements {
+            unlocked: vec![&env, Achievement::FirstWin, Achievement::WinStreak],
+            games_played: 2,
+            win_streak: 2,
+        }
+    );
+    assert_eq!(blendizzard.get_achievements(&player2).unlocked.len(), 0);
+
+    // A loss resets the streak but keeps unlocked achievements
+    blendizzard.start_game(&game, &3, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&3, &false);
+
+    assert_eq!(
+        blendizzard.get_achievements(&player1),
+        Achievements {
+            unlocked: vec![
+                &env,
+                Achievement::FirstWin,
+                Achievement::WinStreak,
+                Achievement::GamesPlayed
+            ],
+            games_played: 3,
+            win_streak: 0,
+        }
+    );
+    assert_eq!(
+        blendizzard.get_achievements(&player2).unlocked,
+        vec![&env, Achievement::FirstWin, Achievement::GamesPlayed]
+    );
+}
+
+#[test]
+fn test_epoch_mvp_and_fp_bonus() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let vault = MockVaultClient::new(&env, &blendizzard.get_config().fee_vault);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+    // +5% FP per achievement
+    blendizzard.set_achievement_settings(&10, &100, &500_000);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+    for (player, faction) in [(&player1, 0), (&player2, 1), (&player3, 0)] {
+        vault.set_user_balance(player, &100_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+    blendizzard.cycle_epoch();
+
+    // Top contributor of the winning faction is the epoch's MVP
+    assert_eq!(
+        blendizzard.get_achievements(&player1).unlocked,
+        vec![&env, Achievement::FirstWin, Achievement::EpochMvp]
+    );
+    assert_eq!(blendizzard.get_achievements(&player2).unlocked.len(), 0);
+
+    // Two achievements: +10% FP compared to a faction mate without any
+    let boosted_fp = blendizzard.get_epoch_player(&1, &player1).available_fp;
+    let base_fp = blendizzard.get_epoch_player(&1, &player3).available_fp;
+    assert_eq!(boosted_fp, base_fp * 11 / 10);
+}
+
+#[test]
+fn test_set_achievement_settings_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _game, _player1, _player2) = setup_players(&env);
+
+    assert_eq!(
+        blendizzard.get_achievement_settings(),
+        AchievementSettings {
+            win_streak: 10,
+            games_played: 100,
+            fp_bonus: 0,
+        }
+    );
+
+    assert_contract_error(
+        &blendizzard.try_set_achievement_settings(&0, &100, &0),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_achievement_settings(&10, &0, &0),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_achievement_settings(&10, &100, &1_000_001),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_achievement_settings(&10, &100, &-1),
+        Error::InvalidConfig,
+    );
+}
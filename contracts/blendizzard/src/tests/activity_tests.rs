@@ -0,0 +1,180 @@
+/// Activity Requirement Tests
+///
+/// Tests that winners only count towards the reward split, and can only claim,
+/// once they meet the epoch's minimum games / FP requirement.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::create_token;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, unboosted_weights, Error,
+};
+use crate::types::{ActivityRequirement, DistributionMode, EpochInfo, EpochPlayer, SCALAR_7};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_wins_count_towards_rewards_once_requirement_is_met() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    blendizzard.set_activity_requirement(&2, &0);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+
+    // One game: the win counts towards standings but not the reward split
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(epoch0.faction_standings.get(0), Some(10_0000000));
+    assert_eq!(epoch0.faction_weighted_standings.get(0).unwrap_or(0), 0);
+    let epoch_player = blendizzard.get_epoch_player(&0, &player1);
+    assert_eq!(epoch_player.games_completed, 1);
+    assert!(!epoch_player.reward_eligible);
+
+    // A second game (even a loss) qualifies: the earlier win is added
+    blendizzard.start_game(&game, &2, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&2, &false);
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(
+        epoch0.faction_weighted_standings.get(0),
+        Some(10_0000000 * SCALAR_7)
+    );
+    assert!(blendizzard.get_epoch_player(&0, &player1).reward_eligible);
+    assert!(blendizzard.get_epoch_player(&0, &player2).reward_eligible);
+
+    // Later wins add to the split as they happen
+    blendizzard.start_game(&game, &3, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&3, &true);
+    assert_eq!(
+        blendizzard.get_epoch(&0).faction_weighted_standings.get(0),
+        Some(15_0000000 * SCALAR_7)
+    );
+}
+
+#[test]
+fn test_ineligible_winner_cannot_claim() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let usdc = create_token(&env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &usdc.address,
+        345_600,
+        vec![&env, 1],
+    );
+    usdc.mint(&blendizzard.address, &1000_0000000);
+
+    let fp = 100_0000000i128;
+    let mut faction_standings = Map::new(&env);
+    faction_standings.set(0, fp);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
+        faction_standings,
+        reward_pool: 1000_0000000,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(&env, 0, &epoch_info);
+        crate::storage::set_epoch_player(
+            &env,
+            0,
+            &player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 1,
+                reward_eligible: false,
+            },
+        );
+    });
+    vault.set_user_balance(&player, &10_0000000);
+
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward(&player, &0),
+        Error::NoRewardsAvailable,
+    );
+}
+
+#[test]
+fn test_set_activity_requirement() {
+    let env = setup_test_env();
+    let (blendizzard, _game, _player1, _player2) = setup_players(&env);
+
+    assert_eq!(
+        blendizzard.get_activity_requirement(),
+        ActivityRequirement {
+            min_games: 0,
+            min_fp: 0,
+        }
+    );
+
+    blendizzard.set_activity_requirement(&3, &50_0000000);
+    assert_eq!(
+        blendizzard.get_activity_requirement(),
+        ActivityRequirement {
+            min_games: 3,
+            min_fp: 50_0000000,
+        }
+    );
+
+    assert_contract_error(
+        &blendizzard.try_set_activity_requirement(&0, &-1),
+        Error::InvalidConfig,
+    );
+}
@@ -0,0 +1,139 @@
+/// Admin Log Tests
+///
+/// Tests that admin and role actions are recorded in the bounded on-chain
+/// admin log returned by `get_admin_log`.
+use super::fee_vault_utils::create_mock_vault;
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env};
+
+fn setup<'a>(env: &'a Env, admin: &Address) -> BlendizzardClient<'a> {
+    create_blendizzard_contract(
+        env,
+        admin,
+        &create_mock_vault(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    )
+}
+
+fn hash_name(env: &Env, name: &str) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, name.as_bytes()))
+        .into()
+}
+
+fn hash_value(env: &Env, value: Bytes) -> BytesN<32> {
+    env.crypto().sha256(&value).into()
+}
+
+#[test]
+fn test_config_setter_records_old_and_new_config() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = setup(&env, &admin);
+    assert_eq!(blendizzard.get_admin_log(&0, &10).len(), 0);
+
+    let old_config = blendizzard.get_config();
+    env.ledger().with_mut(|li| li.sequence_number = 250);
+    blendizzard.set_balance_snapshots(&true);
+    let new_config = blendizzard.get_config();
+
+    let log = blendizzard.get_admin_log(&0, &10);
+    assert_eq!(log.len(), 1);
+    let entry = log.get(0).unwrap();
+    assert_eq!(entry.index, 0);
+    assert_eq!(entry.action, hash_name(&env, "set_balance_snapshots"));
+    assert_eq!(entry.old_value, hash_value(&env, old_config.to_xdr(&env)));
+    assert_eq!(entry.new_value, hash_value(&env, new_config.to_xdr(&env)));
+    assert_eq!(entry.ledger, 250);
+    assert_eq!(entry.caller, admin);
+}
+
+#[test]
+fn test_role_actions_are_recorded_newest_first() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let blendizzard = setup(&env, &admin);
+
+    blendizzard.set_guardian(&Some(guardian.clone()));
+    blendizzard.guardian_pause();
+    blendizzard.unpause();
+
+    let log = blendizzard.get_admin_log(&0, &10);
+    assert_eq!(log.len(), 3);
+
+    let unpause = log.get(0).unwrap();
+    assert_eq!(unpause.index, 2);
+    assert_eq!(unpause.action, hash_name(&env, "unpause"));
+    assert_eq!(unpause.old_value, hash_value(&env, true.to_xdr(&env)));
+    assert_eq!(unpause.new_value, hash_value(&env, false.to_xdr(&env)));
+    assert_eq!(unpause.caller, admin);
+
+    let pause = log.get(1).unwrap();
+    assert_eq!(pause.action, hash_name(&env, "guardian_pause"));
+    assert_eq!(pause.caller, guardian);
+
+    let set_guardian = log.get(2).unwrap();
+    assert_eq!(set_guardian.index, 0);
+    assert_eq!(set_guardian.action, hash_name(&env, "set_guardian"));
+    assert_eq!(
+        set_guardian.new_value,
+        hash_value(&env, Some(guardian).to_xdr(&env))
+    );
+}
+
+#[test]
+fn test_game_registration_is_recorded() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let blendizzard = setup(&env, &admin);
+
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.set_game_wager_limits(&game, &1_0000000, &10_0000000);
+    blendizzard.remove_game(&game);
+
+    let log = blendizzard.get_admin_log(&0, &10);
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.get(0).unwrap().action, hash_name(&env, "remove_game"));
+    assert_eq!(
+        log.get(1).unwrap().action,
+        hash_name(&env, "set_game_wager_limits")
+    );
+    assert_eq!(log.get(2).unwrap().action, hash_name(&env, "add_game"));
+    // The removal's old value is the last registration
+    assert_eq!(log.get(0).unwrap().old_value, log.get(1).unwrap().new_value);
+}
+
+#[test]
+fn test_log_keeps_the_newest_actions_and_pages() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = setup(&env, &admin);
+
+    for i in 0..130u32 {
+        blendizzard.set_balance_snapshots(&(i % 2 == 0));
+    }
+
+    // Only the newest 128 are kept
+    let log = blendizzard.get_admin_log(&0, &200);
+    assert_eq!(log.len(), 128);
+    assert_eq!(log.get(0).unwrap().index, 129);
+    assert_eq!(log.get(127).unwrap().index, 2);
+
+    let page = blendizzard.get_admin_log(&10, &5);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().index, 119);
+    assert_eq!(page.get(4).unwrap().index, 115);
+
+    // Pages stop at the oldest kept action
+    assert_eq!(blendizzard.get_admin_log(&126, &5).len(), 2);
+    assert_eq!(blendizzard.get_admin_log(&128, &5).len(), 0);
+}
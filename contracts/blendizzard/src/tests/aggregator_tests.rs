@@ -0,0 +1,137 @@
+/// Soroswap Aggregator Swap Tests
+///
+/// Tests that cycle_epoch routes the BLND → USDC swap through a configured
+/// aggregator (split across the accepted protocols) and falls back to the
+/// Soroswap router when the aggregated swap fails.
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::aggregator::DexDistribution;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, Address, Env, String, Vec};
+
+/// Aggregator stand-in paying 2 `token_out` per `token_in` (better than the 1:1 pool)
+#[contract]
+pub struct MockAggregator;
+
+#[contractimpl]
+impl MockAggregator {
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        distribution: Vec<DexDistribution>,
+        to: Address,
+        _deadline: u64,
+    ) -> Vec<Vec<i128>> {
+        if env.storage().instance().has(&symbol_short!("fail")) {
+            panic!("aggregator unavailable");
+        }
+
+        let aggregator = env.current_contract_address();
+        let amount_out = amount_in * 2;
+        assert!(amount_out >= amount_out_min);
+        token::Client::new(&env, &token_in).transfer(&to, &aggregator, &amount_in);
+        token::Client::new(&env, &token_out).transfer(&aggregator, &to, &amount_out);
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("routes"), &distribution.len());
+        vec![&env, vec![&env, amount_in, amount_out]]
+    }
+
+    pub fn set_fail(env: Env) {
+        env.storage().instance().set(&symbol_short!("fail"), &true);
+    }
+
+    pub fn routes(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("routes"))
+            .unwrap_or(0)
+    }
+}
+
+/// Create Blendizzard whose swaps go through a funded mock aggregator
+fn setup_with_aggregator<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockAggregatorClient<'a>) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_with_soroswap(env, &admin);
+    let config = blendizzard.get_config();
+
+    let aggregator = MockAggregatorClient::new(env, &env.register(MockAggregator, ()));
+    TokenClient::new(env, &config.usdc_token).mint(&aggregator.address, &1_000_000_0000000);
+
+    blendizzard.set_swap_aggregator(
+        &Some(aggregator.address.clone()),
+        &vec![
+            env,
+            String::from_str(env, "soroswap"),
+            String::from_str(env, "phoenix"),
+        ],
+    );
+
+    (blendizzard, aggregator)
+}
+
+fn advance_past_epoch(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+}
+
+#[test]
+fn test_cycle_swaps_through_aggregator() {
+    let env = setup_test_env();
+    let (blendizzard, aggregator) = setup_with_aggregator(&env);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    let blnd_before = blnd.balance(&blendizzard.address);
+    assert!(blnd_before > 0);
+
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+
+    // Split across both protocols, at the aggregator's 2:1 price
+    assert_eq!(aggregator.routes(), 2);
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(epoch0.reward_pool + epoch0.dev_reward_pool, blnd_before * 2);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+}
+
+#[test]
+fn test_cycle_falls_back_to_router_when_aggregator_fails() {
+    let env = setup_test_env();
+    let (blendizzard, aggregator) = setup_with_aggregator(&env);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    let blnd_before = blnd.balance(&blendizzard.address);
+    aggregator.set_fail();
+
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+
+    // Router fill on the 1:1 pool (fees and price impact keep it below 1:1)
+    let epoch0 = blendizzard.get_epoch(&0);
+    let reward_pool = epoch0.reward_pool + epoch0.dev_reward_pool;
+    assert!(reward_pool > 0 && reward_pool < blnd_before);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+}
+
+#[test]
+fn test_set_swap_aggregator_requires_protocols() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_set_swap_aggregator(&Some(Address::generate(&env)), &Vec::new(&env)),
+        Error::InvalidConfig,
+    );
+
+    // Clearing the aggregator needs no protocols
+    blendizzard.set_swap_aggregator(&None, &Vec::new(&env));
+    assert_eq!(blendizzard.get_config().swap_aggregator, None);
+}
@@ -0,0 +1,216 @@
+/// Approvals Tests
+///
+/// Tests that sensitive admin operations need M-of-N approver approvals of the
+/// exact call once an approver set is configured.
+use super::fee_vault_utils::create_mock_vault;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::types::{AdminOperation, ApproverSet};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+/// Create Blendizzard with three approvers and a 2-of-3 threshold
+fn setup<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Vec<Address>) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &create_mock_vault(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    let approvers = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    blendizzard.set_approvers(&approvers, &2);
+
+    (blendizzard, approvers)
+}
+
+#[test]
+fn test_operation_needs_threshold_approvals_of_exact_call() {
+    let env = setup_test_env();
+    let (blendizzard, approvers) = setup(&env);
+    let new_admin = Address::generate(&env);
+    let operation = AdminOperation::SetAdmin(new_admin.clone());
+
+    assert_contract_error(
+        &blendizzard.try_set_admin(&new_admin),
+        ExtError::ApprovalThresholdNotMet,
+    );
+
+    blendizzard.approve_operation(&approvers.get(0).unwrap(), &operation);
+    // Approving twice doesn't count twice
+    blendizzard.approve_operation(&approvers.get(0).unwrap(), &operation);
+    assert_contract_error(
+        &blendizzard.try_set_admin(&new_admin),
+        ExtError::ApprovalThresholdNotMet,
+    );
+
+    // Approvals of another admin don't cover this one
+    blendizzard.approve_operation(
+        &approvers.get(1).unwrap(),
+        &AdminOperation::SetAdmin(Address::generate(&env)),
+    );
+    assert_contract_error(
+        &blendizzard.try_set_admin(&new_admin),
+        ExtError::ApprovalThresholdNotMet,
+    );
+
+    blendizzard.approve_operation(&approvers.get(2).unwrap(), &operation);
+    assert_eq!(
+        blendizzard.get_approvals(&operation),
+        vec![&env, approvers.get(0).unwrap(), approvers.get(2).unwrap()]
+    );
+    blendizzard.set_admin(&new_admin);
+    assert_eq!(blendizzard.get_admin(), new_admin);
+
+    // The approvals were used up
+    assert_eq!(blendizzard.get_approvals(&operation).len(), 0);
+}
+
+#[test]
+fn test_approve_and_revoke_require_an_approver() {
+    let env = setup_test_env();
+    let (blendizzard, approvers) = setup(&env);
+    let operation = AdminOperation::MigrateVault(Address::generate(&env));
+    let approver = approvers.get(0).unwrap();
+
+    assert_contract_error(
+        &blendizzard.try_approve_operation(&Address::generate(&env), &operation),
+        ExtError::NotApprover,
+    );
+    assert_contract_error(
+        &blendizzard.try_revoke_approval(&approver, &operation),
+        ExtError::ApprovalNotFound,
+    );
+
+    blendizzard.approve_operation(&approver, &operation);
+    blendizzard.approve_operation(&approvers.get(1).unwrap(), &operation);
+    blendizzard.revoke_approval(&approver, &operation);
+    assert_eq!(
+        blendizzard.get_approvals(&operation),
+        vec![&env, approvers.get(1).unwrap()]
+    );
+}
+
+#[test]
+fn test_changing_approvers_needs_approvals() {
+    let env = setup_test_env();
+    let (blendizzard, approvers) = setup(&env);
+    let new_admin = Address::generate(&env);
+    let set_admin = AdminOperation::SetAdmin(new_admin.clone());
+    blendizzard.approve_operation(&approvers.get(0).unwrap(), &set_admin);
+    blendizzard.approve_operation(&approvers.get(1).unwrap(), &set_admin);
+
+    // Drop the first approver: needs 2 of the current approvers to agree
+    let remaining = vec![&env, approvers.get(1).unwrap(), approvers.get(2).unwrap()];
+    assert_contract_error(
+        &blendizzard.try_set_approvers(&remaining, &2),
+        ExtError::ApprovalThresholdNotMet,
+    );
+    let change = AdminOperation::SetApprovers(remaining.clone(), 2);
+    blendizzard.approve_operation(&approvers.get(1).unwrap(), &change);
+    blendizzard.approve_operation(&approvers.get(2).unwrap(), &change);
+    blendizzard.set_approvers(&remaining, &2);
+    assert_eq!(
+        blendizzard.get_approvers(),
+        ApproverSet {
+            approvers: remaining,
+            threshold: 2,
+        }
+    );
+
+    // The removed approver's earlier approval no longer counts
+    assert_contract_error(
+        &blendizzard.try_set_admin(&new_admin),
+        ExtError::ApprovalThresholdNotMet,
+    );
+    blendizzard.approve_operation(&approvers.get(2).unwrap(), &set_admin);
+    blendizzard.set_admin(&new_admin);
+}
+
+#[test]
+fn test_set_approvers_validates() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &create_mock_vault(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    let approver = Address::generate(&env);
+
+    assert_eq!(
+        blendizzard.get_approvers(),
+        ApproverSet {
+            approvers: vec![&env],
+            threshold: 0,
+        }
+    );
+    assert_contract_error(
+        &blendizzard.try_set_approvers(&vec![&env, approver.clone()], &2),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_approvers(&vec![&env, approver.clone()], &0),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_approvers(&vec![&env, approver.clone(), approver.clone()], &1),
+        Error::InvalidConfig,
+    );
+
+    // Without a threshold, admin calls need no approvals
+    blendizzard.set_admin(&Address::generate(&env));
+}
+
+#[test]
+fn test_router_change_needs_approvals() {
+    let env = setup_test_env();
+    let (blendizzard, approvers) = setup(&env);
+    let router = Address::generate(&env);
+
+    // Settings without an address need no approvals
+    blendizzard.update_config(&None, &None, &None, &None, &Some(0), &None, &None);
+
+    assert_contract_error(
+        &blendizzard.try_update_config(
+            &Some(router.clone()),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        ),
+        ExtError::ApprovalThresholdNotMet,
+    );
+
+    let operation = AdminOperation::SetSwapContracts(Some(router.clone()), None);
+    blendizzard.approve_operation(&approvers.get(0).unwrap(), &operation);
+    blendizzard.approve_operation(&approvers.get(1).unwrap(), &operation);
+    blendizzard.update_config(
+        &Some(router.clone()),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(blendizzard.get_config().soroswap_router, router);
+}
@@ -0,0 +1,100 @@
+/// BLND Burn Tests
+///
+/// Tests that a configured share of newly claimed BLND is burned (or sent to a
+/// burn address) instead of swapped, and recorded per epoch and in the global
+/// stats.
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_blendizzard_with_soroswap,
+    setup_test_env, Error,
+};
+use crate::types::{BlndBurn, VaultBackend};
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_claimed_blnd_is_burned_before_the_swap() {
+    let env = setup_test_env();
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(&env);
+    let (blend_fixture, blnd, usdc, blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(&env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(&env, &xlm);
+    let pool = create_blend_pool(&env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    // No usable router: BLND that isn't burned carries over
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    let user = Address::generate(&env);
+    usdc_client.mint(&user, &1_000_0000000);
+    blendizzard.deposit(&user, &1_000_0000000);
+
+    let burn_address = Address::generate(&env);
+    blendizzard.set_blnd_burn(&2_500, &Some(burn_address.clone()));
+
+    env.jump(17_280);
+    assert_eq!(blendizzard.harvest(), 0);
+
+    let burned = blnd_client.balance(&burn_address);
+    assert!(burned > 0);
+    let carryover = blendizzard.get_carryover_blnd();
+    assert_eq!(burned, (burned + carryover) / 4);
+    assert_eq!(blendizzard.get_epoch_blnd_burned(&0), burned);
+    assert_eq!(blendizzard.get_blnd_burn().total_burned, burned);
+    assert_eq!(blendizzard.get_global_stats().total_blnd_burned, burned);
+
+    // Carried-over BLND isn't burned again, only the newly claimed BLND
+    blendizzard.set_blnd_burn(&10_000, &None);
+    env.jump(17_280);
+    blendizzard.harvest();
+    assert_eq!(blendizzard.get_carryover_blnd(), carryover);
+    assert_eq!(blnd_client.balance(&blendizzard.address), carryover);
+    assert!(blendizzard.get_epoch_blnd_burned(&0) > burned);
+}
+
+#[test]
+fn test_set_blnd_burn_validates() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_eq!(
+        blendizzard.get_blnd_burn(),
+        BlndBurn {
+            bps: 0,
+            burn_address: None,
+            total_burned: 0,
+        }
+    );
+    assert_contract_error(
+        &blendizzard.try_set_blnd_burn(&10_001, &None),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_blnd_burn(&1_000, &Some(blendizzard.address.clone())),
+        Error::InvalidConfig,
+    );
+
+    // BLND the contract already held wasn't claimed, so none of it is burned
+    blendizzard.set_blnd_burn(&5_000, &None);
+    let blnd = MockTokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    assert!(blendizzard.harvest() > 0);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+    assert_eq!(blendizzard.get_epoch_blnd_burned(&0), 0);
+}
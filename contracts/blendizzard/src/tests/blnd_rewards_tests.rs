@@ -0,0 +1,149 @@
+/// Dual-Token Reward Tests
+///
+/// Tests claims against epochs whose reward pool is partly paid in retained
+/// BLND (`blnd_retention_bps`), alongside the USDC pool.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    unboosted_weights, Error,
+};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 1000_0000000;
+const REWARD_POOL_BLND: i128 = 500_0000000;
+
+/// Create Blendizzard with a finalized epoch 0 won by faction 0, where
+/// `player1` contributed 3/4 and `player2` 1/4 of the faction's FP
+fn setup_dual_token_epoch<'a>(
+    env: &'a Env,
+    player1: &Address,
+    player2: &Address,
+    reward_pool: i128,
+) -> (BlendizzardClient<'a>, TokenClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+    let blnd = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &blnd.address,
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+    blnd.mint(&blendizzard.address, &REWARD_POOL_BLND);
+
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, 400_0000000i128);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: 400_0000000,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: REWARD_POOL_BLND,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        crate::storage::set_reserved_blnd(env, REWARD_POOL_BLND);
+        for (player, fp) in [(player1, 300_0000000i128), (player2, 100_0000000i128)] {
+            crate::storage::set_epoch_player(
+                env,
+                0,
+                player,
+                &EpochPlayer {
+                    epoch_faction: Some(0),
+                    epoch_balance_snapshot: 10_0000000,
+                    available_fp: 0,
+                    total_fp_contributed: fp,
+                    streak_bonus: 0,
+                    games_completed: 0,
+                    reward_eligible: true,
+                },
+            );
+        }
+    });
+    vault.set_user_balance(player1, &10_0000000);
+    vault.set_user_balance(player2, &10_0000000);
+
+    (blendizzard, usdc, blnd)
+}
+
+#[test]
+fn test_claim_pays_usdc_and_blnd_shares() {
+    let env = setup_test_env();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (blendizzard, usdc, blnd) = setup_dual_token_epoch(&env, &player1, &player2, REWARD_POOL);
+
+    assert_eq!(blendizzard.claim_epoch_reward(&player1, &0), 750_0000000);
+    assert_eq!(usdc.balance(&player1), 750_0000000);
+    assert_eq!(blnd.balance(&player1), 375_0000000);
+
+    assert_eq!(blendizzard.claim_epoch_reward(&player2, &0), 250_0000000);
+    assert_eq!(blnd.balance(&player2), 125_0000000);
+
+    env.as_contract(&blendizzard.address, || {
+        assert_eq!(crate::storage::get_reserved_blnd(&env), 0);
+        assert_eq!(
+            crate::storage::get_epoch_payouts(&env, 0).blnd_claimed,
+            REWARD_POOL_BLND
+        );
+    });
+}
+
+#[test]
+fn test_claim_with_only_blnd_pool() {
+    let env = setup_test_env();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (blendizzard, usdc, blnd) = setup_dual_token_epoch(&env, &player1, &player2, 0);
+
+    // No USDC to pay, but the BLND share is still claimable
+    assert_eq!(blendizzard.claim_epoch_reward(&player1, &0), 0);
+    assert_eq!(usdc.balance(&player1), 0);
+    assert_eq!(blnd.balance(&player1), 375_0000000);
+
+    let result = blendizzard.try_claim_epoch_reward(&player1, &0);
+    assert_contract_error(&result, Error::RewardAlreadyClaimed);
+}
+
+#[test]
+fn test_set_blnd_retention_rejects_invalid_values() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    blendizzard.set_blnd_retention(&10_000);
+    assert_eq!(blendizzard.get_config().blnd_retention_bps, 10_000);
+
+    assert_contract_error(
+        &blendizzard.try_set_blnd_retention(&10_001),
+        Error::InvalidConfig,
+    );
+}
@@ -0,0 +1,116 @@
+/// Boost Tests
+///
+/// Tests that sponsors can donate USDC or BLND to the current epoch's reward
+/// pool, that boosts are listed per sponsor and folded into the pool when the
+/// epoch cycles, and that invalid boosts are rejected.
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::types::EpochBoost;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_usdc_boosts_listed_per_sponsor() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let usdc = TokenClient::new(&env, &blendizzard.get_config().usdc_token);
+
+    let sponsor1 = Address::generate(&env);
+    let sponsor2 = Address::generate(&env);
+    usdc.mint(&sponsor1, &300_0000000);
+    usdc.mint(&sponsor2, &50_0000000);
+
+    assert_eq!(
+        blendizzard.boost_epoch(&sponsor1, &usdc.address, &100_0000000),
+        100_0000000
+    );
+    blendizzard.boost_epoch(&sponsor2, &usdc.address, &50_0000000);
+    blendizzard.boost_epoch(&sponsor1, &usdc.address, &200_0000000);
+
+    assert_eq!(
+        blendizzard.get_epoch_boosts(&0),
+        vec![
+            &env,
+            EpochBoost {
+                sponsor: sponsor1.clone(),
+                token: usdc.address.clone(),
+                amount: 300_0000000,
+            },
+            EpochBoost {
+                sponsor: sponsor2,
+                token: usdc.address.clone(),
+                amount: 50_0000000,
+            },
+        ]
+    );
+    assert_eq!(blendizzard.get_pending_rewards(), 350_0000000);
+    assert_eq!(usdc.balance(&sponsor1), 0);
+
+    // The next cycle folds the boosts into epoch 0's reward pools
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(epoch0.reward_pool + epoch0.dev_reward_pool >= 350_0000000);
+    assert_eq!(blendizzard.get_pending_rewards(), 0);
+    assert_eq!(blendizzard.get_epoch_boosts(&1).len(), 0);
+}
+
+#[test]
+fn test_blnd_boost_is_swapped() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    let held_blnd = blnd.balance(&blendizzard.address);
+
+    let sponsor = Address::generate(&env);
+    blnd.mint(&sponsor, &100_0000000);
+    let usdc_amount = blendizzard.boost_epoch(&sponsor, &blnd.address, &100_0000000);
+
+    assert!(usdc_amount > 0);
+    assert_eq!(blendizzard.get_pending_rewards(), usdc_amount);
+    assert_eq!(blnd.balance(&sponsor), 0);
+    // Only the donated BLND is swapped
+    assert_eq!(blnd.balance(&blendizzard.address), held_blnd);
+    assert_eq!(
+        blendizzard
+            .get_epoch_boosts(&0)
+            .get_unchecked(0)
+            .usdc_amount,
+        usdc_amount
+    );
+}
+
+#[test]
+fn test_invalid_boosts_rejected() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let usdc = TokenClient::new(&env, &blendizzard.get_config().usdc_token);
+    let sponsor = Address::generate(&env);
+    usdc.mint(&sponsor, &10_0000000);
+
+    assert_contract_error(
+        &blendizzard.try_boost_epoch(&sponsor, &usdc.address, &0),
+        Error::InvalidAmount,
+    );
+    // Below the 1 USDC minimum
+    assert_contract_error(
+        &blendizzard.try_boost_epoch(&sponsor, &usdc.address, &9999999),
+        Error::InvalidAmount,
+    );
+    assert_contract_error(
+        &blendizzard.try_boost_epoch(&sponsor, &Address::generate(&env), &1_0000000),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.pause();
+    assert_contract_error(
+        &blendizzard.try_boost_epoch(&sponsor, &usdc.address, &1_0000000),
+        Error::ContractPaused,
+    );
+    assert_eq!(usdc.balance(&sponsor), 10_0000000);
+}
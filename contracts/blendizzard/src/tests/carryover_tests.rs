@@ -0,0 +1,116 @@
+/// Reward Carryover Tests
+///
+/// Tests that rewards left unclaimed past the claim window are carried over
+/// into the next reward pool, and that expired epochs reject claims.
+use super::fee_vault_utils::MockVaultClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, unboosted_weights,
+    Error,
+};
+use crate::types::EpochPlayer;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env, Map};
+
+fn advance_past_epoch(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+}
+
+#[test]
+fn test_unclaimed_rewards_carried_over_after_window() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let developer = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    blendizzard.set_claim_window(&1);
+
+    // Epoch 0: nobody plays, the whole pool goes unclaimed
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+    let epoch0 = blendizzard.get_epoch(&0);
+    let unclaimed = epoch0.reward_pool + epoch0.dev_reward_pool;
+    assert!(unclaimed > 0);
+
+    // Epoch 1: no new BLND, so its pool is only epoch 0's expired rewards
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+    assert_eq!(blendizzard.get_epoch(&1).reward_pool, unclaimed);
+    assert!(blendizzard.get_epoch_payouts(&0).expired);
+    assert!(!blendizzard.get_epoch_payouts(&1).expired);
+
+    let result = blendizzard.try_claim_dev_reward(&developer, &0);
+    assert_contract_error(&result, Error::ClaimWindowExpired);
+}
+
+#[test]
+fn test_claimed_rewards_not_carried_over() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let vault = MockVaultClient::new(&env, &blendizzard.get_config().fee_vault);
+    blendizzard.set_claim_window(&1);
+
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+
+    // Make `player` the only contributor to epoch 0's winning faction
+    let player = Address::generate(&env);
+    let fp = 100_0000000i128;
+    let mut epoch0 = blendizzard.get_epoch(&0);
+    let mut faction_standings = Map::new(&env);
+    faction_standings.set(0, fp);
+    epoch0.faction_weighted_standings = unboosted_weights(&env, &faction_standings);
+    epoch0.faction_standings = faction_standings;
+    epoch0.winning_faction = Some(0);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(&env, 0, &epoch0);
+        crate::storage::set_epoch_player(
+            &env,
+            0,
+            &player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+    vault.set_user_balance(&player, &10_0000000);
+
+    assert_eq!(
+        blendizzard.claim_epoch_reward(&player, &0),
+        epoch0.reward_pool
+    );
+    assert_eq!(
+        blendizzard.get_epoch_payouts(&0).total_claimed,
+        epoch0.reward_pool
+    );
+
+    // Only the unclaimed developer share carries over
+    advance_past_epoch(&env);
+    blendizzard.cycle_epoch();
+    assert_eq!(
+        blendizzard.get_epoch(&1).reward_pool,
+        epoch0.dev_reward_pool
+    );
+}
+
+#[test]
+fn test_rewards_never_expire_by_default() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    for _ in 0..3 {
+        advance_past_epoch(&env);
+        blendizzard.cycle_epoch();
+    }
+
+    assert!(!blendizzard.get_epoch_payouts(&0).expired);
+    assert_eq!(blendizzard.get_epoch(&2).reward_pool, 0);
+}
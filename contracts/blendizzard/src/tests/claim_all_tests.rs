@@ -0,0 +1,146 @@
+/// Claim All Tests
+///
+/// Tests that `claim_all` pays a player's rewards from several epochs in one
+/// call, skips epochs they didn't win, and can be resumed after a truncated
+/// scan without paying any epoch twice.
+use super::fee_vault_utils::MockVaultClient;
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+/// Create Blendizzard with two funded players in factions 0 and 1
+fn setup_players(env: &Env) -> (BlendizzardClient<'_>, Address, Address, Address) {
+    let blendizzard = create_blendizzard_with_soroswap(env, &Address::generate(env));
+    let vault = MockVaultClient::new(env, &blendizzard.get_config().fee_vault);
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    (blendizzard, game, player1, player2)
+}
+
+/// Play one game (won by player1 if `player1_wins`) and cycle the epoch
+fn play_epoch(
+    env: &Env,
+    blendizzard: &BlendizzardClient,
+    game: &Address,
+    player1: &Address,
+    player2: &Address,
+    session_id: u32,
+    player1_wins: bool,
+) {
+    let wager = 10_0000000i128;
+    blendizzard.start_game(game, &session_id, player1, player2, &wager, &wager);
+    blendizzard.end_game(&session_id, &player1_wins);
+
+    let blnd = blendizzard.get_config().blnd_token;
+    TokenClient::new(env, &blnd).mint(&blendizzard.address, &5000_0000000);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_claim_all_pays_every_won_epoch() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 1, true);
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 2, false);
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 3, true);
+
+    let expected = blendizzard.get_epoch(&0).reward_pool + blendizzard.get_epoch(&2).reward_pool;
+    let (paid, last_epoch) = blendizzard.claim_all(&player1, &10);
+
+    assert_eq!(paid, expected);
+    assert_eq!(last_epoch, Some(2));
+    for (epoch, error) in [
+        (0, Error::RewardAlreadyClaimed),
+        (1, Error::NotWinningFaction),
+        (2, Error::RewardAlreadyClaimed),
+    ] {
+        assert_contract_error(&blendizzard.try_claim_epoch_reward(&player1, &epoch), error);
+    }
+
+    // Nothing left to scan
+    assert_eq!(blendizzard.claim_all(&player1, &10), (0, None));
+}
+
+#[test]
+fn test_claim_all_resumes_after_truncation() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+
+    for session_id in 1..=3 {
+        play_epoch(
+            &env,
+            &blendizzard,
+            &game,
+            &player1,
+            &player2,
+            session_id,
+            true,
+        );
+    }
+
+    let (first, last_epoch) = blendizzard.claim_all(&player1, &2);
+    assert_eq!(last_epoch, Some(1));
+    assert_eq!(
+        first,
+        blendizzard.get_epoch(&0).reward_pool + blendizzard.get_epoch(&1).reward_pool
+    );
+
+    let (second, last_epoch) = blendizzard.claim_all(&player1, &2);
+    assert_eq!(last_epoch, Some(2));
+    assert_eq!(second, blendizzard.get_epoch(&2).reward_pool);
+}
+
+#[test]
+fn test_claim_all_skips_individually_claimed_epochs() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 1, true);
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 2, true);
+    let claimed = blendizzard.claim_epoch_reward(&player1, &0);
+
+    let (paid, last_epoch) = blendizzard.claim_all(&player1, &10);
+    assert_eq!(last_epoch, Some(1));
+    assert_eq!(paid, blendizzard.get_epoch(&1).reward_pool);
+    assert!(claimed > 0);
+}
+
+#[test]
+fn test_claim_all_passes_unstored_skipped_epochs() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 1, true);
+
+    // A 30-epoch gap: only the 16 most recent skipped epochs are stored
+    env.ledger().with_mut(|li| li.timestamp += 30 * 345_600);
+    let next_epoch = blendizzard.cycle_epoch();
+    assert!(next_epoch > 17);
+    assert!(blendizzard.try_get_epoch(&2).is_err());
+    play_epoch(&env, &blendizzard, &game, &player1, &player2, 2, true);
+
+    let mut paid = 0;
+    let mut last_epoch = Some(0);
+    while let (amount, Some(epoch)) = blendizzard.claim_all(&player1, &20) {
+        paid += amount;
+        last_epoch = Some(epoch);
+    }
+
+    assert_eq!(last_epoch, Some(next_epoch));
+    assert_eq!(
+        paid,
+        blendizzard.get_epoch(&0).reward_pool + blendizzard.get_epoch(&next_epoch).reward_pool
+    );
+}
@@ -0,0 +1,153 @@
+/// Claim History Tests
+///
+/// Tests that reward payouts are recorded per user and that
+/// `get_claim_history` pages through them newest first, keeping only the most
+/// recent records.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env, unboosted_weights};
+use crate::storage::MAX_CLAIM_HISTORY;
+use crate::types::{ClaimRecord, DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 1000_0000000;
+
+/// Create Blendizzard with a finalized epoch 0 where `player` is the only winner
+fn setup_claimable_epoch<'a>(
+    env: &'a Env,
+    player: &Address,
+) -> (BlendizzardClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+
+    let fp = 100_0000000i128;
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, fp);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool: REWARD_POOL,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        crate::storage::set_epoch_player(
+            env,
+            0,
+            player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+    vault.set_user_balance(player, &10_0000000);
+
+    (blendizzard, usdc)
+}
+
+#[test]
+fn test_claim_is_recorded() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+    assert_eq!(blendizzard.get_claim_history(&player, &0, &10).len(), 0);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1234);
+    blendizzard.approve_claimer(&player, &claimer);
+    blendizzard.claim_epoch_reward_for(&claimer, &player, &0);
+
+    assert_eq!(
+        blendizzard.get_claim_history(&player, &0, &10),
+        vec![
+            &env,
+            ClaimRecord {
+                epoch: 0,
+                amount: REWARD_POOL,
+                token: usdc.address.clone(),
+                ledger: 1234,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_claim_history_pages_newest_first_and_is_bounded() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+
+    env.as_contract(&blendizzard.address, || {
+        for epoch in 0..MAX_CLAIM_HISTORY + 5 {
+            crate::storage::push_claim_record(
+                &env,
+                &player,
+                ClaimRecord {
+                    epoch,
+                    amount: 1,
+                    token: usdc.address.clone(),
+                    ledger: epoch,
+                },
+            );
+        }
+    });
+
+    let newest = MAX_CLAIM_HISTORY + 4;
+    let page = blendizzard.get_claim_history(&player, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().epoch, newest);
+    assert_eq!(page.get(2).unwrap().epoch, newest - 2);
+
+    let page = blendizzard.get_claim_history(&player, &3, &2);
+    assert_eq!(page.get(0).unwrap().epoch, newest - 3);
+
+    // Only the most recent records are kept; the oldest five were dropped
+    let all = blendizzard.get_claim_history(&player, &0, &u32::MAX);
+    assert_eq!(all.len(), MAX_CLAIM_HISTORY);
+    assert_eq!(all.last().unwrap().epoch, 5);
+    assert_eq!(
+        blendizzard
+            .get_claim_history(&player, &MAX_CLAIM_HISTORY, &10)
+            .len(),
+        0
+    );
+}
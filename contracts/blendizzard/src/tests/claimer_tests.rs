@@ -0,0 +1,143 @@
+/// Delegated Claim Tests
+///
+/// Tests approving, using and revoking a claimer that claims epoch rewards on
+/// a player's behalf, with the reward always paid to the player.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, unboosted_weights, Error,
+    ExtError,
+};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 1000_0000000;
+
+/// Create Blendizzard with a finalized epoch 0 where `player` is the only winner
+fn setup_claimable_epoch<'a>(
+    env: &'a Env,
+    player: &Address,
+) -> (BlendizzardClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+
+    let fp = 100_0000000i128;
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, fp);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool: REWARD_POOL,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        crate::storage::set_epoch_player(
+            env,
+            0,
+            player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+    vault.set_user_balance(player, &10_0000000);
+
+    (blendizzard, usdc)
+}
+
+#[test]
+fn test_approved_claimer_claims_to_player() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+
+    blendizzard.approve_claimer(&player, &claimer);
+    assert_eq!(blendizzard.get_claimer(&player), Some(claimer.clone()));
+
+    let claimed = blendizzard.claim_epoch_reward_for(&claimer, &player, &0);
+    assert_eq!(claimed, REWARD_POOL);
+
+    // Paid to the player, never the claimer
+    assert_eq!(usdc.balance(&player), REWARD_POOL);
+    assert_eq!(usdc.balance(&claimer), 0);
+
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward(&player, &0),
+        Error::RewardAlreadyClaimed,
+    );
+}
+
+#[test]
+fn test_unapproved_or_revoked_claimer_is_rejected() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let (blendizzard, _usdc) = setup_claimable_epoch(&env, &player);
+
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward_for(&claimer, &player, &0),
+        ExtError::NotApprovedClaimer,
+    );
+
+    blendizzard.approve_claimer(&player, &claimer);
+    blendizzard.revoke_claimer(&player);
+    assert_eq!(blendizzard.get_claimer(&player), None);
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward_for(&claimer, &player, &0),
+        ExtError::NotApprovedClaimer,
+    );
+
+    // Approving another address replaces the previous claimer
+    let other = Address::generate(&env);
+    blendizzard.approve_claimer(&player, &claimer);
+    blendizzard.approve_claimer(&player, &other);
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward_for(&claimer, &player, &0),
+        ExtError::NotApprovedClaimer,
+    );
+    assert_eq!(
+        blendizzard.claim_epoch_reward_for(&other, &player, &0),
+        REWARD_POOL
+    );
+}
@@ -0,0 +1,125 @@
+/// Commit-Reveal Tests
+///
+/// Tests move commitments and reveals for on-chain resolved games, and
+/// settling a session when one player fails to reveal in time.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::commit_reveal::{move_commitment, REVEAL_WINDOW_SECONDS};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env};
+
+const ROCK: u32 = 0;
+const PAPER: u32 = 1;
+
+/// Create Blendizzard with a started session between two funded players
+fn setup_session<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    (blendizzard, player1, player2)
+}
+
+#[test]
+fn test_commit_and_reveal_moves() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2) = setup_session(&env);
+    let salt1 = BytesN::from_array(&env, &[1; 32]);
+    let salt2 = BytesN::from_array(&env, &[2; 32]);
+
+    blendizzard.commit_move(&1, &player1, &move_commitment(&env, ROCK, &salt1));
+
+    // Reveals only open once both players committed
+    let result = blendizzard.try_reveal_move(&1, &player1, &ROCK, &salt1);
+    assert_contract_error(&result, ExtError::RevealNotOpen);
+
+    blendizzard.commit_move(&1, &player2, &move_commitment(&env, PAPER, &salt2));
+    let result = blendizzard.try_commit_move(&1, &player2, &move_commitment(&env, ROCK, &salt2));
+    assert_contract_error(&result, ExtError::MoveAlreadyCommitted);
+
+    // A different move than committed is rejected
+    let result = blendizzard.try_reveal_move(&1, &player1, &PAPER, &salt1);
+    assert_contract_error(&result, ExtError::CommitmentMismatch);
+
+    blendizzard.reveal_move(&1, &player1, &ROCK, &salt1);
+    let moves = blendizzard.reveal_move(&1, &player2, &PAPER, &salt2);
+    assert_eq!(moves.player1_move, Some(ROCK));
+    assert_eq!(moves.player2_move, Some(PAPER));
+
+    // Both revealed: the game contract reports the outcome as usual
+    let result = blendizzard.try_claim_reveal_timeout(&1);
+    assert_contract_error(&result, ExtError::RevealTimeoutNotClaimable);
+    blendizzard.end_game(&1, &false);
+}
+
+#[test]
+fn test_non_revealer_loses_after_timeout() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2) = setup_session(&env);
+    let salt1 = BytesN::from_array(&env, &[1; 32]);
+    let salt2 = BytesN::from_array(&env, &[2; 32]);
+
+    blendizzard.commit_move(&1, &player1, &move_commitment(&env, ROCK, &salt1));
+    blendizzard.commit_move(&1, &player2, &move_commitment(&env, PAPER, &salt2));
+    blendizzard.reveal_move(&1, &player1, &ROCK, &salt1);
+
+    let result = blendizzard.try_claim_reveal_timeout(&1);
+    assert_contract_error(&result, ExtError::RevealWindowOpen);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += REVEAL_WINDOW_SECONDS + 1);
+
+    // Too late to reveal
+    let result = blendizzard.try_reveal_move(&1, &player2, &PAPER, &salt2);
+    assert_contract_error(&result, ExtError::RevealWindowClosed);
+
+    assert!(blendizzard.claim_reveal_timeout(&1));
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player1)
+            .total_fp_contributed,
+        10_0000000
+    );
+
+    let result = blendizzard.try_end_game(&1, &false);
+    assert_contract_error(&result, Error::SessionAlreadyFinalized);
+}
+
+#[test]
+fn test_commit_rejects_non_player() {
+    let env = setup_test_env();
+    let (blendizzard, _player1, _player2) = setup_session(&env);
+    let salt = BytesN::from_array(&env, &[1; 32]);
+
+    let result = blendizzard.try_commit_move(
+        &1,
+        &Address::generate(&env),
+        &move_commitment(&env, ROCK, &salt),
+    );
+    assert_contract_error(&result, Error::PlayerNotFound);
+}
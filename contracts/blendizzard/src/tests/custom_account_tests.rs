@@ -0,0 +1,329 @@
+/// Custom Account (Smart Wallet) Tests
+///
+/// Tests that players can be passkey smart wallets (custom account contracts)
+/// rather than classic accounts. Each call is authorized through the wallet's
+/// real `__check_auth` instead of `mock_all_auths`, covering faction
+/// selection, DirectPool deposits, starting a game with a scoped session key
+/// and claiming rewards.
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::custom_account_utils::{
+    create_smart_wallet, wallet_auth, AuthInvocation, MockSmartWalletClient,
+};
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    create_blendizzard_contract, create_blendizzard_with_soroswap, setup_test_env,
+};
+use crate::types::VaultBackend;
+use crate::BlendizzardClient;
+use number_guess::{NumberGuessContract, NumberGuessContractClient};
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::auth::Context;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol};
+
+/// Create a smart wallet owned by a passkey derived from `seed`
+fn wallet<'a>(env: &'a Env, seed: u8) -> (MockSmartWalletClient<'a>, BytesN<32>) {
+    let passkey = BytesN::from_array(env, &[seed; 32]);
+    (create_smart_wallet(env, &passkey), passkey)
+}
+
+/// Blendizzard with a mock vault and generated token addresses
+fn setup_mock_vault(env: &Env) -> (BlendizzardClient<'_>, MockVaultClient<'_>) {
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &Address::generate(env),
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    (blendizzard, MockVaultClient::new(env, &vault_addr))
+}
+
+#[test]
+fn test_wallet_selects_faction_with_passkey() {
+    let env = setup_test_env();
+    let (blendizzard, _vault) = setup_mock_vault(&env);
+    let (wallet, passkey) = wallet(&env, 1);
+
+    let select = AuthInvocation::new(
+        &blendizzard.address,
+        "select_faction",
+        vec![&env, wallet.address.into_val(&env), 2u32.into_val(&env)],
+    );
+    env.set_auths(&[wallet_auth(&env, &wallet.address, &passkey, 1, &select)]);
+    blendizzard.select_faction(&wallet.address, &2);
+    env.mock_all_auths();
+
+    assert_eq!(blendizzard.get_player(&wallet.address).selected_faction, 2);
+    assert_eq!(wallet.last_contexts().len(), 1);
+}
+
+#[test]
+fn test_wallet_rejects_unknown_signer() {
+    let env = setup_test_env();
+    let (blendizzard, _vault) = setup_mock_vault(&env);
+    let (wallet, _passkey) = wallet(&env, 1);
+
+    let stranger = BytesN::from_array(&env, &[9; 32]);
+    let select = AuthInvocation::new(
+        &blendizzard.address,
+        "select_faction",
+        vec![&env, wallet.address.into_val(&env), 0u32.into_val(&env)],
+    );
+    env.set_auths(&[wallet_auth(&env, &wallet.address, &stranger, 1, &select)]);
+    let result = blendizzard.try_select_faction(&wallet.address, &0);
+    env.mock_all_auths();
+
+    assert!(result.is_err());
+    assert!(blendizzard.try_get_player(&wallet.address).is_err());
+}
+
+#[test]
+fn test_wallet_deposits_to_direct_pool() {
+    let env = setup_test_env();
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(&env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(&env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(&env, &xlm);
+    let pool = create_blend_pool(&env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    let (wallet, passkey) = wallet(&env, 1);
+    let amount = 100_0000000i128;
+    usdc_client.mint(&wallet.address, &amount);
+
+    // The deposit pulls the USDC with a nested transfer the wallet also signs
+    let deposit = AuthInvocation::new(
+        &blendizzard.address,
+        "deposit",
+        vec![&env, wallet.address.into_val(&env), amount.into_val(&env)],
+    )
+    .with(AuthInvocation::new(
+        &usdc,
+        "transfer",
+        vec![
+            &env,
+            wallet.address.into_val(&env),
+            blendizzard.address.into_val(&env),
+            amount.into_val(&env),
+        ],
+    ));
+    env.set_auths(&[wallet_auth(&env, &wallet.address, &passkey, 1, &deposit)]);
+    let balance = blendizzard.deposit(&wallet.address, &amount);
+    env.mock_all_auths();
+
+    assert!(balance >= amount - 1);
+    assert_eq!(usdc_client.balance(&wallet.address), 0);
+    assert_eq!(wallet.last_contexts().len(), 2);
+}
+
+/// Authorization for a player joining a number-guess session: the game asks
+/// for (session_id, wager) and Blendizzard for (game, session_id, wager)
+fn join_session(
+    env: &Env,
+    game: &Address,
+    blendizzard: &Address,
+    session_id: u32,
+    wager: i128,
+) -> AuthInvocation {
+    AuthInvocation::new(
+        game,
+        "start_game",
+        vec![env, session_id.into_val(env), wager.into_val(env)],
+    )
+    .with(AuthInvocation::new(
+        blendizzard,
+        "start_game",
+        vec![
+            env,
+            game.into_val(env),
+            session_id.into_val(env),
+            wager.into_val(env),
+        ],
+    ))
+}
+
+#[test]
+fn test_wallet_starts_game_with_session_key() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_mock_vault(&env);
+    let game = env.register(
+        NumberGuessContract,
+        (&Address::generate(&env), &blendizzard.address),
+    );
+    let number_guess = NumberGuessContractClient::new(&env, &game);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    // Each player scopes a session key to the game and Blendizzard
+    let session_key = BytesN::from_array(&env, &[7; 32]);
+    let (wallet1, _) = wallet(&env, 1);
+    let (wallet2, _) = wallet(&env, 2);
+    for (player, faction) in [(&wallet1, 0u32), (&wallet2, 1u32)] {
+        vault.set_user_balance(&player.address, &1000_0000000);
+        blendizzard.select_faction(&player.address, &faction);
+        player.add_session_key(
+            &session_key,
+            &vec![&env, game.clone(), blendizzard.address.clone()],
+        );
+    }
+
+    let wager = 10_0000000i128;
+    env.set_auths(&[
+        wallet_auth(
+            &env,
+            &wallet1.address,
+            &session_key,
+            1,
+            &join_session(&env, &game, &blendizzard.address, 1, wager),
+        ),
+        wallet_auth(
+            &env,
+            &wallet2.address,
+            &session_key,
+            1,
+            &join_session(&env, &game, &blendizzard.address, 1, wager),
+        ),
+    ]);
+    number_guess.start_game(&1, &wallet1.address, &wallet2.address, &wager, &wager);
+    env.mock_all_auths();
+
+    assert_eq!(
+        blendizzard.get_active_sessions(&wallet1.address),
+        vec![&env, 1]
+    );
+    assert_eq!(
+        blendizzard.get_active_sessions(&wallet2.address),
+        vec![&env, 1]
+    );
+
+    // Blendizzard's consent is its own context, named and scoped to the wager
+    let contexts = wallet1.last_contexts();
+    assert_eq!(contexts.len(), 2);
+    let Context::Contract(consent) = contexts.get(1).unwrap() else {
+        panic!("expected a contract context");
+    };
+    assert_eq!(consent.contract, blendizzard.address);
+    assert_eq!(consent.fn_name, Symbol::new(&env, "start_game"));
+    assert_eq!(
+        consent.args,
+        vec![
+            &env,
+            game.into_val(&env),
+            1u32.into_val(&env),
+            wager.into_val(&env),
+        ]
+    );
+}
+
+#[test]
+fn test_session_key_cannot_sign_outside_scope() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_mock_vault(&env);
+    let game = env.register(
+        NumberGuessContract,
+        (&Address::generate(&env), &blendizzard.address),
+    );
+    let number_guess = NumberGuessContractClient::new(&env, &game);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    // Scoped to the game only: the nested Blendizzard consent is refused
+    let session_key = BytesN::from_array(&env, &[7; 32]);
+    let (wallet1, _) = wallet(&env, 1);
+    let (wallet2, _) = wallet(&env, 2);
+    for (player, faction) in [(&wallet1, 0u32), (&wallet2, 1u32)] {
+        vault.set_user_balance(&player.address, &1000_0000000);
+        blendizzard.select_faction(&player.address, &faction);
+        player.add_session_key(&session_key, &vec![&env, game.clone()]);
+    }
+
+    let wager = 10_0000000i128;
+    env.set_auths(&[
+        wallet_auth(
+            &env,
+            &wallet1.address,
+            &session_key,
+            1,
+            &join_session(&env, &game, &blendizzard.address, 1, wager),
+        ),
+        wallet_auth(
+            &env,
+            &wallet2.address,
+            &session_key,
+            1,
+            &join_session(&env, &game, &blendizzard.address, 1, wager),
+        ),
+    ]);
+    let result =
+        number_guess.try_start_game(&1, &wallet1.address, &wallet2.address, &wager, &wager);
+    env.mock_all_auths();
+
+    assert!(result.is_err());
+    assert!(blendizzard.get_active_sessions(&wallet1.address).is_empty());
+}
+
+#[test]
+fn test_wallet_claims_epoch_reward() {
+    let env = setup_test_env();
+    let blendizzard = create_blendizzard_with_soroswap(&env, &Address::generate(&env));
+    let config = blendizzard.get_config();
+    let vault = MockVaultClient::new(&env, &config.fee_vault);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let (wallet1, passkey) = wallet(&env, 1);
+    let (wallet2, _) = wallet(&env, 2);
+    vault.set_user_balance(&wallet1.address, &100_0000000);
+    vault.set_user_balance(&wallet2.address, &100_0000000);
+    blendizzard.select_faction(&wallet1.address, &0);
+    blendizzard.select_faction(&wallet2.address, &1);
+    blendizzard.start_game(
+        &game,
+        &1,
+        &wallet1.address,
+        &wallet2.address,
+        &10_0000000,
+        &10_0000000,
+    );
+    blendizzard.end_game(&1, &true);
+
+    TokenClient::new(&env, &config.blnd_token).mint(&blendizzard.address, &5000_0000000);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+
+    let claim = AuthInvocation::new(
+        &blendizzard.address,
+        "claim_epoch_reward",
+        vec![&env, wallet1.address.into_val(&env), 0u32.into_val(&env)],
+    );
+    env.set_auths(&[wallet_auth(&env, &wallet1.address, &passkey, 1, &claim)]);
+    let claimed = blendizzard.claim_epoch_reward(&wallet1.address, &0);
+    env.mock_all_auths();
+
+    assert!(claimed > 0);
+    assert_eq!(claimed, blendizzard.get_epoch(&0).reward_pool);
+    let Context::Contract(call) = wallet1.last_contexts().get(0).unwrap() else {
+        panic!("expected a contract context");
+    };
+    assert_eq!(call.fn_name, Symbol::new(&env, "claim_epoch_reward"));
+}
@@ -0,0 +1,76 @@
+/// Cycle Budget Tests
+///
+/// Tests the cost of cycling an epoch with BLND to swap: the stages of one
+/// `cycle_epoch` share their config and token balance reads, so one call
+/// costs less than the same cycle run stage by stage, and stays within the
+/// network's per-transaction instruction limit.
+use super::fee_vault_utils::MockVaultClient;
+use super::soroswap_utils::TokenClient;
+use super::testutils::{create_blendizzard_with_soroswap, setup_test_env};
+use crate::types::CycleStage;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+/// Network limit on the CPU instructions of one transaction
+const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+
+/// Create Blendizzard with one epoch played, BLND to swap, ready to cycle
+fn setup_played_epoch(env: &Env) -> BlendizzardClient<'_> {
+    let blendizzard = create_blendizzard_with_soroswap(env, &Address::generate(env));
+    let config = blendizzard.get_config();
+    let vault = MockVaultClient::new(env, &config.fee_vault);
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+
+    TokenClient::new(env, &config.blnd_token).mint(&blendizzard.address, &5000_0000000);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard
+}
+
+#[test]
+fn test_cycle_epoch_fits_one_transaction() {
+    let env = setup_test_env();
+    let blendizzard = setup_played_epoch(&env);
+
+    blendizzard.cycle_epoch();
+    let instructions = env.cost_estimate().resources().instructions;
+
+    assert!(instructions > 0);
+    assert!(instructions < TX_MAX_INSTRUCTIONS);
+    assert!(blendizzard.get_epoch(&0).reward_pool > 0);
+}
+
+#[test]
+fn test_cycle_epoch_costs_less_than_its_stages() {
+    let env = setup_test_env();
+    let blendizzard = setup_played_epoch(&env);
+    blendizzard.cycle_epoch();
+    let one_call = env.cost_estimate().resources().instructions;
+    let expected_pool = blendizzard.get_epoch(&0).reward_pool;
+
+    let env = setup_test_env();
+    let blendizzard = setup_played_epoch(&env);
+    let mut staged = 0;
+    for stage in [
+        CycleStage::Finalize,
+        CycleStage::Claim,
+        CycleStage::Swap,
+        CycleStage::Distribute,
+    ] {
+        assert_eq!(blendizzard.cycle_epoch_step(), stage);
+        staged += env.cost_estimate().resources().instructions;
+    }
+
+    assert_eq!(blendizzard.get_epoch(&0).reward_pool, expected_pool);
+    assert!(one_call < staged);
+}
@@ -0,0 +1,218 @@
+/// Direct Pool Backend Tests
+///
+/// Tests the DirectPool vault backend, where Blendizzard supplies USDC to a
+/// real Blend pool (via BlendFixture) itself and tracks player b-tokens.
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    Error,
+};
+use crate::types::VaultBackend;
+use crate::vault::VAULT_MIGRATION_DELAY;
+use crate::BlendizzardClient;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard using the DirectPool backend on a fresh Blend pool
+fn setup_direct_pool<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockTokenClient<'a>) {
+    let (blendizzard, usdc_client, _second_pool) = setup_direct_pools(env);
+    (blendizzard, usdc_client)
+}
+
+/// Same as `setup_direct_pool`, plus a second pool on the same Blend deployment
+fn setup_direct_pools<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockTokenClient<'a>, Address) {
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(env, &xlm);
+    let pool = create_blend_pool(env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+    let second_pool = create_blend_pool(env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    (blendizzard, usdc_client, second_pool)
+}
+
+#[test]
+fn test_direct_pool_deposit_and_withdraw() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+
+    // b-token rounding may cost at most one stroop
+    let balance = blendizzard.deposit(&user, &100_0000000);
+    assert!(balance <= 100_0000000 && balance >= 100_0000000 - 1);
+    assert_eq!(usdc.balance(&user), 0);
+    assert_eq!(blendizzard.get_user_event_seq(&user), 1);
+
+    let balance = blendizzard.withdraw(&user, &50_0000000);
+    assert!(balance <= 50_0000000 && balance >= 50_0000000 - 2);
+    assert_eq!(usdc.balance(&user), 50_0000000);
+    assert_eq!(blendizzard.get_user_event_seq(&user), 2);
+
+    let result = blendizzard.try_withdraw(&user, &100_0000000);
+    assert_contract_error(&result, Error::InsufficientBalance);
+}
+
+#[test]
+fn test_direct_pool_deposit_enforces_caps() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    usdc.mint(&user, &200_0000000);
+    usdc.mint(&other, &100_0000000);
+
+    blendizzard.set_deposit_caps(&100_0000000, &0);
+    blendizzard.deposit(&user, &100_0000000);
+    let result = blendizzard.try_deposit(&user, &1_0000000);
+    assert_contract_error(&result, Error::UserDepositCapExceeded);
+
+    blendizzard.set_deposit_caps(&0, &150_0000000);
+    let result = blendizzard.try_deposit(&other, &60_0000000);
+    assert_contract_error(&result, Error::GlobalDepositCapExceeded);
+    blendizzard.deposit(&other, &40_0000000);
+}
+
+#[test]
+fn test_deposit_from_allowance_credits_player() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+
+    // An integrator funds a player's balance through an allowance
+    let integrator = Address::generate(&env);
+    let user = Address::generate(&env);
+    usdc.mint(&integrator, &100_0000000);
+
+    let result = blendizzard.try_deposit_from_allowance(&integrator, &user, &60_0000000);
+    assert_contract_error(&result, Error::InsufficientBalance);
+
+    usdc.approve(&integrator, &blendizzard.address, &60_0000000, &10_000);
+    let balance = blendizzard.deposit_from_allowance(&integrator, &user, &60_0000000);
+    assert!(balance <= 60_0000000 && balance >= 60_0000000 - 1);
+    assert_eq!(usdc.balance(&integrator), 40_0000000);
+    assert_eq!(usdc.allowance(&integrator, &blendizzard.address), 0);
+    assert_eq!(blendizzard.get_user_event_seq(&user), 1);
+
+    let result = blendizzard.try_deposit_from_allowance(&integrator, &user, &0);
+    assert_contract_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_cannot_switch_backend_with_deposits() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+    blendizzard.deposit(&user, &100_0000000);
+
+    let result = blendizzard.try_set_vault_backend(&VaultBackend::FeeVault, &None);
+    assert_contract_error(&result, Error::VaultBackendInUse);
+}
+
+#[test]
+fn test_migrate_vault_moves_position_to_new_pool() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, second_pool) = setup_direct_pools(&env);
+
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+    let before = blendizzard.deposit(&user, &100_0000000);
+
+    // Migrating to the current pool is rejected
+    let current_pool = blendizzard.get_config().blend_pool.unwrap();
+    let result = blendizzard.try_queue_vault_migration(&current_pool);
+    assert_contract_error(&result, Error::InvalidConfig);
+
+    // USDC b-tokens and XLM d-tokens
+    blendizzard.set_reserve_token_ids(&vec![&env, 1, 2]);
+
+    blendizzard.queue_vault_migration(&second_pool);
+    env.jump_time(VAULT_MIGRATION_DELAY);
+    let moved = blendizzard.migrate_vault(&second_pool);
+    assert!(moved >= before - 1);
+
+    // Configured emission IDs carry over to the new pool's reserves
+    let config = blendizzard.get_config();
+    assert_eq!(config.blend_pool, Some(second_pool));
+    assert_eq!(config.reserve_token_ids, vec![&env, 1, 2]);
+
+    // Player position carried over (up to b-token rounding) and is withdrawable
+    let after = blendizzard.withdraw(&user, &50_0000000);
+    assert!(after <= before - 50_0000000 && after >= before - 50_0000000 - 4);
+    assert_eq!(usdc.balance(&user), 50_0000000);
+}
+
+#[test]
+fn test_direct_pool_entrypoints_require_backend() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let user = Address::generate(&env);
+
+    let result = blendizzard.try_deposit(&user, &100_0000000);
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+
+    let result = blendizzard.try_set_vault_backend(&VaultBackend::DirectPool, &None);
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+}
+
+#[test]
+fn test_cover_shortfall_from_insurance_fund() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+    blendizzard.deposit(&user, &100_0000000);
+
+    // Nothing to cover while the held b-tokens back every credited one
+    let result = blendizzard.try_cover_shortfall();
+    assert_contract_error(&result, Error::InvalidAmount);
+
+    // Simulate a loss: credit 10 USDC worth of b-tokens nobody supplied
+    env.as_contract(&blendizzard.address, || {
+        let total = crate::storage::get_total_pool_b_tokens(&env);
+        crate::storage::set_total_pool_b_tokens(&env, total + 10_0000000);
+    });
+
+    // An empty fund can't cover it
+    let result = blendizzard.try_cover_shortfall();
+    assert_contract_error(&result, Error::InsufficientBalance);
+
+    usdc.mint(&blendizzard.address, &50_0000000);
+    env.as_contract(&blendizzard.address, || {
+        let mut fund = crate::storage::get_insurance_fund(&env);
+        fund.balance = 50_0000000;
+        crate::storage::set_insurance_fund(&env, &fund);
+    });
+
+    let covered = blendizzard.cover_shortfall();
+    assert!(covered >= 10_0000000 && covered <= 10_0000000 + 2);
+    assert_eq!(usdc.balance(&blendizzard.address), 50_0000000 - covered);
+
+    let fund = blendizzard.get_insurance_fund();
+    assert_eq!(fund.balance, 50_0000000 - covered);
+    assert_eq!(fund.total_covered, covered);
+}
@@ -0,0 +1,180 @@
+/// Result Dispute Tests
+///
+/// Tests provisional results for games with a dispute window: finalizing
+/// undisputed results, disputes by players, and arbiter resolution with the
+/// bond slashed or refunded.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+const BOND: i128 = 50_0000000;
+const DISPUTE_LEDGERS: u32 = 100;
+
+/// Create Blendizzard with an arbiter, a game with a dispute window and a
+/// started session 1 (player1 in faction 0, player2 in faction 1)
+fn setup_session<'a>(env: &'a Env) -> (BlendizzardClient<'a>, TokenClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_game_dispute_window(&game, &DISPUTE_LEDGERS);
+    blendizzard.set_arbiter(&Some(Address::generate(env)), &BOND);
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    usdc.mint(&player2, &BOND);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+
+    (blendizzard, usdc, player1, player2)
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_end_game_is_provisional_until_finalized() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc, _player1, _player2) = setup_session(&env);
+
+    blendizzard.end_game(&1, &true);
+    assert!(blendizzard.get_provisional_result(&1).is_some());
+    assert_eq!(blendizzard.get_epoch(&0).faction_standings.get(0), None);
+
+    // Reported outcome can't be replaced while provisional
+    assert_contract_error(
+        &blendizzard.try_end_game(&1, &false),
+        Error::SessionAlreadyFinalized,
+    );
+
+    // Window still open
+    assert_contract_error(
+        &blendizzard.try_finalize_result(&1),
+        ExtError::DisputeWindowOpen,
+    );
+
+    advance_ledgers(&env, DISPUTE_LEDGERS + 1);
+    blendizzard.finalize_result(&1);
+
+    assert!(blendizzard.get_provisional_result(&1).is_none());
+    assert_eq!(
+        blendizzard.get_epoch(&0).faction_standings.get(0),
+        Some(WAGER)
+    );
+}
+
+#[test]
+fn test_frivolous_dispute_slashes_bond() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, _player1, player2) = setup_session(&env);
+
+    blendizzard.end_game(&1, &true);
+    blendizzard.dispute(&player2, &1);
+    assert_eq!(usdc.balance(&player2), 0);
+
+    // Disputed results wait for the arbiter
+    advance_ledgers(&env, DISPUTE_LEDGERS + 1);
+    assert_contract_error(
+        &blendizzard.try_finalize_result(&1),
+        ExtError::ResultDisputed,
+    );
+
+    // Arbiter confirms the reported outcome
+    assert!(blendizzard.resolve_dispute(&1, &true));
+    assert_eq!(usdc.balance(&player2), 0);
+    env.as_contract(&blendizzard.address, || {
+        assert_eq!(crate::storage::get_pending_rewards(&env), BOND);
+    });
+    assert_eq!(
+        blendizzard.get_epoch(&0).faction_standings.get(0),
+        Some(WAGER)
+    );
+}
+
+#[test]
+fn test_upheld_dispute_refunds_bond_and_overturns_result() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, _player1, player2) = setup_session(&env);
+
+    blendizzard.end_game(&1, &true);
+    blendizzard.dispute(&player2, &1);
+
+    assert!(!blendizzard.resolve_dispute(&1, &false));
+    assert_eq!(usdc.balance(&player2), BOND);
+
+    let standings = blendizzard.get_epoch(&0).faction_standings;
+    assert_eq!(standings.get(0), None);
+    assert_eq!(standings.get(1), Some(WAGER));
+}
+
+#[test]
+fn test_dispute_rejected_after_window_or_by_outsider() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc, _player1, player2) = setup_session(&env);
+
+    blendizzard.end_game(&1, &true);
+
+    assert_contract_error(
+        &blendizzard.try_dispute(&Address::generate(&env), &1),
+        Error::PlayerNotFound,
+    );
+
+    advance_ledgers(&env, DISPUTE_LEDGERS + 1);
+    assert_contract_error(
+        &blendizzard.try_dispute(&player2, &1),
+        ExtError::DisputeWindowClosed,
+    );
+}
+
+#[test]
+fn test_dispute_state_errors() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc, player1, player2) = setup_session(&env);
+
+    // Nothing reported yet
+    assert_contract_error(
+        &blendizzard.try_dispute(&player2, &1),
+        ExtError::NoProvisionalResult,
+    );
+    assert_contract_error(
+        &blendizzard.try_resolve_dispute(&1, &true),
+        ExtError::NoProvisionalResult,
+    );
+
+    blendizzard.end_game(&1, &true);
+
+    // Undisputed results are settled with finalize_result, not by the arbiter
+    assert_contract_error(
+        &blendizzard.try_resolve_dispute(&1, &true),
+        ExtError::ResultNotDisputed,
+    );
+
+    blendizzard.dispute(&player2, &1);
+    assert_contract_error(
+        &blendizzard.try_dispute(&player1, &1),
+        ExtError::ResultAlreadyDisputed,
+    );
+}
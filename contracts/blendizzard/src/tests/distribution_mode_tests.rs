@@ -0,0 +1,234 @@
+/// Distribution Mode Tests
+///
+/// Tests the Linear / Quadratic / WinnerTakeAll reward split and the shared
+/// integer square root used for quadratic weights.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::create_token;
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::math::{checked_fixed_sqrt, checked_isqrt};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer, SCALAR_7};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 900_0000000;
+
+// ============================================================================
+// Test Setup Helpers
+// ============================================================================
+
+/// Create Blendizzard with a mock vault and a real USDC token
+fn setup_contract<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockVaultClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+
+    (blendizzard, vault)
+}
+
+/// Seed a finalized epoch 0 won by faction 0, with the given contributions
+fn seed_finalized_epoch(
+    env: &Env,
+    blendizzard: &BlendizzardClient,
+    vault: &MockVaultClient,
+    mode: DistributionMode,
+    players: &[(Address, i128)],
+) {
+    let mut total = 0;
+    let mut sqrt_total = 0;
+    for (_, fp) in players {
+        total += fp;
+        sqrt_total += checked_fixed_sqrt(*fp, SCALAR_7).unwrap();
+    }
+
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, total);
+    let mut faction_weighted_standings = Map::new(env);
+    faction_weighted_standings.set(0, total * SCALAR_7);
+    let mut faction_sqrt_standings = Map::new(env);
+    faction_sqrt_standings.set(0, sqrt_total * SCALAR_7);
+    let mut faction_leaders = Map::new(env);
+    faction_leaders.set(0, players[0].0.clone());
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_standings,
+        reward_pool: REWARD_POOL,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: total,
+        dev_reward_pool: 0,
+        distribution_mode: mode,
+        faction_weighted_standings,
+        faction_sqrt_standings,
+        faction_leaders,
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        for (player, fp) in players {
+            crate::storage::set_epoch_player(
+                env,
+                0,
+                player,
+                &EpochPlayer {
+                    epoch_faction: Some(0),
+                    epoch_balance_snapshot: 10_0000000,
+                    available_fp: 0,
+                    total_fp_contributed: *fp,
+                    streak_bonus: 0,
+                    games_completed: 0,
+                    reward_eligible: true,
+                },
+            );
+        }
+    });
+
+    for (player, _) in players {
+        vault.set_user_balance(player, &10_0000000);
+    }
+}
+
+// ============================================================================
+// Integer Square Root Tests
+// ============================================================================
+
+#[test]
+fn test_checked_isqrt() {
+    assert_eq!(checked_isqrt(0), Some(0));
+    assert_eq!(checked_isqrt(1), Some(1));
+    assert_eq!(checked_isqrt(15), Some(3));
+    assert_eq!(checked_isqrt(16), Some(4));
+    assert_eq!(
+        checked_isqrt(1_000_000_000_000_000_000),
+        Some(1_000_000_000)
+    );
+    assert_eq!(checked_isqrt(i128::MAX), Some(13_043_817_825_332_782_212));
+    assert_eq!(checked_isqrt(-1), None);
+
+    // sqrt(4.0) = 2.0 in 7-decimal fixed point
+    assert_eq!(checked_fixed_sqrt(4_0000000, SCALAR_7), Some(2_0000000));
+    assert_eq!(checked_fixed_sqrt(i128::MAX, SCALAR_7), None);
+}
+
+// ============================================================================
+// Distribution Mode Tests
+// ============================================================================
+
+#[test]
+fn test_quadratic_distribution_dampens_whales() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_contract(&env);
+
+    let whale = Address::generate(&env);
+    let minnow = Address::generate(&env);
+    seed_finalized_epoch(
+        &env,
+        &blendizzard,
+        &vault,
+        DistributionMode::Quadratic,
+        &[(whale.clone(), 900_0000000), (minnow.clone(), 100_0000000)],
+    );
+
+    // sqrt weights 30 : 10 → 3/4 and 1/4 (Linear would be 9/10 and 1/10)
+    let whale_reward = blendizzard.claim_epoch_reward(&whale, &0);
+    let minnow_reward = blendizzard.claim_epoch_reward(&minnow, &0);
+
+    assert_eq!(whale_reward, 675_0000000);
+    assert_eq!(minnow_reward, 225_0000000);
+}
+
+#[test]
+fn test_winner_take_all_pays_only_leader() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_contract(&env);
+
+    let leader = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    seed_finalized_epoch(
+        &env,
+        &blendizzard,
+        &vault,
+        DistributionMode::WinnerTakeAll,
+        &[
+            (leader.clone(), 400_0000000),
+            (runner_up.clone(), 100_0000000),
+        ],
+    );
+
+    let result = blendizzard.try_claim_epoch_reward(&runner_up, &0);
+    assert_contract_error(&result, Error::NoRewardsAvailable);
+
+    assert_eq!(blendizzard.claim_epoch_reward(&leader, &0), REWARD_POOL);
+}
+
+#[test]
+fn test_end_game_tracks_sqrt_standings_and_leader() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_contract(&env);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+    for player in [&player1, &player2, &player3] {
+        vault.set_user_balance(player, &1000_0000000);
+    }
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &0);
+    blendizzard.select_faction(&player3, &1);
+
+    blendizzard.start_game(&game, &1, &player1, &player3, &100_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &player2, &player3, &300_0000000, &10_0000000);
+    blendizzard.end_game(&2, &true);
+    blendizzard.start_game(&game, &3, &player1, &player3, &300_0000000, &10_0000000);
+    blendizzard.end_game(&3, &true);
+
+    let epoch = blendizzard.get_epoch(&0);
+    // player1: sqrt(400) = 20, player2: sqrt(300) ≈ 17.3205080 (no streak bonus)
+    assert_eq!(
+        epoch.faction_sqrt_standings.get(0),
+        Some((20_0000000 + 17_3205080) * SCALAR_7)
+    );
+    assert_eq!(epoch.faction_leaders.get(0), Some(player1));
+}
+
+#[test]
+fn test_distribution_mode_applies_from_next_epoch() {
+    let env = setup_test_env();
+    let (blendizzard, _vault) = setup_contract(&env);
+
+    blendizzard.set_distribution_mode(&DistributionMode::Quadratic);
+
+    assert_eq!(
+        blendizzard.get_config().distribution_mode,
+        DistributionMode::Quadratic
+    );
+    assert_eq!(
+        blendizzard.get_epoch(&0).distribution_mode,
+        DistributionMode::Linear
+    );
+}
@@ -38,8 +38,6 @@ fn test_update_reserve_token_ids() {
 
     // Update only reserve_token_ids
     client.update_config(
-        &None,
-        &None,
         &None,
         &None,
         &None,
@@ -61,19 +59,15 @@ fn test_update_all_config_including_reserve_ids() {
     let client = create_test_blendizzard(&env, &admin);
 
     // Create new addresses for all config params
-    let new_fee_vault = Address::generate(&env);
     let new_soroswap_router = Address::generate(&env);
     let new_blnd_token = Address::generate(&env);
-    let new_usdc_token = Address::generate(&env);
     let new_epoch_duration = 86400u64; // 1 day
     let new_reserve_ids = vec![&env, 1u32, 3u32];
 
     // Update all config parameters at once including reserve_token_ids
     client.update_config(
-        &Some(new_fee_vault),
         &Some(new_soroswap_router),
         &Some(new_blnd_token),
-        &Some(new_usdc_token),
         &Some(new_epoch_duration),
         &Some(new_reserve_ids),
         &None,
@@ -159,8 +153,6 @@ fn test_multiple_reserve_token_ids() {
     let multi_reserve_ids = vec![&env, 1u32, 3u32, 5u32, 7u32];
 
     client.update_config(
-        &None,
-        &None,
         &None,
         &None,
         &None,
@@ -192,8 +184,6 @@ fn test_empty_reserve_token_ids() {
     let empty_reserve_ids = vec![&env];
 
     client.update_config(
-        &None,
-        &None,
         &None,
         &None,
         &None,
@@ -238,3 +228,115 @@ fn test_reserve_token_id_formula() {
     assert_eq!(reserve_1_debt, 2);
     assert_eq!(reserve_2_btoken, 5);
 }
+
+// ============================================================================
+// Automatic Reserve Detection
+// ============================================================================
+
+#[test]
+fn test_auto_reserve_emissions_finds_all_emitting_tokens() {
+    use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+    use super::testutils::create_blendizzard_contract;
+    use crate::types::VaultBackend;
+    use sep_41_token::testutils::MockTokenClient;
+
+    let env = setup_test_env();
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(&env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(&env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(&env, &xlm);
+    let pool = create_blend_pool(&env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    // Static list by default
+    env.as_contract(&blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        let ids = crate::vault::emission_token_ids(&env, &config).unwrap();
+        assert_eq!(ids, vec![&env, 1]);
+    });
+
+    // Both reserves' d-tokens and b-tokens emit in the fixture pool
+    blendizzard.set_auto_reserve_emissions(&true);
+    env.as_contract(&blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        let ids = crate::vault::emission_token_ids(&env, &config).unwrap();
+        assert_eq!(ids, vec![&env, 0, 1, 2, 3]);
+    });
+}
+
+#[test]
+fn test_set_reserve_token_ids_accepts_d_tokens_on_listed_reserves() {
+    use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+    use super::testutils::{assert_contract_error, create_blendizzard_contract, Error};
+    use crate::types::VaultBackend;
+    use sep_41_token::testutils::MockTokenClient;
+
+    let env = setup_test_env();
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(&env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(&env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(&env, &xlm);
+    let pool = create_blend_pool(&env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![&env, 1],
+    );
+
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    // d-tokens and b-tokens of both listed reserves
+    blendizzard.set_reserve_token_ids(&vec![&env, 0, 1, 2, 3]);
+    assert_eq!(
+        blendizzard.get_config().reserve_token_ids,
+        vec![&env, 0, 1, 2, 3]
+    );
+    env.as_contract(&blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        let ids = crate::vault::emission_token_ids(&env, &config).unwrap();
+        assert_eq!(ids, vec![&env, 0, 1, 2, 3]);
+    });
+
+    // Reserve 2 isn't listed on the pool
+    assert_contract_error(
+        &blendizzard.try_set_reserve_token_ids(&vec![&env, 1, 4]),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_reserve_token_ids(&vec![&env, 1, 1]),
+        Error::InvalidConfig,
+    );
+    assert_eq!(
+        blendizzard.get_config().reserve_token_ids,
+        vec![&env, 0, 1, 2, 3]
+    );
+}
@@ -0,0 +1,101 @@
+/// Epoch Clock Tests
+///
+/// Tests that epochs run on ledger sequence numbers when the contract is
+/// initialized with `EpochClock::LedgerSequence`, ignoring timestamps.
+use super::fee_vault_utils::create_mock_vault;
+use super::soroswap_utils::create_token;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract_with_clock, create_test_blendizzard,
+    setup_test_env, Error, DEFAULT_FREE_FP_PER_EPOCH, DEFAULT_MIN_DEPOSIT_TO_CLAIM,
+};
+use crate::types::{EpochClock, EpochPhase};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const EPOCH_LEDGERS: u64 = 120;
+
+/// Create Blendizzard with 120-ledger epochs
+fn setup_ledger_clock(env: &Env) -> BlendizzardClient<'_> {
+    let admin = Address::generate(env);
+    create_blendizzard_contract_with_clock(
+        env,
+        &admin,
+        &create_mock_vault(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &create_token(env, &admin).address,
+        EPOCH_LEDGERS,
+        EpochClock::LedgerSequence,
+        vec![env, 1],
+        DEFAULT_FREE_FP_PER_EPOCH,
+        DEFAULT_MIN_DEPOSIT_TO_CLAIM,
+        1_000_000,
+    )
+}
+
+#[test]
+fn test_timestamp_clock_by_default() {
+    let env = setup_test_env();
+    let blendizzard = create_test_blendizzard(&env, &Address::generate(&env));
+
+    assert_eq!(blendizzard.get_epoch_clock(), EpochClock::Timestamp);
+    assert_eq!(
+        blendizzard.get_epoch(&0).start_time,
+        env.ledger().timestamp()
+    );
+}
+
+#[test]
+fn test_ledger_clock_epoch_boundaries() {
+    let env = setup_test_env();
+    let blendizzard = setup_ledger_clock(&env);
+    let start = env.ledger().sequence() as u64;
+
+    assert_eq!(blendizzard.get_epoch_clock(), EpochClock::LedgerSequence);
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(epoch0.start_time, start);
+    assert_eq!(epoch0.end_time, start + EPOCH_LEDGERS);
+
+    // Time passing alone doesn't end the epoch
+    env.ledger().with_mut(|li| li.timestamp += 30 * 86_400);
+    assert_contract_error(&blendizzard.try_cycle_epoch(), Error::EpochNotReady);
+    let schedule = blendizzard.get_epoch_schedule();
+    assert_eq!(schedule.seconds_remaining, EPOCH_LEDGERS);
+    assert!(!schedule.can_cycle);
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number += EPOCH_LEDGERS as u32);
+    assert!(blendizzard.get_epoch_schedule().can_cycle);
+    assert_eq!(blendizzard.cycle_epoch(), 1);
+
+    let epoch1 = blendizzard.get_epoch(&1);
+    assert_eq!(epoch1.start_time, epoch0.end_time);
+    assert_eq!(epoch1.end_time, epoch0.end_time + EPOCH_LEDGERS);
+}
+
+#[test]
+fn test_ledger_clock_skips_missed_epochs() {
+    let env = setup_test_env();
+    let blendizzard = setup_ledger_clock(&env);
+    let epoch0 = blendizzard.get_epoch(&0);
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number = (epoch0.end_time + 2 * EPOCH_LEDGERS + 1) as u32);
+    assert_eq!(blendizzard.cycle_epoch(), 3);
+    assert_eq!(
+        blendizzard.get_epoch(&3).start_time,
+        epoch0.end_time + 2 * EPOCH_LEDGERS
+    );
+}
+
+#[test]
+fn test_ledger_clock_selection_phase() {
+    let env = setup_test_env();
+    let blendizzard = setup_ledger_clock(&env);
+    blendizzard.set_selection_phase(&20);
+
+    assert_eq!(blendizzard.get_epoch_phase(), EpochPhase::Selection);
+    env.ledger().with_mut(|li| li.sequence_number += 20);
+    assert_eq!(blendizzard.get_epoch_phase(), EpochPhase::Battle);
+}
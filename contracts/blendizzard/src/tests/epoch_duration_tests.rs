@@ -0,0 +1,100 @@
+/// Scheduled Epoch Duration Tests
+///
+/// Tests that a new epoch duration only takes effect when the next epoch
+/// opens, and that each epoch records the duration it was opened with.
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+const FOUR_DAYS: u64 = 345_600;
+const ONE_DAY: u64 = 86_400;
+
+#[test]
+fn test_next_epoch_duration_applies_on_cycle() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let epoch0 = blendizzard.get_epoch(&0);
+
+    blendizzard.set_next_epoch_duration(&ONE_DAY);
+
+    // The current epoch keeps its schedule
+    let config = blendizzard.get_config();
+    assert_eq!(config.epoch_duration, FOUR_DAYS);
+    assert_eq!(config.next_epoch_duration, Some(ONE_DAY));
+    assert_eq!(blendizzard.get_epoch(&0), epoch0);
+    assert_eq!(epoch0.duration, FOUR_DAYS);
+
+    env.ledger().with_mut(|li| li.timestamp = epoch0.end_time);
+    blendizzard.cycle_epoch();
+
+    let epoch1 = blendizzard.get_epoch(&1);
+    assert_eq!(epoch1.start_time, epoch0.end_time);
+    assert_eq!(epoch1.duration, ONE_DAY);
+    assert_eq!(epoch1.end_time, epoch0.end_time + ONE_DAY);
+    assert_eq!(blendizzard.get_epoch(&0).duration, FOUR_DAYS);
+
+    let config = blendizzard.get_config();
+    assert_eq!(config.epoch_duration, ONE_DAY);
+    assert_eq!(config.next_epoch_duration, None);
+}
+
+#[test]
+fn test_late_cycle_skips_epochs_at_new_duration() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let epoch0 = blendizzard.get_epoch(&0);
+
+    blendizzard.set_next_epoch_duration(&ONE_DAY);
+
+    // Two full one-day epochs elapse after epoch 0 before anyone cycles
+    env.ledger()
+        .with_mut(|li| li.timestamp = epoch0.end_time + 2 * ONE_DAY + 1);
+    assert_eq!(blendizzard.cycle_epoch(), 3);
+
+    let epoch3 = blendizzard.get_epoch(&3);
+    assert_eq!(epoch3.start_time, epoch0.end_time + 2 * ONE_DAY);
+    assert_eq!(epoch3.duration, ONE_DAY);
+}
+
+#[test]
+fn test_update_config_schedules_epoch_duration() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let end_time = blendizzard.get_epoch(&0).end_time;
+
+    blendizzard.update_config(&None, &None, &Some(ONE_DAY), &None, &None, &None, &None);
+
+    assert_eq!(blendizzard.get_epoch(&0).end_time, end_time);
+    assert_eq!(blendizzard.get_config().next_epoch_duration, Some(ONE_DAY));
+}
+
+#[test]
+fn test_set_next_epoch_duration_validation() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_set_next_epoch_duration(&0),
+        Error::InvalidConfig,
+    );
+
+    // Must leave room for the selection phase
+    blendizzard.set_selection_phase(&3600);
+    assert_contract_error(
+        &blendizzard.try_set_next_epoch_duration(&3600),
+        Error::InvalidConfig,
+    );
+
+    // ...and the selection phase must fit the scheduled duration too
+    blendizzard.set_next_epoch_duration(&7200);
+    assert_contract_error(
+        &blendizzard.try_set_selection_phase(&7200),
+        Error::InvalidConfig,
+    );
+}
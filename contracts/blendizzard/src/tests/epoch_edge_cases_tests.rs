@@ -5,6 +5,7 @@
 /// - Cycling with no games played
 /// - Tie handling in faction standings
 /// - Error handling during BLND→USDC swap
+/// - Catching up after missed cycles
 ///
 /// These tests verify epoch boundaries and error conditions.
 use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
@@ -296,3 +297,112 @@ fn test_epoch_cycle_swap_failure_handling() {
     // This test documents current behavior - if swap fails, reward pool is 0
     // and epoch still cycles (good for robustness)
 }
+
+/// Test epoch catch-up after several missed epoch durations
+///
+/// If nobody cycles for N durations, cycle_epoch should fast-forward over the
+/// missed epochs (stored as empty, finalized epochs) and keep the new epoch
+/// aligned to the original schedule.
+#[test]
+fn test_epoch_cycle_fast_forwards_missed_epochs() {
+    let env = setup_test_env();
+    let (_game_contract, _vault_addr, _mock_vault, blendizzard) = setup_epoch_test_env(&env);
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    let start_time = epoch0.start_time;
+
+    // Nobody cycles for 3.5 epoch durations
+    env.ledger()
+        .with_mut(|li| li.timestamp = start_time + 345_600 * 3 + 172_800);
+
+    let new_epoch = blendizzard.cycle_epoch();
+
+    // Epoch 0 closed, epochs 1 and 2 skipped, epoch 3 is current
+    assert_eq!(new_epoch, 3);
+    assert_eq!(blendizzard.get_current_epoch(), 3);
+
+    for skipped in 1..3u32 {
+        let epoch = blendizzard.get_epoch(&skipped);
+        assert!(epoch.is_finalized);
+        assert_eq!(epoch.reward_pool, 0);
+        assert_eq!(epoch.start_time, start_time + 345_600 * skipped as u64);
+    }
+
+    // New epoch stays on the original schedule
+    let epoch3 = blendizzard.get_epoch(&3);
+    assert_eq!(epoch3.start_time, start_time + 345_600 * 3);
+    assert_eq!(epoch3.end_time, start_time + 345_600 * 4);
+    assert!(!epoch3.is_finalized);
+}
+
+/// Test that a slightly late cycle doesn't skip or drift
+#[test]
+fn test_late_epoch_cycle_keeps_schedule() {
+    let env = setup_test_env();
+    let (_game_contract, _vault_addr, _mock_vault, blendizzard) = setup_epoch_test_env(&env);
+
+    let start_time = blendizzard.get_epoch(&0).start_time;
+
+    // Cycle one hour late
+    env.ledger()
+        .with_mut(|li| li.timestamp = start_time + 345_600 + 3600);
+    assert_eq!(blendizzard.cycle_epoch(), 1);
+
+    // Epoch 1 starts at epoch 0's end time, not at the (late) cycle time
+    let epoch1 = blendizzard.get_epoch(&1);
+    assert_eq!(epoch1.start_time, start_time + 345_600);
+    assert_eq!(epoch1.end_time, start_time + 2 * 345_600);
+}
+
+/// Test batched epoch history is ordered and clamped to the current epoch
+#[test]
+fn test_get_epochs_range() {
+    let env = setup_test_env();
+    let (_game_contract, _vault_addr, _mock_vault, blendizzard) = setup_epoch_test_env(&env);
+
+    let start_time = blendizzard.get_epoch(&0).start_time;
+    env.ledger()
+        .with_mut(|li| li.timestamp = start_time + 345_600 * 3);
+    assert_eq!(blendizzard.cycle_epoch(), 3);
+
+    let epochs = blendizzard.get_epochs(&1, &100);
+    assert_eq!(epochs.len(), 3);
+    for (i, epoch) in epochs.iter().enumerate() {
+        assert_eq!(epoch.start_time, start_time + 345_600 * (i as u64 + 1));
+    }
+
+    assert_eq!(blendizzard.get_epochs(&0, &0).len(), 1);
+    assert_eq!(blendizzard.get_epochs(&2, &1).len(), 0);
+}
+
+#[test]
+fn test_yield_stats_recorded_on_cycle() {
+    let env = setup_test_env();
+    let (_game_contract, _vault_addr, mock_vault, blendizzard) = setup_epoch_test_env(&env);
+
+    assert_eq!(blendizzard.get_yield_stats().epochs.len(), 0);
+    assert_eq!(blendizzard.get_yield_stats().trailing_apy, 0);
+
+    // 10 USDC of rewards on 1,000 USDC TVL (b_rate stays at 1.0 in the mock)
+    mock_vault.set_total_underlying(&1000_0000000);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, 10_0000000);
+    });
+
+    let start_time = blendizzard.get_epoch(&0).start_time;
+    env.ledger()
+        .with_mut(|li| li.timestamp = start_time + 345_600);
+    blendizzard.cycle_epoch();
+
+    let stats = blendizzard.get_yield_stats();
+    assert_eq!(stats.epochs.len(), 1);
+    let snapshot = stats.epochs.get(0).unwrap();
+    assert_eq!(snapshot.epoch, 0);
+    assert_eq!(snapshot.period, 345_600);
+    assert_eq!(snapshot.tvl, 1000_0000000);
+    assert_eq!(snapshot.rewards, 10_0000000);
+    assert_eq!(snapshot.realized_yield, 100_000); // 1%
+
+    // 1% per 4 days = 91.25% annualized
+    assert_eq!(stats.trailing_apy, 9_125_000);
+}
@@ -0,0 +1,74 @@
+/// Two-Phase Epoch Tests
+///
+/// Tests the faction-selection phase (switching allowed, games disabled) and
+/// battle phase (games allowed, switching disabled).
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::EpochPhase;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address};
+
+const SELECTION_PHASE: u64 = 86_400; // 1 day
+
+#[test]
+fn test_selection_then_battle_phase() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.set_selection_phase(&SELECTION_PHASE);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+
+    // Selection phase: switching allowed, games disabled
+    assert_eq!(blendizzard.get_epoch_phase(), EpochPhase::Selection);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player1, &2);
+    blendizzard.select_faction(&player2, &1);
+
+    let result =
+        blendizzard.try_start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    assert_contract_error(&result, Error::SelectionPhaseActive);
+
+    // Battle phase: games allowed, switching disabled
+    env.ledger().with_mut(|li| li.timestamp += SELECTION_PHASE);
+    assert_eq!(blendizzard.get_epoch_phase(), EpochPhase::Battle);
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    let result = blendizzard.try_select_faction(&player1, &0);
+    assert_contract_error(&result, Error::FactionAlreadyLocked);
+
+    // Re-selecting the same faction and first-time selection still work
+    blendizzard.select_faction(&player1, &2);
+    blendizzard.select_faction(&Address::generate(&env), &0);
+}
+
+#[test]
+fn test_set_selection_phase_validation() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = super::testutils::create_test_blendizzard(&env, &admin);
+
+    let epoch_duration = blendizzard.get_config().epoch_duration;
+    let result = blendizzard.try_set_selection_phase(&epoch_duration);
+    assert_contract_error(&result, Error::InvalidConfig);
+
+    // Disabled by default: always in the battle phase
+    assert_eq!(blendizzard.get_epoch_phase(), EpochPhase::Battle);
+}
@@ -0,0 +1,61 @@
+/// Epoch Schedule Tests
+///
+/// Tests the countdown returned by `get_epoch_schedule` and when it reports
+/// that `cycle_epoch` can be called.
+use super::testutils::{create_blendizzard_with_soroswap, setup_test_env};
+use crate::types::EpochSchedule;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+#[test]
+fn test_schedule_counts_down_and_follows_cycles() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let start = blendizzard.get_epoch(&0).start_time;
+
+    assert_eq!(
+        blendizzard.get_epoch_schedule(),
+        EpochSchedule {
+            epoch: 0,
+            start_time: start,
+            end_time: start + 345_600,
+            seconds_remaining: 345_600,
+            can_cycle: false,
+        }
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 345_000);
+    let schedule = blendizzard.get_epoch_schedule();
+    assert_eq!(schedule.seconds_remaining, 600);
+    assert!(!schedule.can_cycle);
+
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    let schedule = blendizzard.get_epoch_schedule();
+    assert_eq!(schedule.seconds_remaining, 0);
+    assert!(schedule.can_cycle);
+
+    // The next epoch starts where the previous one ended, not at the cycle
+    blendizzard.cycle_epoch();
+    let schedule = blendizzard.get_epoch_schedule();
+    assert_eq!(schedule.epoch, 1);
+    assert_eq!(schedule.start_time, start + 345_600);
+    assert_eq!(schedule.seconds_remaining, 345_599);
+    assert!(!schedule.can_cycle);
+}
+
+#[test]
+fn test_schedule_waits_for_keeper_priority_window() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    blendizzard.set_keeper_settings(&10_0000000, &3_600, &0);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    let schedule = blendizzard.get_epoch_schedule();
+    assert_eq!(schedule.seconds_remaining, 0);
+    assert!(!schedule.can_cycle);
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert!(blendizzard.get_epoch_schedule().can_cycle);
+}
@@ -0,0 +1,150 @@
+/// Epoch Stages Tests
+///
+/// Tests cycling in two stages: `finalize_epoch` closes the epoch and opens
+/// the next one, `process_rewards` collects and distributes its rewards. Both
+/// are safe to retry, and `cycle_epoch` resumes an unfinished cycle, including
+/// one advanced stage by stage with `cycle_epoch_step`.
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::types::CycleStage;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+#[test]
+fn test_finalize_then_process_rewards() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    assert_eq!(blendizzard.get_unprocessed_epoch(), None);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    assert_eq!(blendizzard.finalize_epoch(), 1);
+
+    // The next epoch is open, the closed one has nothing to claim yet
+    assert_eq!(blendizzard.get_current_epoch(), 1);
+    assert_eq!(blendizzard.get_unprocessed_epoch(), Some(0));
+    let closed = blendizzard.get_epoch(&0);
+    assert!(!closed.is_finalized);
+    assert_eq!(closed.winning_faction, None);
+    assert_eq!(closed.reward_pool, 0);
+
+    // Retrying the first stage changes nothing
+    assert_contract_error(&blendizzard.try_finalize_epoch(), Error::EpochNotReady);
+
+    let reward_pool = blendizzard.process_rewards(&0);
+    assert!(reward_pool > 0);
+    let processed = blendizzard.get_epoch(&0);
+    assert!(processed.is_finalized);
+    assert_eq!(processed.winning_faction, Some(0));
+    assert_eq!(processed.reward_pool, reward_pool);
+    assert_eq!(blendizzard.get_unprocessed_epoch(), None);
+
+    // Retrying the second stage changes nothing
+    assert_eq!(blendizzard.process_rewards(&0), reward_pool);
+    assert_eq!(blendizzard.get_epoch(&0), processed);
+}
+
+#[test]
+fn test_next_epoch_waits_for_processing() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.finalize_epoch();
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+
+    assert!(!blendizzard.get_epoch_schedule().can_cycle);
+    assert_contract_error(&blendizzard.try_finalize_epoch(), Error::EpochNotFinalized);
+
+    blendizzard.process_rewards(&0);
+    assert!(blendizzard.get_epoch_schedule().can_cycle);
+    assert_eq!(blendizzard.finalize_epoch(), 2);
+}
+
+#[test]
+fn test_process_rewards_rejects_open_epochs() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_process_rewards(&0),
+        Error::EpochNotFinalized,
+    );
+    assert_contract_error(
+        &blendizzard.try_process_rewards(&5),
+        Error::EpochNotFinalized,
+    );
+}
+
+#[test]
+fn test_cycle_epoch_resumes_unprocessed_epoch() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.finalize_epoch();
+
+    // Finishes the closed epoch without closing the open one
+    assert_eq!(blendizzard.cycle_epoch(), 1);
+    assert_eq!(blendizzard.get_current_epoch(), 1);
+    assert_eq!(blendizzard.get_unprocessed_epoch(), None);
+    let processed = blendizzard.get_epoch(&0);
+    assert!(processed.is_finalized);
+    assert!(processed.reward_pool > 0);
+    assert!(!blendizzard.get_epoch(&1).is_finalized);
+}
+
+#[test]
+fn test_cycle_epoch_steps_match_one_call() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+    let expected_pool = blendizzard.get_epoch(&0).reward_pool;
+
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+
+    assert_eq!(blendizzard.cycle_epoch_step(), CycleStage::Finalize);
+    assert!(blendizzard.get_cycle_progress(&0).finalized);
+    assert_eq!(blendizzard.cycle_epoch_step(), CycleStage::Claim);
+    assert!(blendizzard.get_cycle_progress(&0).claimed);
+    assert_eq!(blendizzard.cycle_epoch_step(), CycleStage::Swap);
+    let progress = blendizzard.get_cycle_progress(&0);
+    assert!(progress.swapped);
+    assert!(!blendizzard.get_epoch(&0).is_finalized);
+
+    assert_eq!(blendizzard.cycle_epoch_step(), CycleStage::Distribute);
+    assert_eq!(blendizzard.get_epoch(&0).reward_pool, expected_pool);
+    assert_eq!(blendizzard.get_unprocessed_epoch(), None);
+    assert!(!blendizzard.get_cycle_progress(&0).finalized);
+}
+
+#[test]
+fn test_cycle_epoch_skips_committed_stages() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+    let expected_pool = blendizzard.get_epoch(&0).reward_pool;
+
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+
+    // A cycle interrupted after the swap resumes without swapping again
+    blendizzard.cycle_epoch_step();
+    blendizzard.cycle_epoch_step();
+    blendizzard.cycle_epoch_step();
+    assert_eq!(blendizzard.cycle_epoch(), 1);
+    assert_eq!(blendizzard.get_epoch(&0).reward_pool, expected_pool);
+}
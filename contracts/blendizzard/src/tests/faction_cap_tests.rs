@@ -0,0 +1,125 @@
+/// Faction Capacity Tests
+///
+/// Tests that `select_faction` rejects a faction that has outgrown the
+/// smallest one by more than the configured member or stake ratio.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::types::FactionCap;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+struct Setup<'a> {
+    blendizzard: BlendizzardClient<'a>,
+    vault: MockVaultClient<'a>,
+    game: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    Setup {
+        blendizzard,
+        vault: MockVaultClient::new(env, &vault_addr),
+        game,
+    }
+}
+
+/// Lock a new faction 0 player and a new faction 1 player into this epoch
+fn play(env: &Env, s: &Setup, session_id: u32, balance0: i128, balance1: i128) -> Address {
+    let player0 = Address::generate(env);
+    let player1 = Address::generate(env);
+    s.vault.set_user_balance(&player0, &balance0);
+    s.vault.set_user_balance(&player1, &balance1);
+    s.blendizzard.select_faction(&player0, &0);
+    s.blendizzard.select_faction(&player1, &1);
+    s.blendizzard
+        .start_game(&s.game, &session_id, &player0, &player1, &WAGER, &WAGER);
+    player0
+}
+
+#[test]
+fn test_member_cap_closes_outgrown_factions() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard
+        .set_faction_cap(&FactionCap::Members(2_0000000));
+
+    // The empty faction 2 counts as one member: factions 0 and 1 may grow to 2
+    let veteran = play(&env, &s, 1, 1000_0000000, 1000_0000000);
+    play(&env, &s, 2, 1000_0000000, 1000_0000000);
+    s.blendizzard.select_faction(&Address::generate(&env), &0);
+
+    play(&env, &s, 3, 1000_0000000, 1000_0000000);
+
+    let newcomer = Address::generate(&env);
+    assert_contract_error(
+        &s.blendizzard.try_select_faction(&newcomer, &0),
+        ExtError::FactionFull,
+    );
+    assert_contract_error(
+        &s.blendizzard.try_select_faction(&newcomer, &1),
+        ExtError::FactionFull,
+    );
+    s.blendizzard.select_faction(&newcomer, &2);
+
+    // Members can keep their current faction
+    s.blendizzard.select_faction(&veteran, &0);
+}
+
+#[test]
+fn test_stake_cap_closes_outgrown_factions() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard.set_faction_cap(&FactionCap::Stake(2_0000000));
+
+    play(&env, &s, 1, 1000_0000000, 100_0000000);
+
+    let newcomer = Address::generate(&env);
+    assert_contract_error(
+        &s.blendizzard.try_select_faction(&newcomer, &0),
+        ExtError::FactionFull,
+    );
+    s.blendizzard.select_faction(&newcomer, &2);
+}
+
+#[test]
+fn test_no_cap_by_default() {
+    let env = setup_test_env();
+    let s = setup(&env);
+
+    for session_id in 1..=3 {
+        play(&env, &s, session_id, 1000_0000000, 1000_0000000);
+    }
+    s.blendizzard.select_faction(&Address::generate(&env), &0);
+}
+
+#[test]
+fn test_set_faction_cap_rejects_ratio_below_one() {
+    let env = setup_test_env();
+    let s = setup(&env);
+
+    assert_contract_error(
+        &s.blendizzard
+            .try_set_faction_cap(&FactionCap::Members(9_000_000)),
+        Error::InvalidConfig,
+    );
+    s.blendizzard.set_faction_cap(&FactionCap::None);
+}
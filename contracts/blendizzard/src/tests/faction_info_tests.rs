@@ -0,0 +1,68 @@
+/// Faction Metadata Tests
+///
+/// Tests the admin-set faction metadata registry and its built-in defaults.
+use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, String};
+
+#[test]
+fn test_get_factions_defaults() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let factions = blendizzard.get_factions();
+    assert_eq!(factions.len(), 3);
+
+    let noodle = factions.get(0).unwrap();
+    assert_eq!(noodle.id, 0);
+    assert_eq!(noodle.name, String::from_str(&env, "WholeNoodle"));
+    assert_eq!(noodle.icon_hash, BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(
+        factions.get(2).unwrap().name,
+        String::from_str(&env, "SpecialRock")
+    );
+}
+
+#[test]
+fn test_set_faction_info() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let icon_hash = BytesN::from_array(&env, &[7u8; 32]);
+    blendizzard.set_faction_info(
+        &1,
+        &String::from_str(&env, "Pointy Sticks"),
+        &String::from_str(&env, "PSTK"),
+        &icon_hash,
+    );
+
+    let stick = blendizzard.get_factions().get(1).unwrap();
+    assert_eq!(stick.id, 1);
+    assert_eq!(stick.name, String::from_str(&env, "Pointy Sticks"));
+    assert_eq!(stick.symbol, String::from_str(&env, "PSTK"));
+    assert_eq!(stick.icon_hash, icon_hash);
+}
+
+#[test]
+fn test_set_faction_info_validation() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let name = String::from_str(&env, "Rocks");
+    let symbol = String::from_str(&env, "ROCK");
+    let icon_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+    let result = blendizzard.try_set_faction_info(&3, &name, &symbol, &icon_hash);
+    assert_contract_error(&result, Error::InvalidFaction);
+
+    let empty = String::from_str(&env, "");
+    let result = blendizzard.try_set_faction_info(&2, &empty, &symbol, &icon_hash);
+    assert_contract_error(&result, Error::InvalidFactionMetadata);
+
+    let long_symbol = String::from_str(&env, "VERYLONGSYMBOL");
+    let result = blendizzard.try_set_faction_info(&2, &name, &long_symbol, &icon_hash);
+    assert_contract_error(&result, Error::InvalidFactionMetadata);
+}
@@ -0,0 +1,119 @@
+/// Faction Lock Tests
+///
+/// Tests the lock status returned by `get_faction_lock`: unlocked, sessions in
+/// progress, played this epoch and battle phase, with the unlock ledger.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::types::{FactionLock, FactionLockReason};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Ledger the first epoch ends at (sequence 100 + 345_600s at 5s per ledger)
+const EPOCH_END_LEDGER: u32 = 100 + 345_600 / 5;
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_lock_follows_sessions_and_epoch_faction() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+
+    let unlocked = FactionLock {
+        reason: FactionLockReason::Unlocked,
+        epoch_faction: None,
+        unlock_ledger: 0,
+    };
+    assert_eq!(blendizzard.get_faction_lock(&player1), unlocked);
+    assert_eq!(
+        blendizzard.get_faction_lock(&Address::generate(&env)),
+        unlocked
+    );
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    assert_eq!(
+        blendizzard.get_faction_lock(&player1),
+        FactionLock {
+            reason: FactionLockReason::ActiveSessions,
+            epoch_faction: Some(0),
+            unlock_ledger: EPOCH_END_LEDGER,
+        }
+    );
+
+    blendizzard.end_game(&1, &true);
+    assert_eq!(
+        blendizzard.get_faction_lock(&player2),
+        FactionLock {
+            reason: FactionLockReason::EpochFaction,
+            epoch_faction: Some(1),
+            unlock_ledger: EPOCH_END_LEDGER,
+        }
+    );
+
+    // The estimate follows the ledger clock
+    env.ledger().with_mut(|li| {
+        li.timestamp += 5_000;
+        li.sequence_number += 1_000;
+    });
+    assert_eq!(
+        blendizzard.get_faction_lock(&player1).unlock_ledger,
+        EPOCH_END_LEDGER
+    );
+}
+
+#[test]
+fn test_battle_phase_locks_existing_players_only() {
+    let env = setup_test_env();
+    let (blendizzard, _game, player1, _player2) = setup_players(&env);
+    blendizzard.set_selection_phase(&86_400);
+
+    assert_eq!(
+        blendizzard.get_faction_lock(&player1).reason,
+        FactionLockReason::Unlocked
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(
+        blendizzard.get_faction_lock(&player1),
+        FactionLock {
+            reason: FactionLockReason::BattlePhase,
+            epoch_faction: None,
+            unlock_ledger: 100 + (345_600 - 86_400) / 5,
+        }
+    );
+
+    // First-time selection is always allowed
+    assert_eq!(
+        blendizzard
+            .get_faction_lock(&Address::generate(&env))
+            .reason,
+        FactionLockReason::Unlocked
+    );
+}
@@ -0,0 +1,87 @@
+/// Faction Totals Tests
+///
+/// Tests the live per-faction members, stake, points and projected pool share
+/// reported by `get_faction_totals`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    Error,
+};
+use crate::types::SCALAR_7;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+const WAGER: i128 = 10_0000000;
+
+#[test]
+fn test_faction_totals_track_members_stake_and_points() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &500_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // Nobody has played yet: faction 0 leads by default
+    let totals = blendizzard.get_faction_totals(&0);
+    assert_eq!(totals.len(), 3);
+    assert_eq!(totals.get(0).unwrap().members, 0);
+    assert_eq!(totals.get(0).unwrap().pool_share, SCALAR_7);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+    blendizzard.end_game(&1, &false);
+
+    // A second game doesn't count the players again
+    blendizzard.start_game(&game, &2, &player1, &player2, &WAGER, &WAGER);
+
+    let snapshot1 = blendizzard
+        .get_epoch_player(&0, &player1)
+        .epoch_balance_snapshot;
+    let snapshot2 = blendizzard
+        .get_epoch_player(&0, &player2)
+        .epoch_balance_snapshot;
+
+    let totals = blendizzard.get_faction_totals(&0);
+    let noodle = totals.get(0).unwrap();
+    let stick = totals.get(1).unwrap();
+    assert_eq!(
+        (noodle.members, noodle.stake, noodle.points),
+        (1, snapshot1, 0)
+    );
+    assert_eq!(
+        (stick.members, stick.stake, stick.points),
+        (1, snapshot2, WAGER)
+    );
+    assert_eq!(noodle.pool_share, 0);
+    assert_eq!(stick.pool_share, SCALAR_7);
+    assert_eq!(totals.get(2).unwrap().members, 0);
+}
+
+#[test]
+fn test_faction_totals_unknown_epoch() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_get_faction_totals(&5),
+        Error::EpochNotFinalized,
+    );
+}
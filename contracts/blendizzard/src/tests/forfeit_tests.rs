@@ -0,0 +1,114 @@
+/// Forfeit Tests
+///
+/// Tests that games can award a session to the opponent of an inactive player
+/// once the session is older than the forfeit delay, and that the delay is
+/// admin-configurable.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a registered game and a started session 1
+/// (player1 in faction 0, player2 in faction 1)
+fn setup_session<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+
+    (blendizzard, player1, player2)
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_forfeit_awards_opponent_after_delay() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2) = setup_session(&env);
+    assert_eq!(blendizzard.get_forfeit_delay(), 720);
+
+    // Too young to forfeit
+    advance_ledgers(&env, 719);
+    assert_contract_error(
+        &blendizzard.try_forfeit(&1, &player1),
+        Error::InvalidSessionState,
+    );
+
+    advance_ledgers(&env, 1);
+    blendizzard.forfeit(&1, &player1);
+
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player2)
+            .total_fp_contributed,
+        WAGER
+    );
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player1)
+            .total_fp_contributed,
+        0
+    );
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 0);
+
+    assert_contract_error(
+        &blendizzard.try_forfeit(&1, &player2),
+        Error::SessionAlreadyFinalized,
+    );
+}
+
+#[test]
+fn test_forfeit_rejects_outsiders_and_unknown_sessions() {
+    let env = setup_test_env();
+    let (blendizzard, _player1, player2) = setup_session(&env);
+    advance_ledgers(&env, 720);
+
+    assert_contract_error(
+        &blendizzard.try_forfeit(&1, &Address::generate(&env)),
+        Error::PlayerNotFound,
+    );
+    assert_contract_error(
+        &blendizzard.try_forfeit(&2, &player2),
+        Error::SessionNotFound,
+    );
+}
+
+#[test]
+fn test_set_forfeit_delay() {
+    let env = setup_test_env();
+    let (blendizzard, player1, _player2) = setup_session(&env);
+
+    assert_contract_error(&blendizzard.try_set_forfeit_delay(&0), Error::InvalidConfig);
+
+    blendizzard.set_forfeit_delay(&10);
+    assert_eq!(blendizzard.get_forfeit_delay(), 10);
+
+    advance_ledgers(&env, 10);
+    blendizzard.forfeit(&1, &player1);
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 0);
+}
@@ -0,0 +1,115 @@
+/// Faction Point Decay Tests
+///
+/// Tests that with `fp_half_life_ledgers` set, wins early in an epoch are
+/// credited at their value decayed to the epoch's end, so late activity
+/// outweighs early grinding.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::math::checked_decay;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+const EPOCH_DURATION: u64 = 345_600;
+/// Half of the epoch's 69_120 ledgers
+const HALF_LIFE_LEDGERS: u32 = 34_560;
+
+/// Create Blendizzard with FP decay and a registered game
+fn setup_with_decay(env: &Env) -> (BlendizzardClient<'_>, MockVaultClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        EPOCH_DURATION,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_fp_decay(&HALF_LIFE_LEDGERS);
+
+    (blendizzard, MockVaultClient::new(env, &vault_addr), game)
+}
+
+/// Play session `session_id` between new players in factions 0 and 1
+fn play(
+    env: &Env,
+    blendizzard: &BlendizzardClient,
+    vault: &MockVaultClient,
+    game: &Address,
+    session_id: u32,
+    player1_won: bool,
+) -> (Address, Address) {
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(game, &session_id, &player1, &player2, &WAGER, &WAGER);
+    blendizzard.end_game(&session_id, &player1_won);
+    (player1, player2)
+}
+
+fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_early_win_is_credited_at_decayed_value() {
+    let env = setup_test_env();
+    let (blendizzard, vault, game) = setup_with_decay(&env);
+
+    // Two half-lives before the epoch ends
+    let (winner, _) = play(&env, &blendizzard, &vault, &game, 1, true);
+
+    let epoch = blendizzard.get_epoch(&0);
+    assert_eq!(epoch.faction_standings.get(0), Some(WAGER / 4));
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &winner)
+            .total_fp_contributed,
+        WAGER / 4
+    );
+    // Developer totals still count the full wagers
+    assert_eq!(epoch.total_game_fp, WAGER * 2);
+}
+
+#[test]
+fn test_late_win_outweighs_early_grinding() {
+    let env = setup_test_env();
+    let (blendizzard, vault, game) = setup_with_decay(&env);
+
+    // Faction 0 wins three games at the start of the epoch
+    for session_id in 1..=3 {
+        play(&env, &blendizzard, &vault, &game, session_id, true);
+    }
+
+    // Faction 1 wins one game right before the end
+    advance_time(&env, EPOCH_DURATION - 5);
+    play(&env, &blendizzard, &vault, &game, 4, false);
+
+    let standings = blendizzard.get_epoch(&0).faction_standings;
+    let early = standings.get(0).unwrap();
+    let late = standings.get(1).unwrap();
+    assert_eq!(early, WAGER * 3 / 4);
+    assert!(late > early && late < WAGER);
+}
+
+#[test]
+fn test_checked_decay() {
+    assert_eq!(checked_decay(1_000_0000000, 0, 100), Some(1_000_0000000));
+    assert_eq!(checked_decay(1_000_0000000, 300, 100), Some(125_0000000));
+    assert_eq!(checked_decay(1_000_0000000, 50, 0), Some(1_000_0000000));
+    assert_eq!(checked_decay(1_000_0000000, 100 * 200, 100), Some(0));
+    assert_eq!(checked_decay(-1, 50, 100), None);
+
+    // Half a half-life: 1 / sqrt(2) = 0.70710678...
+    assert_eq!(checked_decay(1_000_0000000, 50, 100), Some(707_1067811));
+}
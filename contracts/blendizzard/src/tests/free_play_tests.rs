@@ -191,10 +191,8 @@ fn test_update_config_changes_free_fp() {
     // Update free FP via update_config
     let new_free_fp = 500_0000000i128;
     blendizzard.update_config(
-        &None,              // fee_vault
         &None,              // soroswap_router
         &None,              // blnd_token
-        &None,              // usdc_token
         &None,              // epoch_duration
         &None,              // reserve_token_ids
         &Some(new_free_fp), // new_free_fp_per_epoch
@@ -409,8 +407,6 @@ fn test_min_deposit_threshold_is_configurable() {
         &None,
         &None,
         &None,
-        &None,
-        &None,
         &Some(custom_min_deposit),
         &None,
     );
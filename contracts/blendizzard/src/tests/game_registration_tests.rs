@@ -0,0 +1,143 @@
+/// Game Self-Registration Tests
+///
+/// Tests that a game implementing the standard game interface can register
+/// itself as pending, can't be played until the admin approves it, and gets
+/// its init hook called on approval.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::GameMetadata;
+use crate::BlendizzardClient;
+use number_guess::{NumberGuessContract, NumberGuessContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+/// Create Blendizzard and an unregistered number-guess game
+fn setup<'a>(
+    env: &'a Env,
+) -> (
+    BlendizzardClient<'a>,
+    NumberGuessContractClient<'a>,
+    MockVaultClient<'a>,
+) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+
+    let game_admin = Address::generate(env);
+    let game_addr = env.register(NumberGuessContract, (&game_admin, &blendizzard.address));
+
+    (
+        blendizzard,
+        NumberGuessContractClient::new(env, &game_addr),
+        MockVaultClient::new(env, &vault_addr),
+    )
+}
+
+#[test]
+fn test_self_registered_game_is_pending_until_approved() {
+    let env = setup_test_env();
+    let (blendizzard, number_guess, vault) = setup(&env);
+
+    number_guess.register_with_blendizzard();
+
+    let info = blendizzard.get_game_info(&number_guess.address);
+    assert!(info.pending);
+    assert_eq!(info.developer, number_guess.get_admin());
+    assert!(!blendizzard.is_game(&number_guess.address));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // Pending games can't start sessions
+    assert!(number_guess
+        .try_start_game(&1, &player1, &player2, &10_0000000, &10_0000000)
+        .is_err());
+
+    blendizzard.approve_game(&number_guess.address);
+
+    assert!(blendizzard.is_game(&number_guess.address));
+    assert!(!blendizzard.get_game_info(&number_guess.address).pending);
+    number_guess.start_game(&1, &player1, &player2, &10_0000000, &10_0000000);
+}
+
+#[test]
+fn test_approve_requires_pending_registration() {
+    let env = setup_test_env();
+    let (blendizzard, number_guess, _vault) = setup(&env);
+
+    assert_contract_error(
+        &blendizzard.try_approve_game(&number_guess.address),
+        Error::GameNotRegistered,
+    );
+
+    number_guess.register_with_blendizzard();
+    blendizzard.approve_game(&number_guess.address);
+
+    // Already approved: neither approval nor re-registration applies
+    assert_contract_error(
+        &blendizzard.try_approve_game(&number_guess.address),
+        Error::GameNotRegistered,
+    );
+    let metadata = number_guess.game_metadata();
+    assert_contract_error(
+        &blendizzard.try_register_game(
+            &number_guess.address,
+            &GameMetadata {
+                name: metadata.name,
+                developer: metadata.developer,
+            },
+        ),
+        Error::InvalidConfig,
+    );
+}
+
+#[test]
+fn test_register_game_validates_name() {
+    let env = setup_test_env();
+    let (blendizzard, _number_guess, _vault) = setup(&env);
+    let game = Address::generate(&env);
+
+    for name in ["", "A game name that is far too long to display"] {
+        assert_contract_error(
+            &blendizzard.try_register_game(
+                &game,
+                &GameMetadata {
+                    name: String::from_str(&env, name),
+                    developer: Address::generate(&env),
+                },
+            ),
+            Error::InvalidConfig,
+        );
+    }
+}
+
+#[test]
+fn test_remove_game_rejects_pending_registration() {
+    let env = setup_test_env();
+    let (blendizzard, number_guess, _vault) = setup(&env);
+
+    number_guess.register_with_blendizzard();
+    blendizzard.remove_game(&number_guess.address);
+
+    assert_contract_error(
+        &blendizzard.try_get_game_info(&number_guess.address),
+        Error::GameNotWhitelisted,
+    );
+    assert_contract_error(
+        &blendizzard.try_approve_game(&number_guess.address),
+        Error::GameNotRegistered,
+    );
+}
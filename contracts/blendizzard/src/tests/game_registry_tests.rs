@@ -0,0 +1,152 @@
+/// Game Registry Tests
+///
+/// Tests that `get_games` lists registered games in registration order with
+/// their limits, rake, status and lifetime session counts, pages through
+/// them, and that deprecated games refuse new sessions but still settle.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::{GameStatus, RakeRoute};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_games_listed_with_config_and_counts() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    let developer = Address::generate(&env);
+    blendizzard.add_game(&game, &developer);
+    blendizzard.set_game_wager_limits(&game, &1_0000000, &50_0000000);
+    blendizzard.set_game_rake(&game, &500, &RakeRoute::Treasury);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &5_0000000);
+    blendizzard.start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.end_game(&1, &true);
+
+    let games = blendizzard.get_games(&0, &10);
+    assert_eq!(games.len(), 1);
+    let listing = games.get(0).unwrap();
+    assert_eq!(listing.game_id, game);
+    assert_eq!(listing.developer, developer);
+    assert_eq!(listing.rake_bps, 500);
+    assert_eq!(listing.rake_route, RakeRoute::Treasury);
+    assert_eq!(listing.min_wager, 1_0000000);
+    assert_eq!(listing.max_wager, 50_0000000);
+    assert_eq!(listing.status, GameStatus::Active);
+    assert_eq!(listing.sessions_started, 2);
+    assert_eq!(listing.sessions_finished, 1);
+    // The only game with finished sessions has all the weight
+    assert_eq!(listing.weight_bps, 10_000);
+}
+
+#[test]
+fn test_game_weight_is_share_of_wagered_fp() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    let other_game = Address::generate(&env);
+    blendizzard.add_game(&other_game, &Address::generate(&env));
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &3_0000000, &3_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&other_game, &2, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.end_game(&2, &false);
+
+    let games = blendizzard.get_games(&0, &10);
+    assert_eq!(games.get(0).unwrap().weight_bps, 7_500);
+    assert_eq!(games.get(1).unwrap().weight_bps, 2_500);
+}
+
+#[test]
+fn test_games_paged_and_removed_games_dropped() {
+    let env = setup_test_env();
+    let (blendizzard, game, _, _) = setup_players(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    blendizzard.add_game(&second, &Address::generate(&env));
+    blendizzard.add_game(&third, &Address::generate(&env));
+    // Updating a registration doesn't list the game twice
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let page = blendizzard.get_games(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().game_id, second);
+    assert_eq!(blendizzard.get_games(&0, &10).len(), 3);
+
+    blendizzard.remove_game(&second);
+    let games = blendizzard.get_games(&0, &10);
+    assert_eq!(games.len(), 2);
+    assert_eq!(games.get(0).unwrap().game_id, game);
+    assert_eq!(games.get(1).unwrap().game_id, third);
+}
+
+#[test]
+fn test_deprecated_game_refuses_new_sessions() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.set_game_deprecated(&game, &true);
+    assert_eq!(
+        blendizzard.get_games(&0, &1).get(0).unwrap().status,
+        GameStatus::Deprecated
+    );
+
+    let result = blendizzard.try_start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+    assert_contract_error(&result, Error::GameNotWhitelisted);
+
+    // In-flight sessions still settle
+    blendizzard.end_game(&1, &true);
+    assert_eq!(
+        blendizzard
+            .get_games(&0, &1)
+            .get(0)
+            .unwrap()
+            .sessions_finished,
+        1
+    );
+
+    blendizzard.set_game_deprecated(&game, &false);
+    blendizzard.start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+    assert_eq!(
+        blendizzard.get_games(&0, &1).get(0).unwrap().status,
+        GameStatus::Active
+    );
+}
+
+#[test]
+fn test_deprecate_unregistered_game_fails() {
+    let env = setup_test_env();
+    let (blendizzard, _, _, _) = setup_players(&env);
+
+    let result = blendizzard.try_set_game_deprecated(&Address::generate(&env), &true);
+    assert_contract_error(&result, Error::GameNotWhitelisted);
+}
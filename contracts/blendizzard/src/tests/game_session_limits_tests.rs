@@ -0,0 +1,106 @@
+/// Game Session Limits Tests
+///
+/// Tests the per-game caps on concurrently active sessions and on sessions
+/// started per ledger enforced by `start_game`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 1_0000000;
+
+/// Create Blendizzard with a registered game and four funded players
+fn setup<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, [Address; 4]) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let players = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (i, player) in players.iter().enumerate() {
+        vault.set_user_balance(player, &100_0000000);
+        blendizzard.select_faction(player, &(i as u32 % 2));
+    }
+
+    (blendizzard, game, players)
+}
+
+#[test]
+fn test_active_session_cap_frees_up_when_sessions_end() {
+    let env = setup_test_env();
+    let (blendizzard, game, [p1, p2, p3, p4]) = setup(&env);
+    blendizzard.set_game_session_limits(&game, &2, &0);
+
+    let info = blendizzard.get_game_info(&game);
+    assert_eq!(info.max_active_sessions, 2);
+    assert_eq!(info.max_sessions_per_ledger, 0);
+
+    blendizzard.start_game(&game, &1, &p1, &p2, &WAGER, &WAGER);
+    blendizzard.start_game(&game, &2, &p3, &p4, &WAGER, &WAGER);
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &3, &p1, &p4, &WAGER, &WAGER),
+        Error::TooManyActiveSessions,
+    );
+
+    // Another game's sessions don't count
+    let other_game = Address::generate(&env);
+    blendizzard.add_game(&other_game, &Address::generate(&env));
+    blendizzard.start_game(&other_game, &4, &p1, &p4, &WAGER, &WAGER);
+
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &3, &p1, &p4, &WAGER, &WAGER);
+}
+
+#[test]
+fn test_sessions_per_ledger_cap_resets_each_ledger() {
+    let env = setup_test_env();
+    let (blendizzard, game, [p1, p2, p3, p4]) = setup(&env);
+    blendizzard.set_game_session_limits(&game, &0, &2);
+
+    blendizzard.start_game(&game, &1, &p1, &p2, &WAGER, &WAGER);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &p3, &p4, &WAGER, &WAGER);
+    // Ending sessions doesn't lift the per-ledger cap
+    blendizzard.end_game(&2, &true);
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &3, &p1, &p2, &WAGER, &WAGER),
+        Error::TooManyActiveSessions,
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    blendizzard.start_game(&game, &3, &p1, &p2, &WAGER, &WAGER);
+
+    // Removing the limits lifts the cap within the ledger
+    blendizzard.set_game_session_limits(&game, &0, &0);
+    blendizzard.start_game(&game, &4, &p3, &p4, &WAGER, &WAGER);
+    blendizzard.start_game(&game, &5, &p1, &p4, &WAGER, &WAGER);
+}
+
+#[test]
+fn test_set_game_session_limits_requires_registered_game() {
+    let env = setup_test_env();
+    let (blendizzard, _game, _players) = setup(&env);
+
+    assert_contract_error(
+        &blendizzard.try_set_game_session_limits(&Address::generate(&env), &1, &1),
+        Error::GameNotWhitelisted,
+    );
+}
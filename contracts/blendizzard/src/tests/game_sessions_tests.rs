@@ -0,0 +1,144 @@
+/// Game Sessions Tests
+///
+/// Tests that `get_sessions` lists a game's unsettled 1v1 sessions with their
+/// start ledger and outcome state, pages through them and drops them once
+/// they settle.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::create_token;
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::types::SessionState;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_sessions_listed_until_settled() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    assert_eq!(blendizzard.get_sessions(&game, &0, &10).len(), 0);
+
+    let start_ledger = env.ledger().sequence();
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &5_0000000);
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    blendizzard.start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+
+    let sessions = blendizzard.get_sessions(&game, &0, &10);
+    assert_eq!(sessions.len(), 2);
+    let first = sessions.get(0).unwrap();
+    assert_eq!(first.session_id, 1);
+    assert_eq!(first.session.player1, player1);
+    assert_eq!(first.session.player2, player2);
+    assert_eq!(first.session.player1_wager, 10_0000000);
+    assert_eq!(first.session.player2_wager, 5_0000000);
+    assert_eq!(first.start_ledger, start_ledger);
+    assert_eq!(first.state, SessionState::Active);
+    assert_eq!(sessions.get(1).unwrap().start_ledger, start_ledger + 10);
+
+    // Other games don't see the sessions
+    assert_eq!(
+        blendizzard
+            .get_sessions(&Address::generate(&env), &0, &10)
+            .len(),
+        0
+    );
+
+    blendizzard.end_game(&1, &true);
+    let sessions = blendizzard.get_sessions(&game, &0, &10);
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions.get(0).unwrap().session_id, 2);
+}
+
+#[test]
+fn test_sessions_paginate() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    for session_id in 1..=5 {
+        blendizzard.start_game(
+            &game,
+            &session_id,
+            &player1,
+            &player2,
+            &1_0000000,
+            &1_0000000,
+        );
+    }
+
+    let page = blendizzard.get_sessions(&game, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().session_id, 2);
+    assert_eq!(page.get(1).unwrap().session_id, 3);
+
+    assert_eq!(blendizzard.get_sessions(&game, &4, &10).len(), 1);
+    assert_eq!(blendizzard.get_sessions(&game, &5, &10).len(), 0);
+}
+
+#[test]
+fn test_sessions_report_provisional_and_disputed_results() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let usdc = create_token(&env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &usdc.address,
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.set_game_dispute_window(&game, &100);
+    blendizzard.set_arbiter(&Some(Address::generate(&env)), &50_0000000);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    usdc.mint(&player2, &50_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    // A provisional result stays listed until the window closes
+    blendizzard.end_game(&1, &true);
+    let session = blendizzard.get_sessions(&game, &0, &10).get(0).unwrap();
+    assert_eq!(session.state, SessionState::Provisional);
+
+    blendizzard.dispute(&player2, &1);
+    let session = blendizzard.get_sessions(&game, &0, &10).get(0).unwrap();
+    assert_eq!(session.state, SessionState::Disputed);
+}
@@ -0,0 +1,143 @@
+/// Governance Tests
+///
+/// Tests stake-weighted votes on whitelisted next-epoch parameters: option
+/// validation, one vote per parameter and epoch, and the winning values
+/// applying when the epoch cycles.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::types::GovernanceParameter;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{map, vec, Address, Env};
+
+/// Create Blendizzard with two players who played this epoch: player1 (faction 0)
+/// staking 100 USDC and player2 (faction 1) staking 50 USDC
+fn setup_voters<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &50_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&1, &true);
+
+    (blendizzard, player1, player2)
+}
+
+#[test]
+fn test_set_governance_options_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _player1, _player2) = setup_voters(&env);
+    let parameter = GovernanceParameter::DevRewardShare;
+    assert_eq!(blendizzard.get_governance_options(&parameter).len(), 0);
+
+    for options in [
+        vec![&env, 1_000_000, 1_000_000],
+        vec![&env, 10_000_001],
+        vec![&env, -1],
+        vec![&env, 0, 1, 2, 3, 4, 5],
+    ] {
+        assert_contract_error(
+            &blendizzard.try_set_governance_options(&parameter, &options),
+            Error::InvalidConfig,
+        );
+    }
+    assert_contract_error(
+        &blendizzard
+            .try_set_governance_options(&GovernanceParameter::EpochDuration, &vec![&env, 0]),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.set_governance_options(&parameter, &vec![&env, 0, 1_000_000]);
+    assert_eq!(
+        blendizzard.get_governance_options(&parameter),
+        vec![&env, 0, 1_000_000]
+    );
+}
+
+#[test]
+fn test_votes_are_stake_weighted_once_per_epoch() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2) = setup_voters(&env);
+    let parameter = GovernanceParameter::UnderdogBonus;
+    blendizzard.set_governance_options(&parameter, &vec![&env, 0, 2_500_000]);
+
+    assert_contract_error(
+        &blendizzard.try_vote_parameter(&player1, &parameter, &1_000_000),
+        ExtError::ParameterOptionNotOffered,
+    );
+    assert_contract_error(
+        &blendizzard.try_vote_parameter(&Address::generate(&env), &parameter, &0),
+        Error::PlayerNotFound,
+    );
+
+    assert_eq!(
+        blendizzard.vote_parameter(&player1, &parameter, &2_500_000),
+        100_0000000
+    );
+    assert_eq!(
+        blendizzard.vote_parameter(&player2, &parameter, &0),
+        50_0000000
+    );
+    assert_contract_error(
+        &blendizzard.try_vote_parameter(&player1, &parameter, &0),
+        ExtError::ParameterAlreadyVoted,
+    );
+
+    assert_eq!(
+        blendizzard.get_parameter_votes(&parameter, &0),
+        map![&env, (0, 50_0000000), (2_500_000, 100_0000000)]
+    );
+}
+
+#[test]
+fn test_winning_values_apply_when_epoch_cycles() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2) = setup_voters(&env);
+    blendizzard.set_governance_options(
+        &GovernanceParameter::EpochDuration,
+        &vec![&env, 345_600, 172_800],
+    );
+    blendizzard.set_governance_options(
+        &GovernanceParameter::UnderdogBonus,
+        &vec![&env, 0, 2_500_000],
+    );
+    blendizzard
+        .set_governance_options(&GovernanceParameter::DevRewardShare, &vec![&env, 2_000_000]);
+
+    // The larger stake wins the duration vote
+    blendizzard.vote_parameter(&player1, &GovernanceParameter::EpochDuration, &172_800);
+    blendizzard.vote_parameter(&player2, &GovernanceParameter::EpochDuration, &345_600);
+    blendizzard.vote_parameter(&player2, &GovernanceParameter::UnderdogBonus, &2_500_000);
+    blendizzard.vote_parameter(&player2, &GovernanceParameter::DevRewardShare, &2_000_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+
+    let config = blendizzard.get_config();
+    assert_eq!(config.epoch_duration, 172_800);
+    assert_eq!(config.next_epoch_duration, None);
+    assert_eq!(config.max_underdog_bonus, 2_500_000);
+    assert_eq!(config.dev_reward_share, 2_000_000);
+    assert_eq!(blendizzard.get_epoch(&1).duration, 172_800);
+}
@@ -0,0 +1,31 @@
+/// Harvest Tests
+///
+/// Tests mid-epoch `harvest`, which swaps yield into a pending-reward buffer
+/// that the next `cycle_epoch` folds into the reward pool.
+use super::testutils::{create_blendizzard_with_soroswap, setup_test_env};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+#[test]
+fn test_harvest_is_folded_into_next_cycle() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    // Mid-epoch: swap the contract's BLND without finalizing the epoch
+    let harvested = blendizzard.harvest();
+    assert!(harvested > 0);
+    assert_eq!(blendizzard.get_pending_rewards(), harvested);
+    assert_eq!(blendizzard.get_current_epoch(), 0);
+
+    // Nothing left to swap: a second harvest adds nothing
+    assert_eq!(blendizzard.harvest(), 0);
+    assert_eq!(blendizzard.get_pending_rewards(), harvested);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(epoch0.reward_pool + epoch0.dev_reward_pool, harvested);
+    assert_eq!(blendizzard.get_pending_rewards(), 0);
+}
@@ -0,0 +1,70 @@
+/// Health Check Tests
+///
+/// Tests the indicators returned by `health`: a funded deployment is healthy,
+/// pause, unreachable contracts and missing core entries are reported, and
+/// ledgers since the last cycle follow the ledger.
+use super::testutils::{create_blendizzard_with_soroswap, create_test_blendizzard, setup_test_env};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+#[test]
+fn test_funded_deployment_is_healthy() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    let health = blendizzard.health();
+    assert!(health.healthy);
+    assert!(health.vault_balanced);
+    assert!(health.core_entries_live);
+    assert!(!health.paused && !health.in_flight && !health.rewards_unprocessed);
+    assert!(health.swap_reserves.0 > 0 && health.swap_reserves.1 > 0);
+    assert_eq!(health.ledgers_since_cycle, Some(0));
+    assert_eq!(health.cycle_overdue, 0);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 50;
+        li.timestamp += 345_600 + 60;
+    });
+    let health = blendizzard.health();
+    assert_eq!(health.ledgers_since_cycle, Some(50));
+    assert_eq!(health.cycle_overdue, 60);
+
+    blendizzard.cycle_epoch();
+    assert_eq!(blendizzard.health().ledgers_since_cycle, Some(0));
+
+    blendizzard.pause();
+    let health = blendizzard.health();
+    assert!(health.paused);
+    assert!(!health.healthy);
+}
+
+#[test]
+fn test_unreachable_contracts_reported() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    // Vault and router addresses without contracts
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let health = blendizzard.health();
+    assert!(!health.healthy);
+    assert!(!health.vault_balanced);
+    assert_eq!(health.swap_reserves, (0, 0));
+    assert!(health.core_entries_live);
+}
+
+#[test]
+fn test_missing_epoch_entry_reported() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    env.as_contract(&blendizzard.address, || {
+        env.storage()
+            .temporary()
+            .remove(&crate::storage::DataKey::Epoch(0));
+    });
+    let health = blendizzard.health();
+    assert!(!health.core_entries_live);
+    assert!(!health.healthy);
+}
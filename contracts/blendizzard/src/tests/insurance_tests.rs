@@ -0,0 +1,50 @@
+/// Insurance Fund Tests
+///
+/// Tests the insurance fund settings and view, and that `cover_shortfall` is
+/// admin-only and refuses to run without a DirectPool backend. Funding from
+/// rakes is covered in `rake_tests.rs` and covering a real shortfall in
+/// `direct_pool_tests.rs`.
+use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
+use crate::types::InsuranceFund;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_insurance_fund_defaults_to_empty() {
+    let env = setup_test_env();
+    let blendizzard = create_test_blendizzard(&env, &Address::generate(&env));
+
+    assert_eq!(
+        blendizzard.get_insurance_fund(),
+        InsuranceFund {
+            bps: 0,
+            balance: 0,
+            total_covered: 0,
+        }
+    );
+}
+
+#[test]
+fn test_set_insurance_bps() {
+    let env = setup_test_env();
+    let blendizzard = create_test_blendizzard(&env, &Address::generate(&env));
+
+    blendizzard.set_insurance_bps(&250);
+    assert_eq!(blendizzard.get_insurance_fund().bps, 250);
+
+    blendizzard.set_insurance_bps(&10_000);
+    assert_eq!(blendizzard.get_insurance_fund().bps, 10_000);
+
+    let result = blendizzard.try_set_insurance_bps(&10_001);
+    assert_contract_error(&result, Error::InvalidConfig);
+    assert_eq!(blendizzard.get_insurance_fund().bps, 10_000);
+}
+
+#[test]
+fn test_cover_shortfall_requires_direct_pool() {
+    let env = setup_test_env();
+    let blendizzard = create_test_blendizzard(&env, &Address::generate(&env));
+
+    let result = blendizzard.try_cover_shortfall();
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+}
@@ -0,0 +1,156 @@
+/// Invariant Check Tests
+///
+/// The invariant checks only run when built with `--features strict-invariants`.
+/// Without the feature they must be no-ops, even on corrupted state.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::types::EpochPlayer;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+/// Store an epoch player whose contribution exceeds its faction's standing
+fn corrupt_epoch_player(env: &soroban_sdk::Env, contract: &Address, player: &Address) {
+    env.as_contract(contract, || {
+        crate::storage::set_epoch_player(
+            env,
+            0,
+            player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 0,
+                available_fp: 0,
+                total_fp_contributed: 500_0000000,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+}
+
+#[test]
+fn test_game_flow_satisfies_invariants() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // start_game and end_game run the checks when the feature is enabled
+    blendizzard.start_game(&game, &1, &player1, &player2, &100_0000000, &50_0000000);
+    blendizzard.end_game(&1, &true);
+
+    env.as_contract(&blendizzard.address, || {
+        crate::invariants::check_epoch(&env, 0);
+        crate::invariants::check_epoch_player(&env, 0, &player1);
+        crate::invariants::check_epoch_player(&env, 0, &player2);
+    });
+}
+
+#[test]
+fn test_practice_game_satisfies_invariants() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.set_game_practice(&game, &Some(5000000));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // Zero wagers and a practice_fp contribution must pass the strict checks
+    blendizzard.start_game(&game, &1, &player1, &player2, &0, &0);
+    blendizzard.end_game(&1, &true);
+
+    env.as_contract(&blendizzard.address, || {
+        crate::invariants::check_session(&env, 1);
+        crate::invariants::check_epoch(&env, 0);
+        crate::invariants::check_epoch_player(&env, 0, &player1);
+        crate::invariants::check_epoch_player(&env, 0, &player2);
+    });
+}
+
+#[test]
+#[cfg(not(feature = "strict-invariants"))]
+fn test_invariant_checks_are_noops_without_feature() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let blendizzard = super::testutils::create_test_blendizzard(&env, &admin);
+
+    corrupt_epoch_player(&env, &blendizzard.address, &player);
+
+    env.as_contract(&blendizzard.address, || {
+        crate::invariants::check_epoch_player(&env, 0, &player);
+        crate::storage::set_pool_b_tokens(&env, &player, 100_0000000);
+        crate::invariants::check_player(&env, &player);
+    });
+}
+
+#[test]
+#[cfg(feature = "strict-invariants")]
+#[should_panic(expected = "Error(Contract, #90)")]
+fn test_contribution_above_standing_violates_invariant() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let blendizzard = super::testutils::create_test_blendizzard(&env, &admin);
+
+    corrupt_epoch_player(&env, &blendizzard.address, &player);
+
+    env.as_contract(&blendizzard.address, || {
+        crate::invariants::check_epoch_player(&env, 0, &player);
+    });
+}
+
+#[test]
+#[cfg(feature = "strict-invariants")]
+#[should_panic(expected = "Error(Contract, #90)")]
+fn test_credit_above_tracked_total_violates_invariant() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let blendizzard = super::testutils::create_test_blendizzard(&env, &admin);
+
+    // A player credited with b-tokens missing from the tracked sum
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pool_b_tokens(&env, &player, 100_0000000);
+        crate::invariants::check_player(&env, &player);
+    });
+}
@@ -0,0 +1,139 @@
+/// Jackpot Tests
+///
+/// Tests that the jackpot takes its share of each epoch's player reward pool,
+/// pays out to the winning faction every `interval` epochs and rolls over
+/// when nobody in the winning faction played.
+use super::fee_vault_utils::MockVaultClient;
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::types::Jackpot;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+/// Create Blendizzard with real Soroswap, a registered game and two funded
+/// players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_with_soroswap(env, &admin);
+    let vault = MockVaultClient::new(env, &blendizzard.get_config().fee_vault);
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+/// Fund the next swap with BLND and cycle once the epoch has ended
+fn fund_and_cycle(env: &Env, blendizzard: &BlendizzardClient) {
+    TokenClient::new(env, &blendizzard.get_config().blnd_token)
+        .mint(&blendizzard.address, &5000_0000000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+    blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_jackpot_accrues_and_pays_every_interval() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    blendizzard.set_jackpot_settings(&500, &2);
+
+    // Epoch 0 funds the jackpot with 5% of its player reward pool
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+    fund_and_cycle(&env, &blendizzard);
+
+    let jackpot = blendizzard.get_jackpot().balance;
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(jackpot > 0);
+    assert_eq!(jackpot, (epoch0.reward_pool + jackpot) * 500 / 10_000);
+
+    // Epoch 1 is a payout epoch: the whole jackpot joins its reward pool
+    blendizzard.start_game(&game, &2, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &false);
+    fund_and_cycle(&env, &blendizzard);
+
+    assert_eq!(
+        blendizzard.get_jackpot(),
+        Jackpot {
+            bps: 500,
+            interval: 2,
+            balance: 0,
+        }
+    );
+    let epoch1 = blendizzard.get_epoch(&1);
+    assert_eq!(epoch1.winning_faction, Some(1));
+    assert!(epoch1.reward_pool > jackpot);
+
+    // The winner of the payout epoch claims it with the regular rewards
+    let claimed = blendizzard.claim_epoch_reward(&player2, &1);
+    assert_eq!(claimed, epoch1.reward_pool);
+}
+
+#[test]
+fn test_jackpot_rolls_over_when_winner_did_not_play() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    blendizzard.set_jackpot_settings(&1_000, &1);
+
+    // Nobody played epoch 0: the jackpot keeps its share
+    fund_and_cycle(&env, &blendizzard);
+    let rolled_over = blendizzard.get_jackpot().balance;
+    assert!(rolled_over > 0);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+    fund_and_cycle(&env, &blendizzard);
+
+    assert_eq!(blendizzard.get_jackpot().balance, 0);
+    assert!(blendizzard.get_epoch(&1).reward_pool > rolled_over);
+}
+
+#[test]
+fn test_set_jackpot_settings_validates_and_keeps_balance() {
+    let env = setup_test_env();
+    let (blendizzard, _game, _player1, _player2) = setup_players(&env);
+
+    assert_eq!(
+        blendizzard.get_jackpot(),
+        Jackpot {
+            bps: 0,
+            interval: 10,
+            balance: 0,
+        }
+    );
+    assert_contract_error(
+        &blendizzard.try_set_jackpot_settings(&1_001, &10),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_jackpot_settings(&500, &0),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.set_jackpot_settings(&1_000, &10);
+    fund_and_cycle(&env, &blendizzard);
+    let balance = blendizzard.get_jackpot().balance;
+    assert!(balance > 0);
+
+    // Stopping accrual keeps what was accumulated for the next payout
+    blendizzard.set_jackpot_settings(&0, &3);
+    assert_eq!(
+        blendizzard.get_jackpot(),
+        Jackpot {
+            bps: 0,
+            interval: 3,
+            balance,
+        }
+    );
+}
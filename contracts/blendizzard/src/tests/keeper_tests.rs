@@ -0,0 +1,122 @@
+/// Bonded Keeper Tests
+///
+/// Tests the keeper priority window on epoch cycling, the keeper bounty, and
+/// bond registration, slashing and withdrawal.
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error, ExtError,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+const MIN_BOND: i128 = 100_0000000;
+const WINDOW: u64 = 3600;
+const BOUNTY: i128 = 1_0000000;
+
+/// Create Blendizzard with the keeper registry enabled and a bonded keeper
+fn setup(env: &Env) -> (BlendizzardClient<'_>, TokenClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_with_soroswap(env, &admin);
+    let usdc = TokenClient::new(env, &blendizzard.get_config().usdc_token);
+
+    blendizzard.set_keeper_settings(&MIN_BOND, &WINDOW, &BOUNTY);
+
+    let keeper = Address::generate(env);
+    usdc.mint(&keeper, &MIN_BOND);
+    blendizzard.register_keeper(&keeper, &MIN_BOND);
+
+    (blendizzard, usdc, keeper)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_public_cycle_waits_for_priority_window() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, keeper) = setup(&env);
+
+    advance(&env, 345_600);
+    assert_contract_error(
+        &blendizzard.try_cycle_epoch(),
+        ExtError::KeeperPriorityWindow,
+    );
+
+    // Bonded keepers may cycle inside the window and earn the bounty
+    assert_eq!(blendizzard.keeper_cycle_epoch(&keeper), 1);
+    assert_eq!(usdc.balance(&keeper), BOUNTY);
+    assert!(blendizzard.get_epoch(&0).reward_pool > 0);
+    assert_eq!(blendizzard.get_keeper(&keeper).unwrap().unlock_epoch, 2);
+
+    // Past the window the next cycle is open to everyone, without a bounty
+    advance(&env, 345_600 + WINDOW);
+    assert_eq!(blendizzard.cycle_epoch(), 2);
+    assert_eq!(usdc.balance(&keeper), BOUNTY);
+}
+
+#[test]
+fn test_register_keeper_requires_registry_and_min_bond() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let usdc = TokenClient::new(&env, &blendizzard.get_config().usdc_token);
+    let keeper = Address::generate(&env);
+    usdc.mint(&keeper, &MIN_BOND);
+
+    assert_contract_error(
+        &blendizzard.try_register_keeper(&keeper, &MIN_BOND),
+        Error::FeatureNotConfigured,
+    );
+
+    blendizzard.set_keeper_settings(&MIN_BOND, &WINDOW, &BOUNTY);
+    assert_contract_error(
+        &blendizzard.try_register_keeper(&keeper, &(MIN_BOND - 1)),
+        ExtError::KeeperBondTooLow,
+    );
+
+    // Unregistered addresses can't use the priority window
+    advance(&env, 345_600);
+    assert_contract_error(
+        &blendizzard.try_keeper_cycle_epoch(&keeper),
+        ExtError::KeeperNotRegistered,
+    );
+}
+
+#[test]
+fn test_slashed_keeper_loses_priority_and_bond_unlocks_later() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, keeper) = setup(&env);
+
+    advance(&env, 345_600);
+    blendizzard.keeper_cycle_epoch(&keeper);
+
+    // Bond is locked for a full epoch after the cycle
+    assert_contract_error(
+        &blendizzard.try_unregister_keeper(&keeper),
+        ExtError::KeeperBondLocked,
+    );
+
+    // Slashed USDC goes to the next reward pool
+    assert_eq!(blendizzard.slash_keeper(&keeper, &40_0000000), 60_0000000);
+    assert_eq!(blendizzard.get_pending_rewards(), 40_0000000);
+    assert_eq!(blendizzard.get_keeper_registry().total_bonded, 60_0000000);
+    assert_contract_error(
+        &blendizzard.try_slash_keeper(&keeper, &(60_0000000 + 1)),
+        ExtError::SlashExceedsBond,
+    );
+
+    // Below the minimum bond the keeper can't cycle
+    advance(&env, 345_600);
+    assert_contract_error(
+        &blendizzard.try_keeper_cycle_epoch(&keeper),
+        ExtError::KeeperBondTooLow,
+    );
+    advance(&env, WINDOW);
+    blendizzard.cycle_epoch();
+
+    assert_eq!(blendizzard.unregister_keeper(&keeper), 60_0000000);
+    assert_eq!(usdc.balance(&keeper), BOUNTY + 60_0000000);
+    assert_eq!(blendizzard.get_keeper(&keeper), None);
+}
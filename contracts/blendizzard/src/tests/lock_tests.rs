@@ -0,0 +1,207 @@
+/// Deposit Lock Tests
+///
+/// Tests that `deposit_with_lock` blocks withdrawals until the lock expires,
+/// that relocking only extends a running lock, and that a running lock scales
+/// the winner's contribution at settlement (DirectPool only).
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::types::{DepositLock, LockTier, VaultBackend};
+use crate::BlendizzardClient;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard using the DirectPool backend on a fresh Blend pool
+fn setup_direct_pool<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockTokenClient<'a>) {
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(env, &xlm);
+    let pool = create_blend_pool(env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+
+    (blendizzard, usdc_client)
+}
+
+/// Jump to a later epoch without cycling
+fn set_current_epoch(env: &Env, blendizzard: &BlendizzardClient, epoch: u32) {
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_current_epoch(env, epoch);
+    });
+}
+
+#[test]
+fn test_lock_blocks_withdrawal_until_expiry() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+
+    blendizzard.deposit_with_lock(&user, &100_0000000, &LockTier::OneEpoch);
+    assert_eq!(
+        blendizzard.get_deposit_lock(&user),
+        Some(DepositLock {
+            tier: LockTier::OneEpoch,
+            unlock_epoch: 1,
+        })
+    );
+
+    let result = blendizzard.try_withdraw(&user, &10_0000000);
+    assert_contract_error(&result, ExtError::DepositLocked);
+
+    set_current_epoch(&env, &blendizzard, 1);
+    assert_eq!(blendizzard.get_deposit_lock(&user), None);
+    blendizzard.withdraw(&user, &10_0000000);
+    assert_eq!(usdc.balance(&user), 10_0000000);
+}
+
+#[test]
+fn test_relocking_never_shortens_lock() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+    let user = Address::generate(&env);
+    usdc.mint(&user, &100_0000000);
+
+    blendizzard.deposit_with_lock(&user, &50_0000000, &LockTier::FourEpochs);
+    blendizzard.deposit_with_lock(&user, &25_0000000, &LockTier::OneEpoch);
+    assert_eq!(
+        blendizzard.get_deposit_lock(&user),
+        Some(DepositLock {
+            tier: LockTier::FourEpochs,
+            unlock_epoch: 4,
+        })
+    );
+
+    // A plain deposit keeps the running lock
+    blendizzard.deposit_with_lock(&user, &25_0000000, &LockTier::None);
+    set_current_epoch(&env, &blendizzard, 3);
+    assert_eq!(blendizzard.get_deposit_lock(&user).unwrap().unlock_epoch, 4);
+
+    // Relocking after expiry starts a new lock from the current epoch
+    set_current_epoch(&env, &blendizzard, 4);
+    blendizzard.withdraw(&user, &1_0000000);
+    usdc.mint(&user, &1_0000000);
+    blendizzard.deposit_with_lock(&user, &1_0000000, &LockTier::OneEpoch);
+    assert_eq!(
+        blendizzard.get_deposit_lock(&user),
+        Some(DepositLock {
+            tier: LockTier::OneEpoch,
+            unlock_epoch: 5,
+        })
+    );
+}
+
+#[test]
+fn test_lock_requires_direct_pool() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &create_mock_vault(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+
+    let result = blendizzard.try_deposit_with_lock(
+        &Address::generate(&env),
+        &1_0000000,
+        &LockTier::OneEpoch,
+    );
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+}
+
+#[test]
+fn test_lock_multiplier_scales_winning_contribution() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup_direct_pool(&env);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    usdc.mint(&winner, &1000_0000000);
+    usdc.mint(&loser, &1000_0000000);
+    blendizzard.deposit_with_lock(&winner, &1000_0000000, &LockTier::FourEpochs);
+    blendizzard.deposit(&loser, &1000_0000000);
+    blendizzard.select_faction(&winner, &0);
+    blendizzard.select_faction(&loser, &1);
+
+    let wager = 10_0000000;
+    blendizzard.start_game(&game, &1, &winner, &loser, &wager, &wager);
+    blendizzard.end_game(&1, &true);
+
+    let standing = blendizzard.get_epoch(&0).faction_standings.get(0).unwrap();
+    assert_eq!(standing, wager * 125 / 100);
+}
+
+#[test]
+fn test_lock_ignored_without_direct_pool() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    vault.set_user_balance(&winner, &1000_0000000);
+    vault.set_user_balance(&loser, &1000_0000000);
+    blendizzard.select_faction(&winner, &0);
+    blendizzard.select_faction(&loser, &1);
+
+    // A lock left from before a switch to FeeVault can't be enforced,
+    // so it doesn't boost contributions either
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_deposit_lock(
+            &env,
+            &winner,
+            &DepositLock {
+                tier: LockTier::FourEpochs,
+                unlock_epoch: 4,
+            },
+        );
+    });
+    assert_eq!(blendizzard.get_deposit_lock(&winner), None);
+
+    let wager = 10_0000000;
+    blendizzard.start_game(&game, &1, &winner, &loser, &wager, &wager);
+    blendizzard.end_game(&1, &true);
+
+    let standing = blendizzard.get_epoch(&0).faction_standings.get(0).unwrap();
+    assert_eq!(standing, wager);
+}
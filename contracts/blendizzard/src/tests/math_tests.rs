@@ -0,0 +1,99 @@
+/// Shared Math Helper Tests
+///
+/// Unit tests for the checked fixed-point helpers in `math` (mul_div with
+/// rounding direction, basis points and 7-decimal factors).
+use crate::math::{checked_bps, checked_mul_div, checked_mul_scalar_7, Rounding, BPS_SCALE};
+use crate::types::SCALAR_7;
+
+#[test]
+fn test_mul_div_rounding() {
+    assert_eq!(checked_mul_div(10, 1, 3, Rounding::Floor), Some(3));
+    assert_eq!(checked_mul_div(10, 1, 3, Rounding::Ceil), Some(4));
+
+    // Exact results don't depend on the rounding direction
+    assert_eq!(checked_mul_div(9, 1, 3, Rounding::Floor), Some(3));
+    assert_eq!(checked_mul_div(9, 1, 3, Rounding::Ceil), Some(3));
+
+    // Negative results round towards -inf / +inf
+    assert_eq!(checked_mul_div(-10, 1, 3, Rounding::Floor), Some(-4));
+    assert_eq!(checked_mul_div(-10, 1, 3, Rounding::Ceil), Some(-3));
+
+    assert_eq!(checked_mul_div(0, 7, 3, Rounding::Ceil), Some(0));
+}
+
+#[test]
+fn test_mul_div_matches_reference() {
+    for x in 0..40i128 {
+        for y in 0..40i128 {
+            for denominator in 1..12i128 {
+                let product = x * y;
+                let floor = product / denominator;
+                let ceil = (product + denominator - 1) / denominator;
+                assert_eq!(
+                    checked_mul_div(x, y, denominator, Rounding::Floor),
+                    Some(floor)
+                );
+                assert_eq!(
+                    checked_mul_div(x, y, denominator, Rounding::Ceil),
+                    Some(ceil)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mul_div_rejects_zero_denominator_and_overflow() {
+    assert_eq!(checked_mul_div(1, 1, 0, Rounding::Floor), None);
+    assert_eq!(checked_mul_div(1, 1, 0, Rounding::Ceil), None);
+    assert_eq!(checked_mul_div(i128::MAX, 2, 1, Rounding::Floor), None);
+    assert_eq!(checked_mul_div(i128::MIN, 2, 1, Rounding::Ceil), None);
+
+    // Large operands are fine while the result fits
+    assert_eq!(
+        checked_mul_div(i128::MAX, 1, 1, Rounding::Floor),
+        Some(i128::MAX)
+    );
+}
+
+#[test]
+fn test_bps() {
+    assert_eq!(checked_bps(1_000_0000000, 0, Rounding::Floor), Some(0));
+    assert_eq!(
+        checked_bps(1_000_0000000, 2_500, Rounding::Floor),
+        Some(250_0000000)
+    );
+    assert_eq!(
+        checked_bps(1_000_0000000, BPS_SCALE as u32, Rounding::Floor),
+        Some(1_000_0000000)
+    );
+
+    // 1 bps of 9_999 stroops is 0.9999
+    assert_eq!(checked_bps(9_999, 1, Rounding::Floor), Some(0));
+    assert_eq!(checked_bps(9_999, 1, Rounding::Ceil), Some(1));
+
+    assert_eq!(checked_bps(i128::MAX, u32::MAX, Rounding::Floor), None);
+}
+
+#[test]
+fn test_mul_scalar_7() {
+    // 0.3 share and 1.5x multiplier
+    assert_eq!(
+        checked_mul_scalar_7(100_0000000, 3_000_000, Rounding::Floor),
+        Some(30_0000000)
+    );
+    assert_eq!(
+        checked_mul_scalar_7(100_0000000, 15_000_000, Rounding::Floor),
+        Some(150_0000000)
+    );
+    assert_eq!(checked_mul_scalar_7(7, SCALAR_7, Rounding::Floor), Some(7));
+
+    // 1/3 of a stroop
+    assert_eq!(checked_mul_scalar_7(1, 3_333_333, Rounding::Floor), Some(0));
+    assert_eq!(checked_mul_scalar_7(1, 3_333_333, Rounding::Ceil), Some(1));
+
+    assert_eq!(
+        checked_mul_scalar_7(i128::MAX, 2 * SCALAR_7, Rounding::Floor),
+        None
+    );
+}
@@ -0,0 +1,239 @@
+/// Merkle-Drop Distribution Tests
+///
+/// Tests the alternative finalization mode where the admin posts a Merkle root
+/// of (player, amount) leaves and players claim with proofs.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, unboosted_weights, Error,
+};
+use crate::merkle::{hash_pair, leaf_hash};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, Map};
+
+// ============================================================================
+// Test Setup Helpers
+// ============================================================================
+
+/// Create Blendizzard with a finalized epoch 0 holding a 1000 USDC reward pool
+fn setup_finalized_epoch<'a>(
+    env: &'a Env,
+) -> (BlendizzardClient<'a>, MockVaultClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        86400,
+        vec![env, 1],
+    );
+
+    let reward_pool = 1000_0000000i128;
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, 500_0000000);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: 500_0000000,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+    });
+
+    usdc.mint(&blendizzard.address, &reward_pool);
+
+    (blendizzard, vault, usdc)
+}
+
+/// Build a two-leaf tree and return (root, leaf_a, leaf_b)
+fn two_leaf_tree(
+    env: &Env,
+    a: &Address,
+    amount_a: i128,
+    b: &Address,
+    amount_b: i128,
+) -> (BytesN<32>, BytesN<32>, BytesN<32>) {
+    let leaf_a = leaf_hash(env, a, amount_a);
+    let leaf_b = leaf_hash(env, b, amount_b);
+    (hash_pair(env, &leaf_a, &leaf_b), leaf_a, leaf_b)
+}
+
+// ============================================================================
+// Merkle Claim Tests
+// ============================================================================
+
+#[test]
+fn test_merkle_claim_with_valid_proof() {
+    let env = setup_test_env();
+    let (blendizzard, vault, _usdc) = setup_finalized_epoch(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &10_0000000);
+    vault.set_user_balance(&player2, &10_0000000);
+
+    let (root, leaf1, leaf2) = two_leaf_tree(&env, &player1, 600_0000000, &player2, 400_0000000);
+    blendizzard.set_merkle_root(&0, &root);
+
+    let claimed1 = blendizzard.claim_merkle_reward(&player1, &0, &600_0000000, &vec![&env, leaf2]);
+    let claimed2 = blendizzard.claim_merkle_reward(&player2, &0, &400_0000000, &vec![&env, leaf1]);
+
+    assert_eq!(claimed1, 600_0000000);
+    assert_eq!(claimed2, 400_0000000);
+}
+
+#[test]
+fn test_merkle_claim_rejects_wrong_amount_and_double_claim() {
+    let env = setup_test_env();
+    let (blendizzard, vault, _usdc) = setup_finalized_epoch(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &10_0000000);
+
+    let (root, _leaf1, leaf2) = two_leaf_tree(&env, &player1, 600_0000000, &player2, 400_0000000);
+    blendizzard.set_merkle_root(&0, &root);
+
+    // Inflated amount doesn't match the leaf
+    let result =
+        blendizzard.try_claim_merkle_reward(&player1, &0, &900_0000000, &vec![&env, leaf2.clone()]);
+    assert_contract_error(&result, Error::InvalidMerkleProof);
+
+    blendizzard.claim_merkle_reward(&player1, &0, &600_0000000, &vec![&env, leaf2.clone()]);
+
+    let result =
+        blendizzard.try_claim_merkle_reward(&player1, &0, &600_0000000, &vec![&env, leaf2]);
+    assert_contract_error(&result, Error::RewardAlreadyClaimed);
+}
+
+#[test]
+fn test_merkle_root_disables_per_player_claim() {
+    let env = setup_test_env();
+    let (blendizzard, vault, _usdc) = setup_finalized_epoch(&env);
+
+    let player = Address::generate(&env);
+    vault.set_user_balance(&player, &10_0000000);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch_player(
+            &env,
+            0,
+            &player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: 500_0000000,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+
+    let root = leaf_hash(&env, &player, 1000_0000000);
+    blendizzard.set_merkle_root(&0, &root);
+
+    let result = blendizzard.try_claim_epoch_reward(&player, &0);
+    assert_contract_error(&result, Error::MerkleDistributionActive);
+
+    // Root is write-once
+    let result = blendizzard.try_set_merkle_root(&0, &root);
+    assert_contract_error(&result, Error::MerkleRootAlreadySet);
+}
+
+#[test]
+fn test_merkle_claims_capped_by_reward_pool() {
+    let env = setup_test_env();
+    let (blendizzard, vault, _usdc) = setup_finalized_epoch(&env);
+
+    let player = Address::generate(&env);
+    vault.set_user_balance(&player, &10_0000000);
+
+    // Single-leaf tree with an amount larger than the pool
+    let root = leaf_hash(&env, &player, 2000_0000000);
+    blendizzard.set_merkle_root(&0, &root);
+
+    let result = blendizzard.try_claim_merkle_reward(&player, &0, &2000_0000000, &vec![&env]);
+    assert_contract_error(&result, Error::NoRewardsAvailable);
+}
+
+#[test]
+fn test_merkle_root_accounts_for_onchain_claims() {
+    let env = setup_test_env();
+    let (blendizzard, vault, _usdc) = setup_finalized_epoch(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &10_0000000);
+    vault.set_user_balance(&player2, &10_0000000);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch_player(
+            &env,
+            0,
+            &player1,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: 250_0000000,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+
+    // Half the pool is paid on-chain before the root is posted
+    let claimed = blendizzard.claim_epoch_reward(&player1, &0);
+    assert_eq!(claimed, 500_0000000);
+
+    let (root, leaf1, leaf2) = two_leaf_tree(&env, &player1, 500_0000000, &player2, 600_0000000);
+    blendizzard.set_merkle_root(&0, &root);
+
+    // The on-chain claimer can't be paid again from the root
+    let result =
+        blendizzard.try_claim_merkle_reward(&player1, &0, &500_0000000, &vec![&env, leaf2]);
+    assert_contract_error(&result, Error::RewardAlreadyClaimed);
+
+    // Only the unclaimed half of the pool remains for the root
+    let result =
+        blendizzard.try_claim_merkle_reward(&player2, &0, &600_0000000, &vec![&env, leaf1]);
+    assert_contract_error(&result, Error::NoRewardsAvailable);
+}
+
+#[test]
+fn test_set_merkle_root_requires_finalized_epoch() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = super::testutils::create_test_blendizzard(&env, &admin);
+
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+    let result = blendizzard.try_set_merkle_root(&0, &root);
+    assert_contract_error(&result, Error::EpochNotFinalized);
+}
@@ -0,0 +1,73 @@
+/// Minimum FP Stake Tests
+///
+/// Tests the anti-sybil minimum stake: winners below it settle their games
+/// but credit no faction points.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_wins_below_min_stake_credit_no_faction_points() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+    assert_eq!(blendizzard.get_min_fp_stake(), 0);
+    blendizzard.set_min_fp_stake(&10_0000000);
+    assert_eq!(blendizzard.get_min_fp_stake(), 10_0000000);
+
+    let whale = Address::generate(&env);
+    let dust = Address::generate(&env);
+    vault.set_user_balance(&whale, &100_0000000);
+    vault.set_user_balance(&dust, &5_0000000);
+    blendizzard.select_faction(&whale, &0);
+    blendizzard.select_faction(&dust, &1);
+
+    // The dust account's win settles (both wagers are spent) but credits nothing
+    blendizzard.start_game(&game, &1, &whale, &dust, &1_0000000, &1_0000000);
+    blendizzard.end_game(&1, &false);
+    let dust_epoch = blendizzard.get_epoch_player(&0, &dust);
+    assert_eq!(dust_epoch.total_fp_contributed, 0);
+    assert_eq!(blendizzard.get_epoch(&0).faction_standings.get(1), Some(0));
+
+    blendizzard.start_game(&game, &2, &whale, &dust, &1_0000000, &1_0000000);
+    blendizzard.end_game(&2, &true);
+    let whale_epoch = blendizzard.get_epoch_player(&0, &whale);
+    assert!(whale_epoch.total_fp_contributed > 0);
+    assert_eq!(
+        blendizzard.get_epoch(&0).faction_standings.get(0),
+        Some(whale_epoch.total_fp_contributed)
+    );
+    assert!(blendizzard.get_epoch_player(&0, &dust).available_fp < dust_epoch.available_fp);
+}
+
+#[test]
+fn test_set_min_fp_stake_rejects_negative() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &create_mock_vault(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+
+    assert_contract_error(&blendizzard.try_set_min_fp_stake(&-1), Error::InvalidConfig);
+}
@@ -1,20 +1,106 @@
+mod abort_tests;
+mod achievements_tests;
+mod activity_tests;
+mod admin_log_tests;
+mod aggregator_tests;
+mod approvals_tests;
 mod blend_integration_tests;
-pub(crate) mod blend_utils;
+mod blnd_burn_tests;
+mod blnd_rewards_tests;
+mod boost_tests;
+mod carryover_tests;
+mod claim_all_tests;
+mod claim_history_tests;
+mod claimer_tests;
+mod commit_reveal_tests;
 mod cross_epoch_tests;
+mod custom_account_tests;
+mod cycle_budget_tests;
 mod dev_reward_tests;
+mod direct_pool_tests;
+mod dispute_tests;
+mod distribution_mode_tests;
 mod emissions_tests;
+mod epoch_clock_tests;
+mod epoch_duration_tests;
 mod epoch_edge_cases_tests;
-pub(crate) mod fee_vault_utils;
+mod epoch_phase_tests;
+mod epoch_schedule_tests;
+mod epoch_stages_tests;
+mod faction_cap_tests;
+mod faction_info_tests;
+mod faction_lock_tests;
+mod faction_totals_tests;
+mod forfeit_tests;
+mod fp_decay_tests;
 mod fp_edge_cases_tests;
 mod free_play_tests;
 mod game_expiration_tests;
 mod game_mechanics;
+mod game_registration_tests;
+mod game_registry_tests;
+mod game_session_limits_tests;
+mod game_sessions_tests;
+mod global_stats_tests;
+mod governance_tests;
+mod harvest_tests;
+mod health_tests;
+mod insurance_tests;
+mod invariants_tests;
+mod jackpot_tests;
+mod keeper_tests;
+mod lock_tests;
 mod math_rounding_tests;
+mod math_tests;
+mod merkle_tests;
+mod min_fp_stake_tests;
+mod mvp_tests;
 mod number_guess_integration;
+mod onboarding_tests;
+mod oracle_tests;
+mod pending_yield_tests;
+mod point_formula_tests;
+mod pool_status_tests;
+mod practice_tests;
+mod prediction_tests;
+mod protocol_treasury_tests;
+mod prune_tests;
+mod quests_tests;
+mod quote_tests;
+mod rake_tests;
+mod random_tests;
+mod rating_tests;
+mod reentrancy_tests;
+mod referral_tests;
+mod rescue_tests;
 mod reward_and_pause_tests;
+mod reward_cap_tests;
 mod reward_edge_cases_tests;
+mod reward_tokens_tests;
 mod reward_vault_deposit_simple_test;
 mod reward_vault_deposit_test;
+mod series_tests;
+mod session_metadata_tests;
+mod session_tests;
 mod smoke;
-pub(crate) mod soroswap_utils;
+mod snapshot_tests;
+mod stats_tests;
+mod storage_version_tests;
+mod streak_tests;
+mod swap_failure_tests;
+mod team_game_tests;
 pub(crate) mod testutils;
+mod treasury_tests;
+mod ttl_tests;
+mod underdog_tests;
+mod user_data_tests;
+mod vault_migration_tests;
+mod vesting_tests;
+mod whitelist_tests;
+mod win_odds_tests;
+mod xlm_deposit_tests;
+
+// Fixtures shared with downstream crates (see crate::testutils)
+pub(crate) use crate::testutils::{
+    blend_utils, custom_account_utils, fee_vault_utils, soroswap_utils,
+};
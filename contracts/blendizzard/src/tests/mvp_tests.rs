@@ -0,0 +1,108 @@
+/// Epoch MVP Tests
+///
+/// Tests that the winning faction's top contributor is paid the MVP bonus
+/// from the player reward pool and earns an "MVP of epoch N" title.
+use super::fee_vault_utils::MockVaultClient;
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::types::{Title, TitleKind};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with real Soroswap and a registered game, where player1
+/// out-contributes player3 in faction 0 and player2 plays for faction 1
+fn setup_epoch<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_with_soroswap(env, &admin);
+    let vault = MockVaultClient::new(env, &blendizzard.get_config().fee_vault);
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    let player3 = Address::generate(env);
+    for (player, faction) in [(&player1, 0), (&player2, 1), (&player3, 0)] {
+        vault.set_user_balance(player, &100_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &20_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &player3, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&2, &true);
+
+    (blendizzard, player1, player2, player3)
+}
+
+/// Fund the swap with BLND and cycle once the epoch has ended
+fn fund_and_cycle(env: &Env, blendizzard: &BlendizzardClient) {
+    TokenClient::new(env, &blendizzard.get_config().blnd_token)
+        .mint(&blendizzard.address, &5000_0000000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+    blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_mvp_paid_bonus_and_titled() {
+    let env = setup_test_env();
+    let (blendizzard, player1, _player2, player3) = setup_epoch(&env);
+    blendizzard.set_mvp_bonus(&500);
+    let usdc = TokenClient::new(&env, &blendizzard.get_config().usdc_token);
+
+    fund_and_cycle(&env, &blendizzard);
+
+    // 5% of the player reward pool goes straight to the MVP
+    let bonus = usdc.balance(&player1);
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(bonus > 0);
+    assert_eq!(bonus, (epoch0.reward_pool + bonus) * 500 / 10_000);
+
+    assert_eq!(
+        blendizzard.get_titles(&player1),
+        vec![
+            &env,
+            Title {
+                kind: TitleKind::EpochMvp,
+                epoch: 0,
+                faction: 0,
+                bonus,
+            }
+        ]
+    );
+    assert_eq!(blendizzard.get_titles(&player3).len(), 0);
+}
+
+#[test]
+fn test_mvp_title_without_bonus() {
+    let env = setup_test_env();
+    let (blendizzard, player1, player2, _player3) = setup_epoch(&env);
+    let usdc = TokenClient::new(&env, &blendizzard.get_config().usdc_token);
+
+    fund_and_cycle(&env, &blendizzard);
+
+    assert_eq!(usdc.balance(&player1), 0);
+    let titles = blendizzard.get_titles(&player1);
+    assert_eq!(titles.len(), 1);
+    assert_eq!(titles.get(0).unwrap().bonus, 0);
+    assert_eq!(blendizzard.get_titles(&player2).len(), 0);
+}
+
+#[test]
+fn test_set_mvp_bonus_validates() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_eq!(blendizzard.get_mvp_bonus(), 0);
+    blendizzard.set_mvp_bonus(&1_000);
+    assert_eq!(blendizzard.get_mvp_bonus(), 1_000);
+
+    let result = blendizzard.try_set_mvp_bonus(&1_001);
+    assert_contract_error(&result, Error::InvalidConfig);
+    assert_eq!(blendizzard.get_mvp_bonus(), 1_000);
+}
@@ -0,0 +1,65 @@
+/// Onboarding Tests
+///
+/// Tests `onboard`, which deposits into the vault backend and selects a
+/// faction in a single atomic call.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard backed by the mock vault
+fn setup_onboarding<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockVaultClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    (blendizzard, MockVaultClient::new(env, &vault_addr))
+}
+
+#[test]
+fn test_onboard_deposits_and_selects_faction() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup_onboarding(&env);
+
+    let user = Address::generate(&env);
+    vault.set_user_balance(&user, &100_0000000);
+
+    let balance = blendizzard.onboard(&user, &100_0000000, &2);
+    assert_eq!(balance, 100_0000000);
+    assert_eq!(blendizzard.get_player(&user).selected_faction, 2);
+}
+
+#[test]
+fn test_onboard_without_deposit() {
+    let env = setup_test_env();
+    let (blendizzard, _vault) = setup_onboarding(&env);
+
+    let user = Address::generate(&env);
+    assert_eq!(blendizzard.onboard(&user, &0, &1), 0);
+    assert_eq!(blendizzard.get_player(&user).selected_faction, 1);
+
+    let result = blendizzard.try_onboard(&Address::generate(&env), &-1, &1);
+    assert_contract_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_onboard_is_atomic() {
+    let env = setup_test_env();
+    let (blendizzard, _vault) = setup_onboarding(&env);
+    blendizzard.set_deposit_caps(&50_0000000, &0);
+
+    // Deposit over the cap fails, so the faction selection is rolled back too
+    let user = Address::generate(&env);
+    let result = blendizzard.try_onboard(&user, &100_0000000, &0);
+    assert_contract_error(&result, Error::UserDepositCapExceeded);
+    assert_contract_error(&blendizzard.try_get_player(&user), Error::PlayerNotFound);
+}
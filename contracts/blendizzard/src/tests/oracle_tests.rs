@@ -0,0 +1,108 @@
+/// Oracle Price Check Tests
+///
+/// Tests that cycle_epoch skips the BLND → USDC swap (carrying BLND over) when
+/// the Soroswap quote falls too far below the oracle price.
+use super::blend_utils::create_mock_oracle;
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use crate::BlendizzardClient;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Symbol};
+
+/// 5% maximum deviation
+const MAX_DEVIATION: i128 = 500_000;
+
+/// Create Blendizzard (1:1 BLND/USDC pair) with an oracle for both tokens
+fn setup_with_oracle<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockPriceOracleClient<'a>) {
+    let admin = Address::generate(env);
+    let blendizzard = create_blendizzard_with_soroswap(env, &admin);
+    let config = blendizzard.get_config();
+
+    let (oracle, oracle_client) = create_mock_oracle(env);
+    oracle_client.set_data(
+        &admin,
+        &Asset::Other(Symbol::new(env, "USD")),
+        &vec![
+            env,
+            Asset::Stellar(config.blnd_token.clone()),
+            Asset::Stellar(config.usdc_token.clone()),
+        ],
+        &7,
+        &300,
+    );
+
+    blendizzard.set_price_oracle(&Some(oracle), &MAX_DEVIATION);
+
+    (blendizzard, oracle_client)
+}
+
+fn advance_past_epoch(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+}
+
+#[test]
+fn test_swap_proceeds_at_fair_oracle_price() {
+    let env = setup_test_env();
+    let (blendizzard, oracle) = setup_with_oracle(&env);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+
+    // Oracle agrees with the 1:1 pool
+    advance_past_epoch(&env);
+    oracle.set_price_stable(&vec![&env, 1_0000000, 1_0000000]);
+    blendizzard.cycle_epoch();
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(epoch0.reward_pool + epoch0.dev_reward_pool > 0);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+}
+
+#[test]
+fn test_swap_skipped_and_blnd_carried_over_on_deviation() {
+    let env = setup_test_env();
+    let (blendizzard, oracle) = setup_with_oracle(&env);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    let blnd_before = blnd.balance(&blendizzard.address);
+
+    // Oracle says BLND is worth 2 USDC but the pool only pays ~1
+    advance_past_epoch(&env);
+    oracle.set_price_stable(&vec![&env, 2_0000000, 1_0000000]);
+    blendizzard.cycle_epoch();
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(epoch0.is_finalized);
+    assert_eq!(epoch0.reward_pool + epoch0.dev_reward_pool, 0);
+    assert_eq!(blnd.balance(&blendizzard.address), blnd_before);
+    assert_eq!(blendizzard.get_carryover_blnd(), blnd_before);
+
+    // Once the pool price recovers (oracle agrees), the carried BLND is swapped
+    advance_past_epoch(&env);
+    oracle.set_price_stable(&vec![&env, 1_0000000, 1_0000000]);
+    blendizzard.cycle_epoch();
+
+    let epoch1 = blendizzard.get_epoch(&1);
+    assert!(epoch1.reward_pool + epoch1.dev_reward_pool > 0);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+    assert_eq!(blendizzard.get_carryover_blnd(), 0);
+}
+
+#[test]
+fn test_set_price_oracle_validates_deviation() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let oracle = Address::generate(&env);
+
+    let result = blendizzard.try_set_price_oracle(&Some(oracle.clone()), &0);
+    assert_contract_error(&result, Error::InvalidConfig);
+
+    let result = blendizzard.try_set_price_oracle(&Some(oracle), &10_000_000);
+    assert_contract_error(&result, Error::InvalidConfig);
+
+    // Disabling the check doesn't need a threshold
+    blendizzard.set_price_oracle(&None, &0);
+}
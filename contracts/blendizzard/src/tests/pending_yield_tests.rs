@@ -0,0 +1,97 @@
+/// Pending Yield Tests
+///
+/// Tests the "next pot" estimate returned by `get_pending_yield`: admin
+/// balance, BLND to swap quoted on the router, and pool emissions accrued to
+/// the DirectPool position.
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::fee_vault_utils::MockVaultClient;
+use super::testutils::{
+    create_blendizzard_contract, create_blendizzard_with_soroswap, setup_test_env,
+};
+use crate::types::{PendingYield, VaultBackend};
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_pending_yield_quotes_the_next_cycle() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    MockVaultClient::new(&env, &blendizzard.get_config().fee_vault).set_admin_balance(&50_0000000);
+
+    // The mock vault has no pool to read emissions from
+    let quote = blendizzard.quote_reward_swap(&5000_0000000);
+    assert_eq!(
+        blendizzard.get_pending_yield(),
+        PendingYield {
+            admin_balance: 50_0000000,
+            unclaimed_emissions: 0,
+            swappable_blnd: 5000_0000000,
+            harvested: 0,
+            estimated_rewards: 50_0000000 + quote,
+        }
+    );
+
+    // Retained BLND isn't swapped
+    blendizzard.set_blnd_retention(&1_000);
+    let pending = blendizzard.get_pending_yield();
+    assert_eq!(pending.swappable_blnd, 4500_0000000);
+    assert_eq!(
+        pending.estimated_rewards,
+        50_0000000 + blendizzard.quote_reward_swap(&4500_0000000)
+    );
+
+    // Everything was collected by the cycle
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+    blendizzard.cycle_epoch();
+    let pending = blendizzard.get_pending_yield();
+    assert_eq!(pending.admin_balance, 0);
+    assert_eq!(pending.swappable_blnd, 0);
+    assert_eq!(pending.estimated_rewards, 0);
+}
+
+#[test]
+fn test_pending_yield_estimates_direct_pool_emissions() {
+    let env = setup_test_env();
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(&env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(&env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(&env, &xlm);
+    let pool = create_blend_pool(&env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool));
+    assert_eq!(blendizzard.get_pending_yield().unclaimed_emissions, 0);
+
+    let user = Address::generate(&env);
+    usdc_client.mint(&user, &1_000_0000000);
+    blendizzard.deposit(&user, &1_000_0000000);
+
+    // The supplied position accrues b-token emissions over time
+    env.jump(17_280);
+    let first = blendizzard.get_pending_yield();
+    assert!(first.unclaimed_emissions > 0);
+    assert_eq!(first.admin_balance, 0);
+    assert_eq!(first.swappable_blnd, first.unclaimed_emissions);
+
+    env.jump(17_280);
+    assert!(blendizzard.get_pending_yield().unclaimed_emissions > first.unclaimed_emissions);
+}
@@ -0,0 +1,142 @@
+/// Point Formula Tests
+///
+/// Tests the per-game point formulas applied when sessions settle: the
+/// winner's stake (default), a flat amount per win capped at the wager, and
+/// the wager scaled by the margin of victory reported with
+/// `end_game_with_margin`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::PointFormula;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup(env: &Env) -> (BlendizzardClient<'_>, Address, Address, Address) {
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &Address::generate(env),
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    (blendizzard, game, player1, player2)
+}
+
+/// Start a session player1 wins, reporting `margin_bps` if given
+///
+/// # Returns
+/// player1's contribution from the session
+fn win(
+    blendizzard: &BlendizzardClient,
+    game: &Address,
+    player1: &Address,
+    player2: &Address,
+    session_id: u32,
+    margin_bps: Option<u32>,
+) -> i128 {
+    let before = blendizzard
+        .get_epoch_player(&0, player1)
+        .total_fp_contributed;
+    blendizzard.start_game(game, &session_id, player1, player2, &WAGER, &WAGER);
+    match margin_bps {
+        Some(margin_bps) => blendizzard.end_game_with_margin(&session_id, &true, &margin_bps),
+        None => blendizzard.end_game(&session_id, &true),
+    }
+    blendizzard
+        .get_epoch_player(&0, player1)
+        .total_fp_contributed
+        - before
+}
+
+#[test]
+fn test_stake_formula_is_default() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+    assert_eq!(
+        blendizzard.get_game_info(&game).point_formula,
+        PointFormula::Stake
+    );
+
+    // Margins are ignored by the stake formula
+    assert_eq!(win(&blendizzard, &game, &player1, &player2, 1, None), WAGER);
+    assert_eq!(
+        win(&blendizzard, &game, &player1, &player2, 2, Some(1000)),
+        WAGER
+    );
+}
+
+#[test]
+fn test_flat_formula_capped_at_wager() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+
+    blendizzard.set_game_point_formula(&game, &PointFormula::Flat(2_0000000));
+    assert_eq!(
+        win(&blendizzard, &game, &player1, &player2, 1, None),
+        2_0000000
+    );
+
+    blendizzard.set_game_point_formula(&game, &PointFormula::Flat(WAGER * 3));
+    assert_eq!(win(&blendizzard, &game, &player1, &player2, 2, None), WAGER);
+}
+
+#[test]
+fn test_margin_formula_scales_wager() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+    blendizzard.set_game_point_formula(&game, &PointFormula::Margin(2500));
+
+    assert_eq!(
+        win(&blendizzard, &game, &player1, &player2, 1, Some(5000)),
+        WAGER / 2
+    );
+    // Never below the floor
+    assert_eq!(
+        win(&blendizzard, &game, &player1, &player2, 2, Some(1000)),
+        WAGER / 4
+    );
+    // No margin reported counts as a full one
+    assert_eq!(win(&blendizzard, &game, &player1, &player2, 3, None), WAGER);
+}
+
+#[test]
+fn test_point_formula_validation() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+
+    assert_contract_error(
+        &blendizzard.try_set_game_point_formula(&game, &PointFormula::Flat(0)),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_point_formula(&game, &PointFormula::Margin(10_001)),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_point_formula(&Address::generate(&env), &PointFormula::Stake),
+        Error::GameNotWhitelisted,
+    );
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+    assert_contract_error(
+        &blendizzard.try_end_game_with_margin(&1, &true, &10_001),
+        Error::InvalidAmount,
+    );
+}
@@ -0,0 +1,130 @@
+/// Blend Pool Status Tests
+///
+/// Tests that deposits are refused while the Blend pool is frozen (withdrawals
+/// still work), that `sync_pool_status` records status changes, and that game
+/// starts are blocked only when the admin opted in.
+use super::blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, EnvTestUtils};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    Error, ExtError,
+};
+use crate::types::VaultBackend;
+use crate::BlendizzardClient;
+use blend_contract_sdk::pool::Client as PoolClient;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Admin-frozen Blend pool status
+const ADMIN_FROZEN: u32 = 4;
+
+/// Create Blendizzard using the DirectPool backend on a fresh Blend pool
+fn setup<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockTokenClient<'a>, PoolClient<'a>) {
+    env.cost_estimate().budget().reset_unlimited();
+    env.set_default_info();
+
+    let admin = Address::generate(env);
+    let (blend_fixture, blnd, usdc, _blnd_client, usdc_client) =
+        create_blend_fixture_with_tokens(env, &admin);
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let xlm_client = MockTokenClient::new(env, &xlm);
+    let pool = create_blend_pool(env, &blend_fixture, &admin, &usdc_client, &xlm_client);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &blnd,
+        &usdc,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(pool.clone()));
+
+    (blendizzard, usdc_client, PoolClient::new(env, &pool))
+}
+
+#[test]
+fn test_frozen_pool_blocks_deposits() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, pool) = setup(&env);
+    let user = Address::generate(&env);
+    usdc.mint(&user, &200_0000000);
+    blendizzard.deposit(&user, &100_0000000);
+
+    assert_eq!(blendizzard.sync_pool_status(), 0);
+    assert_eq!(blendizzard.get_pool_status(), Some(0));
+
+    pool.set_status(&ADMIN_FROZEN);
+    assert_contract_error(
+        &blendizzard.try_deposit(&user, &50_0000000),
+        ExtError::PoolHalted,
+    );
+    usdc.approve(&user, &blendizzard.address, &50_0000000, &10_000);
+    assert_contract_error(
+        &blendizzard.try_deposit_from_allowance(&user, &user, &50_0000000),
+        ExtError::PoolHalted,
+    );
+
+    // Players can still leave
+    blendizzard.withdraw(&user, &50_0000000);
+    assert_eq!(usdc.balance(&user), 150_0000000);
+
+    assert_eq!(blendizzard.sync_pool_status(), ADMIN_FROZEN);
+    assert_eq!(blendizzard.get_pool_status(), Some(ADMIN_FROZEN));
+    let health = blendizzard.health();
+    assert_eq!(health.pool_status, Some(ADMIN_FROZEN));
+    assert!(!health.healthy);
+
+    pool.set_status(&0);
+    blendizzard.deposit(&user, &50_0000000);
+    assert_eq!(blendizzard.sync_pool_status(), 0);
+}
+
+#[test]
+fn test_halted_pool_blocks_games_when_enabled() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, pool) = setup(&env);
+    let game = Address::generate(&env);
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    for (player, faction) in [(&player1, 0), (&player2, 1)] {
+        usdc.mint(player, &100_0000000);
+        blendizzard.deposit(player, &100_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+    pool.set_status(&ADMIN_FROZEN);
+
+    // Games keep running by default
+    blendizzard.start_game(&game, &1, &player1, &player2, &1_0000000, &1_0000000);
+
+    blendizzard.set_pool_halt_pauses_games(&true);
+    assert!(blendizzard.get_pool_halt_pauses_games());
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000),
+        ExtError::PoolHalted,
+    );
+
+    pool.set_status(&0);
+    blendizzard.start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+}
+
+#[test]
+fn test_unreadable_pool_status() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    // Vault address without a contract
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_sync_pool_status(),
+        Error::FeatureNotConfigured,
+    );
+    assert_eq!(blendizzard.get_pool_status(), None);
+    assert_eq!(blendizzard.health().pool_status, None);
+}
@@ -0,0 +1,146 @@
+/// Practice Session Tests
+///
+/// Tests zero-wager practice sessions: they are only accepted for games that
+/// allow them (`set_game_practice`), lock no faction points, still lock
+/// factions and record stats, and credit the winner the game's flat
+/// `practice_fp` contribution.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::game::MAX_PRACTICE_FP;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup(env: &Env) -> (BlendizzardClient<'_>, Address, Address, Address) {
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &Address::generate(env),
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    let game = Address::generate(env);
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_practice_requires_game_opt_in() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+    assert_eq!(blendizzard.get_game_info(&game).practice_fp, None);
+
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &1, &player1, &player2, &0, &0),
+        Error::InvalidAmount,
+    );
+
+    // A single zero wager is never a practice session
+    blendizzard.set_game_practice(&game, &Some(0));
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &1, &player1, &player2, &0, &1_0000000),
+        Error::InvalidAmount,
+    );
+    blendizzard.start_game(&game, &1, &player1, &player2, &0, &0);
+}
+
+#[test]
+fn test_practice_session_locks_no_fp() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+    blendizzard.set_game_practice(&game, &Some(0));
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &0, &0);
+    let epoch_player = blendizzard.get_epoch_player(&0, &player1);
+    assert_eq!(epoch_player.epoch_faction, Some(0));
+    let available_fp = epoch_player.available_fp;
+    assert!(available_fp > 0);
+
+    blendizzard.end_game(&1, &true);
+
+    // Nothing locked or contributed, but the result is recorded
+    let epoch_player = blendizzard.get_epoch_player(&0, &player1);
+    assert_eq!(epoch_player.available_fp, available_fp);
+    assert_eq!(epoch_player.total_fp_contributed, 0);
+    assert_eq!(
+        blendizzard
+            .get_epoch(&0)
+            .faction_standings
+            .get(0)
+            .unwrap_or(0),
+        0
+    );
+    let stats = blendizzard.get_player_stats(&player1, &game);
+    assert_eq!(stats.wins, 1);
+    assert_eq!(stats.total_wagered, 0);
+    assert_eq!(blendizzard.get_player_stats(&player2, &game).losses, 1);
+}
+
+#[test]
+fn test_practice_win_contributes_practice_fp() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+    let practice_fp = 5000000i128;
+    blendizzard.set_game_practice(&game, &Some(practice_fp));
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &0, &0);
+    blendizzard.end_game(&1, &false);
+
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player2)
+            .total_fp_contributed,
+        practice_fp
+    );
+    assert_eq!(
+        blendizzard.get_epoch(&0).faction_standings.get(1),
+        Some(practice_fp)
+    );
+    assert_eq!(blendizzard.get_epoch(&0).total_game_fp, practice_fp);
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player1)
+            .total_fp_contributed,
+        0
+    );
+}
+
+#[test]
+fn test_set_game_practice_validates() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup(&env);
+
+    assert_contract_error(
+        &blendizzard.try_set_game_practice(&game, &Some(-1)),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_practice(&game, &Some(MAX_PRACTICE_FP + 1)),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_practice(&Address::generate(&env), &Some(0)),
+        Error::GameNotWhitelisted,
+    );
+
+    // Disallowing practice again rejects new practice sessions
+    blendizzard.set_game_practice(&game, &Some(MAX_PRACTICE_FP));
+    blendizzard.set_game_practice(&game, &None);
+    assert_contract_error(
+        &blendizzard.try_start_game(&game, &1, &player1, &player2, &0, &0),
+        Error::InvalidAmount,
+    );
+}
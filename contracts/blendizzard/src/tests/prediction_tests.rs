@@ -0,0 +1,148 @@
+/// Spectator Prediction Tests
+///
+/// Tests USDC predictions on a session's winner: pro-rata payouts of the
+/// losing stakes minus rake, the rake going to pending rewards, and refunds
+/// for sessions that never settle.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a started session 1 and predictions enabled (10% rake)
+fn setup_session<'a>(env: &'a Env) -> (BlendizzardClient<'a>, TokenClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_predictions(&1000_0000000, &1_000_000);
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &WAGER, &WAGER);
+
+    (blendizzard, usdc, player1, player2)
+}
+
+/// Generate a spectator holding `amount` USDC
+fn spectator(env: &Env, usdc: &TokenClient, amount: i128) -> Address {
+    let spectator = Address::generate(env);
+    usdc.mint(&spectator, &amount);
+    spectator
+}
+
+#[test]
+fn test_correct_predictors_split_losing_stakes() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, _player1, _player2) = setup_session(&env);
+
+    let alice = spectator(&env, &usdc, 100_0000000);
+    let bob = spectator(&env, &usdc, 300_0000000);
+    let carol = spectator(&env, &usdc, 200_0000000);
+    blendizzard.predict(&alice, &1, &true, &100_0000000);
+    blendizzard.predict(&bob, &1, &true, &300_0000000);
+    blendizzard.predict(&carol, &1, &false, &200_0000000);
+
+    blendizzard.end_game(&1, &true);
+
+    // 10% rake on the 200 USDC losing side; 180 USDC shared 1:3
+    let pool = blendizzard.get_prediction_pool(&1).unwrap();
+    assert_eq!(pool.player1_won, Some(true));
+    assert_eq!(pool.payout_pool, 180_0000000);
+    env.as_contract(&blendizzard.address, || {
+        assert_eq!(crate::storage::get_pending_rewards(&env), 20_0000000);
+    });
+
+    assert_eq!(blendizzard.claim_prediction(&alice, &1), 145_0000000);
+    assert_eq!(blendizzard.claim_prediction(&bob, &1), 435_0000000);
+    assert_eq!(usdc.balance(&alice), 145_0000000);
+    assert_eq!(usdc.balance(&bob), 435_0000000);
+
+    assert_contract_error(
+        &blendizzard.try_claim_prediction(&carol, &1),
+        Error::NoRewardsAvailable,
+    );
+    assert_contract_error(
+        &blendizzard.try_claim_prediction(&alice, &1),
+        Error::NoRewardsAvailable,
+    );
+}
+
+#[test]
+fn test_prediction_rules() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, player1, _player2) = setup_session(&env);
+    let alice = spectator(&env, &usdc, 2000_0000000);
+
+    // Players can't predict their own session
+    usdc.mint(&player1, &10_0000000);
+    assert_contract_error(
+        &blendizzard.try_predict(&player1, &1, &true, &10_0000000),
+        ExtError::PredictorIsPlayer,
+    );
+
+    // Stake is capped per spectator, and sides can't be switched
+    assert_contract_error(
+        &blendizzard.try_predict(&alice, &1, &true, &1000_0000001),
+        Error::InvalidAmount,
+    );
+    blendizzard.predict(&alice, &1, &true, &10_0000000);
+    assert_contract_error(
+        &blendizzard.try_predict(&alice, &1, &false, &10_0000000),
+        ExtError::PredictionSideMismatch,
+    );
+    assert_contract_error(
+        &blendizzard.try_predict(&alice, &2, &true, &10_0000000),
+        Error::SessionNotFound,
+    );
+
+    // Pending session: nothing to claim yet
+    assert_contract_error(
+        &blendizzard.try_claim_prediction(&alice, &1),
+        ExtError::PredictionNotRefundable,
+    );
+
+    blendizzard.set_predictions(&0, &0);
+    assert_contract_error(
+        &blendizzard.try_predict(&alice, &1, &true, &10_0000000),
+        Error::FeatureNotConfigured,
+    );
+}
+
+#[test]
+fn test_prediction_refunded_when_session_never_settles() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, _player1, _player2) = setup_session(&env);
+    let alice = spectator(&env, &usdc, 50_0000000);
+    blendizzard.predict(&alice, &1, &true, &50_0000000);
+
+    // Simulate the epoch ending with the session still pending
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_current_epoch(&env, 1);
+    });
+
+    assert_eq!(blendizzard.claim_prediction(&alice, &1), 50_0000000);
+    assert_eq!(usdc.balance(&alice), 50_0000000);
+}
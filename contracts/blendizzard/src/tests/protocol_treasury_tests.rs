@@ -0,0 +1,151 @@
+/// Protocol Treasury Tests
+///
+/// Tests that game rakes routed to the protocol treasury are tracked per token
+/// and that only the treasurer can spend them, through the timelocked
+/// `queue_treasury_transfer` + `treasury_transfer`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::protocol_treasury::TREASURY_TRANSFER_DELAY;
+use crate::types::RakeRoute;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const POOL: i128 = 1000_0000000;
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard, rake a session into the protocol treasury and cycle the epoch
+///
+/// Returns the USDC credited to the treasury.
+fn setup_funded<'a>(env: &'a Env) -> (BlendizzardClient<'a>, TokenClient<'a>, i128) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_game_rake(&game, &5000, &RakeRoute::Protocol);
+
+    usdc.mint(&blendizzard.address, &POOL);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(env, POOL);
+    });
+
+    let winner = Address::generate(env);
+    let loser = Address::generate(env);
+    vault.set_user_balance(&winner, &1000_0000000);
+    vault.set_user_balance(&loser, &1000_0000000);
+    blendizzard.select_faction(&winner, &0);
+    blendizzard.select_faction(&loser, &1);
+    blendizzard.start_game(&game, &1, &winner, &loser, &WAGER, &WAGER);
+    blendizzard.end_game(&1, &true);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    blendizzard.cycle_epoch();
+
+    // Standing and each faction's rake are WAGER / 2: a third of the pool each
+    let player_pool = POOL - blendizzard.get_epoch(&0).dev_reward_pool;
+    let raked = player_pool / 3 * 2;
+    (blendizzard, usdc, raked)
+}
+
+#[test]
+fn test_rake_funds_protocol_treasury() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, raked) = setup_funded(&env);
+
+    assert!(raked > 0);
+    assert_eq!(
+        blendizzard.treasury_balance().get(usdc.address.clone()),
+        Some(raked)
+    );
+    assert_eq!(blendizzard.get_faction_treasury(&0), 0);
+    assert_eq!(blendizzard.get_pending_rewards(), 0);
+}
+
+#[test]
+fn test_treasury_transfer_after_timelock() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, raked) = setup_funded(&env);
+    let to = Address::generate(&env);
+    let amount = raked / 2;
+
+    // No treasurer: spending is disabled
+    assert_contract_error(
+        &blendizzard.try_queue_treasury_transfer(&to, &usdc.address, &amount),
+        Error::FeatureNotConfigured,
+    );
+
+    blendizzard.set_treasurer(&Some(Address::generate(&env)));
+    assert_contract_error(
+        &blendizzard.try_queue_treasury_transfer(&to, &usdc.address, &(raked + 1)),
+        ExtError::InsufficientTreasury,
+    );
+
+    let queued = blendizzard.queue_treasury_transfer(&to, &usdc.address, &amount);
+    assert_eq!(
+        queued.executable_at,
+        env.ledger().timestamp() + TREASURY_TRANSFER_DELAY
+    );
+    assert_contract_error(
+        &blendizzard.try_treasury_transfer(&to, &usdc.address, &amount),
+        ExtError::TreasuryTransferTimelocked,
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += TREASURY_TRANSFER_DELAY);
+
+    // Only the queued transfer can execute
+    assert_contract_error(
+        &blendizzard.try_treasury_transfer(&to, &usdc.address, &raked),
+        ExtError::TreasuryTransferNotQueued,
+    );
+    assert_eq!(
+        blendizzard.treasury_transfer(&to, &usdc.address, &amount),
+        raked - amount
+    );
+    assert_eq!(usdc.balance(&to), amount);
+    assert_eq!(blendizzard.get_queued_treasury_transfer(), None);
+
+    // The queued transfer is used up
+    assert_contract_error(
+        &blendizzard.try_treasury_transfer(&to, &usdc.address, &amount),
+        ExtError::TreasuryTransferNotQueued,
+    );
+}
+
+#[test]
+fn test_admin_cancels_treasury_transfer() {
+    let env = setup_test_env();
+    let (blendizzard, usdc, raked) = setup_funded(&env);
+    let to = Address::generate(&env);
+
+    blendizzard.set_treasurer(&Some(Address::generate(&env)));
+    blendizzard.queue_treasury_transfer(&to, &usdc.address, &raked);
+    blendizzard.cancel_treasury_transfer();
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += TREASURY_TRANSFER_DELAY);
+    assert_contract_error(
+        &blendizzard.try_treasury_transfer(&to, &usdc.address, &raked),
+        ExtError::TreasuryTransferNotQueued,
+    );
+    assert_eq!(
+        blendizzard.treasury_balance().get(usdc.address.clone()),
+        Some(raked)
+    );
+}
@@ -0,0 +1,145 @@
+/// Prune Tests
+///
+/// Tests that `prune` deletes the data of expired epochs oldest first, within
+/// the key limit, keeps recent and claimable epochs, and pays the bounty from
+/// the admin-funded bounty fund for epochs that still had entries.
+use super::fee_vault_utils::create_mock_vault;
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const BOUNTY: i128 = 1000000;
+
+/// Create Blendizzard with a one-epoch claim window and a funded USDC token
+fn setup(env: &Env) -> (BlendizzardClient<'_>, TokenClient<'_>) {
+    let admin = Address::generate(env);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &create_mock_vault(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.set_claim_window(&1);
+
+    (blendizzard, usdc)
+}
+
+fn cycle(env: &Env, blendizzard: &BlendizzardClient, epochs: u32) {
+    for _ in 0..epochs {
+        env.ledger().with_mut(|li| li.timestamp += 345_600);
+        blendizzard.cycle_epoch();
+    }
+}
+
+#[test]
+fn test_prunes_expired_epochs_within_limit() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc) = setup(&env);
+    let caller = Address::generate(&env);
+
+    // Nothing is old enough yet
+    cycle(&env, &blendizzard, 3);
+    assert_eq!(blendizzard.prune(&caller, &1000), 0);
+
+    // Current epoch 6: epochs 0-4 expired, 0-2 are past the retention window
+    cycle(&env, &blendizzard, 3);
+    let epoch_keys = crate::storage::EPOCH_DATA_KEYS;
+    assert_eq!(blendizzard.prune(&caller, &(epoch_keys - 1)), 0);
+    assert_eq!(blendizzard.prune(&caller, &epoch_keys), 1);
+    assert_eq!(blendizzard.get_prune_cursor(), 1);
+    assert_contract_error(&blendizzard.try_get_epoch(&0), Error::EpochNotFinalized);
+    assert!(blendizzard.get_epoch(&1).is_finalized);
+
+    assert_eq!(blendizzard.prune(&caller, &1000), 2);
+    assert_eq!(blendizzard.get_prune_cursor(), 3);
+    assert!(blendizzard.get_epoch(&3).is_finalized);
+    assert_eq!(blendizzard.prune(&caller, &1000), 0);
+}
+
+#[test]
+fn test_nothing_pruned_without_claim_window() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc) = setup(&env);
+    blendizzard.set_claim_window(&0);
+
+    cycle(&env, &blendizzard, 8);
+    assert_eq!(blendizzard.prune(&Address::generate(&env), &1000), 0);
+    assert!(blendizzard.get_epoch(&0).is_finalized);
+}
+
+#[test]
+fn test_bounty_paid_from_bounty_fund() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup(&env);
+    let caller = Address::generate(&env);
+    blendizzard.set_prune_bounty(&BOUNTY);
+    assert_eq!(blendizzard.get_prune_bounty(), BOUNTY);
+
+    cycle(&env, &blendizzard, 6);
+    let admin = blendizzard.get_admin();
+    usdc.mint(&admin, &(BOUNTY * 5 / 2));
+    assert_eq!(
+        blendizzard.fund_prune_bounty(&(BOUNTY * 5 / 2)),
+        BOUNTY * 5 / 2
+    );
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, BOUNTY)
+    });
+
+    assert_eq!(blendizzard.prune(&caller, &1000), 3);
+    // Capped by the bounty fund; player rewards are untouched
+    assert_eq!(usdc.balance(&caller), BOUNTY * 5 / 2);
+    assert_eq!(blendizzard.get_prune_bounty_fund(), 0);
+    assert_eq!(blendizzard.get_pending_rewards(), BOUNTY);
+}
+
+#[test]
+fn test_no_bounty_for_already_expired_epochs() {
+    let env = setup_test_env();
+    let (blendizzard, usdc) = setup(&env);
+    let caller = Address::generate(&env);
+    blendizzard.set_prune_bounty(&BOUNTY);
+
+    cycle(&env, &blendizzard, 6);
+    let admin = blendizzard.get_admin();
+    usdc.mint(&admin, &(BOUNTY * 3));
+    blendizzard.fund_prune_bounty(&(BOUNTY * 3));
+
+    // Epoch 0's entries left storage on their own
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::remove_epoch_data(&env, 0);
+    });
+
+    assert_eq!(blendizzard.prune(&caller, &1000), 3);
+    assert_eq!(usdc.balance(&caller), BOUNTY * 2);
+    assert_eq!(blendizzard.get_prune_bounty_fund(), BOUNTY);
+}
+
+#[test]
+fn test_fund_prune_bounty_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc) = setup(&env);
+
+    assert_contract_error(&blendizzard.try_fund_prune_bounty(&0), Error::InvalidAmount);
+}
+
+#[test]
+fn test_set_prune_bounty_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _usdc) = setup(&env);
+
+    assert_contract_error(&blendizzard.try_set_prune_bounty(&-1), Error::InvalidConfig);
+    assert_contract_error(
+        &blendizzard.try_set_prune_bounty(&1_0000001),
+        Error::InvalidConfig,
+    );
+    blendizzard.set_prune_bounty(&0);
+}
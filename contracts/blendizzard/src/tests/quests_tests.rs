@@ -0,0 +1,145 @@
+/// Quests Tests
+///
+/// Tests that play and win quests track progress as sessions settle, honour
+/// the minimum wager, complete once with an FP bonus, and that quests can
+/// only be set for epochs that haven't started running them.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::{Quest, QuestKind};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+fn quest(kind: QuestKind, target: u32, min_wager: i128, bonus_fp: i128) -> Quest {
+    Quest {
+        kind,
+        target,
+        min_wager,
+        bonus_fp,
+    }
+}
+
+#[test]
+fn test_quests_complete_once_with_bonus() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    blendizzard.set_quests(
+        &0,
+        &vec![
+            &env,
+            quest(QuestKind::PlayGames, 2, 0, 5_0000000),
+            quest(QuestKind::WinGames, 1, 10_0000000, 20_0000000),
+        ],
+    );
+
+    // A small win counts towards playing but not the high-stakes win quest
+    blendizzard.start_game(&game, &1, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.end_game(&1, &true);
+    assert_eq!(blendizzard.get_quest_progress(&0, &player1), vec![&env, 1]);
+    assert_eq!(blendizzard.get_quest_progress(&0, &player2), vec![&env, 1]);
+
+    let fp_before = blendizzard.get_epoch_player(&0, &player1).available_fp;
+    let loser_fp_before = blendizzard.get_epoch_player(&0, &player2).available_fp;
+    blendizzard.start_game(&game, &2, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &true);
+
+    // Winner completes both quests, loser only the play quest
+    assert_eq!(
+        blendizzard.get_quest_progress(&0, &player1),
+        vec![&env, 2, 1]
+    );
+    assert_eq!(blendizzard.get_quest_progress(&0, &player2), vec![&env, 2]);
+    assert_eq!(
+        blendizzard.get_epoch_player(&0, &player1).available_fp,
+        fp_before - 10_0000000 + 25_0000000
+    );
+    assert_eq!(
+        blendizzard.get_epoch_player(&0, &player2).available_fp,
+        loser_fp_before - 10_0000000 + 5_0000000
+    );
+
+    // Completed quests stop counting and pay out once
+    let fp_before = blendizzard.get_epoch_player(&0, &player1).available_fp;
+    blendizzard.start_game(&game, &3, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&3, &true);
+    assert_eq!(
+        blendizzard.get_quest_progress(&0, &player1),
+        vec![&env, 2, 1]
+    );
+    assert_eq!(
+        blendizzard.get_epoch_player(&0, &player1).available_fp,
+        fp_before - 10_0000000
+    );
+}
+
+#[test]
+fn test_set_quests_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _game, _player1, _player2) = setup_players(&env);
+    let play = quest(QuestKind::PlayGames, 3, 0, 1_0000000);
+
+    for invalid in [
+        quest(QuestKind::PlayGames, 0, 0, 1_0000000),
+        quest(QuestKind::PlayGames, 3, -1, 1_0000000),
+        quest(QuestKind::WinGames, 3, 0, 0),
+    ] {
+        assert_contract_error(
+            &blendizzard.try_set_quests(&1, &vec![&env, invalid]),
+            Error::InvalidConfig,
+        );
+    }
+    let too_many = vec![
+        &env,
+        play.clone(),
+        play.clone(),
+        play.clone(),
+        play.clone(),
+        play.clone(),
+        play.clone(),
+    ];
+    assert_contract_error(
+        &blendizzard.try_set_quests(&1, &too_many),
+        Error::InvalidConfig,
+    );
+
+    // Later epochs can be replaced, the running epoch's quests can't
+    blendizzard.set_quests(&1, &vec![&env, play.clone()]);
+    blendizzard.set_quests(&1, &vec![&env]);
+    assert_eq!(blendizzard.get_quests(&1).len(), 0);
+
+    blendizzard.set_quests(&0, &vec![&env, play.clone()]);
+    assert_eq!(blendizzard.get_quests(&0), vec![&env, play.clone()]);
+    assert_contract_error(
+        &blendizzard.try_set_quests(&0, &vec![&env, play]),
+        Error::InvalidConfig,
+    );
+}
@@ -0,0 +1,46 @@
+/// Reward Swap Quote Tests
+///
+/// Tests that `quote_reward_swap` matches what cycling the epoch actually
+/// swaps the contract's BLND into.
+use super::soroswap_utils::TokenClient;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, create_test_blendizzard,
+    setup_test_env, Error,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+#[test]
+fn test_quote_matches_cycle_swap() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let blnd = TokenClient::new(&env, &blendizzard.get_config().blnd_token);
+    let blnd_held = blnd.balance(&blendizzard.address);
+
+    // 1:1 pool: fees and price impact keep the quote below the BLND sold
+    let quote = blendizzard.quote_reward_swap(&blnd_held);
+    assert!(quote > 0 && quote < blnd_held);
+
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+    blendizzard.cycle_epoch();
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert_eq!(epoch0.reward_pool + epoch0.dev_reward_pool, quote);
+}
+
+#[test]
+fn test_quote_errors() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    assert_contract_error(&blendizzard.try_quote_reward_swap(&0), Error::InvalidAmount);
+
+    // No router behind the configured address
+    let unrouted = create_test_blendizzard(&env, &admin);
+    assert_contract_error(
+        &unrouted.try_quote_reward_swap(&1000_0000000),
+        Error::SwapError,
+    );
+}
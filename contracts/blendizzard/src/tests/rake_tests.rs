@@ -0,0 +1,208 @@
+/// Game Rake Tests
+///
+/// Tests that a game's rake is taken from both players' wagers and that every
+/// faction's raked share of the reward pool is routed to the next reward
+/// pool, the faction's treasury or burned when the epoch cycles.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::RakeRoute;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const POOL: i128 = 1000_0000000;
+const WAGER: i128 = 10_0000000;
+
+struct Setup<'a> {
+    blendizzard: BlendizzardClient<'a>,
+    vault: MockVaultClient<'a>,
+    usdc: TokenClient<'a>,
+    game: Address,
+}
+
+/// Create Blendizzard with a known USDC reward pool waiting for the next cycle
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    usdc.mint(&blendizzard.address, &POOL);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(env, POOL);
+    });
+
+    Setup {
+        blendizzard,
+        vault: MockVaultClient::new(env, &vault_addr),
+        usdc,
+        game,
+    }
+}
+
+/// Play one session won by a faction 0 player
+fn play_and_win(env: &Env, s: &Setup) {
+    let winner = Address::generate(env);
+    let loser = Address::generate(env);
+    s.vault.set_user_balance(&winner, &1000_0000000);
+    s.vault.set_user_balance(&loser, &1000_0000000);
+    s.blendizzard.select_faction(&winner, &0);
+    s.blendizzard.select_faction(&loser, &1);
+    s.blendizzard
+        .start_game(&s.game, &1, &winner, &loser, &WAGER, &WAGER);
+    s.blendizzard.end_game(&1, &true);
+}
+
+/// Cycle epoch 0 and return its player reward pool before the rake
+fn end_epoch(env: &Env, s: &Setup) -> i128 {
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    s.blendizzard.cycle_epoch();
+    POOL - s.blendizzard.get_epoch(&0).dev_reward_pool
+}
+
+#[test]
+fn test_rake_taken_from_both_wagers() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard
+        .set_game_rake(&s.game, &2500, &RakeRoute::RewardPool);
+
+    let info = s.blendizzard.get_game_info(&s.game);
+    assert_eq!(
+        (info.rake_bps, info.rake_route),
+        (2500, RakeRoute::RewardPool)
+    );
+
+    play_and_win(&env, &s);
+
+    // The winner contributes what's left of their wager; the loser's wager
+    // is raked against their own faction
+    let epoch = s.blendizzard.get_epoch(&0);
+    assert_eq!(epoch.faction_standings.get(0).unwrap(), WAGER * 3 / 4);
+    for faction in [0, 1] {
+        let raked = epoch.faction_rakes.get(faction).unwrap();
+        assert_eq!(
+            (raked.reward_pool, raked.treasury, raked.burn),
+            (WAGER / 4, 0, 0)
+        );
+    }
+}
+
+#[test]
+fn test_rake_routed_to_next_reward_pool() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard
+        .set_game_rake(&s.game, &5000, &RakeRoute::RewardPool);
+    play_and_win(&env, &s);
+
+    let player_pool = end_epoch(&env, &s);
+
+    // Standing and each faction's rake are WAGER / 2: a third of the pool each
+    let raked = player_pool / 3 * 2;
+    assert_eq!(s.blendizzard.get_pending_rewards(), raked);
+    assert_eq!(s.blendizzard.get_epoch(&0).reward_pool, player_pool - raked);
+}
+
+#[test]
+fn test_rake_routed_to_faction_treasury() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard
+        .set_game_rake(&s.game, &5000, &RakeRoute::Treasury);
+    play_and_win(&env, &s);
+
+    let player_pool = end_epoch(&env, &s);
+
+    // Each faction's treasury takes its own players' rake
+    let raked = player_pool / 3;
+    assert_eq!(s.blendizzard.get_faction_treasury(&0), raked);
+    assert_eq!(s.blendizzard.get_faction_treasury(&1), raked);
+    assert_eq!(
+        s.blendizzard.get_epoch(&0).reward_pool,
+        player_pool - raked * 2
+    );
+}
+
+#[test]
+fn test_rake_burned() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard
+        .set_game_rake(&s.game, &5000, &RakeRoute::Burn);
+    play_and_win(&env, &s);
+
+    let player_pool = end_epoch(&env, &s);
+
+    let burned = player_pool / 3 * 2;
+    assert_eq!(s.usdc.balance(&s.blendizzard.address), POOL - burned);
+    assert_eq!(
+        s.blendizzard.get_epoch(&0).reward_pool,
+        player_pool - burned
+    );
+}
+
+#[test]
+fn test_no_rake_by_default() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    play_and_win(&env, &s);
+
+    let player_pool = end_epoch(&env, &s);
+
+    assert_eq!(s.blendizzard.get_epoch(&0).reward_pool, player_pool);
+    assert_eq!(s.blendizzard.get_pending_rewards(), 0);
+}
+
+#[test]
+fn test_set_game_rake_validation() {
+    let env = setup_test_env();
+    let s = setup(&env);
+
+    assert_contract_error(
+        &s.blendizzard
+            .try_set_game_rake(&s.game, &10_001, &RakeRoute::Burn),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &s.blendizzard
+            .try_set_game_rake(&Address::generate(&env), &100, &RakeRoute::Burn),
+        Error::GameNotWhitelisted,
+    );
+}
+
+#[test]
+fn test_rake_skimmed_into_insurance_fund() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard.set_insurance_bps(&1000);
+    s.blendizzard
+        .set_game_rake(&s.game, &5000, &RakeRoute::Treasury);
+    play_and_win(&env, &s);
+
+    let player_pool = end_epoch(&env, &s);
+
+    // 10% of each faction's routed rake goes to the insurance fund instead
+    let raked = player_pool / 3;
+    let insured = raked / 10;
+    assert_eq!(s.blendizzard.get_faction_treasury(&0), raked - insured);
+    assert_eq!(s.blendizzard.get_faction_treasury(&1), raked - insured);
+    assert_eq!(s.blendizzard.get_insurance_fund().balance, insured * 2);
+    assert_eq!(
+        s.blendizzard.get_epoch(&0).reward_pool,
+        player_pool - raked * 2
+    );
+}
@@ -0,0 +1,89 @@
+/// Game Randomness Tests
+///
+/// Tests `random_range` and `shuffle`, which registered games call to draw
+/// randomness for their sessions.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and an active session 1 on it
+fn setup_session<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    (blendizzard, game)
+}
+
+#[test]
+fn test_random_range_stays_in_bounds() {
+    let env = setup_test_env();
+    let (blendizzard, game) = setup_session(&env);
+
+    let mut seen = [false; 6];
+    for _ in 0..60 {
+        let roll = blendizzard.random_range(&game, &1, &1, &6);
+        assert!((1..=6).contains(&roll));
+        seen[(roll - 1) as usize] = true;
+    }
+    assert!(seen.iter().all(|&face| face));
+
+    assert_eq!(blendizzard.random_range(&game, &1, &4, &4), 4);
+    assert_contract_error(
+        &blendizzard.try_random_range(&game, &1, &2, &1),
+        Error::InvalidAmount,
+    );
+}
+
+#[test]
+fn test_shuffle_is_a_permutation() {
+    let env = setup_test_env();
+    let (blendizzard, game) = setup_session(&env);
+
+    let values = vec![&env, 1u32, 2, 3, 4, 5, 6, 7, 8];
+    let shuffled = blendizzard.shuffle(&game, &1, &values);
+    assert_eq!(shuffled.len(), values.len());
+    for value in values.iter() {
+        assert!(shuffled.contains(value));
+    }
+}
+
+#[test]
+fn test_randomness_requires_the_sessions_game() {
+    let env = setup_test_env();
+    let (blendizzard, game) = setup_session(&env);
+
+    assert_contract_error(
+        &blendizzard.try_random_range(&game, &99, &0, &1),
+        Error::SessionNotFound,
+    );
+    assert_contract_error(
+        &blendizzard.try_shuffle(&Address::generate(&env), &1, &vec![&env, 1u32, 2]),
+        ExtError::SessionGameMismatch,
+    );
+}
@@ -0,0 +1,99 @@
+/// Elo Rating Tests
+///
+/// Tests the integer Elo math and per-(player, game) rating updates in end_game.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::rating::{expected_score_bps, rating_change, INITIAL_RATING};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+// ============================================================================
+// Elo Math Tests
+// ============================================================================
+
+#[test]
+fn test_expected_score_matches_elo_formula() {
+    // Equal ratings → 50%
+    assert_eq!(expected_score_bps(1200, 1200), 5000);
+
+    // 400 points behind → 1 / (1 + 10) ≈ 9.09%
+    assert_eq!(expected_score_bps(1200, 1600), 909);
+    assert_eq!(expected_score_bps(1600, 1200), 9091);
+
+    // Interpolated between table entries (float formula: 4928.0)
+    assert_eq!(expected_score_bps(1200, 1205), 4929);
+
+    // Clamped beyond 800 points
+    assert_eq!(expected_score_bps(0, 3000), 99);
+}
+
+#[test]
+fn test_rating_change_favours_upsets() {
+    assert_eq!(rating_change(1200, 1200), 16);
+    // Underdog beating a much stronger player gains close to K
+    assert_eq!(rating_change(1200, 1600), 29);
+    // Favourite beating a much weaker player gains little
+    assert_eq!(rating_change(1600, 1200), 3);
+}
+
+// ============================================================================
+// Rating Update Tests
+// ============================================================================
+
+#[test]
+fn test_end_game_updates_ratings_per_game() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let other_game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    assert_eq!(blendizzard.get_rating(&player1, &game), INITIAL_RATING);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+
+    assert_eq!(blendizzard.get_rating(&player1, &game), INITIAL_RATING + 16);
+    assert_eq!(blendizzard.get_rating(&player2, &game), INITIAL_RATING - 16);
+
+    // Ratings are independent per game
+    assert_eq!(
+        blendizzard.get_rating(&player1, &other_game),
+        INITIAL_RATING
+    );
+
+    // Second win against a now lower-rated opponent gains less
+    blendizzard.start_game(&game, &2, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &true);
+
+    let change = rating_change(INITIAL_RATING + 16, INITIAL_RATING - 16);
+    assert!(change < 16);
+    assert_eq!(
+        blendizzard.get_rating(&player1, &game),
+        INITIAL_RATING + 16 + change
+    );
+    assert_eq!(
+        blendizzard.get_rating(&player2, &game),
+        INITIAL_RATING - 16 - change
+    );
+}
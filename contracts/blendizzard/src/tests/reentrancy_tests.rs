@@ -0,0 +1,98 @@
+/// Reentrancy Guard Tests
+///
+/// Tests that `cycle_epoch`, `withdraw` and `end_game` refuse to run while
+/// another guarded call is in flight, and that the guard is released after
+/// each call.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_guarded_entrypoints_reject_reentry() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    // Simulate a guarded call still in progress
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_in_flight(&env, true);
+    });
+    env.ledger().with_mut(|li| li.timestamp += 345_601);
+
+    assert_contract_error(&blendizzard.try_end_game(&1, &true), ExtError::Reentrancy);
+    assert_contract_error(&blendizzard.try_cycle_epoch(), ExtError::Reentrancy);
+    assert_contract_error(
+        &blendizzard.try_withdraw(&player1, &1_0000000),
+        ExtError::Reentrancy,
+    );
+}
+
+#[test]
+fn test_guard_is_released_after_each_call() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &false);
+
+    // A failed guarded call doesn't leave the guard held either
+    assert_contract_error(
+        &blendizzard.try_end_game(&2, &true),
+        Error::SessionAlreadyFinalized,
+    );
+    blendizzard.start_game(&game, &3, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.end_game(&3, &true);
+
+    env.as_contract(&blendizzard.address, || {
+        assert!(!crate::storage::is_in_flight(&env));
+    });
+}
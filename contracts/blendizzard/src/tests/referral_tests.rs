@@ -0,0 +1,177 @@
+/// Referral Tests
+///
+/// Tests one-time referrer registration and the referral share of claimed
+/// rewards credited to (and claimed by) referrers.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    unboosted_weights, Error,
+};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 1000_0000000;
+
+/// Create Blendizzard with a finalized epoch 0 where `player` is the only winner
+fn setup_claimable_epoch<'a>(
+    env: &'a Env,
+    player: &Address,
+) -> (BlendizzardClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+
+    let fp = 100_0000000i128;
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, fp);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool: REWARD_POOL,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        crate::storage::set_epoch_player(
+            env,
+            0,
+            player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+    vault.set_user_balance(player, &10_0000000);
+
+    (blendizzard, usdc)
+}
+
+// ============================================================================
+// Referrer Registration Tests
+// ============================================================================
+
+#[test]
+fn test_set_referrer_is_one_time() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let player = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    assert_eq!(blendizzard.get_referrer(&player), None);
+    blendizzard.set_referrer(&player, &referrer);
+    assert_eq!(blendizzard.get_referrer(&player), Some(referrer));
+
+    let result = blendizzard.try_set_referrer(&player, &Address::generate(&env));
+    assert_contract_error(&result, Error::ReferrerAlreadySet);
+}
+
+#[test]
+fn test_cannot_refer_self() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let player = Address::generate(&env);
+    let result = blendizzard.try_set_referrer(&player, &player);
+    assert_contract_error(&result, Error::InvalidReferrer);
+}
+
+#[test]
+fn test_set_referral_share_rejects_invalid_values() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_set_referral_share(&-1),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_referral_share(&10_000_001),
+        Error::InvalidConfig,
+    );
+}
+
+// ============================================================================
+// Referral Reward Tests
+// ============================================================================
+
+#[test]
+fn test_claim_credits_referral_share() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+
+    // 10% of claimed rewards go to the referrer
+    blendizzard.set_referral_share(&1_000_000);
+    blendizzard.set_referrer(&player, &referrer);
+
+    let claimed = blendizzard.claim_epoch_reward(&player, &0);
+    assert_eq!(claimed, 900_0000000);
+    assert_eq!(usdc.balance(&player), 900_0000000);
+    assert_eq!(blendizzard.get_referral_balance(&referrer), 100_0000000);
+
+    let referral_claimed = blendizzard.claim_referral_rewards(&referrer);
+    assert_eq!(referral_claimed, 100_0000000);
+    assert_eq!(usdc.balance(&referrer), 100_0000000);
+    assert_eq!(blendizzard.get_referral_balance(&referrer), 0);
+
+    let result = blendizzard.try_claim_referral_rewards(&referrer);
+    assert_contract_error(&result, Error::NoRewardsAvailable);
+}
+
+#[test]
+fn test_claim_without_referral_share_pays_in_full() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let (blendizzard, _usdc) = setup_claimable_epoch(&env, &player);
+
+    // Referrer set, but referrals disabled (share = 0)
+    blendizzard.set_referrer(&player, &referrer);
+
+    assert_eq!(blendizzard.claim_epoch_reward(&player, &0), REWARD_POOL);
+    assert_eq!(blendizzard.get_referral_balance(&referrer), 0);
+}
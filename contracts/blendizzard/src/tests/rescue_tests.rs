@@ -0,0 +1,42 @@
+/// Token Rescue Tests
+///
+/// Tests that stranded tokens can be returned while USDC, BLND and the
+/// fee-vault stay protected.
+use super::soroswap_utils::create_token;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_with_soroswap, setup_test_env, Error,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_rescue_stranded_token() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+
+    let airdrop = create_token(&env, &admin);
+    airdrop.mint(&blendizzard.address, &500_0000000);
+
+    let recipient = Address::generate(&env);
+    blendizzard.rescue_token(&airdrop.address, &recipient, &200_0000000);
+    assert_eq!(airdrop.balance(&recipient), 200_0000000);
+    assert_eq!(airdrop.balance(&blendizzard.address), 300_0000000);
+
+    let result = blendizzard.try_rescue_token(&airdrop.address, &recipient, &300_0000001);
+    assert_contract_error(&result, Error::InsufficientBalance);
+}
+
+#[test]
+fn test_rescue_refuses_protected_tokens() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let config = blendizzard.get_config();
+    let recipient = Address::generate(&env);
+
+    for token in [config.usdc_token, config.blnd_token, config.fee_vault] {
+        let result = blendizzard.try_rescue_token(&token, &recipient, &1);
+        assert_contract_error(&result, Error::TokenNotRescuable);
+    }
+}
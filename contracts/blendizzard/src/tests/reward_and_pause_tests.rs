@@ -147,8 +147,6 @@ fn test_admin_functions_work_when_paused() {
         &None,
         &None,
         &None,
-        &None,
-        &None,
     );
 
     // Config should be updated (just verify get_config works)
@@ -159,6 +157,34 @@ fn test_admin_functions_work_when_paused() {
     assert!(!blendizzard.is_paused());
 }
 
+#[test]
+fn test_guardian_can_pause() {
+    let env = setup_test_env();
+    let (_game, _vault, _mock_vault, blendizzard, _usdc) = setup_complete_game_env(&env);
+    let guardian = Address::generate(&env);
+
+    // No guardian configured yet
+    let result = blendizzard.try_guardian_pause();
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+
+    blendizzard.set_guardian(&Some(guardian.clone()));
+    assert_eq!(blendizzard.get_guardian(), Some(guardian.clone()));
+
+    blendizzard.guardian_pause();
+    assert!(blendizzard.is_paused());
+
+    // Pausing is authorized by the guardian, not the admin
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, guardian);
+
+    // Only the admin can unpause or remove the guardian
+    blendizzard.unpause();
+    assert!(!blendizzard.is_paused());
+    blendizzard.set_guardian(&None);
+    assert_eq!(blendizzard.get_guardian(), None);
+}
+
 // ============================================================================
 // Reward Query Tests (claimable_amount, has_claimed)
 // ============================================================================
@@ -0,0 +1,130 @@
+/// Reward Cap Tests
+///
+/// Tests that a reward cap limits any address's share of an epoch's reward
+/// pool, redistributes the excess pro-rata to the rest of the winning
+/// faction, and applies from the epoch after it is set.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::create_token;
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const POOL: i128 = 1000_0000000;
+
+/// Create Blendizzard with a registered game and a funded USDC token
+fn setup(env: &Env) -> (BlendizzardClient<'_>, MockVaultClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    usdc.mint(&blendizzard.address, &POOL);
+
+    (blendizzard, MockVaultClient::new(env, &vault_addr), game)
+}
+
+fn cycle(env: &Env, blendizzard: &BlendizzardClient) {
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_cap_redistributes_excess_to_faction() {
+    let env = setup_test_env();
+    let (blendizzard, vault, game) = setup(&env);
+
+    // Set during epoch 0, the cap applies from epoch 1
+    blendizzard.set_reward_cap(&4000);
+    cycle(&env, &blendizzard);
+    assert_eq!(blendizzard.get_epoch_reward_cap(&0), 0);
+    assert_eq!(blendizzard.get_epoch_reward_cap(&1), 4000);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, POOL);
+    });
+
+    // Faction 0 weights 60% / 20% / 20%
+    let whale = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let opponent = Address::generate(&env);
+    for (player, faction) in [(&whale, 0), (&member1, 0), (&member2, 0), (&opponent, 1)] {
+        vault.set_user_balance(player, &1000_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+    for (session_id, winner, wager) in [
+        (1, &whale, 30_0000000),
+        (2, &member1, 10_0000000),
+        (3, &member2, 10_0000000),
+    ] {
+        blendizzard.start_game(&game, &session_id, winner, &opponent, &wager, &wager);
+        blendizzard.end_game(&session_id, &true);
+    }
+    cycle(&env, &blendizzard);
+    let pool = blendizzard.get_epoch(&1).reward_pool;
+    assert!(pool > 0);
+
+    // The whale is capped at 40%; the other 60% splits 1:1
+    let whale_reward = blendizzard.claim_epoch_reward(&whale, &1);
+    assert_eq!(whale_reward, pool * 4 / 10);
+    let member_reward = (pool - whale_reward) / 2;
+    assert_eq!(blendizzard.claim_epoch_reward(&member1, &1), member_reward);
+    assert_eq!(blendizzard.claim_epoch_reward(&member2, &1), member_reward);
+    assert!(pool - whale_reward - 2 * member_reward <= 1);
+}
+
+#[test]
+fn test_uncapped_epoch_splits_pro_rata() {
+    let env = setup_test_env();
+    let (blendizzard, vault, game) = setup(&env);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_pending_rewards(&env, POOL);
+    });
+
+    let whale = Address::generate(&env);
+    let member = Address::generate(&env);
+    let opponent = Address::generate(&env);
+    for (player, faction) in [(&whale, 0), (&member, 0), (&opponent, 1)] {
+        vault.set_user_balance(player, &1000_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+    blendizzard.start_game(&game, &1, &whale, &opponent, &30_0000000, &30_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &member, &opponent, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &true);
+
+    // Setting a cap mid-epoch doesn't change the running epoch's split
+    blendizzard.set_reward_cap(&500);
+    cycle(&env, &blendizzard);
+    let pool = blendizzard.get_epoch(&0).reward_pool;
+    assert_eq!(blendizzard.claim_epoch_reward(&whale, &0), pool * 3 / 4);
+}
+
+#[test]
+fn test_set_reward_cap_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _vault, _game) = setup(&env);
+    assert_eq!(blendizzard.get_reward_cap(), 0);
+
+    assert_contract_error(&blendizzard.try_set_reward_cap(&499), Error::InvalidConfig);
+    assert_contract_error(
+        &blendizzard.try_set_reward_cap(&10_001),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.set_reward_cap(&1000);
+    assert_eq!(blendizzard.get_reward_cap(), 1000);
+    blendizzard.set_reward_cap(&0);
+    assert_eq!(blendizzard.get_reward_cap(), 0);
+}
@@ -0,0 +1,200 @@
+/// Reward Token Tests
+///
+/// Tests that boosts in whitelisted reward tokens become per-epoch pools paid
+/// in the same claim as USDC, that unclaimed pools carry over once the claim
+/// window passes, and that owed reward tokens can't be rescued.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::TokenAmount;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+const BOOST: i128 = 400_0000000;
+
+/// Create Blendizzard with a registered game and a whitelisted partner token
+/// boosted by `BOOST`
+fn setup(
+    env: &Env,
+) -> (
+    BlendizzardClient<'_>,
+    MockVaultClient<'_>,
+    Address,
+    TokenClient<'_>,
+) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let usdc = create_token(env, &admin);
+    let partner = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+    blendizzard.set_reward_tokens(&vec![env, partner.address.clone()]);
+
+    let sponsor = Address::generate(env);
+    partner.mint(&sponsor, &BOOST);
+    assert_eq!(
+        blendizzard.boost_epoch(&sponsor, &partner.address, &BOOST),
+        BOOST
+    );
+
+    (
+        blendizzard,
+        MockVaultClient::new(env, &vault_addr),
+        game,
+        partner,
+    )
+}
+
+fn cycle(env: &Env, blendizzard: &BlendizzardClient) {
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_token_pool_split_in_claim() {
+    let env = setup_test_env();
+    let (blendizzard, vault, game, partner) = setup(&env);
+    assert_eq!(
+        blendizzard
+            .get_pending_reward_tokens()
+            .get(partner.address.clone()),
+        Some(BOOST)
+    );
+
+    // Faction 0 weights 3:1
+    let whale = Address::generate(&env);
+    let member = Address::generate(&env);
+    let opponent = Address::generate(&env);
+    for (player, faction) in [(&whale, 0), (&member, 0), (&opponent, 1)] {
+        vault.set_user_balance(player, &1000_0000000);
+        blendizzard.select_faction(player, &faction);
+    }
+    blendizzard.start_game(&game, &1, &whale, &opponent, &30_0000000, &30_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &member, &opponent, &10_0000000, &10_0000000);
+    blendizzard.end_game(&2, &true);
+    cycle(&env, &blendizzard);
+
+    assert_eq!(
+        blendizzard.get_epoch_rewards(&0),
+        vec![
+            &env,
+            TokenAmount {
+                token: blendizzard.get_config().usdc_token,
+                amount: 0,
+            },
+            TokenAmount {
+                token: partner.address.clone(),
+                amount: BOOST,
+            },
+        ]
+    );
+    assert_eq!(blendizzard.get_pending_reward_tokens().len(), 0);
+
+    // No USDC yield: the claims only pay the partner token
+    assert_eq!(blendizzard.claim_epoch_reward(&whale, &0), 0);
+    blendizzard.claim_epoch_reward(&member, &0);
+    assert_eq!(partner.balance(&whale), BOOST * 3 / 4);
+    assert_eq!(partner.balance(&member), BOOST / 4);
+    assert_contract_error(
+        &blendizzard.try_claim_epoch_reward(&opponent, &0),
+        Error::NotWinningFaction,
+    );
+}
+
+#[test]
+fn test_unclaimed_token_pool_carries_over() {
+    let env = setup_test_env();
+    let (blendizzard, _vault, _game, partner) = setup(&env);
+    blendizzard.set_claim_window(&1);
+
+    // Nobody plays epoch 0; its pool expires when epoch 1 closes
+    cycle(&env, &blendizzard);
+    assert_eq!(blendizzard.get_epoch_rewards(&0).len(), 2);
+    cycle(&env, &blendizzard);
+
+    assert_eq!(
+        blendizzard.get_epoch_rewards(&1).get_unchecked(1),
+        TokenAmount {
+            token: partner.address.clone(),
+            amount: BOOST,
+        }
+    );
+    assert_eq!(partner.balance(&blendizzard.address), BOOST);
+}
+
+#[test]
+fn test_owed_reward_tokens_not_rescuable() {
+    let env = setup_test_env();
+    let (blendizzard, _vault, _game, partner) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    // Only tokens beyond what the pools are owed can be rescued
+    partner.mint(&blendizzard.address, &50_0000000);
+    blendizzard.rescue_token(&partner.address, &recipient, &50_0000000);
+    assert_eq!(partner.balance(&recipient), 50_0000000);
+    assert_contract_error(
+        &blendizzard.try_rescue_token(&partner.address, &recipient, &1),
+        Error::TokenNotRescuable,
+    );
+}
+
+#[test]
+fn test_set_reward_tokens_validates() {
+    let env = setup_test_env();
+    let (blendizzard, _vault, _game, partner) = setup(&env);
+    let config = blendizzard.get_config();
+    assert_eq!(
+        blendizzard.get_reward_tokens(),
+        vec![&env, partner.address.clone()]
+    );
+
+    assert_contract_error(
+        &blendizzard.try_set_reward_tokens(&vec![&env, config.usdc_token]),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_reward_tokens(&vec![&env, config.blnd_token]),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_reward_tokens(&vec![
+            &env,
+            partner.address.clone(),
+            partner.address.clone(),
+        ]),
+        Error::InvalidConfig,
+    );
+    let too_many = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    assert_contract_error(
+        &blendizzard.try_set_reward_tokens(&too_many),
+        Error::InvalidConfig,
+    );
+
+    // Removed tokens can't boost anymore
+    blendizzard.set_reward_tokens(&vec![&env]);
+    let sponsor = Address::generate(&env);
+    partner.mint(&sponsor, &BOOST);
+    assert_contract_error(
+        &blendizzard.try_boost_epoch(&sponsor, &partner.address, &BOOST),
+        Error::InvalidConfig,
+    );
+}
@@ -7,8 +7,8 @@
 /// Uses REAL FeeVault to verify actual deposit behavior.
 use super::blend_utils::{create_blend_pool, EnvTestUtils};
 use super::fee_vault_utils::create_fee_vault;
-use super::testutils::{create_blendizzard_contract, setup_test_env};
-use crate::types::{EpochInfo, EpochPlayer};
+use super::testutils::{create_blendizzard_contract, setup_test_env, unboosted_weights};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
 use blend_contract_sdk::testutils::BlendFixture;
 use sep_41_token::testutils::MockTokenClient;
 use soroban_sdk::testutils::Address as _;
@@ -70,12 +70,23 @@ fn test_claim_reward_goes_to_vault_not_player_wallet() {
     let epoch_info = EpochInfo {
         start_time: 0,
         end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
         faction_standings: faction_standings.clone(),
         reward_pool,
         winning_faction: Some(0), // Faction 0 wins
         is_finalized: true,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
     };
 
     // Manually store the epoch
@@ -90,6 +101,9 @@ fn test_claim_reward_goes_to_vault_not_player_wallet() {
         epoch_balance_snapshot: 1000_0000000,
         available_fp: 0,
         total_fp_contributed: player_fp,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     // Manually store player's epoch data
@@ -201,12 +215,23 @@ fn test_cannot_claim_twice() {
     let epoch_info = EpochInfo {
         start_time: 0,
         end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
         faction_standings,
         reward_pool,
         winning_faction: Some(0),
         is_finalized: true,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -218,6 +243,9 @@ fn test_cannot_claim_twice() {
         epoch_balance_snapshot: 1000_0000000,
         available_fp: 0,
         total_fp_contributed: 250_0000000,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -14,7 +14,7 @@ use super::fee_vault_utils::{create_fee_vault, FeeVaultClient};
 use super::soroswap_utils::{
     add_liquidity, create_factory, create_router, create_token, TokenClient,
 };
-use super::testutils::{create_blendizzard_contract, setup_test_env};
+use super::testutils::{create_blendizzard_contract, setup_test_env, unboosted_weights};
 use crate::BlendizzardClient;
 use blend_contract_sdk::testutils::BlendFixture;
 use sep_41_token::testutils::MockTokenClient;
@@ -144,12 +144,23 @@ fn test_claim_reward_deposits_to_vault() {
     let epoch_info = crate::types::EpochInfo {
         start_time: 0,
         end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
         faction_standings: faction_standings.clone(),
         reward_pool,
         winning_faction: Some(0), // Faction 0 wins
         is_finalized: true,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode: crate::types::DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -163,6 +174,9 @@ fn test_claim_reward_deposits_to_vault() {
         epoch_balance_snapshot: 1000_0000000,
         available_fp: 0,
         total_fp_contributed: player_fp,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -238,12 +252,23 @@ fn test_claim_reward_cannot_claim_twice() {
     let epoch_info = crate::types::EpochInfo {
         start_time: 0,
         end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
         faction_standings,
         reward_pool,
         winning_faction: Some(0), // Faction 0 wins
         is_finalized: true,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode: crate::types::DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -257,6 +282,9 @@ fn test_claim_reward_cannot_claim_twice() {
         epoch_balance_snapshot: 1000_0000000,
         available_fp: 0,
         total_fp_contributed: player_fp,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -307,12 +335,23 @@ fn test_claim_reward_proportional_distribution() {
     let epoch_info = crate::types::EpochInfo {
         start_time: 0,
         end_time: 86400,
+        duration: 86400,
+        faction_weighted_standings: unboosted_weights(&env, &faction_standings),
         faction_standings,
         reward_pool: total_rewards,
         winning_faction: Some(0), // Faction 0 wins
         is_finalized: true,
         total_game_fp: 0,
         dev_reward_pool: 0,
+        distribution_mode: crate::types::DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -328,6 +367,9 @@ fn test_claim_reward_proportional_distribution() {
         epoch_balance_snapshot: 1000_0000000,
         available_fp: 0,
         total_fp_contributed: player1_fp,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     let epoch_player2 = crate::types::EpochPlayer {
@@ -335,6 +377,9 @@ fn test_claim_reward_proportional_distribution() {
         epoch_balance_snapshot: 2000_0000000,
         available_fp: 0,
         total_fp_contributed: player2_fp,
+        streak_bonus: 0,
+        games_completed: 0,
+        reward_eligible: true,
     };
 
     env.as_contract(&blendizzard.address, || {
@@ -0,0 +1,103 @@
+/// Best-of-N Series Tests
+///
+/// Tests that series lock FP once, track per-game results, and only settle
+/// standings when one side reaches a majority.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a registered game and two funded players
+fn setup_series<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_best_of_three_settles_on_majority() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_series(&env);
+
+    blendizzard.start_series(&game, &1, &player1, &player2, &WAGER, &WAGER, &3);
+
+    // 1-1: series still open, nothing contributed yet
+    blendizzard.report_series_game(&1, &true);
+    let series = blendizzard.report_series_game(&1, &false);
+    assert_eq!(series.player1_wins, 1);
+    assert_eq!(series.player2_wins, 1);
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player2)
+            .total_fp_contributed,
+        0
+    );
+    assert_eq!(blendizzard.get_active_sessions(&player1), vec![&env, 1]);
+
+    // 1-2: player2 takes the series and their wager counts once
+    let series = blendizzard.report_series_game(&1, &false);
+    assert_eq!(series.results, vec![&env, true, false, false]);
+    assert_eq!(
+        blendizzard
+            .get_epoch_player(&0, &player2)
+            .total_fp_contributed,
+        WAGER
+    );
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 0);
+
+    let result = blendizzard.try_report_series_game(&1, &true);
+    assert_contract_error(&result, Error::SessionAlreadyFinalized);
+}
+
+#[test]
+fn test_series_rejects_end_game() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_series(&env);
+
+    blendizzard.start_series(&game, &1, &player1, &player2, &WAGER, &WAGER, &5);
+
+    let result = blendizzard.try_end_game(&1, &true);
+    assert_contract_error(&result, Error::InvalidSessionState);
+}
+
+#[test]
+fn test_series_validation() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_series(&env);
+
+    for best_of in [0u32, 2, 11] {
+        let result =
+            blendizzard.try_start_series(&game, &1, &player1, &player2, &WAGER, &WAGER, &best_of);
+        assert_contract_error(&result, Error::InvalidSeriesLength);
+    }
+
+    // Regular sessions aren't series
+    blendizzard.start_game(&game, &2, &player1, &player2, &WAGER, &WAGER);
+    let result = blendizzard.try_report_series_game(&2, &true);
+    assert_contract_error(&result, Error::SeriesNotFound);
+}
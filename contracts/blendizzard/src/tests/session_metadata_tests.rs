@@ -0,0 +1,101 @@
+/// Session Metadata Tests
+///
+/// Tests that games can attach opaque match details to a session at start and
+/// with its result, and that oversized metadata is rejected.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::SessionMetadata;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env};
+
+/// Create Blendizzard with a registered game and two funded players in factions 0 and 1
+fn setup_players<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &100_0000000);
+    vault.set_user_balance(&player2, &100_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+#[test]
+fn test_metadata_attached_at_start_and_end() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    let start = Bytes::from_slice(&env, b"seed:42;mode:ranked");
+    let result = Bytes::from_slice(&env, &[7u8; 32]);
+
+    blendizzard.start_game_with_metadata(
+        &game, &1, &player1, &player2, &1_0000000, &1_0000000, &start,
+    );
+    assert_eq!(
+        blendizzard.get_session_metadata(&1),
+        Some(SessionMetadata {
+            start: start.clone(),
+            result: Bytes::new(&env),
+        })
+    );
+
+    blendizzard.end_game_with_metadata(&1, &true, &result);
+    assert_eq!(
+        blendizzard.get_session_metadata(&1),
+        Some(SessionMetadata { start, result })
+    );
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 0);
+
+    // Plain sessions carry no metadata, but results can still attach some
+    blendizzard.start_game(&game, &2, &player1, &player2, &1_0000000, &1_0000000);
+    assert_eq!(blendizzard.get_session_metadata(&2), None);
+    let replay = Bytes::from_slice(&env, b"replay");
+    blendizzard.end_game_with_metadata(&2, &false, &replay);
+    assert_eq!(blendizzard.get_session_metadata(&2).unwrap().result, replay);
+}
+
+#[test]
+fn test_oversized_metadata_is_rejected() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_players(&env);
+    let oversized = Bytes::from_slice(&env, &[0u8; 257]);
+
+    assert_contract_error(
+        &blendizzard.try_start_game_with_metadata(
+            &game, &1, &player1, &player2, &1_0000000, &1_0000000, &oversized,
+        ),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.start_game_with_metadata(
+        &game,
+        &1,
+        &player1,
+        &player2,
+        &1_0000000,
+        &1_0000000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+    );
+    assert_contract_error(
+        &blendizzard.try_end_game_with_metadata(&1, &true, &oversized),
+        Error::InvalidConfig,
+    );
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 1);
+}
@@ -0,0 +1,234 @@
+/// Session ID Tests
+///
+/// Tests global session ID uniqueness (including after a session entry expires),
+/// nonce-derived session IDs, rejection of double-finalized sessions and
+/// per-game wager limits.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::storage::DataKey;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard with a registered game and two funded players
+fn setup_game<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    (blendizzard, game, player1, player2)
+}
+
+// ============================================================================
+// Uniqueness Tests
+// ============================================================================
+
+#[test]
+fn test_session_id_unique_across_games() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+    let other_game = Address::generate(&env);
+    blendizzard.add_game(&other_game, &Address::generate(&env));
+
+    blendizzard.start_game(&game, &7, &player1, &player2, &10_0000000, &10_0000000);
+
+    let result = blendizzard.try_start_game(
+        &other_game,
+        &7,
+        &player1,
+        &player2,
+        &10_0000000,
+        &10_0000000,
+    );
+    assert_contract_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_session_id_not_reusable_after_session_expires() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+
+    blendizzard.start_game(&game, &7, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&7, &true);
+
+    // Simulate the temporary session entry expiring
+    env.as_contract(&blendizzard.address, || {
+        env.storage().temporary().remove(&DataKey::Session(7));
+    });
+
+    let result =
+        blendizzard.try_start_game(&game, &7, &player1, &player2, &10_0000000, &10_0000000);
+    assert_contract_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_end_game_twice_rejected() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+
+    blendizzard.start_game(&game, &7, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.end_game(&7, &true);
+
+    let result = blendizzard.try_end_game(&7, &false);
+    assert_contract_error(&result, Error::SessionAlreadyFinalized);
+}
+
+// ============================================================================
+// Nonce-Derived Session ID Tests
+// ============================================================================
+
+#[test]
+fn test_start_game_with_nonce_derives_ids() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+
+    assert_eq!(blendizzard.get_game_nonce(&game), 0);
+
+    let expected = blendizzard.next_session_id(&game);
+    let session1 =
+        blendizzard.start_game_with_nonce(&game, &player1, &player2, &10_0000000, &10_0000000);
+    assert_eq!(session1, expected);
+    assert_eq!(blendizzard.get_game_nonce(&game), 1);
+
+    let session2 =
+        blendizzard.start_game_with_nonce(&game, &player1, &player2, &10_0000000, &10_0000000);
+    assert_ne!(session1, session2);
+    assert_eq!(blendizzard.get_game_nonce(&game), 2);
+
+    // Derived sessions are regular sessions
+    blendizzard.end_game(&session1, &true);
+    blendizzard.end_game(&session2, &false);
+}
+
+#[test]
+fn test_start_game_with_nonce_skips_used_ids() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+
+    // Another caller-supplied session squats on the next derived ID
+    let squatted = blendizzard.next_session_id(&game);
+    blendizzard.start_game(
+        &game,
+        &squatted,
+        &player1,
+        &player2,
+        &10_0000000,
+        &10_0000000,
+    );
+
+    let session =
+        blendizzard.start_game_with_nonce(&game, &player1, &player2, &10_0000000, &10_0000000);
+    assert_ne!(session, squatted);
+    assert_eq!(blendizzard.get_game_nonce(&game), 2);
+}
+
+// ============================================================================
+// Active Session Limit Tests
+// ============================================================================
+
+#[test]
+fn test_active_session_cap() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+    blendizzard.set_max_active_sessions(&2);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+    blendizzard.start_game(&game, &2, &player1, &player2, &10_0000000, &10_0000000);
+    assert_eq!(blendizzard.get_active_sessions(&player1), vec![&env, 1, 2]);
+
+    let result =
+        blendizzard.try_start_game(&game, &3, &player1, &player2, &10_0000000, &10_0000000);
+    assert_contract_error(&result, Error::TooManyActiveSessions);
+
+    // Ending a session frees a slot
+    blendizzard.end_game(&1, &true);
+    assert_eq!(blendizzard.get_active_sessions(&player2), vec![&env, 2]);
+    blendizzard.start_game(&game, &3, &player1, &player2, &10_0000000, &10_0000000);
+    assert_eq!(blendizzard.get_active_sessions(&player1), vec![&env, 2, 3]);
+}
+
+#[test]
+fn test_sessions_from_previous_epochs_not_active() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+    blendizzard.set_max_active_sessions(&1);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &10_0000000);
+
+    // A stalled session from an earlier epoch can never be ended,
+    // so it doesn't count against the cap
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_current_epoch(&env, 1);
+    });
+    assert_eq!(blendizzard.get_active_sessions(&player1).len(), 0);
+}
+
+// ============================================================================
+// Wager Limit Tests
+// ============================================================================
+
+#[test]
+fn test_game_wager_limits_enforced() {
+    let env = setup_test_env();
+    let (blendizzard, game, player1, player2) = setup_game(&env);
+
+    blendizzard.set_game_wager_limits(&game, &5_0000000, &20_0000000);
+    let info = blendizzard.get_game_info(&game);
+    assert_eq!(info.min_wager, 5_0000000);
+    assert_eq!(info.max_wager, 20_0000000);
+
+    let result = blendizzard.try_start_game(&game, &1, &player1, &player2, &4_0000000, &10_0000000);
+    assert_contract_error(&result, Error::InvalidAmount);
+
+    let result =
+        blendizzard.try_start_game(&game, &1, &player1, &player2, &10_0000000, &20_0000001);
+    assert_contract_error(&result, Error::InvalidAmount);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &5_0000000, &20_0000000);
+
+    // Re-registering the game keeps its limits
+    blendizzard.add_game(&game, &Address::generate(&env));
+    assert_eq!(blendizzard.get_game_info(&game).min_wager, 5_0000000);
+}
+
+#[test]
+fn test_set_game_wager_limits_validation() {
+    let env = setup_test_env();
+    let (blendizzard, game, _player1, _player2) = setup_game(&env);
+
+    assert_contract_error(
+        &blendizzard.try_set_game_wager_limits(&game, &-1, &0),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_wager_limits(&game, &10_0000000, &5_0000000),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_game_wager_limits(&Address::generate(&env), &0, &0),
+        Error::GameNotWhitelisted,
+    );
+
+    // Minimum only (no maximum)
+    blendizzard.set_game_wager_limits(&game, &10_0000000, &0);
+}
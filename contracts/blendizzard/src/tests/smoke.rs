@@ -1,6 +1,6 @@
 use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::Address;
+use soroban_sdk::{vec, Address, String};
 
 // ============================================================================
 // Initialization Tests
@@ -19,6 +19,53 @@ fn test_initialization() {
     assert_eq!(retrieved_admin, admin, "Admin address mismatch");
 }
 
+#[test]
+fn test_get_full_config() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let client = create_test_blendizzard(&env, &admin);
+
+    client.pause();
+    let full = client.get_full_config();
+    let config = client.get_config();
+
+    assert_eq!(
+        full.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(full.admin, admin);
+    assert!(full.paused);
+    assert_eq!(full.current_epoch, 0);
+    assert_eq!(
+        full.swap_path,
+        vec![&env, config.blnd_token.clone(), config.usdc_token.clone()]
+    );
+    assert_eq!(full.config, config);
+}
+
+#[test]
+fn test_event_sequence_numbers() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let client = create_test_blendizzard(&env, &admin);
+    let player = Address::generate(&env);
+
+    let start = client.get_event_seq();
+    assert_eq!(client.get_user_event_seq(&player), 0);
+
+    // Admin events advance only the global counter
+    client.pause();
+    client.unpause();
+    assert_eq!(client.get_event_seq(), start + 2);
+    assert_eq!(client.get_user_event_seq(&player), 0);
+
+    // Player-keyed events advance both
+    client.select_faction(&player, &0);
+    client.select_faction(&player, &1);
+    assert_eq!(client.get_event_seq(), start + 4);
+    assert_eq!(client.get_user_event_seq(&player), 2);
+}
+
 // ============================================================================
 // Faction Selection Tests
 // ============================================================================
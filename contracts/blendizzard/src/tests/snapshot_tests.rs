@@ -0,0 +1,108 @@
+/// Balance Snapshot Tests
+///
+/// Tests that with balance snapshots enabled, FP is based on the balance held
+/// at the start of the epoch (reconstructed from lazy checkpoints) rather than
+/// the live vault balance.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error,
+    DEFAULT_FREE_FP_PER_EPOCH,
+};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+fn setup<'a>(env: &'a Env) -> (BlendizzardClient<'a>, MockVaultClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    (blendizzard, MockVaultClient::new(env, &vault_addr))
+}
+
+/// Move to the next epoch without cycling (no swap infrastructure in these tests)
+fn advance_epoch(env: &Env, blendizzard: &BlendizzardClient) {
+    env.as_contract(&blendizzard.address, || {
+        let current = crate::storage::get_current_epoch(env);
+        let epoch_info = crate::storage::get_epoch(env, current).unwrap();
+        crate::storage::set_epoch(env, current + 1, &epoch_info);
+        crate::storage::set_current_epoch(env, current + 1);
+    });
+}
+
+#[test]
+fn test_deposits_count_from_next_epoch() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup(&env);
+    blendizzard.set_balance_snapshots(&true);
+
+    let player = Address::generate(&env);
+    vault.set_user_balance(&player, &1000_0000000);
+    blendizzard.select_faction(&player, &0);
+
+    // Deposit was first observed this epoch: only free FP
+    let epoch0 = blendizzard.get_epoch_player(&0, &player);
+    assert_eq!(epoch0.available_fp, DEFAULT_FREE_FP_PER_EPOCH);
+    assert_eq!(epoch0.epoch_balance_snapshot, 0);
+
+    // Next epoch: the balance held at the boundary counts, a new deposit doesn't
+    advance_epoch(&env, &blendizzard);
+    vault.set_user_balance(&player, &5000_0000000);
+    assert_eq!(blendizzard.checkpoint_balance(&player), 1000_0000000);
+    let epoch1 = blendizzard.get_epoch_player(&1, &player);
+    assert_eq!(epoch1.epoch_balance_snapshot, 1000_0000000);
+    assert!(epoch1.available_fp > DEFAULT_FREE_FP_PER_EPOCH);
+
+    // Withdrawals still lower the balance immediately
+    vault.set_user_balance(&player, &500_0000000);
+    assert_eq!(blendizzard.checkpoint_balance(&player), 500_0000000);
+}
+
+#[test]
+fn test_snapshots_disabled_use_live_balance() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup(&env);
+
+    let player = Address::generate(&env);
+    vault.set_user_balance(&player, &1000_0000000);
+    blendizzard.select_faction(&player, &0);
+
+    let epoch0 = blendizzard.get_epoch_player(&0, &player);
+    assert_eq!(epoch0.epoch_balance_snapshot, 1000_0000000);
+    assert!(epoch0.available_fp > DEFAULT_FREE_FP_PER_EPOCH);
+
+    assert_eq!(blendizzard.get_balance_checkpoint(&player), None);
+    assert_contract_error(
+        &blendizzard.try_checkpoint_balance(&player),
+        Error::FeatureNotConfigured,
+    );
+}
+
+#[test]
+fn test_fp_balance_capped_at_per_user_cap() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup(&env);
+    blendizzard.set_deposit_caps(&1000_0000000, &0);
+
+    // A direct fee-vault deposit above the cap can't be refused, but the
+    // excess earns no FP
+    let capped = Address::generate(&env);
+    let over = Address::generate(&env);
+    vault.set_user_balance(&capped, &1000_0000000);
+    vault.set_user_balance(&over, &5000_0000000);
+    blendizzard.select_faction(&capped, &0);
+    blendizzard.select_faction(&over, &0);
+
+    let capped_player = blendizzard.get_epoch_player(&0, &capped);
+    let over_player = blendizzard.get_epoch_player(&0, &over);
+    assert_eq!(over_player.epoch_balance_snapshot, 1000_0000000);
+    assert_eq!(over_player.available_fp, capped_player.available_fp);
+}
@@ -0,0 +1,76 @@
+/// Player Stats Tests
+///
+/// Tests per-(player, game) lifetime stats recorded in end_game.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{create_blendizzard_contract, setup_test_env};
+use crate::types::PlayerGameStats;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_end_game_records_player_stats() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let other_game = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.add_game(&other_game, &Address::generate(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // No games yet
+    assert_eq!(blendizzard.get_player_stats(&player1, &game).wins, 0);
+    assert_eq!(blendizzard.get_player_stats_all(&player1).len(), 0);
+
+    blendizzard.start_game(&game, &1, &player1, &player2, &10_0000000, &20_0000000);
+    blendizzard.end_game(&1, &true);
+    blendizzard.start_game(&game, &2, &player1, &player2, &5_0000000, &5_0000000);
+    blendizzard.end_game(&2, &false);
+    blendizzard.start_game(&other_game, &3, &player1, &player2, &1_0000000, &1_0000000);
+    blendizzard.end_game(&3, &true);
+
+    assert_eq!(
+        blendizzard.get_player_stats(&player1, &game),
+        PlayerGameStats {
+            wins: 1,
+            losses: 1,
+            total_wagered: 15_0000000,
+            total_won: 10_0000000,
+        }
+    );
+    assert_eq!(
+        blendizzard.get_player_stats(&player2, &game),
+        PlayerGameStats {
+            wins: 1,
+            losses: 1,
+            total_wagered: 25_0000000,
+            total_won: 5_0000000,
+        }
+    );
+
+    let all_stats = blendizzard.get_player_stats_all(&player1);
+    assert_eq!(all_stats.len(), 2);
+    assert_eq!(all_stats.get(other_game.clone()).unwrap().wins, 1);
+    assert_eq!(
+        all_stats.get(game.clone()).unwrap().total_wagered,
+        15_0000000
+    );
+}
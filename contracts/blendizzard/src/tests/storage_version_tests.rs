@@ -0,0 +1,88 @@
+/// Storage Version Tests
+///
+/// Tests the versioned storage layout: new deployments start at the current
+/// version, and `migrate_storage` runs the v1 -> v2 step once for contracts
+/// deployed before versioning.
+use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
+use crate::storage::{DataKey, ExtDataKey, STORAGE_VERSION};
+use crate::types::Player;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+fn legacy_player() -> Player {
+    Player {
+        selected_faction: 1,
+        time_multiplier_start: 1_000,
+        last_epoch_balance: 50_0000000,
+        streak: 2,
+        last_active_epoch: 0,
+    }
+}
+
+#[test]
+fn test_new_deployment_is_current() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_eq!(blendizzard.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(blendizzard.migrate_storage(&vec![&env]), 0);
+    assert_eq!(blendizzard.get_admin_log(&0, &50).len(), 0);
+}
+
+#[test]
+fn test_migrate_v1_packs_given_users() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let migrated_user = Address::generate(&env);
+    let later_user = Address::generate(&env);
+
+    // Layout of a contract deployed before versioning
+    env.as_contract(&blendizzard.address, || {
+        env.storage().instance().remove(&ExtDataKey::StorageVersion);
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Player(migrated_user.clone()), &legacy_player());
+        storage.set(&DataKey::Player(later_user.clone()), &legacy_player());
+    });
+    assert_eq!(blendizzard.get_storage_version(), 1);
+
+    assert_eq!(
+        blendizzard.migrate_storage(&vec![&env, migrated_user.clone()]),
+        1
+    );
+    assert_eq!(blendizzard.get_storage_version(), STORAGE_VERSION);
+    env.as_contract(&blendizzard.address, || {
+        let storage = env.storage().persistent();
+        assert!(storage.has(&DataKey::User(migrated_user.clone())));
+        assert!(!storage.has(&DataKey::Player(migrated_user.clone())));
+        assert!(storage.has(&DataKey::Player(later_user.clone())));
+    });
+
+    // Users left out are still read from the legacy entries
+    assert_eq!(blendizzard.get_player(&later_user), legacy_player());
+
+    // The step runs once
+    assert_eq!(
+        blendizzard.migrate_storage(&vec![&env, later_user.clone()]),
+        0
+    );
+    assert!(blendizzard.migrate_user_data(&later_user));
+}
+
+#[test]
+fn test_migrate_rejects_newer_layout() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    env.as_contract(&blendizzard.address, || {
+        env.storage()
+            .instance()
+            .set(&ExtDataKey::StorageVersion, &(STORAGE_VERSION + 1));
+    });
+    assert_contract_error(
+        &blendizzard.try_migrate_storage(&vec![&env]),
+        Error::InvalidConfig,
+    );
+}
@@ -0,0 +1,219 @@
+/// Participation Streak Tests
+///
+/// Tests consecutive-epoch streak tracking and the streak bonus applied to
+/// reward weights at claim time.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::create_token;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_blendizzard_with_soroswap,
+    create_test_blendizzard, setup_test_env, Error,
+};
+use crate::streak::next_streak;
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer, Player, SCALAR_7};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Map};
+
+fn player_with_streak(streak: u32, last_active_epoch: u32) -> Player {
+    Player {
+        selected_faction: 0,
+        time_multiplier_start: 0,
+        last_epoch_balance: 0,
+        streak,
+        last_active_epoch,
+    }
+}
+
+// ============================================================================
+// Streak Math Tests
+// ============================================================================
+
+#[test]
+fn test_next_streak_counts_consecutive_epochs() {
+    // First game ever
+    assert_eq!(next_streak(&player_with_streak(0, 0), 0), 1);
+    assert_eq!(next_streak(&player_with_streak(0, 0), 5), 1);
+
+    // Same epoch: unchanged
+    assert_eq!(next_streak(&player_with_streak(3, 7), 7), 3);
+
+    // Next epoch: extended
+    assert_eq!(next_streak(&player_with_streak(3, 7), 8), 4);
+
+    // Skipped an epoch: restarted
+    assert_eq!(next_streak(&player_with_streak(3, 7), 9), 1);
+}
+
+#[test]
+fn test_streak_bonus_is_capped() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    // +5% per consecutive epoch, up to +50%
+    blendizzard.set_streak_bonus(&500_000, &5_000_000);
+    let config = blendizzard.get_config();
+
+    assert_eq!(crate::streak::streak_bonus(&config, 1), 0);
+    assert_eq!(crate::streak::streak_bonus(&config, 2), 500_000);
+    assert_eq!(crate::streak::streak_bonus(&config, 11), 5_000_000);
+    assert_eq!(crate::streak::streak_bonus(&config, 50), 5_000_000);
+}
+
+#[test]
+fn test_set_streak_bonus_rejects_invalid_values() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let result = blendizzard.try_set_streak_bonus(&-1, &5_000_000);
+    assert_contract_error(&result, Error::InvalidConfig);
+
+    let result = blendizzard.try_set_streak_bonus(&500_000, &(SCALAR_7 + 1));
+    assert_contract_error(&result, Error::InvalidConfig);
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_streak_tracked_across_epochs() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let game = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    blendizzard.add_game(&game, &Address::generate(&env));
+    blendizzard.set_streak_bonus(&500_000, &5_000_000);
+
+    let vault = MockVaultClient::new(&env, &blendizzard.get_config().fee_vault);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    vault.set_user_balance(&player1, &1000_0000000);
+    vault.set_user_balance(&player2, &1000_0000000);
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    let epoch_duration = 345_600;
+    let mut session_id = 0;
+    let mut play = |epoch: u32| {
+        session_id += 1;
+        blendizzard.start_game(
+            &game,
+            &session_id,
+            &player1,
+            &player2,
+            &10_0000000,
+            &10_0000000,
+        );
+        blendizzard.end_game(&session_id, &true);
+        blendizzard.get_epoch_player(&epoch, &player1).streak_bonus
+    };
+    let mut cycle = || {
+        env.ledger()
+            .with_mut(|li| li.timestamp += epoch_duration + 1);
+        blendizzard.cycle_epoch();
+    };
+
+    // Epoch 0: first epoch, no bonus
+    assert_eq!(play(0), 0);
+    assert_eq!(blendizzard.get_player(&player1).streak, 1);
+
+    // Epoch 1: consecutive, +5%
+    cycle();
+    assert_eq!(play(1), 500_000);
+    assert_eq!(blendizzard.get_player(&player1).streak, 2);
+
+    // Epoch 2 skipped, epoch 3 restarts the streak
+    cycle();
+    cycle();
+    assert_eq!(play(3), 0);
+
+    let player_data = blendizzard.get_player(&player1);
+    assert_eq!(player_data.streak, 1);
+    assert_eq!(player_data.last_active_epoch, 3);
+}
+
+// ============================================================================
+// Claim Tests
+// ============================================================================
+
+#[test]
+fn test_streak_bonus_boosts_claim_share() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let vault_addr = create_mock_vault(&env);
+    let vault = MockVaultClient::new(&env, &vault_addr);
+    let usdc = create_token(&env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &vault_addr,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &usdc.address,
+        345_600,
+        vec![&env, 1],
+    );
+    let reward_pool = 1000_0000000i128;
+    usdc.mint(&blendizzard.address, &reward_pool);
+
+    // Equal contributions, but player1 has a +50% streak bonus
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let fp = 100_0000000i128;
+    let bonus = 5_000_000i128;
+
+    let mut faction_standings = Map::new(&env);
+    faction_standings.set(0, 2 * fp);
+    let mut faction_weighted_standings = Map::new(&env);
+    faction_weighted_standings.set(0, fp * (SCALAR_7 + bonus) + fp * SCALAR_7);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_standings,
+        reward_pool,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: 2 * fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_weighted_standings,
+        faction_sqrt_standings: Map::new(&env),
+        faction_leaders: Map::new(&env),
+        faction_multipliers: Map::new(&env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(&env),
+        faction_stakes: Map::new(&env),
+        faction_rakes: Map::new(&env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(&env, 0, &epoch_info);
+        for (player, streak_bonus) in [(&player1, bonus), (&player2, 0)] {
+            crate::storage::set_epoch_player(
+                &env,
+                0,
+                player,
+                &EpochPlayer {
+                    epoch_faction: Some(0),
+                    epoch_balance_snapshot: 10_0000000,
+                    available_fp: 0,
+                    total_fp_contributed: fp,
+                    streak_bonus,
+                    games_completed: 0,
+                    reward_eligible: true,
+                },
+            );
+        }
+    });
+    vault.set_user_balance(&player1, &10_0000000);
+    vault.set_user_balance(&player2, &10_0000000);
+
+    // Weights 1.5 : 1.0 → 600 : 400
+    assert_eq!(blendizzard.claim_epoch_reward(&player1, &0), 600_0000000);
+    assert_eq!(blendizzard.claim_epoch_reward(&player2, &0), 400_0000000);
+}
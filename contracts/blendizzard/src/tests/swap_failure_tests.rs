@@ -0,0 +1,57 @@
+/// Swap Failure Tests
+///
+/// Tests that cycle_epoch still closes the epoch when the BLND → USDC swap
+/// reverts, banking the BLND as carryover for a later harvest to convert.
+use super::soroswap_utils::TokenClient;
+use super::testutils::{create_blendizzard_with_soroswap, setup_test_env};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+fn set_router(blendizzard: &BlendizzardClient, router: &Address) {
+    blendizzard.update_config(
+        &Some(router.clone()),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+}
+
+fn advance_past_epoch(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 345_601;
+    });
+}
+
+#[test]
+fn test_cycle_banks_blnd_when_router_is_unusable() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_with_soroswap(&env, &admin);
+    let config = blendizzard.get_config();
+    let blnd = TokenClient::new(&env, &config.blnd_token);
+    let blnd_before = blnd.balance(&blendizzard.address);
+    assert!(blnd_before > 0);
+
+    // A router that can't resolve the pair: the cycle still closes the epoch
+    set_router(&blendizzard, &Address::generate(&env));
+    advance_past_epoch(&env);
+    assert_eq!(blendizzard.cycle_epoch(), 1);
+
+    let epoch0 = blendizzard.get_epoch(&0);
+    assert!(epoch0.is_finalized);
+    assert_eq!(epoch0.reward_pool + epoch0.dev_reward_pool, 0);
+    assert_eq!(blendizzard.get_carryover_blnd(), blnd_before);
+    assert_eq!(blnd.balance(&blendizzard.address), blnd_before);
+
+    // Once the router works again, a harvest converts the banked BLND
+    set_router(&blendizzard, &config.soroswap_router);
+    let harvested = blendizzard.harvest();
+    assert!(harvested > 0);
+    assert_eq!(blendizzard.get_pending_rewards(), harvested);
+    assert_eq!(blendizzard.get_carryover_blnd(), 0);
+    assert_eq!(blnd.balance(&blendizzard.address), 0);
+}
@@ -0,0 +1,114 @@
+/// Team Game Tests
+///
+/// Tests 2v2 team sessions: same-faction team validation, per-member FP
+/// locking, and crediting each winner's wager to the winning faction.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+const WAGER: i128 = 10_0000000;
+
+/// Create Blendizzard with a registered game and four funded players
+/// (the first two in faction 0, the last two in faction 1)
+fn setup_team_game<'a>(env: &'a Env) -> (BlendizzardClient<'a>, Address, Vec<Address>) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let mut players = Vec::new(env);
+    for faction in [0u32, 0, 1, 1] {
+        let player = Address::generate(env);
+        vault.set_user_balance(&player, &1000_0000000);
+        blendizzard.select_faction(&player, &faction);
+        players.push_back(player);
+    }
+
+    (blendizzard, game, players)
+}
+
+#[test]
+fn test_team_game_credits_each_winner() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup_team_game(&env);
+    let team_a = vec![&env, players.get(0).unwrap(), players.get(1).unwrap()];
+    let team_b = vec![&env, players.get(2).unwrap(), players.get(3).unwrap()];
+
+    blendizzard.start_team_game(&game, &5, &team_a, &team_b, &WAGER);
+
+    let session = blendizzard.get_team_session(&5);
+    assert_eq!(session.team_a_faction, 0);
+    assert_eq!(session.team_b_faction, 1);
+    assert_eq!(session.team_a_won, None);
+    for player in players.iter() {
+        assert_eq!(blendizzard.get_active_sessions(&player), vec![&env, 5]);
+    }
+
+    blendizzard.end_team_game(&5, &true);
+
+    assert_eq!(blendizzard.get_team_session(&5).team_a_won, Some(true));
+    for player in players.iter() {
+        assert_eq!(blendizzard.get_active_sessions(&player).len(), 0);
+    }
+    for winner in team_a.iter() {
+        let epoch_player = blendizzard.get_epoch_player(&0, &winner);
+        assert_eq!(epoch_player.total_fp_contributed, WAGER);
+    }
+    for loser in team_b.iter() {
+        let epoch_player = blendizzard.get_epoch_player(&0, &loser);
+        assert_eq!(epoch_player.total_fp_contributed, 0);
+    }
+
+    let standings = env.as_contract(&blendizzard.address, || {
+        crate::storage::get_epoch(&env, 0)
+            .unwrap()
+            .faction_standings
+    });
+    assert_eq!(standings.get(0).unwrap_or(0), 2 * WAGER);
+    assert_eq!(standings.get(1).unwrap_or(0), 0);
+
+    let result = blendizzard.try_end_team_game(&5, &false);
+    assert_contract_error(&result, Error::SessionAlreadyFinalized);
+}
+
+#[test]
+fn test_team_game_rejects_mixed_faction_team() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup_team_game(&env);
+    let team_a = vec![&env, players.get(0).unwrap(), players.get(2).unwrap()];
+    let team_b = vec![&env, players.get(1).unwrap(), players.get(3).unwrap()];
+
+    let result = blendizzard.try_start_team_game(&game, &5, &team_a, &team_b, &WAGER);
+    assert_contract_error(&result, Error::InvalidTeam);
+}
+
+#[test]
+fn test_team_game_rejects_invalid_team_shapes() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup_team_game(&env);
+
+    // Uneven teams
+    let team_a = vec![&env, players.get(0).unwrap(), players.get(1).unwrap()];
+    let team_b = vec![&env, players.get(2).unwrap()];
+    let result = blendizzard.try_start_team_game(&game, &5, &team_a, &team_b, &WAGER);
+    assert_contract_error(&result, Error::InvalidTeam);
+
+    // Same player on both teams
+    let team_b = vec![&env, players.get(2).unwrap(), players.get(0).unwrap()];
+    let result = blendizzard.try_start_team_game(&game, &5, &team_a, &team_b, &WAGER);
+    assert_contract_error(&result, Error::InvalidTeam);
+}
@@ -1,107 +1,20 @@
-use crate::{Blendizzard, BlendizzardClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{vec, Address, Env, Vec};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Map};
 
-// Re-export Error for test usage
-pub use crate::errors::Error;
+// Shared fixtures (also exported to downstream crates via the `testutils` feature)
+pub use crate::testutils::{
+    create_blendizzard_contract, create_blendizzard_contract_with_clock,
+    create_blendizzard_contract_with_dev_share, create_blendizzard_contract_with_free_play,
+    setup_test_env, DEFAULT_FREE_FP_PER_EPOCH, DEFAULT_MIN_DEPOSIT_TO_CLAIM,
+};
+
+// Re-export Error (and ExtError, for codes beyond Error's 50 cases) for test usage
+pub use crate::errors::{ContractError, Error, ExtError};
 
 // Re-export number_guess Error as NumberGuessError to avoid conflicts
 pub use number_guess::Error as NumberGuessError;
 
-/// Default free FP per epoch for tests (100 FP with 7 decimals)
-pub const DEFAULT_FREE_FP_PER_EPOCH: i128 = 100_0000000;
-
-/// Default minimum deposit to claim for tests (1 USDC with 7 decimals)
-pub const DEFAULT_MIN_DEPOSIT_TO_CLAIM: i128 = 1_0000000;
-
-/// Register and initialize the Blendizzard contract
-#[allow(clippy::too_many_arguments)]
-pub fn create_blendizzard_contract<'a>(
-    env: &Env,
-    admin: &Address,
-    fee_vault: &Address,
-    soroswap_router: &Address,
-    blnd_token: &Address,
-    usdc_token: &Address,
-    epoch_duration: u64,
-    reserve_token_ids: Vec<u32>,
-) -> BlendizzardClient<'a> {
-    create_blendizzard_contract_with_free_play(
-        env,
-        admin,
-        fee_vault,
-        soroswap_router,
-        blnd_token,
-        usdc_token,
-        epoch_duration,
-        reserve_token_ids,
-        DEFAULT_FREE_FP_PER_EPOCH,
-        DEFAULT_MIN_DEPOSIT_TO_CLAIM,
-    )
-}
-
-/// Register and initialize the Blendizzard contract with custom free play settings
-#[allow(clippy::too_many_arguments)]
-pub fn create_blendizzard_contract_with_free_play<'a>(
-    env: &Env,
-    admin: &Address,
-    fee_vault: &Address,
-    soroswap_router: &Address,
-    blnd_token: &Address,
-    usdc_token: &Address,
-    epoch_duration: u64,
-    reserve_token_ids: Vec<u32>,
-    free_fp_per_epoch: i128,
-    min_deposit_to_claim: i128,
-) -> BlendizzardClient<'a> {
-    create_blendizzard_contract_with_dev_share(
-        env,
-        admin,
-        fee_vault,
-        soroswap_router,
-        blnd_token,
-        usdc_token,
-        epoch_duration,
-        reserve_token_ids,
-        free_fp_per_epoch,
-        min_deposit_to_claim,
-        1_000_000, // 10% dev_reward_share
-    )
-}
-
-/// Register and initialize the Blendizzard contract with custom dev reward share
-#[allow(clippy::too_many_arguments)]
-pub fn create_blendizzard_contract_with_dev_share<'a>(
-    env: &Env,
-    admin: &Address,
-    fee_vault: &Address,
-    soroswap_router: &Address,
-    blnd_token: &Address,
-    usdc_token: &Address,
-    epoch_duration: u64,
-    reserve_token_ids: Vec<u32>,
-    free_fp_per_epoch: i128,
-    min_deposit_to_claim: i128,
-    dev_reward_share: i128,
-) -> BlendizzardClient<'a> {
-    let contract_address = env.register(
-        Blendizzard,
-        (
-            admin.clone(),
-            fee_vault.clone(),
-            soroswap_router.clone(),
-            blnd_token.clone(),
-            usdc_token.clone(),
-            epoch_duration,
-            reserve_token_ids,
-            free_fp_per_epoch,
-            min_deposit_to_claim,
-            dev_reward_share,
-        ),
-    );
-    BlendizzardClient::new(env, &contract_address)
-}
-
 /// Create a simple Blendizzard contract with mock addresses for quick testing
 ///
 /// Note: Assumes env already has mock_all_auths() called (via setup_test_env())
@@ -210,30 +123,15 @@ pub fn create_blendizzard_with_soroswap<'a>(env: &Env, admin: &Address) -> Blend
     blendizzard_client
 }
 
-/// Standard test environment setup
-pub fn setup_test_env() -> Env {
-    use soroban_sdk::testutils::LedgerInfo;
-
-    let env = Env::default();
-
-    // Set full ledger info (pattern from blend-together)
-    env.ledger().set(LedgerInfo {
-        timestamp: 1441065600, // Sept 1st, 2015 12:00:00 AM UTC
-        protocol_version: 23,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
-
-    env.mock_all_auths();
-
-    // Reset budget for complex fee-vault operations
-    env.cost_estimate().budget().reset_unlimited();
-
-    env
+/// Reward weights for faction standings of players with no streak bonus
+///
+/// Use as `EpochInfo.faction_weighted_standings` in hand-built epoch fixtures.
+pub fn unboosted_weights(env: &Env, faction_standings: &Map<u32, i128>) -> Map<u32, i128> {
+    let mut weights = Map::new(env);
+    for (faction, fp) in faction_standings.iter() {
+        weights.set(faction, fp * crate::types::SCALAR_7);
+    }
+    weights
 }
 
 // ============================================================================
@@ -252,6 +150,9 @@ pub fn setup_test_env() -> Env {
 /// ```
 /// let result = blendizzard.try_start_game(...);
 /// assert_contract_error(&result, Error::InsufficientFactionPoints);
+///
+/// let result = blendizzard.try_migrate_vault(&new_vault);
+/// assert_contract_error(&result, ExtError::VaultMigrationNotQueued);
 /// ```
 ///
 /// # Type Signature
@@ -261,45 +162,60 @@ pub fn setup_test_env() -> Env {
 /// - Err(Ok(error)): Contract reverted with custom error (THIS IS WHAT WE TEST)
 /// - Err(Err(invoke_err)): Low-level invocation failure
 ///
+/// `E` is `Error`, `ExtError` or `ContractError`, depending on the entrypoint.
+///
 /// # Pattern Reference
 /// Based on error testing patterns from:
 /// - soroswap/core: Uses Err(Ok(ErrorType::SpecificError))
 /// - fee-vault-v2: Uses try_ methods with error code assertions
 /// - blend-contracts-v2: Uses #[should_panic] with error codes
 /// - stellar/soroban-examples: Uses try_ methods with error enums
-pub fn assert_contract_error<T, E>(
-    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
-    expected_error: Error,
-) {
+pub fn assert_contract_error<T, E, C>(
+    result: &Result<Result<T, E>, Result<C, soroban_sdk::InvokeError>>,
+    expected_error: impl Into<ContractError>,
+) where
+    C: Copy + Into<ContractError>,
+{
+    let expected_error = expected_error.into();
+    let expected_code = error_code(expected_error);
     match result {
         Err(Ok(actual_error)) => {
+            let actual_error = (*actual_error).into();
             assert_eq!(
-                *actual_error, expected_error,
+                actual_error,
+                expected_error,
                 "Expected error {:?} (code {}), but got {:?} (code {})",
-                expected_error, expected_error as u32, actual_error, *actual_error as u32
+                expected_error,
+                expected_code,
+                actual_error,
+                error_code(actual_error)
             );
         }
         Err(Err(_invoke_error)) => {
             panic!(
                 "Expected contract error {:?} (code {}), but got invocation error",
-                expected_error, expected_error as u32
+                expected_error, expected_code
             );
         }
         Ok(Err(_conv_error)) => {
             panic!(
                 "Expected contract error {:?} (code {}), but got conversion error",
-                expected_error, expected_error as u32
+                expected_error, expected_code
             );
         }
         Ok(Ok(_)) => {
             panic!(
                 "Expected error {:?} (code {}), but operation succeeded",
-                expected_error, expected_error as u32
+                expected_error, expected_code
             );
         }
     }
 }
 
+fn error_code(error: ContractError) -> u32 {
+    soroban_sdk::Error::from(error).get_code()
+}
+
 /// Assert that a Result contains a specific number_guess contract error
 ///
 /// This helper provides type-safe error assertions for the number_guess contract,
@@ -0,0 +1,203 @@
+/// Faction Treasury Tests
+///
+/// Tests the treasury share taken from closed epochs' reward pools and the
+/// stake-weighted proposals members use to spend their faction's treasury.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::types::{FactionTreasuries, TreasurySpend, SCALAR_7};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Map};
+
+const TREASURY: i128 = 100_0000000;
+const WAGER: i128 = 10_0000000;
+
+struct Setup<'a> {
+    blendizzard: BlendizzardClient<'a>,
+    vault: MockVaultClient<'a>,
+    usdc: TokenClient<'a>,
+    game: Address,
+}
+
+/// Create Blendizzard with a funded faction 0 treasury
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    usdc.mint(&blendizzard.address, &TREASURY);
+    env.as_contract(&blendizzard.address, || {
+        let mut balances = Map::new(env);
+        balances.set(0, TREASURY);
+        crate::storage::set_faction_treasuries(
+            env,
+            &FactionTreasuries {
+                balances,
+                next_proposal_id: 0,
+            },
+        );
+    });
+
+    Setup {
+        blendizzard,
+        vault: MockVaultClient::new(env, &vault_addr),
+        usdc,
+        game,
+    }
+}
+
+/// Start a session between a faction 0 player and a faction 1 player
+fn play(env: &Env, s: &Setup, session_id: u32, balance: i128) -> (Address, Address) {
+    let member = Address::generate(env);
+    let opponent = Address::generate(env);
+    s.vault.set_user_balance(&member, &balance);
+    s.vault.set_user_balance(&opponent, &1000_0000000);
+    s.blendizzard.select_faction(&member, &0);
+    s.blendizzard.select_faction(&opponent, &1);
+    s.blendizzard
+        .start_game(&s.game, &session_id, &member, &opponent, &WAGER, &WAGER);
+    (member, opponent)
+}
+
+fn end_epoch(env: &Env, s: &Setup) {
+    env.ledger().with_mut(|li| li.timestamp += 345_600);
+    s.blendizzard.cycle_epoch();
+}
+
+#[test]
+fn test_treasury_share_split_by_standings() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    s.blendizzard.set_faction_treasury_share(&(SCALAR_7 / 10));
+
+    env.as_contract(&s.blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        let mut standings = Map::new(&env);
+        standings.set(0, 300_0000000i128);
+        standings.set(1, 100_0000000i128);
+
+        let funded =
+            crate::treasury::fund_treasuries(&env, &config, 0, 1000_0000000, &standings).unwrap();
+        assert_eq!(funded, 100_0000000);
+    });
+
+    assert_eq!(
+        s.blendizzard.get_faction_treasury(&0),
+        TREASURY + 75_0000000
+    );
+    assert_eq!(s.blendizzard.get_faction_treasury(&1), 25_0000000);
+    assert_eq!(s.blendizzard.get_faction_treasury(&2), 0);
+}
+
+#[test]
+fn test_passed_bounty_is_paid_after_the_epoch() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    let (member, opponent) = play(&env, &s, 1, 1000_0000000);
+    let hunter = Address::generate(&env);
+
+    let id = s.blendizzard.propose_treasury_spend(
+        &member,
+        &TreasurySpend::Bounty(hunter.clone()),
+        &40_0000000,
+    );
+    assert_eq!(
+        s.blendizzard.vote_treasury_proposal(&member, &id, &true),
+        1000_0000000
+    );
+
+    // Only the faction's members vote, once each
+    assert_contract_error(
+        &s.blendizzard
+            .try_vote_treasury_proposal(&opponent, &id, &false),
+        ExtError::NotFactionMember,
+    );
+    assert_contract_error(
+        &s.blendizzard
+            .try_vote_treasury_proposal(&member, &id, &true),
+        ExtError::ProposalAlreadyVoted,
+    );
+    assert_contract_error(
+        &s.blendizzard.try_execute_treasury_proposal(&id),
+        ExtError::ProposalVotingOpen,
+    );
+
+    end_epoch(&env, &s);
+    assert!(s.blendizzard.execute_treasury_proposal(&id));
+
+    assert_eq!(s.usdc.balance(&hunter), 40_0000000);
+    assert_eq!(s.blendizzard.get_faction_treasury(&0), 60_0000000);
+    assert!(s.blendizzard.get_treasury_proposal(&id).is_none());
+    assert_contract_error(
+        &s.blendizzard.try_execute_treasury_proposal(&id),
+        ExtError::ProposalNotFound,
+    );
+}
+
+#[test]
+fn test_rejected_boost_leaves_treasury_untouched() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    let (small, _) = play(&env, &s, 1, 100_0000000);
+    let (large, _) = play(&env, &s, 2, 300_0000000);
+
+    let id = s
+        .blendizzard
+        .propose_treasury_spend(&small, &TreasurySpend::Boost, &TREASURY);
+    s.blendizzard.vote_treasury_proposal(&small, &id, &true);
+    s.blendizzard.vote_treasury_proposal(&large, &id, &false);
+
+    end_epoch(&env, &s);
+
+    // Votes close with the epoch
+    assert_contract_error(
+        &s.blendizzard.try_vote_treasury_proposal(&small, &id, &true),
+        ExtError::ProposalVotingClosed,
+    );
+
+    assert!(!s.blendizzard.execute_treasury_proposal(&id));
+    assert_eq!(s.blendizzard.get_faction_treasury(&0), TREASURY);
+    assert_eq!(s.blendizzard.get_pending_rewards(), 0);
+}
+
+#[test]
+fn test_proposal_requires_funded_treasury_and_stake() {
+    let env = setup_test_env();
+    let s = setup(&env);
+    let (member, opponent) = play(&env, &s, 1, 1000_0000000);
+
+    assert_contract_error(
+        &s.blendizzard
+            .try_propose_treasury_spend(&member, &TreasurySpend::Boost, &(TREASURY + 1)),
+        ExtError::InsufficientTreasury,
+    );
+    assert_contract_error(
+        &s.blendizzard
+            .try_propose_treasury_spend(&opponent, &TreasurySpend::Boost, &1),
+        ExtError::InsufficientTreasury,
+    );
+    assert_contract_error(
+        &s.blendizzard.try_propose_treasury_spend(
+            &Address::generate(&env),
+            &TreasurySpend::Boost,
+            &1,
+        ),
+        Error::PlayerNotFound,
+    );
+}
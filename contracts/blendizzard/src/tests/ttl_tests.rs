@@ -0,0 +1,114 @@
+/// Storage TTL Tests
+///
+/// Tests that touched entries are extended per their storage class strategy,
+/// that session entries follow `max_session_ledgers`, and that `bump_entries`
+/// extends arbitrary existing keys.
+use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
+use crate::storage::{
+    DataKey, INSTANCE_TTL_EXTEND_TO, PERSISTENT_TTL_EXTEND_TO, TEMPORARY_TTL_EXTEND_TO,
+};
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _, Temporary as _};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Standard env, but with short minimum TTLs so extensions are observable
+fn setup_short_ttl_env() -> Env {
+    let env = setup_test_env();
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 100;
+        li.min_temp_entry_ttl = 100;
+        li.max_entry_ttl = 3_110_400;
+    });
+    env
+}
+
+#[test]
+fn test_set_player_uses_persistent_strategy() {
+    let env = setup_short_ttl_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let player = Address::generate(&env);
+
+    blendizzard.select_faction(&player, &0);
+
+    env.as_contract(&blendizzard.address, || {
+        let ttl = env
+            .storage()
+            .persistent()
+            .get_ttl(&DataKey::User(player.clone()));
+        assert_eq!(ttl, PERSISTENT_TTL_EXTEND_TO);
+        assert_eq!(env.storage().instance().get_ttl(), INSTANCE_TTL_EXTEND_TO);
+    });
+}
+
+#[test]
+fn test_bump_entries_extends_existing_keys() {
+    let env = setup_short_ttl_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let player = Address::generate(&env);
+
+    // Write entries directly (bypassing helpers) so they start at the minimum TTL
+    let player_key = DataKey::Player(player.clone());
+    let session_key = DataKey::Session(1);
+    env.as_contract(&blendizzard.address, || {
+        env.storage().persistent().set(&player_key, &true);
+        env.storage().temporary().set(&session_key, &true);
+        assert!(env.storage().persistent().get_ttl(&player_key) < PERSISTENT_TTL_EXTEND_TO);
+        assert!(env.storage().temporary().get_ttl(&session_key) < TEMPORARY_TTL_EXTEND_TO);
+    });
+
+    // Missing session 2 is skipped
+    let bumped = blendizzard.bump_entries(&vec![
+        &env,
+        player_key.clone(),
+        session_key.clone(),
+        DataKey::Session(2),
+        DataKey::Config,
+    ]);
+    assert_eq!(bumped, 3);
+
+    env.as_contract(&blendizzard.address, || {
+        assert_eq!(
+            env.storage().persistent().get_ttl(&player_key),
+            PERSISTENT_TTL_EXTEND_TO
+        );
+        assert_eq!(
+            env.storage().temporary().get_ttl(&session_key),
+            TEMPORARY_TTL_EXTEND_TO
+        );
+        assert!(!env.storage().temporary().has(&DataKey::Session(2)));
+    });
+}
+
+#[test]
+fn test_session_entries_use_max_session_ledgers() {
+    let env = setup_short_ttl_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    // Shorter than one epoch (345_600s = 69_120 ledgers) or longer than 30 days
+    assert_contract_error(
+        &blendizzard.try_set_max_session_ledgers(&60_000),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_max_session_ledgers(&(TEMPORARY_TTL_EXTEND_TO + 1)),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.set_max_session_ledgers(&100_000);
+
+    let session_key = DataKey::Session(1);
+    env.as_contract(&blendizzard.address, || {
+        env.storage().temporary().set(&session_key, &true);
+    });
+    assert_eq!(
+        blendizzard.bump_entries(&vec![&env, session_key.clone()]),
+        1
+    );
+
+    env.as_contract(&blendizzard.address, || {
+        assert_eq!(env.storage().temporary().get_ttl(&session_key), 100_000);
+    });
+}
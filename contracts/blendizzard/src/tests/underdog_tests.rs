@@ -0,0 +1,117 @@
+/// Underdog Multiplier Tests
+///
+/// Tests the FP multiplier for factions with less stake in the previous epoch:
+/// multiplier computation, its effect on FP, and admin validation.
+use super::fee_vault_utils::create_mock_vault;
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    Error, DEFAULT_FREE_FP_PER_EPOCH,
+};
+use crate::types::SCALAR_7;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Map};
+
+#[test]
+fn test_multipliers_scale_with_deficit() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    // Up to 1.5x for a faction with no stake
+    blendizzard.set_underdog_bonus(&5_000_000);
+
+    let mut stakes = Map::new(&env);
+    stakes.set(0, 100_0000000i128);
+    stakes.set(1, 50_0000000i128);
+
+    let multipliers = env.as_contract(&blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        crate::underdog::compute_multipliers(&env, &config, &stakes)
+    });
+
+    // Largest faction is not stored (1.0x), half its stake gets half the bonus
+    assert_eq!(multipliers.get(0), None);
+    assert_eq!(multipliers.get(1), Some(12_500_000));
+    assert_eq!(multipliers.get(2), Some(15_000_000));
+}
+
+#[test]
+fn test_multipliers_disabled_by_default() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    let mut stakes = Map::new(&env);
+    stakes.set(0, 100_0000000i128);
+
+    let multipliers = env.as_contract(&blendizzard.address, || {
+        let config = crate::storage::get_config(&env);
+        crate::underdog::compute_multipliers(&env, &config, &stakes)
+    });
+    assert_eq!(multipliers.len(), 0);
+
+    let current = blendizzard.get_faction_multipliers();
+    for faction in 0..3 {
+        assert_eq!(current.get(faction), Some(SCALAR_7));
+    }
+}
+
+#[test]
+fn test_underdog_faction_earns_boosted_fp() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_blendizzard_contract(
+        &env,
+        &admin,
+        &create_mock_vault(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        345_600,
+        vec![&env, 1],
+    );
+
+    // Faction 1 had less stake last epoch and plays at 1.25x this epoch
+    env.as_contract(&blendizzard.address, || {
+        let mut epoch = crate::storage::get_epoch(&env, 0).unwrap();
+        epoch.faction_multipliers.set(1, 12_500_000);
+        crate::storage::set_epoch(&env, 0, &epoch);
+    });
+    assert_eq!(
+        blendizzard.get_faction_multipliers().get(1),
+        Some(12_500_000)
+    );
+
+    let leader = Address::generate(&env);
+    let underdog = Address::generate(&env);
+    blendizzard.select_faction(&leader, &0);
+    blendizzard.select_faction(&underdog, &1);
+
+    assert_eq!(
+        blendizzard.get_epoch_player(&0, &leader).available_fp,
+        DEFAULT_FREE_FP_PER_EPOCH
+    );
+    assert_eq!(
+        blendizzard.get_epoch_player(&0, &underdog).available_fp,
+        DEFAULT_FREE_FP_PER_EPOCH * 5 / 4
+    );
+}
+
+#[test]
+fn test_set_underdog_bonus_validation() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_set_underdog_bonus(&-1),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_underdog_bonus(&(SCALAR_7 + 1)),
+        Error::InvalidConfig,
+    );
+
+    blendizzard.set_underdog_bonus(&SCALAR_7);
+    assert_eq!(blendizzard.get_config().max_underdog_bonus, SCALAR_7);
+}
@@ -0,0 +1,114 @@
+/// Vault Migration Tests
+///
+/// Tests that `migrate_vault` only executes a migration queued with
+/// `queue_vault_migration` after its timelock, and that FeeVault players move
+/// their own deposits across with `migrate_vault_position`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, setup_test_env, Error, ExtError,
+};
+use crate::vault::VAULT_MIGRATION_DELAY;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+/// Create Blendizzard on a mock fee-vault
+fn setup(env: &Env) -> (BlendizzardClient<'_>, Address) {
+    let vault = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &Address::generate(env),
+        &vault,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    (blendizzard, vault)
+}
+
+#[test]
+fn test_migration_needs_queue_and_timelock() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup(&env);
+    let new_vault = Address::generate(&env);
+
+    assert_contract_error(
+        &blendizzard.try_migrate_vault(&new_vault),
+        ExtError::VaultMigrationNotQueued,
+    );
+    assert_contract_error(
+        &blendizzard.try_queue_vault_migration(&vault),
+        Error::InvalidConfig,
+    );
+
+    let migration = blendizzard.queue_vault_migration(&new_vault);
+    assert_eq!(migration.new_vault, new_vault);
+    assert_eq!(
+        migration.executable_at,
+        env.ledger().timestamp() + VAULT_MIGRATION_DELAY
+    );
+    assert_eq!(blendizzard.get_queued_vault_migration(), Some(migration));
+
+    // Only the queued vault, and only once the delay has passed
+    env.ledger()
+        .with_mut(|li| li.timestamp += VAULT_MIGRATION_DELAY - 1);
+    assert_contract_error(
+        &blendizzard.try_migrate_vault(&new_vault),
+        ExtError::VaultMigrationTimelocked,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert_contract_error(
+        &blendizzard.try_migrate_vault(&Address::generate(&env)),
+        ExtError::VaultMigrationNotQueued,
+    );
+
+    blendizzard.cancel_vault_migration();
+    assert_eq!(blendizzard.get_queued_vault_migration(), None);
+    assert_contract_error(
+        &blendizzard.try_migrate_vault(&new_vault),
+        ExtError::VaultMigrationNotQueued,
+    );
+}
+
+#[test]
+fn test_player_moves_position_to_new_fee_vault() {
+    let env = setup_test_env();
+    let (blendizzard, vault) = setup(&env);
+    let player = Address::generate(&env);
+
+    // Nothing to move before a migration
+    assert_contract_error(
+        &blendizzard.try_migrate_vault_position(&player),
+        Error::FeatureNotConfigured,
+    );
+
+    let old_vault = create_mock_vault(&env);
+    MockVaultClient::new(&env, &old_vault).set_user_balance(&player, &75_0000000);
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_previous_fee_vault(
+            &env,
+            &crate::types::PreviousFeeVault {
+                vault: old_vault.clone(),
+                reserve_token_ids: vec![&env, 1],
+            },
+        );
+    });
+
+    // Deposits not moved yet still count towards the player's vault balance
+    MockVaultClient::new(&env, &vault).set_user_balance(&player, &20_0000000);
+    blendizzard.set_deposit_caps(&100_0000000, &0);
+    assert_eq!(
+        blendizzard.get_capacity(&player).user_remaining,
+        Some(5_0000000)
+    );
+
+    assert_eq!(blendizzard.migrate_vault_position(&player), 75_0000000);
+    // Withdrawn from the old vault, Deposited into the new one
+    assert_eq!(blendizzard.get_user_event_seq(&player), 2);
+    assert_contract_error(
+        &blendizzard.try_migrate_vault_position(&Address::generate(&env)),
+        Error::InsufficientBalance,
+    );
+}
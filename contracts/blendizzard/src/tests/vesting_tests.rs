@@ -0,0 +1,151 @@
+/// Reward Vesting Tests
+///
+/// Tests that reward claims above the vesting threshold stream linearly over
+/// `vesting_ledgers` and are paid out incrementally through `claim_vested`.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{create_token, TokenClient};
+use super::testutils::{
+    assert_contract_error, create_blendizzard_contract, create_test_blendizzard, setup_test_env,
+    unboosted_weights, Error,
+};
+use crate::types::{DistributionMode, EpochInfo, EpochPlayer};
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Map};
+
+const REWARD_POOL: i128 = 1000_0000000;
+
+/// Create Blendizzard with a finalized epoch 0 where `player` is the only winner
+fn setup_claimable_epoch<'a>(
+    env: &'a Env,
+    player: &Address,
+) -> (BlendizzardClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let usdc = create_token(env, &admin);
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+    usdc.mint(&blendizzard.address, &REWARD_POOL);
+
+    let fp = 100_0000000i128;
+    let mut faction_standings = Map::new(env);
+    faction_standings.set(0, fp);
+
+    let epoch_info = EpochInfo {
+        start_time: 0,
+        end_time: 345_600,
+        duration: 345_600,
+        faction_weighted_standings: unboosted_weights(env, &faction_standings),
+        faction_standings,
+        reward_pool: REWARD_POOL,
+        winning_faction: Some(0),
+        is_finalized: true,
+        total_game_fp: fp,
+        dev_reward_pool: 0,
+        distribution_mode: DistributionMode::Linear,
+        faction_sqrt_standings: Map::new(env),
+        faction_leaders: Map::new(env),
+        faction_multipliers: Map::new(env),
+        reward_pool_blnd: 0,
+        aborted: false,
+        faction_member_counts: Map::new(env),
+        faction_stakes: Map::new(env),
+        faction_rakes: Map::new(env),
+    };
+
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_epoch(env, 0, &epoch_info);
+        crate::storage::set_epoch_player(
+            env,
+            0,
+            player,
+            &EpochPlayer {
+                epoch_faction: Some(0),
+                epoch_balance_snapshot: 10_0000000,
+                available_fp: 0,
+                total_fp_contributed: fp,
+                streak_bonus: 0,
+                games_completed: 0,
+                reward_eligible: true,
+            },
+        );
+    });
+    vault.set_user_balance(player, &10_0000000);
+
+    (blendizzard, usdc)
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_large_claim_vests_linearly() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+
+    blendizzard.set_vesting(&100_0000000, &1000);
+
+    // Claim is recorded but nothing is paid yet
+    assert_eq!(blendizzard.claim_epoch_reward(&player, &0), REWARD_POOL);
+    assert_eq!(usdc.balance(&player), 0);
+    assert_eq!(blendizzard.get_claimable_vested(&player), 0);
+    assert_contract_error(
+        &blendizzard.try_claim_vested(&player),
+        Error::NoRewardsAvailable,
+    );
+
+    // Quarter of the way through
+    advance_ledgers(&env, 250);
+    assert_eq!(blendizzard.claim_vested(&player), 250_0000000);
+    assert_eq!(usdc.balance(&player), 250_0000000);
+
+    // Past the end: the rest is released and the schedule is removed
+    advance_ledgers(&env, 2000);
+    assert_eq!(blendizzard.claim_vested(&player), 750_0000000);
+    assert_eq!(usdc.balance(&player), REWARD_POOL);
+    assert_eq!(blendizzard.get_vesting(&player), None);
+}
+
+#[test]
+fn test_claim_below_threshold_paid_immediately() {
+    let env = setup_test_env();
+    let player = Address::generate(&env);
+    let (blendizzard, usdc) = setup_claimable_epoch(&env, &player);
+
+    blendizzard.set_vesting(&REWARD_POOL, &1000);
+
+    blendizzard.claim_epoch_reward(&player, &0);
+    assert_eq!(usdc.balance(&player), REWARD_POOL);
+    assert_eq!(blendizzard.get_vesting(&player), None);
+}
+
+#[test]
+fn test_set_vesting_rejects_invalid_values() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+
+    assert_contract_error(
+        &blendizzard.try_set_vesting(&-1, &1000),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_vesting(&100_0000000, &0),
+        Error::InvalidConfig,
+    );
+
+    // Disabling needs no length
+    blendizzard.set_vesting(&0, &0);
+}
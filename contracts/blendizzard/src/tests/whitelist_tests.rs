@@ -0,0 +1,70 @@
+/// Whitelist Mode Tests
+///
+/// Tests that with whitelist mode enabled only whitelisted users can select a
+/// faction or deposit, and that the admin manages the whitelist in batches.
+use super::testutils::{assert_contract_error, create_test_blendizzard, setup_test_env, Error};
+use crate::types::VaultBackend;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn test_whitelist_mode_gates_faction_selection() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let tester = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    blendizzard.set_whitelist_enabled(&true);
+    blendizzard.add_to_whitelist(&vec![&env, tester.clone()]);
+
+    blendizzard.select_faction(&tester, &0);
+    assert_contract_error(
+        &blendizzard.try_select_faction(&outsider, &0),
+        Error::NotWhitelisted,
+    );
+
+    // Disabling the mode opens selection to everyone
+    blendizzard.set_whitelist_enabled(&false);
+    blendizzard.select_faction(&outsider, &0);
+}
+
+#[test]
+fn test_whitelist_mode_gates_deposits() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    blendizzard.set_vault_backend(&VaultBackend::DirectPool, &Some(Address::generate(&env)));
+    blendizzard.set_whitelist_enabled(&true);
+
+    let outsider = Address::generate(&env);
+    assert_contract_error(
+        &blendizzard.try_deposit(&outsider, &100_0000000),
+        Error::NotWhitelisted,
+    );
+}
+
+#[test]
+fn test_whitelist_batch_add_and_remove() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let blendizzard = create_test_blendizzard(&env, &admin);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let user3 = Address::generate(&env);
+
+    blendizzard.add_to_whitelist(&vec![&env, user1.clone(), user2.clone(), user3.clone()]);
+    assert!(blendizzard.is_whitelisted(&user1));
+    assert!(blendizzard.is_whitelisted(&user3));
+
+    blendizzard.remove_from_whitelist(&vec![&env, user1.clone(), user3.clone()]);
+    assert!(!blendizzard.is_whitelisted(&user1));
+    assert!(blendizzard.is_whitelisted(&user2));
+    assert!(!blendizzard.is_whitelisted(&user3));
+
+    blendizzard.set_whitelist_enabled(&true);
+    assert_contract_error(
+        &blendizzard.try_select_faction(&user1, &1),
+        Error::NotWhitelisted,
+    );
+}
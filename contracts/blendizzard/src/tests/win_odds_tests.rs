@@ -0,0 +1,181 @@
+/// Win Odds Tests
+///
+/// Tests that the win-probability curve scales faction points: even matches
+/// are unchanged, upsets by stake or rating earn more, expected wins less,
+/// and the curve is validated.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::WinOddsCurve;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+/// Create Blendizzard with a registered game and `count` funded players,
+/// alternating between factions 0 and 1
+fn setup(env: &Env, count: u32) -> (BlendizzardClient<'_>, Address, Vec<Address>) {
+    let admin = Address::generate(env);
+    let game = Address::generate(env);
+    let vault_addr = create_mock_vault(env);
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &vault_addr,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        345_600,
+        vec![env, 1],
+    );
+    blendizzard.add_game(&game, &Address::generate(env));
+
+    let vault = MockVaultClient::new(env, &vault_addr);
+    let mut players = Vec::new(env);
+    for i in 0..count {
+        let player = Address::generate(env);
+        vault.set_user_balance(&player, &1000_0000000);
+        blendizzard.select_faction(&player, &(i % 2));
+        players.push_back(player);
+    }
+    (blendizzard, game, players)
+}
+
+/// Play a game won by `winner` and return the winner's contribution
+fn play(
+    blendizzard: &BlendizzardClient,
+    game: &Address,
+    session_id: u32,
+    winner: &Address,
+    loser: &Address,
+    winner_wager: i128,
+    loser_wager: i128,
+) -> i128 {
+    blendizzard.start_game(
+        game,
+        &session_id,
+        winner,
+        loser,
+        &winner_wager,
+        &loser_wager,
+    );
+    blendizzard.end_game(&session_id, &true);
+    blendizzard
+        .get_epoch_player(&0, winner)
+        .total_fp_contributed
+}
+
+#[test]
+fn test_stake_upsets_scaled() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup(&env, 6);
+    blendizzard.set_win_odds_curve(&WinOddsCurve {
+        rating_weight_bps: 0,
+        strength_bps: 5_000,
+    });
+
+    // Even stakes: unchanged
+    let even = play(
+        &blendizzard,
+        &game,
+        1,
+        &players.get_unchecked(0),
+        &players.get_unchecked(1),
+        10_0000000,
+        10_0000000,
+    );
+    assert_eq!(even, 10_0000000);
+
+    // Beating three times the stake: 25% of the stakes -> 1.25x
+    let upset = play(
+        &blendizzard,
+        &game,
+        2,
+        &players.get_unchecked(2),
+        &players.get_unchecked(3),
+        10_0000000,
+        30_0000000,
+    );
+    assert_eq!(upset, 12_5000000);
+
+    // Stomping a third of the stake: 75% of the stakes -> 0.75x
+    let stomp = play(
+        &blendizzard,
+        &game,
+        3,
+        &players.get_unchecked(4),
+        &players.get_unchecked(5),
+        30_0000000,
+        10_0000000,
+    );
+    assert_eq!(stomp, 22_5000000);
+}
+
+#[test]
+fn test_rating_upsets_scaled() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup(&env, 4);
+    blendizzard.set_win_odds_curve(&WinOddsCurve {
+        rating_weight_bps: 10_000,
+        strength_bps: 10_000,
+    });
+    env.as_contract(&blendizzard.address, || {
+        crate::storage::set_rating(&env, &players.get_unchecked(0), &game, 1000);
+        crate::storage::set_rating(&env, &players.get_unchecked(1), &game, 1400);
+        crate::storage::set_rating(&env, &players.get_unchecked(2), &game, 1400);
+        crate::storage::set_rating(&env, &players.get_unchecked(3), &game, 1000);
+    });
+
+    // A 400-point gap gives the lower rated player 9.09%
+    let upset = play(
+        &blendizzard,
+        &game,
+        1,
+        &players.get_unchecked(0),
+        &players.get_unchecked(1),
+        10_0000000,
+        10_0000000,
+    );
+    assert_eq!(upset, 18_1820000);
+    let favourite = play(
+        &blendizzard,
+        &game,
+        2,
+        &players.get_unchecked(2),
+        &players.get_unchecked(3),
+        10_0000000,
+        10_0000000,
+    );
+    assert_eq!(favourite, 1_8180000);
+}
+
+#[test]
+fn test_curve_disabled_by_default_and_validated() {
+    let env = setup_test_env();
+    let (blendizzard, game, players) = setup(&env, 2);
+    assert_eq!(blendizzard.get_win_odds_curve(), WinOddsCurve::default());
+
+    let contribution = play(
+        &blendizzard,
+        &game,
+        1,
+        &players.get_unchecked(0),
+        &players.get_unchecked(1),
+        10_0000000,
+        30_0000000,
+    );
+    assert_eq!(contribution, 10_0000000);
+
+    assert_contract_error(
+        &blendizzard.try_set_win_odds_curve(&WinOddsCurve {
+            rating_weight_bps: 10_001,
+            strength_bps: 0,
+        }),
+        Error::InvalidConfig,
+    );
+    assert_contract_error(
+        &blendizzard.try_set_win_odds_curve(&WinOddsCurve {
+            rating_weight_bps: 0,
+            strength_bps: 10_001,
+        }),
+        Error::InvalidConfig,
+    );
+}
@@ -0,0 +1,185 @@
+/// XLM Deposit Tests
+///
+/// Tests `deposit_xlm`, which converts XLM → USDC via Soroswap and deposits
+/// the result into fee-vault on behalf of the user.
+use super::fee_vault_utils::{create_mock_vault, MockVaultClient};
+use super::soroswap_utils::{
+    add_liquidity, create_factory, create_router, create_token, get_amount_out, TokenClient,
+};
+use super::testutils::{assert_contract_error, create_blendizzard_contract, setup_test_env, Error};
+use crate::types::DepositCapacity;
+use crate::BlendizzardClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+const LIQUIDITY: i128 = 1_000_000_0000000;
+
+// ============================================================================
+// Test Setup Helpers
+// ============================================================================
+
+/// Create Blendizzard with an XLM/USDC Soroswap pair (1:1 reserves)
+fn setup_xlm_pair<'a>(env: &'a Env) -> (BlendizzardClient<'a>, TokenClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(env);
+    let xlm = create_token(env, &admin);
+    let usdc = create_token(env, &admin);
+
+    let factory = create_factory(env, &admin);
+    let router = create_router(env);
+    router.initialize(&factory.address);
+
+    xlm.mint(&admin, &LIQUIDITY);
+    usdc.mint(&admin, &LIQUIDITY);
+    add_liquidity(
+        env,
+        &router,
+        &xlm.address,
+        &usdc.address,
+        LIQUIDITY,
+        LIQUIDITY,
+        &admin,
+    );
+
+    let blendizzard = create_blendizzard_contract(
+        env,
+        &admin,
+        &create_mock_vault(env),
+        &router.address,
+        &Address::generate(env),
+        &usdc.address,
+        345_600,
+        vec![env, 1],
+    );
+
+    (blendizzard, xlm, usdc)
+}
+
+// ============================================================================
+// XLM Deposit Tests
+// ============================================================================
+
+#[test]
+fn test_deposit_xlm_swaps_and_deposits() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+    blendizzard.set_xlm_token(&xlm.address);
+
+    let user = Address::generate(&env);
+    let amount = 100_0000000;
+    xlm.mint(&user, &amount);
+
+    let expected = get_amount_out(amount, LIQUIDITY, LIQUIDITY);
+    let deposited = blendizzard.deposit_xlm(&user, &amount, &expected);
+
+    assert_eq!(deposited, expected);
+    assert_eq!(xlm.balance(&user), 0);
+    assert_eq!(xlm.balance(&blendizzard.address), 0);
+}
+
+#[test]
+fn test_deposit_xlm_requires_xlm_token() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+
+    let user = Address::generate(&env);
+    xlm.mint(&user, &100_0000000);
+
+    let result = blendizzard.try_deposit_xlm(&user, &100_0000000, &0);
+    assert_contract_error(&result, Error::FeatureNotConfigured);
+}
+
+#[test]
+fn test_deposit_xlm_rejects_invalid_amount() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+    blendizzard.set_xlm_token(&xlm.address);
+
+    let user = Address::generate(&env);
+
+    let result = blendizzard.try_deposit_xlm(&user, &0, &0);
+    assert_contract_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_deposit_xlm_enforces_min_usdc_out() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+    blendizzard.set_xlm_token(&xlm.address);
+
+    let user = Address::generate(&env);
+    let amount = 100_0000000;
+    xlm.mint(&user, &amount);
+
+    // 1:1 pool minus fees can never return the full input amount
+    let result = blendizzard.try_deposit_xlm(&user, &amount, &amount);
+    assert_contract_error(&result, Error::SlippageExceeded);
+    assert_eq!(xlm.balance(&user), amount);
+}
+
+// ============================================================================
+// Deposit Cap Tests
+// ============================================================================
+
+#[test]
+fn test_deposit_xlm_enforces_per_user_cap() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+    blendizzard.set_xlm_token(&xlm.address);
+    let vault = MockVaultClient::new(&env, &blendizzard.get_config().fee_vault);
+
+    let user = Address::generate(&env);
+    let amount = 100_0000000;
+    xlm.mint(&user, &(amount * 2));
+    vault.set_user_balance(&user, &50_0000000);
+
+    blendizzard.set_deposit_caps(&100_0000000, &0);
+    assert_eq!(
+        blendizzard.get_capacity(&user),
+        DepositCapacity {
+            user_remaining: Some(50_0000000),
+            global_remaining: None,
+        }
+    );
+
+    let result = blendizzard.try_deposit_xlm(&user, &amount, &0);
+    assert_contract_error(&result, Error::UserDepositCapExceeded);
+
+    // Raising the cap lets the deposit through
+    blendizzard.set_deposit_caps(&200_0000000, &0);
+    blendizzard.deposit_xlm(&user, &amount, &0);
+}
+
+#[test]
+fn test_deposit_xlm_enforces_global_cap() {
+    let env = setup_test_env();
+    let (blendizzard, xlm, _usdc) = setup_xlm_pair(&env);
+    blendizzard.set_xlm_token(&xlm.address);
+    let vault = MockVaultClient::new(&env, &blendizzard.get_config().fee_vault);
+
+    let user = Address::generate(&env);
+    let amount = 100_0000000;
+    xlm.mint(&user, &amount);
+    vault.set_total_underlying(&950_0000000);
+
+    blendizzard.set_deposit_caps(&0, &1000_0000000);
+    assert_eq!(
+        blendizzard.get_capacity(&user),
+        DepositCapacity {
+            user_remaining: None,
+            global_remaining: Some(50_0000000),
+        }
+    );
+
+    let result = blendizzard.try_deposit_xlm(&user, &amount, &0);
+    assert_contract_error(&result, Error::GlobalDepositCapExceeded);
+    assert_eq!(xlm.balance(&user), amount);
+}
+
+#[test]
+fn test_set_deposit_caps_rejects_negative() {
+    let env = setup_test_env();
+    let (blendizzard, _xlm, _usdc) = setup_xlm_pair(&env);
+
+    let result = blendizzard.try_set_deposit_caps(&-1, &0);
+    assert_contract_error(&result, Error::InvalidConfig);
+}
@@ -263,7 +263,7 @@ impl EnvTestUtils for Env {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::testutils::setup_test_env;
+    use crate::testutils::setup_test_env;
 
     #[test]
     fn test_create_blend_fixture() {
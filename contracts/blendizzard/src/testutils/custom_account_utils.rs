@@ -0,0 +1,184 @@
+#![allow(dead_code)]
+
+/// Custom Account Test Utilities
+///
+/// A mock passkey smart wallet (a Soroban custom account contract) and helpers
+/// to authorize calls with it. Unlike `mock_all_auths`, entries built with
+/// `wallet_auth` and passed to `env.set_auths` make the host call the wallet's
+/// real `__check_auth`, so tests exercise the same auth contexts a smart
+/// wallet sees on-chain.
+extern crate std;
+
+use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::xdr::{
+    InvokeContractArgs, ScAddress, ScSymbol, ScVal, SorobanAddressCredentials,
+    SorobanAuthorizationEntry, SorobanAuthorizedFunction, SorobanAuthorizedInvocation,
+    SorobanCredentials,
+};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, IntoVal, TryFromVal,
+    Val, Vec,
+};
+
+// ============================================================================
+// Mock Smart Wallet
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum WalletError {
+    /// The signing key isn't the owner's passkey or a session key
+    UnknownSigner = 1,
+    /// A session key signed for a contract outside its scope
+    OutOfScope = 2,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum WalletKey {
+    Passkey,
+    SessionKey(BytesN<32>),
+    Contexts,
+}
+
+/// Passkey smart wallet with scoped session keys
+///
+/// The "signature" is the id of the signing key: the owner's passkey may
+/// authorize anything, a session key only calls to the contracts it was
+/// scoped to. Real wallets verify a secp256r1 signature over the payload
+/// instead; the policy check over the auth contexts is the same.
+#[contract]
+pub struct MockSmartWallet;
+
+#[contractimpl]
+impl MockSmartWallet {
+    pub fn __constructor(env: Env, passkey: BytesN<32>) {
+        env.storage().instance().set(&WalletKey::Passkey, &passkey);
+    }
+
+    /// Allow `key` to sign calls to `contracts` only
+    pub fn add_session_key(env: Env, key: BytesN<32>, contracts: Vec<Address>) {
+        env.current_contract_address().require_auth();
+        env.storage()
+            .instance()
+            .set(&WalletKey::SessionKey(key), &contracts);
+    }
+
+    /// Auth contexts approved by the last `__check_auth`
+    pub fn last_contexts(env: Env) -> Vec<Context> {
+        env.storage()
+            .instance()
+            .get(&WalletKey::Contexts)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MockSmartWallet {
+    type Error = WalletError;
+    type Signature = BytesN<32>;
+
+    fn __check_auth(
+        env: Env,
+        _signature_payload: Hash<32>,
+        signature: BytesN<32>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), WalletError> {
+        let storage = env.storage().instance();
+        let passkey: BytesN<32> = storage.get(&WalletKey::Passkey).unwrap();
+        if signature != passkey {
+            let scope: Vec<Address> = storage
+                .get(&WalletKey::SessionKey(signature))
+                .ok_or(WalletError::UnknownSigner)?;
+            for context in auth_contexts.iter() {
+                let Context::Contract(call) = context else {
+                    return Err(WalletError::OutOfScope);
+                };
+                if !scope.contains(&call.contract) {
+                    return Err(WalletError::OutOfScope);
+                }
+            }
+        }
+
+        storage.set(&WalletKey::Contexts, &auth_contexts);
+        Ok(())
+    }
+}
+
+/// Deploy a mock smart wallet owned by `passkey`
+pub fn create_smart_wallet<'a>(env: &Env, passkey: &BytesN<32>) -> MockSmartWalletClient<'a> {
+    let address = env.register(MockSmartWallet, (passkey.clone(),));
+    MockSmartWalletClient::new(env, &address)
+}
+
+// ============================================================================
+// Authorization Entries
+// ============================================================================
+
+/// A contract call a wallet authorizes, with the calls nested under it
+pub struct AuthInvocation {
+    pub contract: Address,
+    pub fn_name: &'static str,
+    pub args: Vec<Val>,
+    pub sub_invocations: std::vec::Vec<AuthInvocation>,
+}
+
+impl AuthInvocation {
+    pub fn new(contract: &Address, fn_name: &'static str, args: Vec<Val>) -> Self {
+        Self {
+            contract: contract.clone(),
+            fn_name,
+            args,
+            sub_invocations: std::vec::Vec::new(),
+        }
+    }
+
+    /// Nest a call made (and requiring the same wallet's auth) under this one
+    pub fn with(mut self, sub_invocation: AuthInvocation) -> Self {
+        self.sub_invocations.push(sub_invocation);
+        self
+    }
+
+    fn to_xdr(&self, env: &Env) -> SorobanAuthorizedInvocation {
+        let args: std::vec::Vec<ScVal> = self
+            .args
+            .iter()
+            .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+            .collect();
+        let sub_invocations: std::vec::Vec<SorobanAuthorizedInvocation> = self
+            .sub_invocations
+            .iter()
+            .map(|sub_invocation| sub_invocation.to_xdr(env))
+            .collect();
+        SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: ScAddress::from(&self.contract),
+                function_name: ScSymbol(self.fn_name.try_into().unwrap()),
+                args: args.try_into().unwrap(),
+            }),
+            sub_invocations: sub_invocations.try_into().unwrap(),
+        }
+    }
+}
+
+/// Authorization entry for `wallet` signing `invocation` with `key`
+pub fn wallet_auth(
+    env: &Env,
+    wallet: &Address,
+    key: &BytesN<32>,
+    nonce: i64,
+    invocation: &AuthInvocation,
+) -> SorobanAuthorizationEntry {
+    let signature: Val = key.into_val(env);
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: ScAddress::from(wallet),
+            nonce,
+            signature_expiration_ledger: env.ledger().sequence() + 100,
+            signature: ScVal::try_from_val(env, &signature).unwrap(),
+        }),
+        root_invocation: invocation.to_xdr(env),
+    }
+}
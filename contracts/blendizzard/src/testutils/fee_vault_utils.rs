@@ -90,6 +90,8 @@ pub enum MockVaultDataKey {
     Emissions(u32),
     /// Player underlying token balance (for cross-epoch architecture)
     UserBalance(Address),
+    /// Total underlying held by the vault (for deposit caps)
+    TotalUnderlying,
 }
 
 #[contract]
@@ -122,6 +124,22 @@ impl MockVault {
             .unwrap_or(0)
     }
 
+    /// Mock get_vault - reports the stored total underlying at a 1:1 b_rate
+    pub fn get_vault(env: Env) -> crate::fee_vault_v2::VaultData {
+        let total = env
+            .storage()
+            .instance()
+            .get::<MockVaultDataKey, i128>(&MockVaultDataKey::TotalUnderlying)
+            .unwrap_or(0);
+        crate::fee_vault_v2::VaultData {
+            admin_balance: 0,
+            b_rate: 1_000_000_000_000,
+            last_update_timestamp: 0,
+            total_b_tokens: total,
+            total_shares: total,
+        }
+    }
+
     /// Mock admin_withdraw - withdraws from stored admin balance
     /// Returns the requested amount and decrements the balance
     pub fn admin_withdraw(env: Env, amount: i128) -> i128 {
@@ -212,6 +230,14 @@ impl MockVault {
             .instance()
             .set(&MockVaultDataKey::UserBalance(player), &amount);
     }
+
+    /// Set total vault underlying for testing (deposit caps)
+    /// This is a test-only function to configure the mock vault TVL
+    pub fn set_total_underlying(env: Env, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&MockVaultDataKey::TotalUnderlying, &amount);
+    }
 }
 
 /// Create a mock vault for smoke tests (no constructor auth issues)
@@ -307,8 +333,8 @@ pub fn calculate_expected_shares(amount: i128, total_shares: i128, total_b_token
 
 #[cfg(test)]
 mod tests {
-    use super::super::testutils::setup_test_env;
     use super::*;
+    use crate::testutils::setup_test_env;
 
     #[test]
     fn test_mock_pool_creation() {
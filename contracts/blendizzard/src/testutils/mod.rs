@@ -0,0 +1,196 @@
+//! Test fixtures for Blendizzard and its external dependencies
+//!
+//! Used by this crate's own tests and exported to downstream crates (e.g. game
+//! contracts writing integration tests against Blendizzard) via the `testutils`
+//! cargo feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! blendizzard = { path = "../blendizzard", features = ["testutils"] }
+//! ```
+//!
+//! The re-exports below are the stable API; the submodules contain further
+//! helpers that may change between versions.
+
+use crate::types::EpochClock;
+use crate::{Blendizzard, BlendizzardClient};
+use soroban_sdk::testutils::Ledger as _;
+use soroban_sdk::{Address, Env, Vec};
+
+pub mod blend_utils;
+pub mod custom_account_utils;
+pub mod fee_vault_utils;
+pub mod soroswap_utils;
+
+pub use blend_utils::{create_blend_fixture_with_tokens, create_blend_pool, create_mock_oracle};
+pub use custom_account_utils::{
+    create_smart_wallet, wallet_auth, AuthInvocation, MockSmartWallet, MockSmartWalletClient,
+};
+pub use fee_vault_utils::{create_mock_vault, MockVaultClient};
+pub use soroswap_utils::{
+    add_liquidity, create_factory, create_router, create_token, SoroswapFactoryClient,
+    SoroswapRouterClient, TokenClient,
+};
+
+// ============================================================================
+// Environment
+// ============================================================================
+
+/// Standard test environment setup
+pub fn setup_test_env() -> Env {
+    use soroban_sdk::testutils::LedgerInfo;
+
+    let env = Env::default();
+
+    // Set full ledger info (pattern from blend-together)
+    env.ledger().set(LedgerInfo {
+        timestamp: 1441065600, // Sept 1st, 2015 12:00:00 AM UTC
+        protocol_version: 23,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    env.mock_all_auths();
+
+    // Reset budget for complex fee-vault operations
+    env.cost_estimate().budget().reset_unlimited();
+
+    env
+}
+
+// ============================================================================
+// Blendizzard Deployment
+// ============================================================================
+
+/// Default free FP per epoch for tests (100 FP with 7 decimals)
+pub const DEFAULT_FREE_FP_PER_EPOCH: i128 = 100_0000000;
+
+/// Default minimum deposit to claim for tests (1 USDC with 7 decimals)
+pub const DEFAULT_MIN_DEPOSIT_TO_CLAIM: i128 = 1_0000000;
+
+/// Register and initialize the Blendizzard contract
+#[allow(clippy::too_many_arguments)]
+pub fn create_blendizzard_contract<'a>(
+    env: &Env,
+    admin: &Address,
+    fee_vault: &Address,
+    soroswap_router: &Address,
+    blnd_token: &Address,
+    usdc_token: &Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+) -> BlendizzardClient<'a> {
+    create_blendizzard_contract_with_free_play(
+        env,
+        admin,
+        fee_vault,
+        soroswap_router,
+        blnd_token,
+        usdc_token,
+        epoch_duration,
+        reserve_token_ids,
+        DEFAULT_FREE_FP_PER_EPOCH,
+        DEFAULT_MIN_DEPOSIT_TO_CLAIM,
+    )
+}
+
+/// Register and initialize the Blendizzard contract with custom free play settings
+#[allow(clippy::too_many_arguments)]
+pub fn create_blendizzard_contract_with_free_play<'a>(
+    env: &Env,
+    admin: &Address,
+    fee_vault: &Address,
+    soroswap_router: &Address,
+    blnd_token: &Address,
+    usdc_token: &Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+    free_fp_per_epoch: i128,
+    min_deposit_to_claim: i128,
+) -> BlendizzardClient<'a> {
+    create_blendizzard_contract_with_dev_share(
+        env,
+        admin,
+        fee_vault,
+        soroswap_router,
+        blnd_token,
+        usdc_token,
+        epoch_duration,
+        reserve_token_ids,
+        free_fp_per_epoch,
+        min_deposit_to_claim,
+        1_000_000, // 10% dev_reward_share
+    )
+}
+
+/// Register and initialize the Blendizzard contract with custom dev reward share
+#[allow(clippy::too_many_arguments)]
+pub fn create_blendizzard_contract_with_dev_share<'a>(
+    env: &Env,
+    admin: &Address,
+    fee_vault: &Address,
+    soroswap_router: &Address,
+    blnd_token: &Address,
+    usdc_token: &Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+    free_fp_per_epoch: i128,
+    min_deposit_to_claim: i128,
+    dev_reward_share: i128,
+) -> BlendizzardClient<'a> {
+    create_blendizzard_contract_with_clock(
+        env,
+        admin,
+        fee_vault,
+        soroswap_router,
+        blnd_token,
+        usdc_token,
+        epoch_duration,
+        EpochClock::Timestamp,
+        reserve_token_ids,
+        free_fp_per_epoch,
+        min_deposit_to_claim,
+        dev_reward_share,
+    )
+}
+
+/// Register and initialize the Blendizzard contract with a custom epoch clock
+///
+/// With `EpochClock::LedgerSequence`, `epoch_duration` is in ledgers.
+#[allow(clippy::too_many_arguments)]
+pub fn create_blendizzard_contract_with_clock<'a>(
+    env: &Env,
+    admin: &Address,
+    fee_vault: &Address,
+    soroswap_router: &Address,
+    blnd_token: &Address,
+    usdc_token: &Address,
+    epoch_duration: u64,
+    epoch_clock: EpochClock,
+    reserve_token_ids: Vec<u32>,
+    free_fp_per_epoch: i128,
+    min_deposit_to_claim: i128,
+    dev_reward_share: i128,
+) -> BlendizzardClient<'a> {
+    let contract_address = env.register(
+        Blendizzard,
+        (
+            admin.clone(),
+            fee_vault.clone(),
+            soroswap_router.clone(),
+            blnd_token.clone(),
+            usdc_token.clone(),
+            epoch_duration,
+            epoch_clock,
+            reserve_token_ids,
+            free_fp_per_epoch,
+            min_deposit_to_claim,
+            dev_reward_share,
+        ),
+    );
+    BlendizzardClient::new(env, &contract_address)
+}
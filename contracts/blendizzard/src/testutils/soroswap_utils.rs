@@ -167,7 +167,7 @@ pub struct SoroswapTestSetup<'a> {
 impl<'a> SoroswapTestSetup<'a> {
     /// Create a complete Soroswap test environment
     pub fn new() -> Self {
-        use super::testutils::setup_test_env;
+        use crate::testutils::setup_test_env;
         let env = setup_test_env();
 
         let admin = Address::generate(&env);
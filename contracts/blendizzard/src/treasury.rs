@@ -0,0 +1,293 @@
+use soroban_sdk::{token, Address, Env, Map};
+
+use crate::errors::{ContractError, Error, ExtError};
+use crate::events::{
+    emit_treasury_funded, emit_treasury_proposal_created, emit_treasury_proposal_executed,
+    emit_treasury_voted,
+};
+use crate::math::{checked_mul_div, checked_mul_scalar_7, Rounding};
+use crate::storage;
+use crate::types::{Config, TreasuryProposal, TreasurySpend, FACTION_COUNT};
+
+// ============================================================================
+// Faction Treasuries
+// ============================================================================
+//
+// With `config.faction_treasury_share` set, each `cycle_epoch` moves that share
+// of the closed epoch's player reward pool into faction treasuries, split by
+// the factions' standings in that epoch. The USDC stays in this contract.
+//
+// A faction's members spend their treasury through proposals:
+// - Any member (a player locked into the faction this epoch with a non-zero
+//   balance snapshot) can propose a bounty or a boost of the next reward pool.
+// - Members vote for or against during the epoch the proposal was created in,
+//   weighted by their epoch balance snapshot (one vote per member).
+// - Once that epoch has ended anyone can execute the proposal: it passes if
+//   more stake voted for than against.
+
+/// Pay the treasury share of a closed epoch's player reward pool into the
+/// faction treasuries, pro rata to faction standings
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (treasury share)
+/// * `epoch` - Epoch being closed
+/// * `player_reward_pool` - Player reward pool before the treasury share
+/// * `standings` - The epoch's faction standings
+///
+/// # Returns
+/// USDC moved into treasuries (to deduct from the player reward pool)
+pub(crate) fn fund_treasuries(
+    env: &Env,
+    config: &Config,
+    epoch: u32,
+    player_reward_pool: i128,
+    standings: &Map<u32, i128>,
+) -> Result<i128, Error> {
+    if config.faction_treasury_share == 0 || player_reward_pool <= 0 {
+        return Ok(0);
+    }
+
+    let total_standings = (0..FACTION_COUNT)
+        .map(|faction| standings.get(faction).unwrap_or(0).max(0))
+        .try_fold(0i128, |total, standing| total.checked_add(standing))
+        .ok_or(Error::OverflowError)?;
+    if total_standings == 0 {
+        return Ok(0);
+    }
+
+    let treasury_pool = checked_mul_scalar_7(
+        player_reward_pool,
+        config.faction_treasury_share,
+        Rounding::Floor,
+    )
+    .ok_or(Error::OverflowError)?;
+
+    let mut treasuries = storage::get_faction_treasuries(env);
+    let mut funded: i128 = 0;
+    for faction in 0..FACTION_COUNT {
+        let standing = standings.get(faction).unwrap_or(0);
+        if standing <= 0 {
+            continue;
+        }
+        let amount = checked_mul_div(treasury_pool, standing, total_standings, Rounding::Floor)
+            .ok_or(Error::OverflowError)?;
+        if amount == 0 {
+            continue;
+        }
+
+        let balance = treasuries
+            .balances
+            .get(faction)
+            .unwrap_or(0)
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+        treasuries.balances.set(faction, balance);
+        funded = funded.checked_add(amount).ok_or(Error::OverflowError)?;
+
+        emit_treasury_funded(env, faction, epoch, amount);
+    }
+    storage::set_faction_treasuries(env, &treasuries);
+
+    Ok(funded)
+}
+
+/// Propose spending from the proposer's faction treasury
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `proposer` - Faction member creating the proposal
+/// * `spend` - Bounty recipient or boost
+/// * `amount` - USDC to spend
+///
+/// # Returns
+/// The new proposal ID
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `PlayerNotFound` - If the proposer hasn't played this epoch
+/// * `FactionNotSelected` - If the proposer isn't locked into a faction
+/// * `DepositRequiredToClaim` - If the proposer has no stake this epoch
+/// * `InsufficientTreasury` - If the treasury holds less than amount
+pub(crate) fn propose(
+    env: &Env,
+    proposer: &Address,
+    spend: TreasurySpend,
+    amount: i128,
+) -> Result<u32, ContractError> {
+    proposer.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let epoch = storage::get_current_epoch(env);
+    let (faction, _stake) = member_stake(env, epoch, proposer)?;
+
+    let mut treasuries = storage::get_faction_treasuries(env);
+    if treasuries.balances.get(faction).unwrap_or(0) < amount {
+        return Err(ExtError::InsufficientTreasury.into());
+    }
+
+    let proposal_id = treasuries.next_proposal_id;
+    treasuries.next_proposal_id = proposal_id.checked_add(1).ok_or(Error::OverflowError)?;
+    storage::set_faction_treasuries(env, &treasuries);
+
+    storage::set_treasury_proposal(
+        env,
+        proposal_id,
+        &TreasuryProposal {
+            faction,
+            proposer: proposer.clone(),
+            spend: spend.clone(),
+            amount,
+            epoch,
+            votes_for: 0,
+            votes_against: 0,
+        },
+    );
+
+    emit_treasury_proposal_created(env, proposal_id, proposer, faction, &spend, amount);
+
+    Ok(proposal_id)
+}
+
+/// Vote on a treasury proposal, weighted by the voter's epoch balance snapshot
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `voter` - Member of the proposal's faction
+/// * `proposal_id` - Proposal to vote on
+/// * `support` - true to vote for, false to vote against
+///
+/// # Returns
+/// The voter's weight
+///
+/// # Errors
+/// * `ProposalNotFound` - If the proposal doesn't exist
+/// * `ProposalVotingClosed` - If the proposal's voting epoch has ended
+/// * `PlayerNotFound` - If the voter hasn't played this epoch
+/// * `FactionNotSelected` - If the voter isn't locked into a faction
+/// * `DepositRequiredToClaim` - If the voter has no stake this epoch
+/// * `NotFactionMember` - If the voter is in another faction
+/// * `ProposalAlreadyVoted` - If the voter already voted
+pub(crate) fn vote(
+    env: &Env,
+    voter: &Address,
+    proposal_id: u32,
+    support: bool,
+) -> Result<i128, ContractError> {
+    voter.require_auth();
+
+    let mut proposal = get_proposal(env, proposal_id)?;
+    if proposal.epoch != storage::get_current_epoch(env) {
+        return Err(ExtError::ProposalVotingClosed.into());
+    }
+
+    let (faction, stake) = member_stake(env, proposal.epoch, voter)?;
+    if faction != proposal.faction {
+        return Err(ExtError::NotFactionMember.into());
+    }
+    if storage::has_proposal_vote(env, proposal_id, voter) {
+        return Err(ExtError::ProposalAlreadyVoted.into());
+    }
+
+    if support {
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(stake)
+            .ok_or(Error::OverflowError)?;
+    } else {
+        proposal.votes_against = proposal
+            .votes_against
+            .checked_add(stake)
+            .ok_or(Error::OverflowError)?;
+    }
+    storage::set_proposal_vote(env, proposal_id, voter);
+    storage::set_treasury_proposal(env, proposal_id, &proposal);
+
+    emit_treasury_voted(env, proposal_id, voter, support, stake);
+
+    Ok(stake)
+}
+
+/// Execute a treasury proposal once its voting epoch has ended
+///
+/// Permissionless. A passed proposal pays the bounty or adds the boost to
+/// `PendingRewards`; either way the proposal is removed.
+///
+/// # Returns
+/// true if the proposal passed and was paid
+///
+/// # Errors
+/// * `ProposalNotFound` - If the proposal doesn't exist
+/// * `ProposalVotingOpen` - If the proposal's voting epoch hasn't ended
+/// * `InsufficientTreasury` - If the treasury no longer covers a passed proposal
+pub(crate) fn execute(env: &Env, proposal_id: u32) -> Result<bool, ContractError> {
+    let proposal = get_proposal(env, proposal_id)?;
+    if storage::get_current_epoch(env) <= proposal.epoch {
+        return Err(ExtError::ProposalVotingOpen.into());
+    }
+
+    storage::remove_treasury_proposal(env, proposal_id);
+
+    let passed = proposal.votes_for > proposal.votes_against;
+    if passed {
+        let mut treasuries = storage::get_faction_treasuries(env);
+        let balance = treasuries.balances.get(proposal.faction).unwrap_or(0);
+        if balance < proposal.amount {
+            return Err(ExtError::InsufficientTreasury.into());
+        }
+        treasuries
+            .balances
+            .set(proposal.faction, balance - proposal.amount);
+        storage::set_faction_treasuries(env, &treasuries);
+
+        match &proposal.spend {
+            TreasurySpend::Bounty(recipient) => {
+                let config = storage::get_config(env);
+                token::Client::new(env, &config.usdc_token).transfer(
+                    &env.current_contract_address(),
+                    recipient,
+                    &proposal.amount,
+                );
+            }
+            TreasurySpend::Boost => {
+                let pending = storage::get_pending_rewards(env)
+                    .checked_add(proposal.amount)
+                    .ok_or(Error::OverflowError)?;
+                storage::set_pending_rewards(env, pending);
+            }
+        }
+    }
+
+    emit_treasury_proposal_executed(env, proposal_id, passed);
+
+    Ok(passed)
+}
+
+/// Load a pending treasury proposal
+///
+/// # Errors
+/// * `ProposalNotFound` - If the proposal doesn't exist
+fn get_proposal(env: &Env, proposal_id: u32) -> Result<TreasuryProposal, ExtError> {
+    storage::get_treasury_proposal(env, proposal_id).ok_or(ExtError::ProposalNotFound)
+}
+
+/// Get a member's faction and voting stake for an epoch
+///
+/// # Errors
+/// * `PlayerNotFound` - If the member has no data for the epoch
+/// * `FactionNotSelected` - If the member isn't locked into a faction
+/// * `DepositRequiredToClaim` - If the member's balance snapshot is zero
+pub(crate) fn member_stake(env: &Env, epoch: u32, member: &Address) -> Result<(u32, i128), Error> {
+    let epoch_player =
+        storage::get_epoch_player(env, epoch, member).ok_or(Error::PlayerNotFound)?;
+    let faction = epoch_player
+        .epoch_faction
+        .ok_or(Error::FactionNotSelected)?;
+    if epoch_player.epoch_balance_snapshot <= 0 {
+        return Err(Error::DepositRequiredToClaim);
+    }
+    Ok((faction, epoch_player.epoch_balance_snapshot))
+}
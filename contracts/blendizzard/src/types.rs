@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use soroban_sdk::{contracttype, Address, Map, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Vec};
 
 // ============================================================================
 // Factions
@@ -15,10 +15,55 @@ pub enum Faction {
 
 impl Faction {
     pub fn is_valid(id: u32) -> bool {
-        id <= 2
+        id < FACTION_COUNT
     }
 }
 
+/// Number of factions (valid IDs are 0..FACTION_COUNT)
+pub const FACTION_COUNT: u32 = 3;
+
+/// Display metadata for a faction (Persistent storage)
+///
+/// Set by the admin so integrations can render factions without
+/// hard-coding names. Unset factions fall back to their built-in name.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactionInfo {
+    /// Faction ID
+    pub id: u32,
+
+    /// Short display name (e.g. "WholeNoodle")
+    pub name: String,
+
+    /// Ticker-style symbol (e.g. "NOODLE")
+    pub symbol: String,
+
+    /// Hash of the faction icon (e.g. sha256 of the image or an IPFS digest)
+    /// All zeros if no icon has been set
+    pub icon_hash: BytesN<32>,
+}
+
+/// Live standings of one faction in an epoch (see `get_faction_totals`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactionTotals {
+    /// Faction ID
+    pub faction: u32,
+
+    /// Players locked into the faction this epoch
+    pub members: u32,
+
+    /// Sum of the members' epoch balance snapshots
+    pub stake: i128,
+
+    /// Faction points contributed (the faction's standing)
+    pub points: i128,
+
+    /// Share of the player reward pool the faction wins if the epoch ends now
+    /// (7 decimals; the winner's share once the epoch is finalized)
+    pub pool_share: i128,
+}
+
 // ============================================================================
 // Storage Data Structures
 // ============================================================================
@@ -41,6 +86,31 @@ pub struct Player {
     /// Player's vault balance from the previous epoch (for cross-epoch comparison)
     /// Used to detect >50% withdrawal between epochs
     pub last_epoch_balance: i128,
+
+    /// Number of consecutive epochs played, ending at `last_active_epoch`
+    /// (0 = never played). Only current if `last_active_epoch` is the current
+    /// or previous epoch.
+    pub streak: u32,
+
+    /// Last epoch in which the player played a game
+    pub last_active_epoch: u32,
+}
+
+/// Last observed vault balance of a player (Persistent storage)
+///
+/// Used to reconstruct start-of-epoch balances when `Config::balance_snapshots`
+/// is enabled (see `snapshot.rs`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceCheckpoint {
+    /// Epoch in which the balance was last observed
+    pub epoch: u32,
+
+    /// Vault balance at the last observation
+    pub balance: i128,
+
+    /// Balance counted as held at the start of `epoch`
+    pub start_balance: i128,
 }
 
 /// Per-epoch player data
@@ -66,6 +136,30 @@ pub struct EpochPlayer {
     /// Total faction points contributed to the player's faction this epoch
     /// Used for reward distribution calculation
     pub total_fp_contributed: i128,
+
+    /// Participation streak bonus on this player's reward weight (7 decimals)
+    /// Snapshot at first game of epoch. Example: 500_000 = +5%
+    pub streak_bonus: i128,
+
+    /// Sessions the player finished this epoch (won or lost)
+    pub games_completed: u32,
+
+    /// Whether the player meets the activity requirement, so their contributions
+    /// count towards the reward pool split (see `activity.rs`)
+    pub reward_eligible: bool,
+}
+
+/// Minimum activity per epoch for a share of the reward pool (Instance storage)
+///
+/// Both thresholds must be met. Zero for both (default) makes every winner eligible.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRequirement {
+    /// Sessions to finish in the epoch (won or lost)
+    pub min_games: u32,
+
+    /// FP to contribute to the faction in the epoch
+    pub min_fp: i128,
 }
 
 /// Epoch metadata
@@ -74,12 +168,16 @@ pub struct EpochPlayer {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EpochInfo {
-    /// Unix timestamp when this epoch started
+    /// Unix timestamp (or ledger sequence, see `EpochClock`) when this epoch started
     pub start_time: u64,
 
-    /// Unix timestamp when this epoch ends (start_time + epoch_duration)
+    /// Unix timestamp (or ledger sequence) when this epoch ends (start_time + duration)
     pub end_time: u64,
 
+    /// Epoch duration in seconds (or ledgers) this epoch was opened with
+    /// (`end_time` is earlier only if the epoch was aborted)
+    pub duration: u64,
+
     /// Map of faction_id -> total fp contributed by all players
     /// Used to determine the winning faction
     pub faction_standings: Map<u32, i128>,
@@ -101,6 +199,45 @@ pub struct EpochInfo {
     /// Developer reward pool (portion of rewards for game developers)
     /// Set during cycle_epoch: total_rewards * dev_reward_share
     pub dev_reward_pool: i128,
+
+    /// How the player reward pool is split among the winning faction
+    /// Snapshot of `Config::distribution_mode` when the epoch was created
+    pub distribution_mode: DistributionMode,
+
+    /// Map of faction_id -> sum of fp * (1 + streak_bonus) over its players
+    /// Denominator for `DistributionMode::Linear` (unscaled, see `streak::reward_weight`)
+    pub faction_weighted_standings: Map<u32, i128>,
+
+    /// Map of faction_id -> sum of sqrt(fp) * (1 + streak_bonus) over its players
+    /// Denominator for `DistributionMode::Quadratic` (unscaled, see `streak::reward_weight`)
+    pub faction_sqrt_standings: Map<u32, i128>,
+
+    /// Map of faction_id -> player with the highest fp contribution
+    /// Recipient for `DistributionMode::WinnerTakeAll` (first to reach the top wins ties)
+    pub faction_leaders: Map<u32, Address>,
+
+    /// Map of faction_id -> FP multiplier (7 decimals) for underdog factions
+    /// Computed from the previous epoch's faction stakes when the epoch opens
+    /// (missing factions are at 1.0x, see `underdog::compute_multipliers`)
+    pub faction_multipliers: Map<u32, i128>,
+
+    /// Total BLND reward pool for players (retained instead of swapped,
+    /// see `Config::blnd_retention_bps`), paid alongside `reward_pool`
+    pub reward_pool_blnd: i128,
+
+    /// True if the epoch was ended early by `emergency_abort_epoch`
+    /// (finalized with no winner and no rewards)
+    pub aborted: bool,
+
+    /// Map of faction_id -> players locked into the faction this epoch
+    pub faction_member_counts: Map<u32, u32>,
+
+    /// Map of faction_id -> sum of its players' epoch balance snapshots
+    pub faction_stakes: Map<u32, i128>,
+
+    /// Map of faction_id -> FP raked from the faction's wagers
+    /// (not part of `faction_standings`; see `rake.rs`)
+    pub faction_rakes: Map<u32, FactionRake>,
 }
 
 /// Game session tracking
@@ -134,15 +271,449 @@ pub struct GameSession {
     pub player1_won: Option<bool>,
 }
 
+/// Progress of an in-flight session
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SessionState {
+    /// Started, no outcome reported yet
+    Active = 0,
+
+    /// Outcome reported, waiting out the game's dispute window
+    Provisional = 1,
+
+    /// Reported outcome disputed, waiting for the arbiter
+    Disputed = 2,
+}
+
+/// An in-flight session of a game, returned by `get_sessions`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveSession {
+    /// Session ID
+    pub session_id: u32,
+
+    /// Players, wagers and epoch of the session
+    pub session: GameSession,
+
+    /// Ledger the session started in
+    pub start_ledger: u32,
+
+    /// Whether an outcome was reported or disputed
+    pub state: SessionState,
+}
+
+/// Opaque match details a game attached to a session (Temporary storage)
+///
+/// Blendizzard doesn't interpret the bytes (e.g. map seed and mode at start,
+/// replay hash at the end).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionMetadata {
+    /// Attached by `start_game_with_metadata` (empty if none)
+    pub start: Bytes,
+
+    /// Attached by `end_game_with_metadata` (empty if none)
+    pub result: Bytes,
+}
+
+/// Best-of-N series played over a single session (Temporary storage)
+///
+/// The session's FP wagers are locked once when the series starts and settled
+/// like a regular game when one side reaches a majority of `best_of`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSeries {
+    /// Series length (odd, e.g. 3 for Bo3)
+    pub best_of: u32,
+
+    /// Games won by player1 so far
+    pub player1_wins: u32,
+
+    /// Games won by player2 so far
+    pub player2_wins: u32,
+
+    /// Per-game results in play order (true = player1 won)
+    pub results: Vec<bool>,
+}
+
+/// Outcome reported for a session of a game with a dispute window (Temporary storage)
+///
+/// The session stays pending (FP locked, standings untouched) until the result
+/// is finalized after `final_ledger`, or the arbiter resolves a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProvisionalResult {
+    /// Outcome reported by the game contract (true = player1 won)
+    pub player1_won: bool,
+
+    /// Last ledger in which either player can dispute the result
+    pub final_ledger: u32,
+
+    /// Player who disputed the result (None = undisputed)
+    pub disputer: Option<Address>,
+
+    /// USDC bond posted by the disputer
+    pub bond: i128,
+}
+
+/// Team game session (Temporary storage)
+///
+/// Every member wagers the same FP. When the session ends, each winner's own
+/// wager counts towards their faction, so the team's contribution is split
+/// evenly among teammates.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamSession {
+    /// Address of the game contract
+    pub game_id: Address,
+
+    /// Epoch when this game was created
+    pub epoch_id: u32,
+
+    /// Team A members (all locked to `team_a_faction`)
+    pub team_a: Vec<Address>,
+
+    /// Team B members (all locked to `team_b_faction`)
+    pub team_b: Vec<Address>,
+
+    /// Faction shared by team A for this epoch
+    pub team_a_faction: u32,
+
+    /// Faction shared by team B for this epoch
+    pub team_b_faction: u32,
+
+    /// Faction points wagered by each member
+    pub wager: i128,
+
+    /// Game result (None = pending, Some(true) = team A won, Some(false) = team B won)
+    pub team_a_won: Option<bool>,
+}
+
+/// Commit-reveal move state for a session (Temporary storage)
+///
+/// Commitments are `sha256(move as 4 big-endian bytes || salt)`. The reveal
+/// window opens once both players have committed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveCommits {
+    /// Player1's move commitment (None until committed)
+    pub player1_commit: Option<BytesN<32>>,
+
+    /// Player2's move commitment (None until committed)
+    pub player2_commit: Option<BytesN<32>>,
+
+    /// Player1's revealed move (None until revealed)
+    pub player1_move: Option<u32>,
+
+    /// Player2's revealed move (None until revealed)
+    pub player2_move: Option<u32>,
+
+    /// Unix timestamp after which reveals close (0 until both players committed)
+    pub reveal_deadline: u64,
+}
+
+/// Spectator predictions on a session's winner (Temporary storage)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PredictionPool {
+    /// Total USDC staked on player1 winning
+    pub player1_stake: i128,
+
+    /// Total USDC staked on player2 winning
+    pub player2_stake: i128,
+
+    /// Session outcome (None until the session is settled)
+    pub player1_won: Option<bool>,
+
+    /// Losing stakes (minus rake) shared by correct predictors once resolved
+    pub payout_pool: i128,
+}
+
+/// One spectator's prediction on a session (Temporary storage)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prediction {
+    /// Predicted winner (true = player1)
+    pub player1_wins: bool,
+
+    /// USDC staked
+    pub amount: i128,
+}
+
+/// Faction treasury balances and the proposal counter (Instance storage)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactionTreasuries {
+    /// Map of faction_id -> USDC held for the faction
+    pub balances: Map<u32, i128>,
+
+    /// ID assigned to the next spending proposal
+    pub next_proposal_id: u32,
+}
+
+/// What a faction treasury proposal spends on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TreasurySpend {
+    /// Pay a bounty to the given address
+    Bounty(Address),
+
+    /// Add to the next epoch's reward pool (via `PendingRewards`)
+    Boost,
+}
+
+/// Proposal to spend from a faction treasury (Persistent storage)
+///
+/// Open for stake-weighted votes by the faction's members during the epoch it
+/// was created in, executable once that epoch has ended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryProposal {
+    /// Faction whose treasury pays
+    pub faction: u32,
+
+    /// Member who created the proposal
+    pub proposer: Address,
+
+    /// What the USDC is spent on
+    pub spend: TreasurySpend,
+
+    /// USDC to spend
+    pub amount: i128,
+
+    /// Epoch the vote runs in
+    pub epoch: u32,
+
+    /// Stake voting for the proposal
+    pub votes_for: i128,
+
+    /// Stake voting against the proposal
+    pub votes_against: i128,
+}
+
+/// A registered keeper's USDC bond
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBond {
+    /// USDC bonded (less any slashes)
+    pub amount: i128,
+
+    /// First epoch in which the bond can be withdrawn
+    pub unlock_epoch: u32,
+}
+
+/// Bonded keeper registry (Instance storage)
+///
+/// With `min_bond > 0`, only keepers bonding at least `min_bond` USDC may cycle
+/// an epoch during the first `priority_window` seconds after it ends; after
+/// that anyone may. Keepers earn `bounty` USDC per cycle from the closed
+/// epoch's player reward pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRegistry {
+    /// Minimum bond to act as a keeper (0 = registry disabled)
+    pub min_bond: i128,
+
+    /// Seconds after epoch end reserved for bonded keepers
+    pub priority_window: u64,
+
+    /// USDC paid to the keeper for each cycle
+    pub bounty: i128,
+
+    /// Map of keeper address -> bond
+    pub keepers: Map<Address, KeeperBond>,
+
+    /// Sum of all keeper bonds (USDC held by this contract)
+    pub total_bonded: i128,
+}
+
 /// Game registration info (Persistent storage)
 ///
-/// Stores the developer address for whitelisted games.
+/// Stores the developer address and wager bounds for whitelisted games.
 /// Used to track FP contributions and developer reward claims.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameInfo {
     /// Developer address who receives reward share for this game
     pub developer: Address,
+
+    /// Smallest FP wager accepted per player (0 = no minimum)
+    pub min_wager: i128,
+
+    /// Largest FP wager accepted per player (0 = no maximum)
+    pub max_wager: i128,
+
+    /// Ledgers an `end_game` result stays disputable before it can be
+    /// finalized (0 = results settle immediately)
+    pub dispute_ledgers: u32,
+
+    /// House cut of each wager, in basis points (0 = no rake)
+    pub rake_bps: u32,
+
+    /// Where the raked share of the reward pool goes
+    pub rake_route: RakeRoute,
+
+    /// Self-registration awaiting admin approval (see `register_game`);
+    /// pending games can't be played
+    pub pending: bool,
+
+    /// Most sessions of this game active at once (0 = no limit)
+    pub max_active_sessions: u32,
+
+    /// Most sessions of this game started in one ledger (0 = no limit)
+    pub max_sessions_per_ledger: u32,
+
+    /// Faction points the winner of a practice (zero-wager) session
+    /// contributes, or None if the game doesn't allow practice sessions
+    pub practice_fp: Option<i128>,
+
+    /// How a win converts to faction points (see `PointFormula`)
+    pub point_formula: PointFormula,
+}
+
+/// How a game's wins convert to faction points, before win odds, deposit
+/// locks, decay and rake are applied (see `point_formula.rs`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PointFormula {
+    /// The winner's wager (default)
+    Stake,
+
+    /// A flat amount of FP per win, at most the winner's wager
+    Flat(i128),
+
+    /// The winner's wager scaled by the margin of victory the game reports
+    /// (see `end_game_with_margin`), never below this share of it, in bps
+    Margin(u32),
+}
+
+/// Where a game stands in the registry (see `get_games`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GameStatus {
+    /// Self-registration awaiting admin approval
+    Pending = 0,
+
+    /// Approved and accepting new sessions
+    Active = 1,
+
+    /// Approved, but no new sessions can start (see `set_game_deprecated`)
+    Deprecated = 2,
+}
+
+/// Lifetime session counters of a game (Persistent storage)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameTotals {
+    /// Sessions started (1v1, series games and team games)
+    pub sessions_started: u32,
+
+    /// Sessions settled with an outcome
+    pub sessions_finished: u32,
+
+    /// FP wagered across all finished sessions
+    pub total_wagered: i128,
+}
+
+/// A registered game as listed by `get_games`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameListing {
+    /// Game contract address
+    pub game_id: Address,
+
+    /// Developer address who receives reward share for this game
+    pub developer: Address,
+
+    /// Share of all FP ever wagered on Blendizzard, in basis points
+    pub weight_bps: u32,
+
+    /// House cut of each wager, in basis points
+    pub rake_bps: u32,
+
+    /// Where the raked share of the reward pool goes
+    pub rake_route: RakeRoute,
+
+    /// Smallest FP wager accepted per player (0 = no minimum)
+    pub min_wager: i128,
+
+    /// Largest FP wager accepted per player (0 = no maximum)
+    pub max_wager: i128,
+
+    /// Pending, active or deprecated
+    pub status: GameStatus,
+
+    /// Lifetime sessions started
+    pub sessions_started: u32,
+
+    /// Lifetime sessions finished
+    pub sessions_finished: u32,
+}
+
+/// Session load of a game with session limits (Temporary storage)
+///
+/// Only tracked while one of the game's limits is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameLoad {
+    /// Sessions started while `max_active_sessions` was set that may still be
+    /// active (finished and past-epoch sessions are pruned on the next start)
+    pub active_sessions: Vec<u32>,
+
+    /// Ledger of the last session start
+    pub ledger: u32,
+
+    /// Sessions started in `ledger`
+    pub started_in_ledger: u32,
+}
+
+/// Where a game's rake goes (see `rake.rs`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RakeRoute {
+    /// Next epoch's reward pool (default)
+    RewardPool = 0,
+
+    /// The raked player's faction treasury
+    Treasury = 1,
+
+    /// Burned
+    Burn = 2,
+
+    /// The protocol treasury (see `protocol_treasury.rs`)
+    Protocol = 3,
+}
+
+/// FP raked from one faction's wagers in an epoch, by route
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactionRake {
+    /// FP raked into the reward pool
+    pub reward_pool: i128,
+
+    /// FP raked into the faction treasury
+    pub treasury: i128,
+
+    /// FP raked to be burned
+    pub burn: i128,
+
+    /// FP raked into the protocol treasury
+    pub protocol: i128,
+}
+
+/// Metadata a game contract reports through the standard game interface
+/// (`game_metadata`, and `register_game` when self-registering)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMetadata {
+    /// Display name (at most 32 bytes)
+    pub name: String,
+
+    /// Address to receive developer rewards for the game
+    pub developer: Address,
 }
 
 /// Per-epoch game contribution tracking (Temporary storage)
@@ -156,40 +727,816 @@ pub struct EpochGame {
     pub total_fp_contributed: i128,
 }
 
-// ============================================================================
-// Configuration
-// ============================================================================
+/// Merkle-drop distribution data for an epoch (Temporary storage)
+///
+/// Present only for epochs settled via an off-chain computed Merkle root.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochMerkle {
+    /// Merkle root of all (player, amount) reward leaves
+    pub root: BytesN<32>,
 
-/// Global configuration
+    /// Total USDC claimed against this root so far
+    /// Never allowed to exceed the epoch's reward_pool
+    pub total_claimed: i128,
+}
+
+/// Reward payout tracking for a finalized epoch (Temporary storage)
 ///
-/// Stores contract configuration parameters.
-/// Note: Admin address is stored separately via DataKey::Admin for single source of truth.
-/// Note: Pause state is stored separately via DataKey::Paused for efficient access.
+/// Used to compute how much of an epoch's rewards went unclaimed once its
+/// claim window (`Config::claim_window_epochs`) has passed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Config {
-    /// fee-vault-v2 contract address
-    pub fee_vault: Address,
+pub struct EpochPayouts {
+    /// Total USDC claimed from the epoch (player, Merkle and developer claims,
+    /// including referral shares)
+    pub total_claimed: i128,
 
-    /// Soroswap router contract address
-    pub soroswap_router: Address,
+    /// Claim window has passed; the unclaimed remainder was carried over
+    /// into a later epoch's reward pool and no more claims are accepted
+    pub expired: bool,
 
-    /// BLND token address
-    pub blnd_token: Address,
+    /// Total BLND claimed from the epoch's BLND reward pool
+    pub blnd_claimed: i128,
 
-    /// USDC token address
-    pub usdc_token: Address,
+    /// USDC claimed from the player reward pool only (per-player and Merkle
+    /// claims, including referral shares)
+    pub player_claimed: i128,
+}
 
-    /// Duration of each epoch in seconds (default: 4 days = 345,600 seconds)
-    pub epoch_duration: u64,
+/// A sponsor's donations to an epoch's reward pools (Temporary storage, in `EpochBoosts`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochBoost {
+    /// Address that funded the boost
+    pub sponsor: Address,
 
-    /// Reserve token IDs for claiming BLND emissions from Blend pool
-    /// Formula: reserve_index * 2 + token_type
-    /// token_type: 0 = debt token, 1 = b-token (suppliers)
-    /// Example: For reserve 0 b-tokens (suppliers), use [1]
-    pub reserve_token_ids: Vec<u32>,
+    /// Token added to the pools (USDC for BLND boosts, which are swapped)
+    pub token: Address,
 
-    /// Base FP granted to all players each epoch regardless of deposit (7 decimals)
+    /// Total amount of `token` the sponsor added
+    pub amount: i128,
+}
+
+/// An amount of a token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAmount {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// An epoch's pool of an extra reward token (Temporary storage, in `EpochRewardTokens`)
+///
+/// Split among the winning faction like `EpochInfo::reward_pool`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTokenPool {
+    /// Whitelisted reward token (see `set_reward_tokens`)
+    pub token: Address,
+
+    /// Total amount for players
+    pub amount: i128,
+
+    /// Amount claimed so far (the rest is carried over once the claim window passes)
+    pub claimed: i128,
+}
+
+/// Yield snapshot taken when an epoch is cycled (Instance storage, in `YieldHistory`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochYield {
+    /// Epoch that was closed
+    pub epoch: u32,
+
+    /// Ledger timestamp of the cycle
+    pub timestamp: u64,
+
+    /// Seconds since the previous snapshot (or since the epoch started)
+    pub period: u64,
+
+    /// Vault backend b_rate at the cycle (12 decimals)
+    pub b_rate: i128,
+
+    /// Total USDC held by the vault backend at the cycle
+    pub tvl: i128,
+
+    /// USDC yield converted into the epoch's reward pools (players + developers)
+    pub rewards: i128,
+
+    /// Yield realized over the period, 7 decimals: b_rate growth plus rewards / tvl
+    pub realized_yield: i128,
+}
+
+/// Recent yield history and trailing APY estimate (returned by `get_yield_stats`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldStats {
+    /// Snapshots of the most recently closed epochs, oldest first
+    pub epochs: Vec<EpochYield>,
+
+    /// Realized yield over `epochs` annualized without compounding (7 decimals)
+    pub trailing_apy: i128,
+}
+
+/// Yield the next epoch cycle is expected to collect (returned by `get_pending_yield`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingYield {
+    /// USDC claimable from the fee-vault admin balance (0 with DirectPool)
+    pub admin_balance: i128,
+
+    /// Estimated BLND emissions accrued to the backend's pool position and not yet claimed
+    pub unclaimed_emissions: i128,
+
+    /// BLND the cycle would swap: held here and unclaimed, less retained and reserved BLND
+    pub swappable_blnd: i128,
+
+    /// USDC already harvested this epoch
+    pub harvested: i128,
+
+    /// Estimated reward pool in USDC before the developer share: the admin
+    /// balance, harvested USDC and the quoted swap of `swappable_blnd`
+    pub estimated_rewards: i128,
+}
+
+/// Linear vesting of large reward claims (Persistent storage)
+///
+/// `locked` is released linearly from `last_ledger` until `end_ledger`; released
+/// USDC accumulates in `unlocked` until the player calls `claim_vested`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// USDC released but not yet claimed
+    pub unlocked: i128,
+
+    /// USDC still vesting
+    pub locked: i128,
+
+    /// Ledger up to which `locked` has been released into `unlocked`
+    pub last_ledger: u32,
+
+    /// Ledger at which everything is released
+    pub end_ledger: u32,
+}
+
+/// All persistent per-user state that lives under a single key (Persistent storage)
+///
+/// Packs what used to be separate `Player`, `Referrer`, `Vesting` and
+/// `BalanceCheckpoint` entries, so an entrypoint touching a user loads one
+/// ledger entry instead of up to four. Legacy entries are migrated on the
+/// user's first write (see `storage::set_user_data`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserData {
+    /// Faction selection and multiplier tracking (None until the user selects
+    /// a faction or plays)
+    pub player: Option<Player>,
+
+    /// Referrer credited with a share of the user's claims
+    pub referrer: Option<Address>,
+
+    /// Vesting reward claims
+    pub vesting: Option<VestingSchedule>,
+
+    /// Last observed vault balance (balance snapshots)
+    pub balance_checkpoint: Option<BalanceCheckpoint>,
+
+    /// Address approved to claim epoch rewards on the user's behalf
+    pub claimer: Option<Address>,
+
+    /// Most recent reward payouts, oldest first (bounded, see `get_claim_history`)
+    pub claim_history: Vec<ClaimRecord>,
+
+    /// Unlocked achievements and progress towards them
+    pub achievements: Achievements,
+
+    /// Deposit lock opted into with `deposit_with_lock` (kept after it expires
+    /// until the next lock replaces it)
+    pub deposit_lock: Option<DepositLock>,
+
+    /// Titles earned, oldest first (bounded, see `get_titles`)
+    pub titles: Vec<Title>,
+
+    /// First epoch `claim_all` hasn't scanned yet
+    pub claim_cursor: u32,
+}
+
+/// Kind of an on-chain title
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TitleKind {
+    /// Top contributor of the epoch's winning faction
+    EpochMvp = 0,
+}
+
+/// A title held by a player, e.g. "MVP of epoch N" (see `mvp.rs`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Title {
+    pub kind: TitleKind,
+
+    /// Epoch the title was earned in
+    pub epoch: u32,
+
+    /// Faction the title was earned for
+    pub faction: u32,
+
+    /// USDC bonus paid with the title
+    pub bonus: i128,
+}
+
+/// How long a deposit locks the user's vault balance (see `lock.rs`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LockTier {
+    /// No lock, no multiplier
+    None = 0,
+    /// Locked for the current epoch
+    OneEpoch = 1,
+    /// Locked for the current epoch and the next three
+    FourEpochs = 2,
+}
+
+/// A user's deposit lock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositLock {
+    /// Highest tier locked into while the lock has been running
+    pub tier: LockTier,
+
+    /// First epoch in which withdrawals are allowed again
+    pub unlock_epoch: u32,
+}
+
+/// Milestones a player can unlock as profile badges (see `achievements.rs`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Achievement {
+    /// Won a session
+    FirstWin,
+    /// Won `AchievementSettings::win_streak` sessions in a row
+    WinStreak,
+    /// Finished `AchievementSettings::games_played` sessions
+    GamesPlayed,
+    /// Top contributor of an epoch's winning faction
+    EpochMvp,
+}
+
+/// A player's unlocked achievements and progress, returned by `get_achievements`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Achievements {
+    /// Unlocked achievements, in unlock order
+    pub unlocked: Vec<Achievement>,
+
+    /// Sessions finished across all games
+    pub games_played: u32,
+
+    /// Sessions won in a row across all games (reset by a loss)
+    pub win_streak: u32,
+}
+
+/// Achievement thresholds and FP bonus (Instance storage)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementSettings {
+    /// Consecutive wins for `Achievement::WinStreak`
+    pub win_streak: u32,
+
+    /// Finished sessions for `Achievement::GamesPlayed`
+    pub games_played: u32,
+
+    /// Extra epoch FP per unlocked achievement (7 decimals, 0 = badges only)
+    pub fp_bonus: i128,
+}
+
+/// What a quest counts (see `quests.rs`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum QuestKind {
+    /// Finished sessions, won or lost
+    PlayGames = 0,
+
+    /// Won sessions
+    WinGames = 1,
+}
+
+/// Per-epoch challenge with an FP bonus (Temporary storage, per epoch)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quest {
+    /// What the quest counts
+    pub kind: QuestKind,
+
+    /// Sessions needed to complete the quest
+    pub target: u32,
+
+    /// Smallest FP wager of a session that counts (0 = any)
+    pub min_wager: i128,
+
+    /// FP added to the player's available FP on completion
+    pub bonus_fp: i128,
+}
+
+/// Admin operation that needs approver sign-off, see `ApproverSet`
+///
+/// Each variant carries the exact arguments of the call, so an approval only
+/// covers that call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminOperation {
+    /// `upgrade(new_wasm_hash)`
+    Upgrade(BytesN<32>),
+    /// `migrate_vault(new_vault)`
+    MigrateVault(Address),
+    /// `set_admin(new_admin)`
+    SetAdmin(Address),
+    /// `set_approvers(approvers, threshold)`
+    SetApprovers(Vec<Address>, u32),
+    /// `update_config` with a new Soroswap router and/or BLND token
+    /// (`new_soroswap_router`, `new_blnd_token`)
+    SetSwapContracts(Option<Address>, Option<Address>),
+    /// `set_treasurer(treasurer)`
+    SetTreasurer(Option<Address>),
+}
+
+/// Approvers for sensitive admin operations (Instance storage)
+///
+/// With `threshold > 0`, each `AdminOperation` additionally requires
+/// `threshold` of `approvers` to approve it (in any transactions) before the
+/// admin can execute it. Approvals are used up by the execution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproverSet {
+    /// Addresses allowed to approve operations
+    pub approvers: Vec<Address>,
+
+    /// Approvals required per operation (0 = approvals disabled)
+    pub threshold: u32,
+}
+
+/// Next-epoch parameter players can vote on (see `governance.rs`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GovernanceParameter {
+    /// `Config::dev_reward_share`, applied to the next epoch's rewards
+    DevRewardShare = 0,
+
+    /// `Config::max_underdog_bonus`, applied to the next epoch's multipliers
+    UnderdogBonus = 1,
+
+    /// `Config::epoch_duration` of the next epoch, in seconds
+    EpochDuration = 2,
+}
+
+/// BLND burn settings and lifetime total (Instance storage)
+///
+/// Each claim of yield (`cycle_epoch` or `harvest`) burns `bps` of the newly
+/// claimed BLND instead of swapping it to USDC.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlndBurn {
+    /// Share of claimed BLND burned, in basis points (0 = disabled)
+    pub bps: u32,
+
+    /// Address the BLND is sent to instead of being burned with the token's
+    /// `burn` (None burns it)
+    pub burn_address: Option<Address>,
+
+    /// BLND burned (or sent to the burn address) since deployment
+    pub total_burned: i128,
+}
+
+/// Win-probability curve for faction points (Instance storage)
+///
+/// Winning contributions are scaled by how unlikely the win was, judged from
+/// the players' Elo ratings and stakes (see `faction_points.rs`).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WinOddsCurve {
+    /// Weight of the Elo expectation vs the stake ratio, in basis points
+    /// (10000 = ratings only, 0 = stakes only)
+    pub rating_weight_bps: u32,
+
+    /// Largest bonus (upset) or penalty (expected win), in basis points of
+    /// the contribution (0 = disabled)
+    pub strength_bps: u32,
+}
+
+/// Jackpot settings and accumulated balance (Instance storage)
+///
+/// Each `cycle_epoch` moves `bps` of the closed epoch's new player reward pool
+/// into `balance`; every `interval`-th epoch the whole balance is added to that
+/// epoch's player reward pool for the winning faction.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Jackpot {
+    /// Share of each epoch's new player reward pool, in basis points (0 = no accrual)
+    pub bps: u32,
+
+    /// Pay out on every epoch whose number + 1 is a multiple of this
+    pub interval: u32,
+
+    /// USDC accumulated for the next payout
+    pub balance: i128,
+}
+
+/// Insurance fund settings and balance (Instance storage)
+///
+/// Funded with `bps` of the USDC routed from game rakes each cycle; spent by
+/// `cover_shortfall` when the vault backend holds less than it credits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceFund {
+    /// Share of routed rake USDC, in basis points (0 = no accrual)
+    pub bps: u32,
+
+    /// USDC available to cover shortfalls
+    pub balance: i128,
+
+    /// USDC spent covering shortfalls so far
+    pub total_covered: i128,
+}
+
+/// Protocol treasury: tracked token balances and the treasurer (Instance storage)
+///
+/// Funded by game rakes routed to `RakeRoute::Protocol`. Only the treasurer
+/// can spend it, through a timelocked `queue_treasury_transfer` +
+/// `treasury_transfer`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolTreasury {
+    /// Address allowed to spend the treasury (None = spending disabled)
+    pub treasurer: Option<Address>,
+
+    /// Map of token -> amount held for the treasury
+    pub balances: Map<Address, i128>,
+
+    /// Transfer waiting for its timelock, if any
+    pub queued_transfer: Option<QueuedTreasuryTransfer>,
+}
+
+/// Treasury transfer waiting for its timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedTreasuryTransfer {
+    /// Recipient
+    pub to: Address,
+
+    /// Token to transfer
+    pub token: Address,
+
+    /// Amount to transfer
+    pub amount: i128,
+
+    /// Earliest ledger timestamp the transfer can execute at
+    pub executable_at: u64,
+}
+
+/// One reward payout in a user's claim history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRecord {
+    /// Epoch claimed from (the epoch of the release for `claim_vested`)
+    pub epoch: u32,
+
+    /// Amount paid, net of any referral share (a claim above the vesting
+    /// threshold records the amount that started vesting; its releases are
+    /// recorded again when `claim_vested` pays them)
+    pub amount: i128,
+
+    /// Token paid (USDC or BLND)
+    pub token: Address,
+
+    /// Ledger sequence of the claim
+    pub ledger: u32,
+}
+
+/// Lifetime stats of a player on one game contract (Persistent storage)
+///
+/// Updated in `end_game`. Sessions always have a winner, so there is no draw count.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerGameStats {
+    /// Games won
+    pub wins: u32,
+
+    /// Games lost
+    pub losses: u32,
+
+    /// Total FP wagered across all finished games
+    pub total_wagered: i128,
+
+    /// Total FP wagered in won games (contributed to faction standings)
+    pub total_won: i128,
+}
+
+/// Lifetime protocol-wide counters (Instance storage)
+///
+/// Updated as deposits, sessions and claims happen; see `GlobalStats` for the
+/// public view.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LifetimeTotals {
+    /// USDC deposited through this contract (direct fee-vault deposits aren't seen)
+    pub total_deposits: i128,
+
+    /// FP wagered across all finished sessions on all games
+    pub total_wagered: i128,
+
+    /// USDC rewards claimed by players and developers
+    pub total_rewards_distributed: i128,
+
+    /// BLND rewards claimed by players
+    pub total_blnd_claimed: i128,
+}
+
+/// Protocol-wide stats for dashboards, returned by `get_global_stats`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalStats {
+    /// USDC deposited through this contract (direct fee-vault deposits aren't seen)
+    pub total_deposits: i128,
+
+    /// FP wagered across all finished sessions on all games
+    pub total_wagered: i128,
+
+    /// USDC rewards claimed by players and developers
+    pub total_rewards_distributed: i128,
+
+    /// BLND rewards claimed by players
+    pub total_blnd_claimed: i128,
+
+    /// Claimed BLND burned instead of swapped (see `BlndBurn`)
+    pub total_blnd_burned: i128,
+
+    /// Current USDC held by the vault backend for all depositors (b-tokens × b_rate)
+    pub tvl: i128,
+}
+
+/// One admin or role action in the on-chain admin log, returned by `get_admin_log`
+///
+/// Values are stored as SHA-256 hashes of their XDR encoding, so an indexer
+/// can check a log entry against the value it saw in the call or the config.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    /// Position in the log (0 for the first action ever recorded)
+    pub index: u32,
+
+    /// SHA-256 of the entrypoint name (e.g. "set_game_rake")
+    pub action: BytesN<32>,
+
+    /// SHA-256 of the value before the action (the whole `Config` for config setters)
+    pub old_value: BytesN<32>,
+
+    /// SHA-256 of the value after the action
+    pub new_value: BytesN<32>,
+
+    /// Ledger sequence the action happened in
+    pub ledger: u32,
+
+    /// Admin, guardian or arbiter that authorized the action
+    pub caller: Address,
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// How an epoch's player reward pool is split among winning-faction members
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DistributionMode {
+    /// Proportional to fp contributed (default)
+    Linear = 0,
+
+    /// Proportional to sqrt(fp contributed), dampening whale dominance
+    Quadratic = 1,
+
+    /// Entire pool to the faction's top contributor
+    WinnerTakeAll = 2,
+}
+
+/// What epoch times and durations are measured in (chosen at initialization)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EpochClock {
+    /// Unix timestamps and seconds (default)
+    Timestamp = 0,
+
+    /// Ledger sequence numbers and ledger counts
+    LedgerSequence = 1,
+}
+
+/// Where player deposits are held and where epoch yield comes from
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VaultBackend {
+    /// Players deposit into fee-vault-v2; yield is its admin fees plus emissions (default)
+    FeeVault = 0,
+
+    /// This contract supplies to the Blend pool itself and holds the b-tokens;
+    /// yield is the pool's BLND emissions
+    DirectPool = 1,
+}
+
+/// Vault migration waiting for its timelock (Instance storage)
+///
+/// Queued by `queue_vault_migration`; `migrate_vault(new_vault)` executes it
+/// once `executable_at` has passed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedVaultMigration {
+    /// New fee-vault (FeeVault) or Blend pool (DirectPool)
+    pub new_vault: Address,
+
+    /// Earliest ledger timestamp the migration can execute at
+    pub executable_at: u64,
+}
+
+/// Fee-vault replaced by the last FeeVault migration (Instance storage)
+///
+/// Players move their deposits across with `migrate_vault_position`. Until
+/// the vault is drained its admin fees and emissions are still claimed each
+/// cycle, and its deposits still count towards players' vault balances.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreviousFeeVault {
+    /// Fee-vault address
+    pub vault: Address,
+
+    /// Reserve token IDs its emissions are claimed for (on its own pool)
+    pub reserve_token_ids: Vec<u32>,
+}
+
+/// Cap on faction imbalance enforced by `select_faction` (see `faction.rs`)
+///
+/// Ratios have 7 decimals (e.g. 2_0000000 = a faction may grow to twice the
+/// smallest faction). Measured over the players locked into each faction in
+/// the current epoch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FactionCap {
+    /// No cap (default)
+    None,
+
+    /// Cap on member count ratio
+    Members(i128),
+
+    /// Cap on stake (epoch balance snapshot) ratio
+    Stake(i128),
+}
+
+/// Phase of the current epoch
+///
+/// With `Config::selection_phase_duration` set, each epoch starts with a
+/// selection phase (faction switching allowed, games disabled) followed by a
+/// battle phase (faction switching disabled, games count).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EpochPhase {
+    /// Factions can be switched; games can't be started
+    Selection = 0,
+
+    /// Games can be played; factions can't be switched
+    Battle = 1,
+}
+
+/// Timing of the current epoch, returned by `get_epoch_schedule`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochSchedule {
+    /// Current epoch number
+    pub epoch: u32,
+
+    /// Unix timestamp when the epoch started
+    pub start_time: u64,
+
+    /// Unix timestamp when the epoch ends
+    pub end_time: u64,
+
+    /// Seconds (or ledgers, see `EpochClock`) until `end_time` (0 once it has passed)
+    pub seconds_remaining: u64,
+
+    /// True if a public `cycle_epoch` call would pass its timing checks now
+    /// (epoch ended, outside the keeper priority window and no closed epoch
+    /// awaiting `process_rewards`)
+    pub can_cycle: bool,
+}
+
+/// Stage of the epoch cycle run by `cycle_epoch_step`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CycleStage {
+    /// Closed the current epoch and opened the next one
+    Finalize = 0,
+
+    /// Claimed the closed epoch's vault yield
+    Claim = 1,
+
+    /// Swapped BLND to USDC for the closed epoch
+    Swap = 2,
+
+    /// Distributed the closed epoch's rewards (cycle complete)
+    Distribute = 3,
+}
+
+/// Cycle stages done for a closed epoch (Temporary storage)
+///
+/// Lets `cycle_epoch` resume where an earlier call or `cycle_epoch_step`
+/// stopped, without claiming or swapping twice. Removed once the epoch's
+/// rewards are distributed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleProgress {
+    /// Epoch closed and the next one opened (`finalize_epoch`)
+    pub finalized: bool,
+
+    /// Vault yield claimed; its USDC is in the pending reward buffer
+    pub claimed: bool,
+
+    /// BLND newly claimed by the claim stage (input to the BLND burn)
+    pub claimed_blnd: i128,
+
+    /// BLND swapped to USDC (or carried over if the swap was skipped)
+    pub swapped: bool,
+}
+
+/// Why a player's faction is locked, see `FactionLock`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FactionLockReason {
+    /// Not locked: a new selection applies to the player's next game
+    Unlocked = 0,
+
+    /// Battle phase: `select_faction` rejects a switch until the next epoch
+    BattlePhase = 1,
+
+    /// Sessions in progress are played for the epoch's locked faction
+    ActiveSessions = 2,
+
+    /// Already played this epoch: a new selection applies from the next epoch
+    EpochFaction = 3,
+}
+
+/// A player's faction lock status, returned by `get_faction_lock`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactionLock {
+    /// Reason the faction is locked (the most restrictive one applies)
+    pub reason: FactionLockReason,
+
+    /// Faction the player is locked into for the current epoch (None before
+    /// their first game this epoch)
+    pub epoch_faction: Option<u32>,
+
+    /// Estimated ledger the lock ends at: the current epoch's end (0 if unlocked)
+    pub unlock_ledger: u32,
+}
+
+/// Global configuration
+///
+/// Stores contract configuration parameters.
+/// Note: Admin address is stored separately via DataKey::Admin for single source of truth.
+/// Note: Pause state is stored separately via DataKey::Paused for efficient access.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// fee-vault-v2 contract address
+    pub fee_vault: Address,
+
+    /// Soroswap router contract address
+    pub soroswap_router: Address,
+
+    /// BLND token address
+    pub blnd_token: Address,
+
+    /// USDC token address
+    pub usdc_token: Address,
+
+    /// Duration of each epoch in seconds (default: 4 days = 345,600 seconds)
+    pub epoch_duration: u64,
+
+    /// Reserve token IDs for claiming BLND emissions from Blend pool
+    /// Formula: reserve_index * 2 + token_type
+    /// token_type: 0 = d-token (borrowers), 1 = b-token (suppliers)
+    /// Example: For reserve 0 b-tokens (suppliers), use [1]; add 0 for its d-tokens
+    /// when the vault also holds a borrow position
+    pub reserve_token_ids: Vec<u32>,
+
+    /// Base FP granted to all players each epoch regardless of deposit (7 decimals)
     /// Enables "free play" where players can participate without depositing
     /// Default: 100_0000000 (100 FP)
     pub free_fp_per_epoch: i128,
@@ -203,6 +1550,221 @@ pub struct Config {
     /// Portion of epoch rewards allocated to game developers
     /// Default: 1_000_000 (10% = 0.10 with 7 decimals)
     pub dev_reward_share: i128,
+
+    /// Native XLM token (SAC) address, used by `deposit_xlm`
+    /// None until set by admin via `set_xlm_token`
+    pub xlm_token: Option<Address>,
+
+    /// Reward distribution mode applied to epochs created from now on
+    /// Default: Linear
+    pub distribution_mode: DistributionMode,
+
+    /// SEP-40 price oracle used to sanity-check the BLND → USDC swap
+    /// None = no check (trust Soroswap pricing)
+    pub price_oracle: Option<Address>,
+
+    /// Maximum shortfall of the Soroswap quote vs the oracle price (7 decimals)
+    /// If exceeded, the swap is skipped and BLND carries over to the next epoch
+    /// Example: 500_000 = 5%
+    pub max_price_deviation: i128,
+
+    /// Reward weight bonus per consecutive epoch played (7 decimals)
+    /// Example: 500_000 = +5% per epoch. Default: 0 (disabled)
+    pub streak_bonus_per_epoch: i128,
+
+    /// Cap on the streak bonus (7 decimals)
+    /// Example: 5_000_000 = +50%
+    pub max_streak_bonus: i128,
+
+    /// Share of a referred player's claimed rewards credited to their referrer (7 decimals)
+    /// Example: 500_000 = 5% (500 bps). Default: 0 (disabled)
+    pub referral_share: i128,
+
+    /// Number of epochs rewards stay claimable after an epoch is finalized
+    /// Unclaimed USDC is then carried over into the next reward pool
+    /// Default: 0 (rewards never expire)
+    pub claim_window_epochs: u32,
+
+    /// Maximum simultaneous unfinished sessions per player in the current epoch
+    /// Default: 0 (unlimited)
+    pub max_active_sessions: u32,
+
+    /// Length of the faction-selection phase at the start of each epoch (seconds)
+    /// Must be shorter than `epoch_duration`. Default: 0 (no selection phase)
+    pub selection_phase_duration: u64,
+
+    /// Maximum vault balance a user can reach through `deposit_xlm`
+    /// Default: 0 (unlimited)
+    pub max_deposit_per_user: i128,
+
+    /// Maximum total underlying held by the fee-vault after a `deposit_xlm`
+    /// Default: 0 (unlimited)
+    pub max_total_deposits: i128,
+
+    /// Where player deposits are held. Default: FeeVault
+    pub vault_backend: VaultBackend,
+
+    /// Blend pool used by the DirectPool backend
+    pub blend_pool: Option<Address>,
+
+    /// Maximum FP bonus for factions with less stake in the previous epoch (7 decimals)
+    /// Example: 2_500_000 = up to 1.25x FP. Default: 0 (disabled)
+    pub max_underdog_bonus: i128,
+
+    /// TTL given to session entries (sessions, series, team sessions, moves) on each touch, in ledgers
+    /// Abandoned sessions expire this long after their last interaction.
+    /// Default: 0 (same as other temporary data, 30 days)
+    pub max_session_ledgers: u32,
+
+    /// Reward claims above this amount (USDC) vest linearly instead of being paid at once
+    /// Default: 0 (vesting disabled)
+    pub vesting_threshold: i128,
+
+    /// Number of ledgers a vested claim is streamed over (~5s each)
+    pub vesting_ledgers: u32,
+
+    /// Maximum USDC a spectator can stake on one session
+    /// Default: 0 (predictions disabled)
+    pub max_prediction: i128,
+
+    /// Share of losing prediction stakes sent to the reward pool (7 decimals)
+    pub prediction_rake: i128,
+
+    /// Base FP on start-of-epoch balances instead of live vault balances
+    /// Default: false
+    pub balance_snapshots: bool,
+
+    /// Share of claimed BLND kept as BLND and paid to players alongside USDC
+    /// (basis points, 10_000 = 100%). Default: 0 (all BLND swapped to USDC)
+    pub blnd_retention_bps: u32,
+
+    /// USDC bond a player posts to dispute a provisional result. Default: 0
+    pub dispute_bond: i128,
+
+    /// Soroswap Aggregator used for the BLND → USDC swap (None = Soroswap router only)
+    pub swap_aggregator: Option<Address>,
+
+    /// Aggregator protocol IDs the swap is split across (e.g. "soroswap", "phoenix")
+    pub aggregator_protocols: Vec<String>,
+
+    /// Half-life, in ledgers, of faction points contributed within an epoch,
+    /// measured to the epoch's end. Default: 0 (no decay)
+    pub fp_half_life_ledgers: u32,
+
+    /// Only whitelisted users may deposit and select a faction (private beta).
+    /// Default: false
+    pub whitelist_enabled: bool,
+
+    /// Share of each epoch's player reward pool paid into faction treasuries,
+    /// split by faction standings (7 decimals). Default: 0 (no treasuries)
+    pub faction_treasury_share: i128,
+
+    /// Claim emissions for every emission-bearing reserve token of the pool
+    /// instead of `reserve_token_ids`. Default: false
+    pub auto_reserve_emissions: bool,
+
+    /// Epoch duration scheduled by `set_next_epoch_duration`, applied to
+    /// `epoch_duration` when the next epoch opens. Default: None
+    pub next_epoch_duration: Option<u64>,
+
+    /// Cap on how far the largest faction can outgrow the smallest before
+    /// `select_faction` rejects it. Default: FactionCap::None
+    pub faction_cap: FactionCap,
+}
+
+/// Remaining deposit room under the configured caps
+///
+/// `None` means the corresponding cap is disabled (unlimited).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositCapacity {
+    /// Amount the user can still deposit before reaching the per-user cap
+    pub user_remaining: Option<i128>,
+
+    /// Amount that can still be deposited before the vault reaches the global cap
+    pub global_remaining: Option<i128>,
+}
+
+/// Full deployment parameter set returned by `get_full_config`
+///
+/// Bundles the configuration with the values stored outside of it, so
+/// tooling can verify a deployment with a single typed read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullConfig {
+    /// Contract version (crate version of the deployed WASM)
+    pub version: String,
+
+    /// Admin address
+    pub admin: Address,
+
+    /// Emergency pause state
+    pub paused: bool,
+
+    /// Current epoch number
+    pub current_epoch: u32,
+
+    /// Path used for the epoch BLND → USDC swap
+    pub swap_path: Vec<Address>,
+
+    /// Global configuration (vault, router, tokens, epoch duration,
+    /// reserve IDs, reward shares and other tunables)
+    pub config: Config,
+}
+
+/// Liveness and consistency indicators for monitoring (returned by `health`)
+///
+/// Queries of external contracts that fail are reported as unhealthy rather
+/// than failing the call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthCheck {
+    /// Vault balanced, not paused or stuck in flight, swap pair funded, pool
+    /// not halted and core entries live (epoch timing is left to the
+    /// caller's thresholds)
+    pub healthy: bool,
+
+    /// The vault backend answered and holds at least what players are
+    /// credited with (DirectPool: pool b-tokens; always true otherwise when
+    /// the fee-vault answers, as players hold its shares directly)
+    pub vault_balanced: bool,
+
+    /// USDC b-tokens this contract holds in the Blend pool (DirectPool only)
+    pub vault_b_tokens: i128,
+
+    /// USDC b-tokens credited to players (DirectPool only)
+    pub accounted_b_tokens: i128,
+
+    /// Current ledger sequence
+    pub ledger: u32,
+
+    /// Ledgers since the current epoch was opened (None if it was opened
+    /// before this was tracked)
+    pub ledgers_since_cycle: Option<u32>,
+
+    /// Seconds the current epoch is past its end without being cycled
+    pub cycle_overdue: u64,
+
+    /// A closed epoch is waiting for `process_rewards`
+    pub rewards_unprocessed: bool,
+
+    /// Emergency pause state
+    pub paused: bool,
+
+    /// A guarded fund-moving call is marked in progress (set only during
+    /// such calls, so a stored flag means state is stuck)
+    pub in_flight: bool,
+
+    /// BLND and USDC reserves of the swap pair (0 if the router can't be queried)
+    pub swap_reserves: (i128, i128),
+
+    /// Blend pool status (None if it can't be read); on ice or frozen
+    /// (>= 2) is unhealthy
+    pub pool_status: Option<u32>,
+
+    /// Admin, config, current epoch number and the current epoch's entry
+    /// are all present
+    pub core_entries_live: bool,
 }
 
 // ============================================================================
@@ -213,6 +1775,9 @@ pub struct Config {
 /// Used for all multiplier calculations
 pub const SCALAR_7: i128 = 10_000_000;
 
+/// Fixed-point scalar for Blend b_rate (12 decimals)
+pub const SCALAR_12: i128 = 1_000_000_000_000;
+
 /// Fixed-point representation of 1.0 (with 7 decimals)
 pub const FIXED_POINT_ONE: i128 = SCALAR_7;
 
@@ -0,0 +1,81 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{Env, Map};
+
+use crate::storage;
+use crate::types::{Config, EpochInfo, FACTION_COUNT, SCALAR_7};
+
+// ============================================================================
+// Underdog Multiplier
+// ============================================================================
+//
+// To keep epochs competitive, factions with less stake behind them in the
+// previous epoch earn more FP in the next one. Multipliers are computed from
+// the closed epoch's faction stakes (sum of members' epoch balance snapshots)
+// when a new epoch opens and stored in its EpochInfo:
+//
+//   multiplier = 1.0 + max_underdog_bonus * (largest - stake) / largest
+//
+// The faction with the largest stake stays at 1.0x and a faction with no
+// stake gets the full bonus. Factions at 1.0x are not stored.
+
+/// Compute the faction multipliers for an epoch opening after `closed_stakes`
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (maximum underdog bonus)
+/// * `closed_stakes` - Faction stakes of the epoch just closed
+///
+/// # Returns
+/// Map of faction_id -> FP multiplier (7 decimals) for boosted factions
+pub(crate) fn compute_multipliers(
+    env: &Env,
+    config: &Config,
+    closed_stakes: &Map<u32, i128>,
+) -> Map<u32, i128> {
+    let mut multipliers = Map::new(env);
+    if config.max_underdog_bonus == 0 {
+        return multipliers;
+    }
+
+    let largest = (0..FACTION_COUNT)
+        .map(|faction| closed_stakes.get(faction).unwrap_or(0))
+        .max()
+        .unwrap_or(0);
+    if largest <= 0 {
+        return multipliers;
+    }
+
+    for faction in 0..FACTION_COUNT {
+        let deficit = largest - closed_stakes.get(faction).unwrap_or(0);
+        let bonus = config
+            .max_underdog_bonus
+            .fixed_mul_floor(deficit, largest)
+            .unwrap_or(0);
+        if bonus > 0 {
+            multipliers.set(faction, SCALAR_7 + bonus);
+        }
+    }
+    multipliers
+}
+
+/// FP multiplier of a faction in an epoch (7 decimals, SCALAR_7 = 1.0x)
+pub(crate) fn faction_multiplier(epoch: &EpochInfo, faction: u32) -> i128 {
+    epoch.faction_multipliers.get(faction).unwrap_or(SCALAR_7)
+}
+
+/// FP multipliers of every faction in the current epoch
+///
+/// # Returns
+/// Map of faction_id -> FP multiplier (7 decimals), including 1.0x factions
+pub(crate) fn current_multipliers(env: &Env) -> Map<u32, i128> {
+    let epoch = storage::get_epoch(env, storage::get_current_epoch(env));
+    let mut multipliers = Map::new(env);
+    for faction in 0..FACTION_COUNT {
+        let multiplier = epoch
+            .as_ref()
+            .map(|epoch| faction_multiplier(epoch, faction))
+            .unwrap_or(SCALAR_7);
+        multipliers.set(faction, multiplier);
+    }
+    multipliers
+}
@@ -1,18 +1,317 @@
 use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, token, vec, Address, Env, Vec};
 
-use crate::errors::Error;
+use crate::blend_pool::{Client as PoolClient, Request};
+use crate::errors::{ContractError, Error, ExtError};
 use crate::fee_vault_v2::Client as FeeVaultClient;
+use crate::math::{checked_mul_div, Rounding};
 use crate::storage;
+use crate::swap;
+use crate::types::{
+    Config, DepositCapacity, PreviousFeeVault, QueuedVaultMigration, VaultBackend, SCALAR_12,
+    SCALAR_7,
+};
+
+// ============================================================================
+// Vault Backends
+// ============================================================================
+//
+// With the FeeVault backend (default), players deposit into fee-vault-v2
+// directly and this contract only queries their positions. With DirectPool,
+// this contract supplies USDC to the Blend pool itself, holds the b-tokens and
+// tracks each player's share in `PoolBTokens`. Every other module goes through
+// the functions below and doesn't care which backend is active.
+//
+// `PoolBTokens` are recorded in units of the first pool's b-tokens. Migrating
+// to a new pool moves the whole position at once and updates `PoolBTokenRate`
+// (current b-tokens per recorded unit), so player records never need rewriting.
+// Migrations are timelocked: `queue_migration` first, `migrate_vault` once
+// `VAULT_MIGRATION_DELAY` has passed.
+
+/// Delay between queueing a vault migration and executing it (2 days, in seconds)
+pub(crate) const VAULT_MIGRATION_DELAY: u64 = 172_800;
+
+/// Blend pool request types used by the DirectPool backend
+const REQUEST_SUPPLY: u32 = 0;
+const REQUEST_WITHDRAW: u32 = 1;
+
+/// Blend pool client for the DirectPool backend
+fn pool_client<'a>(env: &'a Env, config: &Config) -> Result<PoolClient<'a>, Error> {
+    let pool = config
+        .blend_pool
+        .clone()
+        .ok_or(Error::FeatureNotConfigured)?;
+    Ok(PoolClient::new(env, &pool))
+}
+
+/// Convert recorded `PoolBTokens` units to USDC at the reserve's current b_rate (12 decimals)
+fn b_tokens_to_underlying(env: &Env, config: &Config, recorded: i128) -> Result<i128, Error> {
+    if recorded == 0 {
+        return Ok(0);
+    }
+    let reserve = pool_client(env, config)?.get_reserve(&config.usdc_token);
+    recorded
+        .fixed_mul_floor(storage::get_pool_b_token_rate(env), SCALAR_12)
+        .and_then(|b_tokens| b_tokens.fixed_mul_floor(reserve.data.b_rate, SCALAR_12))
+        .ok_or(Error::OverflowError)
+}
+
+/// Convert current pool b-tokens to recorded `PoolBTokens` units
+fn to_recorded_b_tokens(env: &Env, b_tokens: i128, rounding: Rounding) -> Result<i128, Error> {
+    let rate = storage::get_pool_b_token_rate(env);
+    checked_mul_div(b_tokens, SCALAR_12, rate, rounding).ok_or(Error::OverflowError)
+}
+
+/// Submit a supply or withdraw request for this contract's pool position
+///
+/// # Returns
+/// Change in the contract's USDC b-token supply (positive on supply)
+fn submit_to_pool(
+    env: &Env,
+    config: &Config,
+    request_type: u32,
+    amount: i128,
+    to: &Address,
+) -> Result<i128, Error> {
+    let pool = pool_client(env, config)?;
+    let current_contract = env.current_contract_address();
+    let index = pool.get_reserve(&config.usdc_token).config.index;
+
+    let before = pool
+        .get_positions(&current_contract)
+        .supply
+        .get(index)
+        .unwrap_or(0);
+    let after = pool
+        .submit(
+            &current_contract,
+            &current_contract,
+            to,
+            &vec![
+                env,
+                Request {
+                    address: config.usdc_token.clone(),
+                    amount,
+                    request_type,
+                },
+            ],
+        )
+        .supply
+        .get(index)
+        .unwrap_or(0);
+
+    Ok(after - before)
+}
+
+/// Supply USDC held by this contract to the Blend pool and credit the b-tokens to `player`
+fn supply_for_player(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let minted = submit_to_pool(
+        env,
+        config,
+        REQUEST_SUPPLY,
+        amount,
+        &env.current_contract_address(),
+    )?;
+    let minted = to_recorded_b_tokens(env, minted, Rounding::Floor)?;
+
+    let balance = storage::get_pool_b_tokens(env, player);
+    storage::set_pool_b_tokens(
+        env,
+        player,
+        balance.checked_add(minted).ok_or(Error::OverflowError)?,
+    );
+    let total = storage::get_total_pool_b_tokens(env);
+    storage::set_total_pool_b_tokens(env, total.checked_add(minted).ok_or(Error::OverflowError)?);
+
+    Ok(())
+}
+
+/// Supply USDC held by this contract to the Blend pool without crediting any player
+///
+/// Backs b-tokens already credited in `PoolBTokens` (see `insurance.rs`).
+///
+/// # Returns
+/// Pool b-tokens minted
+pub(crate) fn supply_uncredited(env: &Env, config: &Config, amount: i128) -> Result<i128, Error> {
+    submit_to_pool(
+        env,
+        config,
+        REQUEST_SUPPLY,
+        amount,
+        &env.current_contract_address(),
+    )
+}
+
+/// Claim yield for the epoch into this contract
+///
+/// FeeVault: withdraws the vault's accrued admin fees and claims pool emissions
+/// through the vault, and does the same for the previous fee-vault until every
+/// player has moved out of it. DirectPool: claims the pool emissions earned by
+/// this contract's position.
+pub(crate) fn claim_yield(env: &Env, config: &Config) -> Result<(), Error> {
+    let current_contract = env.current_contract_address();
+
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let reserve_token_ids = emission_token_ids(env, config)?;
+            claim_fee_vault_yield(env, &config.fee_vault, &reserve_token_ids);
+
+            // Deposits not moved across yet still earn in the previous vault
+            if let Some(previous) = storage::get_previous_fee_vault(env) {
+                let reserve_token_ids = if config.auto_reserve_emissions {
+                    let pool = FeeVaultClient::new(env, &previous.vault).get_config().0;
+                    accruing_token_ids(env, &PoolClient::new(env, &pool))
+                } else {
+                    previous.reserve_token_ids.clone()
+                };
+                claim_fee_vault_yield(env, &previous.vault, &reserve_token_ids);
+
+                if FeeVaultClient::new(env, &previous.vault)
+                    .get_vault()
+                    .total_shares
+                    == 0
+                {
+                    storage::remove_previous_fee_vault(env);
+                }
+            }
+        }
+        VaultBackend::DirectPool => {
+            let reserve_token_ids = emission_token_ids(env, config)?;
+            if !reserve_token_ids.is_empty() {
+                pool_client(env, config)?.claim(
+                    &current_contract,
+                    &reserve_token_ids,
+                    &current_contract,
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Withdraw a fee-vault's admin balance and claim its emissions into this contract
+fn claim_fee_vault_yield(env: &Env, vault: &Address, reserve_token_ids: &Vec<u32>) {
+    let vault_client = FeeVaultClient::new(env, vault);
+
+    // Withdraw the fee-vault admin balance (contract is admin)
+    let admin_balance = vault_client.get_underlying_admin_balance();
+    if admin_balance > 0 {
+        vault_client.admin_withdraw(&admin_balance);
+    }
+
+    // Emissions are separate from admin fees and MUST be claimed explicitly
+    if !reserve_token_ids.is_empty() {
+        vault_client.claim_emissions(reserve_token_ids, &env.current_contract_address());
+    }
+}
+
+/// Reserve token IDs to claim pool emissions for
+///
+/// `config.reserve_token_ids`, unless `config.auto_reserve_emissions` is set:
+/// then every b-token and d-token of the backend's pool (IDs `index * 2` and
+/// `index * 2 + 1` per reserve) that has accrued or is accruing emissions, so
+/// newly listed reserves are picked up without a config change.
+pub(crate) fn emission_token_ids(env: &Env, config: &Config) -> Result<Vec<u32>, Error> {
+    if !config.auto_reserve_emissions {
+        return Ok(config.reserve_token_ids.clone());
+    }
+
+    Ok(accruing_token_ids(env, &emission_pool(env, config)?))
+}
+
+/// Every b-token and d-token ID of a pool that has accrued or is accruing emissions
+fn accruing_token_ids(env: &Env, pool: &PoolClient) -> Vec<u32> {
+    let mut reserve_token_ids = Vec::new(env);
+    for index in 0..pool.get_reserve_list().len() {
+        for token_id in [index * 2, index * 2 + 1] {
+            let accruing = pool
+                .get_reserve_emissions(&token_id)
+                .is_some_and(|emissions| emissions.eps > 0 || emissions.index > 0);
+            if accruing {
+                reserve_token_ids.push_back(token_id);
+            }
+        }
+    }
+    reserve_token_ids
+}
+
+/// Map reserve token IDs from one Blend pool to another by reserve asset
+///
+/// Reserve indexes differ between pools; each ID keeps its asset and its token
+/// type (d-token `index * 2` or b-token `index * 2 + 1`).
+///
+/// # Errors
+/// * `InvalidConfig` - If an ID's reserve isn't listed on the old pool, or its
+///   asset isn't listed on the new one
+fn remap_reserve_token_ids(
+    env: &Env,
+    reserve_token_ids: &Vec<u32>,
+    old_pool: &Address,
+    new_pool: &Address,
+) -> Result<Vec<u32>, Error> {
+    let old_reserves = PoolClient::new(env, old_pool).get_reserve_list();
+    let new_reserves = PoolClient::new(env, new_pool).get_reserve_list();
+    let mut remapped = Vec::new(env);
+    for token_id in reserve_token_ids.iter() {
+        let asset = old_reserves.get(token_id / 2).ok_or(Error::InvalidConfig)?;
+        let index = new_reserves
+            .first_index_of(&asset)
+            .ok_or(Error::InvalidConfig)?;
+        remapped.push_back(index * 2 + token_id % 2);
+    }
+    Ok(remapped)
+}
+
+/// Check that every reserve token ID exists on the backend's pool
+///
+/// Both d-token (`index * 2`) and b-token (`index * 2 + 1`) IDs are accepted,
+/// as long as `index` is a listed reserve and no ID is repeated.
+///
+/// # Errors
+/// * `InvalidConfig` - If an ID is repeated or its reserve isn't listed on the pool
+/// * `FeatureNotConfigured` - If DirectPool is active without a pool
+pub(crate) fn validate_reserve_token_ids(
+    env: &Env,
+    config: &Config,
+    reserve_token_ids: &Vec<u32>,
+) -> Result<(), Error> {
+    let reserve_count = emission_pool(env, config)?.get_reserve_list().len();
+    let mut seen = Vec::new(env);
+    for token_id in reserve_token_ids.iter() {
+        if token_id / 2 >= reserve_count || seen.contains(token_id) {
+            return Err(Error::InvalidConfig);
+        }
+        seen.push_back(token_id);
+    }
+    Ok(())
+}
+
+/// Blend pool the backend's emissions accrue on
+fn emission_pool<'a>(env: &'a Env, config: &Config) -> Result<PoolClient<'a>, Error> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => Ok(PoolClient::new(
+            env,
+            &FeeVaultClient::new(env, &config.fee_vault).get_config().0,
+        )),
+        VaultBackend::DirectPool => pool_client(env, config),
+    }
+}
 
 // ============================================================================
 // Vault Query Operations
 // ============================================================================
 
-/// Query the player's underlying token balance from fee-vault-v2
+/// Query the player's underlying token balance from the vault backend
 ///
 /// This is the primary way to check a player's vault position in the new architecture.
-/// The contract no longer tracks balances internally - we query the vault directly.
+/// With FeeVault the contract doesn't track balances - we query the vault directly,
+/// adding what the player hasn't moved out of the previous fee-vault yet.
 ///
 /// # Arguments
 /// * `env` - Contract environment
@@ -22,8 +321,913 @@ use crate::storage;
 /// * Player's underlying token balance in the vault
 pub(crate) fn get_vault_balance(env: &Env, player: &Address) -> i128 {
     let config = storage::get_config(env);
-    let vault_client = FeeVaultClient::new(env, &config.fee_vault);
-    vault_client.get_underlying_tokens(player)
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let vault_client = FeeVaultClient::new(env, &config.fee_vault);
+            let balance = vault_client.get_underlying_tokens(player);
+            match storage::get_previous_fee_vault(env) {
+                Some(previous) => {
+                    let unmoved =
+                        FeeVaultClient::new(env, &previous.vault).get_underlying_tokens(player);
+                    balance.saturating_add(unmoved)
+                }
+                None => balance,
+            }
+        }
+        VaultBackend::DirectPool => {
+            let b_tokens = storage::get_pool_b_tokens(env, player);
+            b_tokens_to_underlying(env, &config, b_tokens)
+                .unwrap_or_else(|e| panic_with_error!(env, e))
+        }
+    }
+}
+
+/// Query the total underlying tokens held by the vault backend for all depositors
+///
+/// Computed from the b-token supply and current b_rate (12 decimals).
+pub(crate) fn get_vault_tvl(env: &Env, config: &Config) -> Result<i128, Error> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let vault_client = FeeVaultClient::new(env, &config.fee_vault);
+            let vault = vault_client.get_vault();
+            vault
+                .total_b_tokens
+                .fixed_mul_floor(vault.b_rate, SCALAR_12)
+                .ok_or(Error::OverflowError)
+        }
+        VaultBackend::DirectPool => {
+            b_tokens_to_underlying(env, config, storage::get_total_pool_b_tokens(env))
+        }
+    }
+}
+
+/// Current b_rate (12 decimals) and TVL of the vault backend
+///
+/// Returns None if the backend can't be queried, so yield snapshots never
+/// block an epoch cycle.
+pub(crate) fn try_get_b_rate_and_tvl(env: &Env, config: &Config) -> Option<(i128, i128)> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let vault = FeeVaultClient::new(env, &config.fee_vault)
+                .try_get_vault()
+                .ok()?
+                .ok()?;
+            let tvl = vault
+                .total_b_tokens
+                .fixed_mul_floor(vault.b_rate, SCALAR_12)?;
+            Some((vault.b_rate, tvl))
+        }
+        VaultBackend::DirectPool => {
+            let reserve = pool_client(env, config)
+                .ok()?
+                .try_get_reserve(&config.usdc_token)
+                .ok()?
+                .ok()?;
+            let b_rate = reserve.data.b_rate;
+            let tvl = storage::get_total_pool_b_tokens(env)
+                .fixed_mul_floor(storage::get_pool_b_token_rate(env), SCALAR_12)?
+                .fixed_mul_floor(b_rate, SCALAR_12)?;
+            Some((b_rate, tvl))
+        }
+    }
+}
+
+/// USDC b-tokens held in the pool and credited to players, as (held, credited)
+///
+/// Both are 0 with the FeeVault backend, where players hold vault shares
+/// directly. Returns None if the backend can't be queried.
+pub(crate) fn try_get_b_token_balances(env: &Env, config: &Config) -> Option<(i128, i128)> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            FeeVaultClient::new(env, &config.fee_vault)
+                .try_get_vault()
+                .ok()?
+                .ok()?;
+            Some((0, 0))
+        }
+        VaultBackend::DirectPool => {
+            let pool = pool_client(env, config).ok()?;
+            let index = pool
+                .try_get_reserve(&config.usdc_token)
+                .ok()?
+                .ok()?
+                .config
+                .index;
+            let held = pool
+                .try_get_positions(&env.current_contract_address())
+                .ok()?
+                .ok()?
+                .supply
+                .get(index)
+                .unwrap_or(0);
+            let credited = storage::get_total_pool_b_tokens(env)
+                .fixed_mul_floor(storage::get_pool_b_token_rate(env), SCALAR_12)?;
+            Some((held, credited))
+        }
+    }
+}
+
+/// USDC claimable from the vault backend's admin balance
+///
+/// Returns None if the backend can't be queried (always 0 with DirectPool).
+pub(crate) fn try_get_admin_balance(env: &Env, config: &Config) -> Option<i128> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => FeeVaultClient::new(env, &config.fee_vault)
+            .try_get_underlying_admin_balance()
+            .ok()?
+            .ok(),
+        VaultBackend::DirectPool => Some(0),
+    }
+}
+
+/// Estimate the BLND emissions `claim_yield` would claim right now
+///
+/// Replays the pool's emission accrual for the backend's position (the
+/// fee-vault's, or this contract's with DirectPool) on every claimed reserve
+/// token: the reserve index is brought up to now from `eps`, and the holder
+/// accrues its balance times the index change since its last update.
+///
+/// Returns None if the pool can't be queried, so views never fail on it.
+pub(crate) fn try_estimate_emissions(env: &Env, config: &Config) -> Option<i128> {
+    let token_ids = emission_token_ids(env, config).ok()?;
+    if token_ids.is_empty() {
+        return Some(0);
+    }
+
+    let pool = try_emission_pool(env, config)?;
+    let holder = match config.vault_backend {
+        VaultBackend::FeeVault => config.fee_vault.clone(),
+        VaultBackend::DirectPool => env.current_contract_address(),
+    };
+    let reserve_list = pool.try_get_reserve_list().ok()?.ok()?;
+    let positions = pool.try_get_positions(&holder).ok()?.ok()?;
+    let now = env.ledger().timestamp();
+
+    let mut total: i128 = 0;
+    for token_id in token_ids.iter() {
+        let Some(emissions) = pool.try_get_reserve_emissions(&token_id).ok()?.ok()? else {
+            continue;
+        };
+        let index = token_id / 2;
+        let reserve = pool.try_get_reserve(&reserve_list.get(index)?).ok()?.ok()?;
+        let (supply, balance) = if token_id % 2 == 1 {
+            let balance = positions.supply.get(index).unwrap_or(0)
+                + positions.collateral.get(index).unwrap_or(0);
+            (reserve.data.b_supply, balance)
+        } else {
+            let balance = positions.liabilities.get(index).unwrap_or(0);
+            (reserve.data.d_supply, balance)
+        };
+
+        // Reserve index as of now (accrual stops at expiration)
+        let accrue_until = now.min(emissions.expiration);
+        let mut reserve_index = emissions.index;
+        if accrue_until > emissions.last_time && emissions.eps > 0 && supply > 0 {
+            let emitted = ((accrue_until - emissions.last_time) as i128)
+                .checked_mul(emissions.eps as i128)?;
+            reserve_index =
+                reserve_index.checked_add(emitted.fixed_div_floor(supply, reserve.scalar)?)?;
+        }
+
+        let (accrued, user_index) =
+            match pool.try_get_user_emissions(&holder, &token_id).ok()?.ok()? {
+                Some(user) => (user.accrued, user.index),
+                None => (0, 0),
+            };
+        let to_accrue = balance.fixed_mul_floor(
+            reserve_index - user_index,
+            reserve.scalar.checked_mul(SCALAR_7)?,
+        )?;
+        total = total.checked_add(accrued)?.checked_add(to_accrue)?;
+    }
+    Some(total)
+}
+
+/// `emission_pool`, or None if the backend's pool can't be resolved
+fn try_emission_pool<'a>(env: &'a Env, config: &Config) -> Option<PoolClient<'a>> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let (pool, _) = FeeVaultClient::new(env, &config.fee_vault)
+                .try_get_config()
+                .ok()?
+                .ok()?;
+            Some(PoolClient::new(env, &pool))
+        }
+        VaultBackend::DirectPool => pool_client(env, config).ok(),
+    }
+}
+
+// ============================================================================
+// Deposit Caps
+// ============================================================================
+//
+// Guarded-launch limits on deposits routed through this contract. Players can
+// still deposit into fee-vault-v2 directly; those deposits count towards both
+// caps but can't be blocked here. Reward and referral payouts are never capped.
+//
+// With the FeeVault backend the per-user cap is also enforced where it matters
+// for the game: FP is computed from the balance capped at
+// `max_deposit_per_user` (see `snapshot::fp_balance`), so a direct deposit
+// above the cap earns nothing. The global cap has no such backstop - refusing
+// games once the fee-vault is over it would let any outside depositor halt
+// play - so it only bounds deposits made through this contract. Use the
+// DirectPool backend for a hard global cap.
+
+/// Remaining deposit room for a user under the configured caps
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - User whose per-user room to compute
+pub(crate) fn get_capacity(env: &Env, user: &Address) -> Result<DepositCapacity, Error> {
+    let config = storage::get_config(env);
+
+    let user_remaining = if config.max_deposit_per_user > 0 {
+        let balance = get_vault_balance(env, user);
+        Some((config.max_deposit_per_user - balance).max(0))
+    } else {
+        None
+    };
+
+    let global_remaining = if config.max_total_deposits > 0 {
+        let tvl = get_vault_tvl(env, &config)?;
+        Some((config.max_total_deposits - tvl).max(0))
+    } else {
+        None
+    };
+
+    Ok(DepositCapacity {
+        user_remaining,
+        global_remaining,
+    })
+}
+
+/// Check that `user` may deposit and play while whitelist mode is enabled
+///
+/// # Errors
+/// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+pub(crate) fn require_whitelisted(env: &Env, config: &Config, user: &Address) -> Result<(), Error> {
+    if config.whitelist_enabled && !storage::is_whitelisted(env, user) {
+        return Err(Error::NotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Check that depositing `amount` for `user` stays within the deposit caps
+///
+/// With the FeeVault backend this only bounds deposits routed through this
+/// contract: players can still deposit into the fee-vault directly.
+///
+/// # Errors
+/// * `UserDepositCapExceeded` - If the user's balance would exceed the per-user cap
+/// * `GlobalDepositCapExceeded` - If the vault's total would exceed the global cap
+fn require_within_caps(
+    env: &Env,
+    config: &Config,
+    user: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if config.max_deposit_per_user > 0 {
+        let new_balance = get_vault_balance(env, user)
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+        if new_balance > config.max_deposit_per_user {
+            return Err(Error::UserDepositCapExceeded);
+        }
+    }
+
+    if config.max_total_deposits > 0 {
+        let new_tvl = get_vault_tvl(env, config)?
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+        if new_tvl > config.max_total_deposits {
+            return Err(Error::GlobalDepositCapExceeded);
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Blend Pool Status
+// ============================================================================
+//
+// A Blend pool reports a status: 0-1 active, 2-3 on ice (borrowing disabled),
+// 4-5 frozen (supplying disabled too) and 6 setup; the even value of each
+// pair is set by the pool admin, the odd one by the backstop. Deposits routed
+// through this contract are refused from on ice onwards, since that is how a
+// pool in trouble is first flagged. Withdrawals are never blocked, so players
+// can always leave. If the status can't be read (e.g. the fee-vault doesn't
+// answer `get_config`), deposits go through as before.
+//
+// A refused deposit can't emit anything, so `sync_pool_status` (callable by
+// anyone, e.g. a keeper) records the status and emits `PoolStatusChanged`
+// whenever it changes. With `set_pool_halt_pauses_games`, game starts are
+// refused too while the pool is halted.
+
+/// First pool status that halts deposits (on ice, set by the pool admin)
+pub(crate) const POOL_STATUS_ON_ICE: u32 = 2;
+
+/// Check if a pool status halts deposits (on ice, frozen or setup)
+pub(crate) fn is_pool_halted(status: u32) -> bool {
+    status >= POOL_STATUS_ON_ICE
+}
+
+/// The backend's Blend pool and its status, or None if either can't be read
+pub(crate) fn try_pool_status(env: &Env, config: &Config) -> Option<(Address, u32)> {
+    let pool = try_emission_pool(env, config)?;
+    let status = pool.try_get_config().ok()?.ok()?.status;
+    Some((pool.address.clone(), status))
+}
+
+/// Check that the Blend pool accepts deposits
+///
+/// # Errors
+/// * `PoolHalted` - If the pool is on ice or frozen
+pub(crate) fn require_pool_accepting_deposits(env: &Env, config: &Config) -> Result<(), ExtError> {
+    if let Some((_, status)) = try_pool_status(env, config) {
+        if is_pool_halted(status) {
+            return Err(ExtError::PoolHalted);
+        }
+    }
+    Ok(())
+}
+
+/// Check that games may start, if the admin blocks them while the pool is halted
+///
+/// # Errors
+/// * `PoolHalted` - If blocking is on and the pool is on ice or frozen
+pub(crate) fn require_pool_open_for_games(env: &Env, config: &Config) -> Result<(), ExtError> {
+    if storage::get_pool_halt_pauses_games(env) {
+        require_pool_accepting_deposits(env, config)?;
+    }
+    Ok(())
+}
+
+/// Record the Blend pool's current status, emitting `PoolStatusChanged` if it changed
+///
+/// # Returns
+/// The pool status
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If the pool or its status can't be read
+pub(crate) fn sync_pool_status(env: &Env) -> Result<u32, Error> {
+    let config = storage::get_config(env);
+    let (pool, status) = try_pool_status(env, &config).ok_or(Error::FeatureNotConfigured)?;
+
+    let old_status = storage::get_pool_status(env);
+    if old_status != Some(status) {
+        storage::set_pool_status(env, status);
+        crate::events::emit_pool_status_changed(
+            env,
+            &pool,
+            old_status,
+            status,
+            is_pool_halted(status),
+        );
+    }
+
+    Ok(status)
+}
+
+// ============================================================================
+// Vault Deposit Operations
+// ============================================================================
+
+/// Deposit USDC held by this contract into the vault backend on behalf of a player
+///
+/// FeeVault:
+/// 1. USDC is transferred from contract → player
+/// 2. Vault deposit is called, transferring USDC from player → fee-vault
+///
+/// Note: Player must authorize both the calling entrypoint AND the vault deposit
+/// in their transaction
+///
+/// DirectPool: the contract supplies the USDC to the Blend pool and credits the
+/// b-tokens to the player (no extra authorization needed).
+pub(crate) fn deposit_for_player(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            // Step 1: Transfer USDC from contract to player
+            let usdc_client = token::Client::new(env, &config.usdc_token);
+            usdc_client.transfer(&env.current_contract_address(), player, &amount);
+
+            // Step 2: Deposit into fee-vault on behalf of player
+            let vault_client = FeeVaultClient::new(env, &config.fee_vault);
+            let _shares_minted = vault_client.deposit(player, &amount);
+        }
+        VaultBackend::DirectPool => supply_for_player(env, config, player, amount)?,
+    }
+    Ok(())
+}
+
+/// Pay USDC to a player who may not have signed the transaction
+///
+/// Same as `deposit_for_player`, except that with the FeeVault backend an
+/// unauthorized player (e.g. a delegated claim) gets a plain USDC transfer,
+/// since fee-vault-v2 deposits need the player's authorization.
+pub(crate) fn pay_player(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    amount: i128,
+    player_authorized: bool,
+) -> Result<(), Error> {
+    if player_authorized || config.vault_backend == VaultBackend::DirectPool {
+        return deposit_for_player(env, config, player, amount);
+    }
+
+    token::Client::new(env, &config.usdc_token).transfer(
+        &env.current_contract_address(),
+        player,
+        &amount,
+    );
+    Ok(())
+}
+
+/// Deposit USDC into the Blend pool through this contract (DirectPool backend)
+///
+/// With the FeeVault backend players deposit into fee-vault-v2 directly instead.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - Player depositing USDC
+/// * `amount` - Amount of USDC to deposit
+///
+/// # Returns
+/// Player's vault balance after the deposit
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+/// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+/// * `PoolHalted` - If the Blend pool is on ice or frozen
+/// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+/// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+pub(crate) fn deposit(env: &Env, user: &Address, amount: i128) -> Result<i128, ContractError> {
+    // Authenticate user
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::DirectPool {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+
+    deposit_from_user(env, &config, user, amount)?;
+
+    let pool = pool_client(env, &config)?.address;
+    crate::events::emit_deposited(env, user, user, &pool, amount);
+
+    Ok(get_vault_balance(env, user))
+}
+
+/// Deposit USDC from a token allowance into the Blend pool (DirectPool backend)
+///
+/// For contracts and smart wallets that can't authorize the nested USDC
+/// transfer of `deposit`: `from` approves this contract on the USDC token
+/// (SEP-41 `approve`), then calls this to pull the USDC with `transfer_from`
+/// and credit it to `user`. Only `from` authorizes the call.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `from` - Account whose allowance funds the deposit
+/// * `user` - Player credited with the deposit
+/// * `amount` - Amount of USDC to deposit
+///
+/// # Returns
+/// Player's vault balance after the deposit
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+/// * `InsufficientBalance` - If the allowance doesn't cover the amount
+/// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+/// * `PoolHalted` - If the Blend pool is on ice or frozen
+/// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+/// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+pub(crate) fn deposit_from_allowance(
+    env: &Env,
+    from: &Address,
+    user: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    from.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::DirectPool {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    let this = env.current_contract_address();
+    if usdc_client.allowance(from, &this) < amount {
+        return Err(Error::InsufficientBalance.into());
+    }
+
+    require_whitelisted(env, &config, user)?;
+    require_pool_accepting_deposits(env, &config)?;
+    require_within_caps(env, &config, user, amount)?;
+
+    usdc_client.transfer_from(&this, from, &this, &amount);
+    supply_for_player(env, &config, user, amount)?;
+    crate::stats::record_deposit(env, amount)?;
+
+    let pool = pool_client(env, &config)?.address;
+    crate::events::emit_deposited(env, user, from, &pool, amount);
+
+    Ok(get_vault_balance(env, user))
+}
+
+/// Deposit the user's own USDC into the vault backend, within the deposit caps
+///
+/// FeeVault: calls fee-vault-v2 `deposit` for the user (the user must
+/// authorize the nested vault deposit). DirectPool: pulls the USDC into this
+/// contract and supplies it to the Blend pool.
+///
+/// # Errors
+/// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+/// * `PoolHalted` - If the Blend pool is on ice or frozen
+/// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+/// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+pub(crate) fn deposit_from_user(
+    env: &Env,
+    config: &Config,
+    user: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    require_whitelisted(env, config, user)?;
+    require_pool_accepting_deposits(env, config)?;
+    require_within_caps(env, config, user, amount)?;
+
+    match config.vault_backend {
+        VaultBackend::FeeVault => {
+            let vault_client = FeeVaultClient::new(env, &config.fee_vault);
+            let _shares_minted = vault_client.deposit(user, &amount);
+        }
+        VaultBackend::DirectPool => {
+            let usdc_client = token::Client::new(env, &config.usdc_token);
+            usdc_client.transfer(user, &env.current_contract_address(), &amount);
+
+            supply_for_player(env, config, user, amount)?;
+        }
+    }
+
+    crate::stats::record_deposit(env, amount)?;
+    Ok(())
+}
+
+/// Withdraw USDC from the Blend pool through this contract (DirectPool backend)
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - Player withdrawing USDC
+/// * `amount` - Amount of USDC to withdraw
+///
+/// # Returns
+/// Player's vault balance after the withdrawal
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `FeatureNotConfigured` - If the DirectPool backend isn't active
+/// * `InsufficientBalance` - If the player's b-tokens don't cover the withdrawal
+/// * `DepositLocked` - If the player's balance is locked (see `lock.rs`)
+pub(crate) fn withdraw(env: &Env, user: &Address, amount: i128) -> Result<i128, ContractError> {
+    // Authenticate user
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+    crate::lock::require_unlocked(env, user)?;
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::DirectPool {
+        return Err(Error::FeatureNotConfigured.into());
+    }
+
+    // Pool burns b-tokens rounded up; charge the player exactly what was burnt
+    let burnt = -submit_to_pool(env, &config, REQUEST_WITHDRAW, amount, user)?;
+    let burnt = to_recorded_b_tokens(env, burnt, Rounding::Ceil)?;
+
+    let balance = storage::get_pool_b_tokens(env, user);
+    if burnt > balance {
+        return Err(Error::InsufficientBalance.into());
+    }
+    storage::set_pool_b_tokens(env, user, balance - burnt);
+    let total = storage::get_total_pool_b_tokens(env);
+    storage::set_total_pool_b_tokens(env, total - burnt);
+
+    let pool = pool_client(env, &config)?.address;
+    crate::events::emit_withdrawn(env, user, &pool, amount);
+
+    Ok(get_vault_balance(env, user))
+}
+
+/// Queue a migration of the vault backend to `new_vault`
+///
+/// `migrate_vault` can execute it once `VAULT_MIGRATION_DELAY` has passed,
+/// giving players time to withdraw before their deposits move. Queueing
+/// replaces any migration queued earlier.
+///
+/// Caller must have authenticated the admin.
+///
+/// # Errors
+/// * `InvalidConfig` - If new_vault is the current vault
+pub(crate) fn queue_migration(
+    env: &Env,
+    new_vault: &Address,
+) -> Result<QueuedVaultMigration, Error> {
+    let config = storage::get_config(env);
+    let current_vault = match config.vault_backend {
+        VaultBackend::FeeVault => Some(config.fee_vault),
+        VaultBackend::DirectPool => config.blend_pool,
+    };
+    if current_vault.as_ref() == Some(new_vault) {
+        return Err(Error::InvalidConfig);
+    }
+
+    let migration = QueuedVaultMigration {
+        new_vault: new_vault.clone(),
+        executable_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(VAULT_MIGRATION_DELAY),
+    };
+    storage::set_queued_vault_migration(env, Some(&migration));
+    Ok(migration)
+}
+
+/// Use up the queued migration to `new_vault` once its timelock has passed
+fn take_queued_migration(env: &Env, new_vault: &Address) -> Result<(), ExtError> {
+    let Some(migration) = storage::get_queued_vault_migration(env) else {
+        return Err(ExtError::VaultMigrationNotQueued);
+    };
+    if migration.new_vault != *new_vault {
+        return Err(ExtError::VaultMigrationNotQueued);
+    }
+    if env.ledger().timestamp() < migration.executable_at {
+        return Err(ExtError::VaultMigrationTimelocked);
+    }
+    storage::set_queued_vault_migration(env, None);
+    Ok(())
+}
+
+/// Move the vault backend to a new fee-vault or Blend pool
+///
+/// Executes the migration queued with `queue_migration`. Claims the old backend's pending yield into this contract first, so no
+/// epoch rewards are left behind: BLND is swapped by the next cycle as usual
+/// and USDC fees are added to the pending-reward buffer. The configured reserve
+/// token IDs are then mapped to the new pool's reserves by asset.
+///
+/// FeeVault: `new_vault` is a fee-vault-v2 for USDC. Player deposits are owned
+/// by the players themselves and withdrawing them needs each player's
+/// authorization, so the old vault is remembered and every player moves their
+/// balance across with `migrate_position`. Until they all have, the old vault's
+/// yield is still claimed (see `PreviousFeeVault`).
+/// DirectPool: `new_vault` is a Blend pool. The contract withdraws its whole
+/// USDC position from the old pool and supplies it to the new one, rescaling
+/// all player positions through `PoolBTokenRate`.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `new_vault` - New fee-vault (FeeVault) or Blend pool (DirectPool)
+///
+/// # Returns
+/// (old vault address, USDC moved to the new pool)
+///
+/// # Errors
+/// * `VaultMigrationNotQueued` - If no migration to new_vault is queued
+/// * `VaultMigrationTimelocked` - If the queued migration's delay hasn't passed
+/// * `InvalidConfig` - If new_vault is the current vault, a fee-vault for another
+///   asset, or a pool missing the reserve of a configured reserve token ID
+/// * `FeatureNotConfigured` - If DirectPool is active without a pool
+pub(crate) fn migrate_vault(
+    env: &Env,
+    new_vault: &Address,
+) -> Result<(Address, i128), ContractError> {
+    take_queued_migration(env, new_vault)?;
+
+    let mut config = storage::get_config(env);
+    let current_contract = env.current_contract_address();
+    let usdc = token::Client::new(env, &config.usdc_token);
+
+    let usdc_before = usdc.balance(&current_contract);
+    claim_yield(env, &config)?;
+    let fees = usdc.balance(&current_contract) - usdc_before;
+    if fees > 0 {
+        let pending = storage::get_pending_rewards(env)
+            .checked_add(fees)
+            .ok_or(Error::OverflowError)?;
+        storage::set_pending_rewards(env, pending);
+    }
+
+    let old_emission_pool = emission_pool(env, &config)?.address;
+    let (old_vault, new_pool, moved) = match config.vault_backend {
+        VaultBackend::FeeVault => {
+            if *new_vault == config.fee_vault {
+                return Err(Error::InvalidConfig.into());
+            }
+            let (pool, asset) = FeeVaultClient::new(env, new_vault).get_config();
+            if asset != config.usdc_token {
+                return Err(Error::InvalidConfig.into());
+            }
+
+            let old_vault = config.fee_vault.clone();
+            storage::set_previous_fee_vault(
+                env,
+                &PreviousFeeVault {
+                    vault: old_vault.clone(),
+                    reserve_token_ids: config.reserve_token_ids.clone(),
+                },
+            );
+            config.fee_vault = new_vault.clone();
+            (old_vault, pool, 0)
+        }
+        VaultBackend::DirectPool => {
+            let old_pool = config
+                .blend_pool
+                .clone()
+                .ok_or(Error::FeatureNotConfigured)?;
+            if *new_vault == old_pool {
+                return Err(Error::InvalidConfig.into());
+            }
+
+            // Withdraw the whole position (the pool caps the request at the balance)
+            let before = usdc.balance(&current_contract);
+            let pool = pool_client(env, &config)?;
+            let index = pool.get_reserve(&config.usdc_token).config.index;
+            let position = pool
+                .get_positions(&current_contract)
+                .supply
+                .get(index)
+                .unwrap_or(0);
+            if position > 0 {
+                submit_to_pool(env, &config, REQUEST_WITHDRAW, i128::MAX, &current_contract)?;
+            }
+            let moved = usdc.balance(&current_contract) - before;
+
+            config.blend_pool = Some(new_vault.clone());
+            (old_pool, new_vault.clone(), moved)
+        }
+    };
+
+    // Emission token IDs are reserve indexes, which differ between pools
+    config.reserve_token_ids = remap_reserve_token_ids(
+        env,
+        &config.reserve_token_ids,
+        &old_emission_pool,
+        &new_pool,
+    )?;
+    validate_reserve_token_ids(env, &config, &config.reserve_token_ids)?;
+    storage::set_config(env, &config);
+
+    if moved > 0 {
+        let minted = submit_to_pool(env, &config, REQUEST_SUPPLY, moved, &current_contract)?;
+        let recorded = storage::get_total_pool_b_tokens(env);
+        if recorded > 0 {
+            let rate = minted
+                .fixed_div_floor(recorded, SCALAR_12)
+                .ok_or(Error::OverflowError)?;
+            storage::set_pool_b_token_rate(env, rate);
+        }
+    }
+
+    // b_rates of different pools aren't comparable, so yield tracking restarts
+    storage::set_yield_history(env, &soroban_sdk::Vec::new(env));
+
+    Ok((old_vault, moved))
+}
+
+/// Move a player's deposit from the previous fee-vault into the current one
+///
+/// After a FeeVault migration players still hold shares of the old vault.
+/// This withdraws their whole balance there and deposits it into the new
+/// vault. The player must authorize this call and both nested vault calls.
+/// Deposit caps don't apply, since nothing new is deposited.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player moving their deposit
+///
+/// # Returns
+/// USDC moved into the current fee-vault
+///
+/// # Errors
+/// * `FeatureNotConfigured` - If the FeeVault backend isn't active, or it was
+///   never migrated
+/// * `InsufficientBalance` - If the player has nothing left in the previous vault
+pub(crate) fn migrate_position(env: &Env, player: &Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let config = storage::get_config(env);
+    if config.vault_backend != VaultBackend::FeeVault {
+        return Err(Error::FeatureNotConfigured);
+    }
+    let old_vault = storage::get_previous_fee_vault(env)
+        .ok_or(Error::FeatureNotConfigured)?
+        .vault;
+
+    let old_client = FeeVaultClient::new(env, &old_vault);
+    let amount = old_client.get_underlying_tokens(player);
+    if amount <= 0 {
+        return Err(Error::InsufficientBalance);
+    }
+    old_client.withdraw(player, &amount);
+    FeeVaultClient::new(env, &config.fee_vault).deposit(player, &amount);
+
+    crate::events::emit_withdrawn(env, player, &old_vault, amount);
+    crate::events::emit_deposited(env, player, player, &config.fee_vault, amount);
+
+    Ok(amount)
+}
+
+/// Deposit XLM into the fee-vault, converting it to USDC via Soroswap first
+///
+/// Onboarding helper for players who hold XLM but no USDC:
+/// 1. Transfer XLM from user → contract
+/// 2. Swap XLM → USDC via the configured Soroswap router
+/// 3. Deposit the resulting USDC into fee-vault on behalf of the user
+///
+/// The user must authorize the XLM transfer, this call, and the vault deposit.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - Player depositing XLM
+/// * `amount` - Amount of XLM to convert (7 decimals)
+/// * `min_usdc_out` - Minimum USDC to receive from the swap (slippage protection)
+///
+/// # Returns
+/// Amount of USDC deposited into fee-vault
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0 or min_usdc_out < 0
+/// * `FeatureNotConfigured` - If the admin hasn't set the XLM token address
+/// * `SlippageExceeded` - If the swap yields less than min_usdc_out
+/// * `NotWhitelisted` - If whitelist mode is on and the user isn't whitelisted
+/// * `PoolHalted` - If the Blend pool is on ice or frozen
+/// * `SwapError` - If the swap fails or yields nothing
+/// * `UserDepositCapExceeded` - If the deposit exceeds the per-user cap
+/// * `GlobalDepositCapExceeded` - If the deposit exceeds the global cap
+pub(crate) fn deposit_xlm(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+    min_usdc_out: i128,
+) -> Result<i128, ContractError> {
+    // Authenticate user
+    user.require_auth();
+
+    if amount <= 0 || min_usdc_out < 0 {
+        return Err(Error::InvalidAmount.into());
+    }
+
+    let config = storage::get_config(env);
+    let xlm_token = config
+        .xlm_token
+        .clone()
+        .ok_or(Error::FeatureNotConfigured)?;
+    require_whitelisted(env, &config, user)?;
+    require_pool_accepting_deposits(env, &config)?;
+
+    // Step 1: Pull XLM from the user
+    let xlm_client = token::Client::new(env, &xlm_token);
+    xlm_client.transfer(user, &env.current_contract_address(), &amount);
+
+    // Step 2: Convert XLM → USDC
+    let usdc_amount = swap::swap_exact_in(
+        env,
+        &config.soroswap_router,
+        &xlm_token,
+        &config.usdc_token,
+        amount,
+        min_usdc_out,
+    )?;
+    if usdc_amount <= 0 {
+        return Err(Error::SwapError.into());
+    }
+
+    // Caps apply to the USDC actually deposited
+    require_within_caps(env, &config, user, usdc_amount)?;
+
+    // Step 3: Deposit into the vault backend on behalf of the user
+    deposit_for_player(env, &config, user, usdc_amount)?;
+    crate::stats::record_deposit(env, usdc_amount)?;
+
+    crate::events::emit_xlm_deposited(env, user, amount, usdc_amount);
+
+    Ok(usdc_amount)
 }
 
 // ============================================================================
@@ -71,12 +1275,16 @@ pub(crate) fn check_cross_epoch_withdrawal_reset(
 
     // Calculate withdrawal percentage (as fixed-point with 7 decimals)
     // Formula: abs(net_change) / last_epoch_balance > 0.5
-    // SECURITY: Use fixed_div_ceil to round UP (more conservative, favors protocol)
+    // SECURITY: Round UP (more conservative, favors protocol)
     // Example: 50.1% withdrawal rounds to ceiling → more likely to trigger reset
     let abs_withdrawal = -net_change;
-    let withdrawal_ratio = abs_withdrawal
-        .fixed_div_ceil(player_data.last_epoch_balance, crate::types::SCALAR_7)
-        .ok_or(Error::OverflowError)?;
+    let withdrawal_ratio = checked_mul_div(
+        abs_withdrawal,
+        crate::types::SCALAR_7,
+        player_data.last_epoch_balance,
+        Rounding::Ceil,
+    )
+    .ok_or(Error::OverflowError)?;
 
     // Check if > 50% (use constant for efficiency)
     let reset = withdrawal_ratio > crate::types::WITHDRAWAL_RESET_THRESHOLD;
@@ -0,0 +1,149 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::events::{emit_reward_vesting_started, emit_vested_rewards_claimed};
+use crate::math::{checked_mul_div, Rounding};
+use crate::storage;
+use crate::types::{Config, VestingSchedule};
+
+// ============================================================================
+// Reward Vesting
+// ============================================================================
+//
+// Reward claims above `config.vesting_threshold` are not paid out at once.
+// They are added to the player's vesting schedule and released linearly over
+// `config.vesting_ledgers`; `claim_vested` deposits whatever has been released
+// so far into the fee-vault (same payout path as `claim_epoch_reward`).
+//
+// A player has a single schedule. A new vested claim releases what is due so
+// far, adds the claim to the locked balance and moves the end ledger out to
+// `now + vesting_ledgers`, so any earlier locked remainder is spread over the
+// new window as well.
+
+/// Pay a claimed reward, vesting it if it is above the threshold
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (vesting threshold and length)
+/// * `player` - Player receiving the reward
+/// * `epoch` - Epoch the reward was claimed from
+/// * `amount` - Reward amount (net of any referral share)
+/// * `player_authorized` - Whether the player signed (false for delegated
+///   claims, see `vault::pay_player`)
+pub(crate) fn pay_reward(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+    player_authorized: bool,
+) -> Result<(), Error> {
+    if config.vesting_threshold == 0 || amount <= config.vesting_threshold {
+        return crate::vault::pay_player(env, config, player, amount, player_authorized);
+    }
+
+    let now = env.ledger().sequence();
+    let mut schedule = match storage::get_vesting(env, player) {
+        Some(schedule) => release(schedule, now)?,
+        None => VestingSchedule {
+            unlocked: 0,
+            locked: 0,
+            last_ledger: now,
+            end_ledger: now,
+        },
+    };
+
+    schedule.last_ledger = now;
+    schedule.locked = schedule
+        .locked
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    schedule.end_ledger = schedule
+        .end_ledger
+        .max(now.saturating_add(config.vesting_ledgers));
+    storage::set_vesting(env, player, &schedule);
+
+    emit_reward_vesting_started(env, player, epoch, amount, schedule.end_ledger);
+
+    Ok(())
+}
+
+/// Claim all vested rewards released so far
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `player` - Player claiming
+///
+/// # Returns
+/// Amount of USDC deposited into fee-vault
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If nothing has been released yet
+pub(crate) fn claim_vested(env: &Env, player: &Address) -> Result<i128, Error> {
+    // Authenticate player
+    player.require_auth();
+
+    let schedule = storage::get_vesting(env, player).ok_or(Error::NoRewardsAvailable)?;
+    let mut schedule = release(schedule, env.ledger().sequence())?;
+
+    let amount = schedule.unlocked;
+    if amount <= 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    // Zero the released balance before paying out
+    schedule.unlocked = 0;
+    if schedule.locked == 0 {
+        storage::remove_vesting(env, player);
+    } else {
+        storage::set_vesting(env, player, &schedule);
+    }
+
+    let config = storage::get_config(env);
+    crate::vault::deposit_for_player(env, &config, player, amount)?;
+    crate::rewards::record_claim_history(
+        env,
+        &config,
+        player,
+        storage::get_current_epoch(env),
+        amount,
+        0,
+    );
+
+    emit_vested_rewards_claimed(env, player, amount);
+
+    Ok(amount)
+}
+
+/// Amount a player could claim with `claim_vested` right now
+pub(crate) fn claimable_vested(env: &Env, player: &Address) -> Result<i128, Error> {
+    match storage::get_vesting(env, player) {
+        Some(schedule) => Ok(release(schedule, env.ledger().sequence())?.unlocked),
+        None => Ok(0),
+    }
+}
+
+/// Move the part of `locked` due by `now` into `unlocked`
+fn release(mut schedule: VestingSchedule, now: u32) -> Result<VestingSchedule, Error> {
+    if now <= schedule.last_ledger {
+        return Ok(schedule);
+    }
+
+    let released = if now >= schedule.end_ledger {
+        schedule.locked
+    } else {
+        let elapsed = (now - schedule.last_ledger) as i128;
+        let remaining = (schedule.end_ledger - schedule.last_ledger) as i128;
+        checked_mul_div(schedule.locked, elapsed, remaining, Rounding::Floor)
+            .ok_or(Error::OverflowError)?
+    };
+
+    schedule.unlocked = schedule
+        .unlocked
+        .checked_add(released)
+        .ok_or(Error::OverflowError)?;
+    schedule.locked -= released;
+    schedule.last_ledger = now.min(schedule.end_ledger);
+
+    Ok(schedule)
+}
@@ -0,0 +1,112 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::Env;
+
+use crate::storage;
+use crate::types::{Config, EpochYield, YieldStats, SCALAR_7};
+
+// ============================================================================
+// Yield Statistics
+// ============================================================================
+//
+// Every epoch cycle snapshots the vault backend's b_rate and TVL together with
+// the USDC yield that went into the epoch's reward pools. The realized yield of
+// an epoch is the b_rate growth since the previous snapshot (supply interest
+// kept by depositors) plus rewards / TVL (BLND emissions and admin fees paid
+// out as rewards). The first snapshot has no b_rate baseline and only counts
+// rewards.
+
+/// Number of closed epochs kept in the yield history
+const MAX_YIELD_HISTORY: u32 = 12;
+
+/// Seconds per (365-day) year, for annualizing
+const SECONDS_PER_YEAR: i128 = 365 * 24 * 60 * 60;
+
+/// Record the yield snapshot of an epoch being cycled
+///
+/// Skipped silently if the vault backend can't be queried.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `config` - Contract configuration (vault backend)
+/// * `epoch` - Epoch being closed
+/// * `epoch_start` - Start time of the epoch being closed
+/// * `rewards` - USDC converted into the epoch's reward pools (players + developers)
+pub(crate) fn record_epoch_yield(
+    env: &Env,
+    config: &Config,
+    epoch: u32,
+    epoch_start: u64,
+    rewards: i128,
+) {
+    let Some((b_rate, tvl)) = crate::vault::try_get_b_rate_and_tvl(env, config) else {
+        return;
+    };
+
+    let timestamp = env.ledger().timestamp();
+    let mut history = storage::get_yield_history(env);
+    let (period, supply_yield) = match history.last() {
+        Some(previous) => (
+            timestamp.saturating_sub(previous.timestamp),
+            growth(previous.b_rate, b_rate),
+        ),
+        None => (timestamp.saturating_sub(epoch_start), 0),
+    };
+
+    let reward_yield = if tvl > 0 {
+        rewards.fixed_div_floor(tvl, SCALAR_7).unwrap_or(0)
+    } else {
+        0
+    };
+
+    history.push_back(EpochYield {
+        epoch,
+        timestamp,
+        period,
+        b_rate,
+        tvl,
+        rewards,
+        realized_yield: supply_yield.saturating_add(reward_yield),
+    });
+    if history.len() > MAX_YIELD_HISTORY {
+        history.pop_front();
+    }
+    storage::set_yield_history(env, &history);
+}
+
+/// Get the yield history and trailing APY estimate
+///
+/// The APY is the realized yield over the whole history annualized without
+/// compounding; 0 until the first epoch has been cycled.
+pub(crate) fn get_yield_stats(env: &Env) -> YieldStats {
+    let epochs = storage::get_yield_history(env);
+
+    let mut total_yield: i128 = 0;
+    let mut total_period: u64 = 0;
+    for snapshot in epochs.iter() {
+        total_yield = total_yield.saturating_add(snapshot.realized_yield);
+        total_period = total_period.saturating_add(snapshot.period);
+    }
+
+    let trailing_apy = if total_period > 0 {
+        total_yield
+            .fixed_mul_floor(SECONDS_PER_YEAR, total_period as i128)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    YieldStats {
+        epochs,
+        trailing_apy,
+    }
+}
+
+/// Relative b_rate growth (7 decimals)
+fn growth(previous: i128, current: i128) -> i128 {
+    if previous <= 0 {
+        return 0;
+    }
+    (current - previous)
+        .fixed_div_floor(previous, SCALAR_7)
+        .unwrap_or(0)
+}
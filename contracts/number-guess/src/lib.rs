@@ -11,7 +11,7 @@
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Bytes,
-    BytesN, Env, IntoVal,
+    BytesN, Env, IntoVal, String,
 };
 
 // Import Blendizzard contract interface
@@ -29,6 +29,8 @@ pub trait Blendizzard {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn register_game(env: Env, game_id: Address, metadata: GameMetadata);
 }
 
 // ============================================================================
@@ -74,6 +76,14 @@ pub struct Game {
     pub winner: Option<Address>,
 }
 
+/// Game metadata reported to Blendizzard (standard game interface)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMetadata {
+    pub name: String,
+    pub developer: Address,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -368,6 +378,57 @@ impl NumberGuessContract {
             .ok_or(Error::GameNotFound)
     }
 
+    // ========================================================================
+    // Blendizzard Game Interface
+    // ========================================================================
+
+    /// Request registration with Blendizzard (admin only).
+    /// The game stays pending until the Blendizzard admin approves it.
+    pub fn register_with_blendizzard(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let blendizzard_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendizzardAddress)
+            .expect("Blendizzard address not set");
+        let metadata = Self::game_metadata(env.clone());
+        BlendizzardClient::new(&env, &blendizzard_addr)
+            .register_game(&env.current_contract_address(), &metadata);
+    }
+
+    /// Init hook called by Blendizzard when the registration is approved.
+    ///
+    /// # Arguments
+    /// * `blendizzard` - The approving Blendizzard contract (must be ours)
+    pub fn blendizzard_init(env: Env, blendizzard: Address) {
+        blendizzard.require_auth();
+        let blendizzard_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendizzardAddress)
+            .expect("Blendizzard address not set");
+        assert!(blendizzard == blendizzard_addr, "Unknown Blendizzard");
+    }
+
+    /// Game metadata passed to Blendizzard when registering.
+    /// Developer rewards go to the admin.
+    pub fn game_metadata(env: Env) -> GameMetadata {
+        GameMetadata {
+            name: String::from_str(&env, "Number Guess"),
+            developer: env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .expect("Admin not set"),
+        }
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
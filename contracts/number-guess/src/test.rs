@@ -7,9 +7,9 @@
 // For full integration tests with the real Blendizzard contract, see:
 // contracts/blendizzard/src/tests/number_guess_integration.rs
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{Error, GameMetadata, NumberGuessContract, NumberGuessContractClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
 
 // ============================================================================
 // Mock Blendizzard for Unit Testing
@@ -39,6 +39,10 @@ impl MockBlendizzard {
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
+
+    pub fn register_game(_env: Env, _game_id: Address, _metadata: GameMetadata) {
+        // Mock implementation - does nothing
+    }
 }
 
 // ============================================================================
@@ -538,3 +542,24 @@ fn test_upgrade_function_exists() {
     // This confirms the authorization check passed
     assert!(result.is_err());
 }
+
+#[test]
+fn test_game_metadata_and_registration() {
+    let (env, client, _blendizzard, _player1, _player2) = setup_test();
+
+    let metadata = client.game_metadata();
+    assert_eq!(metadata.name, String::from_str(&env, "Number Guess"));
+    assert_eq!(metadata.developer, client.get_admin());
+
+    client.register_with_blendizzard();
+}
+
+#[test]
+fn test_blendizzard_init_rejects_other_contracts() {
+    let (env, client, blendizzard, _player1, _player2) = setup_test();
+
+    client.blendizzard_init(&blendizzard.address);
+    assert!(client
+        .try_blendizzard_init(&Address::generate(&env))
+        .is_err());
+}